@@ -0,0 +1,162 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::time::{Duration, Instant};
+
+use binius_circuits::{
+	arithmetic::{self, Flags},
+	builder::ConstraintSystemBuilder,
+	keccakf::{keccakf, KeccakfState},
+	unconstrained::unconstrained,
+};
+use binius_core::{
+	constraint_system::{self, ConstraintSystem, PCSParams, Proof},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+	witness::{MultilinearExtensionIndex, WitnessLayout},
+};
+use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_math::DefaultEvaluationDomainFactory;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+type U = OptimalUnderlier;
+type F = BinaryField128b;
+
+const SECURITY_BITS: usize = 100;
+const LOG_INV_RATE: usize = 1;
+
+/// A standard circuit, added to a builder with the binary logarithm of its size.
+type Circuit = fn(&mut ConstraintSystemBuilder<U, F>, usize) -> Result<(), anyhow::Error>;
+
+fn u32_add(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+) -> Result<(), anyhow::Error> {
+	let x = unconstrained::<_, _, BinaryField1b>(builder, "x", log_size + 5)?;
+	let y = unconstrained::<_, _, BinaryField1b>(builder, "y", log_size + 5)?;
+	arithmetic::u32::add(builder, "add", x, y, Flags::Unchecked)?;
+	Ok(())
+}
+
+fn keccakf_permutations(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(0);
+	let inputs = (0..1 << log_size)
+		.map(|_| KeccakfState(rng.gen()))
+		.collect::<Vec<_>>();
+	keccakf(builder, Some(inputs), log_size)?;
+	Ok(())
+}
+
+fn build(
+	circuit: Circuit,
+	log_size: usize,
+	allocator: &bumpalo::Bump,
+) -> (ConstraintSystem<F>, MultilinearExtensionIndex<U, F>) {
+	let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+	circuit(&mut builder, log_size).unwrap();
+	let witness = builder.take_witness().unwrap();
+	(builder.build().unwrap(), witness)
+}
+
+fn prove(
+	constraint_system: &ConstraintSystem<F>,
+	witness: MultilinearExtensionIndex<U, F>,
+) -> Proof {
+	constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		_,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		constraint_system,
+		&PCSParams::new(LOG_INV_RATE, SECURITY_BITS),
+		witness,
+		&DefaultEvaluationDomainFactory::default(),
+		&make_portable_backend(),
+	)
+	.unwrap()
+}
+
+fn verify(constraint_system: &ConstraintSystem<F>, proof: Proof) {
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(constraint_system, &PCSParams::new(LOG_INV_RATE, SECURITY_BITS), vec![], proof)
+	.unwrap();
+}
+
+/// Proves and verifies standard circuits end to end. The witness is generated outside of the
+/// measured time, since proving consumes it.
+fn bench_prove_verify(c: &mut Criterion) {
+	let mut group = c.benchmark_group("prove_verify");
+	group.sample_size(10);
+	let circuits: [(&str, Circuit, usize); 2] = [
+		("u32_add", u32_add, 14),
+		("keccakf", keccakf_permutations, 8),
+	];
+
+	for (name, circuit, log_size) in circuits {
+		group.bench_function(BenchmarkId::new(format!("{name}/prove"), log_size), |bench| {
+			bench.iter_custom(|iters| {
+				let mut elapsed = Duration::ZERO;
+				for _ in 0..iters {
+					let allocator = bumpalo::Bump::new();
+					let (constraint_system, witness) = build(circuit, log_size, &allocator);
+					let start = Instant::now();
+					prove(&constraint_system, witness);
+					elapsed += start.elapsed();
+				}
+				elapsed
+			});
+		});
+
+		let allocator = bumpalo::Bump::new();
+		let (constraint_system, witness) = build(circuit, log_size, &allocator);
+		let proof = prove(&constraint_system, witness);
+		group.bench_function(BenchmarkId::new(format!("{name}/verify"), log_size), |bench| {
+			bench.iter(|| verify(&constraint_system, proof.clone()));
+		});
+	}
+	group.finish()
+}
+
+/// Proves the keccak-f permutation, whose zerocheck reads a wide table of 1-bit columns, with the
+/// witness in every layout.
+fn bench_witness_layout(c: &mut Criterion) {
+	let mut group = c.benchmark_group("prove_verify/witness_layout");
+	group.sample_size(10);
+	let log_size = 8;
+	for layout in [WitnessLayout::ColumnMajor, WitnessLayout::RowMajor] {
+		group.bench_function(BenchmarkId::new(format!("keccakf/{layout:?}"), log_size), |bench| {
+			bench.iter_custom(|iters| {
+				let mut elapsed = Duration::ZERO;
+				for _ in 0..iters {
+					let allocator = bumpalo::Bump::new();
+					let (constraint_system, mut witness) =
+						build(keccakf_permutations, log_size, &allocator);
+					witness.set_layout(layout);
+					let start = Instant::now();
+					prove(&constraint_system, witness);
+					elapsed += start.elapsed();
+				}
+				elapsed
+			});
+		});
+	}
+	group.finish()
+}
+
+criterion_main!(prove_verify);
+criterion_group!(prove_verify, bench_prove_verify, bench_witness_layout);
@@ -1,10 +1,22 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+pub mod u16;
 pub mod u32;
+pub mod u64;
+pub mod u8;
+pub(crate) mod uint;
 
-/// Whether to allow or disallow arithmetic overflow
+/// How arithmetic handles overflow
+///
+/// To read the carry or borrow itself, use the `add_with_carry` and `sub_with_borrow` gadgets of
+/// the integer modules instead.
 #[derive(Debug, Clone, Copy)]
 pub enum Flags {
+	/// Overflow makes the constraints unsatisfiable
 	Checked,
+	/// Results wrap around
 	Unchecked,
+	/// Results are clamped to the largest or smallest integer. Only supported by addition and
+	/// subtraction.
+	Saturating,
 }
@@ -3,13 +3,13 @@
 use binius_core::oracle::{OracleId, ProjectionVariant, ShiftVariant};
 use binius_field::{
 	as_packed_field::PackScalar, packed::set_packed_slice, BinaryField1b, BinaryField32b,
-	ExtensionField, Field, TowerField,
+	BinaryField8b, ExtensionField, Field, TowerField,
 };
 use binius_macros::arith_expr;
+use binius_utils::rayon::prelude::*;
 use bytemuck::Pod;
-use rayon::prelude::*;
 
-use crate::{builder::ConstraintSystemBuilder, transparent};
+use crate::{bitwise, builder::ConstraintSystemBuilder, transparent};
 
 pub fn packed<U, F>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
@@ -43,6 +43,10 @@ where
 	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
 	F: TowerField,
 {
+	anyhow::ensure!(
+		!matches!(flags, super::Flags::Saturating),
+		"Saturating multiplication is not supported"
+	);
 	if value == 0 {
 		let log_rows = builder.log_rows([input])?;
 		return transparent::constant(builder, name, log_rows, BinaryField1b::ZERO);
@@ -147,10 +151,60 @@ where
 		);
 	}
 
+	let zout = match flags {
+		super::Flags::Saturating => super::uint::saturate::<u32, _, _>(builder, zout, cout, true)?,
+		_ => zout,
+	};
+
 	builder.pop_namespace();
 	Ok(zout)
 }
 
+/// Subtracts `yin` from `xin`. With [`super::Flags::Checked`], `xin` must not be less than `yin`.
+pub fn sub<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: super::Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	super::uint::sub::<u32, _, _>(builder, name, xin, yin, flags)
+}
+
+/// Adds `xin` and `yin` with wrapping semantics, returning the sum and a column with the carry
+/// out of every integer, one bit per integer.
+pub fn add_with_carry<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	super::uint::add_with_carry::<u32, _, _>(builder, name, xin, yin)
+}
+
+/// Subtracts `yin` from `xin` with wrapping semantics, returning the difference and a column with
+/// the borrow of every integer, set iff `xin < yin`, one bit per integer.
+pub fn sub_with_borrow<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	super::uint::sub_with_borrow::<u32, _, _>(builder, name, xin, yin)
+}
+
 pub fn half<U, F>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
 	name: impl ToString,
@@ -217,6 +271,32 @@ where
 	Ok(shifted)
 }
 
+/// Rotates every integer left by `offset` bits.
+pub fn rotl<F, U>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	anyhow::ensure!(offset < 32, "offset must be less than 32, got {offset}");
+	if offset == 0 {
+		return Ok(input);
+	}
+
+	let rotated = builder.add_shifted(name, input, offset, 5, ShiftVariant::CircularLeft)?;
+	if let Some(witness) = builder.witness() {
+		(witness.new_column(rotated).as_mut_slice::<u32>(), witness.get(input)?.as_slice::<u32>())
+			.into_par_iter()
+			.for_each(|(rotated, input)| *rotated = input.rotate_left(offset as u32));
+	}
+
+	Ok(rotated)
+}
+
 pub fn select_bit<U, F>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
 	name: impl ToString,
@@ -250,6 +330,217 @@ where
 	Ok(bits)
 }
 
+/// Decomposes a `u32` column into its 4 bytes as `BinaryField8b` columns, from least to most
+/// significant, for use with byte-wise lookups.
+pub fn bytes<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<[OracleId; 4], anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField8b>
+		+ PackScalar<BinaryField32b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField8b> + ExtensionField<BinaryField32b>,
+{
+	super::uint::bytes::<u32, _, _, 4>(builder, name, input)
+}
+
+/// Flips every bit of the integers, as the sum of the input and the all-ones column.
+pub fn not<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([input])?;
+	let output =
+		builder.add_linear_combination_with_offset(name, log_rows, F::ONE, [(input, F::ONE)])?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(output)
+				.as_mut_slice::<u32>(),
+			witness.get::<BinaryField1b>(input)?.as_slice::<u32>(),
+		)
+			.into_par_iter()
+			.for_each(|(output, input)| *output = !*input);
+	}
+	Ok(output)
+}
+
+/// Reverses the byte order of the integers, converting between little- and big-endian.
+///
+/// The result is a virtual column, built from shifts and linear combinations of the input.
+pub fn bswap<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	super::uint::reverse_blocks::<u32, _, _>(builder, name, input, 3)
+}
+
+/// Reverses the bit order of the integers.
+///
+/// The result is a virtual column, built from shifts and linear combinations of the input.
+pub fn bitreverse<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	super::uint::reverse_blocks::<u32, _, _>(builder, name, input, 0)
+}
+
+/// Counts the set bits of the integers.
+///
+/// The bits are summed in a tree of depth 5: level `k` adds the two halves of every block of
+/// `2^k` bits, which are virtual shifts of the previous level, into a count held by the block.
+pub fn popcount<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let mut count = input;
+	for log_block in 1..=5 {
+		let half = 1 << (log_block - 1);
+		let high = shift_blocks(
+			builder,
+			format!("high{log_block}"),
+			count,
+			half,
+			log_block,
+			ShiftVariant::LogicalRight,
+		)?;
+		let low_shl = shift_blocks(
+			builder,
+			format!("low_shl{log_block}"),
+			count,
+			half,
+			log_block,
+			ShiftVariant::LogicalLeft,
+		)?;
+		let low = shift_blocks(
+			builder,
+			format!("low{log_block}"),
+			low_shl,
+			half,
+			log_block,
+			ShiftVariant::LogicalRight,
+		)?;
+		// The count of a block of 2^k bits fits into the block, so the sum does not carry out of it.
+		count = add(builder, format!("count{log_block}"), low, high, super::Flags::Unchecked)?;
+	}
+	builder.pop_namespace();
+	Ok(count)
+}
+
+/// Counts the leading zero bits of the integers, which is 32 for zero.
+///
+/// Every bit below the most significant set bit is set by or-ing the input with its right shifts,
+/// and the zero bits left are counted.
+pub fn clz<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let mut smeared = input;
+	for log_offset in 0..5 {
+		let offset = 1 << log_offset;
+		let shifted = shr(builder, format!("shr{offset}"), smeared, offset)?;
+		smeared = bitwise::or(builder, format!("smear{offset}"), smeared, shifted)?;
+	}
+	let zeros = not(builder, "zeros", smeared)?;
+	let count = popcount(builder, "popcount", zeros)?;
+	builder.pop_namespace();
+	Ok(count)
+}
+
+/// Counts the trailing zero bits of the integers, which is 32 for zero.
+///
+/// Every bit above the least significant set bit is set by or-ing the input with its left shifts,
+/// and the zero bits left are counted.
+pub fn ctz<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let mut smeared = input;
+	for log_offset in 0..5 {
+		let offset = 1 << log_offset;
+		let shifted = shl(builder, format!("shl{offset}"), smeared, offset)?;
+		smeared = bitwise::or(builder, format!("smear{offset}"), smeared, shifted)?;
+	}
+	let zeros = not(builder, "zeros", smeared)?;
+	let count = popcount(builder, "popcount", zeros)?;
+	builder.pop_namespace();
+	Ok(count)
+}
+
+/// Shifts every block of `2^log_block` bits of the integers by `offset`.
+fn shift_blocks<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+	log_block: usize,
+	variant: ShiftVariant,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let shift: fn(u32, usize) -> u32 = match variant {
+		ShiftVariant::LogicalLeft => |x, offset| x << offset,
+		ShiftVariant::LogicalRight => |x, offset| x >> offset,
+		_ => anyhow::bail!("only logical shifts are supported, got {variant:?}"),
+	};
+	let shifted = builder.add_shifted(name, input, offset, log_block, variant)?;
+	if let Some(witness) = builder.witness() {
+		// The bits shifted into a block from outside of it are zero.
+		let block = 1 << log_block;
+		let ones = (1u64 << block) - 1;
+		let pattern = match variant {
+			ShiftVariant::LogicalLeft => ones ^ ((1 << offset) - 1),
+			_ => ones >> offset,
+		};
+		let mask = (0..32)
+			.step_by(block)
+			.fold(0, |mask, i| mask | pattern << i) as u32;
+		(witness.new_column(shifted).as_mut_slice::<u32>(), witness.get(input)?.as_slice::<u32>())
+			.into_par_iter()
+			.for_each(|(shifted, input)| *shifted = shift(*input, offset) & mask);
+	}
+	Ok(shifted)
+}
+
 pub fn constant<F, U>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
 	name: impl ToString,
@@ -293,10 +584,16 @@ where
 
 #[cfg(test)]
 mod tests {
-	use binius_core::constraint_system::validate::validate_witness;
-	use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b, TowerField};
+	use binius_core::{
+		constraint_system::validate::validate_witness, oracle::MultilinearPolyOracle,
+	};
+	use binius_field::{
+		arch::OptimalUnderlier, packed::get_packed_slice, BinaryField128b, BinaryField1b,
+		BinaryField8b, Field, TowerField,
+	};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
 
-	use crate::{arithmetic, builder::ConstraintSystemBuilder};
+	use crate::{arithmetic, builder::ConstraintSystemBuilder, testing::SoundnessProbe};
 
 	type U = OptimalUnderlier;
 	type F = BinaryField128b;
@@ -323,4 +620,206 @@ mod tests {
 		let boundaries = vec![];
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
+
+	#[test]
+	fn test_carry_and_saturating() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let xs = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u32>>();
+		let ys = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u32>>();
+		let x = builder.add_committed("x", 6 + 5, BinaryField1b::TOWER_LEVEL);
+		let y = builder.add_committed("y", 6 + 5, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(x)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&xs);
+			witness
+				.new_column::<BinaryField1b>(y)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&ys);
+		}
+
+		let (sum, carry) = arithmetic::u32::add_with_carry(&mut builder, "adc", x, y).unwrap();
+		let (diff, borrow) = arithmetic::u32::sub_with_borrow(&mut builder, "sbb", x, y).unwrap();
+		let sat_sum =
+			arithmetic::u32::add(&mut builder, "add_sat", x, y, arithmetic::Flags::Saturating)
+				.unwrap();
+		let sat_diff =
+			arithmetic::u32::sub(&mut builder, "sub_sat", x, y, arithmetic::Flags::Saturating)
+				.unwrap();
+		assert!(arithmetic::u32::mul_const(
+			&mut builder,
+			"mul_sat",
+			x,
+			3,
+			arithmetic::Flags::Saturating
+		)
+		.is_err());
+
+		let witness = builder.witness().unwrap();
+		let words = |id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>();
+		let bit = |id, i| get_packed_slice(witness.get::<BinaryField1b>(id).unwrap().packed(), i);
+		for (i, (&x, &y)) in xs.iter().zip(&ys).enumerate() {
+			let (expected_sum, expected_carry) = x.overflowing_add(y);
+			let (expected_diff, expected_borrow) = x.overflowing_sub(y);
+			assert_eq!(words(sum)[i], expected_sum);
+			assert_eq!(bit(carry, i) == BinaryField1b::ONE, expected_carry);
+			assert_eq!(words(diff)[i], expected_diff);
+			assert_eq!(bit(borrow, i) == BinaryField1b::ONE, expected_borrow);
+			assert_eq!(words(sat_sum)[i], x.saturating_add(y));
+			assert_eq!(words(sat_diff)[i], x.saturating_sub(y));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_add_binds_outputs() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let x = builder.add_committed("x", 2 + 5, BinaryField1b::TOWER_LEVEL);
+		let y = builder.add_committed("y", 2 + 5, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			rng.fill(witness.new_column::<BinaryField1b>(x).as_mut_slice::<u32>());
+			rng.fill(witness.new_column::<BinaryField1b>(y).as_mut_slice::<u32>());
+		}
+
+		arithmetic::u32::add(&mut builder, "add", x, y, arithmetic::Flags::Unchecked).unwrap();
+
+		// Every bit of the sum and of the carries is bound by the constraints.
+		let mut probe = SoundnessProbe::new(builder, vec![]).unwrap();
+		probe.check_all_except(&[x, y]).unwrap();
+	}
+
+	#[test]
+	fn test_bytes() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let words = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u32>>();
+		let word = builder.add_committed("word", 6 + 5, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(word)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&words);
+		}
+
+		let bytes = arithmetic::u32::bytes(&mut builder, "bytes", word).unwrap();
+
+		let witness = builder.witness().unwrap();
+		for (k, &byte) in bytes.iter().enumerate() {
+			let byte = witness.get::<BinaryField8b>(byte).unwrap();
+			for (i, &word) in words.iter().enumerate() {
+				assert_eq!(byte.as_slice::<u8>()[i], word.to_le_bytes()[k]);
+			}
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_bit_counts() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut words = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u32>>();
+		words[..6].copy_from_slice(&[0, 1, u32::MAX, 1 << 31, 0x0001_0000, 0x00f0_0f00]);
+		let word = builder.add_committed("word", 6 + 5, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(word)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&words);
+		}
+
+		let popcount = arithmetic::u32::popcount(&mut builder, "popcount", word).unwrap();
+		let clz = arithmetic::u32::clz(&mut builder, "clz", word).unwrap();
+		let ctz = arithmetic::u32::ctz(&mut builder, "ctz", word).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let counts = |id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>();
+		for (i, &word) in words.iter().enumerate() {
+			assert_eq!(counts(popcount)[i], word.count_ones());
+			assert_eq!(counts(clz)[i], word.leading_zeros());
+			assert_eq!(counts(ctz)[i], word.trailing_zeros());
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_bswap_and_bitreverse() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let words = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u32>>();
+		let word = builder.add_committed("word", 6 + 5, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(word)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&words);
+		}
+
+		let bswap = arithmetic::u32::bswap(&mut builder, "bswap", word).unwrap();
+		let bitreverse = arithmetic::u32::bitreverse(&mut builder, "bitreverse", word).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let values = |id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>();
+		for (i, &word) in words.iter().enumerate() {
+			assert_eq!(values(bswap)[i], word.swap_bytes());
+			assert_eq!(values(bitreverse)[i], word.reverse_bits());
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+		// Only the input is committed.
+		let committed = constraint_system
+			.oracles
+			.iter()
+			.filter(|oracle| matches!(oracle, MultilinearPolyOracle::Committed { .. }))
+			.count();
+		assert_eq!(committed, 1);
+	}
+
+	#[test]
+	fn test_rotl() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let words = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u32>>();
+		let word = builder.add_committed("word", 6 + 5, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(word)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&words);
+		}
+
+		let rotl7 = arithmetic::u32::rotl(&mut builder, "rotl7", word, 7).unwrap();
+		let rotl16 = arithmetic::u32::rotl(&mut builder, "rotl16", word, 16).unwrap();
+		assert!(arithmetic::u32::rotl(&mut builder, "rotl32", word, 32).is_err());
+
+		let witness = builder.witness().unwrap();
+		let values = |id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>();
+		for (i, &word) in words.iter().enumerate() {
+			assert_eq!(values(rotl7)[i], word.rotate_left(7));
+			assert_eq!(values(rotl16)[i], word.rotate_left(16));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
 }
@@ -0,0 +1,350 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Bitwise operations, addition and subtraction on columns of `u64` integers.
+//!
+//! Every block of 64 consecutive bits of a column holds one integer in little-endian bit order, the
+//! same layout as [`super::u32`]. Shifts and rotations are virtual shifted oracles, and `xor` and
+//! `not` are linear combinations, so only `and` commits a new column. These are the building blocks
+//! of 64-bit hash functions like SHA-512, Blake2b and Keccak. Addition and subtraction use the same
+//! carry constraints as the narrower integer modules.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField64b, BinaryField8b, ExtensionField,
+	TowerField,
+};
+use binius_utils::rayon::prelude::*;
+use bytemuck::Pod;
+
+use super::{uint, Flags};
+use crate::{bitwise, builder::ConstraintSystemBuilder};
+
+const LOG_U64_BITS: usize = 6;
+
+/// Adds `xin` and `yin`. Results wrap around unless the [`Flags::Checked`] or
+/// [`Flags::Saturating`] flag is given.
+pub fn add<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::add::<u64, _, _>(builder, name, xin, yin, flags)
+}
+
+/// Subtracts `yin` from `xin`. With [`Flags::Checked`], `xin` must not be less than `yin`.
+pub fn sub<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::sub::<u64, _, _>(builder, name, xin, yin, flags)
+}
+
+/// Adds `xin` and `yin` with wrapping semantics, returning the sum and a column with the carry
+/// out of every integer, one bit per integer.
+pub fn add_with_carry<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::add_with_carry::<u64, _, _>(builder, name, xin, yin)
+}
+
+/// Subtracts `yin` from `xin` with wrapping semantics, returning the difference and a column with
+/// the borrow of every integer, set iff `xin < yin`, one bit per integer.
+pub fn sub_with_borrow<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::sub_with_borrow::<u64, _, _>(builder, name, xin, yin)
+}
+
+/// Shifts every integer left by `offset` bits, filling with zeros.
+pub fn shl<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	shift(builder, name, input, offset, ShiftVariant::LogicalLeft, |x| x << offset)
+}
+
+/// Shifts every integer right by `offset` bits, filling with zeros.
+pub fn shr<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	shift(builder, name, input, offset, ShiftVariant::LogicalRight, |x| x >> offset)
+}
+
+/// Rotates every integer left by `offset` bits.
+pub fn rotl<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	shift(builder, name, input, offset, ShiftVariant::CircularLeft, |x| {
+		x.rotate_left(offset as u32)
+	})
+}
+
+/// Rotates every integer right by `offset` bits.
+pub fn rotr<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	shift(builder, name, input, offset, ShiftVariant::CircularRight, |x| {
+		x.rotate_right(offset as u32)
+	})
+}
+
+pub fn xor<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	bitwise::xor(builder, name, xin, yin)
+}
+
+pub fn and<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	bitwise::and(builder, name, xin, yin)
+}
+
+/// Flips every bit of the integers, as the sum of the input and the all-ones column.
+pub fn not<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([input])?;
+	let output =
+		builder.add_linear_combination_with_offset(name, log_rows, F::ONE, [(input, F::ONE)])?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(output)
+				.as_mut_slice::<u64>(),
+			witness.get::<BinaryField1b>(input)?.as_slice::<u64>(),
+		)
+			.into_par_iter()
+			.for_each(|(output, input)| *output = !*input);
+	}
+	Ok(output)
+}
+
+/// Reverses the byte order of the integers, converting between little- and big-endian.
+///
+/// The result is a virtual column, built from shifts and linear combinations of the input.
+pub fn bswap<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::reverse_blocks::<u64, _, _>(builder, name, input, 3)
+}
+
+/// Decomposes a `u64` column into its 8 bytes as `BinaryField8b` columns, from least to most
+/// significant, for use with byte-wise lookups.
+pub fn bytes<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<[OracleId; 8], anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField8b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField8b> + ExtensionField<BinaryField64b>,
+{
+	uint::bytes::<u64, _, _, 8>(builder, name, input)
+}
+
+fn shift<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+	variant: ShiftVariant,
+	op: impl Fn(u64) -> u64 + Sync,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	anyhow::ensure!(offset < 64, "offset must be less than 64, got {offset}");
+	if offset == 0 {
+		return Ok(input);
+	}
+
+	let shifted = builder.add_shifted(name, input, offset, LOG_U64_BITS, variant)?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(shifted)
+				.as_mut_slice::<u64>(),
+			witness.get::<BinaryField1b>(input)?.as_slice::<u64>(),
+		)
+			.into_par_iter()
+			.for_each(|(shifted, input)| *shifted = op(*input));
+	}
+	Ok(shifted)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{
+		arch::OptimalUnderlier, packed::get_packed_slice, BinaryField128b, BinaryField1b, Field,
+		TowerField,
+	};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use crate::{arithmetic, builder::ConstraintSystemBuilder};
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+
+	fn column(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: &str,
+		log_rows: usize,
+		values: &[u64],
+	) -> OracleId {
+		let id = builder.add_committed(name, log_rows, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(id)
+				.as_mut_slice::<u64>()
+				.copy_from_slice(values);
+		}
+		id
+	}
+
+	fn values(builder: &mut ConstraintSystemBuilder<U, F>, id: OracleId) -> Vec<u64> {
+		let witness = builder.witness().unwrap();
+		witness
+			.get::<BinaryField1b>(id)
+			.unwrap()
+			.as_slice::<u64>()
+			.to_vec()
+	}
+
+	#[test]
+	fn test_bitops() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let xs = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u64>>();
+		let ys = (0..1 << 6).map(|_| rng.gen()).collect::<Vec<u64>>();
+		let log_rows = 6 + u64::BITS.ilog2() as usize;
+		let x = column(&mut builder, "x", log_rows, &xs);
+		let y = column(&mut builder, "y", log_rows, &ys);
+
+		let shl = arithmetic::u64::shl(&mut builder, "shl", x, 13).unwrap();
+		let shr = arithmetic::u64::shr(&mut builder, "shr", x, 41).unwrap();
+		let rotl = arithmetic::u64::rotl(&mut builder, "rotl", x, 1).unwrap();
+		let rotr = arithmetic::u64::rotr(&mut builder, "rotr", x, 63).unwrap();
+		let xor = arithmetic::u64::xor(&mut builder, "xor", x, y).unwrap();
+		let and = arithmetic::u64::and(&mut builder, "and", x, y).unwrap();
+		let not = arithmetic::u64::not(&mut builder, "not", x).unwrap();
+		let bswap = arithmetic::u64::bswap(&mut builder, "bswap", x).unwrap();
+		let (sum, carry) = arithmetic::u64::add_with_carry(&mut builder, "adc", x, y).unwrap();
+		let sat_diff =
+			arithmetic::u64::sub(&mut builder, "sub_sat", x, y, arithmetic::Flags::Saturating)
+				.unwrap();
+		assert_eq!(arithmetic::u64::rotr(&mut builder, "rotr_0", x, 0).unwrap(), x);
+		assert!(arithmetic::u64::rotr(&mut builder, "rotr_64", x, 64).is_err());
+
+		for (i, (&x, &y)) in xs.iter().zip(&ys).enumerate() {
+			assert_eq!(values(&mut builder, shl)[i], x << 13);
+			assert_eq!(values(&mut builder, shr)[i], x >> 41);
+			assert_eq!(values(&mut builder, rotl)[i], x.rotate_left(1));
+			assert_eq!(values(&mut builder, rotr)[i], x.rotate_right(63));
+			assert_eq!(values(&mut builder, xor)[i], x ^ y);
+			assert_eq!(values(&mut builder, and)[i], x & y);
+			assert_eq!(values(&mut builder, not)[i], !x);
+			assert_eq!(values(&mut builder, bswap)[i], x.swap_bytes());
+			assert_eq!(values(&mut builder, sum)[i], x.wrapping_add(y));
+			assert_eq!(values(&mut builder, sat_diff)[i], x.saturating_sub(y));
+			let carry = builder
+				.witness()
+				.unwrap()
+				.get::<BinaryField1b>(carry)
+				.unwrap();
+			let carry = get_packed_slice(carry.packed(), i) == BinaryField1b::ONE;
+			assert_eq!(carry, x.checked_add(y).is_none());
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+}
@@ -0,0 +1,237 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Arithmetic on columns of `u8` integers.
+//!
+//! Every block of 8 consecutive bits of a column holds one integer in little-endian bit order, the
+//! same layout as [`super::u32`]. Results wrap around unless the [`Flags::Checked`] flag is given,
+//! in which case overflow is rejected, or the [`Flags::Saturating`] flag, in which case they are
+//! clamped.
+
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField8b, ExtensionField, TowerField,
+};
+use bytemuck::Pod;
+
+use super::{uint, Flags};
+use crate::builder::ConstraintSystemBuilder;
+
+pub fn add<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::add::<u8, _, _>(builder, name, xin, yin, flags)
+}
+
+/// Subtracts `yin` from `xin`. With [`Flags::Checked`], `xin` must not be less than `yin`.
+pub fn sub<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::sub::<u8, _, _>(builder, name, xin, yin, flags)
+}
+
+/// Adds `xin` and `yin` with wrapping semantics, returning the sum and a column with the carry
+/// out of every integer, one bit per integer.
+pub fn add_with_carry<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::add_with_carry::<u8, _, _>(builder, name, xin, yin)
+}
+
+/// Subtracts `yin` from `xin` with wrapping semantics, returning the difference and a column with
+/// the borrow of every integer, set iff `xin < yin`, one bit per integer.
+pub fn sub_with_borrow<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::sub_with_borrow::<u8, _, _>(builder, name, xin, yin)
+}
+
+/// Multiplies `xin` by `yin` with shift-and-add.
+pub fn mul<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	uint::mul::<u8, _, _>(builder, name, xin, yin, flags)
+}
+
+pub fn packed<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField8b> + Pod,
+	F: TowerField + ExtensionField<BinaryField8b>,
+{
+	uint::packed::<u8, _, _>(builder, name, input)
+}
+
+/// Splits a `u32` column into its 4 byte lanes, from least to most significant.
+pub fn unpack_u32<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<[OracleId; 4], anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField8b> + Pod,
+	F: TowerField + ExtensionField<BinaryField8b>,
+{
+	uint::unpack_u32::<u8, _, _, 4>(builder, name, input)
+}
+
+/// Joins 4 byte lanes, from least to most significant, into a `u32` column.
+pub fn pack_u32<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	lanes: [OracleId; 4],
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField8b> + Pod,
+	F: TowerField + ExtensionField<BinaryField8b>,
+{
+	uint::pack_u32::<u8, _, _, 4>(builder, name, lanes)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b, TowerField};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use crate::{arithmetic, builder::ConstraintSystemBuilder};
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+
+	fn column(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: &str,
+		log_rows: usize,
+		values: impl Fn(usize) -> u8,
+	) -> OracleId {
+		let id = builder.add_committed(name, log_rows, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(id)
+				.as_mut_slice::<u8>()
+				.iter_mut()
+				.enumerate()
+				.for_each(|(i, v)| *v = values(i));
+		}
+		id
+	}
+
+	fn values(builder: &mut ConstraintSystemBuilder<U, F>, id: OracleId) -> Vec<u8> {
+		let witness = builder.witness().unwrap();
+		witness
+			.get::<BinaryField1b>(id)
+			.unwrap()
+			.as_slice::<u8>()
+			.to_vec()
+	}
+
+	#[test]
+	fn test_add_sub_mul() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let xs = (0..1 << 7).map(|_| rng.gen()).collect::<Vec<u8>>();
+		let ys = (0..1 << 7).map(|_| rng.gen()).collect::<Vec<u8>>();
+		let log_rows = 7 + u8::BITS.ilog2() as usize;
+		let x = column(&mut builder, "x", log_rows, |i| xs[i % xs.len()]);
+		let y = column(&mut builder, "y", log_rows, |i| ys[i % ys.len()]);
+
+		let flags = arithmetic::Flags::Unchecked;
+		let sum = arithmetic::u8::add(&mut builder, "add", x, y, flags).unwrap();
+		let diff = arithmetic::u8::sub(&mut builder, "sub", x, y, flags).unwrap();
+		let product = arithmetic::u8::mul(&mut builder, "mul", x, y, flags).unwrap();
+
+		for (i, (&x, &y)) in xs.iter().zip(&ys).enumerate() {
+			assert_eq!(values(&mut builder, sum)[i], x.wrapping_add(y));
+			assert_eq!(values(&mut builder, diff)[i], x.wrapping_sub(y));
+			assert_eq!(values(&mut builder, product)[i], x.wrapping_mul(y));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_checked_mul_rejects_overflow() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_rows = 7 + u8::BITS.ilog2() as usize;
+		let x = column(&mut builder, "x", log_rows, |_| 1 << (u8::BITS - 1));
+		let y = column(&mut builder, "y", log_rows, |_| 2);
+		let _ = arithmetic::u8::mul(&mut builder, "mul", x, y, arithmetic::Flags::Checked).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+
+	#[test]
+	fn test_unpack_pack_u32() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let words = (0..1 << 7).map(|_| rng.gen()).collect::<Vec<u32>>();
+		let word = builder.add_committed("word", 7 + 5, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(word)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&words);
+		}
+
+		let lanes = arithmetic::u8::unpack_u32(&mut builder, "unpack", word).unwrap();
+		for (k, &lane) in lanes.iter().enumerate() {
+			for (j, &w) in words.iter().enumerate() {
+				assert_eq!(values(&mut builder, lane)[j], (w >> (k as u32 * u8::BITS)) as u8);
+			}
+		}
+		let repacked = arithmetic::u8::pack_u32(&mut builder, "pack", lanes).unwrap();
+		assert_eq!(values(&mut builder, repacked), values(&mut builder, word));
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+}
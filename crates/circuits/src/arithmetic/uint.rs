@@ -0,0 +1,1009 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets shared by the unsigned integer modules and the floating-point gadgets.
+//!
+//! An integer column is a column of bits where every block of `T::BITS` consecutive rows holds
+//! one integer in little-endian bit order, the same layout as the 32-bit integers in
+//! [`super::u32`]. Since the layout is the same for all widths, a `u32` column of $2^n$ rows holds
+//! the same bits as a `u8` column whose integers are the little-endian bytes of the `u32` values.
+
+use binius_core::oracle::{OracleId, ProjectionVariant, ShiftVariant};
+use binius_field::{
+	as_packed_field::PackScalar, packed::set_packed_slice, BinaryField1b, BinaryField8b,
+	ExtensionField, Field, TowerField,
+};
+use binius_macros::arith_expr;
+use binius_math::ArithExpr;
+use binius_utils::rayon::prelude::*;
+use bytemuck::Pod;
+
+use super::Flags;
+use crate::builder::ConstraintSystemBuilder;
+
+/// An unsigned integer type of at most 64 bits.
+pub(crate) trait UInt: Pod + Send + Sync {
+	/// The binary logarithm of the bit width.
+	const LOG_BITS: usize;
+
+	/// The tower field with the same bit width, used for the packed representation.
+	type Packed: TowerField + Pod;
+
+	fn bits() -> usize {
+		1 << Self::LOG_BITS
+	}
+
+	fn from_u64_truncated(value: u64) -> Self;
+
+	fn into_u64(self) -> u64;
+}
+
+impl UInt for u8 {
+	const LOG_BITS: usize = 3;
+	type Packed = binius_field::BinaryField8b;
+
+	fn from_u64_truncated(value: u64) -> Self {
+		value as u8
+	}
+
+	fn into_u64(self) -> u64 {
+		self as u64
+	}
+}
+
+impl UInt for u16 {
+	const LOG_BITS: usize = 4;
+	type Packed = binius_field::BinaryField16b;
+
+	fn from_u64_truncated(value: u64) -> Self {
+		value as u16
+	}
+
+	fn into_u64(self) -> u64 {
+		self as u64
+	}
+}
+
+impl UInt for u32 {
+	const LOG_BITS: usize = 5;
+	type Packed = binius_field::BinaryField32b;
+
+	fn from_u64_truncated(value: u64) -> Self {
+		value as u32
+	}
+
+	fn into_u64(self) -> u64 {
+		self as u64
+	}
+}
+
+impl UInt for u64 {
+	const LOG_BITS: usize = 6;
+	type Packed = binius_field::BinaryField64b;
+
+	fn from_u64_truncated(value: u64) -> Self {
+		value
+	}
+
+	fn into_u64(self) -> u64 {
+		self
+	}
+}
+
+pub(crate) fn add<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let (zout, cout) = wrapping_add::<T, _, _>(builder, xin, yin, flags)?;
+	let zout = match flags {
+		Flags::Saturating => saturate::<T, _, _>(builder, zout, cout, true)?,
+		_ => zout,
+	};
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+pub(crate) fn sub<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let (zout, cout) = wrapping_sub::<T, _, _>(builder, xin, yin, flags)?;
+	let zout = match flags {
+		Flags::Saturating => saturate::<T, _, _>(builder, zout, cout, false)?,
+		_ => zout,
+	};
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Adds two integer columns with wrapping semantics, also returning the carry out of every
+/// integer as a column with one bit per integer.
+pub(crate) fn add_with_carry<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let (zout, cout) = wrapping_add::<T, _, _>(builder, xin, yin, Flags::Unchecked)?;
+	let carry = select_bit::<T, _, _>(builder, "carry", cout, T::bits() - 1)?;
+	builder.pop_namespace();
+	Ok((zout, carry))
+}
+
+/// Subtracts `yin` from `xin` with wrapping semantics, also returning the borrow of every integer,
+/// which is set iff `xin < yin`, as a column with one bit per integer.
+pub(crate) fn sub_with_borrow<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let (zout, cout) = wrapping_sub::<T, _, _>(builder, xin, yin, Flags::Unchecked)?;
+	let borrow = select_bit::<T, _, _>(builder, "borrow", cout, T::bits() - 1)?;
+	builder.pop_namespace();
+	Ok((zout, borrow))
+}
+
+/// The wrapping sum of two integer columns and the carry column of [`constrain_sum`].
+fn wrapping_add<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([xin, yin])?;
+	let zout = builder.add_committed("zout", log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<BinaryField1b>(xin)?.as_slice::<T>(),
+			witness.get::<BinaryField1b>(yin)?.as_slice::<T>(),
+			witness
+				.new_column::<BinaryField1b>(zout)
+				.as_mut_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, yin, zout)| {
+				*zout = T::from_u64_truncated(xin.into_u64().wrapping_add(yin.into_u64()));
+			});
+	}
+	let cout = constrain_sum::<T, _, _>(builder, xin, yin, zout, flags)?;
+	Ok((zout, cout))
+}
+
+/// The wrapping difference of two integer columns and the carry column of [`constrain_sum`],
+/// whose top bit is the borrow.
+fn wrapping_sub<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([xin, yin])?;
+	let zout = builder.add_committed("zout", log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<BinaryField1b>(xin)?.as_slice::<T>(),
+			witness.get::<BinaryField1b>(yin)?.as_slice::<T>(),
+			witness
+				.new_column::<BinaryField1b>(zout)
+				.as_mut_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, yin, zout)| {
+				*zout = T::from_u64_truncated(xin.into_u64().wrapping_sub(yin.into_u64()));
+			});
+	}
+	// The difference is constrained by yin + zout = xin, which overflows iff xin < yin.
+	let cout = constrain_sum::<T, _, _>(builder, yin, zout, xin, flags)?;
+	Ok((zout, cout))
+}
+
+/// Clamps the wrapped result `zout` wherever the top bit of the carry column `cout` is set, to
+/// the maximum integer if `to_max` and to zero otherwise.
+pub(crate) fn saturate<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	zout: OracleId,
+	cout: OracleId,
+	to_max: bool,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([zout, cout])?;
+	let overflow = broadcast_bit::<T, _, _>(builder, "overflow", cout, T::bits() - 1)?;
+	let saturated = builder.add_committed("saturated", log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<BinaryField1b>(zout)?.as_slice::<T>(),
+			witness.get::<BinaryField1b>(overflow)?.as_slice::<T>(),
+			witness
+				.new_column::<BinaryField1b>(saturated)
+				.as_mut_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(zout, overflow, saturated)| {
+				let (zout, overflow) = (zout.into_u64(), overflow.into_u64());
+				*saturated = T::from_u64_truncated(if to_max {
+					zout | overflow
+				} else {
+					zout & !overflow
+				});
+			});
+	}
+	if to_max {
+		builder.assert_zero(
+			"saturate_max",
+			[zout, overflow, saturated],
+			arith_expr!([z, o, s] = z + o - z * o - s).convert_field(),
+		);
+	} else {
+		builder.assert_zero(
+			"saturate_zero",
+			[zout, overflow, saturated],
+			arith_expr!([z, o, s] = z - z * o - s).convert_field(),
+		);
+	}
+	Ok(saturated)
+}
+
+/// Multiplies two integer columns with shift-and-add, keeping the low `T::BITS` bits of the
+/// product.
+pub(crate) fn mul<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	mul_low_bits::<T, _, _>(builder, name, xin, yin, T::bits(), flags)
+}
+
+/// Multiplies `xin` by the lowest `y_bits` bits of `yin`, which is cheaper than [`mul`] when the
+/// caller knows that the remaining bits of `yin` are zero.
+pub(crate) fn mul_low_bits<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	y_bits: usize,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	anyhow::ensure!(
+		!matches!(flags, Flags::Saturating),
+		"Saturating multiplication is not supported"
+	);
+	anyhow::ensure!(
+		(1..=T::bits()).contains(&y_bits),
+		"y_bits must be between 1 and {}. Got {y_bits}",
+		T::bits()
+	);
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin, yin])?;
+
+	let x_bits = match flags {
+		Flags::Checked => (0..T::bits())
+			.map(|j| select_bit::<T, _, _>(builder, format!("x_bit{j}"), xin, j))
+			.collect::<Result<Vec<_>, _>>()?,
+		Flags::Unchecked | Flags::Saturating => Vec::new(),
+	};
+
+	let mut result = None;
+	for i in 0..y_bits {
+		let y_bit = broadcast_bit::<T, _, _>(builder, format!("y_bit{i}"), yin, i)?;
+
+		// The partial product (x << i) * y_i.
+		let x_shifted = shl::<T, _, _>(builder, format!("x_shl{i}"), xin, i)?;
+		let partial =
+			builder.add_committed(format!("partial{i}"), log_rows, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			(
+				witness.get::<BinaryField1b>(x_shifted)?.as_slice::<T>(),
+				witness.get::<BinaryField1b>(y_bit)?.as_slice::<T>(),
+				witness
+					.new_column::<BinaryField1b>(partial)
+					.as_mut_slice::<T>(),
+			)
+				.into_par_iter()
+				.for_each(|(x_shifted, y_bit, partial)| {
+					*partial = T::from_u64_truncated(x_shifted.into_u64() & y_bit.into_u64());
+				});
+		}
+		builder.assert_zero(
+			format!("partial{i}"),
+			[x_shifted, y_bit, partial],
+			arith_expr!([x, y, z] = x * y - z).convert_field(),
+		);
+
+		if matches!(flags, Flags::Checked) {
+			// The bits of x shifted out must be zero wherever y_i is set.
+			let y_bit_low = select_bit::<T, _, _>(builder, format!("y_bit{i}_low"), y_bit, 0)?;
+			for (j, &x_bit) in x_bits.iter().enumerate().skip(T::bits() - i) {
+				builder.assert_zero(
+					format!("overflow{i}_{j}"),
+					[x_bit, y_bit_low],
+					arith_expr!([x, y] = x * y).convert_field(),
+				);
+			}
+		}
+
+		result = Some(match result {
+			None => partial,
+			Some(acc) => add::<T, _, _>(builder, format!("acc{i}"), acc, partial, flags)?,
+		});
+	}
+
+	builder.pop_namespace();
+	Ok(result.expect("y_bits is positive"))
+}
+
+/// Broadcasts bit `index` of every integer to all bits of the integer, giving either zero or
+/// all ones.
+pub(crate) fn broadcast_bit<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	index: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	let output = builder.add_committed("output", log_rows, BinaryField1b::TOWER_LEVEL);
+	let rotated =
+		builder.add_shifted("rotated", output, 1, T::LOG_BITS, ShiftVariant::CircularLeft)?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(output)
+				.as_mut_slice::<T>(),
+			witness.get::<BinaryField1b>(input)?.as_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(output, input)| {
+				let mask = 0u64.wrapping_sub((input.into_u64() >> index) & 1);
+				*output = T::from_u64_truncated(mask);
+			});
+		witness.set(rotated, witness.get::<BinaryField1b>(output)?)?;
+	}
+	// A block equal to its own rotation is either all zeros or all ones.
+	builder.assert_zero(
+		"broadcast",
+		[output, rotated],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+	let low = select_bit::<T, _, _>(builder, "low", output, 0)?;
+	let expected = select_bit::<T, _, _>(builder, "expected", input, index)?;
+	builder.assert_zero("select", [low, expected], arith_expr!([x, y] = x - y).convert_field());
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Selects `if_true` where the broadcast condition `cond` is all ones and `if_false` where it is
+/// zero.
+pub(crate) fn select<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	cond: OracleId,
+	if_false: OracleId,
+	if_true: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([cond, if_false, if_true])?;
+	let output = builder.add_committed(name, log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<BinaryField1b>(cond)?.as_slice::<T>(),
+			witness.get::<BinaryField1b>(if_false)?.as_slice::<T>(),
+			witness.get::<BinaryField1b>(if_true)?.as_slice::<T>(),
+			witness
+				.new_column::<BinaryField1b>(output)
+				.as_mut_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(cond, if_false, if_true, output)| {
+				let (cond, if_false, if_true) =
+					(cond.into_u64(), if_false.into_u64(), if_true.into_u64());
+				*output = T::from_u64_truncated((if_false & !cond) | (if_true & cond));
+			});
+	}
+	builder.assert_zero(
+		"select",
+		[cond, if_false, if_true, output],
+		arith_expr!([c, x, y, z] = x + c * (y - x) - z).convert_field(),
+	);
+	Ok(output)
+}
+
+pub(crate) fn shl<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	if offset == 0 {
+		return Ok(input);
+	}
+
+	let shifted =
+		builder.add_shifted(name, input, offset, T::LOG_BITS, ShiftVariant::LogicalLeft)?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(shifted)
+				.as_mut_slice::<T>(),
+			witness.get::<BinaryField1b>(input)?.as_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(shifted, input)| {
+				*shifted = T::from_u64_truncated(input.into_u64() << offset)
+			});
+	}
+
+	Ok(shifted)
+}
+
+pub(crate) fn shr<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	if offset == 0 {
+		return Ok(input);
+	}
+
+	let shifted =
+		builder.add_shifted(name, input, offset, T::LOG_BITS, ShiftVariant::LogicalRight)?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(shifted)
+				.as_mut_slice::<T>(),
+			witness.get::<BinaryField1b>(input)?.as_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(shifted, input)| {
+				*shifted = T::from_u64_truncated(input.into_u64() >> offset)
+			});
+	}
+
+	Ok(shifted)
+}
+
+/// Reverses the order of the blocks of `2^log_block` bits of every integer.
+///
+/// The blocks are reversed in `T::LOG_BITS - log_block` levels, where level `j` swaps the halves
+/// of every block of `2^(j + 1)` bits. A level is the sum of two shifts of the previous level
+/// within those blocks, so the result is a virtual column without committed helpers.
+pub(crate) fn reverse_blocks<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	log_block: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	let mut output = input;
+	for level in log_block..T::LOG_BITS {
+		let half = 1 << level;
+		let high = builder.add_shifted(
+			format!("high{level}"),
+			output,
+			half,
+			level + 1,
+			ShiftVariant::LogicalLeft,
+		)?;
+		let low = builder.add_shifted(
+			format!("low{level}"),
+			output,
+			half,
+			level + 1,
+			ShiftVariant::LogicalRight,
+		)?;
+		let swapped = builder.add_linear_combination(
+			format!("swapped{level}"),
+			log_rows,
+			[(high, F::ONE), (low, F::ONE)],
+		)?;
+
+		if let Some(witness) = builder.witness() {
+			let low_half = (0..T::bits())
+				.step_by(2 * half)
+				.fold(0u64, |mask, i| mask | ((1 << half) - 1) << i);
+			let mut high = witness.new_column::<BinaryField1b>(high);
+			let mut low = witness.new_column::<BinaryField1b>(low);
+			let mut swapped = witness.new_column::<BinaryField1b>(swapped);
+			(
+				high.as_mut_slice::<T>(),
+				low.as_mut_slice::<T>(),
+				swapped.as_mut_slice::<T>(),
+				witness.get::<BinaryField1b>(output)?.as_slice::<T>(),
+			)
+				.into_par_iter()
+				.for_each(|(high, low, swapped, input)| {
+					let input = input.into_u64();
+					let high_value = (input & low_half) << half;
+					let low_value = (input >> half) & low_half;
+					*high = T::from_u64_truncated(high_value);
+					*low = T::from_u64_truncated(low_value);
+					*swapped = T::from_u64_truncated(high_value | low_value);
+				});
+		}
+		output = swapped;
+	}
+	builder.pop_namespace();
+	Ok(output)
+}
+
+pub(crate) fn select_bit<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	index: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([input])?;
+	anyhow::ensure!(
+		log_rows >= T::LOG_BITS,
+		"Polynomial must have n_vars >= {}. Got {log_rows}",
+		T::LOG_BITS
+	);
+	anyhow::ensure!(
+		index < T::bits(),
+		"Only index values below {} are allowed. Got {index}",
+		T::bits()
+	);
+
+	let query =
+		binius_core::polynomial::test_utils::decompose_index_to_hypercube_point(T::LOG_BITS, index);
+	let bits = builder.add_projected(name, input, query, ProjectionVariant::FirstVars)?;
+
+	if let Some(witness) = builder.witness() {
+		let mut bits = witness.new_column::<BinaryField1b>(bits);
+		let bits = bits.packed();
+		let input = witness.get::<BinaryField1b>(input)?.as_slice::<T>();
+		input
+			.iter()
+			.take(1 << (log_rows - T::LOG_BITS))
+			.enumerate()
+			.for_each(|(i, &val)| {
+				let value = match (val.into_u64() >> index) & 1 {
+					0 => BinaryField1b::ZERO,
+					_ => BinaryField1b::ONE,
+				};
+				set_packed_slice(bits, i, value);
+			});
+	}
+
+	Ok(bits)
+}
+
+/// Packs an integer column into a column of tower field elements of the same bit width.
+pub(crate) fn packed<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<T::Packed> + Pod,
+	F: TowerField + ExtensionField<T::Packed>,
+{
+	let packed = builder.add_packed(name, input, T::LOG_BITS)?;
+	if let Some(witness) = builder.witness() {
+		witness.set(packed, witness.get::<BinaryField1b>(input)?.repacked::<T::Packed>())?;
+	}
+	Ok(packed)
+}
+
+/// A column holding `value` in every integer, as in [`super::u32::constant`].
+pub(crate) fn constant<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_count: usize,
+	value: T,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<T::Packed> + Pod,
+	F: TowerField + ExtensionField<T::Packed>,
+{
+	builder.push_namespace(name);
+	let output =
+		builder.add_committed("output", log_count + T::LOG_BITS, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		witness
+			.new_column::<BinaryField1b>(output)
+			.as_mut_slice::<T>()
+			.fill(value);
+	}
+
+	let output_packed = packed::<T, _, _>(builder, "output_packed", output)?;
+	let value = bytemuck::cast::<T, T::Packed>(value);
+	let transparent = crate::transparent::constant(builder, "transparent", log_count, value)?;
+	builder.assert_zero(
+		"unpack",
+		[output_packed, transparent],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Converts a column of `T` integers into a column of `V` integers with the same values.
+///
+/// Widening always succeeds, narrowing is only satisfiable if every value fits into `V`.
+pub(crate) fn cast<T, V, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	V: UInt,
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<T::Packed>
+		+ PackScalar<V::Packed>
+		+ Pod,
+	F: TowerField + ExtensionField<T::Packed> + ExtensionField<V::Packed>,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	anyhow::ensure!(
+		log_rows >= T::LOG_BITS,
+		"Polynomial must have n_vars >= {}. Got {log_rows}",
+		T::LOG_BITS
+	);
+	let output = builder.add_committed(
+		"output",
+		log_rows - T::LOG_BITS + V::LOG_BITS,
+		BinaryField1b::TOWER_LEVEL,
+	);
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(output)
+				.as_mut_slice::<V>(),
+			witness.get::<BinaryField1b>(input)?.as_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(output, input)| *output = V::from_u64_truncated(input.into_u64()));
+	}
+
+	// The binary tower embeds narrower fields as the low bits of wider ones, so two packed
+	// integers are equal as field elements iff they are equal as integers.
+	let input_packed = packed::<T, _, _>(builder, "input_packed", input)?;
+	let output_packed = packed::<V, _, _>(builder, "output_packed", output)?;
+	builder.assert_zero(
+		"cast",
+		[input_packed, output_packed],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Splits a `u32` column into `N` columns of narrow integers, one per lane of the `u32` values.
+///
+/// Lane `k` holds the `k`-th least significant `T::BITS` bits of every `u32`.
+pub(crate) fn unpack_u32<T, U, F, const N: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<[OracleId; N], anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<T::Packed> + Pod,
+	F: TowerField + ExtensionField<T::Packed>,
+{
+	debug_assert_eq!(N << T::LOG_BITS, 32);
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	anyhow::ensure!(log_rows >= 5, "Polynomial must have n_vars >= 5. Got {log_rows}");
+
+	let lanes = std::array::from_fn(|k| {
+		builder.add_committed(
+			format!("lane{k}"),
+			log_rows - (5 - T::LOG_BITS),
+			BinaryField1b::TOWER_LEVEL,
+		)
+	});
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField1b>(input)?.as_slice::<T>();
+		for (k, &lane) in lanes.iter().enumerate() {
+			let mut lane = witness.new_column::<BinaryField1b>(lane);
+			lane.as_mut_slice::<T>()
+				.par_iter_mut()
+				.enumerate()
+				.for_each(|(j, value)| {
+					if let Some(&word_lane) = input.get(N * j + k) {
+						*value = word_lane;
+					}
+				});
+		}
+	}
+	constrain_lanes::<T, _, _, N>(builder, input, lanes)?;
+	builder.pop_namespace();
+	Ok(lanes)
+}
+
+/// Joins `N` columns of narrow integers into a `u32` column, the inverse of [`unpack_u32`].
+pub(crate) fn pack_u32<T, U, F, const N: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	lanes: [OracleId; N],
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<T::Packed> + Pod,
+	F: TowerField + ExtensionField<T::Packed>,
+{
+	debug_assert_eq!(N << T::LOG_BITS, 32);
+	builder.push_namespace(name);
+	let lane_log_rows = builder.log_rows(lanes)?;
+	let log_rows = lane_log_rows + (5 - T::LOG_BITS);
+
+	let output = builder.add_committed("output", log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		let mut output = witness.new_column::<BinaryField1b>(output);
+		let output = output.as_mut_slice::<T>();
+		for (k, &lane) in lanes.iter().enumerate() {
+			let lane = witness.get::<BinaryField1b>(lane)?.as_slice::<T>();
+			output
+				.par_iter_mut()
+				.skip(k)
+				.step_by(N)
+				.zip(lane)
+				.for_each(|(word_lane, &value)| *word_lane = value);
+		}
+	}
+	constrain_lanes::<T, _, _, N>(builder, output, lanes)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Decomposes a column of `T` integers into `N` columns of bytes, from least to most significant.
+///
+/// The bytes are committed as `BinaryField8b` columns with one element per integer, ready to be
+/// looked up in byte tables. A single linear constraint recombines them into the packed integers.
+pub(crate) fn bytes<T, U, F, const N: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<[OracleId; N], anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField8b>
+		+ PackScalar<T::Packed>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField8b> + ExtensionField<T::Packed>,
+{
+	debug_assert_eq!(N << 3, T::bits());
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	anyhow::ensure!(
+		log_rows >= T::LOG_BITS,
+		"Polynomial must have n_vars >= {}. Got {log_rows}",
+		T::LOG_BITS
+	);
+
+	let bytes = builder.add_committed_multiple::<N>(
+		"bytes",
+		log_rows - T::LOG_BITS,
+		BinaryField8b::TOWER_LEVEL,
+	);
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField1b>(input)?.as_slice::<T>();
+		for (k, &byte) in bytes.iter().enumerate() {
+			let mut byte = witness.new_column::<BinaryField8b>(byte);
+			byte.as_mut_slice::<u8>()
+				.par_iter_mut()
+				.zip(input)
+				.for_each(|(byte, value)| *byte = (value.into_u64() >> (8 * k)) as u8);
+		}
+	}
+
+	let input_packed = packed::<T, _, _>(builder, "input_packed", input)?;
+	let recombined = (0..N)
+		.map(|k| {
+			Ok(ArithExpr::Var(k + 1)
+				* ArithExpr::Const(<F as TowerField>::basis(BinaryField8b::TOWER_LEVEL, k)?))
+		})
+		.sum::<Result<ArithExpr<F>, binius_field::Error>>()?;
+	builder.assert_zero(
+		"bytes",
+		std::iter::once(input_packed).chain(bytes),
+		ArithExpr::Var(0) - recombined,
+	);
+	builder.pop_namespace();
+	Ok(bytes)
+}
+
+/// Constrains `lanes[k]` to hold lane `k` of the `u32` column `word`.
+///
+/// Both sides are packed into the tower field of width `T::BITS`, then lane `k` of the packed
+/// `u32` column is selected by projecting its lowest `5 - T::LOG_BITS` variables onto `k`.
+fn constrain_lanes<T, U, F, const N: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	word: OracleId,
+	lanes: [OracleId; N],
+) -> Result<(), anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<T::Packed> + Pod,
+	F: TowerField + ExtensionField<T::Packed>,
+{
+	let word_packed = packed::<T, _, _>(builder, "word_packed", word)?;
+	let log_rows = builder.log_rows([word_packed])?;
+	let lane_vars = 5 - T::LOG_BITS;
+
+	for (k, &lane) in lanes.iter().enumerate() {
+		let lane_packed = packed::<T, _, _>(builder, format!("lane{k}_packed"), lane)?;
+		let query =
+			binius_core::polynomial::test_utils::decompose_index_to_hypercube_point(lane_vars, k);
+		let word_lane = builder.add_projected(
+			format!("word_lane{k}"),
+			word_packed,
+			query,
+			ProjectionVariant::FirstVars,
+		)?;
+		if let Some(witness) = builder.witness() {
+			let word = witness.get::<T::Packed>(word_packed)?.as_slice::<T>();
+			let mut word_lane = witness.new_column::<T::Packed>(word_lane);
+			word_lane
+				.as_mut_slice::<T>()
+				.iter_mut()
+				.take(1 << (log_rows - lane_vars))
+				.enumerate()
+				.for_each(|(j, value)| *value = word[N * j + k]);
+		}
+		builder.assert_zero(
+			format!("lane{k}"),
+			[word_lane, lane_packed],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+	}
+	Ok(())
+}
+
+/// Constrains `zout = xin + yin` with a committed carry column, as in [`super::u32::add`], and
+/// returns the carry column, whose top bit of every integer is the carry out.
+fn constrain_sum<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	xin: OracleId,
+	yin: OracleId,
+	zout: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	T: UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows([xin, yin, zout])?;
+	let cout = builder.add_committed("cout", log_rows, BinaryField1b::TOWER_LEVEL);
+	let cin = builder.add_shifted("cin", cout, 1, T::LOG_BITS, ShiftVariant::LogicalLeft)?;
+
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<BinaryField1b>(xin)?.as_slice::<T>(),
+			witness.get::<BinaryField1b>(yin)?.as_slice::<T>(),
+			witness.get::<BinaryField1b>(zout)?.as_slice::<T>(),
+			witness
+				.new_column::<BinaryField1b>(cout)
+				.as_mut_slice::<T>(),
+			witness.new_column::<BinaryField1b>(cin).as_mut_slice::<T>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, yin, zout, cout, cin)| {
+				let (xin, yin, zout) = (xin.into_u64(), yin.into_u64(), zout.into_u64());
+				let carry = ((xin as u128 + yin as u128) >> T::bits()) as u64;
+				let cin_value = xin ^ yin ^ zout;
+				*cin = T::from_u64_truncated(cin_value);
+				*cout = T::from_u64_truncated((carry << (T::bits() - 1)) | (cin_value >> 1));
+			});
+	}
+
+	builder.assert_zero(
+		"sum",
+		[xin, yin, cin, zout],
+		arith_expr!([xin, yin, cin, zout] = xin + yin + cin - zout).convert_field(),
+	);
+
+	builder.assert_zero(
+		"carry",
+		[xin, yin, cin, cout],
+		arith_expr!([xin, yin, cin, cout] = (xin + cin) * (yin + cin) + cin - cout).convert_field(),
+	);
+
+	if matches!(flags, Flags::Checked) {
+		let last_cout = select_bit::<T, _, _>(builder, "last_cout", cout, T::bits() - 1)?;
+		builder.assert_zero(
+			"overflow",
+			[last_cout],
+			arith_expr!([last_cout] = last_cout).convert_field(),
+		);
+	}
+
+	Ok(cout)
+}
@@ -0,0 +1,303 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Finite automata over byte columns, checked with lookups.
+//!
+//! An [`Automaton`] is a table of transitions, each of which reads a byte, moves to the next
+//! state and emits an output byte. [`run_automaton`] commits the states and outputs of running an
+//! automaton over a byte column and looks up every step in the transition table. This is the
+//! building block of the gadgets that parse text, such as [`crate::utf8`] and [`crate::json`].
+
+use std::{
+	collections::{HashMap, VecDeque},
+	hash::Hash,
+};
+
+use anyhow::{ensure, Result};
+use binius_core::{
+	constraint_system::channel::Boundary,
+	oracle::{OracleId, ShiftVariant},
+};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField, PackedField, TowerField,
+};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+use itertools::izip;
+
+use crate::{builder::ConstraintSystemBuilder, plain_lookup::plain_lookup, transparent};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Transition {
+	pub state: u8,
+	pub byte: u8,
+	pub next: u8,
+	pub output: u8,
+}
+
+impl Transition {
+	/// The lookup value of the transition, with the state, byte, next state and output from the
+	/// most to the least significant byte.
+	fn packed(self) -> u32 {
+		u32::from_be_bytes([self.state, self.byte, self.next, self.output])
+	}
+}
+
+/// A deterministic finite automaton over bytes with an output byte per transition.
+///
+/// State 0 is the initial state. The automaton rejects an input once it reads a byte without a
+/// transition from the current state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Automaton {
+	/// Sorted by state and byte.
+	transitions: Vec<Transition>,
+}
+
+impl Automaton {
+	pub fn new(mut transitions: Vec<Transition>) -> Result<Self> {
+		ensure!(!transitions.is_empty(), "the automaton has no transitions");
+		transitions.sort();
+		transitions.dedup();
+		if let Some(pair) = transitions
+			.windows(2)
+			.find(|pair| (pair[0].state, pair[0].byte) == (pair[1].state, pair[1].byte))
+		{
+			anyhow::bail!(
+				"state {} has several transitions on byte {:#04x}",
+				pair[0].state,
+				pair[0].byte
+			);
+		}
+		Ok(Self { transitions })
+	}
+
+	/// Builds the automaton of the states reachable from `initial` with the transition function
+	/// `step`, which returns the next state and the output, or `None` to reject.
+	///
+	/// The states are numbered in the order they are reached, so the result only depends on
+	/// `step`, and there may be at most 256 of them.
+	pub fn from_fn<S: Clone + Eq + Hash>(
+		initial: S,
+		step: impl Fn(&S, u8) -> Option<(S, u8)>,
+	) -> Result<Self> {
+		let mut ids = HashMap::from([(initial.clone(), 0)]);
+		let mut queue = VecDeque::from([initial]);
+		let mut transitions = Vec::new();
+		while let Some(state) = queue.pop_front() {
+			let id = ids[&state];
+			for byte in 0..=u8::MAX {
+				let Some((next, output)) = step(&state, byte) else {
+					continue;
+				};
+				let next_id = match ids.get(&next) {
+					Some(&next_id) => next_id,
+					None => {
+						let next_id = u8::try_from(ids.len())
+							.map_err(|_| anyhow::anyhow!("the automaton has over 256 states"))?;
+						ids.insert(next.clone(), next_id);
+						queue.push_back(next);
+						next_id
+					}
+				};
+				transitions.push(Transition {
+					state: id,
+					byte,
+					next: next_id,
+					output,
+				});
+			}
+		}
+		Self::new(transitions)
+	}
+
+	pub fn transitions(&self) -> &[Transition] {
+		&self.transitions
+	}
+
+	pub fn step(&self, state: u8, byte: u8) -> Option<Transition> {
+		self.transitions
+			.binary_search_by_key(&(state, byte), |t| (t.state, t.byte))
+			.ok()
+			.map(|index| self.transitions[index])
+	}
+
+	/// Runs the automaton on `input` from the initial state, returning the transitions taken.
+	pub fn trace(&self, input: &[u8]) -> Result<Vec<Transition>> {
+		let mut state = 0;
+		input
+			.iter()
+			.enumerate()
+			.map(|(i, &byte)| {
+				let transition = self.step(state, byte).ok_or_else(|| {
+					anyhow::anyhow!("byte {byte:#04x} at row {i} is rejected in state {state}")
+				})?;
+				state = transition.next;
+				Ok(transition)
+			})
+			.collect()
+	}
+
+	/// Whether the automaton reads all of `input` and ends in the initial state.
+	pub fn accepts(&self, input: &[u8]) -> bool {
+		self.trace(input)
+			.is_ok_and(|trace| trace.last().map_or(0, |t| t.next) == 0)
+	}
+
+	fn has_outputs(&self) -> bool {
+		self.transitions.iter().any(|t| t.output != 0)
+	}
+}
+
+/// The columns committed by [`run_automaton`].
+#[derive(Debug, Clone)]
+pub struct AutomatonRun<F: TowerField> {
+	/// The state before every byte, which is a shift of `next_states` with the initial state in
+	/// the first row.
+	pub states: OracleId,
+	/// The state after every byte.
+	pub next_states: OracleId,
+	/// The output of every transition, or `None` if all outputs of the automaton are zero.
+	pub outputs: Option<OracleId>,
+	/// The boundary of the transition lookup, which must be supplied to the prover and the
+	/// verifier.
+	pub boundary: Boundary<F>,
+}
+
+/// Runs `automaton` on the first `count` bytes of `input`.
+///
+/// The prover commits the state after every byte and the output of every transition, and every
+/// `(state, byte, next state, output)` tuple is looked up with [`plain_lookup`] in a transparent
+/// table of the transitions of the automaton. The state before the first byte is shifted in as
+/// zero, the initial state. If `end_in_initial` is set, the state after the last byte is
+/// constrained to be the initial state too, which then serves as the accepting state. The rows
+/// past `count` are not constrained, and their states and outputs are zero.
+///
+/// Like [`plain_lookup`], every transition may occur less than `1 << LOG_MAX_MULTIPLICITY` times.
+pub fn run_automaton<U, F, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	count: usize,
+	automaton: &Automaton,
+	end_in_initial: bool,
+) -> Result<AutomatonRun<F>>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<B8> + PackScalar<B32> + Pod,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	let log_rows = builder.log_rows([input])?;
+	ensure!(
+		(1..=1 << log_rows).contains(&count),
+		"count must be between 1 and {}, got {count}",
+		1 << log_rows
+	);
+	let transitions = automaton.transitions();
+	ensure!(
+		((1 << LOG_MAX_MULTIPLICITY) - 1) * transitions.len() >= count,
+		"{count} bytes exceed the multiplicities of {} transitions",
+		transitions.len()
+	);
+
+	builder.push_namespace(name);
+	let next_states = builder.add_committed("next_states", log_rows, B8::TOWER_LEVEL);
+	let states =
+		builder.add_shifted("states", next_states, 1, log_rows, ShiftVariant::LogicalLeft)?;
+	let outputs = automaton
+		.has_outputs()
+		.then(|| builder.add_committed("outputs", log_rows, B8::TOWER_LEVEL));
+	let mut lookup_terms = vec![
+		(states, <F as TowerField>::basis(3, 3)?),
+		(input, <F as TowerField>::basis(3, 2)?),
+		(next_states, <F as TowerField>::basis(3, 1)?),
+	];
+	if let Some(outputs) = outputs {
+		lookup_terms.push((outputs, <F as TowerField>::basis(3, 0)?));
+	}
+	let lookup_values = builder.add_linear_combination("lookup_values", log_rows, lookup_terms)?;
+
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<B8>(input)?.as_slice::<u8>();
+		let trace = automaton.trace(&input[..count])?;
+		if end_in_initial {
+			ensure!(
+				trace.last().is_some_and(|t| t.next == 0),
+				"the input ends outside of the initial state"
+			);
+		}
+
+		let mut next_states_column = witness.new_column::<B8>(next_states);
+		let mut states_column = witness.new_column::<B8>(states);
+		let mut lookup_values_column = witness.new_column::<B32>(lookup_values);
+		let next_states = next_states_column.as_mut_slice::<u8>();
+		let states = states_column.as_mut_slice::<u8>();
+		let lookup_values = lookup_values_column.as_mut_slice::<u32>();
+
+		for (transition, state, next_state) in
+			izip!(&trace, states.iter_mut(), next_states.iter_mut())
+		{
+			*state = transition.state;
+			*next_state = transition.next;
+		}
+		if let Some(outputs) = outputs {
+			let mut outputs_column = witness.new_column::<B8>(outputs);
+			for (transition, output) in izip!(&trace, outputs_column.as_mut_slice::<u8>()) {
+				*output = transition.output;
+			}
+		}
+		// The states past the input are zero, except the state after the last byte
+		if count < states.len() {
+			states[count] = next_states[count - 1];
+		}
+		for (i, lookup_value) in lookup_values.iter_mut().enumerate() {
+			let transition = trace.get(i).copied().unwrap_or(Transition {
+				state: states[i],
+				byte: input[i],
+				next: 0,
+				output: 0,
+			});
+			*lookup_value = transition.packed();
+		}
+	}
+
+	if end_in_initial {
+		let last = transparent::interval(builder, "last", log_rows, count - 1, count)?;
+		builder.assert_zero(
+			"accepted",
+			[next_states, last],
+			arith_expr!(F[state, last] = state * last),
+		);
+	}
+
+	let mut values = transitions
+		.iter()
+		.map(|transition| B32::new(transition.packed()))
+		.collect::<Vec<_>>();
+	// The table must be a whole number of packed elements of a power of two length
+	values.resize(
+		values
+			.len()
+			.next_power_of_two()
+			.max(<PackedType<U, B32>>::WIDTH),
+		values[0],
+	);
+	let table = transparent::make_transparent(builder, "table", &values)?;
+	let boundary = plain_lookup::<U, F, B32, LOG_MAX_MULTIPLICITY>(
+		builder,
+		table,
+		transitions.len(),
+		values[0],
+		lookup_values,
+		count,
+	)?;
+
+	builder.pop_namespace();
+	Ok(AutomatonRun {
+		states,
+		next_states,
+		outputs,
+		boundary,
+	})
+}
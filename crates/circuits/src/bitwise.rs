@@ -5,10 +5,10 @@ use binius_field::{
 	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, TowerField,
 };
 use binius_macros::arith_expr;
+use binius_utils::rayon::prelude::*;
 use bytemuck::Pod;
-use rayon::prelude::*;
 
-use crate::builder::ConstraintSystemBuilder;
+use crate::builder::{ConstraintSystemBuilder, WitnessTask};
 
 pub fn and<U, F>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
@@ -23,19 +23,7 @@ where
 	builder.push_namespace(name);
 	let log_rows = builder.log_rows([xin, yin])?;
 	let zout = builder.add_committed("zout", log_rows, BinaryField1b::TOWER_LEVEL);
-	if let Some(witness) = builder.witness() {
-		(
-			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
-			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
-			witness
-				.new_column::<BinaryField1b>(zout)
-				.as_mut_slice::<u32>(),
-		)
-			.into_par_iter()
-			.for_each(|(xin, yin, zout)| {
-				*zout = (*xin) & (*yin);
-			});
-	}
+	schedule_word_op(builder, "bitwise_and", xin, yin, zout, |x, y| x & y)?;
 	builder.assert_zero(
 		"bitwise_and",
 		[xin, yin, zout],
@@ -58,19 +46,7 @@ where
 	builder.push_namespace(name);
 	let log_rows = builder.log_rows([xin, yin])?;
 	let zout = builder.add_linear_combination("zout", log_rows, [(xin, F::ONE), (yin, F::ONE)])?;
-	if let Some(witness) = builder.witness() {
-		(
-			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
-			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
-			witness
-				.new_column::<BinaryField1b>(zout)
-				.as_mut_slice::<u32>(),
-		)
-			.into_par_iter()
-			.for_each(|(xin, yin, zout)| {
-				*zout = (*xin) ^ (*yin);
-			});
-	}
+	schedule_word_op(builder, "bitwise_xor", xin, yin, zout, |x, y| x ^ y)?;
 	builder.pop_namespace();
 	Ok(zout)
 }
@@ -88,19 +64,7 @@ where
 	builder.push_namespace(name);
 	let log_rows = builder.log_rows([xin, yin])?;
 	let zout = builder.add_committed("zout", log_rows, BinaryField1b::TOWER_LEVEL);
-	if let Some(witness) = builder.witness() {
-		(
-			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
-			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
-			witness
-				.new_column::<BinaryField1b>(zout)
-				.as_mut_slice::<u32>(),
-		)
-			.into_par_iter()
-			.for_each(|(xin, yin, zout)| {
-				*zout = (*xin) | (*yin);
-			});
-	}
+	schedule_word_op(builder, "bitwise_or", xin, yin, zout, |x, y| x | y)?;
 	builder.assert_zero(
 		"bitwise_or",
 		[xin, yin, zout],
@@ -109,3 +73,32 @@ where
 	builder.pop_namespace();
 	Ok(zout)
 }
+
+/// Schedules filling `zout` with `op` applied to the 32-bit words of `xin` and `yin`.
+fn schedule_word_op<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: &str,
+	xin: OracleId,
+	yin: OracleId,
+	zout: OracleId,
+	op: fn(u32, u32) -> u32,
+) -> Result<(), anyhow::Error>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	if let Some(witness) = builder.witness() {
+		let task = WitnessTask::<U, F>::new(name, move |columns| {
+			let xin = columns.input::<BinaryField1b>(0)?.as_slice::<u32>();
+			let yin = columns.input::<BinaryField1b>(1)?.as_slice::<u32>();
+			(xin, yin, columns.output_slice::<u32>(0))
+				.into_par_iter()
+				.for_each(|(xin, yin, zout)| {
+					*zout = op(*xin, *yin);
+				});
+			Ok(())
+		});
+		witness.schedule(task.input(xin).input(yin).output::<BinaryField1b>(zout))?;
+	}
+	Ok(())
+}
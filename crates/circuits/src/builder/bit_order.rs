@@ -0,0 +1,73 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Bit orders of external bit-packed data.
+//!
+//! A witness column of a field with fewer than 8 bits packs several rows into every byte, with
+//! the first row in the least significant bits. Data from systems that pack the first element
+//! into the most significant bits, like most network formats and hash function specifications,
+//! must be reordered when it is imported, otherwise it silently becomes a different witness.
+
+use binius_field::TowerField;
+
+/// The position of the first element within every byte of bit-packed data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+	/// The first element is in the least significant bits, the order of witness columns.
+	#[default]
+	LsbFirst,
+	/// The first element is in the most significant bits.
+	MsbFirst,
+}
+
+impl BitOrder {
+	/// Converts bytes packed with elements of `FS` from this bit order to `to`, in place.
+	///
+	/// Elements of 8 bits or more are whole bytes, so their bytes are the same in both orders.
+	pub fn convert<FS: TowerField>(self, to: Self, bytes: &mut [u8]) {
+		if self == to || FS::N_BITS >= 8 {
+			return;
+		}
+		for byte in bytes {
+			*byte = reverse_elements(*byte, FS::N_BITS);
+		}
+	}
+}
+
+/// Reverses the order of the elements of `element_bits` bits within a byte.
+fn reverse_elements(byte: u8, element_bits: usize) -> u8 {
+	match element_bits {
+		1 => byte.reverse_bits(),
+		2 => {
+			let byte = byte.rotate_left(4);
+			(byte & 0x33) << 2 | (byte & 0xcc) >> 2
+		}
+		4 => byte.rotate_left(4),
+		_ => byte,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b};
+
+	use super::*;
+
+	#[test]
+	fn test_convert_reverses_elements_within_bytes() {
+		let mut bits = [0b1000_0001, 0b0000_0110];
+		BitOrder::MsbFirst.convert::<BinaryField1b>(BitOrder::LsbFirst, &mut bits);
+		assert_eq!(bits, [0b1000_0001, 0b0110_0000]);
+
+		let mut pairs = [0b11_10_01_00];
+		BitOrder::MsbFirst.convert::<BinaryField2b>(BitOrder::LsbFirst, &mut pairs);
+		assert_eq!(pairs, [0b00_01_10_11]);
+
+		let mut nibbles = [0x12];
+		BitOrder::LsbFirst.convert::<BinaryField4b>(BitOrder::MsbFirst, &mut nibbles);
+		assert_eq!(nibbles, [0x21]);
+
+		let mut bytes = [0x12];
+		BitOrder::LsbFirst.convert::<BinaryField8b>(BitOrder::MsbFirst, &mut bytes);
+		assert_eq!(bytes, [0x12]);
+	}
+}
@@ -1,26 +1,38 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use core::iter::IntoIterator;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::BTreeSet, ops::Range, rc::Rc};
 
-use anyhow::anyhow;
 use binius_core::{
 	constraint_system::{
-		channel::{ChannelId, Flush, FlushDirection},
-		ConstraintSystem,
+		channel::{
+			BalancingPolicy, ChannelId, ChannelInfo, ChannelRegistry, Flush, FlushDirection,
+		},
+		error::Error as ConstraintSystemError,
+		ConstraintSystem, MatMul,
 	},
 	oracle::{
-		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
-		ProjectionVariant, ShiftVariant,
+		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, MultilinearPolyOracle,
+		OracleId, ProjectionVariant, ShiftVariant,
 	},
 	polynomial::MultivariatePoly,
 	witness::MultilinearExtensionIndex,
 };
-use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, TowerField};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+	BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	PackedFieldIndexable, TowerField,
+};
 use binius_math::ArithExpr;
 use binius_utils::bail;
+use bytemuck::Pod;
 
-use crate::builder::witness;
+use crate::{
+	builder::{profile::ConstraintNamespaces, witness, Error},
+	lasso::{self, TableDescriptor},
+	transparent,
+};
 
 #[derive(Default)]
 pub struct ConstraintSystemBuilder<'arena, U, F>
@@ -32,9 +44,69 @@ where
 	constraints: ConstraintSetBuilder<F>,
 	non_zero_oracle_ids: Vec<OracleId>,
 	flushes: Vec<Flush>,
+	matmuls: Vec<MatMul>,
 	witness: Option<witness::Builder<'arena, U, F>>,
-	next_channel_id: ChannelId,
+	channels: ChannelRegistry,
 	namespace_path: Vec<String>,
+	gates: Vec<Gate>,
+	budgets: Vec<Budget>,
+	budget_policy: BudgetPolicy,
+	/// Columns constrained to bits by [`Self::assert_bit`] or created by
+	/// [`Self::add_committed_bit`].
+	bit_columns: BTreeSet<OracleId>,
+	/// The first oracle and the namespace of every constraint, in the order they were added.
+	constraint_namespaces: Vec<(Option<OracleId>, String)>,
+}
+
+/// What [`ConstraintSystemBuilder::exit_budget`] does when a gadget exceeds its budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BudgetPolicy {
+	/// Fail with [`Error::BudgetExceeded`].
+	#[default]
+	Error,
+	/// Log a warning and continue.
+	Warn,
+}
+
+/// The number of columns and constraints a gadget added, see
+/// [`ConstraintSystemBuilder::enter_budget`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BudgetUsage {
+	pub columns: usize,
+	pub constraints: usize,
+}
+
+/// A budget opened by [`ConstraintSystemBuilder::enter_budget`].
+struct Budget {
+	namespace: String,
+	max: BudgetUsage,
+	n_oracles: usize,
+	n_constraints: usize,
+}
+
+/// The state of a [`ConstraintSystemBuilder`], to which it can be rolled back.
+///
+/// Obtained from [`ConstraintSystemBuilder::snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+	n_oracles: usize,
+	n_constraints: usize,
+	n_non_zero_oracles: usize,
+	n_flushes: usize,
+	n_matmuls: usize,
+	n_channels: usize,
+	namespace_depth: usize,
+	gate_depth: usize,
+}
+
+/// A selector gating the constraints of a conditional branch.
+struct Gate {
+	selector: OracleId,
+	/// Whether the branch is taken in the rows where the selector is zero.
+	negated: bool,
+	n_vars: usize,
+	/// The first constraint of the branch that could not be gated.
+	error: Option<anyhow::Error>,
 }
 
 impl<'arena, U, F> ConstraintSystemBuilder<'arena, U, F>
@@ -57,7 +129,22 @@ where
 
 	#[allow(clippy::type_complexity)]
 	pub fn build(self) -> Result<ConstraintSystem<F>, anyhow::Error> {
+		for oracle in self.oracles.borrow().iter() {
+			if let MultilinearPolyOracle::Committed { tower_level, .. } = oracle {
+				if tower_level > F::TOWER_LEVEL {
+					bail!(ConstraintSystemError::CannotCommitTowerLevel {
+						id: oracle.id(),
+						tower_level,
+						max: F::TOWER_LEVEL,
+					});
+				}
+			}
+		}
 		let table_constraints = self.constraints.build(&self.oracles.borrow())?;
+		self.channels.validate_flushes(&self.flushes)?;
+		for matmul in &self.matmuls {
+			matmul.validate(&self.oracles.borrow())?;
+		}
 		Ok(ConstraintSystem {
 			max_channel_id: self
 				.flushes
@@ -68,14 +155,129 @@ where
 			table_constraints,
 			non_zero_oracle_ids: self.non_zero_oracle_ids,
 			oracles: Rc::into_inner(self.oracles)
-				.ok_or(anyhow!(
-					"Failed to build ConstraintSystem: references still exist to oracles"
-				))?
+				.ok_or(Error::OutstandingReferences("oracles"))?
 				.into_inner(),
 			flushes: self.flushes,
+			matmuls: self.matmuls,
+			channels: self.channels,
 		})
 	}
 
+	/// Sets what [`Self::exit_budget`] does when a budget is exceeded.
+	pub fn set_budget_policy(&mut self, policy: BudgetPolicy) {
+		self.budget_policy = policy;
+	}
+
+	/// Opens a budget of at most `max_columns` columns and `max_constraints` constraints for the
+	/// gadget built until the matching [`Self::exit_budget`].
+	///
+	/// Columns are the oracles added to the builder, committed or virtual, and constraints the zero
+	/// constraints. Budgets may be nested, in which case the columns and constraints of the inner
+	/// budget also count towards the outer one. Declaring the expected size of a gadget this way
+	/// catches a change of a shared gadget that blows up the size of the circuits using it.
+	pub fn enter_budget(&mut self, max_columns: usize, max_constraints: usize) {
+		self.budgets.push(Budget {
+			namespace: self.namespace_path.join("::"),
+			max: BudgetUsage {
+				columns: max_columns,
+				constraints: max_constraints,
+			},
+			n_oracles: self.oracles.borrow().size(),
+			n_constraints: self.constraints.len(),
+		});
+	}
+
+	/// Closes the innermost budget opened by [`Self::enter_budget`], returning the number of
+	/// columns and constraints added since.
+	///
+	/// Exceeding the budget is an error, or a logged warning under [`BudgetPolicy::Warn`].
+	pub fn exit_budget(&mut self) -> Result<BudgetUsage, anyhow::Error> {
+		let Some(budget) = self.budgets.pop() else {
+			bail!(Error::NoOpenBudget {
+				namespace: self.namespace_path.join("::"),
+			});
+		};
+		// A rollback may have removed more than was added under the budget.
+		let usage = BudgetUsage {
+			columns: self
+				.oracles
+				.borrow()
+				.size()
+				.saturating_sub(budget.n_oracles),
+			constraints: self.constraints.len().saturating_sub(budget.n_constraints),
+		};
+		if usage.columns > budget.max.columns || usage.constraints > budget.max.constraints {
+			let error = Error::BudgetExceeded {
+				namespace: budget.namespace,
+				columns: usage.columns,
+				max_columns: budget.max.columns,
+				constraints: usage.constraints,
+				max_constraints: budget.max.constraints,
+			};
+			match self.budget_policy {
+				BudgetPolicy::Error => bail!(error),
+				BudgetPolicy::Warn => tracing::warn!("{error}"),
+			}
+		}
+		Ok(usage)
+	}
+
+	/// Records the current state of the builder, see [`Self::rollback`].
+	pub fn snapshot(&self) -> Snapshot {
+		Snapshot {
+			n_oracles: self.oracles.borrow().size(),
+			n_constraints: self.constraints.len(),
+			n_non_zero_oracles: self.non_zero_oracle_ids.len(),
+			n_flushes: self.flushes.len(),
+			n_matmuls: self.matmuls.len(),
+			n_channels: self.channels.len(),
+			namespace_depth: self.namespace_path.len(),
+			gate_depth: self.gates.len(),
+		}
+	}
+
+	/// Undoes everything added to the builder since `snapshot` was taken: oracles, constraints,
+	/// non-zero checks, flushes, matrix multiplications, channels and witness columns, as well as
+	/// namespaces pushed and not yet popped.
+	///
+	/// This allows building a gadget speculatively, for example to compare the cost of two
+	/// implementations and keep the cheaper one. Oracle and channel ids handed out since the
+	/// snapshot become invalid and are reused by later additions. The snapshot must have been taken
+	/// on this builder inside the current branch of [`Self::if_else`], and not before the state it
+	/// describes was rolled back. Witness columns of the removed oracles must not be written
+	/// afterwards, which rules out keeping their [`witness::EntryBuilder`]s alive.
+	pub fn rollback(&mut self, snapshot: Snapshot) -> Result<(), anyhow::Error> {
+		let current = self.snapshot();
+		if snapshot.n_oracles > current.n_oracles
+			|| snapshot.n_constraints > current.n_constraints
+			|| snapshot.n_non_zero_oracles > current.n_non_zero_oracles
+			|| snapshot.n_flushes > current.n_flushes
+			|| snapshot.n_matmuls > current.n_matmuls
+			|| snapshot.n_channels > current.n_channels
+			|| snapshot.namespace_depth > current.namespace_depth
+			|| snapshot.gate_depth != current.gate_depth
+		{
+			bail!(Error::InvalidSnapshot {
+				namespace: self.namespace_path.join("::"),
+			});
+		}
+
+		self.oracles.borrow_mut().truncate(snapshot.n_oracles);
+		self.constraints.truncate(snapshot.n_constraints);
+		self.constraint_namespaces.truncate(snapshot.n_constraints);
+		self.non_zero_oracle_ids
+			.truncate(snapshot.n_non_zero_oracles);
+		self.flushes.truncate(snapshot.n_flushes);
+		self.matmuls.truncate(snapshot.n_matmuls);
+		self.channels.truncate(snapshot.n_channels);
+		self.namespace_path.truncate(snapshot.namespace_depth);
+		self.bit_columns.split_off(&snapshot.n_oracles);
+		if let Some(witness) = &self.witness {
+			witness.truncate(snapshot.n_oracles);
+		}
+		Ok(())
+	}
+
 	pub fn witness(&mut self) -> Option<&mut witness::Builder<'arena, U, F>> {
 		self.witness.as_mut()
 	}
@@ -84,9 +286,7 @@ where
 		&mut self,
 	) -> Result<MultilinearExtensionIndex<'arena, U, F>, anyhow::Error> {
 		Option::take(&mut self.witness)
-			.ok_or_else(|| {
-				anyhow!("Witness is missing. Are you in verifier mode, or have you already extraced the witness?")
-			})?
+			.ok_or(Error::NoWitness)?
 			.build()
 	}
 
@@ -147,20 +347,252 @@ where
 		oracle_ids: impl IntoIterator<Item = OracleId>,
 		composition: ArithExpr<F>,
 	) {
+		let mut oracle_ids = oracle_ids.into_iter().collect::<Vec<_>>();
+		let mut composition = composition;
+		if !self.gates.is_empty() {
+			let oracles = self.oracles.borrow();
+			let gate = self.gates.last_mut().expect("gates is not empty");
+			if let Some(&id) = oracle_ids
+				.iter()
+				.find(|&&id| oracles.n_vars(id) != gate.n_vars)
+			{
+				gate.error.get_or_insert_with(|| {
+					Error::UngatableConstraint {
+						namespace: self.namespace_path.join("::"),
+						constraint: name.to_string(),
+						id,
+						label: oracles.label(id),
+						selector: gate.selector,
+						selector_label: oracles.label(gate.selector),
+					}
+					.into()
+				});
+				return;
+			}
+			for gate in &self.gates {
+				let selector = ArithExpr::Var(oracle_ids.len());
+				oracle_ids.push(gate.selector);
+				composition *= if gate.negated {
+					selector + ArithExpr::one()
+				} else {
+					selector
+				};
+			}
+		}
+		self.constraint_namespaces
+			.push((oracle_ids.first().copied(), self.namespace_path.join("::")));
 		self.constraints
 			.add_zerocheck(name, oracle_ids, composition);
 	}
 
+	/// The namespaces the constraints added so far were added in, see [`ConstraintNamespaces`].
+	pub fn constraint_namespaces(&self) -> ConstraintNamespaces {
+		ConstraintNamespaces::new(self.constraint_namespaces.clone())
+	}
+
+	/// Constrains the rows `rows` of the columns `a` and `b` to be equal.
+	///
+	/// Columns with the same number of rows are compared with a zero constraint on their
+	/// difference, gated by a [`transparent::interval`] selector unless `rows` covers the whole
+	/// columns. Columns with different numbers of rows cannot share a zero constraint, so they are
+	/// compared with flushes instead: the first `rows.end` rows of `a` are pushed and those of `b`
+	/// pulled on a new closed channel, each next to its row index. This requires `rows` to start
+	/// at row 0, and cannot be done inside a branch of [`Self::if_else`].
+	pub fn assert_copy(
+		&mut self,
+		name: impl ToString,
+		a: OracleId,
+		b: OracleId,
+		rows: Range<usize>,
+	) -> Result<(), anyhow::Error>
+	where
+		U: Pod + PackScalar<BinaryField1b> + PackScalar<BinaryField32b>,
+		F: ExtensionField<BinaryField32b>,
+	{
+		let (a_log_rows, b_log_rows) = {
+			let oracles = self.oracles.borrow();
+			(oracles.n_vars(a), oracles.n_vars(b))
+		};
+		if rows.is_empty()
+			|| rows.end > 1 << a_log_rows.min(b_log_rows)
+			|| (a_log_rows != b_log_rows && rows.start != 0)
+		{
+			bail!(Error::InvalidCopyRows {
+				namespace: self.namespace_path.join("::"),
+				ids: vec![a, b],
+				rows,
+			});
+		}
+
+		let name = name.to_string();
+		self.push_namespace(&name);
+		let result = if a_log_rows == b_log_rows {
+			self.copy_by_zero_constraint(a, b, a_log_rows, rows)
+		} else {
+			self.copy_by_flushes(a, b, a_log_rows, b_log_rows, rows.end)
+		};
+		self.pop_namespace();
+		result
+	}
+
+	fn copy_by_zero_constraint(
+		&mut self,
+		a: OracleId,
+		b: OracleId,
+		log_rows: usize,
+		rows: Range<usize>,
+	) -> Result<(), anyhow::Error>
+	where
+		U: Pod + PackScalar<BinaryField1b>,
+	{
+		let difference = ArithExpr::Var(0) - ArithExpr::Var(1);
+		if rows == (0..1 << log_rows) {
+			self.assert_zero("copy", [a, b], difference);
+		} else {
+			let selector = transparent::interval(self, "rows", log_rows, rows.start, rows.end)?;
+			self.assert_zero("copy", [a, b, selector], difference * ArithExpr::Var(2));
+		}
+		Ok(())
+	}
+
+	fn copy_by_flushes(
+		&mut self,
+		a: OracleId,
+		b: OracleId,
+		a_log_rows: usize,
+		b_log_rows: usize,
+		count: usize,
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<BinaryField32b>,
+		F: ExtensionField<BinaryField32b>,
+	{
+		let a_index =
+			transparent::enumeration::<_, _, BinaryField32b>(self, "a_index", a_log_rows)?;
+		let b_index =
+			transparent::enumeration::<_, _, BinaryField32b>(self, "b_index", b_log_rows)?;
+		let channel = self.add_named_channel("copy", 2, BalancingPolicy::Closed);
+		self.send(channel, count, [a_index, a]);
+		self.receive(channel, count, [b_index, b]);
+		Ok(())
+	}
+
+	/// Adds the constraints of two conditional branches, gated by a selector column.
+	///
+	/// The constraints asserted by `then_branch` only need to hold in the rows where the selector
+	/// is one, and those asserted by `else_branch` in the rows where it is zero, which is done by
+	/// multiplying each constraint by the selector or its complement. The selector must be a column
+	/// of bits, like a committed `BinaryField1b` column, and every constraint in a branch must be
+	/// over columns with the same number of rows as the selector. Branches may be nested, with
+	/// every level of nesting raising the degree of the gated constraints by one.
+	///
+	/// Flushes, non-zero checks and matrix multiplications cannot be gated and are rejected inside
+	/// the branches. The outputs of the branches are returned as they are; columns computed by
+	/// both branches can be merged with [`crate::mux::choose`].
+	pub fn if_else<T, E>(
+		&mut self,
+		selector: OracleId,
+		then_branch: impl FnOnce(&mut Self) -> Result<T, anyhow::Error>,
+		else_branch: impl FnOnce(&mut Self) -> Result<E, anyhow::Error>,
+	) -> Result<(T, E), anyhow::Error> {
+		let then_output = self.gated(selector, false, then_branch)?;
+		let else_output = self.gated(selector, true, else_branch)?;
+		Ok((then_output, else_output))
+	}
+
+	fn gated<T>(
+		&mut self,
+		selector: OracleId,
+		negated: bool,
+		branch: impl FnOnce(&mut Self) -> Result<T, anyhow::Error>,
+	) -> Result<T, anyhow::Error> {
+		let n_vars = self.log_rows([selector])?;
+		let counts = (self.flushes.len(), self.non_zero_oracle_ids.len(), self.matmuls.len());
+		self.gates.push(Gate {
+			selector,
+			negated,
+			n_vars,
+			error: None,
+		});
+		let output = branch(self);
+		let gate = self.gates.pop().expect("gate was pushed above");
+		let output = output?;
+		if let Some(error) = gate.error {
+			return Err(error);
+		}
+		if counts != (self.flushes.len(), self.non_zero_oracle_ids.len(), self.matmuls.len()) {
+			bail!(Error::UngatableOperation {
+				namespace: self.namespace_path.join("::"),
+			});
+		}
+		Ok(output)
+	}
+
+	/// Constrains every row of `output` to be the entry of a table at the index in the same row of
+	/// `index`, and fills the witness of `output`.
+	///
+	/// The table is never materialized; it is decomposed into a subtable over chunks of the index.
+	/// See [`crate::lasso::indexed`] for the layout of the columns.
+	pub fn lookup_indexed<FI, FO>(
+		&mut self,
+		table: &TableDescriptor,
+		index: OracleId,
+		output: OracleId,
+	) -> Result<(), anyhow::Error>
+	where
+		U: Pod
+			+ PackScalar<BinaryField8b>
+			+ PackScalar<BinaryField16b>
+			+ PackScalar<BinaryField32b>
+			+ PackScalar<FI>
+			+ PackScalar<FO>,
+		PackedType<U, BinaryField32b>: PackedFieldIndexable,
+		F: ExtensionField<BinaryField8b>
+			+ ExtensionField<BinaryField16b>
+			+ ExtensionField<BinaryField32b>
+			+ ExtensionField<FI>
+			+ ExtensionField<FO>,
+		FI: TowerField,
+		FO: TowerField,
+	{
+		lasso::lookup_indexed::<_, _, FI, FO>(self, table, index, output)
+	}
+
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
 		self.non_zero_oracle_ids.push(oracle_id);
 	}
 
+	/// Constrains the matrix `matmul.c` to be the product of `matmul.a` and `matmul.b`.
+	///
+	/// The product is proven with a dedicated sumcheck over the inner dimension rather than with
+	/// per-entry table constraints.
+	pub fn assert_matmul(&mut self, matmul: MatMul) {
+		self.matmuls.push(matmul);
+	}
+
 	pub fn add_channel(&mut self) -> ChannelId {
-		let channel_id = self.next_channel_id;
-		self.next_channel_id += 1;
-		channel_id
+		self.channels.add(ChannelInfo::default())
+	}
+
+	/// Adds a channel with a name for error messages, the number of values in each flushed tuple,
+	/// and the expected balancing policy.
+	pub fn add_named_channel(
+		&mut self,
+		name: impl ToString,
+		arity: usize,
+		policy: BalancingPolicy,
+	) -> ChannelId {
+		self.channels.add(ChannelInfo {
+			name: Some(self.scoped_name(name)),
+			arity: Some(arity),
+			policy,
+		})
 	}
 
+	/// Adds a committed column with values in the binary tower field of the given level.
+	///
+	/// The tower level must not exceed `F::TOWER_LEVEL`, otherwise [`Self::build`] fails. Values
+	/// wider than 128 bits are committed as several columns.
 	pub fn add_committed(
 		&mut self,
 		name: impl ToString,
@@ -173,6 +605,56 @@ where
 			.committed(n_vars, tower_level)
 	}
 
+	/// Adds a committed column of bits.
+	///
+	/// The column is committed at tower level 0, where every value is a bit, and tagged as a bit
+	/// column, see [`Self::is_bit`].
+	pub fn add_committed_bit(&mut self, name: impl ToString, n_vars: usize) -> OracleId {
+		let id = self.add_committed(name, n_vars, BinaryField1b::TOWER_LEVEL);
+		self.bit_columns.insert(id);
+		id
+	}
+
+	/// Adds a committed column of bits stored at a wider tower level, for example to pack it with
+	/// other columns of that level, and constrains its values to be bits, see [`Self::assert_bit`].
+	pub fn add_committed_bit_at_level(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		tower_level: usize,
+	) -> OracleId {
+		let name = name.to_string();
+		let id = self.add_committed(&name, n_vars, tower_level);
+		self.assert_bit(format!("{name}_is_bit"), id);
+		id
+	}
+
+	/// Constrains every value of a column to be 0 or 1, and tags the column as a bit column.
+	///
+	/// The constraint `x * (x - 1) = 0` is only added if the column is not already known to hold
+	/// bits, that is if it has values above tower level 0 and was not constrained before. Inside a
+	/// branch of [`Self::if_else`] the constraint only holds in the rows of the branch, so the
+	/// column is not tagged.
+	pub fn assert_bit(&mut self, name: impl ToString, id: OracleId) {
+		if self.is_bit(id) {
+			return;
+		}
+		self.assert_zero(name, [id], ArithExpr::Var(0).pow(2) + ArithExpr::Var(0));
+		if self.gates.is_empty() {
+			self.bit_columns.insert(id);
+		}
+	}
+
+	/// Whether the values of a column are known to be bits, because they are in tower level 0 or
+	/// the column was constrained by [`Self::assert_bit`].
+	///
+	/// Gadgets that rely on an input being a bit column can check this instead of adding a
+	/// redundant constraint.
+	pub fn is_bit(&self, id: OracleId) -> bool {
+		self.bit_columns.contains(&id)
+			|| self.oracles.borrow().oracle(id).binary_tower_level() == BinaryField1b::TOWER_LEVEL
+	}
+
 	pub fn add_committed_multiple<const N: usize>(
 		&mut self,
 		name: impl ToString,
@@ -222,6 +704,23 @@ where
 			.packed(id, log_degree)
 	}
 
+	/// Adds a committed column of univariate polynomials in Lagrange basis, returning the
+	/// committed oracle holding its values and the univariate oracle reading it.
+	///
+	/// See [`binius_core::oracle::Univariate`] for how the values are read.
+	pub fn add_committed_univariate(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		log_domain_size: usize,
+		tower_level: usize,
+	) -> Result<(OracleId, OracleId), OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.committed_univariate(n_vars, log_domain_size, tower_level)
+	}
+
 	pub fn add_projected(
 		&mut self,
 		name: impl ToString,
@@ -261,6 +760,20 @@ where
 			.shifted(id, offset, block_bits, variant)
 	}
 
+	/// Adds a cyclic right rotation by `offset` of every block of `2^block_bits` values.
+	///
+	/// With bits indexed least significant first, this is the `ROTR` operation of SHA-2 style
+	/// hash functions. Left rotations are the right rotations by `2^block_bits - offset`.
+	pub fn add_rotated(
+		&mut self,
+		name: impl ToString,
+		id: OracleId,
+		offset: usize,
+		block_bits: usize,
+	) -> Result<OracleId, OracleError> {
+		self.add_shifted(name, id, offset, block_bits, ShiftVariant::CircularRight)
+	}
+
 	pub fn add_transparent(
 		&mut self,
 		name: impl ToString,
@@ -272,6 +785,20 @@ where
 			.transparent(poly)
 	}
 
+	/// Adds the concatenation of `inner`, each with `n_vars` variables, selected by the high
+	/// variables. Trailing segments are zero when the count is not a power of two.
+	pub fn add_concatenated(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.concatenated(n_vars, inner)
+	}
+
 	pub fn add_zero_padded(
 		&mut self,
 		name: impl ToString,
@@ -338,11 +865,17 @@ where
 		let mut oracle_ids = oracle_ids.into_iter();
 		let oracles = self.oracles.borrow();
 		let Some(first_id) = oracle_ids.next() else {
-			bail!(anyhow!("log_rows: You need to specify at least one column"));
+			bail!(Error::NoColumns {
+				namespace: self.namespace_path.join("::"),
+			});
 		};
 		let log_rows = oracles.n_vars(first_id);
-		if oracle_ids.any(|id| oracles.n_vars(id) != log_rows) {
-			bail!(anyhow!("log_rows: All columns must have the same number of rows"))
+		let oracle_ids = oracle_ids.collect::<Vec<_>>();
+		if oracle_ids.iter().any(|&id| oracles.n_vars(id) != log_rows) {
+			bail!(Error::RowsMismatch {
+				namespace: self.namespace_path.join("::"),
+				ids: std::iter::once(first_id).chain(oracle_ids).collect(),
+			})
 		}
 		Ok(log_rows)
 	}
@@ -0,0 +1,152 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+
+/// Errors raised by the [`super::ConstraintSystemBuilder`] and the witness [`super::witness::Builder`].
+///
+/// The builders return [`anyhow::Error`] so that gadgets can mix in their own errors, and these
+/// variants can be recovered with [`anyhow::Error::downcast_ref`]. Every variant carries the IDs of
+/// the oracles it refers to next to their labels, and the namespace it was raised in where one is
+/// known, so that callers can react to a failure without parsing the message.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("log_rows: You need to specify at least one column (namespace {namespace:?})")]
+	NoColumns { namespace: String },
+
+	#[error("log_rows: All columns must have the same number of rows (namespace {namespace:?}, columns {ids:?})")]
+	RowsMismatch {
+		namespace: String,
+		ids: Vec<OracleId>,
+	},
+
+	#[error("OracleId {id} does not exist in MultilinearOracleSet")]
+	InvalidOracleId { id: OracleId },
+
+	#[error("Witness for {label} is missing")]
+	MissingWitness { id: OracleId, label: String },
+
+	#[error(
+		"Provided tower level ({got}) for {label} does not match stored tower level {expected}."
+	)]
+	TowerLevelMismatch {
+		id: OracleId,
+		label: String,
+		expected: usize,
+		got: usize,
+	},
+
+	#[error("Witness for {label} is lazy and computed only by the prover")]
+	LazyColumn { id: OracleId, label: String },
+
+	#[error("Witness for {label} has already been written")]
+	ColumnWrittenTwice { id: OracleId, label: String },
+
+	#[error("Witness columns written more than once: {}", labels.join(", "))]
+	ColumnsWrittenTwice {
+		ids: Vec<OracleId>,
+		labels: Vec<String>,
+	},
+
+	#[error("Committed columns without witness data: {}", labels.join(", "))]
+	MissingColumns {
+		ids: Vec<OracleId>,
+		labels: Vec<String>,
+	},
+
+	#[error("Witness tasks {} depend on each other's outputs", tasks.join(", "))]
+	WitnessTaskCycle {
+		ids: Vec<OracleId>,
+		tasks: Vec<String>,
+	},
+
+	#[error("Witness for oracle {id} needs {expected} bytes, got {got}")]
+	ByteLengthMismatch {
+		id: OracleId,
+		expected: usize,
+		got: usize,
+	},
+
+	#[error(
+		"Witness is missing. Are you in verifier mode, or have you already extraced the witness?"
+	)]
+	NoWitness,
+
+	#[error("constraint {constraint} over {label} cannot be gated by the selector {selector_label}, which has a different number of rows (namespace {namespace:?})")]
+	UngatableConstraint {
+		namespace: String,
+		constraint: String,
+		id: OracleId,
+		label: String,
+		selector: OracleId,
+		selector_label: String,
+	},
+
+	#[error("rows {rows:?} cannot be copied between columns {ids:?}, they must be a non-empty range of rows of both columns starting at row 0 if the columns differ in size (namespace {namespace:?})")]
+	InvalidCopyRows {
+		namespace: String,
+		ids: Vec<OracleId>,
+		rows: std::ops::Range<usize>,
+	},
+
+	#[error("flushes, non-zero checks and matrix multiplications cannot be gated by a selector (namespace {namespace:?})")]
+	UngatableOperation { namespace: String },
+
+	#[error("the builder cannot be rolled back to a snapshot of a later state or of another branch (namespace {namespace:?})")]
+	InvalidSnapshot { namespace: String },
+
+	#[error("gadget added {columns} columns and {constraints} constraints, exceeding its budget of {max_columns} columns and {max_constraints} constraints (namespace {namespace:?})")]
+	BudgetExceeded {
+		namespace: String,
+		columns: usize,
+		max_columns: usize,
+		constraints: usize,
+		max_constraints: usize,
+	},
+
+	#[error("exit_budget called without an open budget (namespace {namespace:?})")]
+	NoOpenBudget { namespace: String },
+
+	#[error("Failed to build: references still exist to {0}")]
+	OutstandingReferences(&'static str),
+
+	#[error(
+		"the constraint namespaces were not recorded by the builder of this constraint system"
+	)]
+	ConstraintNamespacesMismatch,
+}
+
+impl Error {
+	/// The oracles the error refers to.
+	pub fn oracle_ids(&self) -> Vec<OracleId> {
+		match self {
+			Self::InvalidOracleId { id }
+			| Self::MissingWitness { id, .. }
+			| Self::TowerLevelMismatch { id, .. }
+			| Self::LazyColumn { id, .. }
+			| Self::ColumnWrittenTwice { id, .. }
+			| Self::ByteLengthMismatch { id, .. } => vec![*id],
+			Self::UngatableConstraint { id, selector, .. } => vec![*id, *selector],
+			Self::RowsMismatch { ids, .. }
+			| Self::ColumnsWrittenTwice { ids, .. }
+			| Self::MissingColumns { ids, .. }
+			| Self::InvalidCopyRows { ids, .. }
+			| Self::WitnessTaskCycle { ids, .. } => ids.clone(),
+			_ => Vec::new(),
+		}
+	}
+
+	/// The namespace of the builder when the error was raised, if known.
+	pub fn namespace(&self) -> Option<&str> {
+		match self {
+			Self::NoColumns { namespace }
+			| Self::RowsMismatch { namespace, .. }
+			| Self::UngatableConstraint { namespace, .. }
+			| Self::InvalidCopyRows { namespace, .. }
+			| Self::UngatableOperation { namespace }
+			| Self::InvalidSnapshot { namespace }
+			| Self::BudgetExceeded { namespace, .. }
+			| Self::NoOpenBudget { namespace } => Some(namespace),
+			_ => None,
+		}
+	}
+}
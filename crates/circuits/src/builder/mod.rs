@@ -1,6 +1,14 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+pub mod bit_order;
 pub mod constraint_system;
+pub mod error;
+pub mod profile;
+pub mod schedule;
 pub mod witness;
 
-pub use constraint_system::ConstraintSystemBuilder;
+pub use bit_order::BitOrder;
+pub use constraint_system::{BudgetPolicy, BudgetUsage, ConstraintSystemBuilder, Snapshot};
+pub use error::Error;
+pub use profile::{ConstraintNamespaces, NamespaceProfile, NamespaceTime};
+pub use schedule::{TaskColumns, WitnessTask};
@@ -0,0 +1,182 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Attribution of prover time to the namespaces of a circuit.
+//!
+//! A [`ProverProfile`] records the time the zerocheck spends on each table constraint set, but the
+//! constraint sets group the constraints of all gadgets that share columns. The builder records
+//! the namespace every constraint was added in, and [`ConstraintNamespaces::attribute`] spreads
+//! the time of each constraint set over its constraints in proportion to the size of their
+//! compositions, then sums it up by namespace.
+//!
+//! Evalcheck time cannot be measured per oracle, so it is estimated instead: the time of the
+//! evalcheck phase is spread over the virtual oracles of the constraint system in proportion to
+//! their number of rows, and each oracle counts towards the namespace of its label.
+
+use std::{
+	collections::BTreeMap,
+	fmt::{self, Display},
+	time::Duration,
+};
+
+use binius_core::{
+	constraint_system::{phase, profile::ProverProfile, ConstraintSystem},
+	oracle::{MultilinearPolyOracle, OracleId},
+};
+use binius_field::TowerField;
+use itertools::izip;
+
+use super::Error;
+
+/// The namespaces the constraints of a [`super::ConstraintSystemBuilder`] were added in.
+///
+/// Obtained from [`super::ConstraintSystemBuilder::constraint_namespaces`] before building the
+/// constraint system.
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintNamespaces {
+	constraints: Vec<(Option<OracleId>, String)>,
+}
+
+/// The prover time attributed to a namespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamespaceTime {
+	pub namespace: String,
+	pub time: Duration,
+}
+
+/// The prover time of a proof by namespace, see [`ConstraintNamespaces::attribute`].
+///
+/// The namespaces are sorted by decreasing time. Time of a phase that is not attributed to any
+/// namespace, like sampling challenges or building the provers, is part of the phase total only.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceProfile {
+	pub zerocheck: Vec<NamespaceTime>,
+	pub zerocheck_total: Duration,
+	pub evalcheck: Vec<NamespaceTime>,
+	pub evalcheck_total: Duration,
+}
+
+impl ConstraintNamespaces {
+	pub(super) fn new(constraints: Vec<(Option<OracleId>, String)>) -> Self {
+		Self { constraints }
+	}
+
+	/// Attributes the prover time of `profile`, recorded for a proof of `constraint_system`, to
+	/// the namespaces of the circuit.
+	///
+	/// Fails if `constraint_system` was not built by the builder these namespaces were taken from.
+	pub fn attribute<F: TowerField>(
+		&self,
+		constraint_system: &ConstraintSystem<F>,
+		profile: &ProverProfile,
+	) -> Result<NamespaceProfile, anyhow::Error> {
+		let constraint_sets = &constraint_system.table_constraints;
+		if profile.constraint_sets.len() != constraint_sets.len() {
+			return Err(Error::ConstraintNamespacesMismatch.into());
+		}
+
+		// The constraint set builder keeps constraints in the order they were added within each
+		// set, and every oracle belongs to at most one set.
+		let mut namespaces = constraint_sets
+			.iter()
+			.map(|constraint_set| Vec::with_capacity(constraint_set.constraints.len()))
+			.collect::<Vec<_>>();
+		for (first_oracle, namespace) in &self.constraints {
+			let set_index = first_oracle
+				.and_then(|id| {
+					constraint_sets
+						.iter()
+						.position(|constraint_set| constraint_set.oracle_ids.contains(&id))
+				})
+				.ok_or(Error::ConstraintNamespacesMismatch)?;
+			namespaces[set_index].push(namespace.as_str());
+		}
+
+		let mut zerocheck = BTreeMap::<&str, Duration>::new();
+		for (constraint_set, namespaces, &time) in
+			izip!(constraint_sets, &namespaces, &profile.constraint_sets)
+		{
+			if namespaces.len() != constraint_set.constraints.len() {
+				return Err(Error::ConstraintNamespacesMismatch.into());
+			}
+			let sizes = constraint_set
+				.constraints
+				.iter()
+				.map(|constraint| constraint.composition.size())
+				.collect::<Vec<_>>();
+			let total_size = sizes.iter().sum::<usize>().max(1);
+			for (&namespace, size) in namespaces.iter().zip(sizes) {
+				*zerocheck.entry(namespace).or_default() +=
+					time.mul_f64(size as f64 / total_size as f64);
+			}
+		}
+
+		let oracles = &constraint_system.oracles;
+		let virtual_rows = |oracle: &MultilinearPolyOracle<F>| match oracle {
+			MultilinearPolyOracle::Committed { .. }
+			| MultilinearPolyOracle::Transparent { .. }
+			| MultilinearPolyOracle::Univariate { .. } => 0,
+			_ => 1usize << oracle.n_vars(),
+		};
+		let total_rows = oracles
+			.iter()
+			.map(|oracle| virtual_rows(&oracle))
+			.sum::<usize>();
+		let evalcheck_total = profile.phase(phase::EVALCHECK);
+		let mut evalcheck = BTreeMap::<String, Duration>::new();
+		for oracle in oracles.iter() {
+			let rows = virtual_rows(&oracle);
+			if rows > 0 {
+				let label = oracle.label();
+				let namespace = label
+					.rsplit_once("::")
+					.map_or("", |(namespace, _)| namespace);
+				*evalcheck.entry(namespace.to_string()).or_default() +=
+					evalcheck_total.mul_f64(rows as f64 / total_rows as f64);
+			}
+		}
+
+		Ok(NamespaceProfile {
+			zerocheck: sorted_by_time(
+				zerocheck
+					.into_iter()
+					.map(|(namespace, time)| (namespace.to_string(), time)),
+			),
+			zerocheck_total: profile.phase(phase::ZEROCHECK),
+			evalcheck: sorted_by_time(evalcheck),
+			evalcheck_total,
+		})
+	}
+}
+
+fn sorted_by_time(times: impl IntoIterator<Item = (String, Duration)>) -> Vec<NamespaceTime> {
+	let mut times = times
+		.into_iter()
+		.map(|(namespace, time)| NamespaceTime { namespace, time })
+		.collect::<Vec<_>>();
+	times.sort_by(|a, b| b.time.cmp(&a.time));
+	times
+}
+
+impl Display for NamespaceProfile {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (phase, times, total) in [
+			(phase::ZEROCHECK, &self.zerocheck, self.zerocheck_total),
+			(phase::EVALCHECK, &self.evalcheck, self.evalcheck_total),
+		] {
+			for NamespaceTime { namespace, time } in times {
+				let share = if total.is_zero() {
+					0.0
+				} else {
+					100.0 * time.as_secs_f64() / total.as_secs_f64()
+				};
+				let namespace = if namespace.is_empty() {
+					"<root>"
+				} else {
+					namespace
+				};
+				writeln!(f, "{namespace}: {share:.1}% of {phase} time ({time:.2?})")?;
+			}
+		}
+		Ok(())
+	}
+}
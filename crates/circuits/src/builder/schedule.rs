@@ -0,0 +1,167 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Dependency-aware scheduling of witness generation.
+//!
+//! Gadgets usually fill their witness columns as soon as they add them, so witness generation
+//! follows the order in which the circuit is built. A gadget can instead hand a [`WitnessTask`] to
+//! [`super::witness::Builder::schedule`], declaring the columns its fill closure reads and the
+//! columns it writes. Scheduled tasks run in waves: every wave runs all pending tasks whose inputs
+//! are available in parallel, and makes their outputs available to the next wave. Pending tasks
+//! run when one of their outputs is read with [`super::witness::Builder::get`], on
+//! [`super::witness::Builder::run_scheduled`], and when the witness is built.
+
+use anyhow::Error;
+use binius_core::{oracle::OracleId, witness::MultilinearWitness};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::{UnderlierType, WithUnderlier},
+	ExtensionField, Field, PackedField, TowerField,
+};
+use binius_math::MultilinearExtension;
+use binius_utils::bail;
+use bytemuck::{must_cast_slice_mut, Pod};
+
+use super::{error::Error as BuilderError, witness::WitnessEntry};
+
+type FillFn<'arena, U> =
+	Box<dyn FnOnce(&mut TaskColumns<'_, 'arena, U>) -> Result<(), Error> + Send + 'arena>;
+
+type WitnessFn<U, FW> =
+	for<'a> fn(
+		usize,
+		&'a [U],
+	) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, binius_math::Error>;
+
+/// A unit of witness generation that reads some columns and writes others.
+pub struct WitnessTask<'arena, U: PackScalar<FW>, FW: Field> {
+	pub(super) name: String,
+	pub(super) inputs: Vec<OracleId>,
+	pub(super) outputs: Vec<TaskOutput<U, FW>>,
+	pub(super) fill: FillFn<'arena, U>,
+}
+
+pub(super) struct TaskOutput<U: PackScalar<FW>, FW: Field> {
+	pub(super) id: OracleId,
+	pub(super) tower_level: usize,
+	/// The base-2 logarithm of the number of rows that fit in one underlier.
+	pub(super) log_width: usize,
+	pub(super) witness: WitnessFn<U, FW>,
+}
+
+impl<'arena, U, FW> WitnessTask<'arena, U, FW>
+where
+	U: PackScalar<FW>,
+	FW: TowerField,
+{
+	/// Creates a task running `fill`, which reads the inputs and writes the outputs of the task
+	/// through [`TaskColumns`], in the order they are declared.
+	pub fn new(
+		name: impl ToString,
+		fill: impl FnOnce(&mut TaskColumns<'_, 'arena, U>) -> Result<(), Error> + Send + 'arena,
+	) -> Self {
+		Self {
+			name: name.to_string(),
+			inputs: Vec::new(),
+			outputs: Vec::new(),
+			fill: Box::new(fill),
+		}
+	}
+
+	/// Declares a column the task reads.
+	pub fn input(mut self, id: OracleId) -> Self {
+		self.inputs.push(id);
+		self
+	}
+
+	/// Declares a column with values in `FS` the task writes.
+	pub fn output<FS: TowerField>(mut self, id: OracleId) -> Self
+	where
+		U: PackScalar<FS>,
+		FW: ExtensionField<FS>,
+	{
+		self.outputs.push(TaskOutput {
+			id,
+			tower_level: FS::TOWER_LEVEL,
+			log_width: <PackedType<U, FS>>::LOG_WIDTH,
+			witness: output_witness::<U, FW, FS>,
+		});
+		self
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+}
+
+fn output_witness<U, FW, FS>(
+	log_rows: usize,
+	data: &[U],
+) -> Result<MultilinearWitness<'_, PackedType<U, FW>>, binius_math::Error>
+where
+	U: PackScalar<FW> + PackScalar<FS>,
+	FW: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	MultilinearExtension::new(log_rows, PackedType::<U, FS>::from_underliers_ref(data))
+		.map(|x| x.specialize_arc_dyn())
+}
+
+/// A column read or written by a task while it runs.
+pub(super) struct TaskColumn<D> {
+	pub(super) id: OracleId,
+	pub(super) label: String,
+	pub(super) tower_level: usize,
+	pub(super) log_rows: usize,
+	pub(super) data: D,
+}
+
+/// The columns a running [`WitnessTask`] reads and writes, indexed in declaration order.
+pub struct TaskColumns<'a, 'arena, U> {
+	pub(super) inputs: &'a [TaskColumn<&'arena [U]>],
+	pub(super) outputs: &'a mut [TaskColumn<&'arena mut [U]>],
+}
+
+impl<'arena, U: UnderlierType> TaskColumns<'_, 'arena, U> {
+	/// The input declared at position `index`.
+	pub fn input<FS: TowerField>(&self, index: usize) -> Result<WitnessEntry<'arena, U, FS>, Error>
+	where
+		U: PackScalar<FS>,
+	{
+		let column = &self.inputs[index];
+		check_tower_level::<FS, _>(column)?;
+		Ok(WitnessEntry::new(column.data, column.log_rows))
+	}
+
+	/// The output declared at position `index`, to be filled by the task.
+	pub fn output<FS: TowerField>(
+		&mut self,
+		index: usize,
+	) -> Result<&mut [PackedType<U, FS>], Error>
+	where
+		U: PackScalar<FS>,
+	{
+		let column = &mut self.outputs[index];
+		check_tower_level::<FS, _>(column)?;
+		Ok(PackedType::<U, FS>::from_underliers_ref_mut(&mut *column.data))
+	}
+
+	/// The output declared at position `index`, viewed as a slice of `T`.
+	pub fn output_slice<T: Pod>(&mut self, index: usize) -> &mut [T]
+	where
+		U: Pod,
+	{
+		must_cast_slice_mut(&mut *self.outputs[index].data)
+	}
+}
+
+fn check_tower_level<FS: TowerField, D>(column: &TaskColumn<D>) -> Result<(), Error> {
+	if column.tower_level != FS::TOWER_LEVEL {
+		bail!(BuilderError::TowerLevelMismatch {
+			id: column.id,
+			label: column.label.clone(),
+			expected: column.tower_level,
+			got: FS::TOWER_LEVEL,
+		});
+	}
+	Ok(())
+}
@@ -1,21 +1,29 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{
+	borrow::Cow, cell::RefCell, collections::HashSet, marker::PhantomData, rc::Rc, sync::Arc,
+};
 
-use anyhow::{anyhow, Error};
+use anyhow::Error;
 use binius_core::{
-	oracle::{MultilinearOracleSet, OracleId},
-	witness::{MultilinearExtensionIndex, MultilinearWitness},
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle, OracleId},
+	witness::{LazyMultilinearWitness, MultilinearExtensionIndex, MultilinearWitness},
 };
 use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
+	as_packed_field::{underliers_as_scalars, underliers_as_scalars_mut, PackScalar, PackedType},
 	underlier::WithUnderlier,
-	ExtensionField, Field, PackedField, TowerField,
+	ExtensionField, Field, PackedField, PackedFieldIndexable, TowerField,
 };
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
 
+use super::{
+	error::Error as BuilderError,
+	schedule::{TaskColumn, TaskColumns, WitnessTask},
+	BitOrder,
+};
+
 pub struct Builder<'arena, U: PackScalar<FW>, FW: TowerField> {
 	bump: &'arena bumpalo::Bump,
 
@@ -23,12 +31,32 @@ pub struct Builder<'arena, U: PackScalar<FW>, FW: TowerField> {
 
 	#[allow(clippy::type_complexity)]
 	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena, U, FW>>>>>,
+
+	/// In strict mode every column may be written at most once, and building fails if any
+	/// committed column has no witness data.
+	strict: bool,
+
+	/// Oracle ids that have already been handed out via `new_column` or populated via `set`.
+	claimed: RefCell<Vec<bool>>,
+
+	/// Oracle ids that were written more than once while in strict mode.
+	double_writes: RefCell<Vec<OracleId>>,
+
+	/// Tasks handed to [`Self::schedule`] that have not run yet.
+	scheduled: RefCell<Vec<WitnessTask<'arena, U, FW>>>,
 }
 
 struct WitnessBuilderEntry<'arena, U: PackScalar<FW>, FW: Field> {
-	witness: Result<MultilinearWitness<'arena, PackedType<U, FW>>, binius_math::Error>,
+	column: Column<'arena, U, FW>,
 	tower_level: usize,
-	data: &'arena [U],
+}
+
+enum Column<'arena, U: PackScalar<FW>, FW: Field> {
+	Materialized {
+		witness: Result<MultilinearWitness<'arena, PackedType<U, FW>>, binius_math::Error>,
+		data: &'arena [U],
+	},
+	Lazy(LazyMultilinearWitness<'arena, PackedType<U, FW>>),
 }
 
 impl<'arena, U, FW> Builder<'arena, U, FW>
@@ -44,6 +72,67 @@ where
 			bump: allocator,
 			oracles,
 			entries: Rc::new(RefCell::new(Vec::new())),
+			strict: false,
+			claimed: RefCell::new(Vec::new()),
+			double_writes: RefCell::new(Vec::new()),
+			scheduled: RefCell::new(Vec::new()),
+		}
+	}
+
+	/// Enables or disables write-once enforcement of witness columns.
+	///
+	/// In strict mode, writing the same column twice, either through [`Self::new_column`] or
+	/// [`Self::set`], is an error, as is building the witness while some committed oracle has no
+	/// witness data. `set` fails immediately; double writes through `new_column` are reported by
+	/// [`Self::build`].
+	pub fn set_strict(&mut self, strict: bool) {
+		self.strict = strict;
+	}
+
+	pub fn is_strict(&self) -> bool {
+		self.strict
+	}
+
+	/// Returns the ids of all committed oracles that have no witness data yet, in increasing order.
+	///
+	/// Columns obtained with [`Self::new_column`] are only counted as populated once the
+	/// [`EntryBuilder`] has been dropped, and outputs of [`Self::schedule`] once their task has run.
+	pub fn missing_columns(&self) -> Vec<OracleId> {
+		let oracles = self.oracles.borrow();
+		let entries = self.entries.borrow();
+		oracles
+			.iter()
+			.filter(|oracle| matches!(oracle, MultilinearPolyOracle::Committed { .. }))
+			.map(|oracle| oracle.id())
+			.filter(|&id| !matches!(entries.get(id), Some(Some(_))))
+			.collect()
+	}
+
+	/// Discards the witness data of all oracles with an id of `n_oracles` or more, together with
+	/// the pending tasks of [`Self::schedule`] writing any of them.
+	///
+	/// The memory of discarded columns stays allocated in the arena until it is dropped.
+	pub(crate) fn truncate(&self, n_oracles: usize) {
+		self.entries.borrow_mut().truncate(n_oracles);
+		self.claimed.borrow_mut().truncate(n_oracles);
+		self.double_writes.borrow_mut().retain(|&id| id < n_oracles);
+		self.scheduled
+			.borrow_mut()
+			.retain(|task| task.outputs.iter().all(|output| output.id < n_oracles));
+	}
+
+	/// Marks the column as written, returning whether it had already been written before.
+	fn claim(&self, id: OracleId) -> bool {
+		let mut claimed = self.claimed.borrow_mut();
+		if id >= claimed.len() {
+			claimed.resize(id + 1, false);
+		}
+		std::mem::replace(&mut claimed[id], true)
+	}
+
+	fn claim_new_column(&self, id: OracleId) {
+		if self.claim(id) && self.strict {
+			self.double_writes.borrow_mut().push(id);
 		}
 	}
 
@@ -52,6 +141,7 @@ where
 		U: PackScalar<FS>,
 		FW: ExtensionField<FS>,
 	{
+		self.claim_new_column(id);
 		let oracles = self.oracles.borrow();
 		let log_rows = oracles.n_vars(id);
 		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
@@ -74,6 +164,7 @@ where
 		U: PackScalar<FS>,
 		FW: ExtensionField<FS>,
 	{
+		self.claim_new_column(id);
 		let oracles = self.oracles.borrow();
 		let log_rows = oracles.n_vars(id);
 		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
@@ -88,32 +179,274 @@ where
 		}
 	}
 
+	/// Creates the column from external bit-packed data, see [`EntryBuilder::copy_from_bytes`].
+	pub fn new_column_from_bytes<FS: TowerField>(
+		&self,
+		id: OracleId,
+		bytes: &[u8],
+		order: BitOrder,
+	) -> Result<(), Error>
+	where
+		U: PackScalar<FS> + Pod,
+		FW: ExtensionField<FS>,
+	{
+		self.new_column::<FS>(id).copy_from_bytes(bytes, order)
+	}
+
+	/// Registers a column whose rows are computed by `row` only when the prover needs them.
+	///
+	/// The column is not stored in the witness. The prover materializes it when it first reads
+	/// it, for example to commit it or to fold it in a sumcheck, and frees it once that step is
+	/// done, computing it again if a later step needs it. This lowers the peak memory of circuits
+	/// with helper columns that are cheap to recompute, at the cost of recomputation. `row` may
+	/// capture other columns obtained with [`Self::get`], but a lazy column cannot be read with
+	/// [`Self::get`] itself.
+	pub fn new_lazy_column<FS: TowerField>(
+		&self,
+		id: OracleId,
+		row: impl Fn(usize) -> FS + Send + Sync + 'arena,
+	) -> Result<(), Error>
+	where
+		U: PackScalar<FS>,
+		FW: ExtensionField<FS>,
+	{
+		let oracles = self.oracles.borrow();
+		if !oracles.is_valid_oracle_id(id) {
+			bail!(BuilderError::InvalidOracleId { id });
+		}
+		if self.claim(id) && self.strict {
+			bail!(BuilderError::ColumnWrittenTwice {
+				id,
+				label: oracles.label(id),
+			});
+		}
+		let log_rows = oracles.n_vars(id);
+		let compute: LazyMultilinearWitness<'arena, PackedType<U, FW>> = Arc::new(move || {
+			let log_width = <PackedType<U, FS>>::LOG_WIDTH;
+			let data = (0..1 << log_rows.saturating_sub(log_width))
+				.into_par_iter()
+				.map(|i| {
+					<PackedType<U, FS>>::from_fn(|j| {
+						let index = i << log_width | j;
+						if index < 1 << log_rows {
+							row(index)
+						} else {
+							FS::ZERO
+						}
+					})
+				})
+				.collect::<Vec<_>>();
+			Ok(MultilinearExtension::new(log_rows, data)?.specialize_arc_dyn())
+		});
+		let mut entries = self.entries.borrow_mut();
+		if id >= entries.len() {
+			entries.resize_with(id + 1, || None);
+		}
+		entries[id] = Some(WitnessBuilderEntry {
+			column: Column::Lazy(compute),
+			tower_level: FS::TOWER_LEVEL,
+		});
+		Ok(())
+	}
+
+	/// Schedules a task that fills its output columns from its input columns.
+	///
+	/// The task does not run right away. Pending tasks run in parallel where their dependencies
+	/// allow, either on [`Self::run_scheduled`], or when one of their outputs is read with
+	/// [`Self::get`], or when the witness is built. Every input must either be written by another
+	/// scheduled task or have witness data by then. A column can be the output of only one pending
+	/// task.
+	pub fn schedule(&self, task: WitnessTask<'arena, U, FW>) -> Result<(), Error> {
+		let oracles = self.oracles.borrow();
+		let mut scheduled = self.scheduled.borrow_mut();
+		for &id in task
+			.inputs
+			.iter()
+			.chain(task.outputs.iter().map(|output| &output.id))
+		{
+			if !oracles.is_valid_oracle_id(id) {
+				bail!(BuilderError::InvalidOracleId { id });
+			}
+		}
+		for output in &task.outputs {
+			let pending = scheduled
+				.iter()
+				.any(|task| task.outputs.iter().any(|other| other.id == output.id));
+			if (self.claim(output.id) && self.strict) || pending {
+				bail!(BuilderError::ColumnWrittenTwice {
+					id: output.id,
+					label: oracles.label(output.id),
+				});
+			}
+		}
+		scheduled.push(task);
+		Ok(())
+	}
+
+	/// Runs all pending tasks of [`Self::schedule`].
+	///
+	/// The tasks run in waves. Every wave runs the pending tasks that read no output of another
+	/// pending task in parallel, and stores their outputs in the witness.
+	pub fn run_scheduled(&self) -> Result<(), Error> {
+		let mut pending = std::mem::take(&mut *self.scheduled.borrow_mut());
+		while !pending.is_empty() {
+			let pending_outputs = pending
+				.iter()
+				.flat_map(|task| task.outputs.iter().map(|output| output.id))
+				.collect::<HashSet<_>>();
+			let (ready, waiting): (Vec<_>, Vec<_>) = pending
+				.into_iter()
+				.partition(|task| task.inputs.iter().all(|id| !pending_outputs.contains(id)));
+			if ready.is_empty() {
+				let mut ids = pending_outputs.into_iter().collect::<Vec<_>>();
+				ids.sort_unstable();
+				bail!(BuilderError::WitnessTaskCycle {
+					ids,
+					tasks: waiting.into_iter().map(|task| task.name).collect(),
+				});
+			}
+
+			let jobs = ready
+				.into_iter()
+				.map(|task| self.prepare_task(task))
+				.collect::<Result<Vec<_>, _>>()?;
+			let finished = jobs
+				.into_par_iter()
+				.map(|(task, inputs, mut outputs)| {
+					let mut columns = TaskColumns {
+						inputs: &inputs,
+						outputs: &mut outputs,
+					};
+					(task.fill)(&mut columns)
+						.map_err(|err| err.context(format!("witness task {} failed", task.name)))?;
+					Ok((task.outputs, outputs))
+				})
+				.collect::<Result<Vec<_>, Error>>()?;
+
+			let mut entries = self.entries.borrow_mut();
+			for (task_outputs, columns) in finished {
+				for (output, column) in task_outputs.into_iter().zip(columns) {
+					let data: &'arena [U] = column.data;
+					if column.id >= entries.len() {
+						entries.resize_with(column.id + 1, || None);
+					}
+					entries[column.id] = Some(WitnessBuilderEntry {
+						column: Column::Materialized {
+							witness: (output.witness)(column.log_rows, data),
+							data,
+						},
+						tower_level: output.tower_level,
+					});
+				}
+			}
+			pending = waiting;
+		}
+		Ok(())
+	}
+
+	/// Collects the inputs of a task from the witness and allocates its outputs.
+	#[allow(clippy::type_complexity)]
+	fn prepare_task(
+		&self,
+		task: WitnessTask<'arena, U, FW>,
+	) -> Result<
+		(
+			WitnessTask<'arena, U, FW>,
+			Vec<TaskColumn<&'arena [U]>>,
+			Vec<TaskColumn<&'arena mut [U]>>,
+		),
+		Error,
+	> {
+		let oracles = self.oracles.borrow();
+		let entries = self.entries.borrow();
+		let inputs = task
+			.inputs
+			.iter()
+			.map(|&id| {
+				let entry = entries
+					.get(id)
+					.and_then(|entry| entry.as_ref())
+					.ok_or_else(|| BuilderError::MissingWitness {
+						id,
+						label: oracles.label(id),
+					})?;
+				let Column::Materialized { data, .. } = entry.column else {
+					bail!(BuilderError::LazyColumn {
+						id,
+						label: oracles.label(id),
+					});
+				};
+				Ok(TaskColumn {
+					id,
+					label: oracles.label(id),
+					tower_level: entry.tower_level,
+					log_rows: oracles.n_vars(id),
+					data,
+				})
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+		let outputs = task
+			.outputs
+			.iter()
+			.map(|output| {
+				let log_rows = oracles.n_vars(output.id);
+				let len = 1 << log_rows.saturating_sub(output.log_width);
+				TaskColumn {
+					id: output.id,
+					label: oracles.label(output.id),
+					tower_level: output.tower_level,
+					log_rows,
+					data: bumpalo::vec![in self.bump; U::default(); len].into_bump_slice_mut(),
+				}
+			})
+			.collect();
+		Ok((task, inputs, outputs))
+	}
+
 	pub fn get<FS: TowerField>(&self, id: OracleId) -> Result<WitnessEntry<'arena, U, FS>, Error>
 	where
 		U: PackScalar<FS>,
 		FW: ExtensionField<FS>,
 	{
+		let is_scheduled = self
+			.scheduled
+			.borrow()
+			.iter()
+			.any(|task| task.outputs.iter().any(|output| output.id == id));
+		if is_scheduled {
+			self.run_scheduled()?;
+		}
 		let entries = self.entries.borrow();
 		let oracles = self.oracles.borrow();
 		if !oracles.is_valid_oracle_id(id) {
-			bail!(anyhow!("OracleId {id} does not exist in MultilinearOracleSet"));
+			bail!(BuilderError::InvalidOracleId { id });
 		}
 		let entry = entries
 			.get(id)
 			.and_then(|entry| entry.as_ref())
-			.ok_or_else(|| anyhow!("Witness for {} is missing", oracles.label(id)))?;
+			.ok_or_else(|| BuilderError::MissingWitness {
+				id,
+				label: oracles.label(id),
+			})?;
 
 		if entry.tower_level != FS::TOWER_LEVEL {
-			bail!(anyhow!(
-				"Provided tower level ({}) for {} does not match stored tower level {}.",
-				FS::TOWER_LEVEL,
-				oracles.label(id),
-				entry.tower_level
-			));
+			bail!(BuilderError::TowerLevelMismatch {
+				id,
+				label: oracles.label(id),
+				expected: entry.tower_level,
+				got: FS::TOWER_LEVEL,
+			});
 		}
 
+		let Column::Materialized { data, .. } = entry.column else {
+			bail!(BuilderError::LazyColumn {
+				id,
+				label: oracles.label(id),
+			});
+		};
+
 		Ok(WitnessEntry {
-			data: entry.data,
+			data,
 			log_rows: oracles.n_vars(id),
 			_marker: PhantomData,
 		})
@@ -130,31 +463,62 @@ where
 	{
 		let oracles = self.oracles.borrow();
 		if !oracles.is_valid_oracle_id(id) {
-			bail!(anyhow!("OracleId {id} does not exist in MultilinearOracleSet"));
+			bail!(BuilderError::InvalidOracleId { id });
+		}
+		if self.claim(id) && self.strict {
+			bail!(BuilderError::ColumnWrittenTwice {
+				id,
+				label: oracles.label(id),
+			});
 		}
 		let mut entries = self.entries.borrow_mut();
 		if id >= entries.len() {
 			entries.resize_with(id + 1, || None);
 		}
 		entries[id] = Some(WitnessBuilderEntry {
-			data: entry.data,
+			column: Column::Materialized {
+				data: entry.data,
+				witness: MultilinearExtension::new(entry.log_rows, entry.packed())
+					.map(|x| x.specialize_arc_dyn()),
+			},
 			tower_level: FS::TOWER_LEVEL,
-			witness: MultilinearExtension::new(entry.log_rows, entry.packed())
-				.map(|x| x.specialize_arc_dyn()),
 		});
 		Ok(())
 	}
 
 	pub fn build(self) -> Result<MultilinearExtensionIndex<'arena, U, FW>, Error> {
+		self.run_scheduled()?;
+		if self.strict {
+			let oracles = self.oracles.borrow();
+			let double_writes = self.double_writes.borrow();
+			if !double_writes.is_empty() {
+				bail!(BuilderError::ColumnsWrittenTwice {
+					ids: double_writes.clone(),
+					labels: double_writes.iter().map(|&id| oracles.label(id)).collect(),
+				});
+			}
+			let missing = self.missing_columns();
+			if !missing.is_empty() {
+				bail!(BuilderError::MissingColumns {
+					labels: missing.iter().map(|&id| oracles.label(id)).collect(),
+					ids: missing,
+				});
+			}
+		}
+
 		let mut result = MultilinearExtensionIndex::new();
 		let entries = Rc::into_inner(self.entries)
-			.ok_or(anyhow!("Failed to build. There are still entries refs. Make sure there are no pending column insertions."))?
-			.into_inner()
-			.into_iter()
-			.enumerate()
-			.filter_map(|(id, entry)| entry.map(|entry| Ok((id, entry.witness?))))
-			.collect::<Result<Vec<_>, Error>>()?;
-		result.update_multilin_poly(entries)?;
+			.ok_or(BuilderError::OutstandingReferences("witness entries"))?
+			.into_inner();
+		let mut materialized = Vec::new();
+		for (id, entry) in entries.into_iter().enumerate() {
+			match entry.map(|entry| entry.column) {
+				Some(Column::Materialized { witness, .. }) => materialized.push((id, witness?)),
+				Some(Column::Lazy(compute)) => result.update_lazy_multilin_poly(id, compute),
+				None => {}
+			}
+		}
+		result.update_multilin_poly(materialized)?;
 		Ok(result)
 	}
 }
@@ -167,6 +531,14 @@ pub struct WitnessEntry<'arena, U: PackScalar<FS>, FS: TowerField> {
 }
 
 impl<'arena, U: PackScalar<FS>, FS: TowerField> WitnessEntry<'arena, U, FS> {
+	pub(super) fn new(data: &'arena [U], log_rows: usize) -> Self {
+		Self {
+			data,
+			log_rows,
+			_marker: PhantomData,
+		}
+	}
+
 	#[inline]
 	pub fn packed(&self) -> &'arena [PackedType<U, FS>] {
 		WithUnderlier::from_underliers_ref(self.data)
@@ -187,6 +559,16 @@ impl<'arena, U: PackScalar<FS>, FS: TowerField> WitnessEntry<'arena, U, FS> {
 	pub fn low_rows(&self) -> usize {
 		self.log_rows
 	}
+
+	/// Views the column as scalars of a byte-aligned extension `FV` of the column field, without
+	/// copying. For example, a column of bits can be read as `BinaryField32b` words.
+	pub fn as_scalars<FV>(&self) -> &'arena [FV]
+	where
+		FV: TowerField + ExtensionField<FS>,
+		U: PackScalar<FV, Packed: PackedFieldIndexable>,
+	{
+		underliers_as_scalars(self.data)
+	}
 }
 
 impl<'arena, U: PackScalar<FS> + Pod, FS: TowerField> WitnessEntry<'arena, U, FS> {
@@ -194,6 +576,17 @@ impl<'arena, U: PackScalar<FS> + Pod, FS: TowerField> WitnessEntry<'arena, U, FS
 	pub fn as_slice<T: Pod>(&self) -> &'arena [T] {
 		must_cast_slice(self.data)
 	}
+
+	/// The bytes of the column packed in the given bit order, borrowed if it is the column order.
+	pub fn to_bytes(&self, order: BitOrder) -> Cow<'arena, [u8]> {
+		let bytes = &self.as_slice::<u8>()[..column_byte_len::<FS>(self.log_rows)];
+		if order == BitOrder::LsbFirst || FS::N_BITS >= 8 {
+			return Cow::Borrowed(bytes);
+		}
+		let mut bytes = bytes.to_vec();
+		BitOrder::LsbFirst.convert::<FS>(order, &mut bytes);
+		Cow::Owned(bytes)
+	}
 }
 
 pub struct EntryBuilder<'arena, U, FW, FS>
@@ -216,11 +609,27 @@ where
 	FS: TowerField,
 	FW: TowerField + ExtensionField<FS>,
 {
+	/// The binary logarithm of the number of rows of the column, which may be fewer than the
+	/// scalars of its packed elements.
+	pub fn log_rows(&self) -> usize {
+		self.log_rows
+	}
+
 	#[inline]
 	pub fn packed(&mut self) -> &mut [PackedType<U, FS>] {
 		PackedType::<U, FS>::from_underliers_ref_mut(self.underliers())
 	}
 
+	/// Views the column as mutable scalars of a byte-aligned extension `FV` of the column field,
+	/// without copying.
+	pub fn as_mut_scalars<FV>(&mut self) -> &mut [FV]
+	where
+		FV: TowerField + ExtensionField<FS>,
+		U: PackScalar<FV, Packed: PackedFieldIndexable>,
+	{
+		underliers_as_scalars_mut(self.underliers())
+	}
+
 	#[inline]
 	fn underliers(&mut self) -> &mut [U] {
 		self.data
@@ -239,6 +648,25 @@ where
 	pub fn as_mut_slice<T: Pod>(&mut self) -> &mut [T] {
 		must_cast_slice_mut(self.underliers())
 	}
+
+	/// Fills the column with external data packed in the given bit order.
+	///
+	/// `bytes` must hold exactly the rows of the column, `2^log_rows * FS::N_BITS / 8` bytes
+	/// rounded up.
+	pub fn copy_from_bytes(&mut self, bytes: &[u8], order: BitOrder) -> Result<(), Error> {
+		let expected = column_byte_len::<FS>(self.log_rows);
+		if bytes.len() != expected {
+			bail!(BuilderError::ByteLengthMismatch {
+				id: self.id,
+				expected,
+				got: bytes.len(),
+			});
+		}
+		let column = &mut self.as_mut_slice::<u8>()[..expected];
+		column.copy_from_slice(bytes);
+		order.convert::<FS>(BitOrder::LsbFirst, column);
+		Ok(())
+	}
 }
 
 impl<U, FW, FS> Drop for EntryBuilder<'_, U, FW, FS>
@@ -255,13 +683,320 @@ where
 			entries.resize_with(id + 1, || None);
 		}
 		entries[id] = Some(WitnessBuilderEntry {
-			data,
+			column: Column::Materialized {
+				data,
+				witness: MultilinearExtension::new(
+					self.log_rows,
+					PackedType::<U, FS>::from_underliers_ref(data),
+				)
+				.map(|x| x.specialize_arc_dyn()),
+			},
 			tower_level: FS::TOWER_LEVEL,
-			witness: MultilinearExtension::new(
-				self.log_rows,
-				PackedType::<U, FS>::from_underliers_ref(data),
-			)
-			.map(|x| x.specialize_arc_dyn()),
 		})
 	}
 }
+
+/// The number of bytes the rows of a column take.
+fn column_byte_len<FS: TowerField>(log_rows: usize) -> usize {
+	((1 << log_rows) * FS::N_BITS).div_ceil(8)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::transparent::step_down::StepDown;
+	use binius_field::{
+		arch::OptimalUnderlier, BinaryField128b, BinaryField1b, BinaryField32b, Field, PackedField,
+		TowerField,
+	};
+	use binius_math::MultilinearPoly;
+
+	use crate::builder::{BitOrder, ConstraintSystemBuilder, Error, WitnessTask};
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+
+	#[test]
+	fn test_strict_rejects_double_new_column() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		witness.set_strict(true);
+		witness.new_column::<BinaryField1b>(a);
+		witness.new_column::<BinaryField1b>(a);
+
+		let err = builder.take_witness().unwrap_err();
+		assert!(err.to_string().contains("more than once"));
+		assert!(matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::ColumnsWrittenTwice { ids, .. }) if *ids == vec![a]
+		));
+	}
+
+	#[test]
+	fn test_strict_rejects_double_set() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", 5, BinaryField1b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		witness.set_strict(true);
+		witness.new_column::<BinaryField1b>(a);
+		let entry = witness.get::<BinaryField1b>(a).unwrap();
+		witness.set(b, entry).unwrap();
+		let err = witness.set(b, entry).unwrap_err();
+		assert!(matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::ColumnWrittenTwice { id, .. }) if *id == b
+		));
+	}
+
+	#[test]
+	fn test_missing_columns() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", 5, BinaryField1b::TOWER_LEVEL);
+		let c = builder.add_committed("c", 5, BinaryField1b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		assert_eq!(witness.missing_columns(), vec![a, b, c]);
+		witness.new_column::<BinaryField1b>(b);
+		assert_eq!(witness.missing_columns(), vec![a, c]);
+
+		witness.set_strict(true);
+		let err = builder.take_witness().unwrap_err();
+		assert!(err.to_string().contains("without witness data"));
+		assert!(matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::MissingColumns { ids, .. }) if *ids == vec![a, c]
+		));
+	}
+
+	#[test]
+	fn test_lazy_column() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 5, BinaryField32b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		witness.set_strict(true);
+		witness
+			.new_lazy_column::<BinaryField32b>(a, |i| BinaryField32b::new(i as u32))
+			.unwrap();
+		assert!(witness.missing_columns().is_empty());
+		let err = witness.get::<BinaryField32b>(a).unwrap_err();
+		assert!(matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::LazyColumn { id, .. }) if *id == a
+		));
+
+		let witness = builder.take_witness().unwrap();
+		assert!(witness.is_lazy(a));
+		let column = witness.get_multilin_poly(a).unwrap();
+		for i in 0..1 << 5 {
+			assert_eq!(
+				column.evaluate_on_hypercube(i).unwrap(),
+				F::from(BinaryField32b::new(i as u32))
+			);
+		}
+	}
+
+	#[test]
+	fn test_lazy_column_large_n_vars() {
+		// A column with more than 2^32 rows is never stored when it is lazy.
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let n_vars = 36;
+		let tall = builder.add_committed("tall", n_vars, BinaryField1b::TOWER_LEVEL);
+		let step_down = builder
+			.add_transparent("step_down", StepDown::new(n_vars, (1 << 33) + 1).unwrap())
+			.unwrap();
+
+		let witness = builder.witness().unwrap();
+		witness.set_strict(true);
+		witness
+			.new_lazy_column::<BinaryField1b>(tall, move |i| {
+				BinaryField1b::from((i >= 1 << 33) as u8)
+			})
+			.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		assert!(witness.is_lazy(tall));
+		assert!(!witness.has(step_down));
+		let constraint_system = builder.build().unwrap();
+		assert_eq!(constraint_system.oracles.n_vars(tall), n_vars);
+		constraint_system.oracles.check_n_vars().unwrap();
+	}
+
+	#[test]
+	fn test_scheduled_tasks() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 5, BinaryField32b::TOWER_LEVEL);
+		let b = builder.add_committed("b", 5, BinaryField32b::TOWER_LEVEL);
+		let c = builder.add_committed("c", 5, BinaryField32b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		witness.set_strict(true);
+		// `c` depends on `b`, which is scheduled after it.
+		witness
+			.schedule(
+				WitnessTask::<U, F>::new("c", |columns| {
+					let b = columns.input::<BinaryField32b>(0)?.as_slice::<u32>();
+					for (c, b) in columns.output_slice::<u32>(0).iter_mut().zip(b) {
+						*c = b + 1;
+					}
+					Ok(())
+				})
+				.input(b)
+				.output::<BinaryField32b>(c),
+			)
+			.unwrap();
+		witness
+			.schedule(
+				WitnessTask::<U, F>::new("b", |columns| {
+					let a = columns.input::<BinaryField32b>(0)?.as_slice::<u32>();
+					for (b, a) in columns.output_slice::<u32>(0).iter_mut().zip(a) {
+						*b = 2 * a;
+					}
+					Ok(())
+				})
+				.input(a)
+				.output::<BinaryField32b>(b),
+			)
+			.unwrap();
+		let err = witness
+			.schedule(WitnessTask::<U, F>::new("b_again", |_| Ok(())).output::<BinaryField32b>(b))
+			.unwrap_err();
+		assert!(matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::ColumnWrittenTwice { id, .. }) if *id == b
+		));
+		witness
+			.new_column::<BinaryField32b>(a)
+			.as_mut_slice::<u32>()
+			.iter_mut()
+			.enumerate()
+			.for_each(|(i, a)| *a = i as u32);
+
+		// Reading an output runs the pending tasks.
+		let c_entry = witness.get::<BinaryField32b>(c).unwrap();
+		for (i, &c) in c_entry.as_slice::<u32>().iter().enumerate() {
+			assert_eq!(c, 2 * i as u32 + 1);
+		}
+		builder.take_witness().unwrap();
+	}
+
+	#[test]
+	fn test_scheduled_task_cycle() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", 5, BinaryField1b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		for (name, input, output) in [("a", b, a), ("b", a, b)] {
+			witness
+				.schedule(
+					WitnessTask::<U, F>::new(name, |_| Ok(()))
+						.input(input)
+						.output::<BinaryField1b>(output),
+				)
+				.unwrap();
+		}
+		let err = builder.take_witness().unwrap_err();
+		assert!(matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::WitnessTaskCycle { ids, .. }) if *ids == vec![a, b]
+		));
+	}
+
+	#[test]
+	fn test_non_strict_allows_overwrite() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		witness.new_column::<BinaryField1b>(a);
+		witness.new_column::<BinaryField1b>(a);
+		builder.take_witness().unwrap();
+	}
+
+	#[test]
+	fn test_scalar_views() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 10, BinaryField1b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		{
+			let mut column = witness.new_column::<BinaryField1b>(a);
+			for (i, word) in column
+				.as_mut_scalars::<BinaryField32b>()
+				.iter_mut()
+				.enumerate()
+			{
+				*word = BinaryField32b::new((i as u32).wrapping_mul(0x9e3779b9));
+			}
+		}
+
+		let entry = witness.get::<BinaryField1b>(a).unwrap();
+		let words = entry.as_scalars::<BinaryField32b>();
+		assert_eq!(words.len(), 1 << 5);
+		assert_eq!(
+			words,
+			entry
+				.repacked::<BinaryField32b>()
+				.as_scalars::<BinaryField32b>()
+		);
+		for (i, &word) in entry.as_slice::<u32>().iter().enumerate() {
+			assert_eq!(word, (i as u32).wrapping_mul(0x9e3779b9));
+		}
+	}
+
+	#[test]
+	fn test_bit_order_import() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 4, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", 4, BinaryField1b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		// Rows 0 and 9 are set, in both conventions.
+		witness
+			.new_column_from_bytes::<BinaryField1b>(
+				a,
+				&[0b0000_0001, 0b0000_0010],
+				BitOrder::LsbFirst,
+			)
+			.unwrap();
+		witness
+			.new_column_from_bytes::<BinaryField1b>(
+				b,
+				&[0b1000_0000, 0b0100_0000],
+				BitOrder::MsbFirst,
+			)
+			.unwrap();
+
+		let a_entry = witness.get::<BinaryField1b>(a).unwrap();
+		let b_entry = witness.get::<BinaryField1b>(b).unwrap();
+		assert_eq!(a_entry.to_bytes(BitOrder::LsbFirst), b_entry.to_bytes(BitOrder::LsbFirst));
+		assert_eq!(&*b_entry.to_bytes(BitOrder::MsbFirst), &[0b1000_0000, 0b0100_0000]);
+		let rows = a_entry.packed()[0].iter().take(16).collect::<Vec<_>>();
+		for (i, row) in rows.into_iter().enumerate() {
+			assert_eq!(row == BinaryField1b::ONE, i == 0 || i == 9);
+		}
+
+		let err = witness
+			.new_column_from_bytes::<BinaryField1b>(a, &[0; 3], BitOrder::LsbFirst)
+			.unwrap_err();
+		assert!(matches!(
+			err.downcast_ref::<Error>(),
+			Some(Error::ByteLengthMismatch { id, expected: 2, got: 3 }) if *id == a
+		));
+	}
+}
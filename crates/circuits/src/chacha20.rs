@@ -0,0 +1,95 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The ChaCha20 block function, as specified by RFC 8439.
+//!
+//! The state is held in columns of 32-bit words, one block per row, as in [`crate::sha256`]. The
+//! quarter rounds are built from [`arithmetic::u32::add`], [`bitwise::xor`] and
+//! [`arithmetic::u32::rotl`].
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, TowerField,
+};
+use bytemuck::Pod;
+
+use crate::{
+	arithmetic::{self, Flags},
+	bitwise,
+	builder::ConstraintSystemBuilder,
+	sha256::u32const_repeating,
+};
+
+/// The first row of the state, "expand 32-byte k" in little-endian words.
+pub const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+/// The quarter rounds of a double round, a column round followed by a diagonal round.
+const QUARTER_ROUNDS: [[usize; 4]; 8] = [
+	[0, 4, 8, 12],
+	[1, 5, 9, 13],
+	[2, 6, 10, 14],
+	[3, 7, 11, 15],
+	[0, 5, 10, 15],
+	[1, 6, 11, 12],
+	[2, 7, 8, 13],
+	[3, 4, 9, 14],
+];
+
+fn quarter_round<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	state: &mut [OracleId; 16],
+	[a, b, c, d]: [usize; 4],
+) -> Result<()>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	// Every step is x += y; z ^= x; z <<<= rotation
+	for (x, y, z, rotation) in [(a, b, d, 16), (c, d, b, 12), (a, b, d, 8), (c, d, b, 7)] {
+		state[x] = arithmetic::u32::add(builder, "add", state[x], state[y], Flags::Unchecked)?;
+		let xor = bitwise::xor(builder, "xor", state[z], state[x])?;
+		state[z] = arithmetic::u32::rotl(builder, "rotl", xor, rotation)?;
+	}
+	Ok(())
+}
+
+/// Computes the ChaCha20 block of the `key`, the block `counter` and the `nonce`.
+///
+/// All inputs and the 16 output words of the keystream block are columns of 32-bit words with
+/// `log_size` variables, holding the little-endian words of the specification.
+pub fn chacha20_block<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	key: [OracleId; 8],
+	counter: OracleId,
+	nonce: [OracleId; 3],
+	log_size: usize,
+) -> Result<[OracleId; 16]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let constants =
+		CONSTANTS.try_map(|word| u32const_repeating(log_size, builder, word, "constant"))?;
+	let mut initial = [OracleId::MAX; 16];
+	initial[..4].copy_from_slice(&constants);
+	initial[4..12].copy_from_slice(&key);
+	initial[12] = counter;
+	initial[13..].copy_from_slice(&nonce);
+
+	let mut state = initial;
+	for round in 0..10 {
+		builder.push_namespace(format!("double_round_{round}"));
+		for quarter in QUARTER_ROUNDS {
+			quarter_round(builder, &mut state, quarter)?;
+		}
+		builder.pop_namespace();
+	}
+
+	let output = std::array::try_from_fn(|i| {
+		arithmetic::u32::add(builder, "output", state[i], initial[i], Flags::Unchecked)
+	})?;
+	builder.pop_namespace();
+	Ok(output)
+}
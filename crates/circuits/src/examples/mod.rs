@@ -0,0 +1,8 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Complete example subsystems built from the gadgets in this crate.
+//!
+//! Unlike the gadgets, these examples are whole circuits with their own witness generation and
+//! public boundaries, intended as references for structuring larger constraint systems.
+
+pub mod stack_vm;
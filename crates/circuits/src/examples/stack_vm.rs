@@ -0,0 +1,683 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! A small stack machine, proven with one table per kind of instruction.
+//!
+//! This is a worked example of the intended architecture for VM circuits. Every executed
+//! instruction is a row in the table for its opcode, and the tables are connected by three
+//! channels:
+//!
+//! * The *state* channel carries the registers `(pc, sp, clk)`. Every row pulls the registers
+//!   before its instruction and pushes the registers after it. The boundaries push the initial
+//!   state and pull the final state, and since `clk` increases with every step, the rows must
+//!   form a single execution of `clk` steps.
+//! * The *program* channel carries the instructions `(pc, opcode, arg, fetches)`. The boundaries
+//!   push every instruction of the public program with zero fetches and pull it with the number
+//!   of times it was executed. Every row pulls its instruction and pushes it back with the fetch
+//!   counter incremented, so that rows can only execute instructions of the program.
+//! * The *memory* channel carries the stack cells `(addr, value, clk)`, where `clk` is the step
+//!   that wrote the cell. Instructions push the cells they write and pull the cells they pop,
+//!   checking that popped cells were written at an earlier step. The cells left on the stack when
+//!   the execution ends are pulled by the boundaries and form the output of the program.
+//!
+//! The values on the stack are elements of $\mathbb{F}_{2^{32}}$, so `Add` and `Mul` are the
+//! binary field operations, which are native to the constraint system. The registers are 32-bit
+//! integers.
+//!
+//! The program is public and identified by its [`Program::hash`]. A verifier that knows the
+//! program rebuilds the constraint system and the boundaries from the program and the [`Advice`]
+//! published by the prover.
+
+use std::array;
+
+use anyhow::{bail, ensure};
+use binius_core::{
+	constraint_system::channel::{Boundary, ChannelId, FlushDirection},
+	oracle::OracleId,
+};
+use binius_field::{
+	as_packed_field::PackScalar, AESTowerField8b, BinaryField1b, BinaryField32b, ExtensionField,
+	PackedField, TowerField,
+};
+use binius_hash::{hasher_hash, Groestl256};
+use binius_macros::arith_expr;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use bytemuck::Pod;
+
+use crate::{
+	arithmetic::{self, Flags},
+	builder::ConstraintSystemBuilder,
+};
+
+const PUSH: u32 = 0;
+const ADD: u32 = 1;
+const MUL: u32 = 2;
+const JUMP: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+	/// Pushes the immediate value onto the stack.
+	Push(u32),
+	/// Pops two values and pushes their sum.
+	Add,
+	/// Pops two values and pushes their product.
+	Mul,
+	/// Continues the execution at the given instruction.
+	Jump(u32),
+}
+
+impl Instruction {
+	pub fn opcode(&self) -> u32 {
+		match self {
+			Self::Push(_) => PUSH,
+			Self::Add => ADD,
+			Self::Mul => MUL,
+			Self::Jump(_) => JUMP,
+		}
+	}
+
+	pub fn arg(&self) -> u32 {
+		match self {
+			Self::Push(arg) | Self::Jump(arg) => *arg,
+			Self::Add | Self::Mul => 0,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program(pub Vec<Instruction>);
+
+impl Program {
+	/// The Grøstl-256 digest of the program, which identifies it publicly.
+	///
+	/// Every instruction is encoded as its opcode followed by its argument, both in little-endian.
+	pub fn hash(&self) -> [u8; 32] {
+		let encoding = self
+			.0
+			.iter()
+			.flat_map(|instruction| [instruction.opcode(), instruction.arg()])
+			.flat_map(u32::to_le_bytes)
+			.map(AESTowerField8b::new)
+			.collect::<Vec<_>>();
+		let digest =
+			hasher_hash::<AESTowerField8b, Groestl256<AESTowerField8b, AESTowerField8b>>(encoding);
+		array::from_fn(|i| digest.get(i).val())
+	}
+}
+
+/// The public outputs of an execution, which the verifier needs to rebuild the constraint system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Advice {
+	/// The number of executed instructions for each opcode.
+	pub counts: [usize; 4],
+	/// The number of times each instruction of the program was executed.
+	pub fetches: Vec<u32>,
+	/// The registers `(pc, sp, clk)` after the last step.
+	pub final_state: [u32; 3],
+	/// The cells left on the stack from the bottom up, as pairs of the value and the step that
+	/// wrote it.
+	pub stack: Vec<(u32, u32)>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Step {
+	pc: u32,
+	sp: u32,
+	clk: u32,
+	fetches: u32,
+	arg: u32,
+}
+
+/// A step of an instruction that pops the two topmost cells `a` and `b` of the stack.
+#[derive(Debug, Default, Clone, Copy)]
+struct BinaryStep {
+	step: Step,
+	a: u32,
+	a_clk: u32,
+	b: u32,
+	b_clk: u32,
+}
+
+struct Channels {
+	state: ChannelId,
+	program: ChannelId,
+	memory: ChannelId,
+}
+
+/// The columns of a table that take part in the instruction fetch and the register transition.
+struct StepColumns {
+	/// The unpacked `clk` register.
+	clk: OracleId,
+	/// The packed registers `(pc, sp, clk)` before the step.
+	state: [OracleId; 3],
+	/// The packed registers `(pc, sp)` after the step.
+	next: [OracleId; 2],
+	/// The packed instruction argument.
+	arg: OracleId,
+}
+
+pub struct StackVm {
+	program: Program,
+	max_steps: u32,
+	pushes: Vec<Step>,
+	adds: Vec<BinaryStep>,
+	muls: Vec<BinaryStep>,
+	jumps: Vec<Step>,
+}
+
+impl StackVm {
+	/// Creates a machine that runs the program until the program counter leaves the program, or
+	/// for at most `max_steps` steps.
+	pub fn new(program: Program, max_steps: u32) -> Self {
+		Self {
+			program,
+			max_steps,
+			pushes: vec![],
+			adds: vec![],
+			muls: vec![],
+			jumps: vec![],
+		}
+	}
+
+	pub fn program(&self) -> &Program {
+		&self.program
+	}
+
+	pub fn init_prover(&mut self) -> Result<Advice, anyhow::Error> {
+		let instructions = &self.program.0;
+		let (mut pushes, mut adds, mut muls, mut jumps) = (vec![], vec![], vec![], vec![]);
+		let mut fetches = vec![0; instructions.len()];
+		let mut stack = Vec::<(u32, u32)>::new();
+		let mut pc = 0;
+		let mut clk = 0;
+
+		while clk < self.max_steps {
+			let Some(&instruction) = instructions.get(pc as usize) else {
+				break;
+			};
+			let step = Step {
+				pc,
+				sp: stack.len() as u32,
+				clk,
+				fetches: fetches[pc as usize],
+				arg: instruction.arg(),
+			};
+			fetches[pc as usize] += 1;
+			pc += 1;
+
+			match instruction {
+				Instruction::Push(value) => {
+					stack.push((value, clk));
+					pushes.push(step);
+				}
+				Instruction::Add | Instruction::Mul => {
+					let (Some((a, a_clk)), Some((b, b_clk))) = (stack.pop(), stack.pop()) else {
+						bail!("stack underflow at instruction {}", step.pc);
+					};
+					let (x, y) = (BinaryField32b::new(a), BinaryField32b::new(b));
+					let (value, steps) = if instruction == Instruction::Add {
+						(x + y, &mut adds)
+					} else {
+						(x * y, &mut muls)
+					};
+					stack.push((value.val(), clk));
+					steps.push(BinaryStep {
+						step,
+						a,
+						a_clk,
+						b,
+						b_clk,
+					});
+				}
+				Instruction::Jump(target) => {
+					pc = target;
+					jumps.push(step);
+				}
+			}
+			clk += 1;
+		}
+
+		let advice = Advice {
+			counts: [pushes.len(), adds.len(), muls.len(), jumps.len()],
+			fetches,
+			final_state: [pc, stack.len() as u32, clk],
+			stack,
+		};
+		(self.pushes, self.adds, self.muls, self.jumps) = (pushes, adds, muls, jumps);
+		Ok(advice)
+	}
+
+	pub fn build<U, F>(
+		self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		advice: Advice,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error>
+	where
+		U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+		F: TowerField + ExtensionField<BinaryField32b>,
+	{
+		ensure!(
+			advice.fetches.len() == self.program.0.len(),
+			"advice has fetch counts for {} instructions, but the program has {}",
+			advice.fetches.len(),
+			self.program.0.len()
+		);
+
+		let channels = Channels {
+			state: builder.add_channel(),
+			program: builder.add_channel(),
+			memory: builder.add_channel(),
+		};
+
+		let [push_count, add_count, mul_count, jump_count] = advice.counts;
+		if push_count > 0 {
+			self.push(builder, &channels, push_count)?;
+		}
+		if add_count > 0 {
+			self.binary(builder, &channels, ADD, add_count, &self.adds)?;
+		}
+		if mul_count > 0 {
+			self.binary(builder, &channels, MUL, mul_count, &self.muls)?;
+		}
+		if jump_count > 0 {
+			self.jump(builder, &channels, jump_count)?;
+		}
+
+		Ok(self.get_boundaries(&channels, &advice))
+	}
+
+	fn push<U, F>(
+		&self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		channels: &Channels,
+		count: usize,
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+		F: TowerField + ExtensionField<BinaryField32b>,
+	{
+		builder.push_namespace("push");
+		debug_assert!(builder.witness().is_none() || count == self.pushes.len());
+		let log_32b_rows = log2_ceil_usize(count);
+
+		let steps = &self.pushes;
+		let pc = committed_u32(builder, "pc", log_32b_rows, steps.iter().map(|s| s.pc));
+		let sp = committed_u32(builder, "sp", log_32b_rows, steps.iter().map(|s| s.sp));
+		let clk = committed_u32(builder, "clk", log_32b_rows, steps.iter().map(|s| s.clk));
+		let arg = committed_u32(builder, "arg", log_32b_rows, steps.iter().map(|s| s.arg));
+
+		let one = arithmetic::u32::constant(builder, "one", log_32b_rows, 1)?;
+		let next_pc = arithmetic::u32::add(builder, "next_pc", pc, one, Flags::Checked)?;
+		let next_sp = arithmetic::u32::add(builder, "next_sp", sp, one, Flags::Checked)?;
+
+		let [pc, sp, clk_packed, arg, next_pc, next_sp] = pack(
+			builder,
+			[
+				("pc", pc),
+				("sp", sp),
+				("clk", clk),
+				("arg", arg),
+				("next_pc", next_pc),
+				("next_sp", next_sp),
+			],
+		)?;
+
+		// The immediate value is written to the cell above the top of the stack.
+		builder.send(channels.memory, count, [sp, arg, clk_packed]);
+
+		let columns = StepColumns {
+			clk,
+			state: [pc, sp, clk_packed],
+			next: [next_pc, next_sp],
+			arg,
+		};
+		step(builder, channels, PUSH, count, one, columns, steps.iter().map(|s| s.fetches))?;
+
+		builder.pop_namespace();
+		Ok(())
+	}
+
+	/// Builds the table for an instruction that replaces the two topmost cells of the stack with
+	/// the result of a binary field operation.
+	///
+	/// Instead of committing the registers, the table commits the address of the second cell and
+	/// the gaps between the steps that wrote the two cells and the current step, and derives the
+	/// registers by checked additions. This proves that the popped cells were written before the
+	/// current step without any comparisons.
+	fn binary<U, F>(
+		&self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		channels: &Channels,
+		opcode: u32,
+		count: usize,
+		steps: &[BinaryStep],
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+		F: TowerField + ExtensionField<BinaryField32b>,
+	{
+		builder.push_namespace(if opcode == ADD { "add" } else { "mul" });
+		debug_assert!(builder.witness().is_none() || count == steps.len());
+		let log_32b_rows = log2_ceil_usize(count);
+
+		let pc = committed_u32(builder, "pc", log_32b_rows, steps.iter().map(|s| s.step.pc));
+		let second =
+			committed_u32(builder, "second", log_32b_rows, steps.iter().map(|s| s.step.sp - 2));
+		let b_clk = committed_u32(builder, "b_clk", log_32b_rows, steps.iter().map(|s| s.b_clk));
+		let b_gap = committed_u32(
+			builder,
+			"b_gap",
+			log_32b_rows,
+			steps.iter().map(|s| s.a_clk - s.b_clk - 1),
+		);
+		let a_gap = committed_u32(
+			builder,
+			"a_gap",
+			log_32b_rows,
+			steps.iter().map(|s| s.step.clk - s.a_clk - 1),
+		);
+		let arg = committed_u32(builder, "arg", log_32b_rows, steps.iter().map(|s| s.step.arg));
+
+		let a = committed_b32(builder, "a", log_32b_rows, steps.iter().map(|s| s.a));
+		let b = committed_b32(builder, "b", log_32b_rows, steps.iter().map(|s| s.b));
+		let c = committed_b32(
+			builder,
+			"c",
+			log_32b_rows,
+			steps.iter().map(|s| {
+				let (a, b) = (BinaryField32b::new(s.a), BinaryField32b::new(s.b));
+				let c = if opcode == ADD { a + b } else { a * b };
+				c.val()
+			}),
+		);
+		if opcode == ADD {
+			builder.assert_zero("c", [a, b, c], arith_expr!([a, b, c] = a + b - c).convert_field());
+		} else {
+			builder.assert_zero("c", [a, b, c], arith_expr!([a, b, c] = a * b - c).convert_field());
+		}
+
+		let one = arithmetic::u32::constant(builder, "one", log_32b_rows, 1)?;
+		let top = arithmetic::u32::add(builder, "top", second, one, Flags::Checked)?;
+		let sp = arithmetic::u32::add(builder, "sp", top, one, Flags::Checked)?;
+		// The second cell was written before the top cell, which was written before this step.
+		let after_b = arithmetic::u32::add(builder, "after_b", b_clk, one, Flags::Checked)?;
+		let a_clk = arithmetic::u32::add(builder, "a_clk", after_b, b_gap, Flags::Checked)?;
+		let after_a = arithmetic::u32::add(builder, "after_a", a_clk, one, Flags::Checked)?;
+		let clk = arithmetic::u32::add(builder, "clk", after_a, a_gap, Flags::Checked)?;
+		let next_pc = arithmetic::u32::add(builder, "next_pc", pc, one, Flags::Checked)?;
+
+		let [pc, sp, clk_packed, second, top, a_clk, b_clk, arg, next_pc] = pack(
+			builder,
+			[
+				("pc", pc),
+				("sp", sp),
+				("clk", clk),
+				("second", second),
+				("top", top),
+				("a_clk", a_clk),
+				("b_clk", b_clk),
+				("arg", arg),
+				("next_pc", next_pc),
+			],
+		)?;
+
+		builder.receive(channels.memory, count, [top, a, a_clk]);
+		builder.receive(channels.memory, count, [second, b, b_clk]);
+		builder.send(channels.memory, count, [second, c, clk_packed]);
+
+		let columns = StepColumns {
+			clk,
+			state: [pc, sp, clk_packed],
+			next: [next_pc, top],
+			arg,
+		};
+		step(builder, channels, opcode, count, one, columns, steps.iter().map(|s| s.step.fetches))?;
+
+		builder.pop_namespace();
+		Ok(())
+	}
+
+	fn jump<U, F>(
+		&self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		channels: &Channels,
+		count: usize,
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+		F: TowerField + ExtensionField<BinaryField32b>,
+	{
+		builder.push_namespace("jump");
+		debug_assert!(builder.witness().is_none() || count == self.jumps.len());
+		let log_32b_rows = log2_ceil_usize(count);
+
+		let steps = &self.jumps;
+		let pc = committed_u32(builder, "pc", log_32b_rows, steps.iter().map(|s| s.pc));
+		let sp = committed_u32(builder, "sp", log_32b_rows, steps.iter().map(|s| s.sp));
+		let clk = committed_u32(builder, "clk", log_32b_rows, steps.iter().map(|s| s.clk));
+		let arg = committed_u32(builder, "arg", log_32b_rows, steps.iter().map(|s| s.arg));
+
+		let one = arithmetic::u32::constant(builder, "one", log_32b_rows, 1)?;
+
+		let [pc, sp, clk_packed, arg] =
+			pack(builder, [("pc", pc), ("sp", sp), ("clk", clk), ("arg", arg)])?;
+
+		let columns = StepColumns {
+			clk,
+			state: [pc, sp, clk_packed],
+			next: [arg, sp],
+			arg,
+		};
+		step(builder, channels, JUMP, count, one, columns, steps.iter().map(|s| s.fetches))?;
+
+		builder.pop_namespace();
+		Ok(())
+	}
+
+	fn get_boundaries<F>(&self, channels: &Channels, advice: &Advice) -> Vec<Boundary<F>>
+	where
+		F: TowerField + From<BinaryField32b>,
+	{
+		let boundary = |channel_id, direction, values: &[u32]| Boundary {
+			values: values
+				.iter()
+				.map(|&value| BinaryField32b::new(value).into())
+				.collect(),
+			channel_id,
+			direction,
+			multiplicity: 1,
+		};
+
+		let mut boundaries = vec![
+			boundary(channels.state, FlushDirection::Push, &[0, 0, 0]),
+			boundary(channels.state, FlushDirection::Pull, &advice.final_state),
+		];
+		for (pc, (instruction, &fetches)) in self.program.0.iter().zip(&advice.fetches).enumerate()
+		{
+			let (pc, opcode, arg) = (pc as u32, instruction.opcode(), instruction.arg());
+			boundaries.push(boundary(
+				channels.program,
+				FlushDirection::Push,
+				&[pc, opcode, arg, 0],
+			));
+			boundaries.push(boundary(
+				channels.program,
+				FlushDirection::Pull,
+				&[pc, opcode, arg, fetches],
+			));
+		}
+		for (addr, &(value, clk)) in advice.stack.iter().enumerate() {
+			boundaries.push(boundary(
+				channels.memory,
+				FlushDirection::Pull,
+				&[addr as u32, value, clk],
+			));
+		}
+		boundaries
+	}
+}
+
+/// Fetches the instruction of a step from the program and transitions the registers from
+/// `(pc, sp, clk)` to `(next_pc, next_sp, clk + 1)`.
+fn step<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	channels: &Channels,
+	opcode: u32,
+	count: usize,
+	one: OracleId,
+	columns: StepColumns,
+	fetches: impl IntoIterator<Item = u32>,
+) -> Result<(), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	let StepColumns {
+		clk,
+		state: [pc, sp, clk_packed],
+		next: [next_pc, next_sp],
+		arg,
+	} = columns;
+	let log_32b_rows = builder.log_rows([clk])? - 5;
+
+	let fetches = committed_u32(builder, "fetches", log_32b_rows, fetches);
+	let next_fetches = arithmetic::u32::add(builder, "next_fetches", fetches, one, Flags::Checked)?;
+	let next_clk = arithmetic::u32::add(builder, "next_clk", clk, one, Flags::Checked)?;
+	let opcode = arithmetic::u32::constant(builder, "opcode", log_32b_rows, opcode)?;
+
+	let [fetches, next_fetches, next_clk, opcode] = pack(
+		builder,
+		[
+			("fetches", fetches),
+			("next_fetches", next_fetches),
+			("next_clk", next_clk),
+			("opcode", opcode),
+		],
+	)?;
+
+	builder.receive(channels.program, count, [pc, opcode, arg, fetches]);
+	builder.send(channels.program, count, [pc, opcode, arg, next_fetches]);
+
+	builder.receive(channels.state, count, [pc, sp, clk_packed]);
+	builder.send(channels.state, count, [next_pc, next_sp, next_clk]);
+
+	Ok(())
+}
+
+fn committed_u32<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_32b_rows: usize,
+	values: impl IntoIterator<Item = u32>,
+) -> OracleId
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let id = builder.add_committed(name, log_32b_rows + 5, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		for (dst, src) in witness
+			.new_column::<BinaryField1b>(id)
+			.as_mut_slice::<u32>()
+			.iter_mut()
+			.zip(values)
+		{
+			*dst = src;
+		}
+	}
+	id
+}
+
+fn committed_b32<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_32b_rows: usize,
+	values: impl IntoIterator<Item = u32>,
+) -> OracleId
+where
+	U: PackScalar<F> + PackScalar<BinaryField32b> + Pod,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	let id = builder.add_committed(name, log_32b_rows, BinaryField32b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		for (dst, src) in witness
+			.new_column::<BinaryField32b>(id)
+			.as_mut_slice::<u32>()
+			.iter_mut()
+			.zip(values)
+		{
+			*dst = src;
+		}
+	}
+	id
+}
+
+fn pack<U, F, const N: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	columns: [(&str, OracleId); N],
+) -> Result<[OracleId; N], anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	columns.try_map(|(name, id)| arithmetic::u32::packed(builder, format!("{name}_packed"), id))
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField32b};
+
+	use super::{Instruction, Program, StackVm};
+	use crate::builder::ConstraintSystemBuilder;
+
+	#[test]
+	fn test_stack_vm() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder =
+			ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new_with_witness(
+				&allocator,
+			);
+
+		let program = Program(vec![
+			Instruction::Push(3),
+			Instruction::Push(5),
+			Instruction::Add,
+			Instruction::Push(7),
+			Instruction::Mul,
+			Instruction::Jump(7),
+			Instruction::Push(11),
+			Instruction::Push(13),
+		]);
+		let mut vm = StackVm::new(program, 100);
+		let advice = vm.init_prover().unwrap();
+
+		let expected = (BinaryField32b::new(3) + BinaryField32b::new(5)) * BinaryField32b::new(7);
+		assert_eq!(advice.counts, [4, 1, 1, 1]);
+		assert_eq!(advice.fetches, vec![1, 1, 1, 1, 1, 1, 0, 1]);
+		assert_eq!(advice.final_state, [8, 2, 7]);
+		assert_eq!(advice.stack, vec![(expected.val(), 4), (13, 6)]);
+
+		let boundaries = vm.build(&mut builder, advice).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
+	#[test]
+	fn test_program_hash() {
+		let program = Program(vec![Instruction::Push(1), Instruction::Jump(0)]);
+		assert_eq!(program.hash(), program.clone().hash());
+		assert_ne!(
+			program.hash(),
+			Program(vec![Instruction::Push(1), Instruction::Jump(1)]).hash()
+		);
+		assert_ne!(program.hash(), Program(vec![Instruction::Push(1)]).hash());
+	}
+
+	#[test]
+	fn test_stack_vm_underflow() {
+		let mut vm = StackVm::new(Program(vec![Instruction::Push(1), Instruction::Add]), 100);
+		assert!(vm.init_prover().is_err());
+	}
+}
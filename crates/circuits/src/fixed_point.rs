@@ -0,0 +1,442 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Signed fixed-point arithmetic for quantized inference.
+//!
+//! A fixed-point column uses the `u32` column layout of [`crate::arithmetic::u32`] and holds two's
+//! complement integers scaled by $2^{-\text{frac\_bits}}$, so Q16.16 values use `frac_bits = 16`.
+//! The scale only matters to [`mul`] and [`dot_product`], which take it as a parameter.
+//!
+//! Products are computed exactly in 64 bits and rounded toward negative infinity when they are
+//! scaled back. With [`Flags::Checked`], results which do not fit into 32 bits are rejected,
+//! otherwise they wrap around.
+
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, BinaryField64b, ExtensionField,
+	TowerField,
+};
+use binius_macros::arith_expr;
+use binius_utils::rayon::prelude::*;
+use bytemuck::Pod;
+
+use crate::{
+	arithmetic::{uint, Flags},
+	bitwise,
+	builder::ConstraintSystemBuilder,
+};
+
+pub fn add<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let zout = uint::add::<u32, _, _>(builder, "zout", xin, yin, Flags::Unchecked)?;
+	if matches!(flags, Flags::Checked) {
+		assert_no_signed_overflow::<u32, _, _>(builder, "overflow", xin, yin, zout)?;
+	}
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Multiplies two fixed-point columns with `frac_bits` fractional bits.
+pub fn mul<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	frac_bits: usize,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	builder.push_namespace(name);
+	let product = wide_product(builder, "product", xin, yin)?;
+	let zout = rescale(builder, "rescale", product, frac_bits, flags)?;
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Replaces negative values with zero.
+pub fn relu<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let negative = uint::broadcast_bit::<u32, _, _>(builder, "negative", input, 31)?;
+	let log_rows = builder.log_rows([input])?;
+	let output = builder.add_committed("output", log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<BinaryField1b>(input)?.as_slice::<i32>(),
+			witness
+				.new_column::<BinaryField1b>(output)
+				.as_mut_slice::<i32>(),
+		)
+			.into_par_iter()
+			.for_each(|(&input, output)| *output = input.max(0));
+	}
+	builder.assert_zero(
+		"relu",
+		[input, negative, output],
+		arith_expr!([x, negative, z] = x - x * negative - z).convert_field(),
+	);
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Clamps every value to the range `min..=max`, given as raw fixed-point representations.
+pub fn clamp<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	min: i32,
+	max: i32,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	anyhow::ensure!(min <= max, "Clamp range is empty: {min} > {max}");
+	builder.push_namespace(name);
+	let wide = uint::cast::<u32, u64, _, _>(builder, "wide", input)?;
+	let log_count = builder.log_rows([wide])? - 6;
+
+	// Flipping the sign bit maps the signed order onto the unsigned order, which is compared
+	// with the borrow of a 64-bit subtraction.
+	let bias = uint::constant::<u64, _, _>(builder, "bias", log_count, 1 << 31)?;
+	let biased = bitwise::xor(builder, "biased", wide, bias)?;
+	let biased_bound = |bound: i32| (bound as u32 ^ (1 << 31)) as u64;
+	let min_biased =
+		uint::constant::<u64, _, _>(builder, "min_biased", log_count, biased_bound(min))?;
+	let max_biased =
+		uint::constant::<u64, _, _>(builder, "max_biased", log_count, biased_bound(max))?;
+	let below = uint::sub::<u64, _, _>(builder, "below", biased, min_biased, Flags::Unchecked)?;
+	let above = uint::sub::<u64, _, _>(builder, "above", max_biased, biased, Flags::Unchecked)?;
+	let below = uint::broadcast_bit::<u64, _, _>(builder, "below_mask", below, 63)?;
+	let above = uint::broadcast_bit::<u64, _, _>(builder, "above_mask", above, 63)?;
+
+	let min = uint::constant::<u64, _, _>(builder, "min", log_count, min as u32 as u64)?;
+	let max = uint::constant::<u64, _, _>(builder, "max", log_count, max as u32 as u64)?;
+	let output = uint::select::<u64, _, _>(builder, "clamp_min", below, wide, min)?;
+	let output = uint::select::<u64, _, _>(builder, "clamp_max", above, output, max)?;
+	let output = uint::cast::<u64, u32, _, _>(builder, "output", output)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Computes the dot product of the fixed-point columns `xins` and `yins` row by row.
+///
+/// The products are accumulated exactly in 64 bits and rescaled once at the end, so the result is
+/// rounded once and only the final value is range checked. With [`Flags::Checked`], each
+/// accumulation step only checks that the 64-bit sum does not overflow, which is far cheaper than
+/// the range check of [`mul`].
+pub fn dot_product<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xins: &[OracleId],
+	yins: &[OracleId],
+	frac_bits: usize,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	anyhow::ensure!(
+		xins.len() == yins.len(),
+		"Dot product operands have different lengths: {} and {}",
+		xins.len(),
+		yins.len()
+	);
+	anyhow::ensure!(!xins.is_empty(), "Dot product operands must not be empty");
+	builder.push_namespace(name);
+
+	let mut acc = None;
+	for (i, (&xin, &yin)) in xins.iter().zip(yins).enumerate() {
+		let product = wide_product(builder, format!("product{i}"), xin, yin)?;
+		acc = Some(match acc {
+			None => product,
+			Some(acc) => {
+				let sum = uint::add::<u64, _, _>(
+					builder,
+					format!("acc{i}"),
+					acc,
+					product,
+					Flags::Unchecked,
+				)?;
+				if matches!(flags, Flags::Checked) {
+					assert_no_signed_overflow::<u64, _, _>(
+						builder,
+						format!("acc{i}_overflow"),
+						acc,
+						product,
+						sum,
+					)?;
+				}
+				sum
+			}
+		});
+	}
+
+	let acc = acc.expect("operands are not empty");
+	let zout = rescale(builder, "rescale", acc, frac_bits, flags)?;
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// The exact product of two columns of signed 32-bit integers as a column of signed 64-bit
+/// integers.
+fn wide_product<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	builder.push_namespace(name);
+	let x = uint::cast::<u32, u64, _, _>(builder, "x", xin)?;
+	let y = uint::cast::<u32, u64, _, _>(builder, "y", yin)?;
+	let unsigned =
+		uint::mul_low_bits::<u64, _, _>(builder, "unsigned", x, y, 32, Flags::Unchecked)?;
+
+	// A negative x contributes x - 2^32 to the signed product, so (y << 32) is subtracted, and
+	// the same for y. The 2^64 term of two negative operands vanishes modulo 2^64.
+	let x_negative = uint::broadcast_bit::<u64, _, _>(builder, "x_negative", x, 31)?;
+	let y_negative = uint::broadcast_bit::<u64, _, _>(builder, "y_negative", y, 31)?;
+	let x_high = uint::shl::<u64, _, _>(builder, "x_high", x, 32)?;
+	let y_high = uint::shl::<u64, _, _>(builder, "y_high", y, 32)?;
+	let x_correction = bitwise::and(builder, "x_correction", x_negative, y_high)?;
+	let y_correction = bitwise::and(builder, "y_correction", y_negative, x_high)?;
+	let product =
+		uint::sub::<u64, _, _>(builder, "x_corrected", unsigned, x_correction, Flags::Unchecked)?;
+	let product =
+		uint::sub::<u64, _, _>(builder, "y_corrected", product, y_correction, Flags::Unchecked)?;
+	builder.pop_namespace();
+	Ok(product)
+}
+
+/// Divides signed 64-bit integers by $2^{\text{frac\_bits}}$, rounding toward negative infinity,
+/// and truncates them to 32 bits.
+///
+/// With [`Flags::Checked`], the scaled values must fit into signed 32-bit integers.
+fn rescale<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	frac_bits: usize,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	anyhow::ensure!(frac_bits <= 32, "Only frac_bits values up to 32 are allowed. Got {frac_bits}");
+	builder.push_namespace(name);
+	let shifted = uint::shl::<u64, _, _>(builder, "shl", input, 32 - frac_bits)?;
+	let shifted = uint::shr::<u64, _, _>(builder, "shr", shifted, 32)?;
+	let output = uint::cast::<u64, u32, _, _>(builder, "output", shifted)?;
+
+	if matches!(flags, Flags::Checked) {
+		// The value fits iff all bits from bit 31 of the result upwards equal the sign bit.
+		let negative = uint::broadcast_bit::<u64, _, _>(builder, "negative", input, 63)?;
+		let magnitude = bitwise::xor(builder, "magnitude", input, negative)?;
+		let high = uint::shr::<u64, _, _>(builder, "high", magnitude, frac_bits + 31)?;
+		builder.assert_zero("range", [high], arith_expr!([high] = high).convert_field());
+	}
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Constrains `zout = xin + yin` to not overflow as a sum of signed integers, which happens iff
+/// both operands have the same sign and the sum has the other sign.
+fn assert_no_signed_overflow<T, U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	zout: OracleId,
+) -> Result<(), anyhow::Error>
+where
+	T: uint::UInt,
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let [x_sign, y_sign, z_sign] = [("x_sign", xin), ("y_sign", yin), ("z_sign", zout)]
+		.map(|(name, id)| uint::select_bit::<T, _, _>(builder, name, id, T::bits() - 1));
+	builder.assert_zero(
+		"overflow",
+		[x_sign?, y_sign?, z_sign?],
+		arith_expr!([x, y, z] = (x - z) * (y - z)).convert_field(),
+	);
+	builder.pop_namespace();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b, TowerField};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use crate::{arithmetic::Flags, builder::ConstraintSystemBuilder, fixed_point};
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+
+	const FRAC_BITS: usize = 16;
+
+	fn column(builder: &mut ConstraintSystemBuilder<U, F>, name: &str, values: &[i32]) -> OracleId {
+		let log_rows = values.len().ilog2() as usize + 5;
+		let id = builder.add_committed(name, log_rows, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(id)
+				.as_mut_slice::<i32>()
+				.copy_from_slice(values);
+		}
+		id
+	}
+
+	fn values(builder: &mut ConstraintSystemBuilder<U, F>, id: OracleId) -> Vec<i32> {
+		let witness = builder.witness().unwrap();
+		witness
+			.get::<BinaryField1b>(id)
+			.unwrap()
+			.as_slice::<i32>()
+			.to_vec()
+	}
+
+	fn random_values(rng: &mut StdRng, bound: i32) -> Vec<i32> {
+		(0..1 << 6).map(|_| rng.gen_range(-bound..=bound)).collect()
+	}
+
+	fn is_valid(mut builder: ConstraintSystemBuilder<U, F>) -> bool {
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).is_ok()
+	}
+
+	fn fixed_mul(x: i32, y: i32) -> i32 {
+		((x as i64 * y as i64) >> FRAC_BITS) as i32
+	}
+
+	#[test]
+	fn test_add_mul_relu_clamp() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let xs = random_values(&mut rng, 100 << FRAC_BITS);
+		let ys = random_values(&mut rng, 100 << FRAC_BITS);
+		let x = column(&mut builder, "x", &xs);
+		let y = column(&mut builder, "y", &ys);
+
+		let sum = fixed_point::add(&mut builder, "add", x, y, Flags::Checked).unwrap();
+		let product =
+			fixed_point::mul(&mut builder, "mul", x, y, FRAC_BITS, Flags::Checked).unwrap();
+		let relu = fixed_point::relu(&mut builder, "relu", x).unwrap();
+		let (min, max) = (-(50 << FRAC_BITS), 20 << FRAC_BITS);
+		let clamp = fixed_point::clamp(&mut builder, "clamp", x, min, max).unwrap();
+
+		for (i, (&x, &y)) in xs.iter().zip(&ys).enumerate() {
+			assert_eq!(values(&mut builder, sum)[i], x + y);
+			assert_eq!(values(&mut builder, product)[i], fixed_mul(x, y));
+			assert_eq!(values(&mut builder, relu)[i], x.max(0));
+			assert_eq!(values(&mut builder, clamp)[i], x.clamp(min, max));
+		}
+		assert!(is_valid(builder));
+	}
+
+	#[test]
+	fn test_dot_product() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let xs = (0..4)
+			.map(|_| random_values(&mut rng, 10 << FRAC_BITS))
+			.collect::<Vec<_>>();
+		let ys = (0..4)
+			.map(|_| random_values(&mut rng, 10 << FRAC_BITS))
+			.collect::<Vec<_>>();
+		let x = xs
+			.iter()
+			.enumerate()
+			.map(|(i, xs)| column(&mut builder, &format!("x{i}"), xs))
+			.collect::<Vec<_>>();
+		let y = ys
+			.iter()
+			.enumerate()
+			.map(|(i, ys)| column(&mut builder, &format!("y{i}"), ys))
+			.collect::<Vec<_>>();
+
+		let dot = fixed_point::dot_product(&mut builder, "dot", &x, &y, FRAC_BITS, Flags::Checked)
+			.unwrap();
+		for (row, &result) in values(&mut builder, dot).iter().enumerate() {
+			let expected = (0..4)
+				.map(|i| xs[i][row] as i64 * ys[i][row] as i64)
+				.sum::<i64>();
+			assert_eq!(result, (expected >> FRAC_BITS) as i32);
+		}
+		assert!(is_valid(builder));
+	}
+
+	#[test]
+	fn test_checked_overflow_rejected() {
+		let big = vec![i32::MAX / 2 + 1; 4];
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let x = column(&mut builder, "x", &big);
+		fixed_point::add(&mut builder, "add", x, x, Flags::Checked).unwrap();
+		assert!(!is_valid(builder));
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let x = column(&mut builder, "x", &big);
+		fixed_point::mul(&mut builder, "mul", x, x, FRAC_BITS, Flags::Checked).unwrap();
+		assert!(!is_valid(builder));
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let x = column(&mut builder, "x", &big);
+		fixed_point::mul(&mut builder, "mul", x, x, FRAC_BITS, Flags::Unchecked).unwrap();
+		assert!(is_valid(builder));
+	}
+}
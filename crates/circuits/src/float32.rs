@@ -0,0 +1,578 @@
+// Copyright 2025 Irreducible Inc.
+
+//! IEEE-754 binary32 arithmetic on columns of `u32` bit patterns.
+//!
+//! Operands and results use the `u32` column layout of [`crate::arithmetic::u32`], holding the
+//! bit patterns of `f32` values. Results are rounded to nearest, ties to even.
+//!
+//! The gadgets support zeros and normal numbers. Subnormal, infinite and NaN operands, as well as
+//! results which overflow or underflow the normal range, make the constraints unsatisfiable.
+//!
+//! Internally every value is widened to 64 bits, which leaves room for the 48-bit significand
+//! product and for the guard, round and sticky bits of the aligned addition.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, BinaryField64b, ExtensionField,
+	Field, TowerField,
+};
+use binius_macros::arith_expr;
+use binius_utils::rayon::prelude::*;
+use bytemuck::Pod;
+
+use crate::{
+	arithmetic::{uint, Flags},
+	bitwise,
+	builder::ConstraintSystemBuilder,
+	transparent,
+};
+
+/// Number of explicitly stored significand bits.
+const MANTISSA_BITS: usize = 23;
+
+/// Adds two columns of `f32` values.
+pub fn add<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	builder.push_namespace(name);
+	let a = uint::cast::<u32, u64, _, _>(builder, "a", xin)?;
+	let b = uint::cast::<u32, u64, _, _>(builder, "b", yin)?;
+
+	// Order the operands by magnitude, which for non-negative floats is the integer order.
+	let a_magnitude = magnitude(builder, "a_magnitude", a)?;
+	let b_magnitude = magnitude(builder, "b_magnitude", b)?;
+	let magnitude_diff = uint::sub::<u64, _, _>(
+		builder,
+		"magnitude_diff",
+		a_magnitude,
+		b_magnitude,
+		Flags::Unchecked,
+	)?;
+	let swap = uint::broadcast_bit::<u64, _, _>(builder, "swap", magnitude_diff, 63)?;
+	let x = uint::select::<u64, _, _>(builder, "x", swap, a, b)?;
+	let y = uint::select::<u64, _, _>(builder, "y", swap, b, a)?;
+	let x = unpack(builder, "x_unpacked", x)?;
+	let y = unpack(builder, "y_unpacked", y)?;
+
+	// Align the smaller significand, keeping guard and round bits and a sticky bit for everything
+	// shifted out further.
+	let exponent_diff =
+		uint::sub::<u64, _, _>(builder, "exponent_diff", x.exponent, y.exponent, Flags::Unchecked)?;
+	let x_significand = uint::shl::<u64, _, _>(builder, "x_significand", x.significand, 3)?;
+	let y_significand = uint::shl::<u64, _, _>(builder, "y_significand", y.significand, 3)?;
+	let y_shifted = shift_var(
+		builder,
+		"y_shifted",
+		y_significand,
+		exponent_diff,
+		8,
+		ShiftVariant::LogicalRight,
+	)?;
+	let y_kept =
+		shift_var(builder, "y_kept", y_shifted, exponent_diff, 8, ShiftVariant::LogicalLeft)?;
+	let y_lost = bitwise::xor(builder, "y_lost", y_significand, y_kept)?;
+	let sticky = nonzero(builder, "sticky", y_lost, MANTISSA_BITS + 4)?;
+	let y_aligned = bitwise::or(builder, "y_aligned", y_shifted, sticky)?;
+
+	let signs = bitwise::xor(builder, "signs", x.sign, y.sign)?;
+	let subtract = uint::broadcast_bit::<u64, _, _>(builder, "subtract", signs, 0)?;
+	let sum = uint::add::<u64, _, _>(builder, "sum", x_significand, y_aligned, Flags::Unchecked)?;
+	let diff = uint::sub::<u64, _, _>(builder, "diff", x_significand, y_aligned, Flags::Unchecked)?;
+	let significand = uint::select::<u64, _, _>(builder, "significand", subtract, sum, diff)?;
+
+	// Normalize the leading bit to position 27 with a hinted left shift.
+	let log_rows = builder.log_rows([significand])?;
+	let shift = builder.add_committed("shift", log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness
+				.new_column::<BinaryField1b>(shift)
+				.as_mut_slice::<u64>(),
+			witness.get::<BinaryField1b>(significand)?.as_slice::<u64>(),
+		)
+			.into_par_iter()
+			.for_each(|(shift, &significand)| {
+				*shift = match significand {
+					0 => 0,
+					_ => significand.leading_zeros() as u64 - 36,
+				};
+			});
+	}
+	let shift_high = uint::shr::<u64, _, _>(builder, "shift_high", shift, 5)?;
+	assert_zero(builder, "shift_range", shift_high);
+	let normalized =
+		shift_var(builder, "normalized", significand, shift, 5, ShiftVariant::LogicalLeft)?;
+	let normalized_high = uint::shr::<u64, _, _>(builder, "normalized_high", normalized, 28)?;
+	assert_zero(builder, "normalized_range", normalized_high);
+	let leading_bit = uint::shr::<u64, _, _>(builder, "leading_bit", normalized, 27)?;
+	let nonzero_word = nonzero(builder, "nonzero", significand, 28)?;
+	builder.assert_zero(
+		"leading_bit",
+		[leading_bit, nonzero_word],
+		arith_expr!([bit, nonzero] = (bit - 1) * nonzero).convert_field(),
+	);
+
+	let (mantissa, carry) = round(builder, "round", normalized, 4)?;
+	let exponent =
+		uint::add::<u64, _, _>(builder, "exponent", x.exponent, carry, Flags::Unchecked)?;
+	let log_count = log_rows - 6;
+	let one = uint::constant::<u64, _, _>(builder, "one", log_count, 1)?;
+	let exponent =
+		uint::add::<u64, _, _>(builder, "exponent_inc", exponent, one, Flags::Unchecked)?;
+	let exponent =
+		uint::sub::<u64, _, _>(builder, "exponent_norm", exponent, shift, Flags::Unchecked)?;
+
+	// An exact zero is positive unless both operands are negative.
+	let nonzero = uint::broadcast_bit::<u64, _, _>(builder, "nonzero_mask", nonzero_word, 0)?;
+	let zero_sign = bitwise::and(builder, "zero_sign", x.sign, y.sign)?;
+	let output = pack(builder, "output", x.sign, exponent, mantissa, nonzero, zero_sign)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Multiplies two columns of `f32` values.
+pub fn mul<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	builder.push_namespace(name);
+	let a = uint::cast::<u32, u64, _, _>(builder, "a", xin)?;
+	let b = uint::cast::<u32, u64, _, _>(builder, "b", yin)?;
+	let x = unpack(builder, "x_unpacked", a)?;
+	let y = unpack(builder, "y_unpacked", b)?;
+	let sign = bitwise::xor(builder, "sign", x.sign, y.sign)?;
+
+	// The product of two 24-bit significands has its leading bit at position 46 or 47.
+	let product = uint::mul_low_bits::<u64, _, _>(
+		builder,
+		"product",
+		x.significand,
+		y.significand,
+		MANTISSA_BITS + 1,
+		Flags::Unchecked,
+	)?;
+	let top = uint::shr::<u64, _, _>(builder, "top", product, 47)?;
+	let top_mask = uint::broadcast_bit::<u64, _, _>(builder, "top_mask", top, 0)?;
+	let product_shl = uint::shl::<u64, _, _>(builder, "product_shl", product, 1)?;
+	let normalized =
+		uint::select::<u64, _, _>(builder, "normalized", top_mask, product_shl, product)?;
+	let (mantissa, carry) = round(builder, "round", normalized, 24)?;
+
+	let exponent =
+		uint::add::<u64, _, _>(builder, "exponent", x.exponent, y.exponent, Flags::Unchecked)?;
+	let exponent =
+		uint::add::<u64, _, _>(builder, "exponent_top", exponent, top, Flags::Unchecked)?;
+	let exponent =
+		uint::add::<u64, _, _>(builder, "exponent_carry", exponent, carry, Flags::Unchecked)?;
+	let log_count = builder.log_rows([exponent])? - 6;
+	let bias = uint::constant::<u64, _, _>(builder, "bias", log_count, 127u64.wrapping_neg())?;
+	let exponent =
+		uint::add::<u64, _, _>(builder, "exponent_unbiased", exponent, bias, Flags::Unchecked)?;
+
+	let nonzero = bitwise::and(builder, "nonzero", x.nonzero, y.nonzero)?;
+	let output = pack(builder, "output", sign, exponent, mantissa, nonzero, sign)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// The fields of a zero or normal `f32` value held in a `u64` column.
+struct Unpacked {
+	/// The sign in the lowest bit.
+	sign: OracleId,
+	/// The biased exponent.
+	exponent: OracleId,
+	/// The significand including the implicit leading bit, zero for zeros.
+	significand: OracleId,
+	/// All ones for nonzero values and zero for zeros.
+	nonzero: OracleId,
+}
+
+fn unpack<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<Unpacked, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField64b> + Pod,
+	F: TowerField + ExtensionField<BinaryField64b>,
+{
+	builder.push_namespace(name);
+	let sign = uint::shr::<u64, _, _>(builder, "sign", input, 31)?;
+	let exponent = uint::shl::<u64, _, _>(builder, "exponent_shl", input, 33)?;
+	let exponent = uint::shr::<u64, _, _>(builder, "exponent", exponent, 56)?;
+	let mantissa = low_bits(builder, "mantissa", input, MANTISSA_BITS)?;
+
+	// Reject infinities and NaNs, whose exponent is all ones.
+	let log_count = builder.log_rows([input])? - 6;
+	let one = uint::constant::<u64, _, _>(builder, "one", log_count, 1)?;
+	let exponent_inc =
+		uint::add::<u64, _, _>(builder, "exponent_inc", exponent, one, Flags::Unchecked)?;
+	let exponent_inc_high = uint::shr::<u64, _, _>(builder, "exponent_inc_high", exponent_inc, 8)?;
+	assert_zero(builder, "finite", exponent_inc_high);
+
+	// Reject subnormals, whose exponent is zero but mantissa is not.
+	let nonzero_word = nonzero(builder, "nonzero_word", exponent, 8)?;
+	let nonzero = uint::broadcast_bit::<u64, _, _>(builder, "nonzero", nonzero_word, 0)?;
+	builder.assert_zero(
+		"normal",
+		[mantissa, nonzero],
+		arith_expr!([mantissa, nonzero] = mantissa * (nonzero - 1)).convert_field(),
+	);
+
+	let implicit_bit =
+		uint::shl::<u64, _, _>(builder, "implicit_bit", nonzero_word, MANTISSA_BITS)?;
+	let significand = bitwise::xor(builder, "significand", mantissa, implicit_bit)?;
+	builder.pop_namespace();
+	Ok(Unpacked {
+		sign,
+		exponent,
+		significand,
+		nonzero,
+	})
+}
+
+/// Rounds a value with its leading bit at position `23 + extra_bits` to a 24-bit significand.
+///
+/// Returns the stored 23 mantissa bits and a word which is one iff rounding carried into the next
+/// power of two, in which case the mantissa is zero.
+fn round<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	extra_bits: usize,
+) -> Result<(OracleId, OracleId), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField64b> + Pod,
+	F: TowerField + ExtensionField<BinaryField64b>,
+{
+	builder.push_namespace(name);
+	let kept = uint::shr::<u64, _, _>(builder, "kept", input, extra_bits)?;
+	let lsb = bit(builder, "lsb", input, extra_bits)?;
+	let guard = bit(builder, "guard", input, extra_bits - 1)?;
+	let rest = low_bits(builder, "rest", input, extra_bits - 1)?;
+	let sticky = nonzero(builder, "sticky", rest, extra_bits - 1)?;
+
+	// Round up above the halfway point, and to even at the halfway point.
+	let tie_break = bitwise::or(builder, "tie_break", sticky, lsb)?;
+	let round_up = bitwise::and(builder, "round_up", guard, tie_break)?;
+	let rounded = uint::add::<u64, _, _>(builder, "rounded", kept, round_up, Flags::Unchecked)?;
+	let carry = uint::shr::<u64, _, _>(builder, "carry", rounded, MANTISSA_BITS + 1)?;
+	let mantissa = low_bits(builder, "mantissa", rounded, MANTISSA_BITS)?;
+	builder.pop_namespace();
+	Ok((mantissa, carry))
+}
+
+/// Assembles the `u32` column of `f32` values from their fields, or of signed zeros where
+/// `nonzero` is zero.
+///
+/// The biased exponent is checked to be in the normal range wherever `nonzero` is set.
+fn pack<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	sign: OracleId,
+	exponent: OracleId,
+	mantissa: OracleId,
+	nonzero: OracleId,
+	zero_sign: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ Pod,
+	F: TowerField + ExtensionField<BinaryField32b> + ExtensionField<BinaryField64b>,
+{
+	builder.push_namespace(name);
+
+	// The exponent must be between 1 and 254, so neither exponent - 1 nor exponent + 1 may leave
+	// the range of 8-bit integers.
+	let log_count = builder.log_rows([exponent])? - 6;
+	let minus_one = uint::constant::<u64, _, _>(builder, "minus_one", log_count, u64::MAX)?;
+	let one = uint::constant::<u64, _, _>(builder, "one", log_count, 1)?;
+	let exponent_dec =
+		uint::add::<u64, _, _>(builder, "exponent_dec", exponent, minus_one, Flags::Unchecked)?;
+	let exponent_inc =
+		uint::add::<u64, _, _>(builder, "exponent_inc", exponent, one, Flags::Unchecked)?;
+	let underflow = uint::shr::<u64, _, _>(builder, "underflow", exponent_dec, 8)?;
+	let overflow = uint::shr::<u64, _, _>(builder, "overflow", exponent_inc, 8)?;
+	for (name, out_of_range) in [("underflow", underflow), ("overflow", overflow)] {
+		builder.assert_zero(
+			name,
+			[out_of_range, nonzero],
+			arith_expr!([out_of_range, nonzero] = out_of_range * nonzero).convert_field(),
+		);
+	}
+
+	let sign = uint::shl::<u64, _, _>(builder, "sign", sign, 31)?;
+	let exponent = uint::shl::<u64, _, _>(builder, "exponent", exponent, MANTISSA_BITS)?;
+	let value = bitwise::xor(builder, "sign_exponent", sign, exponent)?;
+	let value = bitwise::xor(builder, "value", value, mantissa)?;
+	let zero = uint::shl::<u64, _, _>(builder, "zero", zero_sign, 31)?;
+	let value = uint::select::<u64, _, _>(builder, "select", nonzero, zero, value)?;
+	let output = uint::cast::<u64, u32, _, _>(builder, "cast", value)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Shifts every value by the amount in the corresponding value of `amount`, which must be below
+/// `2^amount_bits`, with a barrel shifter.
+fn shift_var<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	amount: OracleId,
+	amount_bits: usize,
+	variant: ShiftVariant,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input, amount])?;
+	let mut output = input;
+	for k in 0..amount_bits {
+		let offset = 1 << k;
+		let shifted = if offset < 64 {
+			match variant {
+				ShiftVariant::LogicalLeft => {
+					uint::shl::<u64, _, _>(builder, format!("shifted{k}"), output, offset)?
+				}
+				ShiftVariant::LogicalRight => {
+					uint::shr::<u64, _, _>(builder, format!("shifted{k}"), output, offset)?
+				}
+				ShiftVariant::CircularLeft | ShiftVariant::CircularRight => {
+					anyhow::bail!("circular shifts by a variable amount are not supported")
+				}
+			}
+		} else {
+			transparent::constant(builder, format!("shifted{k}"), log_rows, BinaryField1b::ZERO)?
+		};
+		let amount_bit = uint::broadcast_bit::<u64, _, _>(builder, format!("bit{k}"), amount, k)?;
+		output =
+			uint::select::<u64, _, _>(builder, format!("stage{k}"), amount_bit, output, shifted)?;
+	}
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// A word which is one iff the value, which must be below `2^bits`, is nonzero.
+fn nonzero<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	bits: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField64b> + Pod,
+	F: TowerField + ExtensionField<BinaryField64b>,
+{
+	// Adding 2^bits - 1 carries into bit `bits` iff the value is nonzero.
+	builder.push_namespace(name);
+	let log_count = builder.log_rows([input])? - 6;
+	let ones = uint::constant::<u64, _, _>(builder, "ones", log_count, (1 << bits) - 1)?;
+	let sum = uint::add::<u64, _, _>(builder, "sum", input, ones, Flags::Unchecked)?;
+	let output = uint::shr::<u64, _, _>(builder, "output", sum, bits)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// A word holding bit `index` of every value.
+fn bit<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	index: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let shifted = uint::shl::<u64, _, _>(builder, "shl", input, 63 - index)?;
+	let output = uint::shr::<u64, _, _>(builder, "output", shifted, 63)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// The lowest `bits` bits of every value.
+fn low_bits<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	bits: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let shifted = uint::shl::<u64, _, _>(builder, "shl", input, 64 - bits)?;
+	let output = uint::shr::<u64, _, _>(builder, "output", shifted, 64 - bits)?;
+	builder.pop_namespace();
+	Ok(output)
+}
+
+fn assert_zero<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	builder.assert_zero(name, [input], arith_expr!([x] = x).convert_field());
+}
+
+/// The value with its sign bit cleared.
+fn magnitude<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	low_bits(builder, name, input, 31)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b, TowerField};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use crate::{builder::ConstraintSystemBuilder, float32};
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+	type Gadget = fn(
+		&mut ConstraintSystemBuilder<U, F>,
+		&'static str,
+		OracleId,
+		OracleId,
+	) -> Result<OracleId, anyhow::Error>;
+
+	fn column(builder: &mut ConstraintSystemBuilder<U, F>, name: &str, values: &[f32]) -> OracleId {
+		let log_rows = values.len().ilog2() as usize + 5;
+		let id = builder.add_committed(name, log_rows, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(id)
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.zip(values)
+				.for_each(|(bits, value)| *bits = value.to_bits());
+		}
+		id
+	}
+
+	/// The bit pattern of an IEEE result, or `None` if the operation is not supported.
+	fn expected(result: f32, operands: [f32; 2]) -> Option<u32> {
+		let supported = |value: f32| value == 0.0 || value.is_normal();
+		(operands.into_iter().all(supported) && supported(result)).then_some(result.to_bits())
+	}
+
+	/// Operand pairs covering cancellation, rounding ties, carries and signed zeros.
+	fn operands() -> Vec<(f32, f32)> {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut operands = vec![
+			(1.0, -1.0),
+			(-0.0, -0.0),
+			(0.0, -0.0),
+			(0.0, 3.5),
+			(-2.5, 0.0),
+			(1.0, f32::EPSILON / 2.0),
+			(1.0 + f32::EPSILON, f32::EPSILON / 2.0),
+			(1.0, -f32::EPSILON / 4.0),
+			(f32::from_bits(0x3fffffff), f32::from_bits(0x3fffffff)),
+			(1.0e30, 1.0e-30),
+			(-1.0e-30, 1.0e30),
+			(16777215.0, 1.0),
+			(1.5, -1.25),
+			(3.0, 1.0 / 3.0),
+		];
+		while operands.len() < 1 << 7 {
+			let random = |rng: &mut StdRng| {
+				let exponent = rng.gen_range(100u32..156);
+				f32::from_bits(rng.gen::<u32>() & 0x807fffff | exponent << 23)
+			};
+			operands.push((random(&mut rng), random(&mut rng)));
+		}
+		operands
+	}
+
+	fn check(gadget: Gadget, op: fn(f32, f32) -> f32, operands: &[(f32, f32)]) -> bool {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let xs = operands.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+		let ys = operands.iter().map(|&(_, y)| y).collect::<Vec<_>>();
+		let x = column(&mut builder, "x", &xs);
+		let y = column(&mut builder, "y", &ys);
+		let z = gadget(&mut builder, "op", x, y).unwrap();
+
+		let results = builder
+			.witness()
+			.unwrap()
+			.get::<BinaryField1b>(z)
+			.unwrap()
+			.as_slice::<u32>()
+			.to_vec();
+		for (&(x, y), &result) in operands.iter().zip(&results) {
+			if let Some(expected) = expected(op(x, y), [x, y]) {
+				assert_eq!(result, expected, "{x:e} op {y:e}");
+			}
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).is_ok()
+	}
+
+	#[test]
+	fn test_add() {
+		assert!(check(float32::add, |x, y| x + y, &operands()));
+	}
+
+	#[test]
+	fn test_mul() {
+		assert!(check(float32::mul, |x, y| x * y, &operands()));
+	}
+
+	#[test]
+	fn test_unsupported_values_rejected() {
+		for (x, y) in [(f32::MAX, f32::MAX), (f32::INFINITY, 1.0), (f32::NAN, 1.0)] {
+			assert!(!check(float32::add, |x, y| x + y, &[(x, y), (1.0, 1.0)]), "{x:e} + {y:e}");
+		}
+		for (x, y) in [
+			(f32::MIN_POSITIVE, 0.5),
+			(f32::MAX, 2.0),
+			(f32::from_bits(1), 1.0),
+		] {
+			assert!(!check(float32::mul, |x, y| x * y, &[(x, y), (1.0, 1.0)]), "{x:e} * {y:e}");
+		}
+	}
+}
@@ -0,0 +1,876 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Golden test vectors for gadgets.
+//!
+//! Every gadget in [`GADGETS`] builds itself on fixed inputs and names the input and output
+//! columns to record. The test renders the columns, which must satisfy the constraints, along
+//! with the size of the constraint system into a text file under `golden/` and compares it with
+//! the checked-in file. A change to a gadget's semantics or a blow-up of its constraint count
+//! thus fails the test with the first differing line.
+//!
+//! After an intended change, regenerate the files with
+//!
+//! ```text
+//! BINIUS_UPDATE_GOLDEN=1 cargo test -p binius_circuits golden
+//! ```
+//!
+//! and review the diff.
+//!
+//! Some modules have no vectors of their own:
+//!
+//! - `unconstrained`, `u32fib` and `groestl_p_permutation` draw their inputs from the thread RNG,
+//!   so their columns differ between runs. The Grøstl permutation also needs a top field over the
+//!   AES tower.
+//! - `transparent` only adds columns that are public by construction.
+//! - `automaton`, `plain_lookup`, `message_schedule`, `pack`, `permutation` and the remaining lasso
+//!   building blocks are covered through the gadgets built on them, such as `regex_match`, `json`,
+//!   `sha3`, `sort`, `poly1305` and `lasso_u32add`.
+//! - `builder`, `trace`, `collatz` and `examples` are not gadgets.
+
+use std::{array, fmt::Write as _, fs, path::PathBuf};
+
+use anyhow::Context as _;
+use binius_core::{
+	constraint_system::{channel::Boundary, validate::validate_witness},
+	oracle::OracleId,
+};
+use binius_field::{
+	arch::OptimalUnderlier, as_packed_field::PackScalar, BinaryField128b, BinaryField1b,
+	BinaryField32b as B32, BinaryField64b as B64, BinaryField8b as B8, ExtensionField, TowerField,
+};
+use bytemuck::Pod;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+	arithmetic::{self, Flags},
+	bitwise,
+	builder::{BitOrder, ConstraintSystemBuilder},
+	chacha20, fixed_point, float32, hmac, json,
+	keccakf::{self, KeccakfState},
+	lanes, lasso, linalg, mux, poly1305, range_check, regex,
+	sha256::sha256,
+	sha3, sort, tower,
+	utf8::{self, Charset},
+	vision,
+};
+
+type U = OptimalUnderlier;
+type F = BinaryField128b;
+
+/// The environment variable that makes the test rewrite the golden files instead of checking them.
+const UPDATE_ENV: &str = "BINIUS_UPDATE_GOLDEN";
+
+type Circuit = fn(&mut ConstraintSystemBuilder<U, F>, &mut Vector) -> Result<(), anyhow::Error>;
+
+/// The registered gadgets, by the name of their golden file.
+const GADGETS: &[(&str, Circuit)] = &[
+	("u32_add", u32_add),
+	("u32_add_with_carry", u32_add_with_carry),
+	("u32_sub_saturating", u32_sub_saturating),
+	("u8_mul", u8_mul),
+	("u64_add_checked", u64_add_checked),
+	("bitwise_or", bitwise_or),
+	("sha256", sha256_compress),
+	("u8_add_saturating", u8_add_saturating),
+	("u16_mul", u16_mul),
+	("u32_mul_const", u32_mul_const),
+	("u32_shifts", u32_shifts),
+	("u32_bit_counts", u32_bit_counts),
+	("u32_bswap", u32_bswap),
+	("u64_rotr", u64_rotr),
+	("bitwise_and_xor", bitwise_and_xor),
+	("float32_add", float32_add),
+	("float32_mul", float32_mul),
+	("fixed_point_mul", fixed_point_mul),
+	("fixed_point_relu_clamp", fixed_point_relu_clamp),
+	("fixed_point_dot_product", fixed_point_dot_product),
+	("tower_mul_inverse", tower_mul_inverse),
+	("lanes_add", lanes_add),
+	("mux_choose", mux_choose),
+	("chacha20", chacha20_block),
+	("poly1305", poly1305),
+	("keccakf", keccakf),
+	("sha3_256", sha3_256),
+	("hmac_sha256", hmac_sha256),
+	("vision", vision_permutation),
+	("sort", sort),
+	("linalg_matmul", linalg_matmul),
+	("range_check", range_check),
+	("utf8_text", utf8_text),
+	("json_strings", json_strings),
+	("regex_match", regex_match),
+	("lasso_u32add", lasso_u32add),
+];
+
+/// The columns recorded for one gadget, and the boundaries its statement needs.
+#[derive(Default)]
+struct Vector {
+	inputs: Vec<(String, Vec<u8>)>,
+	outputs: Vec<(String, Vec<u8>)>,
+	boundaries: Vec<Boundary<F>>,
+}
+
+impl Vector {
+	fn input<FS: TowerField>(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: impl ToString,
+		id: OracleId,
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		self.inputs
+			.push((name.to_string(), column_bytes::<FS>(builder, id)?));
+		Ok(())
+	}
+
+	fn output<FS: TowerField>(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: impl ToString,
+		id: OracleId,
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		self.outputs
+			.push((name.to_string(), column_bytes::<FS>(builder, id)?));
+		Ok(())
+	}
+
+	/// Records the columns `ids` as inputs named `prefix` followed by their index.
+	fn input_columns<FS: TowerField>(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		prefix: &str,
+		ids: &[OracleId],
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		for (i, &id) in ids.iter().enumerate() {
+			self.input::<FS>(builder, format!("{prefix}{i}"), id)?;
+		}
+		Ok(())
+	}
+
+	/// Records the columns `ids` as outputs named `prefix` followed by their index.
+	fn output_columns<FS: TowerField>(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		prefix: &str,
+		ids: &[OracleId],
+	) -> Result<(), anyhow::Error>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		for (i, &id) in ids.iter().enumerate() {
+			self.output::<FS>(builder, format!("{prefix}{i}"), id)?;
+		}
+		Ok(())
+	}
+}
+
+fn column_bytes<FS: TowerField>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	id: OracleId,
+) -> Result<Vec<u8>, anyhow::Error>
+where
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	let witness = builder.witness().context("golden vectors need a witness")?;
+	Ok(witness
+		.get::<FS>(id)?
+		.to_bytes(BitOrder::LsbFirst)
+		.into_owned())
+}
+
+/// Builds the gadget, validates its witness and renders its golden file.
+fn render(name: &str, circuit: Circuit) -> Result<String, anyhow::Error> {
+	let allocator = bumpalo::Bump::new();
+	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let mut vector = Vector::default();
+	circuit(&mut builder, &mut vector)?;
+	let witness = builder.take_witness()?;
+	let constraint_system = builder.build()?;
+	validate_witness(&constraint_system, &vector.boundaries, &witness)?;
+
+	let committed = constraint_system
+		.oracles
+		.iter()
+		.filter(|oracle| {
+			matches!(oracle, binius_core::oracle::MultilinearPolyOracle::Committed { .. })
+		})
+		.count();
+	let constraints = constraint_system
+		.table_constraints
+		.iter()
+		.map(|set| set.constraints.len())
+		.sum::<usize>();
+
+	let mut out = String::new();
+	writeln!(out, "# Golden vectors of {name}, regenerate with {UPDATE_ENV}=1")?;
+	writeln!(out, "oracles {}", constraint_system.oracles.size())?;
+	writeln!(out, "committed {committed}")?;
+	writeln!(out, "constraint_sets {}", constraint_system.table_constraints.len())?;
+	writeln!(out, "constraints {constraints}")?;
+	writeln!(out, "non_zero {}", constraint_system.non_zero_oracle_ids.len())?;
+	writeln!(out, "flushes {}", constraint_system.flushes.len())?;
+	for (kind, columns) in [("input", &vector.inputs), ("output", &vector.outputs)] {
+		for (name, bytes) in columns {
+			write!(out, "{kind} {name} ")?;
+			for byte in bytes {
+				write!(out, "{byte:02x}")?;
+			}
+			writeln!(out)?;
+		}
+	}
+	Ok(out)
+}
+
+fn golden_path(name: &str) -> PathBuf {
+	PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+		.join("golden")
+		.join(format!("{name}.golden"))
+}
+
+/// Adds a committed column of `T` integers with random values.
+fn random_column<T: Pod>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	rng: &mut StdRng,
+	name: &str,
+	log_count: usize,
+) -> OracleId
+where
+	rand::distributions::Standard: rand::distributions::Distribution<T>,
+{
+	let log_bits = (8 * size_of::<T>()).ilog2() as usize;
+	let id = builder.add_committed(name, log_count + log_bits, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		witness
+			.new_column::<BinaryField1b>(id)
+			.as_mut_slice::<T>()
+			.iter_mut()
+			.for_each(|value| *value = rng.gen());
+	}
+	id
+}
+
+fn binary_op<T: Pod>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+	seed: u64,
+	op: impl FnOnce(
+		&mut ConstraintSystemBuilder<U, F>,
+		OracleId,
+		OracleId,
+	) -> Result<OracleId, anyhow::Error>,
+) -> Result<(), anyhow::Error>
+where
+	rand::distributions::Standard: rand::distributions::Distribution<T>,
+{
+	let mut rng = StdRng::seed_from_u64(seed);
+	let x = random_column::<T>(builder, &mut rng, "x", 3);
+	let y = random_column::<T>(builder, &mut rng, "y", 3);
+	let z = op(builder, x, y)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.input::<BinaryField1b>(builder, "y", y)?;
+	vector.output::<BinaryField1b>(builder, "z", z)
+}
+
+fn u32_add(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<u32>(builder, vector, 0, |builder, x, y| {
+		arithmetic::u32::add(builder, "add", x, y, Flags::Unchecked)
+	})
+}
+
+fn u32_add_with_carry(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(1);
+	let x = random_column::<u32>(builder, &mut rng, "x", 3);
+	let y = random_column::<u32>(builder, &mut rng, "y", 3);
+	let (z, carry) = arithmetic::u32::add_with_carry(builder, "adc", x, y)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.input::<BinaryField1b>(builder, "y", y)?;
+	vector.output::<BinaryField1b>(builder, "z", z)?;
+	vector.output::<BinaryField1b>(builder, "carry", carry)
+}
+
+fn u32_sub_saturating(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<u32>(builder, vector, 2, |builder, x, y| {
+		arithmetic::u32::sub(builder, "sub", x, y, Flags::Saturating)
+	})
+}
+
+fn u8_mul(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<u8>(builder, vector, 3, |builder, x, y| {
+		arithmetic::u8::mul(builder, "mul", x, y, Flags::Unchecked)
+	})
+}
+
+fn u64_add_checked(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	// Halve the random values so that the checked sum does not overflow.
+	binary_op::<u64>(builder, vector, 4, |builder, x, y| {
+		let x = arithmetic::u64::shr(builder, "x_half", x, 1)?;
+		let y = arithmetic::u64::shr(builder, "y_half", y, 1)?;
+		arithmetic::u64::add(builder, "add", x, y, Flags::Checked)
+	})
+}
+
+fn bitwise_or(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<u32>(builder, vector, 5, |builder, x, y| bitwise::or(builder, "or", x, y))
+}
+
+fn sha256_compress(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(6);
+	let input: [OracleId; 16] =
+		array::from_fn(|i| random_column::<u32>(builder, &mut rng, &format!("w{i}"), 2));
+	let output = sha256(builder, input, 2 + 5)?;
+	vector.input_columns::<BinaryField1b>(builder, "w", &input)?;
+	vector.output_columns::<BinaryField1b>(builder, "h", &output)
+}
+
+/// Adds a committed 1-bit column holding the `T` integers `values`.
+fn int_column<T: Pod>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: &str,
+	values: &[T],
+) -> OracleId {
+	let log_bits = (8 * size_of::<T>()).ilog2() as usize;
+	let log_rows = values.len().ilog2() as usize + log_bits;
+	let id = builder.add_committed(name, log_rows, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		witness
+			.new_column::<BinaryField1b>(id)
+			.as_mut_slice::<T>()
+			.copy_from_slice(values);
+	}
+	id
+}
+
+/// Adds a committed column of `FS` elements holding `values`.
+fn field_column<FS: TowerField + Pod>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: &str,
+	values: &[FS],
+) -> OracleId
+where
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	let id = builder.add_committed(name, values.len().ilog2() as usize, FS::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		witness
+			.new_column::<FS>(id)
+			.as_mut_slice::<FS>()
+			.copy_from_slice(values);
+	}
+	id
+}
+
+fn random_field_column<FS: TowerField + Pod>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	rng: &mut StdRng,
+	name: &str,
+	log_rows: usize,
+) -> OracleId
+where
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	let values = (0..1 << log_rows)
+		.map(|_| FS::random(&mut *rng))
+		.collect::<Vec<_>>();
+	field_column(builder, name, &values)
+}
+
+/// Adds a committed byte column holding `text`, padded with `padding`.
+fn text_column(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: &str,
+	text: &[u8],
+	log_rows: usize,
+	padding: u8,
+) -> OracleId {
+	let mut bytes = vec![padding; 1 << log_rows];
+	bytes[..text.len()].copy_from_slice(text);
+	field_column(builder, name, &bytes.into_iter().map(B8::new).collect::<Vec<_>>())
+}
+
+fn unary_op<T: Pod>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+	seed: u64,
+	op: impl FnOnce(&mut ConstraintSystemBuilder<U, F>, OracleId) -> Result<OracleId, anyhow::Error>,
+) -> Result<(), anyhow::Error>
+where
+	rand::distributions::Standard: rand::distributions::Distribution<T>,
+{
+	let mut rng = StdRng::seed_from_u64(seed);
+	let x = random_column::<T>(builder, &mut rng, "x", 3);
+	let z = op(builder, x)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.output::<BinaryField1b>(builder, "z", z)
+}
+
+fn u8_add_saturating(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<u8>(builder, vector, 7, |builder, x, y| {
+		arithmetic::u8::add(builder, "add", x, y, Flags::Saturating)
+	})
+}
+
+fn u16_mul(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<u16>(builder, vector, 8, |builder, x, y| {
+		arithmetic::u16::mul(builder, "mul", x, y, Flags::Unchecked)
+	})
+}
+
+fn u32_mul_const(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	unary_op::<u32>(builder, vector, 9, |builder, x| {
+		arithmetic::u32::mul_const(builder, "mul", x, 0x9e37_79b9, Flags::Unchecked)
+	})
+}
+
+fn u32_shifts(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(10);
+	let x = random_column::<u32>(builder, &mut rng, "x", 3);
+	let shl = arithmetic::u32::shl(builder, "shl", x, 13)?;
+	let shr = arithmetic::u32::shr(builder, "shr", x, 13)?;
+	let rotl = arithmetic::u32::rotl(builder, "rotl", x, 13)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.output::<BinaryField1b>(builder, "shl", shl)?;
+	vector.output::<BinaryField1b>(builder, "shr", shr)?;
+	vector.output::<BinaryField1b>(builder, "rotl", rotl)
+}
+
+fn u32_bit_counts(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	// Shift the random values by random amounts so that the counts vary.
+	let mut rng = StdRng::seed_from_u64(11);
+	let values = (0..8)
+		.map(|_| rng.gen::<u32>() >> rng.gen_range(0..32))
+		.collect::<Vec<_>>();
+	let x = int_column(builder, "x", &values);
+	let popcount = arithmetic::u32::popcount(builder, "popcount", x)?;
+	let clz = arithmetic::u32::clz(builder, "clz", x)?;
+	let ctz = arithmetic::u32::ctz(builder, "ctz", x)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.output::<BinaryField1b>(builder, "popcount", popcount)?;
+	vector.output::<BinaryField1b>(builder, "clz", clz)?;
+	vector.output::<BinaryField1b>(builder, "ctz", ctz)
+}
+
+fn u32_bswap(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	unary_op::<u32>(builder, vector, 12, |builder, x| arithmetic::u32::bswap(builder, "bswap", x))
+}
+
+fn u64_rotr(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	unary_op::<u64>(builder, vector, 13, |builder, x| arithmetic::u64::rotr(builder, "rotr", x, 17))
+}
+
+fn bitwise_and_xor(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(14);
+	let x = random_column::<u32>(builder, &mut rng, "x", 3);
+	let y = random_column::<u32>(builder, &mut rng, "y", 3);
+	let and = bitwise::and(builder, "and", x, y)?;
+	let xor = bitwise::xor(builder, "xor", x, y)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.input::<BinaryField1b>(builder, "y", y)?;
+	vector.output::<BinaryField1b>(builder, "and", and)?;
+	vector.output::<BinaryField1b>(builder, "xor", xor)
+}
+
+/// Random normal `f32` bit patterns, whose sums and products are normal as well.
+fn random_floats(rng: &mut StdRng, count: usize) -> Vec<u32> {
+	(0..count)
+		.map(|_| (rng.gen::<u32>() & 0x807f_ffff) | (rng.gen_range(100u32..156) << 23))
+		.collect()
+}
+
+fn float32_op(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+	seed: u64,
+	op: impl FnOnce(
+		&mut ConstraintSystemBuilder<U, F>,
+		OracleId,
+		OracleId,
+	) -> Result<OracleId, anyhow::Error>,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(seed);
+	let x = int_column(builder, "x", &random_floats(&mut rng, 8));
+	let y = int_column(builder, "y", &random_floats(&mut rng, 8));
+	let z = op(builder, x, y)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.input::<BinaryField1b>(builder, "y", y)?;
+	vector.output::<BinaryField1b>(builder, "z", z)
+}
+
+fn float32_add(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	float32_op(builder, vector, 15, |builder, x, y| float32::add(builder, "add", x, y))
+}
+
+fn float32_mul(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	float32_op(builder, vector, 16, |builder, x, y| float32::mul(builder, "mul", x, y))
+}
+
+fn fixed_point_mul(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<i32>(builder, vector, 17, |builder, x, y| {
+		fixed_point::mul(builder, "mul", x, y, 16, Flags::Unchecked)
+	})
+}
+
+fn fixed_point_relu_clamp(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(18);
+	let x = random_column::<i32>(builder, &mut rng, "x", 3);
+	let relu = fixed_point::relu(builder, "relu", x)?;
+	let clamped = fixed_point::clamp(builder, "clamp", x, -1 << 30, 1 << 30)?;
+	vector.input::<BinaryField1b>(builder, "x", x)?;
+	vector.output::<BinaryField1b>(builder, "relu", relu)?;
+	vector.output::<BinaryField1b>(builder, "clamp", clamped)
+}
+
+fn fixed_point_dot_product(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(19);
+	let xs: [OracleId; 2] =
+		array::from_fn(|i| random_column::<i32>(builder, &mut rng, &format!("x{i}"), 3));
+	let ys: [OracleId; 2] =
+		array::from_fn(|i| random_column::<i32>(builder, &mut rng, &format!("y{i}"), 3));
+	let dot = fixed_point::dot_product(builder, "dot", &xs, &ys, 16, Flags::Unchecked)?;
+	vector.input_columns::<BinaryField1b>(builder, "x", &xs)?;
+	vector.input_columns::<BinaryField1b>(builder, "y", &ys)?;
+	vector.output::<BinaryField1b>(builder, "z", dot)
+}
+
+fn tower_mul_inverse(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(20);
+	let x = random_field_column::<F>(builder, &mut rng, "x", 3);
+	let y = random_field_column::<F>(builder, &mut rng, "y", 3);
+	let product = tower::mul::<_, _, F>(builder, "mul", x, y)?;
+	let square = tower::square::<_, _, F>(builder, "square", x)?;
+	let inverse = tower::inverse::<_, _, F>(builder, "inverse", y)?;
+	vector.input::<F>(builder, "x", x)?;
+	vector.input::<F>(builder, "y", y)?;
+	vector.output::<F>(builder, "mul", product)?;
+	vector.output::<F>(builder, "square", square)?;
+	vector.output::<F>(builder, "inverse", inverse)
+}
+
+fn lanes_add(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(21);
+	let inputs: [OracleId; 4] =
+		array::from_fn(|i| random_column::<u32>(builder, &mut rng, &format!("in{i}"), 2));
+	let a = lanes::pack_lanes(builder, "a", &inputs[..2])?;
+	let b = lanes::pack_lanes(builder, "b", &inputs[2..])?;
+	let sum = lanes::u32add_lanes(builder, "add", a, b, 1, Flags::Unchecked)?;
+	vector.input_columns::<BinaryField1b>(builder, "in", &inputs)?;
+	vector.output::<BinaryField1b>(builder, "sum", sum)
+}
+
+fn mux_choose(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(22);
+	let selector = random_column::<u8>(builder, &mut rng, "selector", 1);
+	let if_true = random_field_column::<B32>(builder, &mut rng, "if_true", 4);
+	let if_false = random_field_column::<B32>(builder, &mut rng, "if_false", 4);
+	let output = mux::choose::<_, _, B32>(builder, "choose", selector, &[if_true], &[if_false])?;
+	vector.input::<BinaryField1b>(builder, "selector", selector)?;
+	vector.input::<B32>(builder, "if_true", if_true)?;
+	vector.input::<B32>(builder, "if_false", if_false)?;
+	vector.output::<B32>(builder, "z", output[0])
+}
+
+fn chacha20_block(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(23);
+	let key: [OracleId; 8] =
+		array::from_fn(|i| random_column::<u32>(builder, &mut rng, &format!("key{i}"), 2));
+	let counter = random_column::<u32>(builder, &mut rng, "counter", 2);
+	let nonce: [OracleId; 3] =
+		array::from_fn(|i| random_column::<u32>(builder, &mut rng, &format!("nonce{i}"), 2));
+	let block = chacha20::chacha20_block(builder, "chacha20", key, counter, nonce, 2 + 5)?;
+	vector.input_columns::<BinaryField1b>(builder, "key", &key)?;
+	vector.input::<BinaryField1b>(builder, "counter", counter)?;
+	vector.input_columns::<BinaryField1b>(builder, "nonce", &nonce)?;
+	vector.output_columns::<BinaryField1b>(builder, "block", &block)
+}
+
+fn poly1305(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(24);
+	let log_bytes = 4;
+	let key: [OracleId; 8] =
+		array::from_fn(|i| random_column::<u32>(builder, &mut rng, &format!("key{i}"), log_bytes));
+	let message: [OracleId; 20] = array::from_fn(|i| {
+		random_field_column::<B8>(builder, &mut rng, &format!("message{i}"), log_bytes)
+	});
+	let tag = poly1305::poly1305(builder, "poly1305", key, &message, log_bytes + 5)?;
+	vector.input_columns::<BinaryField1b>(builder, "key", &key)?;
+	vector.input_columns::<B8>(builder, "message", &message)?;
+	vector.output_columns::<B8>(builder, "tag", &tag)
+}
+
+fn keccakf(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(25);
+	let states = (0..1 << 3)
+		.map(|_| KeccakfState(rng.gen()))
+		.collect::<Vec<_>>();
+	let oracles = keccakf::keccakf(builder, Some(states), 3)?;
+	vector.input_columns::<B64>(builder, "lane", &oracles.input)?;
+	vector.output_columns::<B64>(builder, "lane", &oracles.output)
+}
+
+fn sha3_256(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	// The 136 message columns are determined by the seed and are not recorded.
+	let mut rng = StdRng::seed_from_u64(26);
+	let length = 200;
+	let message = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+	let columns: [OracleId; sha3::RATE_BYTES] = array::from_fn(|k| {
+		let bytes = message
+			.iter()
+			.skip(k)
+			.step_by(sha3::RATE_BYTES)
+			.copied()
+			.collect::<Vec<_>>();
+		text_column(builder, &format!("message{k}"), &bytes, 5, 0)
+	});
+	let digest = sha3::sha3_256(builder, "sha3", columns, length)?;
+	vector.boundaries.push(digest.schedule.length_boundary());
+	let lanes = digest.lanes;
+	vector.output_columns::<B64>(builder, "lane", &lanes)
+}
+
+fn hmac_sha256(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(27);
+	let key: [OracleId; 5] =
+		array::from_fn(|i| random_column::<u32>(builder, &mut rng, &format!("key{i}"), 2));
+	let message: [OracleId; 13] =
+		array::from_fn(|i| random_column::<u32>(builder, &mut rng, &format!("message{i}"), 2));
+	let mac = hmac::hmac(builder, "hmac", &key, 20, &message, 52, 2 + 5)?;
+	vector.input_columns::<BinaryField1b>(builder, "key", &key)?;
+	vector.input_columns::<BinaryField1b>(builder, "message", &message)?;
+	vector.output_columns::<BinaryField1b>(builder, "mac", &mac)
+}
+
+fn vision_permutation(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(28);
+	let state: [OracleId; 24] =
+		array::from_fn(|i| random_field_column::<B32>(builder, &mut rng, &format!("state{i}"), 2));
+	let output = vision::vision_permutation(builder, 2, state)?;
+	vector.input_columns::<B32>(builder, "state", &state)?;
+	vector.output_columns::<B32>(builder, "state", &output)
+}
+
+fn sort(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(29);
+	let keys = (0..16).map(|_| rng.gen_range(0..8)).collect::<Vec<u32>>();
+	let keys = int_column(builder, "keys", &keys);
+	let values = random_column::<u32>(builder, &mut rng, "values", 4);
+	let sorted = sort::sort_witness(builder, "sort", &[keys, values], 12)?;
+	vector.input::<BinaryField1b>(builder, "keys", keys)?;
+	vector.input::<BinaryField1b>(builder, "values", values)?;
+	vector.output::<BinaryField1b>(builder, "keys", sorted[0])?;
+	vector.output::<BinaryField1b>(builder, "values", sorted[1])
+}
+
+fn linalg_matmul(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(31);
+	let (log_rows, log_inner, log_cols) = (2, 3, 1);
+	let a = random_field_column::<B32>(builder, &mut rng, "a", log_rows + log_inner);
+	let b = random_field_column::<B32>(builder, &mut rng, "b", log_inner + log_cols);
+	let c = linalg::matmul::<_, _, B32>(builder, "matmul", a, b, log_rows, log_inner, log_cols)?;
+	vector.input::<B32>(builder, "a", a)?;
+	vector.input::<B32>(builder, "b", b)?;
+	vector.output::<B32>(builder, "c", c)
+}
+
+fn range_check(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let mut rng = StdRng::seed_from_u64(32);
+	let values = (0..1 << 5)
+		.map(|_| B32::new(rng.gen_range(0..1 << 10)))
+		.collect::<Vec<_>>();
+	let values = field_column(builder, "values", &values);
+	let boundary = range_check::range_check::<_, _, B32, 8>(builder, "range_check", values, 10)?;
+	vector.boundaries.push(boundary);
+	vector.input::<B32>(builder, "values", values)
+}
+
+fn utf8_text(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let text = "Zo\u{eb} \u{4f60}\u{597d} \u{1f44b}".as_bytes();
+	let input = text_column(builder, "text", text, 5, 0xff);
+	let boundary = utf8::assert_text::<_, _, 8>(builder, "utf8", input, text.len(), Charset::Utf8)?;
+	vector.boundaries.push(boundary);
+	vector.input::<B8>(builder, "text", input)
+}
+
+fn json_strings(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let text = br#"{"name": "Zoe", "tags": ["a", "}"]}"#;
+	let input = text_column(builder, "text", text, 6, 0);
+	let (strings, boundary) = json::strings::<_, _, 8>(builder, "strings", input, text.len())?;
+	vector.boundaries.push(boundary);
+	vector.input::<B8>(builder, "text", input)?;
+	vector.output::<B8>(builder, "strings", strings)
+}
+
+fn regex_match(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	let text = b"GET /api/users/42 HTTP/1.1";
+	let input = text_column(builder, "text", text, 5, 0);
+	let (matches, boundary) =
+		regex::regex_match::<_, _, 8>(builder, "id", r"/\d+", input, text.len())?;
+	vector.boundaries.push(boundary);
+	vector.input::<B8>(builder, "text", input)?;
+	vector.output::<B8>(builder, "matches", matches)
+}
+
+fn lasso_u32add(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	vector: &mut Vector,
+) -> Result<(), anyhow::Error> {
+	binary_op::<u32>(builder, vector, 33, |builder, x, y| {
+		lasso::u32add::<_, _, BinaryField1b, BinaryField1b>(builder, "u32add", x, y)
+	})
+}
+
+#[test]
+fn test_golden_vectors() {
+	let update = std::env::var_os(UPDATE_ENV).is_some();
+	let mut failures = Vec::new();
+	for &(name, circuit) in GADGETS {
+		let actual = render(name, circuit).unwrap_or_else(|err| panic!("{name}: {err:#}"));
+		let path = golden_path(name);
+		if update {
+			fs::create_dir_all(path.parent().expect("golden files are in a directory")).unwrap();
+			fs::write(&path, &actual).unwrap();
+			continue;
+		}
+		let Ok(expected) = fs::read_to_string(&path) else {
+			failures.push(format!("{name}: missing {}", path.display()));
+			continue;
+		};
+		if let Some((expected, actual)) = expected
+			.lines()
+			.map(Some)
+			.chain(std::iter::repeat(None))
+			.zip(actual.lines().map(Some).chain(std::iter::repeat(None)))
+			.take_while(|pair| *pair != (None, None))
+			.find(|(expected, actual)| expected != actual)
+		{
+			failures.push(format!(
+				"{name}: expected {:?}, got {:?}",
+				expected.unwrap_or("<end of file>"),
+				actual.unwrap_or("<end of file>"),
+			));
+		}
+	}
+	assert!(
+		failures.is_empty(),
+		"golden vectors differ, rerun with {UPDATE_ENV}=1 if the change is intended:\n{}",
+		failures.join("\n")
+	);
+}
@@ -0,0 +1,313 @@
+// Copyright 2025 Irreducible Inc.
+
+//! HMAC-SHA256 and HKDF gadgets, as specified by RFC 2104 and RFC 5869.
+//!
+//! The gadgets compose the SHA-256 compression function of [`crate::sha256`] and compute one
+//! instance per row. Keys, messages and outputs are given by columns of 32-bit words, as in
+//! [`crate::sha256::sha256`], with the bytes of every word in big-endian order. A string of
+//! `length` bytes takes `length.div_ceil(4)` words, and the bytes of the last word past the string
+//! must be zero, which is constrained.
+//!
+//! The lengths are fixed by the constraint system, so the padding of the hashed messages and the
+//! ipad and opad masks of the keys are added to the words as constants.
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, TowerField,
+};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+use itertools::izip;
+
+use crate::{
+	arithmetic,
+	builder::ConstraintSystemBuilder,
+	sha256::{compress, u32const_repeating, INIT},
+};
+
+type B1 = BinaryField1b;
+
+const BLOCK_BYTES: usize = 64;
+const DIGEST_WORDS: usize = 8;
+const IPAD: u32 = 0x36363636;
+const OPAD: u32 = 0x5c5c5c5c;
+
+/// A key prepared for HMAC, see [`hmac_key`].
+#[derive(Debug, Clone, Copy)]
+pub struct HmacKey {
+	/// The state after absorbing the key masked with the ipad.
+	pub inner: [OracleId; DIGEST_WORDS],
+	/// The state after absorbing the key masked with the opad.
+	pub outer: [OracleId; DIGEST_WORDS],
+}
+
+/// Returns `word ^ constant`, where a missing word is zero.
+fn xor_const<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	word: Option<OracleId>,
+	constant: u32,
+) -> Result<OracleId>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	let word = match word {
+		Some(word) if constant == 0 => return Ok(word),
+		Some(word) => word,
+		None => return u32const_repeating(log_size, builder, constant, "constant"),
+	};
+	let constant_column = u32const_repeating(log_size, builder, constant, "constant")?;
+	let xor = builder.add_linear_combination(
+		"xor_const",
+		log_size,
+		[(word, F::ONE), (constant_column, F::ONE)],
+	)?;
+	if let Some(witness) = builder.witness() {
+		let word = witness.get::<B1>(word)?.as_slice::<u32>();
+		let mut xor_column = witness.new_column::<B1>(xor);
+		for (xor, word) in izip!(xor_column.as_mut_slice::<u32>(), word) {
+			*xor = word ^ constant;
+		}
+	}
+	Ok(xor)
+}
+
+/// Checks that `words` hold a string of `length` bytes, and constrains the bytes of the last word
+/// past the string to be zero.
+fn assert_string<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	words: &[OracleId],
+	length: usize,
+) -> Result<()>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	ensure!(
+		words.len() == length.div_ceil(4),
+		"a string of {length} bytes takes {} words, got {}",
+		length.div_ceil(4),
+		words.len()
+	);
+	if length % 4 == 0 {
+		return Ok(());
+	}
+
+	// The bytes past the string are the low bits of the word, which are zero exactly when
+	// shifting them to the top leaves zero
+	let tail = arithmetic::u32::shl(builder, "tail", words[words.len() - 1], 8 * (length % 4))?;
+	builder.assert_zero("tail_zero", [tail], arith_expr!([tail] = tail).convert_field());
+	Ok(())
+}
+
+/// Hashes the string of `length` bytes in `message`, followed by the constant `suffix`, starting
+/// from the chaining `state` after `absorbed` bytes, which must be a whole number of blocks.
+fn hash_from<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	state: [OracleId; DIGEST_WORDS],
+	absorbed: usize,
+	message: &[OracleId],
+	length: usize,
+	suffix: &[u8],
+) -> Result<[OracleId; DIGEST_WORDS]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	ensure!(absorbed % BLOCK_BYTES == 0, "{absorbed} bytes are not a whole number of blocks");
+	assert_string(builder, message, length)?;
+
+	// The constant bytes of the padded message, which are zero where the message is
+	let total = absorbed + length + suffix.len();
+	let mut constants = vec![0u8; length];
+	constants.extend_from_slice(suffix);
+	constants.push(0x80);
+	constants.resize((length + suffix.len() + 9).next_multiple_of(BLOCK_BYTES) - 8, 0);
+	constants.extend_from_slice(&(8 * total as u64).to_be_bytes());
+
+	let words = constants
+		.chunks_exact(4)
+		.enumerate()
+		.map(|(i, bytes)| {
+			let constant = u32::from_be_bytes(bytes.try_into().expect("chunks of 4 bytes"));
+			xor_const(builder, log_size, message.get(i).copied(), constant)
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	words.chunks_exact(16).try_fold(state, |state, block| {
+		let block = block.try_into().expect("chunks of 16 words");
+		compress(builder, state, block, log_size)
+	})
+}
+
+/// Hashes the string of `length` bytes in `message` with SHA-256.
+///
+/// Unlike [`crate::sha256::sha256`], which compresses a single block, the message is padded and
+/// may span several blocks.
+pub fn sha256_message<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	message: &[OracleId],
+	length: usize,
+	log_size: usize,
+) -> Result<[OracleId; DIGEST_WORDS]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let init = INIT.try_map(|word| u32const_repeating(log_size, builder, word, "INIT"))?;
+	let digest = hash_from(builder, log_size, init, 0, message, length, &[])?;
+	builder.pop_namespace();
+	Ok(digest)
+}
+
+/// Prepares the key of `length` bytes in `key` for HMAC, by absorbing it masked with the ipad and
+/// the opad into two states, which are shared by all messages authenticated with the key.
+///
+/// Keys longer than a block are hashed first, as the specification requires.
+pub fn hmac_key<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	key: &[OracleId],
+	length: usize,
+	log_size: usize,
+) -> Result<HmacKey>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let init = INIT.try_map(|word| u32const_repeating(log_size, builder, word, "INIT"))?;
+	let hashed;
+	let key = if length > BLOCK_BYTES {
+		hashed = hash_from(builder, log_size, init, 0, key, length, &[])?;
+		&hashed[..]
+	} else {
+		assert_string(builder, key, length)?;
+		key
+	};
+
+	let [inner, outer] = [IPAD, OPAD].try_map(|pad| {
+		let block =
+			std::array::try_from_fn(|i| xor_const(builder, log_size, key.get(i).copied(), pad))?;
+		compress(builder, init, block, log_size)
+	})?;
+	builder.pop_namespace();
+	Ok(HmacKey { inner, outer })
+}
+
+/// Computes the HMAC-SHA256 with a prepared `key` of the string of `length` bytes in `message`,
+/// followed by the constant `suffix`.
+pub fn hmac_with_key<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	key: &HmacKey,
+	message: &[OracleId],
+	length: usize,
+	suffix: &[u8],
+	log_size: usize,
+) -> Result<[OracleId; DIGEST_WORDS]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let inner = hash_from(builder, log_size, key.inner, BLOCK_BYTES, message, length, suffix)?;
+	let outer = hash_from(builder, log_size, key.outer, BLOCK_BYTES, &inner, 32, &[])?;
+	builder.pop_namespace();
+	Ok(outer)
+}
+
+/// Computes the HMAC-SHA256 with the key of `key_length` bytes in `key` of the string of
+/// `length` bytes in `message`.
+pub fn hmac<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	key: &[OracleId],
+	key_length: usize,
+	message: &[OracleId],
+	length: usize,
+	log_size: usize,
+) -> Result<[OracleId; DIGEST_WORDS]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	builder.push_namespace(name);
+	let key = hmac_key(builder, "key", key, key_length, log_size)?;
+	let mac = hmac_with_key(builder, "mac", &key, message, length, &[], log_size)?;
+	builder.pop_namespace();
+	Ok(mac)
+}
+
+/// The HKDF-Extract step, which derives a pseudorandom key from the input keying material of
+/// `ikm_length` bytes in `ikm` with the salt of `salt_length` bytes in `salt`.
+///
+/// An empty salt is equivalent to the zero salt the specification substitutes for it.
+pub fn hkdf_extract<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	salt: &[OracleId],
+	salt_length: usize,
+	ikm: &[OracleId],
+	ikm_length: usize,
+	log_size: usize,
+) -> Result<[OracleId; DIGEST_WORDS]>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	hmac(builder, name, salt, salt_length, ikm, ikm_length, log_size)
+}
+
+/// The HKDF-Expand step, which derives `output_length` bytes of keying material from the
+/// pseudorandom key `prk` and the context of `info_length` bytes in `info`.
+///
+/// Returns the `output_length.div_ceil(4)` words of the output. Unlike the inputs, the bytes of
+/// the last word past the output are not zero, but the following bytes of the expansion.
+pub fn hkdf_expand<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	prk: [OracleId; DIGEST_WORDS],
+	info: &[OracleId],
+	info_length: usize,
+	output_length: usize,
+	log_size: usize,
+) -> Result<Vec<OracleId>>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	let n_blocks = output_length.div_ceil(32);
+	ensure!(
+		(1..=255).contains(&n_blocks),
+		"the output length must be between 1 and {} bytes, got {output_length}",
+		255 * 32
+	);
+
+	builder.push_namespace(name);
+	let key = hmac_key(builder, "prk", &prk, 32, log_size)?;
+	let mut output = Vec::with_capacity(n_blocks * DIGEST_WORDS);
+	for counter in 1..=n_blocks as u8 {
+		// T(i) = HMAC(PRK, T(i - 1) || info || i), where T(0) is empty
+		let previous = output.len().saturating_sub(DIGEST_WORDS);
+		let message = [&output[previous..], info].concat();
+		let block = hmac_with_key(
+			builder,
+			format!("t{counter}"),
+			&key,
+			&message,
+			4 * (output.len() - previous) + info_length,
+			&[counter],
+			log_size,
+		)?;
+		output.extend(block);
+	}
+	output.truncate(output_length.div_ceil(4));
+	builder.pop_namespace();
+	Ok(output)
+}
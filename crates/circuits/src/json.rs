@@ -0,0 +1,315 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets for the structural parsing of JSON text in byte columns.
+//!
+//! Every gadget runs an [`Automaton`] over the bytes with [`run_automaton`], and returns the
+//! column of its outputs along with the boundary of its transition lookup, which must be supplied
+//! to the prover and the verifier. The automata track the lexical structure of the text, strings
+//! and brackets, to tell structure apart from string contents, but don't validate the grammar of
+//! JSON. Combine them with [`crate::utf8::assert_text`] to validate the encoding of the text.
+//!
+//! The text must end outside of any string, at depth zero. The rows past the text are not
+//! constrained, and their outputs are zero.
+
+use anyhow::{ensure, Result};
+use binius_core::{constraint_system::channel::Boundary, oracle::OracleId};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	TowerField,
+};
+use bytemuck::Pod;
+
+use crate::{
+	automaton::{run_automaton, Automaton},
+	builder::ConstraintSystemBuilder,
+};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+const WHITESPACE: [u8; 4] = [b' ', b'\t', b'\n', b'\r'];
+
+/// The lexical state of JSON text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Lexer {
+	Outside,
+	String,
+	/// In a string, after a backslash.
+	Escape,
+}
+
+impl Lexer {
+	fn step(self, byte: u8) -> Option<Self> {
+		match (self, byte) {
+			(Self::Outside, b'"') => Some(Self::String),
+			(Self::Outside, _) => Some(Self::Outside),
+			(Self::String, b'"') => Some(Self::Outside),
+			(Self::String, b'\\') => Some(Self::Escape),
+			// Control characters must be escaped in strings
+			(Self::String | Self::Escape, 0..0x20) => None,
+			(Self::String | Self::Escape, _) => Some(Self::String),
+		}
+	}
+}
+
+/// An automaton with the output 1 for the bytes of strings, quotes included, and 0 for the
+/// others.
+pub fn strings_automaton() -> Result<Automaton> {
+	Automaton::from_fn(Lexer::Outside, |&lexer, byte| {
+		let next = lexer.step(byte)?;
+		let in_string = lexer != Lexer::Outside || next != Lexer::Outside;
+		Some((next, in_string as u8))
+	})
+}
+
+/// An automaton with the output of the nesting depth of objects and arrays after every byte.
+///
+/// The automaton rejects text nested deeper than `max_depth` or closing more brackets than it
+/// opened. It doesn't tell braces and square brackets apart. There are `3 * (max_depth + 1)`
+/// states, so `max_depth` must be below 85.
+pub fn depth_automaton(max_depth: u8) -> Result<Automaton> {
+	ensure!(max_depth > 0, "the maximum depth must be positive");
+	Automaton::from_fn((Lexer::Outside, 0), |&(lexer, depth), byte| {
+		let depth = match (lexer, byte) {
+			(Lexer::Outside, b'{' | b'[') => (depth < max_depth).then(|| depth + 1)?,
+			(Lexer::Outside, b'}' | b']') => depth.checked_sub(1)?,
+			_ => depth,
+		};
+		Some(((lexer.step(byte)?, depth), depth))
+	})
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum KeyState {
+	/// Outside of a value of the key, with the length of the prefix of the key matched by the
+	/// string being read, as long as it matches.
+	Scan {
+		lexer: Lexer,
+		matched: Option<usize>,
+	},
+	/// After a string equal to the key.
+	AfterKey,
+	/// After a string equal to the key and a colon.
+	BeforeValue,
+	/// In a string value of the key.
+	StringValue(Lexer),
+	/// In a number or literal value of the key.
+	ScalarValue,
+}
+
+impl KeyState {
+	const OUTSIDE: Self = Self::Scan {
+		lexer: Lexer::Outside,
+		matched: None,
+	};
+
+	fn step(&self, key: &[u8], byte: u8) -> Option<(Self, u8)> {
+		let scan = |byte| {
+			let lexer = Lexer::Outside.step(byte)?;
+			let matched = (lexer == Lexer::String).then_some(0);
+			Some((Self::Scan { lexer, matched }, 0))
+		};
+		match *self {
+			Self::Scan {
+				lexer: Lexer::Outside,
+				..
+			} => scan(byte),
+			Self::Scan { lexer, matched } => {
+				let next = lexer.step(byte)?;
+				let state = match next {
+					Lexer::Outside if matched == Some(key.len()) => Self::AfterKey,
+					Lexer::String if lexer == Lexer::String => Self::Scan {
+						lexer: next,
+						matched: matched
+							.filter(|&matched| key.get(matched) == Some(&byte))
+							.map(|matched| matched + 1),
+					},
+					_ => Self::Scan {
+						lexer: next,
+						matched: None,
+					},
+				};
+				Some((state, 0))
+			}
+			Self::AfterKey => match byte {
+				_ if WHITESPACE.contains(&byte) => Some((Self::AfterKey, 0)),
+				b':' => Some((Self::BeforeValue, 0)),
+				_ => scan(byte),
+			},
+			Self::BeforeValue => match byte {
+				_ if WHITESPACE.contains(&byte) => Some((Self::BeforeValue, 0)),
+				b'"' => Some((Self::StringValue(Lexer::String), 1)),
+				b'{' | b'[' | b'}' | b']' | b',' | b':' | 0..0x20 => None,
+				_ => Some((Self::ScalarValue, 1)),
+			},
+			Self::StringValue(lexer) => match lexer.step(byte)? {
+				Lexer::Outside => Some((Self::OUTSIDE, 1)),
+				next => Some((Self::StringValue(next), 1)),
+			},
+			Self::ScalarValue => match byte {
+				b',' | b'}' | b']' => scan(byte),
+				_ if WHITESPACE.contains(&byte) => scan(byte),
+				_ => Some((Self::ScalarValue, 1)),
+			},
+		}
+	}
+}
+
+/// An automaton with the output 1 for the bytes of the values of `key` and 0 for the others.
+///
+/// The key is matched in objects at any depth, against the raw bytes of the strings, so it must
+/// not contain quotes, backslashes or control characters, and is not matched by keys that spell
+/// it with escapes. String values are selected with their quotes. The values of the key must be
+/// strings, numbers or literals, the automaton rejects objects and arrays.
+pub fn key_automaton(key: &str) -> Result<Automaton> {
+	let key = key.as_bytes();
+	ensure!(
+		key.iter()
+			.all(|&byte| byte >= 0x20 && byte != b'"' && byte != b'\\'),
+		"the key must not contain quotes, backslashes or control characters"
+	);
+	Automaton::from_fn(KeyState::OUTSIDE, |state, byte| state.step(key, byte))
+}
+
+fn run_json_automaton<U, F, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	count: usize,
+	automaton: &Automaton,
+) -> Result<(OracleId, Boundary<F>)>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<B8> + PackScalar<B32> + Pod,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	let run =
+		run_automaton::<U, F, LOG_MAX_MULTIPLICITY>(builder, name, input, count, automaton, true)?;
+	let outputs = run
+		.outputs
+		.ok_or_else(|| anyhow::anyhow!("the automaton has no outputs"))?;
+	Ok((outputs, run.boundary))
+}
+
+/// Returns a byte column that is 1 in the rows of string bytes, quotes included, and 0 in the
+/// others, see [`strings_automaton`].
+pub fn strings<U, F, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	count: usize,
+) -> Result<(OracleId, Boundary<F>)>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<B8> + PackScalar<B32> + Pod,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	run_json_automaton::<U, F, LOG_MAX_MULTIPLICITY>(
+		builder,
+		name,
+		input,
+		count,
+		&strings_automaton()?,
+	)
+}
+
+/// Returns a byte column of the nesting depth after every byte, see [`depth_automaton`].
+pub fn depth<U, F, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	count: usize,
+	max_depth: u8,
+) -> Result<(OracleId, Boundary<F>)>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<B8> + PackScalar<B32> + Pod,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	run_json_automaton::<U, F, LOG_MAX_MULTIPLICITY>(
+		builder,
+		name,
+		input,
+		count,
+		&depth_automaton(max_depth)?,
+	)
+}
+
+/// Returns a byte column that is 1 in the rows of the values of `key` and 0 in the others, see
+/// [`key_automaton`].
+pub fn key_selector<U, F, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	count: usize,
+	key: &str,
+) -> Result<(OracleId, Boundary<F>)>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<B8> + PackScalar<B32> + Pod,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	run_json_automaton::<U, F, LOG_MAX_MULTIPLICITY>(
+		builder,
+		name,
+		input,
+		count,
+		&key_automaton(key)?,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn outputs(automaton: &Automaton, text: &str) -> Vec<u8> {
+		assert!(automaton.accepts(text.as_bytes()), "{text}");
+		automaton
+			.trace(text.as_bytes())
+			.unwrap()
+			.iter()
+			.map(|transition| transition.output)
+			.collect()
+	}
+
+	fn selected(automaton: &Automaton, text: &str) -> String {
+		text.chars()
+			.zip(outputs(automaton, text))
+			.map(|(c, output)| if output == 1 { c } else { '_' })
+			.collect()
+	}
+
+	#[test]
+	fn test_strings() {
+		let automaton = strings_automaton().unwrap();
+		assert_eq!(selected(&automaton, r#"{"a": "x\"}"}"#), r#"_"a"__"x\"}"_"#);
+		assert!(!automaton.accepts(br#"{"a": "x}"#));
+		assert!(!automaton.accepts(b"{\"a\": \"x\ny\"}"));
+	}
+
+	#[test]
+	fn test_depth() {
+		let automaton = depth_automaton(2).unwrap();
+		assert_eq!(
+			outputs(&automaton, r#"{"a":[1,"]"]}"#),
+			[1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 1, 0]
+		);
+		assert!(!automaton.accepts(br#"{"a":1}}"#));
+		assert!(!automaton.accepts(br#"{"a":{"b":{}}}"#));
+		assert!(!automaton.accepts(br#"{"a":[1]"#));
+	}
+
+	#[test]
+	fn test_key_selector() {
+		let automaton = key_automaton("name").unwrap();
+		assert_eq!(
+			selected(
+				&automaton,
+				r#"{"name": "Zoe \"Z\"", "user": {"name" :42}, "x": "name", "y": "\"name\":1"}"#
+			),
+			r#"_________"Zoe \"Z\""___________________42__________________________________"#
+		);
+		assert_eq!(
+			selected(&automaton, r#"{"names":1,"name":true}"#),
+			r#"__________________true_"#
+		);
+		assert!(!automaton.accepts(br#"{"name": {"first": "Zoe"}}"#));
+		assert!(key_automaton("a\"b").is_err());
+	}
+}
@@ -0,0 +1,170 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Gadgets operating on several logical u32 lanes stored in one wide column.
+//!
+//! A lane column with `2^log_lanes` lanes of `2^log_rows` u32 values each is a single `1b` column
+//! with `log_rows + log_lanes + 5` variables. The low 5 variables index the bit within a u32, the
+//! next `log_rows` variables index the row and the top `log_lanes` variables index the lane. In
+//! other words, the lanes are concatenated one after another, and each lane has the layout of a
+//! regular u32 column as used by [`crate::arithmetic::u32`].
+//!
+//! Since the u32 constraints only relate bits within the same 32-bit block, one set of
+//! constraints and one set of oracles can serve all lanes at once, rather than one set per lane.
+
+use binius_core::oracle::{OracleId, ProjectionVariant};
+use binius_field::{as_packed_field::PackScalar, BinaryField1b, TowerField};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+
+use crate::{
+	arithmetic::{self, Flags},
+	bitwise,
+	builder::ConstraintSystemBuilder,
+};
+
+/// Returns the number of u32 rows per lane of a lane column.
+///
+/// Fails if the column is too small to hold `2^log_lanes` lanes of at least one u32 each.
+pub fn lane_log_rows<U, F>(
+	builder: &ConstraintSystemBuilder<U, F>,
+	oracle_ids: impl IntoIterator<Item = OracleId>,
+	log_lanes: usize,
+) -> Result<usize, anyhow::Error>
+where
+	U: PackScalar<F>,
+	F: TowerField,
+{
+	let log_rows = builder.log_rows(oracle_ids)?;
+	log_rows.checked_sub(log_lanes + 5).ok_or_else(|| {
+		anyhow::anyhow!(
+			"lane column has n_vars={log_rows}, which is too small for 2^{log_lanes} u32 lanes"
+		)
+	})
+}
+
+/// Adds the u32 lanes of two lane columns.
+///
+/// With [`Flags::Checked`], overflow is disallowed in every lane.
+pub fn u32add_lanes<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	log_lanes: usize,
+	flags: Flags,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	lane_log_rows(builder, [xin, yin], log_lanes)?;
+	arithmetic::u32::add(builder, name, xin, yin, flags)
+}
+
+/// Bitwise XOR of the u32 lanes of two lane columns.
+pub fn u32xor_lanes<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	log_lanes: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	lane_log_rows(builder, [xin, yin], log_lanes)?;
+	bitwise::xor(builder, name, xin, yin)
+}
+
+/// Extracts a single lane of a lane column as a regular u32 column.
+///
+/// The lane is a projection of the top variables and does not need to be committed.
+pub fn lane<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	log_lanes: usize,
+	index: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let log_rows = lane_log_rows(builder, [input], log_lanes)?;
+	anyhow::ensure!(
+		index < 1 << log_lanes,
+		"lane index {index} out of range for 2^{log_lanes} lanes"
+	);
+
+	let query = (0..log_lanes)
+		.map(|i| {
+			if (index >> i) & 1 == 1 {
+				F::ONE
+			} else {
+				F::ZERO
+			}
+		})
+		.collect();
+	let lane = builder.add_projected(name, input, query, ProjectionVariant::LastVars)?;
+
+	if let Some(witness) = builder.witness() {
+		let lane_len = 1 << log_rows;
+		let input = witness.get::<BinaryField1b>(input)?.as_slice::<u32>();
+		witness
+			.new_column::<BinaryField1b>(lane)
+			.as_mut_slice::<u32>()[..lane_len]
+			.copy_from_slice(&input[index * lane_len..(index + 1) * lane_len]);
+	}
+
+	Ok(lane)
+}
+
+/// Commits a lane column whose lanes are copies of the given u32 columns.
+///
+/// All inputs must have the same number of rows and their number must be a power of two. Each
+/// lane is constrained to equal its input column.
+pub fn pack_lanes<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	inputs: &[OracleId],
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	anyhow::ensure!(
+		inputs.len().is_power_of_two(),
+		"the number of lanes must be a power of two, got {}",
+		inputs.len()
+	);
+	let log_lanes = inputs.len().ilog2() as usize;
+	let log_rows = builder.log_rows(inputs.iter().copied())?;
+	anyhow::ensure!(log_rows >= 5, "Polynomial must have n_vars >= 5. Got {log_rows}");
+
+	builder.push_namespace(name);
+	let packed = builder.add_committed("lanes", log_rows + log_lanes, BinaryField1b::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let mut packed_column = witness.new_column::<BinaryField1b>(packed);
+		let packed_u32 = packed_column.as_mut_slice::<u32>();
+		let lane_len = 1 << (log_rows - 5);
+		for (i, &input) in inputs.iter().enumerate() {
+			packed_u32[i * lane_len..(i + 1) * lane_len].copy_from_slice(
+				&witness.get::<BinaryField1b>(input)?.as_slice::<u32>()[..lane_len],
+			);
+		}
+	}
+
+	for (i, &input) in inputs.iter().enumerate() {
+		let lane = lane(builder, format!("lane_{i}"), packed, log_lanes, i)?;
+		builder.assert_zero(
+			format!("lane_{i}_eq"),
+			[lane, input],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(packed)
+}
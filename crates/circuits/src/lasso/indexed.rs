@@ -0,0 +1,252 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Lookups into tables too large to materialize, decomposed into small subtables.
+//!
+//! A table indexed by `n` chunks of `chunk_bits` bits, whose entries are `n` bytes computed
+//! chunk by chunk from the same subtable, has `2^(n * chunk_bits)` entries. A `u32` range check
+//! has `2^32` of them, for instance. The lookup commits the chunks of every index and output,
+//! constrains them to recompose the index and output columns, and looks up every
+//! `(index chunk, output byte)` pair in a subtable of only `2^chunk_bits` entries with [`lasso`].
+//!
+//! [`lasso`]: super::lasso::lasso
+
+use std::{fmt, sync::Arc};
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+	BinaryField16b, BinaryField32b, BinaryField8b, ExtensionField, PackedFieldIndexable,
+	TowerField,
+};
+use binius_math::ArithExpr;
+use bytemuck::Pod;
+
+use super::batch::LookupBatch;
+use crate::builder::ConstraintSystemBuilder;
+
+type B8 = BinaryField8b;
+type B16 = BinaryField16b;
+type B32 = BinaryField32b;
+
+/// A table whose entries are computed chunk by chunk from a subtable.
+///
+/// The index of an entry is split into little-endian chunks of `chunk_bits` bits, and byte `k`
+/// of the entry is the subtable evaluated at chunk `k` of the index.
+#[derive(Clone)]
+pub struct TableDescriptor {
+	name: String,
+	chunk_bits: usize,
+	subtable: Arc<dyn Fn(u16) -> u8 + Send + Sync>,
+}
+
+impl TableDescriptor {
+	/// A table over indices split into chunks of `chunk_bits` bits, which must be 8 or 16.
+	pub fn new(
+		name: impl ToString,
+		chunk_bits: usize,
+		subtable: impl Fn(u16) -> u8 + Send + Sync + 'static,
+	) -> Result<Self> {
+		ensure!(
+			chunk_bits == 8 || chunk_bits == 16,
+			"index chunks must have 8 or 16 bits, got {chunk_bits}"
+		);
+		Ok(Self {
+			name: name.to_string(),
+			chunk_bits,
+			subtable: Arc::new(subtable),
+		})
+	}
+
+	/// A table mapping every byte of the index separately.
+	pub fn bytewise(name: impl ToString, f: impl Fn(u8) -> u8 + Send + Sync + 'static) -> Self {
+		Self::new(name, 8, move |chunk| f(chunk as u8)).expect("8-bit chunks are supported")
+	}
+
+	/// A table combining two operands byte by byte.
+	///
+	/// Chunk `k` of the index holds byte `k` of the first operand in its high byte and byte `k`
+	/// of the second operand in its low byte.
+	pub fn pairwise(name: impl ToString, f: impl Fn(u8, u8) -> u8 + Send + Sync + 'static) -> Self {
+		Self::new(name, 16, move |chunk| f((chunk >> 8) as u8, chunk as u8))
+			.expect("16-bit chunks are supported")
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn chunk_bits(&self) -> usize {
+		self.chunk_bits
+	}
+
+	/// The subtable entry for one chunk of the index.
+	pub fn subtable(&self, chunk: u16) -> u8 {
+		(self.subtable)(chunk)
+	}
+
+	/// The table entry for an index of `n_chunks` chunks.
+	pub fn entry(&self, index: u64, n_chunks: usize) -> u64 {
+		let mask = (1 << self.chunk_bits) - 1;
+		(0..n_chunks)
+			.map(|k| {
+				(self.subtable(((index >> (k * self.chunk_bits)) & mask) as u16) as u64) << (8 * k)
+			})
+			.fold(0, |entry, byte| entry | byte)
+	}
+}
+
+impl fmt::Debug for TableDescriptor {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("TableDescriptor")
+			.field("name", &self.name)
+			.field("chunk_bits", &self.chunk_bits)
+			.finish_non_exhaustive()
+	}
+}
+
+/// Constrains every row of `output` to be the entry of `table` at the index in the same row of
+/// `index`, and fills the witness of `output`.
+///
+/// The index column has one chunk per output byte, so `FI` must have `chunk_bits` bits for every
+/// byte of `FO`: a `u32` table of bytes is indexed by `BinaryField32b` and a pairwise one by
+/// `BinaryField64b`.
+pub fn lookup_indexed<U, F, FI, FO>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	table: &TableDescriptor,
+	index: OracleId,
+	output: OracleId,
+) -> Result<()>
+where
+	U: Pod
+		+ UnderlierType
+		+ PackScalar<B8>
+		+ PackScalar<B16>
+		+ PackScalar<B32>
+		+ PackScalar<FI>
+		+ PackScalar<FO>
+		+ PackScalar<F>,
+	PackedType<U, B32>: PackedFieldIndexable,
+	F: TowerField
+		+ ExtensionField<B8>
+		+ ExtensionField<B16>
+		+ ExtensionField<B32>
+		+ ExtensionField<FI>
+		+ ExtensionField<FO>,
+	FI: TowerField,
+	FO: TowerField,
+{
+	let chunk_bits = table.chunk_bits;
+	let n_chunks = FO::N_BITS / 8;
+	ensure!(n_chunks > 0, "the table entries must have at least 8 bits, got {}", FO::N_BITS);
+	ensure!(
+		n_chunks * chunk_bits == FI::N_BITS,
+		"{} entries of {} bits need an index of {} bits, got {}",
+		table.name,
+		FO::N_BITS,
+		n_chunks * chunk_bits,
+		FI::N_BITS
+	);
+
+	builder.push_namespace(&table.name);
+	let log_rows = builder.log_rows([index, output])?;
+	let index_chunks = (0..n_chunks)
+		.map(|k| builder.add_committed(format!("index_{k}"), log_rows, B16::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	let output_chunks = (0..n_chunks)
+		.map(|k| builder.add_committed(format!("output_{k}"), log_rows, B8::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	// A subtable entry and a looked up pair hold the chunk in bytes 2-3 and the output in byte 0.
+	let chunk_coeff = <F as TowerField>::basis(4, 1)?;
+	let lookup_t = builder.add_committed("lookup_t", chunk_bits, B32::TOWER_LEVEL);
+	let lookups_u = index_chunks
+		.iter()
+		.zip(&output_chunks)
+		.map(|(&index_chunk, &output_chunk)| {
+			builder.add_linear_combination(
+				"lookup_u",
+				log_rows,
+				[(index_chunk, chunk_coeff), (output_chunk, F::ONE)],
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let mut u_to_t_mappings = vec![Vec::new(); n_chunks];
+	if let Some(witness) = builder.witness() {
+		let index_bytes = witness.get::<FI>(index)?.as_slice::<u8>();
+		let chunk_bytes = chunk_bits / 8;
+		let chunks = index_bytes
+			.chunks_exact(chunk_bytes)
+			.map(|bytes| match bytes {
+				[low] => *low as u16,
+				[low, high] => u16::from_le_bytes([*low, *high]),
+				_ => unreachable!("chunks have 8 or 16 bits"),
+			})
+			.collect::<Vec<_>>();
+
+		let mut output_witness = witness.new_column::<FO>(output);
+		let output_bytes = output_witness.as_mut_slice::<u8>();
+		for (k, (&index_chunk, &output_chunk)) in
+			index_chunks.iter().zip(&output_chunks).enumerate()
+		{
+			let mut index_chunk_witness = witness.new_column::<B16>(index_chunk);
+			let mut output_chunk_witness = witness.new_column::<B8>(output_chunk);
+			let mut lookup_u_witness = witness.new_column::<B32>(lookups_u[k]);
+			let index_chunk_values = index_chunk_witness.as_mut_slice::<u16>();
+			let output_chunk_values = output_chunk_witness.as_mut_slice::<u8>();
+			let lookup_u_values = lookup_u_witness.as_mut_slice::<u32>();
+			for row in 0..1 << log_rows {
+				let chunk = chunks[row * n_chunks + k];
+				let byte = table.subtable(chunk);
+				index_chunk_values[row] = chunk;
+				output_chunk_values[row] = byte;
+				output_bytes[row * n_chunks + k] = byte;
+				lookup_u_values[row] = (chunk as u32) << 16 | byte as u32;
+			}
+			u_to_t_mappings[k] = index_chunk_values[..1 << log_rows]
+				.iter()
+				.map(|&chunk| chunk as usize)
+				.collect();
+		}
+
+		let mut lookup_t_witness = witness.new_column::<B32>(lookup_t);
+		for (chunk, entry) in lookup_t_witness
+			.as_mut_slice::<u32>()
+			.iter_mut()
+			.enumerate()
+			.take(1 << chunk_bits)
+		{
+			*entry = (chunk as u32) << 16 | table.subtable(chunk as u16) as u32;
+		}
+	}
+
+	builder.assert_zero(
+		"index",
+		[index].into_iter().chain(index_chunks.iter().copied()),
+		recomposition(chunk_bits.ilog2() as usize, n_chunks)?,
+	);
+	builder.assert_zero(
+		"output",
+		[output].into_iter().chain(output_chunks.iter().copied()),
+		recomposition(B8::TOWER_LEVEL, n_chunks)?,
+	);
+
+	let mut lookup_batch = LookupBatch::new([lookup_t]);
+	for (lookup_u, u_to_t_mapping) in lookups_u.into_iter().zip(u_to_t_mappings) {
+		lookup_batch.add([lookup_u], u_to_t_mapping, 1 << log_rows);
+	}
+	lookup_batch.execute::<_, _, B32>(builder)?;
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+/// The expression `x_0 - sum_k basis_k * x_{k+1}`, asserting that the first variable is made of
+/// the `n_chunks` others, each holding an element of the subfield with tower level `iota`.
+fn recomposition<F: TowerField>(iota: usize, n_chunks: usize) -> Result<ArithExpr<F>> {
+	(0..n_chunks).try_fold(ArithExpr::Var(0), |expr, k| {
+		Ok(expr - ArithExpr::Const(<F as TowerField>::basis(iota, k)?) * ArithExpr::Var(k + 1))
+	})
+}
@@ -1,3 +1,4 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 pub mod u8_arithmetic;
+pub mod u8_bytewise;
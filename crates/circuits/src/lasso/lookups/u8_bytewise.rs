@@ -0,0 +1,98 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Tables of byte-wise functions, looked up with [`crate::lasso::u8_unary_op`] and
+//! [`crate::lasso::u8_binary_op`]. Tables of functions of one byte are transparent.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField32b, ExtensionField,
+	TowerField,
+};
+use bytemuck::Pod;
+
+use crate::{builder::ConstraintSystemBuilder, transparent};
+
+type B32 = BinaryField32b;
+
+/// A table of the entries `x << 8 | f(x)` of every byte `x`, at row `x`.
+pub fn unary_op_lookup<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	f: impl Fn(u8) -> u8,
+) -> Result<OracleId>
+where
+	U: PackScalar<B32> + PackScalar<F>,
+	F: TowerField + ExtensionField<B32>,
+{
+	let values = (0..=u8::MAX)
+		.map(|x| B32::new((x as u32) << 8 | f(x) as u32))
+		.collect::<Vec<_>>();
+	transparent::make_transparent(builder, name, &values)
+}
+
+/// A table of the entries `x << 16 | y << 8 | f(x, y)` of every pair of bytes, at row
+/// `x << 8 | y`.
+///
+/// With $2^{16}$ rows the table is too large for a transparent polynomial, so like the tables of
+/// [`super::u8_arithmetic`] it is a committed column.
+pub fn binary_op_lookup<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	f: impl Fn(u8, u8) -> u8,
+) -> Result<OracleId>
+where
+	U: Pod + UnderlierType + PackScalar<B32> + PackScalar<F>,
+	F: TowerField + ExtensionField<B32>,
+{
+	let table = builder.add_committed(name, 16, B32::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		for (i, entry) in witness
+			.new_column::<B32>(table)
+			.as_mut_slice::<u32>()
+			.iter_mut()
+			.enumerate()
+		{
+			let [y, x, ..] = (i as u32).to_le_bytes();
+			*entry = (i as u32) << 8 | f(x, y) as u32;
+		}
+	}
+	Ok(table)
+}
+
+/// A table of the S-box `sbox`, such as the AES S-box.
+pub fn sbox_lookup<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	sbox: &[u8; 256],
+) -> Result<OracleId>
+where
+	U: PackScalar<B32> + PackScalar<F>,
+	F: TowerField + ExtensionField<B32>,
+{
+	unary_op_lookup(builder, name, |x| sbox[x as usize])
+}
+
+/// A table of the number of set bits of every byte.
+pub fn popcount_lookup<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+) -> Result<OracleId>
+where
+	U: PackScalar<B32> + PackScalar<F>,
+	F: TowerField + ExtensionField<B32>,
+{
+	unary_op_lookup(builder, name, |x| x.count_ones() as u8)
+}
+
+/// A table of the bitwise AND of every pair of bytes.
+pub fn and_lookup<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+) -> Result<OracleId>
+where
+	U: Pod + UnderlierType + PackScalar<B32> + PackScalar<F>,
+	F: TowerField + ExtensionField<B32>,
+{
+	binary_op_lookup(builder, name, |x, y| x & y)
+}
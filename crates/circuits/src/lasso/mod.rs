@@ -2,17 +2,21 @@
 
 pub mod batch;
 pub mod big_integer_ops;
+pub mod indexed;
 pub mod lasso;
 pub mod lookups;
 pub mod sha256;
 pub mod u32add;
+pub mod u8_bytewise;
 pub mod u8_double_conditional_increment;
 pub mod u8add;
 pub mod u8add_carryfree;
 pub mod u8mul;
 
+pub use indexed::{lookup_indexed, TableDescriptor};
 pub use sha256::sha256;
 pub use u32add::u32add;
+pub use u8_bytewise::{u8_binary_op, u8_unary_op};
 pub use u8_double_conditional_increment::u8_double_conditional_increment;
 pub use u8add::u8add;
 pub use u8add_carryfree::u8add_carryfree;
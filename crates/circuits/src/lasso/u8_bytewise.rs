@@ -0,0 +1,143 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField32b, BinaryField8b,
+	ExtensionField, TowerField,
+};
+use bytemuck::Pod;
+use itertools::izip;
+
+use super::batch::LookupBatch;
+use crate::builder::ConstraintSystemBuilder;
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// Applies the byte function `f` to every byte of `input` by a lookup.
+///
+/// The lookups are added to `lookup_batch`, whose table must be a
+/// [`super::lookups::u8_bytewise::unary_op_lookup`] of the same function.
+pub fn u8_unary_op<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	input: OracleId,
+	f: impl Fn(u8) -> u8,
+	n_lookups: usize,
+) -> Result<OracleId>
+where
+	U: Pod + UnderlierType + PackScalar<B8> + PackScalar<B32> + PackScalar<F>,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	ensure!(n_lookups <= 1 << log_rows);
+	let output = builder.add_committed("output", log_rows, B8::TOWER_LEVEL);
+
+	let lookup_u = builder.add_linear_combination(
+		"lookup_u",
+		log_rows,
+		[
+			(input, <F as TowerField>::basis(3, 1)?),
+			(output, <F as TowerField>::basis(3, 0)?),
+		],
+	)?;
+
+	let mut u_to_t_mapping = Vec::new();
+
+	if let Some(witness) = builder.witness() {
+		let mut output_witness = witness.new_column::<B8>(output);
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut u_to_t_mapping_witness = vec![0; 1 << log_rows];
+
+		let input_u8 = witness.get::<B8>(input)?.as_slice::<u8>();
+		let output_u8 = output_witness.as_mut_slice::<u8>();
+		let lookup_u_u32 = lookup_u_witness.as_mut_slice::<u32>();
+
+		for (x, output, lookup_u, u_to_t) in izip!(
+			input_u8,
+			output_u8.iter_mut(),
+			lookup_u_u32.iter_mut(),
+			u_to_t_mapping_witness.iter_mut()
+		) {
+			*output = f(*x);
+			*lookup_u = (*x as u32) << 8 | *output as u32;
+			*u_to_t = *x as usize;
+		}
+
+		u_to_t_mapping = u_to_t_mapping_witness;
+	}
+
+	lookup_batch.add([lookup_u], u_to_t_mapping, n_lookups);
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Applies the byte function `f` to every pair of bytes of `xin` and `yin` by a lookup.
+///
+/// The lookups are added to `lookup_batch`, whose table must be a
+/// [`super::lookups::u8_bytewise::binary_op_lookup`] of the same function.
+pub fn u8_binary_op<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+	f: impl Fn(u8, u8) -> u8,
+	n_lookups: usize,
+) -> Result<OracleId>
+where
+	U: Pod + UnderlierType + PackScalar<B8> + PackScalar<B32> + PackScalar<F>,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin, yin])?;
+	ensure!(n_lookups <= 1 << log_rows);
+	let output = builder.add_committed("output", log_rows, B8::TOWER_LEVEL);
+
+	let lookup_u = builder.add_linear_combination(
+		"lookup_u",
+		log_rows,
+		[
+			(xin, <F as TowerField>::basis(3, 2)?),
+			(yin, <F as TowerField>::basis(3, 1)?),
+			(output, <F as TowerField>::basis(3, 0)?),
+		],
+	)?;
+
+	let mut u_to_t_mapping = Vec::new();
+
+	if let Some(witness) = builder.witness() {
+		let mut output_witness = witness.new_column::<B8>(output);
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut u_to_t_mapping_witness = vec![0; 1 << log_rows];
+
+		let xin_u8 = witness.get::<B8>(xin)?.as_slice::<u8>();
+		let yin_u8 = witness.get::<B8>(yin)?.as_slice::<u8>();
+		let output_u8 = output_witness.as_mut_slice::<u8>();
+		let lookup_u_u32 = lookup_u_witness.as_mut_slice::<u32>();
+
+		for (x, y, output, lookup_u, u_to_t) in izip!(
+			xin_u8,
+			yin_u8,
+			output_u8.iter_mut(),
+			lookup_u_u32.iter_mut(),
+			u_to_t_mapping_witness.iter_mut()
+		) {
+			*output = f(*x, *y);
+			let lookup_index = (*x as usize) << 8 | *y as usize;
+			*lookup_u = (lookup_index << 8 | *output as usize) as u32;
+			*u_to_t = lookup_index;
+		}
+
+		u_to_t_mapping = u_to_t_mapping_witness;
+	}
+
+	lookup_batch.add([lookup_u], u_to_t_mapping, n_lookups);
+
+	builder.pop_namespace();
+	Ok(output)
+}
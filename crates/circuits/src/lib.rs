@@ -9,29 +9,56 @@
 #![feature(array_try_map, array_try_from_fn)]
 #![allow(clippy::module_inception)]
 
+// Lets code generated by `binius_macros` refer to this crate by name.
+extern crate self as binius_circuits;
+
 pub mod arithmetic;
+pub mod automaton;
 pub mod bitwise;
 pub mod builder;
+pub mod chacha20;
 pub mod collatz;
+pub mod examples;
+pub mod fixed_point;
+pub mod float32;
+#[cfg(test)]
+mod golden;
 pub mod groestl;
+pub mod hmac;
+pub mod json;
 pub mod keccakf;
+pub mod lanes;
 pub mod lasso;
+pub mod linalg;
+pub mod message_schedule;
+pub mod mux;
 mod pack;
+pub mod permutation;
 pub mod plain_lookup;
+pub mod poly1305;
+pub mod range_check;
+pub mod regex;
 pub mod sha256;
+pub mod sha3;
+pub mod sort;
+#[cfg(any(test, feature = "proptest"))]
+pub mod testing;
+pub mod tower;
+pub mod trace;
 pub mod transparent;
 pub mod u32fib;
 pub mod unconstrained;
+pub mod utf8;
 pub mod vision;
 
 #[cfg(test)]
 mod tests {
-	use std::array;
+	use std::{array, time::Duration};
 
 	use binius_core::{
 		constraint_system::{
 			self,
-			channel::{Boundary, FlushDirection},
+			channel::{BalancingPolicy, Boundary, FlushDirection},
 			validate::validate_witness,
 		},
 		fiat_shamir::HasherChallenger,
@@ -40,7 +67,7 @@ mod tests {
 	};
 	use binius_field::{
 		arch::OptimalUnderlier,
-		as_packed_field::PackedType,
+		as_packed_field::{PackScalar, PackedType},
 		tower_levels::{TowerLevel1, TowerLevel16, TowerLevel2, TowerLevel4, TowerLevel8},
 		underlier::WithUnderlier,
 		AESTowerField16b, BinaryField128b, BinaryField1b, BinaryField32b, BinaryField64b,
@@ -48,16 +75,20 @@ mod tests {
 	};
 	use binius_hal::make_portable_backend;
 	use binius_hash::compress::Groestl256ByteCompression;
-	use binius_math::DefaultEvaluationDomainFactory;
+	use binius_math::{ArithExpr, DefaultEvaluationDomainFactory};
 	use groestl_crypto::Groestl256;
-	use rand::{rngs::StdRng, Rng, SeedableRng};
+	use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 	use sha2::{compress256, digest::generic_array::GenericArray};
 
 	use crate::{
 		arithmetic, bitwise,
-		builder::ConstraintSystemBuilder,
+		builder::{BudgetPolicy, BudgetUsage, ConstraintSystemBuilder, Error},
+		chacha20,
+		examples::stack_vm::{Instruction, Program, StackVm},
 		groestl::groestl_p_permutation,
+		hmac, json,
 		keccakf::{keccakf, KeccakfState},
+		lanes,
 		lasso::{
 			self,
 			batch::LookupBatch,
@@ -69,10 +100,14 @@ mod tests {
 			lookups,
 			u32add::SeveralU32add,
 		},
-		plain_lookup,
+		mux, permutation, plain_lookup, poly1305, range_check,
+		regex::regex_match,
 		sha256::sha256,
+		sha3::{self, sha3_256},
+		sort, transparent,
 		u32fib::u32fib,
 		unconstrained::unconstrained,
+		utf8::{self, Charset},
 		vision::vision_permutation,
 	};
 
@@ -154,6 +189,66 @@ mod tests {
 		test_bytesliced_add_carryfree::<8, TowerLevel8>();
 	}
 
+	#[test]
+	fn test_lasso_lookup_indexed() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 10;
+		let mut rng = StdRng::seed_from_u64(0);
+		let xs = (0..1 << log_size).map(|_| rng.gen()).collect::<Vec<u32>>();
+		let ys = (0..1 << log_size).map(|_| rng.gen()).collect::<Vec<u32>>();
+
+		// A 2^32-entry table of bytes rotated and masked, indexed by the u32 itself.
+		let bytewise = lasso::TableDescriptor::bytewise("bytewise", |b| b.rotate_left(3) ^ 0x5a);
+		let x = builder.add_committed("x", log_size, BinaryField32b::TOWER_LEVEL);
+		let x_out = builder.add_committed("x_out", log_size, BinaryField32b::TOWER_LEVEL);
+		// A 2^64-entry table of u32 and-not, indexed by the interleaved bytes of both operands.
+		let pairwise = lasso::TableDescriptor::pairwise("and_not", |a, b| a & !b);
+		let xy = builder.add_committed("xy", log_size, BinaryField64b::TOWER_LEVEL);
+		let xy_out = builder.add_committed("xy_out", log_size, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField32b>(x)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&xs);
+			let mut xy_column = witness.new_column::<BinaryField64b>(xy);
+			for ((xy, &x), &y) in xy_column.as_mut_slice::<u64>().iter_mut().zip(&xs).zip(&ys) {
+				*xy = (0..4)
+					.map(|k| ((x >> (8 * k)) as u8 as u64) << 8 | (y >> (8 * k)) as u8 as u64)
+					.enumerate()
+					.fold(0, |xy, (k, chunk)| xy | chunk << (16 * k));
+			}
+		}
+
+		builder
+			.lookup_indexed::<BinaryField32b, BinaryField32b>(&bytewise, x, x_out)
+			.unwrap();
+		builder
+			.lookup_indexed::<BinaryField64b, BinaryField32b>(&pairwise, xy, xy_out)
+			.unwrap();
+		assert!(builder
+			.lookup_indexed::<BinaryField32b, BinaryField32b>(&pairwise, xy, xy_out)
+			.is_err());
+
+		let witness = builder.witness().unwrap();
+		let x_out = witness
+			.get::<BinaryField32b>(x_out)
+			.unwrap()
+			.as_slice::<u32>();
+		let xy_out = witness
+			.get::<BinaryField32b>(xy_out)
+			.unwrap()
+			.as_slice::<u32>();
+		for (i, (&x, &y)) in xs.iter().zip(&ys).enumerate() {
+			assert_eq!(x_out[i], bytewise.entry(x as u64, 4) as u32);
+			assert_eq!(xy_out[i], x & !y);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
 	#[test]
 	fn test_lasso_u8mul() {
 		let allocator = bumpalo::Bump::new();
@@ -190,6 +285,72 @@ mod tests {
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
 
+	#[test]
+	fn test_bytewise_lookups() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 10;
+		let mut rng = StdRng::seed_from_u64(0);
+		let words = (0..1 << log_size).map(|_| rng.gen()).collect::<Vec<u64>>();
+
+		let word = builder.add_committed("word", log_size + 6, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField1b>(word)
+				.as_mut_slice::<u64>()
+				.copy_from_slice(&words);
+		}
+		let bytes = arithmetic::u64::bytes(&mut builder, "bytes", word).unwrap();
+
+		let popcount_table =
+			lookups::u8_bytewise::popcount_lookup(&mut builder, "popcount table").unwrap();
+		let and_table = lookups::u8_bytewise::and_lookup(&mut builder, "and table").unwrap();
+		let mut popcount_batch = LookupBatch::new([popcount_table]);
+		let mut and_batch = LookupBatch::new([and_table]);
+
+		let popcount = lasso::u8_unary_op(
+			&mut builder,
+			&mut popcount_batch,
+			"popcount",
+			bytes[0],
+			|x| x.count_ones() as u8,
+			1 << log_size,
+		)
+		.unwrap();
+		let and = lasso::u8_binary_op(
+			&mut builder,
+			&mut and_batch,
+			"and",
+			bytes[1],
+			bytes[7],
+			|x, y| x & y,
+			1 << log_size,
+		)
+		.unwrap();
+
+		popcount_batch
+			.execute::<_, _, BinaryField32b>(&mut builder)
+			.unwrap();
+		and_batch
+			.execute::<_, _, BinaryField32b>(&mut builder)
+			.unwrap();
+
+		{
+			let witness = builder.witness().unwrap();
+			let popcount = witness.get::<BinaryField8b>(popcount).unwrap();
+			let and = witness.get::<BinaryField8b>(and).unwrap();
+			for (i, &word) in words.iter().enumerate() {
+				let [b0, b1, .., b7] = word.to_le_bytes();
+				assert_eq!(popcount.as_slice::<u8>()[i], b0.count_ones() as u8);
+				assert_eq!(and.as_slice::<u8>()[i], b1 & b7);
+			}
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
 	#[test]
 	fn test_lasso_batched_u8mul() {
 		let allocator = bumpalo::Bump::new();
@@ -339,6 +500,310 @@ mod tests {
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
 
+	#[test]
+	fn test_mux() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let n = 5;
+		let index = builder.add_committed("index", log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			witness
+				.new_column::<BinaryField8b>(index)
+				.as_mut_slice::<u8>()
+				.iter_mut()
+				.for_each(|value| *value = rng.gen_range(0..n as u8));
+		}
+		let inputs = (0..n)
+			.map(|i| {
+				unconstrained::<_, _, BinaryField32b>(&mut builder, format!("in_{i}"), log_size)
+					.unwrap()
+			})
+			.collect::<Vec<_>>();
+
+		let selectors = mux::one_hot(&mut builder, "one_hot", index, n).unwrap();
+		let output =
+			mux::select::<_, _, BinaryField32b>(&mut builder, "select", &selectors, &inputs)
+				.unwrap();
+
+		let witness = builder.witness().unwrap();
+		let index_values = witness
+			.get::<BinaryField8b>(index)
+			.unwrap()
+			.as_slice::<u8>();
+		let output_values = witness
+			.get::<BinaryField32b>(output)
+			.unwrap()
+			.as_slice::<u32>();
+		for row in 0..1 << log_size {
+			let input = inputs[index_values[row] as usize];
+			let input_values = witness
+				.get::<BinaryField32b>(input)
+				.unwrap()
+				.as_slice::<u32>();
+			assert_eq!(output_values[row], input_values[row]);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
+	#[test]
+	fn test_one_hot_rejects_three_selectors() {
+		let log_size = 8;
+		let n = 3;
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let index = builder.add_committed("index", log_size, BinaryField8b::TOWER_LEVEL);
+		let selectors = mux::one_hot(&mut builder, "one_hot", index, n).unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		// Every row has index 0, and `set` selectors are set in every row
+		let check = |set: &[usize]| {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+			assert_eq!(builder.add_committed("index", log_size, BinaryField8b::TOWER_LEVEL), index);
+			let witness = builder.witness().unwrap();
+			witness.new_column::<BinaryField8b>(index);
+			for (i, &selector) in selectors.iter().enumerate() {
+				let id = builder.add_committed("selector", log_size, BinaryField1b::TOWER_LEVEL);
+				assert_eq!(id, selector);
+				let fill = if set.contains(&i) { u8::MAX } else { 0 };
+				builder
+					.witness()
+					.unwrap()
+					.new_column::<BinaryField1b>(id)
+					.as_mut_slice::<u8>()
+					.fill(fill);
+			}
+			let witness = builder.take_witness().unwrap();
+			validate_witness(&constraint_system, &[], &witness)
+		};
+
+		check(&[0]).unwrap();
+		// The sum of three set selectors is one in characteristic 2
+		assert!(check(&[0, 1, 2]).is_err());
+	}
+
+	#[test]
+	fn test_if_else() {
+		type B32 = BinaryField32b;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let selector = unconstrained::<_, _, BinaryField1b>(&mut builder, "s", log_size).unwrap();
+		let x = unconstrained::<_, _, B32>(&mut builder, "x", log_size).unwrap();
+		let y = unconstrained::<_, _, B32>(&mut builder, "y", log_size).unwrap();
+
+		// Each branch computes its output only in the rows where it is taken.
+		let branch = |builder: &mut ConstraintSystemBuilder<U, F>,
+		              name: &str,
+		              taken: bool,
+		              op: fn(B32, B32) -> B32,
+		              composition: ArithExpr<F>| {
+			let output = builder.add_committed(name, log_size, B32::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let bits = witness.get::<BinaryField1b>(selector)?.as_slice::<u8>();
+				let x = witness.get::<B32>(x)?.as_slice::<B32>();
+				let y = witness.get::<B32>(y)?.as_slice::<B32>();
+				let mut column = witness.new_column::<B32>(output);
+				for (row, out) in column.as_mut_slice::<B32>().iter_mut().enumerate() {
+					if ((bits[row / 8] >> (row % 8)) & 1 == 1) == taken {
+						*out = op(x[row], y[row]);
+					}
+				}
+			}
+			builder.assert_zero(name, [x, y, output], composition);
+			Ok(output)
+		};
+		let (product, sum) = builder
+			.if_else(
+				selector,
+				|builder| {
+					branch(
+						builder,
+						"product",
+						true,
+						|x, y| x * y,
+						ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+					)
+				},
+				|builder| {
+					branch(
+						builder,
+						"sum",
+						false,
+						|x, y| x + y,
+						ArithExpr::Var(0) + ArithExpr::Var(1) - ArithExpr::Var(2),
+					)
+				},
+			)
+			.unwrap();
+		let output = mux::choose::<_, _, B32>(&mut builder, "choose", selector, &[product], &[sum])
+			.unwrap()[0];
+
+		let witness = builder.witness().unwrap();
+		let bits = witness
+			.get::<BinaryField1b>(selector)
+			.unwrap()
+			.as_slice::<u8>();
+		let [x, y, output] =
+			[x, y, output].map(|id| witness.get::<B32>(id).unwrap().as_slice::<B32>());
+		for row in 0..1 << log_size {
+			let expected = if (bits[row / 8] >> (row % 8)) & 1 == 1 {
+				x[row] * y[row]
+			} else {
+				x[row] + y[row]
+			};
+			assert_eq!(output[row], expected);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_if_else_rejects_ungatable() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let selector = builder.add_committed("s", 8, BinaryField1b::TOWER_LEVEL);
+		let wide = builder.add_committed("wide", 9, BinaryField1b::TOWER_LEVEL);
+		let channel = builder.add_channel();
+
+		builder.push_namespace("branch");
+		let err = builder
+			.if_else(
+				selector,
+				|builder| {
+					builder.assert_zero("wide", [wide], ArithExpr::Var(0));
+					Ok(())
+				},
+				|_| Ok(()),
+			)
+			.unwrap_err();
+		assert!(err.to_string().contains("cannot be gated"));
+		let err = err.downcast_ref::<crate::builder::Error>().unwrap();
+		assert!(matches!(
+			err,
+			crate::builder::Error::UngatableConstraint { id, selector: s, .. } if *id == wide && *s == selector
+		));
+		assert_eq!(err.oracle_ids(), vec![wide, selector]);
+		assert_eq!(err.namespace(), Some("branch"));
+
+		let err = builder
+			.if_else(
+				selector,
+				|_| Ok(()),
+				|builder| {
+					builder.send(channel, 1 << 8, [selector]);
+					Ok(())
+				},
+			)
+			.unwrap_err();
+		assert!(matches!(
+			err.downcast_ref::<crate::builder::Error>(),
+			Some(crate::builder::Error::UngatableOperation { .. })
+		));
+	}
+
+	#[test]
+	fn test_permutation() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let count = 200;
+		let a = (0..2)
+			.map(|i| {
+				unconstrained::<_, _, BinaryField32b>(&mut builder, format!("a_{i}"), log_size)
+					.unwrap()
+			})
+			.collect::<Vec<_>>();
+		let b = (0..2)
+			.map(|i| builder.add_committed(format!("b_{i}"), log_size, BinaryField32b::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut perm = (0..count).collect::<Vec<_>>();
+		perm.shuffle(&mut rng);
+		let witness = builder.witness().unwrap();
+		for (&a, &b) in a.iter().zip(&b) {
+			let a_values = witness
+				.get::<BinaryField32b>(a)
+				.unwrap()
+				.as_slice::<BinaryField32b>();
+			let mut b_column = witness.new_column::<BinaryField32b>(b);
+			let b_values = b_column.as_mut_slice::<BinaryField32b>();
+			for (row, &i) in perm.iter().enumerate() {
+				b_values[row] = a_values[i];
+			}
+		}
+
+		let mut wrong_perm = perm.clone();
+		wrong_perm.swap(0, 1);
+		assert!(permutation::permutation::<_, _, BinaryField32b>(
+			&mut builder,
+			&a,
+			&b,
+			count,
+			Some(&wrong_perm[..])
+		)
+		.is_err());
+
+		permutation::permutation::<_, _, BinaryField32b>(
+			&mut builder,
+			&a,
+			&b,
+			count,
+			Some(&perm[..]),
+		)
+		.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
+	#[test]
+	fn test_sort() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 12;
+		let count = 100;
+		let keys = unconstrained::<_, _, BinaryField1b>(&mut builder, "keys", log_size).unwrap();
+		let values =
+			unconstrained::<_, _, BinaryField1b>(&mut builder, "values", log_size).unwrap();
+
+		let sorted = sort::sort_witness(&mut builder, "sort", &[keys, values], count).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let sorted_keys = witness
+			.get::<BinaryField1b>(sorted[0])
+			.unwrap()
+			.as_slice::<u32>();
+		assert!(sorted_keys[..count].is_sorted());
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
+	#[test]
+	fn test_assert_sorted_rejects_unsorted() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let input = builder.add_committed("input", 8, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut column = witness.new_column::<BinaryField1b>(input);
+			column.as_mut_slice::<u32>()[..3].copy_from_slice(&[1, 3, 2]);
+		}
+		assert!(sort::assert_sorted(&mut builder, "sorted", input, 3).is_err());
+	}
+
 	#[test]
 	fn test_u32fib() {
 		let allocator = bumpalo::Bump::new();
@@ -369,6 +834,47 @@ mod tests {
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
 
+	#[test]
+	fn test_lanes() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 10;
+		let inputs = (0..4)
+			.map(|i| {
+				unconstrained::<_, _, BinaryField1b>(&mut builder, format!("in_{i}"), log_size)
+					.unwrap()
+			})
+			.collect::<Vec<_>>();
+		let a = lanes::pack_lanes(&mut builder, "a", &inputs[..2]).unwrap();
+		let b = lanes::pack_lanes(&mut builder, "b", &inputs[2..]).unwrap();
+		let _sum = lanes::u32add_lanes(&mut builder, "add", a, b, 1, arithmetic::Flags::Unchecked)
+			.unwrap();
+		let xor = lanes::u32xor_lanes(&mut builder, "xor", a, b, 1).unwrap();
+		let xor_1 = lanes::lane(&mut builder, "xor_1", xor, 1, 1).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let xor_1_values = witness
+			.get::<BinaryField1b>(xor_1)
+			.unwrap()
+			.as_slice::<u32>();
+		let in_1 = witness
+			.get::<BinaryField1b>(inputs[1])
+			.unwrap()
+			.as_slice::<u32>();
+		let in_3 = witness
+			.get::<BinaryField1b>(inputs[3])
+			.unwrap()
+			.as_slice::<u32>();
+		for (i, &value) in xor_1_values.iter().take(1 << (log_size - 5)).enumerate() {
+			assert_eq!(value, in_1[i] ^ in_3[i]);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
 	#[test]
 	fn test_keccakf() {
 		let allocator = bumpalo::Bump::new();
@@ -388,6 +894,54 @@ mod tests {
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
 
+	#[test]
+	fn test_sha3_256() {
+		use tiny_keccak::{Hasher, Sha3};
+
+		let log_size = 5;
+		for length in [0, 135, 136, 300] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+			let mut rng = StdRng::seed_from_u64(0);
+			let message = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+
+			let columns: [OracleId; sha3::RATE_BYTES] =
+				builder.add_committed_multiple("message", log_size, BinaryField8b::TOWER_LEVEL);
+			let witness = builder.witness().unwrap();
+			for (k, &id) in columns.iter().enumerate() {
+				let mut column = witness.new_column::<BinaryField8b>(id);
+				let bytes = column.as_mut_slice::<u8>();
+				for (row, &byte) in message.iter().skip(k).step_by(sha3::RATE_BYTES).enumerate() {
+					bytes[row] = byte;
+				}
+			}
+
+			let digest = sha3_256(&mut builder, "sha3", columns, length).unwrap();
+
+			let mut expected = [0u8; 32];
+			let mut hasher = Sha3::v256();
+			hasher.update(&message);
+			hasher.finalize(&mut expected);
+			let witness = builder.witness().unwrap();
+			let lanes = digest
+				.lanes
+				.map(|id| witness.get::<BinaryField64b>(id).unwrap().as_slice::<u64>()[digest.row]);
+			assert_eq!(lanes.map(u64::to_le_bytes).concat(), expected, "length {length}");
+
+			let boundary = digest.schedule.length_boundary::<F>();
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+			validate_witness(&constraint_system, &[boundary.clone()], &witness).unwrap();
+
+			// The length must match the public input the boundary is derived from.
+			let other_length = Boundary {
+				values: vec![F::from(BinaryField64b::new(length as u64 + 1))],
+				..boundary
+			};
+			assert!(validate_witness(&constraint_system, &[other_length], &witness).is_err());
+		}
+	}
+
 	#[test]
 	fn test_sha256() {
 		let allocator = bumpalo::Bump::new();
@@ -483,6 +1037,270 @@ mod tests {
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
 
+	fn string_words(
+		builder: &mut ConstraintSystemBuilder<U, BinaryField1b>,
+		name: &str,
+		bytes: &[u8],
+		log_size: usize,
+	) -> Vec<OracleId> {
+		bytes
+			.chunks(4)
+			.enumerate()
+			.map(|(i, chunk)| {
+				let id = builder.add_committed(
+					format!("{name}{i}"),
+					log_size,
+					BinaryField1b::TOWER_LEVEL,
+				);
+				let mut word = [0; 4];
+				word[..chunk.len()].copy_from_slice(chunk);
+				if let Some(witness) = builder.witness() {
+					witness
+						.new_column::<BinaryField1b>(id)
+						.as_mut_slice::<u32>()
+						.fill(u32::from_be_bytes(word));
+				}
+				id
+			})
+			.collect()
+	}
+
+	fn words_bytes(
+		builder: &mut ConstraintSystemBuilder<U, BinaryField1b>,
+		words: &[OracleId],
+	) -> Vec<u8> {
+		let witness = builder.witness().unwrap();
+		words
+			.iter()
+			.flat_map(|&id| {
+				let word = witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>();
+				assert!(word.iter().all(|&row| row == word[0]));
+				word[0].to_be_bytes()
+			})
+			.collect()
+	}
+
+	fn hex_bytes(hex: &str) -> Vec<u8> {
+		(0..hex.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_hmac_sha256() {
+		// RFC 4231, test cases 6 and 7, with a key and a message longer than a block
+		let key = [0xaa; 131];
+		let cases: [(&[u8], &str); 2] = [
+			(
+				b"Test Using Larger Than Block-Size Key - Hash Key First",
+				"60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+			),
+			(
+				b"This is a test using a larger than block-size key and a larger than block-size \
+				data. The key needs to be hashed before being used by the HMAC algorithm.",
+				"9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+			),
+		];
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, BinaryField1b>::new_with_witness(&allocator);
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let key_words = string_words(&mut builder, "key", &key, log_size);
+		let key = hmac::hmac_key(&mut builder, "key", &key_words, key.len(), log_size).unwrap();
+		for (i, (message, expected)) in cases.into_iter().enumerate() {
+			let message_words = string_words(&mut builder, &format!("m{i}_"), message, log_size);
+			let mac = hmac::hmac_with_key(
+				&mut builder,
+				format!("mac{i}"),
+				&key,
+				&message_words,
+				message.len(),
+				&[],
+				log_size,
+			)
+			.unwrap();
+			assert_eq!(words_bytes(&mut builder, &mac), hex_bytes(expected));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_hkdf() {
+		// RFC 5869, test case 1
+		let ikm = [0x0b; 22];
+		let salt = hex_bytes("000102030405060708090a0b0c");
+		let info = hex_bytes("f0f1f2f3f4f5f6f7f8f9");
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, BinaryField1b>::new_with_witness(&allocator);
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let ikm_words = string_words(&mut builder, "ikm", &ikm, log_size);
+		let salt_words = string_words(&mut builder, "salt", &salt, log_size);
+		let info_words = string_words(&mut builder, "info", &info, log_size);
+
+		let prk = hmac::hkdf_extract(
+			&mut builder,
+			"extract",
+			&salt_words,
+			salt.len(),
+			&ikm_words,
+			ikm.len(),
+			log_size,
+		)
+		.unwrap();
+		assert_eq!(
+			words_bytes(&mut builder, &prk),
+			hex_bytes("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5")
+		);
+		let okm =
+			hmac::hkdf_expand(&mut builder, "expand", prk, &info_words, info.len(), 42, log_size)
+				.unwrap();
+		let mut okm = words_bytes(&mut builder, &okm);
+		okm.truncate(42);
+		assert_eq!(
+			okm,
+			hex_bytes(
+				"3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+			)
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_sha256_message_rejects_nonzero_padding() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, BinaryField1b>::new_with_witness(&allocator);
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		// The fourth byte of the word is past the message of three bytes
+		let message = string_words(&mut builder, "message", b"abcd", log_size);
+		assert!(hmac::sha256_message(&mut builder, "sha256", &message, 5, log_size).is_err());
+		hmac::sha256_message(&mut builder, "sha256", &message, 3, log_size).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+
+	fn le_words(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: &str,
+		bytes: &[u8],
+		log_size: usize,
+	) -> Vec<OracleId> {
+		bytes
+			.chunks_exact(4)
+			.enumerate()
+			.map(|(i, chunk)| {
+				let id = builder.add_committed(
+					format!("{name}{i}"),
+					log_size,
+					BinaryField1b::TOWER_LEVEL,
+				);
+				if let Some(witness) = builder.witness() {
+					witness
+						.new_column::<BinaryField1b>(id)
+						.as_mut_slice::<u32>()
+						.fill(u32::from_le_bytes(chunk.try_into().unwrap()));
+				}
+				id
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_chacha20_block() {
+		// RFC 8439, section 2.3.2
+		let key = (0..32).collect::<Vec<u8>>();
+		let nonce = hex_bytes("000000090000004a00000000");
+		let expected = hex_bytes(
+			"10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+			d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e",
+		);
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let key = le_words(&mut builder, "key", &key, log_size);
+		let counter = le_words(&mut builder, "counter", &1u32.to_le_bytes(), log_size);
+		let nonce = le_words(&mut builder, "nonce", &nonce, log_size);
+		let block = chacha20::chacha20_block(
+			&mut builder,
+			"chacha20",
+			key.try_into().unwrap(),
+			counter[0],
+			nonce.try_into().unwrap(),
+			log_size,
+		)
+		.unwrap();
+
+		let witness = builder.witness().unwrap();
+		let block_bytes = block
+			.iter()
+			.flat_map(|&id| {
+				let word = witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>();
+				assert!(word.iter().all(|&row| row == word[0]));
+				word[0].to_le_bytes()
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(block_bytes, expected);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_poly1305() {
+		// RFC 8439, section 2.5.2, with a message of two full blocks and a partial one
+		let key = hex_bytes("85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b");
+		let message = b"Cryptographic Forum Research Group";
+		let expected = hex_bytes("a8061dc1305136c6c22b8baf0c0127a9");
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_bytes = 10;
+		let log_size = log_bytes + 5;
+		let key = le_words(&mut builder, "key", &key, log_size);
+		let message = message
+			.iter()
+			.map(|&byte| {
+				let id = builder.add_committed("message", log_bytes, BinaryField8b::TOWER_LEVEL);
+				if let Some(witness) = builder.witness() {
+					witness
+						.new_column::<BinaryField8b>(id)
+						.as_mut_slice::<u8>()
+						.fill(byte);
+				}
+				id
+			})
+			.collect::<Vec<_>>();
+		let tag = poly1305::poly1305(
+			&mut builder,
+			"poly1305",
+			key.try_into().unwrap(),
+			&message,
+			log_size,
+		)
+		.unwrap();
+
+		let witness = builder.witness().unwrap();
+		for (&id, &expected) in tag.iter().zip(&expected) {
+			let byte = witness.get::<BinaryField8b>(id).unwrap().as_slice::<u8>();
+			assert!(byte.iter().all(|&row| row == expected));
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
 	#[test]
 	fn test_groestl() {
 		let allocator = bumpalo::Bump::new();
@@ -519,6 +1337,44 @@ mod tests {
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
 
+	#[test]
+	fn test_named_channel_rejects_wrong_arity() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let channel = builder.add_named_channel("pairs", 2, BalancingPolicy::WithBoundaries);
+		let x = builder.add_committed("x", 4, 3);
+		builder.send(channel, 1 << 4, [x]);
+
+		let err = builder.build().unwrap_err();
+		assert!(err.to_string().contains("pairs"));
+	}
+
+	#[test]
+	fn test_closed_channel_rejects_boundaries() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		let log_size = PackedType::<U, BinaryField8b>::LOG_WIDTH;
+		let channel = builder.add_named_channel("internal", 1, BalancingPolicy::Closed);
+		let x = unconstrained::<_, _, BinaryField8b>(&mut builder, "x", log_size).unwrap();
+		builder.send(channel, 1 << log_size, [x]);
+		builder.receive(channel, 1 << log_size, [x]);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let boundaries = [FlushDirection::Push, FlushDirection::Pull].map(|direction| Boundary {
+			values: vec![F::ONE],
+			channel_id: channel,
+			direction,
+			multiplicity: 1,
+		});
+		assert!(matches!(
+			validate_witness(&constraint_system, &boundaries, &witness),
+			Err(constraint_system::error::Error::ChannelUnexpectedBoundary { .. })
+		));
+	}
+
 	#[test]
 	fn test_boundaries() {
 		// Proving Collatz Orbits
@@ -611,7 +1467,13 @@ mod tests {
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
 			_,
-		>(&constraint_system, 1, 10, witness, &domain_factory, &backend)
+		>(
+			&constraint_system,
+			&constraint_system::PCSParams::new(1, 10),
+			witness,
+			&domain_factory,
+			&backend,
+		)
 		.unwrap();
 
 		constraint_system::verify::<
@@ -620,17 +1482,117 @@ mod tests {
 			Groestl256,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
-		>(&constraint_system, 1, 10, vec![pull_boundaries, push_boundaries], proof)
+		>(
+			&constraint_system,
+			&constraint_system::PCSParams::new(1, 10),
+			vec![pull_boundaries, push_boundaries],
+			proof,
+		)
 		.unwrap();
 	}
 
+	#[test]
+	fn test_lazy_column() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let x = unconstrained::<_, _, BinaryField32b>(&mut builder, "x", 10).unwrap();
+		let y = builder.add_committed("y", 10, BinaryField32b::TOWER_LEVEL);
+		builder.assert_zero(
+			"y",
+			[x, y],
+			ArithExpr::Var(0) + ArithExpr::Const(F::new(7)) - ArithExpr::Var(1),
+		);
+
+		let witness = builder.witness().unwrap();
+		let xs = witness
+			.get::<BinaryField32b>(x)
+			.unwrap()
+			.as_scalars::<BinaryField32b>();
+		witness
+			.new_lazy_column::<BinaryField32b>(y, move |i| xs[i] + BinaryField32b::new(7))
+			.unwrap();
+		assert!(witness.get::<BinaryField32b>(y).is_err());
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(witness.is_lazy(y));
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_profile_by_namespace() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let _ = u32fib(&mut builder, "u32fib", 14).unwrap();
+		bitwise_and_xor(&mut builder, 10);
+
+		let namespaces = builder.constraint_namespaces();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let profiler = constraint_system::profile::ProverProfiler::new();
+		let options = constraint_system::ProveOptions::default().with_profiler(profiler.clone());
+		constraint_system::prove_with_options::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			&constraint_system::PCSParams::new(1, 10),
+			witness,
+			&domain_factory,
+			&backend,
+			&options,
+		)
+		.unwrap();
+
+		let profile = profiler.profile().unwrap();
+		assert_eq!(profile.constraint_sets.len(), constraint_system.table_constraints.len());
+		let report = namespaces.attribute(&constraint_system, &profile).unwrap();
+		let attributed = report
+			.zerocheck
+			.iter()
+			.map(|entry| entry.time)
+			.sum::<Duration>();
+		assert!(attributed <= report.zerocheck_total);
+		assert!(report
+			.zerocheck
+			.iter()
+			.any(|entry| entry.namespace.starts_with("u32fib")));
+		assert!(report
+			.zerocheck
+			.iter()
+			.any(|entry| entry.namespace == "and"));
+		assert!(report.to_string().contains("% of zerocheck time"));
+
+		// Namespaces of another builder do not match the constraint system.
+		let other = ConstraintSystemBuilder::<U, F>::new().constraint_namespaces();
+		assert!(other.attribute(&constraint_system, &profile).is_err());
+	}
+
+	fn bitwise_and_xor<F>(builder: &mut ConstraintSystemBuilder<U, F>, log_size: usize)
+	where
+		U: PackScalar<F>,
+		F: TowerField,
+	{
+		let x = unconstrained::<_, _, BinaryField1b>(builder, "x", log_size).unwrap();
+		let y = unconstrained::<_, _, BinaryField1b>(builder, "y", log_size).unwrap();
+		let z = bitwise::and(builder, "and", x, y).unwrap();
+		let _ = bitwise::xor(builder, "xor", z, y).unwrap();
+	}
+
 	#[test]
 	fn test_plain_u8_mul_lookup() {
 		const MAX_LOG_MULTIPLICITY: usize = 18;
 		let log_lookup_count = 19;
 
-		let log_inv_rate = 1;
-		let security_bits = 20;
+		let pcs_params = constraint_system::PCSParams::new(1, 20);
 
 		let proof = {
 			let allocator = bumpalo::Bump::new();
@@ -658,7 +1620,7 @@ mod tests {
 				Groestl256ByteCompression,
 				HasherChallenger<Groestl256>,
 				_,
-			>(&constraint_system, log_inv_rate, security_bits, witness, &domain_factory, &backend)
+			>(&constraint_system, &pcs_params, witness, &domain_factory, &backend)
 			.unwrap()
 		};
 
@@ -681,8 +1643,485 @@ mod tests {
 				Groestl256,
 				Groestl256ByteCompression,
 				HasherChallenger<Groestl256>,
-			>(&constraint_system, log_inv_rate, security_bits, vec![boundary], proof)
+			>(&constraint_system, &pcs_params, vec![boundary], proof)
+			.unwrap();
+		}
+	}
+
+	fn range_check_witness(values: impl Fn(&mut StdRng) -> u32) -> Result<(), anyhow::Error> {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 12;
+		let bits = 10;
+
+		let values_id = builder.add_committed("values", log_size, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			witness
+				.new_column::<BinaryField32b>(values_id)
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.for_each(|value| *value = values(&mut rng));
+		}
+
+		let boundary = range_check::range_check::<_, _, BinaryField32b, 8>(
+			&mut builder,
+			"range_check",
+			values_id,
+			bits,
+		)?;
+
+		let witness = builder.take_witness()?;
+		let constraint_system = builder.build()?;
+		validate_witness(&constraint_system, &[boundary], &witness)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_range_check() {
+		range_check_witness(|rng| rng.gen_range(0..1 << 10)).unwrap();
+	}
+
+	#[test]
+	fn test_range_check_rejects_out_of_range_value() {
+		range_check_witness(|rng| rng.gen_range(1 << 10..1 << 11)).unwrap_err();
+	}
+
+	fn text_witness(text: &[u8], charset: Charset) -> Result<(), anyhow::Error> {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+
+		let input = builder.add_committed("text", log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			// The rows past the text are not constrained
+			let mut column = witness.new_column::<BinaryField8b>(input);
+			let bytes = column.as_mut_slice::<u8>();
+			bytes.fill(0xFF);
+			bytes[..text.len()].copy_from_slice(text);
+		}
+
+		let boundary =
+			utf8::assert_text::<_, _, 8>(&mut builder, "text", input, text.len(), charset)?;
+
+		let witness = builder.take_witness()?;
+		let constraint_system = builder.build()?;
+		validate_witness(&constraint_system, &[boundary], &witness)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_assert_text() {
+		text_witness(
+			"{\"name\": \"Zo\u{eb}\", \"greeting\": \"\u{4f60}\u{597d} \u{1f44b}\"}".as_bytes(),
+			Charset::Utf8,
+		)
+		.unwrap();
+		text_witness(b"https://example.com/search?q=binius", Charset::PRINTABLE_ASCII).unwrap();
+	}
+
+	#[test]
+	fn test_assert_text_rejects_invalid_text() {
+		// Overlong encoding of '/'
+		text_witness(b"a\xC0\xAFb", Charset::Utf8).unwrap_err();
+		// Truncated two-byte character
+		text_witness(b"caf\xC3", Charset::Utf8).unwrap_err();
+		text_witness("caf\u{e9}".as_bytes(), Charset::ASCII).unwrap_err();
+		text_witness(b"line\n", Charset::PRINTABLE_ASCII).unwrap_err();
+	}
+
+	#[test]
+	fn test_json() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let text = br#"{"user": {"name": "Zoe", "tags": ["a", "}"]}, "id": 7}"#;
+
+		let input = builder.add_committed("text", log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField8b>(input)
+				.as_mut_slice::<u8>()[..text.len()]
+				.copy_from_slice(text);
+		}
+
+		let (strings, strings_boundary) =
+			json::strings::<_, _, 8>(&mut builder, "strings", input, text.len()).unwrap();
+		let (depth, depth_boundary) =
+			json::depth::<_, _, 8>(&mut builder, "depth", input, text.len(), 4).unwrap();
+		let (name, name_boundary) =
+			json::key_selector::<_, _, 8>(&mut builder, "name", input, text.len(), "name").unwrap();
+
+		{
+			let witness = builder.witness().unwrap();
+			let strings = witness.get::<BinaryField8b>(strings).unwrap();
+			let depth = witness.get::<BinaryField8b>(depth).unwrap();
+			let name = witness.get::<BinaryField8b>(name).unwrap();
+			let strings = strings.as_slice::<u8>();
+			let name = name.as_slice::<u8>();
+			assert_eq!(strings.iter().map(|&s| s as usize).sum::<usize>(), 33);
+			assert_eq!(depth.as_slice::<u8>()[..text.len()].iter().max(), Some(&3));
+			let selected = (0..text.len())
+				.filter(|&i| name[i] == 1)
+				.map(|i| text[i])
+				.collect::<Vec<_>>();
+			assert_eq!(selected, br#""Zoe""#);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(
+			&constraint_system,
+			&[strings_boundary, depth_boundary, name_boundary],
+			&witness,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_regex_match() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let text = b"GET /api/users/42?fields=name HTTP/1.1";
+
+		let input = builder.add_committed("text", log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField8b>(input)
+				.as_mut_slice::<u8>()[..text.len()]
+				.copy_from_slice(text);
+		}
+
+		let (request, request_boundary) = regex_match::<_, _, 8>(
+			&mut builder,
+			"request",
+			r"^(GET|POST) /[\w/]*(\?[\w=&]*)? HTTP/1\.[01]",
+			input,
+			text.len(),
+		)
+		.unwrap();
+		let (id, id_boundary) =
+			regex_match::<_, _, 8>(&mut builder, "id", r"/\d+", input, text.len()).unwrap();
+
+		{
+			let witness = builder.witness().unwrap();
+			let request = witness.get::<BinaryField8b>(request).unwrap();
+			let id = witness.get::<BinaryField8b>(id).unwrap();
+			assert_eq!(request.as_slice::<u8>()[text.len() - 1], 1);
+			let id_ends = (0..text.len())
+				.filter(|&i| id.as_slice::<u8>()[i] == 1)
+				.collect::<Vec<_>>();
+			// The user id, and the major version in `HTTP/1.1`
+			assert_eq!(id_ends, [15, 16, 35]);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[request_boundary, id_boundary], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_transparent_selectors() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 10;
+
+		let interval = transparent::interval(&mut builder, "interval", log_size, 100, 555).unwrap();
+		let every_kth = transparent::every_kth(&mut builder, "every_kth", log_size, 8, 3).unwrap();
+		let even = transparent::even_rows(&mut builder, "even", log_size).unwrap();
+		let odd = transparent::odd_rows(&mut builder, "odd", log_size).unwrap();
+		assert!(transparent::every_kth(&mut builder, "every_third", log_size, 3, 0).is_err());
+
+		// The committed column is zero exactly where the selectors are one.
+		let column = builder.add_committed("column", log_size, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut column = witness.new_column::<BinaryField1b>(column);
+			let column = column.packed();
+			for i in 0..1 << log_size {
+				let selected = (100..555).contains(&i) || i % 8 == 3;
+				let value = if selected {
+					BinaryField1b::ZERO
+				} else {
+					BinaryField1b::ONE
+				};
+				binius_field::packed::set_packed_slice(column, i, value);
+			}
+		}
+		let selected = ArithExpr::Var(0) * ArithExpr::Var(1);
+		builder.assert_zero("interval", [interval, column], selected.clone());
+		builder.assert_zero("every_kth", [every_kth, column], selected);
+		builder.assert_zero(
+			"parity",
+			[even, odd],
+			ArithExpr::Var(0) + ArithExpr::Var(1) - ArithExpr::one(),
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_assert_copy() {
+		// Copies rows of `a` to a column of the same size and to a smaller column, optionally
+		// breaking one row of the copy that `tampered` selects.
+		let check = |tampered: Option<(bool, usize)>| {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+			let a = builder.add_committed("a", 6, BinaryField32b::TOWER_LEVEL);
+			let b = builder.add_committed("b", 6, BinaryField32b::TOWER_LEVEL);
+			let c = builder.add_committed("c", 4, BinaryField32b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let a_values = (0..64).map(|i| i * 7 + 1).collect::<Vec<u32>>();
+				let mut b_values = (0..64).map(|i| i * 5).collect::<Vec<u32>>();
+				b_values[10..40].copy_from_slice(&a_values[10..40]);
+				let mut c_values = a_values[..16].to_vec();
+				match tampered {
+					Some((false, row)) => b_values[row] ^= 1,
+					Some((true, row)) => c_values[row] ^= 1,
+					None => {}
+				}
+				for (id, values) in [(a, a_values), (b, b_values), (c, c_values)] {
+					witness
+						.new_column::<BinaryField32b>(id)
+						.as_mut_slice::<u32>()
+						.copy_from_slice(&values);
+				}
+			}
+			builder.assert_copy("a_to_b", a, b, 10..40).unwrap();
+			builder.assert_copy("a_to_c", a, c, 0..12).unwrap();
+
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+			validate_witness(&constraint_system, &[], &witness)
+		};
+
+		check(None).unwrap();
+		// Rows outside the copied ranges are unconstrained.
+		check(Some((false, 40))).unwrap();
+		check(Some((true, 12))).unwrap();
+		assert!(check(Some((false, 10))).is_err());
+		assert!(check(Some((true, 11))).is_err());
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 6, BinaryField32b::TOWER_LEVEL);
+		let c = builder.add_committed("c", 4, BinaryField32b::TOWER_LEVEL);
+		assert!(builder.assert_copy("empty", a, a, 3..3).is_err());
+		assert!(builder.assert_copy("too_long", a, c, 0..17).is_err());
+		assert!(builder.assert_copy("offset", a, c, 1..8).is_err());
+	}
+
+	#[test]
+	fn test_snapshot_rollback() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let a = builder.add_committed("a", 4, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField32b>(a)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&(1..17).collect::<Vec<u32>>());
+		}
+		let snapshot = builder.snapshot();
+
+		// A speculative gadget whose constraints the witness does not satisfy.
+		builder.push_namespace("speculative");
+		let b = builder.add_committed("b", 4, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness.new_column::<BinaryField32b>(b);
+		}
+		builder.assert_zero("a_is_b", [a, b], ArithExpr::Var(0) - ArithExpr::Var(1));
+		builder.assert_not_zero(b);
+		let channel = builder.add_channel();
+		builder.send(channel, 16, [b]);
+		let later = builder.snapshot();
+		builder.rollback(snapshot).unwrap();
+		assert!(builder.rollback(later).is_err());
+
+		// The fallback reuses the ids handed out by the discarded gadget.
+		let c = builder.add_committed("c", 4, BinaryField32b::TOWER_LEVEL);
+		assert_eq!(c, b);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField32b>(c)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&(1..17).collect::<Vec<u32>>());
+		}
+		builder.assert_zero("a_is_c", [a, c], ArithExpr::Var(0) - ArithExpr::Var(1));
+		builder.assert_not_zero(c);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert_eq!(constraint_system.oracles.size(), 2);
+		assert_eq!(constraint_system.oracles.oracle(c).name().unwrap(), "c");
+		assert!(constraint_system.flushes.is_empty());
+		assert!(constraint_system.channels.is_empty());
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_budgets() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let add_gadget = |builder: &mut ConstraintSystemBuilder<U, F>| {
+			let a = builder.add_committed("a", 4, BinaryField1b::TOWER_LEVEL);
+			let b = builder.add_committed("b", 4, BinaryField1b::TOWER_LEVEL);
+			builder.assert_zero("a_is_b", [a, b], ArithExpr::Var(0) - ArithExpr::Var(1));
+		};
+
+		builder.enter_budget(4, 2);
+		builder.push_namespace("inner");
+		builder.enter_budget(1, 1);
+		add_gadget(&mut builder);
+		let err = builder.exit_budget().unwrap_err();
+		match err.downcast_ref::<Error>() {
+			Some(Error::BudgetExceeded {
+				namespace,
+				columns: 2,
+				max_columns: 1,
+				constraints: 1,
+				max_constraints: 1,
+			}) => assert_eq!(namespace, "inner"),
+			_ => panic!("unexpected error: {err}"),
+		}
+		builder.pop_namespace();
+		// The columns of the inner budget count towards the outer one.
+		add_gadget(&mut builder);
+		assert_eq!(
+			builder.exit_budget().unwrap(),
+			BudgetUsage {
+				columns: 4,
+				constraints: 2
+			}
+		);
+		assert!(builder.exit_budget().is_err());
+
+		builder.set_budget_policy(BudgetPolicy::Warn);
+		builder.enter_budget(0, 0);
+		add_gadget(&mut builder);
+		assert_eq!(builder.exit_budget().unwrap().columns, 2);
+	}
+
+	#[test]
+	fn test_bit_columns() {
+		let check = |value: u32| {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+			let bit = builder.add_committed_bit("bit", 4);
+			let wide = builder.add_committed_bit_at_level("wide", 4, BinaryField32b::TOWER_LEVEL);
+			let word = builder.add_committed("word", 4, BinaryField32b::TOWER_LEVEL);
+			assert!(builder.is_bit(bit));
+			assert!(builder.is_bit(wide));
+			assert!(!builder.is_bit(word));
+			builder.assert_bit("word_is_bit", word);
+			assert!(builder.is_bit(word));
+			// Already tagged, so no second constraint is added.
+			builder.assert_bit("word_is_bit_again", word);
+
+			if let Some(witness) = builder.witness() {
+				witness.new_column::<BinaryField1b>(bit);
+				for id in [wide, word] {
+					let mut column = witness.new_column::<BinaryField32b>(id);
+					let values = column.as_mut_slice::<u32>();
+					values.fill(1);
+					values[5] = value;
+				}
+			}
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+			// One constraint each for `wide` and `word`, which share no oracle and so are in
+			// separate constraint sets
+			let n_constraints = constraint_system
+				.table_constraints
+				.iter()
+				.map(|constraint_set| constraint_set.constraints.len())
+				.sum::<usize>();
+			assert_eq!(n_constraints, 2);
+			validate_witness(&constraint_system, &[], &witness)
+		};
+
+		check(0).unwrap();
+		check(1).unwrap();
+		assert!(check(2).is_err());
+	}
+
+	#[test]
+	fn test_prove_stack_vm() {
+		// Computes a running product in a loop, leaving the accumulator on the stack.
+		let program = Program(vec![
+			Instruction::Push(1),
+			Instruction::Push(3),
+			Instruction::Mul,
+			Instruction::Push(5),
+			Instruction::Add,
+			Instruction::Jump(1),
+		]);
+		let max_steps = 500;
+
+		let pcs_params = constraint_system::PCSParams::new(1, 20);
+
+		let (advice, proof) = {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+			let mut vm = StackVm::new(program.clone(), max_steps);
+			let advice = vm.init_prover().unwrap();
+			let boundaries = vm.build(&mut builder, advice.clone()).unwrap();
+
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+			validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+
+			let domain_factory = DefaultEvaluationDomainFactory::default();
+			let backend = make_portable_backend();
+
+			let proof = constraint_system::prove::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(&constraint_system, &pcs_params, witness, &domain_factory, &backend)
+			.unwrap();
+			(advice, proof)
+		};
+
+		assert_eq!(advice.final_state[2], max_steps);
+		assert_eq!(advice.stack.len(), 1);
+
+		// The verifier rebuilds the constraint system from the program and the published advice.
+		{
+			let mut builder = ConstraintSystemBuilder::<U, F>::new();
+			let boundaries = StackVm::new(program, max_steps)
+				.build(&mut builder, advice)
+				.unwrap();
+			let constraint_system = builder.build().unwrap();
+
+			constraint_system::verify::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(&constraint_system, &pcs_params, boundaries, proof)
 			.unwrap();
 		}
 	}
+
+	#[test]
+	fn test_commit_tower_level_too_high() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		builder.add_committed("word", 8, BinaryField128b::TOWER_LEVEL);
+		let word256 = builder.add_committed("word256", 8, BinaryField128b::TOWER_LEVEL + 1);
+
+		let err = builder.build().unwrap_err();
+		assert!(matches!(
+			err.downcast_ref::<constraint_system::error::Error>(),
+			Some(constraint_system::error::Error::CannotCommitTowerLevel { id, tower_level: 8, max: 7 })
+				if *id == word256
+		));
+	}
 }
@@ -0,0 +1,242 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Linear algebra over tower fields.
+//!
+//! Matrices are columns with one field element per entry, laid out in row-major order: the entry
+//! in row `i` and column `j` of a matrix with $2^{\text{log\_cols}}$ columns is at index
+//! `i << log_cols | j`.
+
+use binius_core::{constraint_system::MatMul, oracle::OracleId};
+use binius_field::{as_packed_field::PackScalar, ExtensionField, TowerField};
+use bytemuck::Pod;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Multiplies the $2^{\text{log\_rows}} \times 2^{\text{log\_inner}}$ matrix `a` by the
+/// $2^{\text{log\_inner}} \times 2^{\text{log\_cols}}$ matrix `b`.
+///
+/// Both inputs must be `FS` columns. The product is a committed `FS` column constrained with a
+/// single [`MatMul`] constraint, which is proven by a sumcheck over the inner dimension instead of
+/// a table constraint for every entry of the product.
+pub fn matmul<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+	log_rows: usize,
+	log_inner: usize,
+	log_cols: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	builder.push_namespace(name);
+	let c = builder.add_committed("c", log_rows + log_cols, FS::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let a_values = witness.get::<FS>(a)?.as_slice::<FS>();
+		let b_values = witness.get::<FS>(b)?.as_slice::<FS>();
+		let mut c_column = witness.new_column::<FS>(c);
+		let c_values = c_column.as_mut_slice::<FS>();
+		for (index, out) in c_values
+			.iter_mut()
+			.take(1 << (log_rows + log_cols))
+			.enumerate()
+		{
+			let (row, col) = (index >> log_cols, index & ((1 << log_cols) - 1));
+			*out = (0..1 << log_inner)
+				.map(|inner| a_values[row << log_inner | inner] * b_values[inner << log_cols | col])
+				.sum();
+		}
+	}
+
+	builder.assert_matmul(MatMul {
+		a,
+		b,
+		c,
+		log_rows,
+		log_inner,
+		log_cols,
+	});
+
+	builder.pop_namespace();
+	Ok(c)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{
+		constraint_system::{self, validate::validate_witness},
+		fiat_shamir::HasherChallenger,
+		tower::CanonicalTowerFamily,
+	};
+	use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField32b, Field};
+	use binius_hal::make_portable_backend;
+	use binius_hash::compress::Groestl256ByteCompression;
+	use binius_math::DefaultEvaluationDomainFactory;
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::unconstrained::unconstrained;
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+	type Builder<'a> = ConstraintSystemBuilder<'a, U, F>;
+
+	fn prove_and_verify(mut builder: Builder) -> Result<(), anyhow::Error> {
+		let witness = builder.take_witness()?;
+		let constraint_system = builder.build()?;
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let pcs_params = constraint_system::PCSParams::new(1, 10);
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, &pcs_params, witness, &domain_factory, &backend)?;
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, &pcs_params, vec![], proof)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_matmul() {
+		for (log_rows, log_inner, log_cols) in [(2, 3, 1), (0, 2, 2), (3, 2, 0), (2, 4, 3)] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = Builder::new_with_witness(&allocator);
+			let a = unconstrained::<_, _, BinaryField32b>(&mut builder, "a", log_rows + log_inner)
+				.unwrap();
+			let b = unconstrained::<_, _, BinaryField32b>(&mut builder, "b", log_inner + log_cols)
+				.unwrap();
+			let c = matmul::<_, _, BinaryField32b>(
+				&mut builder,
+				"matmul",
+				a,
+				b,
+				log_rows,
+				log_inner,
+				log_cols,
+			)
+			.unwrap();
+
+			// A second product with the output as a factor.
+			if log_cols == log_inner {
+				matmul::<_, _, BinaryField32b>(
+					&mut builder,
+					"matmul_c",
+					c,
+					b,
+					log_rows,
+					log_inner,
+					log_cols,
+				)
+				.unwrap();
+			}
+
+			let witness = builder.witness().unwrap();
+			let a_values = witness.get::<BinaryField32b>(a).unwrap().as_slice();
+			let b_values = witness.get::<BinaryField32b>(b).unwrap().as_slice();
+			let c_values = witness
+				.get::<BinaryField32b>(c)
+				.unwrap()
+				.as_slice::<BinaryField32b>();
+			let expected = a_values[0..1 << log_inner]
+				.iter()
+				.zip(b_values.iter().step_by(1 << log_cols))
+				.map(|(&a, &b): (&BinaryField32b, &BinaryField32b)| a * b)
+				.sum::<BinaryField32b>();
+			assert_eq!(c_values[0], expected);
+
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+			validate_witness(&constraint_system, &[], &witness).unwrap();
+		}
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = Builder::new_with_witness(&allocator);
+		let a = unconstrained::<_, _, BinaryField32b>(&mut builder, "a", 6).unwrap();
+		let b = unconstrained::<_, _, BinaryField32b>(&mut builder, "b", 5).unwrap();
+		matmul::<_, _, BinaryField32b>(&mut builder, "matmul", a, b, 3, 3, 2).unwrap();
+		prove_and_verify(builder).unwrap();
+	}
+
+	#[test]
+	fn test_matmul_rejects_wrong_product() {
+		fn wrong_builder(allocator: &bumpalo::Bump) -> Builder<'_> {
+			let (log_rows, log_inner, log_cols) = (2, 3, 2);
+			let mut builder = Builder::new_with_witness(allocator);
+			let a = unconstrained::<_, _, BinaryField32b>(&mut builder, "a", log_rows + log_inner)
+				.unwrap();
+			let b = unconstrained::<_, _, BinaryField32b>(&mut builder, "b", log_inner + log_cols)
+				.unwrap();
+			let c = builder.add_committed("c", log_rows + log_cols, BinaryField32b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				// The product of the factors, except for a single entry.
+				let a_values = witness.get::<BinaryField32b>(a).unwrap().as_slice();
+				let b_values = witness.get::<BinaryField32b>(b).unwrap().as_slice();
+				let mut c_column = witness.new_column::<BinaryField32b>(c);
+				let c_values = c_column.as_mut_slice::<BinaryField32b>();
+				for (index, out) in c_values.iter_mut().enumerate() {
+					let (row, col) = (index >> log_cols, index & ((1 << log_cols) - 1));
+					*out = (0..1 << log_inner)
+						.map(|inner| {
+							let a: BinaryField32b = a_values[row << log_inner | inner];
+							let b: BinaryField32b = b_values[inner << log_cols | col];
+							a * b
+						})
+						.sum();
+				}
+				c_values[5] += BinaryField32b::ONE;
+			}
+			builder.assert_matmul(MatMul {
+				a,
+				b,
+				c,
+				log_rows,
+				log_inner,
+				log_cols,
+			});
+			builder
+		}
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = wrong_builder(&allocator);
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness)
+			.expect_err("Rejected wrong matrix product");
+
+		let allocator = bumpalo::Bump::new();
+		assert!(prove_and_verify(wrong_builder(&allocator)).is_err());
+	}
+
+	#[test]
+	fn test_matmul_rejects_shape_mismatch() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = Builder::new_with_witness(&allocator);
+		let a = unconstrained::<_, _, BinaryField32b>(&mut builder, "a", 5).unwrap();
+		let b = unconstrained::<_, _, BinaryField32b>(&mut builder, "b", 5).unwrap();
+		builder.assert_matmul(MatMul {
+			a,
+			b,
+			c: b,
+			log_rows: 2,
+			log_inner: 3,
+			log_cols: 3,
+		});
+		assert!(builder.build().is_err());
+	}
+}
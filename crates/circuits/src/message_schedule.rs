@@ -0,0 +1,423 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Hashing of variable-length messages with a permutation called once per block.
+//!
+//! A hash is split into two tables connected by channels. The message schedule table, built by
+//! [`message_schedule`], holds the message in blocks of bytes, one block per row, packs every
+//! block into lanes and adds the padding. It sends each block tagged with its index over the chunk
+//! channel. The permutation table, built by [`absorb_rows`] next to the permutation gadget,
+//! receives the blocks by index, adds them to the rate lanes of the state chained from the previous
+//! row and feeds the result to the permutation, whose output is sent to the next row over the state
+//! channel.
+//!
+//! The schedule also sends the length of the message once over its length channel. The verifier
+//! balances it with [`MessageSchedule::length_boundary`], derived from the public input holding the
+//! length, so a proof for a constraint system built for another length is rejected.
+
+use std::iter;
+
+use anyhow::ensure;
+use binius_core::{
+	constraint_system::channel::{BalancingPolicy, Boundary, ChannelId, FlushDirection},
+	oracle::{OracleId, ShiftVariant},
+};
+use binius_field::{
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, BinaryField64b,
+	BinaryField8b, ExtensionField, TowerField,
+};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+
+use crate::{
+	builder::ConstraintSystemBuilder,
+	transparent::{constant, enumeration, interval, step_down},
+};
+
+/// The message schedule table of a hash, see [`message_schedule`].
+#[derive(Debug, Clone)]
+pub struct MessageSchedule {
+	pub log_size: usize,
+	/// The number of blocks of the padded message, which are in the first rows of the table.
+	pub n_blocks: usize,
+	/// The lanes of the padded blocks.
+	pub lanes: Vec<OracleId>,
+	/// The channel the blocks are sent over, each as its index followed by its lanes.
+	pub chunk_channel: ChannelId,
+	/// The channel the length of the message is sent over.
+	pub length_channel: ChannelId,
+	length: u64,
+}
+
+impl MessageSchedule {
+	/// The boundary that balances the length sent by the schedule, for a verifier who knows the
+	/// length of the message.
+	pub fn length_boundary<F>(&self) -> Boundary<F>
+	where
+		F: TowerField + ExtensionField<BinaryField64b>,
+	{
+		Boundary {
+			values: vec![F::from(BinaryField64b::new(self.length))],
+			channel_id: self.length_channel,
+			direction: FlushDirection::Pull,
+			multiplicity: 1,
+		}
+	}
+}
+
+/// Adds the message schedule table of a message of `length` bytes.
+///
+/// The message is given by byte columns, as many as a block has bytes, where the value of column
+/// `k` in row `i` is byte `i * message.len() + k` of the message. The bytes after the message must
+/// be zero, which is constrained. The message is followed by the `padding` bytes, which must
+/// complete the last block and are added to the blocks as constants.
+///
+/// Every block is packed into lanes of `FL`, each holding consecutive bytes in little-endian
+/// order.
+pub fn message_schedule<U, F, FL>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	message: &[OracleId],
+	length: usize,
+	padding: &[u8],
+) -> Result<MessageSchedule, anyhow::Error>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<FL>
+		+ PackScalar<BinaryField64b>
+		+ PackScalar<BinaryField8b>
+		+ PackScalar<BinaryField1b>,
+	F: TowerField
+		+ ExtensionField<FL>
+		+ ExtensionField<BinaryField64b>
+		+ ExtensionField<BinaryField8b>,
+	FL: TowerField + ExtensionField<BinaryField8b> + Pod,
+{
+	let lane_bytes = <FL as ExtensionField<BinaryField8b>>::DEGREE;
+	let block_bytes = message.len();
+	ensure!(
+		block_bytes > 0 && block_bytes % lane_bytes == 0,
+		"a block of {block_bytes} bytes does not split into lanes of {lane_bytes} bytes"
+	);
+	let padded_length = length + padding.len();
+	ensure!(
+		!padding.is_empty() && padded_length % block_bytes == 0,
+		"{} bytes of padding do not complete the last block of a message of {length} bytes",
+		padding.len()
+	);
+
+	builder.push_namespace(name);
+	let log_size = builder.log_rows(message.iter().copied())?;
+	let n_rows = 1 << log_size;
+	let n_blocks = padded_length / block_bytes;
+	ensure!(n_blocks <= n_rows, "a message of {length} bytes does not fit into {n_rows} blocks");
+
+	let mut padded = vec![0u8; padded_length];
+	padded[length..].copy_from_slice(padding);
+	let first_padded_row = length / block_bytes;
+	let padded_rows = (first_padded_row..n_blocks)
+		.map(|row| interval(builder, format!("padded_row[{row}]"), log_size, row, row + 1))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let byte_basis = (0..lane_bytes)
+		.map(|k| <FL as ExtensionField<BinaryField8b>>::basis(k).map(F::from))
+		.collect::<Result<Vec<_>, _>>()?;
+	let lane_value = |bytes: &[u8]| {
+		FL::from_bases(
+			&bytes
+				.iter()
+				.copied()
+				.map(BinaryField8b::new)
+				.collect::<Vec<_>>(),
+		)
+	};
+	let lanes = (0..block_bytes / lane_bytes)
+		.map(|lane| {
+			let bytes = (0..lane_bytes).map(|k| (message[lane * lane_bytes + k], byte_basis[k]));
+			let mut padding = Vec::new();
+			for (&selector, row) in padded_rows.iter().zip(first_padded_row..) {
+				let start = row * block_bytes + lane * lane_bytes;
+				let value = lane_value(&padded[start..start + lane_bytes])?;
+				if value != FL::ZERO {
+					padding.push((selector, F::from(value)));
+				}
+			}
+			let lanes = builder.add_linear_combination(
+				format!("lanes[{lane}]"),
+				log_size,
+				bytes.chain(padding),
+			)?;
+			Ok(lanes)
+		})
+		.collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+	if let Some(witness) = builder.witness() {
+		let message = message
+			.iter()
+			.map(|&id| Ok(witness.get::<BinaryField8b>(id)?.as_slice::<u8>()))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+		let mut columns = lanes
+			.iter()
+			.map(|&id| witness.new_column::<FL>(id))
+			.collect::<Vec<_>>();
+		for (lane, column) in columns.iter_mut().enumerate() {
+			let values = column.as_mut_slice::<FL>();
+			for (row, value) in values.iter_mut().enumerate() {
+				let mut bytes = (0..lane_bytes)
+					.map(|k| message[lane * lane_bytes + k][row])
+					.collect::<Vec<_>>();
+				if (first_padded_row..n_blocks).contains(&row) {
+					let start = row * block_bytes + lane * lane_bytes;
+					for (byte, pad) in bytes.iter_mut().zip(&padded[start..start + lane_bytes]) {
+						*byte ^= pad;
+					}
+				}
+				*value = lane_value(&bytes)?;
+			}
+		}
+	}
+
+	// Byte k of the message is in the rows before `length / block_bytes + 1` if
+	// k < length % block_bytes, and in the rows before `length / block_bytes` otherwise.
+	let in_message_before = step_down(builder, "in_message_before", log_size, first_padded_row)?;
+	let in_message_through =
+		step_down(builder, "in_message_through", log_size, first_padded_row + 1)?;
+	for (k, &byte) in message.iter().enumerate() {
+		let in_message = if k < length % block_bytes {
+			in_message_through
+		} else {
+			in_message_before
+		};
+		builder.assert_zero(
+			format!("after_message[{k}]"),
+			[byte, in_message],
+			arith_expr!([byte, in_message] = byte * (1 - in_message)).convert_field(),
+		);
+	}
+
+	let index = enumeration::<_, _, BinaryField64b>(builder, "index", log_size)?;
+	let chunk_channel =
+		builder.add_named_channel("chunks", 1 + lanes.len(), BalancingPolicy::Closed);
+	builder.send(chunk_channel, n_blocks, iter::once(index).chain(lanes.iter().copied()));
+
+	let length_column = constant(builder, "length", log_size, BinaryField64b::new(length as u64))?;
+	let length_channel = builder.add_named_channel("length", 1, BalancingPolicy::WithBoundaries);
+	builder.send(length_channel, 1, [length_column]);
+
+	builder.pop_namespace();
+	Ok(MessageSchedule {
+		log_size,
+		n_blocks,
+		lanes,
+		chunk_channel,
+		length_channel,
+		length: length as u64,
+	})
+}
+
+/// The rows of a permutation table absorbing the blocks of a [`MessageSchedule`], see
+/// [`absorb_rows`].
+#[derive(Debug, Clone)]
+pub struct AbsorbRows<FL> {
+	pub log_size: usize,
+	/// The blocks received from the schedule, one per row.
+	pub blocks: Vec<OracleId>,
+	/// The state before the block of a row is added, which is zero in the first row.
+	pub chained: Vec<OracleId>,
+	/// The inputs of the permutation in every row, if the builder has a witness.
+	pub inputs: Option<Vec<Vec<FL>>>,
+	index: OracleId,
+	chained_next: Vec<OracleId>,
+	n_blocks: usize,
+}
+
+/// Adds the rows of a permutation table of `state_lanes` lanes that absorb the blocks of
+/// `schedule`, the first row absorbing the first block into the zero state.
+///
+/// The permutation itself is added by the caller, with the witness computed from
+/// [`AbsorbRows::inputs`], and linked to the rows with [`AbsorbRows::link`]. The prover computes
+/// the inputs with `permute`, which must compute the same permutation on a state. The rows after
+/// the last block absorb zero blocks and are ignored.
+pub fn absorb_rows<U, F, FL>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	schedule: &MessageSchedule,
+	log_size: usize,
+	state_lanes: usize,
+	permute: impl Fn(&mut [FL]),
+) -> Result<AbsorbRows<FL>, anyhow::Error>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<FL>
+		+ PackScalar<BinaryField64b>
+		+ PackScalar<BinaryField1b>,
+	F: TowerField + ExtensionField<FL> + ExtensionField<BinaryField64b>,
+	FL: TowerField + Pod,
+{
+	let rate_lanes = schedule.lanes.len();
+	ensure!(
+		rate_lanes <= state_lanes,
+		"blocks of {rate_lanes} lanes do not fit into a state of {state_lanes} lanes"
+	);
+	let n_rows = 1 << log_size;
+	ensure!(
+		schedule.n_blocks <= n_rows,
+		"{} blocks do not fit into {n_rows} permutation rows",
+		schedule.n_blocks
+	);
+
+	builder.push_namespace(name);
+	let blocks = (0..rate_lanes)
+		.map(|lane| builder.add_committed(format!("blocks[{lane}]"), log_size, FL::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	let chained = (0..state_lanes)
+		.map(|lane| builder.add_committed(format!("chained[{lane}]"), log_size, FL::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	let chained_next = chained
+		.iter()
+		.enumerate()
+		.map(|(lane, &id)| {
+			builder.add_shifted(
+				format!("chained_next[{lane}]"),
+				id,
+				1,
+				log_size,
+				ShiftVariant::LogicalRight,
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	let index = enumeration::<_, _, BinaryField64b>(builder, "index", log_size)?;
+	let first_row = step_down(builder, "first_row", log_size, 1)?;
+
+	let inputs = if let Some(witness) = builder.witness() {
+		let lanes = schedule
+			.lanes
+			.iter()
+			.map(|&id| Ok(witness.get::<FL>(id)?.as_slice::<FL>()))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+		let mut blocks = blocks
+			.iter()
+			.map(|&id| witness.new_column::<FL>(id))
+			.collect::<Vec<_>>();
+		let mut chained = chained
+			.iter()
+			.map(|&id| witness.new_column::<FL>(id))
+			.collect::<Vec<_>>();
+		let mut chained_next = chained_next
+			.iter()
+			.map(|&id| witness.new_column::<FL>(id))
+			.collect::<Vec<_>>();
+		let mut blocks = blocks
+			.iter_mut()
+			.map(|column| column.as_mut_slice::<FL>())
+			.collect::<Vec<_>>();
+		let mut chained = chained
+			.iter_mut()
+			.map(|column| column.as_mut_slice::<FL>())
+			.collect::<Vec<_>>();
+		let mut chained_next = chained_next
+			.iter_mut()
+			.map(|column| column.as_mut_slice::<FL>())
+			.collect::<Vec<_>>();
+
+		let mut inputs = Vec::with_capacity(n_rows);
+		let mut state = vec![FL::ZERO; state_lanes];
+		for row in 0..n_rows {
+			for lane in 0..state_lanes {
+				chained[lane][row] = state[lane];
+				if row > 0 {
+					chained_next[lane][row - 1] = state[lane];
+				}
+			}
+			if row < schedule.n_blocks {
+				for lane in 0..rate_lanes {
+					blocks[lane][row] = lanes[lane][row];
+					state[lane] += lanes[lane][row];
+				}
+			}
+			inputs.push(state.clone());
+			permute(state.as_mut_slice());
+		}
+		Some(inputs)
+	} else {
+		None
+	};
+
+	builder.receive(
+		schedule.chunk_channel,
+		schedule.n_blocks,
+		iter::once(index).chain(blocks.iter().copied()),
+	);
+
+	for (lane, &chained) in chained.iter().enumerate() {
+		builder.assert_zero(
+			format!("initial_state[{lane}]"),
+			[chained, first_row],
+			arith_expr!([chained, first_row] = chained * first_row).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(AbsorbRows {
+		log_size,
+		blocks,
+		chained,
+		inputs,
+		index,
+		chained_next,
+		n_blocks: schedule.n_blocks,
+	})
+}
+
+impl<FL> AbsorbRows<FL> {
+	/// Constrains the `input` lanes of the permutation to the chained state with the block added,
+	/// and links the `output` lanes of every row before the last block to the chained state of
+	/// the next row.
+	pub fn link<U, F>(
+		&self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		input: &[OracleId],
+		output: &[OracleId],
+	) -> Result<(), anyhow::Error>
+	where
+		U: UnderlierType + Pod + PackScalar<F>,
+		F: TowerField,
+	{
+		let state_lanes = self.chained.len();
+		ensure!(
+			input.len() == state_lanes && output.len() == state_lanes,
+			"the permutation must have {state_lanes} input and output lanes"
+		);
+
+		for (lane, (&input, &chained)) in input.iter().zip(&self.chained).enumerate() {
+			if let Some(&block) = self.blocks.get(lane) {
+				builder.assert_zero(
+					format!("absorb[{lane}]"),
+					[input, chained, block],
+					arith_expr!([input, chained, block] = input - chained - block).convert_field(),
+				);
+			} else {
+				builder.assert_zero(
+					format!("capacity[{lane}]"),
+					[input, chained],
+					arith_expr!([input, chained] = input - chained).convert_field(),
+				);
+			}
+		}
+
+		let state_channel =
+			builder.add_named_channel("state", 1 + state_lanes, BalancingPolicy::Closed);
+		let count = self.n_blocks - 1;
+		builder.send(state_channel, count, iter::once(self.index).chain(output.iter().copied()));
+		builder.receive(
+			state_channel,
+			count,
+			iter::once(self.index).chain(self.chained_next.iter().copied()),
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,210 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! One-hot decoding and multiplexer gadgets.
+
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField8b, ExtensionField, TowerField,
+};
+use binius_math::ArithExpr;
+use bytemuck::Pod;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Decodes an index column into `n` one-hot selector columns.
+///
+/// The index column holds values in `0..n`, encoded as the `BinaryField8b` elements with the
+/// same bit representation. Selector `i` is 1 exactly in the rows where the index equals `i`.
+///
+/// The selectors are committed `1b` columns, which makes them bits by construction. Because the
+/// field has characteristic 2, requiring that the selectors sum to one only bounds the number of
+/// set selectors to be odd. Each selector `i` is therefore also constrained to be set only where
+/// the index equals `i`, so that at most one selector is set in every row. This takes `n + 1`
+/// constraints in total.
+pub fn one_hot<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	index: OracleId,
+	n: usize,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField8b> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField + ExtensionField<BinaryField8b>,
+{
+	anyhow::ensure!(
+		(1..=1 << 8).contains(&n),
+		"one-hot decoding supports between 1 and 256 selectors, got {n}"
+	);
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([index])?;
+	let selectors = (0..n)
+		.map(|i| {
+			builder.add_committed(format!("selector_{i}"), log_rows, BinaryField1b::TOWER_LEVEL)
+		})
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let index_values = witness.get::<BinaryField8b>(index)?.as_slice::<u8>();
+		let mut selector_columns = selectors
+			.iter()
+			.map(|&selector| witness.new_column::<BinaryField1b>(selector))
+			.collect::<Vec<_>>();
+		let mut selector_bits = selector_columns
+			.iter_mut()
+			.map(|column| column.as_mut_slice::<u8>())
+			.collect::<Vec<_>>();
+		for (row, &value) in index_values.iter().take(1 << log_rows).enumerate() {
+			let selector = selector_bits.get_mut(value as usize).ok_or_else(|| {
+				anyhow::anyhow!("index {value} in row {row} is out of range for {n} selectors")
+			})?;
+			selector[row / 8] |= 1 << (row % 8);
+		}
+	}
+
+	let sum = (0..n).map(ArithExpr::Var).sum::<ArithExpr<F>>();
+	builder.assert_zero("sum_to_one", selectors.iter().copied(), sum - ArithExpr::one());
+
+	for (i, &selector) in selectors.iter().enumerate() {
+		let value = ArithExpr::Const(BinaryField8b::new(i as u8).into());
+		builder.assert_zero(
+			format!("decode_{i}"),
+			[selector, index],
+			ArithExpr::Var(0) * (ArithExpr::Var(1) - value),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(selectors)
+}
+
+/// Selects in every row the input column picked by the selector columns.
+///
+/// The output is committed and constrained to equal `sum_i selectors[i] * inputs[i]`, which is
+/// the selected input when the selectors are one-hot, e.g. as produced by [`one_hot`].
+pub fn select<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	selectors: &[OracleId],
+	inputs: &[OracleId],
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	anyhow::ensure!(
+		!selectors.is_empty() && selectors.len() == inputs.len(),
+		"expected the same non-zero number of selectors and inputs, got {} and {}",
+		selectors.len(),
+		inputs.len()
+	);
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(selectors.iter().chain(inputs).copied())?;
+	let output = builder.add_committed("output", log_rows, FS::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let selector_bits = selectors
+			.iter()
+			.map(|&selector| Ok(witness.get::<BinaryField1b>(selector)?.as_slice::<u8>()))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+		let input_values = inputs
+			.iter()
+			.map(|&input| Ok(witness.get::<FS>(input)?.as_slice::<FS>()))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+		let mut output_column = witness.new_column::<FS>(output);
+		let output_values = output_column.as_mut_slice::<FS>();
+		for (row, out) in output_values.iter_mut().take(1 << log_rows).enumerate() {
+			*out = selector_bits
+				.iter()
+				.zip(&input_values)
+				.filter(|(bits, _)| (bits[row / 8] >> (row % 8)) & 1 == 1)
+				.map(|(_, values)| values[row])
+				.sum();
+		}
+	}
+
+	let n = selectors.len();
+	let selected = (0..n)
+		.map(|i| ArithExpr::Var(i) * ArithExpr::Var(n + i))
+		.sum::<ArithExpr<F>>();
+	builder.assert_zero(
+		"select",
+		selectors.iter().chain(inputs).copied().chain([output]),
+		selected - ArithExpr::Var(2 * n),
+	);
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Selects in every row between the columns `if_true` and `if_false`, depending on the bit in the
+/// selector column.
+///
+/// Every output is committed and constrained to equal `s * if_true + (1 - s) * if_false` for the
+/// selector `s`. This merges the outputs of the branches of
+/// [`ConstraintSystemBuilder::if_else`], which are paired up in order.
+pub fn choose<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	selector: OracleId,
+	if_true: &[OracleId],
+	if_false: &[OracleId],
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	anyhow::ensure!(
+		if_true.len() == if_false.len(),
+		"expected the same number of columns in both branches, got {} and {}",
+		if_true.len(),
+		if_false.len()
+	);
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(
+		[selector]
+			.into_iter()
+			.chain(if_true.iter().copied())
+			.chain(if_false.iter().copied()),
+	)?;
+	let outputs = (0..if_true.len())
+		.map(|i| builder.add_committed(format!("output_{i}"), log_rows, FS::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let selector_bits = witness.get::<BinaryField1b>(selector)?.as_slice::<u8>();
+		for ((&output, &if_true), &if_false) in outputs.iter().zip(if_true).zip(if_false) {
+			let if_true = witness.get::<FS>(if_true)?.as_slice::<FS>();
+			let if_false = witness.get::<FS>(if_false)?.as_slice::<FS>();
+			let mut output_column = witness.new_column::<FS>(output);
+			let output_values = output_column.as_mut_slice::<FS>();
+			for (row, out) in output_values.iter_mut().take(1 << log_rows).enumerate() {
+				let bit = (selector_bits[row / 8] >> (row % 8)) & 1;
+				*out = if bit == 1 {
+					if_true[row]
+				} else {
+					if_false[row]
+				};
+			}
+		}
+	}
+
+	for (i, ((&output, &if_true), &if_false)) in
+		outputs.iter().zip(if_true).zip(if_false).enumerate()
+	{
+		builder.assert_zero(
+			format!("choose_{i}"),
+			[selector, if_true, if_false, output],
+			ArithExpr::Var(0) * ArithExpr::Var(1)
+				+ (ArithExpr::one() - ArithExpr::Var(0)) * ArithExpr::Var(2)
+				- ArithExpr::Var(3),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(outputs)
+}
@@ -0,0 +1,80 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+use binius_field::{as_packed_field::PackScalar, ExtensionField, TowerField};
+use bytemuck::Pod;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Asserts that the rows of the columns `b` are a permutation of the rows of the columns `a`.
+///
+/// Row `i` of a column set is the tuple of the `i`-th values of its columns, so `a[j]` and `b[j]`
+/// are compared as the `j`-th element of the tuple. Only the first `count` rows of each side are
+/// taken into account. All columns on one side must have the same number of rows, but the two
+/// sides may differ in height.
+///
+/// The rows of `a` are pushed to a new channel and the rows of `b` are pulled from it, hence the
+/// channel balances if and only if the multisets of rows are equal.
+///
+/// If `permutation` is given and the builder has a witness, the witness is checked to satisfy
+/// `b[j][i] == a[j][permutation[i]]` for every column `j` and row `i < count`. This does not add
+/// any constraints, it only serves to catch witness generation bugs early with a readable error.
+pub fn permutation<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	a: &[OracleId],
+	b: &[OracleId],
+	count: usize,
+	permutation: Option<&[usize]>,
+) -> Result<(), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	anyhow::ensure!(
+		!a.is_empty() && a.len() == b.len(),
+		"expected the same non-zero number of columns on both sides, got {} and {}",
+		a.len(),
+		b.len()
+	);
+
+	for side in [a, b] {
+		let log_rows = builder.log_rows(side.iter().copied())?;
+		anyhow::ensure!(
+			count <= 1 << log_rows,
+			"count {count} exceeds the {} rows of the permuted columns",
+			1 << log_rows
+		);
+	}
+
+	if let (Some(permutation), Some(witness)) = (permutation, builder.witness()) {
+		anyhow::ensure!(
+			permutation.len() == count,
+			"expected a permutation of length {count}, got {}",
+			permutation.len()
+		);
+		let mut seen = vec![false; count];
+		for &i in permutation {
+			anyhow::ensure!(
+				i < count && !std::mem::replace(&mut seen[i], true),
+				"permutation witness is not a permutation of 0..{count}"
+			);
+		}
+		for (column, (&a_id, &b_id)) in a.iter().zip(b).enumerate() {
+			let a_values = witness.get::<FS>(a_id)?.as_slice::<FS>();
+			let b_values = witness.get::<FS>(b_id)?.as_slice::<FS>();
+			for (row, &i) in permutation.iter().enumerate() {
+				anyhow::ensure!(
+					b_values[row] == a_values[i],
+					"row {row} of column {column} does not match row {i} of the original"
+				);
+			}
+		}
+	}
+
+	let channel = builder.add_channel();
+	builder.send(channel, count, a.iter().copied());
+	builder.receive(channel, count, b.iter().copied());
+
+	Ok(())
+}
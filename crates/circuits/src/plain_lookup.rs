@@ -188,6 +188,12 @@ where
 				}
 			},
 		);
+		// The rows past the table are unused and hold the balancer value, like a zero multiplicity
+		for packed_component_col in packed_component_cols.iter_mut() {
+			for i in table_count..1 << n_vars {
+				set_packed_slice(packed_component_col, i, balancer_value);
+			}
+		}
 	}
 
 	let expression = {
@@ -211,7 +217,7 @@ where
 #[cfg(test)]
 pub mod test_plain_lookup {
 	use binius_field::BinaryField32b;
-	use rayon::prelude::*;
+	use binius_utils::rayon::prelude::*;
 
 	use super::*;
 	use crate::transparent;
@@ -337,7 +343,7 @@ mod count_multiplicity_tests {
 		let table: Vec<i32> = vec![];
 		let values = vec![1, 2, 3];
 		let result = count_multiplicities(&table, &values, false).unwrap();
-		assert_eq!(result, vec![]);
+		assert_eq!(result, Vec::<usize>::new());
 	}
 
 	#[test]
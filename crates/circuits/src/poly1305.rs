@@ -0,0 +1,211 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The Poly1305 one-time authenticator, as specified by RFC 8439.
+//!
+//! The accumulator lives modulo the 130-bit prime `2^130 - 5`, which is not native to the tower,
+//! so it is held in 32 byte columns and multiplied with
+//! [`byte_sliced_modular_mul`](fn@crate::lasso::big_integer_ops::byte_sliced_modular_mul::byte_sliced_modular_mul). The
+//! multiplications leave the accumulator reduced only up to multiples of the prime, so the sums
+//! are constrained not to overflow and the final accumulator is constrained to be the canonical
+//! residue before the tag is computed.
+
+use std::collections::HashMap;
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	tower_levels::{TowerLevel16, TowerLevel32, TowerLevel64},
+	underlier::{UnderlierType, WithUnderlier},
+	BinaryField, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	Field, PackedFieldIndexable, TowerField,
+};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+
+use crate::{
+	arithmetic, bitwise,
+	builder::ConstraintSystemBuilder,
+	lasso::{
+		batch::LookupBatch,
+		big_integer_ops::{byte_sliced_add, byte_sliced_modular_mul},
+		lookups::u8_arithmetic::add_lookup,
+	},
+	sha256::u32const_repeating,
+	transparent,
+};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+type B16 = BinaryField16b;
+type B32 = BinaryField32b;
+
+/// The number of bytes of the accumulator, the smallest byte-sliced width above 130 bits.
+const WIDTH: usize = 32;
+
+/// The masks clamping the little-endian words of `r`.
+const CLAMP: [u32; 4] = [0x0fffffff, 0x0ffffffc, 0x0ffffffc, 0x0ffffffc];
+
+/// The little-endian bytes of the prime `2^130 - 5`.
+const MODULUS: [u8; WIDTH] = {
+	let mut modulus = [0; WIDTH];
+	let mut i = 1;
+	while i < 16 {
+		modulus[i] = 0xff;
+		i += 1;
+	}
+	modulus[0] = 0xfb;
+	modulus[16] = 0x03;
+	modulus
+};
+
+/// The little-endian bytes of `2^256` minus the prime, whose sum with a value carries out of the
+/// accumulator exactly when the value is not below the prime.
+const MODULUS_COMPLEMENT: [u8; WIDTH] = {
+	let mut complement = [0xff; WIDTH];
+	let mut i = 1;
+	while i < 16 {
+		complement[i] = 0;
+		i += 1;
+	}
+	complement[0] = 0x05;
+	complement[16] = 0xfc;
+	complement
+};
+
+/// Returns a constant byte column for each of `values`, sharing the columns of equal values.
+fn constant_bytes<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	log_size: usize,
+	values: &[u8],
+) -> Result<Vec<OracleId>>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<B8>,
+	F: TowerField + ExtensionField<B8>,
+{
+	let mut columns = HashMap::new();
+	values
+		.iter()
+		.map(|&value| match columns.get(&value) {
+			Some(&column) => Ok(column),
+			None => {
+				let column = transparent::constant(
+					builder,
+					format!("constant_{value:#04x}"),
+					log_size,
+					B8::new(value),
+				)?;
+				columns.insert(value, column);
+				Ok(column)
+			}
+		})
+		.collect()
+}
+
+/// Computes the Poly1305 tag of `message` with the one-time `key`.
+///
+/// The key is given by 8 columns of 32-bit words with `log_size` variables, holding `r` and `s`
+/// in little-endian words, such as the first half of a [`crate::chacha20::chacha20_block`]. The
+/// key is clamped by the gadget. The message and the returned tag are given by byte columns with
+/// one byte per word of the key, so `log_size - 5` variables. The length of the message is fixed
+/// by the constraint system.
+pub fn poly1305<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	key: [OracleId; 8],
+	message: &[OracleId],
+	log_size: usize,
+) -> Result<[OracleId; 16]>
+where
+	U: Pod
+		+ UnderlierType
+		+ PackScalar<B1>
+		+ PackScalar<B8>
+		+ PackScalar<B16>
+		+ PackScalar<B32>
+		+ PackScalar<F>,
+	PackedType<U, B8>: PackedFieldIndexable,
+	PackedType<U, B16>: PackedFieldIndexable,
+	PackedType<U, B32>: PackedFieldIndexable,
+	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	<F as WithUnderlier>::Underlier: From<u8>,
+{
+	ensure!(log_size >= 5, "log_size must hold at least one word, got {log_size}");
+	let log_bytes = log_size - 5;
+	builder.push_namespace(name);
+
+	let zero_byte = transparent::constant(builder, "zero_byte", log_bytes, B8::ZERO)?;
+	let zero_carry = transparent::constant(builder, "zero_carry", log_bytes, B1::ZERO)?;
+	let one_byte = transparent::constant(builder, "one_byte", log_bytes, B8::ONE)?;
+
+	let mut r = [zero_byte; WIDTH];
+	for (i, (&word, mask)) in key[..4].iter().zip(CLAMP).enumerate() {
+		let mask = u32const_repeating(log_size, builder, mask, "clamp_mask")?;
+		let clamped = bitwise::and(builder, format!("clamp_{i}"), word, mask)?;
+		let bytes = arithmetic::u32::bytes(builder, format!("r_{i}"), clamped)?;
+		r[4 * i..4 * i + 4].copy_from_slice(&bytes);
+	}
+	let mut s = [zero_byte; 16];
+	for (i, &word) in key[4..].iter().enumerate() {
+		let bytes = arithmetic::u32::bytes(builder, format!("s_{i}"), word)?;
+		s[4 * i..4 * i + 4].copy_from_slice(&bytes);
+	}
+
+	let lookup_t_add = add_lookup(builder, "add table")?;
+	let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
+	let no_carry = arith_expr!([carry] = carry);
+
+	let mut acc = [zero_byte; WIDTH];
+	for (i, chunk) in message.chunks(16).enumerate() {
+		builder.push_namespace(format!("block_{i}"));
+		// The block is read as a little-endian number with a one byte appended
+		let mut block = [zero_byte; WIDTH];
+		block[..chunk.len()].copy_from_slice(chunk);
+		block[chunk.len()] = one_byte;
+
+		let (carry, sum) = byte_sliced_add::<_, _, TowerLevel32>(
+			builder,
+			"sum",
+			&acc,
+			&block,
+			zero_carry,
+			log_bytes,
+			&mut lookup_batch_add,
+		)?;
+		builder.assert_zero("sum_overflow", [carry], no_carry.clone().convert_field());
+		acc = byte_sliced_modular_mul::<_, _, TowerLevel32, TowerLevel64>(
+			builder, "product", &sum, &r, &MODULUS, log_bytes, zero_byte, zero_carry,
+		)?;
+		builder.pop_namespace();
+	}
+
+	let complement: [OracleId; WIDTH] = constant_bytes(builder, log_bytes, &MODULUS_COMPLEMENT)?
+		.try_into()
+		.expect("one column per byte");
+	let (carry, _) = byte_sliced_add::<_, _, TowerLevel32>(
+		builder,
+		"reduced",
+		&acc,
+		&complement,
+		zero_carry,
+		log_bytes,
+		&mut lookup_batch_add,
+	)?;
+	builder.assert_zero("reduced", [carry], no_carry.convert_field());
+
+	// The tag is the sum modulo 2^128, which drops the carry
+	let acc_low: [OracleId; 16] = acc[..16].try_into().expect("the accumulator has 32 bytes");
+	let (_, tag) = byte_sliced_add::<_, _, TowerLevel16>(
+		builder,
+		"tag",
+		&acc_low,
+		&s,
+		zero_carry,
+		log_bytes,
+		&mut lookup_batch_add,
+	)?;
+
+	lookup_batch_add.execute::<_, _, B32>(builder)?;
+	builder.pop_namespace();
+	Ok(tag)
+}
@@ -0,0 +1,54 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_core::{constraint_system::channel::Boundary, oracle::OracleId};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	BinaryField1b, ExtensionField, PackedField, TowerField,
+};
+use bytemuck::Pod;
+
+use crate::{builder::ConstraintSystemBuilder, plain_lookup::plain_lookup, transparent};
+
+/// Checks that every value in `oracle` is below `1 << bits`.
+///
+/// Rather than decomposing every value into committed bits, the values are looked up with
+/// [`plain_lookup`] in a transparent [`transparent::enumeration`] of the field elements below
+/// `1 << bits`. The table is padded to a whole number of packed elements, and only its first
+/// `1 << bits` rows are valid table values. The gadget commits `2 * LOG_MAX_MULTIPLICITY` columns
+/// of the height of the table regardless of the height of `oracle`, so it is cheaper than the bit
+/// decomposition when many values are checked against a small range.
+///
+/// Like [`plain_lookup`], every value may occur less than `1 << LOG_MAX_MULTIPLICITY` times, and
+/// the returned boundary must be supplied to the prover and the verifier.
+pub fn range_check<U, F, FS, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	oracle: OracleId,
+	bits: usize,
+) -> Result<Boundary<F>, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	let log_values = builder.log_rows([oracle])?;
+	let table_count = 1 << bits;
+	if ((1 << LOG_MAX_MULTIPLICITY) - 1) * table_count < 1 << log_values {
+		anyhow::bail!("2^{log_values} values exceed the multiplicities of 2^{bits} table values");
+	}
+
+	builder.push_namespace(name);
+	let log_table = bits.max(<PackedType<U, FS>>::LOG_WIDTH);
+	let table = transparent::enumeration::<_, _, FS>(builder, "table", log_table)?;
+	let boundary = plain_lookup::<U, F, FS, LOG_MAX_MULTIPLICITY>(
+		builder,
+		table,
+		table_count,
+		FS::ZERO,
+		oracle,
+		1 << log_values,
+	)?;
+
+	builder.pop_namespace();
+	Ok(boundary)
+}
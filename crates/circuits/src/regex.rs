@@ -0,0 +1,452 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Compilation of regular expressions to automata over byte columns.
+//!
+//! A pattern is parsed, compiled to a nondeterministic automaton with Thompson's construction, and
+//! determinized by the subset construction into an [`Automaton`], which [`regex_match`] runs over
+//! a byte column with [`run_automaton`].
+//!
+//! The patterns match bytes rather than characters, and support the following syntax:
+//! - literal bytes, with non-ASCII characters standing for their UTF-8 encoding;
+//! - `.` for any byte;
+//! - classes like `[a-z_]` and `[^,]`, of ASCII characters, ranges and escapes;
+//! - the escapes `\d`, `\w`, `\s` and their negations `\D`, `\W`, `\S`, the escapes `\n`, `\r`,
+//!   `\t` and `\xHH`, and escaped punctuation;
+//! - groups `(...)` and alternations `a|b`;
+//! - the repetitions `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}`;
+//! - a leading `^`, which anchors the matches to the start of the input.
+
+use std::collections::BTreeSet;
+
+use anyhow::{bail, ensure, Result};
+use binius_core::{constraint_system::channel::Boundary, oracle::OracleId};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	TowerField,
+};
+use bytemuck::Pod;
+
+use crate::{
+	automaton::{run_automaton, Automaton},
+	builder::ConstraintSystemBuilder,
+};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// The largest count of a counted repetition, which limits the size of the automaton.
+const MAX_REPETITIONS: usize = 64;
+
+/// A set of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ByteSet([u128; 2]);
+
+impl ByteSet {
+	const EMPTY: Self = Self([0; 2]);
+
+	fn range(lo: u8, hi: u8) -> Self {
+		let mut set = Self::EMPTY;
+		for byte in lo..=hi {
+			set.0[byte as usize >> 7] |= 1 << (byte & 0x7F);
+		}
+		set
+	}
+
+	fn byte(byte: u8) -> Self {
+		Self::range(byte, byte)
+	}
+
+	fn union(self, other: Self) -> Self {
+		Self([self.0[0] | other.0[0], self.0[1] | other.0[1]])
+	}
+
+	fn complement(self) -> Self {
+		Self([!self.0[0], !self.0[1]])
+	}
+
+	fn contains(self, byte: u8) -> bool {
+		(self.0[byte as usize >> 7] >> (byte & 0x7F)) & 1 == 1
+	}
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+	Bytes(ByteSet),
+	Concat(Vec<Node>),
+	Alternation(Vec<Node>),
+	Repetition {
+		node: Box<Node>,
+		min: usize,
+		max: Option<usize>,
+	},
+}
+
+struct Parser<'a> {
+	pattern: &'a [u8],
+	pos: usize,
+}
+
+impl Parser<'_> {
+	fn peek(&self) -> Option<u8> {
+		self.pattern.get(self.pos).copied()
+	}
+
+	fn next(&mut self) -> Result<u8> {
+		let byte = self
+			.peek()
+			.ok_or_else(|| anyhow::anyhow!("unexpected end of the pattern"))?;
+		self.pos += 1;
+		Ok(byte)
+	}
+
+	fn eat(&mut self, byte: u8) -> bool {
+		let eaten = self.peek() == Some(byte);
+		if eaten {
+			self.pos += 1;
+		}
+		eaten
+	}
+
+	fn alternation(&mut self) -> Result<Node> {
+		let mut alternatives = vec![self.concat()?];
+		while self.eat(b'|') {
+			alternatives.push(self.concat()?);
+		}
+		Ok(match alternatives.len() {
+			1 => alternatives.pop().expect("there is one alternative"),
+			_ => Node::Alternation(alternatives),
+		})
+	}
+
+	fn concat(&mut self) -> Result<Node> {
+		let mut nodes = Vec::new();
+		while !matches!(self.peek(), None | Some(b'|' | b')')) {
+			nodes.push(self.repetition()?);
+		}
+		Ok(Node::Concat(nodes))
+	}
+
+	fn repetition(&mut self) -> Result<Node> {
+		let node = self.atom()?;
+		let (min, max) = match self.peek() {
+			Some(b'*') => (0, None),
+			Some(b'+') => (1, None),
+			Some(b'?') => (0, Some(1)),
+			Some(b'{') => {
+				self.pos += 1;
+				let min = self.number()?;
+				let max = if self.eat(b',') {
+					(self.peek() != Some(b'}'))
+						.then(|| self.number())
+						.transpose()?
+				} else {
+					Some(min)
+				};
+				ensure!(self.peek() == Some(b'}'), "unclosed repetition at {}", self.pos);
+				ensure!(
+					max.is_none_or(|max| min <= max),
+					"invalid repetition bounds at {}",
+					self.pos
+				);
+				(min, max)
+			}
+			_ => return Ok(node),
+		};
+		self.pos += 1;
+		Ok(Node::Repetition {
+			node: Box::new(node),
+			min,
+			max,
+		})
+	}
+
+	fn number(&mut self) -> Result<usize> {
+		let start = self.pos;
+		while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+			self.pos += 1;
+		}
+		let number = std::str::from_utf8(&self.pattern[start..self.pos])?
+			.parse::<usize>()
+			.map_err(|_| anyhow::anyhow!("expected a number at {start}"))?;
+		ensure!(number <= MAX_REPETITIONS, "repetition count {number} exceeds {MAX_REPETITIONS}");
+		Ok(number)
+	}
+
+	fn atom(&mut self) -> Result<Node> {
+		let pos = self.pos;
+		let set = match self.next()? {
+			b'(' => {
+				let node = self.alternation()?;
+				ensure!(self.eat(b')'), "unclosed group at {pos}");
+				return Ok(node);
+			}
+			b'[' => self.class()?,
+			b'.' => ByteSet::EMPTY.complement(),
+			b'\\' => self.escape()?,
+			byte @ (b'*' | b'+' | b'?' | b'{' | b'}' | b')' | b']' | b'^' | b'$') => {
+				bail!("unexpected {:?} at {pos}", byte as char)
+			}
+			byte => ByteSet::byte(byte),
+		};
+		Ok(Node::Bytes(set))
+	}
+
+	fn escape(&mut self) -> Result<ByteSet> {
+		let digits = ByteSet::range(b'0', b'9');
+		let word = ByteSet::range(b'a', b'z')
+			.union(ByteSet::range(b'A', b'Z'))
+			.union(digits)
+			.union(ByteSet::byte(b'_'));
+		let space = [b' ', b'\t', b'\n', b'\r', 0x0B, 0x0C]
+			.into_iter()
+			.map(ByteSet::byte)
+			.fold(ByteSet::EMPTY, ByteSet::union);
+
+		let pos = self.pos;
+		Ok(match self.next()? {
+			b'd' => digits,
+			b'D' => digits.complement(),
+			b'w' => word,
+			b'W' => word.complement(),
+			b's' => space,
+			b'S' => space.complement(),
+			b'n' => ByteSet::byte(b'\n'),
+			b'r' => ByteSet::byte(b'\r'),
+			b't' => ByteSet::byte(b'\t'),
+			b'x' => {
+				let hex = [self.next()?, self.next()?];
+				let byte = std::str::from_utf8(&hex)
+					.ok()
+					.and_then(|hex| u8::from_str_radix(hex, 16).ok())
+					.ok_or_else(|| anyhow::anyhow!("invalid hexadecimal escape at {pos}"))?;
+				ByteSet::byte(byte)
+			}
+			byte if byte.is_ascii_punctuation() => ByteSet::byte(byte),
+			byte => bail!("unknown escape {:?} at {pos}", byte as char),
+		})
+	}
+
+	fn class(&mut self) -> Result<ByteSet> {
+		let start = self.pos;
+		let negated = self.eat(b'^');
+		let mut set = ByteSet::EMPTY;
+		// A closing bracket right after the opening one is a literal
+		while self.peek() != Some(b']') || self.pos == start + negated as usize {
+			let pos = self.pos;
+			let lo = match self.next()? {
+				b'\\' => self.escape()?,
+				byte => ByteSet::byte(byte),
+			};
+			if self.peek() == Some(b'-') && self.pattern.get(self.pos + 1) != Some(&b']') {
+				self.pos += 1;
+				let hi = self.next()?;
+				let lo = self.pattern[pos];
+				ensure!(lo != b'\\' && lo <= hi, "invalid class range at {pos}");
+				set = set.union(ByteSet::range(lo, hi));
+			} else {
+				set = set.union(lo);
+			}
+		}
+		self.pos += 1;
+		Ok(if negated { set.complement() } else { set })
+	}
+}
+
+/// A nondeterministic automaton with epsilon transitions and at most one byte transition per
+/// state.
+#[derive(Default)]
+struct Nfa {
+	epsilon: Vec<Vec<usize>>,
+	bytes: Vec<Option<(ByteSet, usize)>>,
+}
+
+impl Nfa {
+	fn add_state(&mut self) -> usize {
+		self.epsilon.push(Vec::new());
+		self.bytes.push(None);
+		self.epsilon.len() - 1
+	}
+
+	/// Adds the states of `node`, returning its start and end states.
+	fn add(&mut self, node: &Node) -> (usize, usize) {
+		let start = self.add_state();
+		let end = match node {
+			Node::Bytes(set) => {
+				let end = self.add_state();
+				self.bytes[start] = Some((*set, end));
+				end
+			}
+			Node::Concat(nodes) => nodes.iter().fold(start, |end, node| {
+				let (node_start, node_end) = self.add(node);
+				self.epsilon[end].push(node_start);
+				node_end
+			}),
+			Node::Alternation(nodes) => {
+				let end = self.add_state();
+				for node in nodes {
+					let (node_start, node_end) = self.add(node);
+					self.epsilon[start].push(node_start);
+					self.epsilon[node_end].push(end);
+				}
+				end
+			}
+			Node::Repetition { node, min, max } => {
+				let mut end = start;
+				for _ in 0..*min {
+					let (node_start, node_end) = self.add(node);
+					self.epsilon[end].push(node_start);
+					end = node_end;
+				}
+				match max {
+					Some(max) => {
+						let skip = self.add_state();
+						for _ in *min..*max {
+							let (node_start, node_end) = self.add(node);
+							self.epsilon[end].push(node_start);
+							self.epsilon[end].push(skip);
+							end = node_end;
+						}
+						self.epsilon[end].push(skip);
+						skip
+					}
+					None => {
+						let (node_start, node_end) = self.add(node);
+						let loop_end = self.add_state();
+						self.epsilon[end].push(node_start);
+						self.epsilon[end].push(loop_end);
+						self.epsilon[node_end].push(node_start);
+						self.epsilon[node_end].push(loop_end);
+						loop_end
+					}
+				}
+			}
+		};
+		(start, end)
+	}
+
+	fn closure(&self, states: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+		let mut closure = BTreeSet::new();
+		let mut stack = states.into_iter().collect::<Vec<_>>();
+		while let Some(state) = stack.pop() {
+			if closure.insert(state) {
+				stack.extend(&self.epsilon[state]);
+			}
+		}
+		closure
+	}
+}
+
+/// Compiles `pattern` to an automaton whose output is 1 after the bytes where a match ends and 0
+/// after the others.
+///
+/// Without a leading `^`, matches may start at any byte. The automaton reads any input, so it
+/// never rejects.
+pub fn compile(pattern: &str) -> Result<Automaton> {
+	let (anchored, pattern) = match pattern.strip_prefix('^') {
+		Some(pattern) => (true, pattern),
+		None => (false, pattern),
+	};
+	let mut parser = Parser {
+		pattern: pattern.as_bytes(),
+		pos: 0,
+	};
+	let node = parser.alternation()?;
+	ensure!(parser.peek().is_none(), "unmatched ')' at {}", parser.pos);
+
+	let mut nfa = Nfa::default();
+	let (start, accept) = nfa.add(&node);
+	let initial = nfa.closure([start]);
+	Automaton::from_fn(initial.clone(), |states, byte| {
+		let targets = states.iter().filter_map(|&state| {
+			nfa.bytes[state]
+				.filter(|(set, _)| set.contains(byte))
+				.map(|(_, target)| target)
+		});
+		let mut next = nfa.closure(targets);
+		if !anchored {
+			next.extend(&initial);
+		}
+		let matched = next.contains(&accept);
+		Some((next, matched as u8))
+	})
+}
+
+/// Returns a byte column that is 1 in the rows where a match of `pattern` ends and 0 in the
+/// others, over the first `count` bytes of `input`, see [`compile`].
+///
+/// For instance, the input matches `^pattern$` if the column is 1 in its last row, and contains
+/// a match of `pattern` if the column is 1 in any row. The automaton of the pattern is run with
+/// [`run_automaton`], whose lookup table has 256 rows per state of the automaton, and which
+/// fails if the automaton has over 256 states. The returned boundary must be supplied to the
+/// prover and the verifier.
+pub fn regex_match<U, F, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	pattern: &str,
+	input: OracleId,
+	count: usize,
+) -> Result<(OracleId, Boundary<F>)>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<B8> + PackScalar<B32> + Pod,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	let automaton = compile(pattern)?;
+	let run = run_automaton::<U, F, LOG_MAX_MULTIPLICITY>(
+		builder, name, input, count, &automaton, false,
+	)?;
+	let matches = run
+		.outputs
+		.ok_or_else(|| anyhow::anyhow!("the pattern {pattern:?} never matches"))?;
+	Ok((matches, run.boundary))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// The rows where matches end, as a string of the matching bytes and underscores.
+	fn match_ends(pattern: &str, text: &str) -> String {
+		let automaton = compile(pattern).unwrap();
+		let trace = automaton.trace(text.as_bytes()).unwrap();
+		text.chars()
+			.zip(trace)
+			.map(|(c, transition)| if transition.output == 1 { c } else { '_' })
+			.collect()
+	}
+
+	#[test]
+	fn test_match_ends() {
+		assert_eq!(match_ends("ab", "abcabab"), "_b__b_b");
+		assert_eq!(match_ends("^ab", "abcabab"), "_b_____");
+		assert_eq!(match_ends("a(b|cd)+", "abcdxacd"), "_b_d___d");
+		assert_eq!(match_ends(r"\d{2,3}", "a1234"), "__234");
+		assert_eq!(match_ends("[^,]+,", "ab,,c,"), "__,__,");
+		assert_eq!(match_ends(r"^[a-z]+@[a-z]+\.com", "bob@mail.com"), "___________m");
+	}
+
+	#[test]
+	fn test_full_match() {
+		let full_match = |pattern: &str, text: &str| {
+			let automaton = compile(&format!("^({pattern})")).unwrap();
+			let trace = automaton.trace(text.as_bytes()).unwrap();
+			trace.last().unwrap().output == 1
+		};
+		let url = r"https?://[\w.-]+(/[\w./%-]*)?(\?[\w=&%-]*)?";
+		assert!(full_match(url, "https://example.com/a/b.html?q=1&r=2"));
+		assert!(full_match(url, "http://example.com"));
+		assert!(!full_match(url, "ftp://example.com"));
+		assert!(!full_match(url, "https://exa mple.com"));
+		assert!(full_match("x{3}y?", "xxx"));
+		assert!(!full_match("x{3}y?", "xxxx"));
+		assert!(full_match("caf\u{e9}|tea", "caf\u{e9}"));
+	}
+
+	#[test]
+	fn test_invalid_patterns() {
+		for pattern in [
+			"(ab", "ab)", "a**b", "[a-", r"\q", "x{65}", "x{3,2}", "a$b", r"\x4",
+		] {
+			assert!(compile(pattern).is_err(), "{pattern}");
+		}
+	}
+}
@@ -54,12 +54,11 @@ where
 		.iter()
 		.map(|(oracle_id, shift, t)| {
 			match t {
-				RotateRightType::Circular => builder.add_shifted(
+				RotateRightType::Circular => builder.add_rotated(
 					format!("RotateRightType::Circular shift:{} oracle_id: {}", shift, oracle_id),
 					*oracle_id,
-					32 - shift,
+					*shift,
 					LOG_U32_BITS,
-					ShiftVariant::CircularLeft,
 				),
 				RotateRightType::Logical => builder.add_shifted(
 					format!("RotateRightType::Logical shift:{} oracle_id: {}", shift, oracle_id),
@@ -165,6 +164,28 @@ where
 		Err(anyhow::Error::msg("log_size too small"))?
 	}
 
+	let init = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
+	compress(builder, init, input, log_size)
+}
+
+/// The SHA-256 compression function, which absorbs the block `input` into the chaining `state`.
+///
+/// Both are given by columns of 32-bit words, one compression per row. Chaining compressions
+/// from [`INIT`] over the padded blocks of a message computes its hash.
+pub fn compress<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	state: [OracleId; 8],
+	input: [OracleId; 16],
+	log_size: usize,
+) -> Result<[OracleId; 8], anyhow::Error>
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<B1>,
+	F: TowerField,
+{
+	if log_size < <PackedType<U, BinaryField1b>>::LOG_WIDTH {
+		Err(anyhow::Error::msg("log_size too small"))?
+	}
+
 	let mut w = [OracleId::MAX; 64];
 
 	w[0..16].copy_from_slice(&input);
@@ -207,9 +228,7 @@ where
 		)?;
 	}
 
-	let init_oracles = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
-
-	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = init_oracles;
+	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
 
 	let k = ROUND_CONSTS_K
 		.map(|val| u32const_repeating(log_size, builder, val, "ROUND_CONSTS_K").unwrap());
@@ -307,14 +326,8 @@ where
 	let abcdefgh = [a, b, c, d, e, f, g, h];
 
 	let output = std::array::from_fn(|i| {
-		arithmetic::u32::add(
-			builder,
-			"output",
-			init_oracles[i],
-			abcdefgh[i],
-			arithmetic::Flags::Unchecked,
-		)
-		.unwrap()
+		arithmetic::u32::add(builder, "output", state[i], abcdefgh[i], arithmetic::Flags::Unchecked)
+			.unwrap()
 	});
 
 	Ok(output)
@@ -0,0 +1,115 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The SHA3-256 hash function, built from the sponge construction over the [`keccakf`]
+//! permutation.
+//!
+//! The message is laid out by a [`message_schedule`] in blocks of [`RATE_BYTES`] bytes, one block
+//! per row, and absorbed by a permutation table of the same height, each row calling the
+//! permutation once.
+
+use std::array;
+
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, BinaryField64b,
+	BinaryField8b, ExtensionField, TowerField,
+};
+use bytemuck::Pod;
+
+use crate::{
+	builder::ConstraintSystemBuilder,
+	keccakf::{keccakf, KeccakfState},
+	message_schedule::{absorb_rows, message_schedule, MessageSchedule},
+};
+
+/// The number of message bytes absorbed per permutation call.
+pub const RATE_BYTES: usize = 136;
+/// The number of state lanes the message is absorbed into.
+pub const RATE_LANES: usize = RATE_BYTES / 8;
+/// The number of state lanes holding the digest.
+pub const DIGEST_LANES: usize = 4;
+
+const STATE_LANES: usize = 25;
+
+/// The digest of a message hashed by [`sha3_256`].
+#[derive(Debug, Clone)]
+pub struct Sha3Digest {
+	/// The first [`DIGEST_LANES`] lanes of the permutation output. They hold the digest, with its
+	/// bytes in little-endian order, in row [`Self::row`].
+	pub lanes: [OracleId; DIGEST_LANES],
+	/// The row of the last block of the message.
+	pub row: usize,
+	/// The schedule of the message, whose [`MessageSchedule::length_boundary`] the statement must
+	/// include.
+	pub schedule: MessageSchedule,
+}
+
+/// Hashes a message of `length` bytes with SHA3-256.
+///
+/// The message is given by [`RATE_BYTES`] byte columns, where the value of column `k` in row `i`
+/// is byte `i * RATE_BYTES + k` of the message. The bytes after the message must be zero, which
+/// is constrained. The padded message, which always ends with a padding byte, has
+/// `length / RATE_BYTES + 1` blocks and must fit into the rows of the columns. The rows after the
+/// last block are ignored.
+pub fn sha3_256<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	message: [OracleId; RATE_BYTES],
+	length: usize,
+) -> Result<Sha3Digest, anyhow::Error>
+where
+	U: UnderlierType
+		+ Pod
+		+ PackScalar<F>
+		+ PackScalar<BinaryField64b>
+		+ PackScalar<BinaryField8b>
+		+ PackScalar<BinaryField1b>,
+	F: TowerField + ExtensionField<BinaryField64b> + ExtensionField<BinaryField8b>,
+{
+	builder.push_namespace(name);
+	let schedule = message_schedule::<_, _, BinaryField64b>(
+		builder,
+		"schedule",
+		&message,
+		length,
+		&padding(length),
+	)?;
+	let rows = absorb_rows(
+		builder,
+		"absorb",
+		&schedule,
+		schedule.log_size,
+		STATE_LANES,
+		|state: &mut [BinaryField64b]| {
+			let mut lanes: [u64; STATE_LANES] = array::from_fn(|lane| state[lane].val());
+			tiny_keccak::keccakf(&mut lanes);
+			for (lane, value) in state.iter_mut().zip(lanes) {
+				*lane = BinaryField64b::new(value);
+			}
+		},
+	)?;
+	let inputs = rows.inputs.as_ref().map(|inputs| {
+		inputs
+			.iter()
+			.map(|state| KeccakfState(array::from_fn(|lane| state[lane].val())))
+			.collect::<Vec<_>>()
+	});
+	let permutation = keccakf(builder, inputs, schedule.log_size)?;
+	rows.link(builder, &permutation.input, &permutation.output)?;
+
+	builder.pop_namespace();
+	Ok(Sha3Digest {
+		lanes: array::from_fn(|lane| permutation.output[lane]),
+		row: schedule.n_blocks - 1,
+		schedule,
+	})
+}
+
+/// The padding after a message of `length` bytes: the domain separation bits of SHA-3 followed by
+/// the `pad10*1` rule, up to the end of the block.
+fn padding(length: usize) -> Vec<u8> {
+	let mut padding = vec![0u8; RATE_BYTES - length % RATE_BYTES];
+	padding[0] ^= 0x06;
+	*padding.last_mut().expect("the padding is not empty") ^= 0x80;
+	padding
+}
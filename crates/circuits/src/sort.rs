@@ -0,0 +1,134 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Gadgets for asserting that u32 columns are sorted.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, ExtensionField, TowerField,
+};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+
+use crate::{arithmetic, builder::ConstraintSystemBuilder, permutation, transparent::step_down};
+
+/// Asserts that the first `count` u32 values of a column are sorted in nondecreasing order.
+///
+/// For every pair of consecutive rows the prover commits the difference `next - current`, and
+/// the gadget checks that `current + diff` equals `next` without overflow. As the difference is a
+/// u32, this is a range check of `next - current` to `[0, 2^32)`.
+pub fn assert_sorted<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	count: usize,
+) -> Result<(), anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	anyhow::ensure!(log_rows >= 5, "Polynomial must have n_vars >= 5. Got {log_rows}");
+	let packed_log_rows = log_rows - 5;
+	anyhow::ensure!(
+		(1..=1 << packed_log_rows).contains(&count),
+		"count must be between 1 and {}, got {count}",
+		1 << packed_log_rows
+	);
+
+	let next = builder.add_shifted("next", input, 32, log_rows, ShiftVariant::LogicalRight)?;
+	let diff = builder.add_committed("diff", log_rows, BinaryField1b::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField1b>(input)?.as_slice::<u32>();
+		let mut next = witness.new_column::<BinaryField1b>(next);
+		let mut diff = witness.new_column::<BinaryField1b>(diff);
+		let next = next.as_mut_slice::<u32>();
+		let diff = diff.as_mut_slice::<u32>();
+		next[..input.len() - 1].copy_from_slice(&input[1..]);
+		for i in 0..count - 1 {
+			diff[i] = input[i + 1]
+				.checked_sub(input[i])
+				.ok_or_else(|| anyhow::anyhow!("row {} is smaller than row {i}", i + 1))?;
+		}
+	}
+
+	let enabled = step_down(builder, "enabled", packed_log_rows, count - 1)?;
+	let sum = arithmetic::u32::add(builder, "sum", input, diff, arithmetic::Flags::Checked)?;
+	let sum_packed = arithmetic::u32::packed(builder, "sum_packed", sum)?;
+	let next_packed = arithmetic::u32::packed(builder, "next_packed", next)?;
+
+	builder.assert_zero(
+		"sorted",
+		[sum_packed, next_packed, enabled],
+		arith_expr!(F[a, b, enabled] = (a - b) * enabled),
+	);
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+/// Commits a copy of a tuple of u32 columns with the first `count` rows sorted by the first
+/// column, and constrains it to be sorted and a row permutation of the original.
+///
+/// The sort is stable, so rows with equal keys keep their original relative order. Rows past
+/// `count` are left zero in the sorted copy. Returns the sorted columns in the order of `inputs`.
+pub fn sort_witness<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	inputs: &[OracleId],
+	count: usize,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<BinaryField32b> + Pod,
+	F: TowerField + ExtensionField<BinaryField32b>,
+{
+	anyhow::ensure!(!inputs.is_empty(), "expected at least one column to sort");
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(inputs.iter().copied())?;
+	let sorted = (0..inputs.len())
+		.map(|i| builder.add_committed(format!("sorted_{i}"), log_rows, BinaryField1b::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	let mut order = None;
+	if let Some(witness) = builder.witness() {
+		let keys = witness.get::<BinaryField1b>(inputs[0])?.as_slice::<u32>();
+		anyhow::ensure!(count <= keys.len(), "count {count} exceeds the number of rows");
+		let mut perm = (0..count).collect::<Vec<_>>();
+		perm.sort_by_key(|&i| keys[i]);
+
+		for (&input, &sorted) in inputs.iter().zip(&sorted) {
+			let input = witness.get::<BinaryField1b>(input)?.as_slice::<u32>();
+			let mut sorted = witness.new_column::<BinaryField1b>(sorted);
+			let sorted = sorted.as_mut_slice::<u32>();
+			for (row, &i) in perm.iter().enumerate() {
+				sorted[row] = input[i];
+			}
+		}
+		order = Some(perm);
+	}
+
+	assert_sorted(builder, "assert_sorted", sorted[0], count)?;
+
+	let inputs_packed = inputs
+		.iter()
+		.enumerate()
+		.map(|(i, &input)| arithmetic::u32::packed(builder, format!("input_packed_{i}"), input))
+		.collect::<Result<Vec<_>, _>>()?;
+	let sorted_packed = sorted
+		.iter()
+		.enumerate()
+		.map(|(i, &sorted)| arithmetic::u32::packed(builder, format!("sorted_packed_{i}"), sorted))
+		.collect::<Result<Vec<_>, _>>()?;
+	permutation::permutation::<_, _, BinaryField32b>(
+		builder,
+		&inputs_packed,
+		&sorted_packed,
+		count,
+		order.as_deref(),
+	)?;
+
+	builder.pop_namespace();
+	Ok(sorted)
+}
@@ -0,0 +1,1264 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Property-based testing harness for gadgets.
+//!
+//! [`check_gadget`] runs a circuit on inputs generated by a [`proptest`] strategy. Every case
+//! builds a fresh constraint system with a witness, extracts the witness, and validates it against
+//! the constraints; the first [`Config::prove_cases`] cases additionally go through a full
+//! prove/verify cycle. When a case fails, proptest shrinks the input and the returned
+//! [`TestError`] reports the minimal failing one.
+//!
+//! The circuit closure may also compare the witness it filled against a reference implementation
+//! and return an error on a mismatch, which fails the case like an unsatisfied constraint.
+//!
+//! [`check_agreement`] instead checks that witness validation and the full protocol agree on
+//! whether circuits with possibly invalid witnesses, like the ones generated by [`random_system`],
+//! are satisfied. Divergences are shrunk the same way, so the returned [`TestError`] holds a
+//! minimal system on which the cheap checker and the protocol disagree.
+//!
+//! [`SoundnessProbe`] goes the other way: it takes a valid witness of a gadget, perturbs chosen
+//! rows of a committed column, and checks that the constraints reject the result. Probing every
+//! bit of the outputs of a gadget with [`SoundnessProbe::check_bits`] shows that its constraints
+//! bind each of them, rather than only being satisfied by the witness the gadget fills in.
+//!
+//! The module is available to the tests of this crate and, with the `proptest` feature, to
+//! downstream crates.
+
+use std::{
+	cell::Cell,
+	fmt::Debug,
+	iter,
+	panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use anyhow::Context;
+use binius_core::{
+	constraint_system::{
+		self, channel::Boundary, validate::validate_witness, ConstraintSystem, PCSParams,
+	},
+	fiat_shamir::HasherChallenger,
+	oracle::{MultilinearPolyOracle, OracleId},
+	tower::CanonicalTowerFamily,
+	witness::{MultilinearExtensionIndex, MultilinearWitness},
+};
+use binius_field::{
+	arch::OptimalUnderlier,
+	as_packed_field::{PackScalar, PackedType},
+	underlier::WithUnderlier,
+	BinaryField128b, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField64b, BinaryField8b,
+	ExtensionField, RepackedExtension, TowerField,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_macros::arith_expr;
+use binius_math::{DefaultEvaluationDomainFactory, MultilinearExtension};
+use bytemuck::must_cast_slice_mut;
+use groestl_crypto::Groestl256;
+use proptest::{
+	collection::vec,
+	prelude::{any, prop_oneof, Just},
+	strategy::Strategy,
+	test_runner::{Config as ProptestConfig, TestCaseError, TestError, TestRunner},
+};
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// The underlier of the constraint systems built by the harness.
+pub type U = OptimalUnderlier;
+
+/// The field of the constraint systems built by the harness.
+pub type F = BinaryField128b;
+
+/// Settings of a [`check_gadget`] run.
+#[derive(Debug, Clone)]
+pub struct Config {
+	/// The number of generated inputs to check.
+	pub cases: u32,
+	/// The number of leading cases that are also proven and verified, which is much slower than
+	/// validating the witness.
+	pub prove_cases: u32,
+	/// The maximum number of steps spent shrinking a failing input.
+	pub max_shrink_iters: u32,
+	/// The parameters of the polynomial commitment scheme used to prove.
+	pub pcs_params: PCSParams,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			cases: 32,
+			prove_cases: 1,
+			max_shrink_iters: 4096,
+			pcs_params: PCSParams::new(1, 10),
+		}
+	}
+}
+
+/// Checks a circuit on inputs generated by `strategy`.
+///
+/// `circuit` adds the gadget under test to an empty builder with a witness, fills the input
+/// columns from the generated value, and returns the boundary values of the statement. Fails with
+/// the minimal failing input if any case errors while building, fails witness validation, or,
+/// for the first [`Config::prove_cases`] cases, is rejected by the verifier.
+pub fn check_gadget<S>(
+	config: &Config,
+	strategy: S,
+	circuit: impl Fn(
+		&mut ConstraintSystemBuilder<U, F>,
+		&S::Value,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error>,
+) -> Result<(), TestError<S::Value>>
+where
+	S: Strategy,
+	S::Value: Debug,
+{
+	let mut runner = TestRunner::new(ProptestConfig {
+		cases: config.cases,
+		max_shrink_iters: config.max_shrink_iters,
+		failure_persistence: None,
+		..ProptestConfig::default()
+	});
+	// Once a proven case fails, the shrunk inputs are proven as well so that the failure reproduces.
+	let proven = Cell::new(0);
+	let proving_failed = Cell::new(false);
+	runner.run(&strategy, |input| {
+		let prove = proven.get() < config.prove_cases || proving_failed.get();
+		if prove {
+			proven.set(proven.get() + 1);
+		}
+		run_case(config, &circuit, &input, prove).map_err(|err| {
+			proving_failed.set(prove);
+			TestCaseError::fail(format!("{err:#}"))
+		})
+	})
+}
+
+/// Checks that witness validation and the protocol agree on circuits built from inputs generated
+/// by `strategy`.
+///
+/// Unlike for [`check_gadget`], `circuit` may fill in witnesses that do not satisfy its
+/// constraints. Every case is validated and also proven and verified, and fails if exactly one of
+/// the two rejects it: a witness that validates but is rejected by the protocol points to a
+/// completeness bug, and one that fails validation but is accepted by the verifier to a soundness
+/// bug or a check of the validator the protocol lacks. A prover that errors or panics counts as a
+/// rejection. All cases are proven, [`Config::prove_cases`] is ignored.
+pub fn check_agreement<S>(
+	config: &Config,
+	strategy: S,
+	circuit: impl Fn(
+		&mut ConstraintSystemBuilder<U, F>,
+		&S::Value,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error>,
+) -> Result<(), TestError<S::Value>>
+where
+	S: Strategy,
+	S::Value: Debug,
+{
+	let mut runner = TestRunner::new(ProptestConfig {
+		cases: config.cases,
+		max_shrink_iters: config.max_shrink_iters,
+		failure_persistence: None,
+		..ProptestConfig::default()
+	});
+	runner.run(&strategy, |input| {
+		agreement_case(config, &circuit, &input)
+			.map_err(|err| TestCaseError::fail(format!("{err:#}")))
+	})
+}
+
+fn agreement_case<T>(
+	config: &Config,
+	circuit: &impl Fn(&mut ConstraintSystemBuilder<U, F>, &T) -> Result<Vec<Boundary<F>>, anyhow::Error>,
+	input: &T,
+) -> Result<(), anyhow::Error> {
+	let allocator = bumpalo::Bump::new();
+	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let boundaries = circuit(&mut builder, input)?;
+	let witness = builder.take_witness()?;
+	let constraint_system = builder.build()?;
+
+	let validated = validate_witness(&constraint_system, &boundaries, &witness);
+	let proven = catch_unwind(AssertUnwindSafe(|| {
+		prove_and_verify(config, &constraint_system, boundaries, witness)
+	}))
+	.unwrap_or_else(|_| Err(anyhow::anyhow!("the prover panicked")));
+	match (validated, proven) {
+		(Ok(()), Err(err)) => {
+			anyhow::bail!("the witness is valid, but the protocol rejects it: {err:#}")
+		}
+		(Err(err), Ok(())) => {
+			anyhow::bail!("the witness is invalid, but the protocol accepts it: {err:#}")
+		}
+		_ => Ok(()),
+	}
+}
+
+fn run_case<T>(
+	config: &Config,
+	circuit: &impl Fn(&mut ConstraintSystemBuilder<U, F>, &T) -> Result<Vec<Boundary<F>>, anyhow::Error>,
+	input: &T,
+	prove: bool,
+) -> Result<(), anyhow::Error> {
+	let allocator = bumpalo::Bump::new();
+	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let boundaries = circuit(&mut builder, input)?;
+	let witness = builder.take_witness()?;
+	let constraint_system = builder.build()?;
+	validate_witness(&constraint_system, &boundaries, &witness)?;
+	if !prove {
+		return Ok(());
+	}
+	prove_and_verify(config, &constraint_system, boundaries, witness)
+}
+
+fn prove_and_verify(
+	config: &Config,
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: Vec<Boundary<F>>,
+	witness: MultilinearExtensionIndex<U, F>,
+) -> Result<(), anyhow::Error> {
+	let domain_factory = DefaultEvaluationDomainFactory::default();
+	let backend = make_portable_backend();
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		_,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(constraint_system, &config.pcs_params, witness, &domain_factory, &backend)?;
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(constraint_system, &config.pcs_params, boundaries, proof)?;
+	Ok(())
+}
+
+/// A change to one row of a committed column, flipping the bits of the row that are set in
+/// `mask`.
+///
+/// Rows are scalars of the tower field of the column, so the rows of a 1-bit column holding `u32`
+/// values are the bits of the values, least significant first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Perturbation {
+	pub oracle: OracleId,
+	pub row: usize,
+	pub mask: u128,
+}
+
+impl Perturbation {
+	pub fn flip_bit(oracle: OracleId, row: usize, bit: usize) -> Self {
+		Self {
+			oracle,
+			row,
+			mask: 1 << bit,
+		}
+	}
+}
+
+/// A constraint system with a valid witness, which checks that perturbations of the witness are
+/// rejected.
+///
+/// Only the perturbed committed column changes: the witnesses of virtual oracles derived from it
+/// keep their original values. A perturbation therefore counts as caught only if a constraint,
+/// non-zero check or channel rejects it, not if it is merely inconsistent with a stale virtual
+/// oracle, since a dishonest prover would recompute those.
+pub struct SoundnessProbe<'arena> {
+	constraint_system: ConstraintSystem<F>,
+	boundaries: Vec<Boundary<F>>,
+	witness: MultilinearExtensionIndex<'arena, U, F>,
+}
+
+impl<'arena> SoundnessProbe<'arena> {
+	/// Builds the constraint system and the witness of `builder`, which must be valid.
+	pub fn new(
+		mut builder: ConstraintSystemBuilder<'arena, U, F>,
+		boundaries: Vec<Boundary<F>>,
+	) -> Result<Self, anyhow::Error> {
+		let witness = builder.take_witness()?;
+		let constraint_system = builder.build()?;
+		validate_witness(&constraint_system, &boundaries, &witness)
+			.context("the unperturbed witness is invalid")?;
+		Ok(Self {
+			constraint_system,
+			boundaries,
+			witness,
+		})
+	}
+
+	/// Checks that witness validation rejects the witness with `perturbation` applied.
+	///
+	/// The witness is restored afterwards, so that probes can be checked one after another.
+	pub fn check(&mut self, perturbation: Perturbation) -> Result<(), anyhow::Error> {
+		let perturbed = self.perturbed(perturbation)?;
+		let original = self.witness.get_multilin_poly(perturbation.oracle)?;
+		self.witness
+			.update_multilin_poly([(perturbation.oracle, perturbed)])?;
+		let result = validate_witness(&self.constraint_system, &self.boundaries, &self.witness);
+		self.witness
+			.update_multilin_poly([(perturbation.oracle, original)])?;
+
+		let label = self.constraint_system.oracles.label(perturbation.oracle);
+		match result {
+			Ok(()) => anyhow::bail!("the constraints accept {perturbation:?} of {label}"),
+			Err(constraint_system::error::Error::VirtualOracleEvalMismatch { oracle, .. }) => {
+				anyhow::bail!(
+					"the constraints accept {perturbation:?} of {label}, only the stale witness \
+					 of {oracle} rejects it"
+				)
+			}
+			Err(_) => Ok(()),
+		}
+	}
+
+	/// Checks every single-bit perturbation of every row of the committed column `id`.
+	pub fn check_bits(&mut self, id: OracleId) -> Result<(), anyhow::Error> {
+		let (n_vars, tower_level) = self.committed(id)?;
+		for row in 0..1 << n_vars {
+			for bit in 0..1 << tower_level {
+				self.check(Perturbation::flip_bit(id, row, bit))?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Checks every single-bit perturbation of every committed column other than `inputs`, which
+	/// covers the outputs of a gadget along with its auxiliary columns.
+	pub fn check_all_except(&mut self, inputs: &[OracleId]) -> Result<(), anyhow::Error> {
+		let committed = self
+			.constraint_system
+			.oracles
+			.iter()
+			.filter(|oracle| matches!(oracle, MultilinearPolyOracle::Committed { .. }))
+			.map(|oracle| oracle.id())
+			.filter(|id| !inputs.contains(id))
+			.collect::<Vec<_>>();
+		for id in committed {
+			self.check_bits(id)?;
+		}
+		Ok(())
+	}
+
+	/// Proves the witness with `perturbation` applied and checks that the prover fails or the
+	/// verifier rejects the proof.
+	///
+	/// This is much slower than [`Self::check`], and consumes the probe since proving consumes the
+	/// witness.
+	pub fn check_proof(
+		self,
+		config: &Config,
+		perturbation: Perturbation,
+	) -> Result<(), anyhow::Error> {
+		let perturbed = self.perturbed(perturbation)?;
+		let Self {
+			constraint_system,
+			boundaries,
+			mut witness,
+		} = self;
+		witness.update_multilin_poly([(perturbation.oracle, perturbed)])?;
+		let proven = catch_unwind(AssertUnwindSafe(|| {
+			prove_and_verify(config, &constraint_system, boundaries, witness)
+		}))
+		.unwrap_or_else(|_| Err(anyhow::anyhow!("the prover panicked")));
+		anyhow::ensure!(
+			proven.is_err(),
+			"the protocol accepts {perturbation:?} of {}",
+			constraint_system.oracles.label(perturbation.oracle)
+		);
+		Ok(())
+	}
+
+	/// The number of variables and the tower level of the committed oracle `id`.
+	fn committed(&self, id: OracleId) -> Result<(usize, usize), anyhow::Error> {
+		let oracles = &self.constraint_system.oracles;
+		anyhow::ensure!(oracles.is_valid_oracle_id(id), "invalid oracle id {id}");
+		match oracles.oracle(id) {
+			MultilinearPolyOracle::Committed {
+				n_vars,
+				tower_level,
+				..
+			} => Ok((n_vars, tower_level)),
+			oracle => {
+				anyhow::bail!("only committed oracles can be perturbed, not {}", oracle.label())
+			}
+		}
+	}
+
+	fn perturbed(
+		&self,
+		perturbation: Perturbation,
+	) -> Result<MultilinearWitness<'arena, PackedType<U, F>>, anyhow::Error> {
+		let (n_vars, tower_level) = self.committed(perturbation.oracle)?;
+		anyhow::ensure!(
+			perturbation.row < 1 << n_vars,
+			"row {} is out of range for a column of 2^{n_vars} rows",
+			perturbation.row
+		);
+		anyhow::ensure!(
+			tower_level == F::TOWER_LEVEL || perturbation.mask >> (1 << tower_level) == 0,
+			"the mask {:#x} has more than 2^{tower_level} bits",
+			perturbation.mask
+		);
+		match tower_level {
+			0 => flip_bits::<BinaryField1b>(&self.witness, perturbation, n_vars),
+			3 => flip_bits::<BinaryField8b>(&self.witness, perturbation, n_vars),
+			4 => flip_bits::<BinaryField16b>(&self.witness, perturbation, n_vars),
+			5 => flip_bits::<BinaryField32b>(&self.witness, perturbation, n_vars),
+			6 => flip_bits::<BinaryField64b>(&self.witness, perturbation, n_vars),
+			7 => flip_bits::<BinaryField128b>(&self.witness, perturbation, n_vars),
+			_ => anyhow::bail!("unsupported tower level {tower_level}"),
+		}
+	}
+}
+
+/// Copies the column of `perturbation` with the bits of its row flipped.
+fn flip_bits<'arena, FS>(
+	witness: &MultilinearExtensionIndex<'arena, U, F>,
+	perturbation: Perturbation,
+	n_vars: usize,
+) -> Result<MultilinearWitness<'arena, PackedType<U, F>>, anyhow::Error>
+where
+	FS: TowerField,
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+	PackedType<U, F>: RepackedExtension<PackedType<U, FS>>,
+{
+	let column = witness.get::<FS>(perturbation.oracle)?;
+	let mut underliers = PackedType::<U, FS>::to_underliers_ref(column.evals()).to_vec();
+	let bytes = must_cast_slice_mut::<U, u8>(&mut underliers);
+	for bit in (0..FS::N_BITS).filter(|&bit| perturbation.mask >> bit & 1 == 1) {
+		let index = perturbation.row * FS::N_BITS + bit;
+		bytes[index / 8] ^= 1 << (index % 8);
+	}
+	let packed = underliers
+		.into_iter()
+		.map(PackedType::<U, FS>::from_underlier)
+		.collect::<Vec<_>>();
+	Ok(MultilinearExtension::new(n_vars, packed)?.specialize_arc_dyn())
+}
+
+/// A small constraint system over 8-bit columns with a witness that may not satisfy it, generated
+/// by [`random_system`].
+#[derive(Debug, Clone)]
+pub struct RandomSystem {
+	/// The values of the committed input columns, each with the same power-of-two number of rows.
+	pub inputs: Vec<Vec<u8>>,
+	/// Committed columns constrained to be the product of two input columns, given by their
+	/// indices, with rows whose product is overwritten by the given values.
+	#[allow(clippy::type_complexity)]
+	pub products: Vec<(usize, usize, Vec<(usize, u8)>)>,
+	/// Committed columns holding the rows of an input column, given by its index, in the given
+	/// order, which are constrained to be a permutation of the input column by a channel. The
+	/// order may repeat rows, so that the channel is unbalanced.
+	pub permutations: Vec<(usize, Vec<usize>)>,
+}
+
+impl RandomSystem {
+	/// Adds the system to `builder`, filling in its witness if the builder has one.
+	pub fn build(
+		&self,
+		builder: &mut ConstraintSystemBuilder<U, F>,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let n_rows = self.inputs.first().map_or(0, Vec::len);
+		anyhow::ensure!(
+			n_rows.is_power_of_two() && self.inputs.iter().all(|input| input.len() == n_rows),
+			"the inputs must have the same power-of-two number of rows"
+		);
+		let log_rows = n_rows.ilog2() as usize;
+
+		let column = |builder: &mut ConstraintSystemBuilder<U, F>, name: String, values: &[u8]| {
+			let id = builder.add_committed(name, log_rows, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				witness
+					.new_column::<BinaryField8b>(id)
+					.as_mut_slice::<u8>()
+					.copy_from_slice(values);
+			}
+			id
+		};
+
+		let inputs = self
+			.inputs
+			.iter()
+			.enumerate()
+			.map(|(i, values)| column(builder, format!("input_{i}"), values))
+			.collect::<Vec<_>>();
+
+		// The protocol only opens committed columns that some constraint refers to, so balance
+		// each input against itself to give it an evaluation claim.
+		for &input in &inputs {
+			let channel = builder.add_channel();
+			builder.send(channel, n_rows, [input]);
+			builder.receive(channel, n_rows, [input]);
+		}
+
+		for (i, (lhs, rhs, overwrites)) in self.products.iter().enumerate() {
+			let mut values = iter::zip(&self.inputs[*lhs], &self.inputs[*rhs])
+				.map(|(&lhs, &rhs)| (BinaryField8b::new(lhs) * BinaryField8b::new(rhs)).val())
+				.collect::<Vec<_>>();
+			for &(row, value) in overwrites {
+				values[row] = value;
+			}
+			let product = column(builder, format!("product_{i}"), &values);
+			builder.assert_zero(
+				format!("product_{i}"),
+				[inputs[*lhs], inputs[*rhs], product],
+				arith_expr!([x, y, z] = x * y - z).convert_field(),
+			);
+		}
+
+		for (i, (input, order)) in self.permutations.iter().enumerate() {
+			let values = order
+				.iter()
+				.map(|&row| self.inputs[*input][row])
+				.collect::<Vec<_>>();
+			let permuted = column(builder, format!("permuted_{i}"), &values);
+			let channel = builder.add_channel();
+			builder.send(channel, n_rows, [inputs[*input]]);
+			builder.receive(channel, n_rows, [permuted]);
+		}
+
+		Ok(vec![])
+	}
+}
+
+/// Generates [`RandomSystem`]s with 64 or 128 rows, a good share of which have invalid witnesses.
+pub fn random_system() -> impl Strategy<Value = RandomSystem> {
+	(6..8usize, 1..4usize)
+		.prop_flat_map(|(log_rows, n_inputs)| {
+			let n_rows = 1 << log_rows;
+			let overwrites = prop_oneof![
+				3 => Just(Vec::new()),
+				1 => vec((0..n_rows, any::<u8>()), 1..3),
+			];
+			let order = prop_oneof![
+				3 => Just((0..n_rows).collect::<Vec<_>>()).prop_shuffle(),
+				1 => vec(0..n_rows, n_rows),
+			];
+			(
+				vec(vec(any::<u8>(), n_rows), n_inputs),
+				vec((0..n_inputs, 0..n_inputs, overwrites), 0..3),
+				vec((0..n_inputs, order), 0..2),
+			)
+		})
+		.prop_map(|(inputs, products, permutations)| RandomSystem {
+			inputs,
+			products,
+			permutations,
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::array;
+
+	use binius_core::oracle::OracleId;
+	use binius_field::{
+		as_packed_field::{PackScalar, PackedType},
+		BinaryField1b, BinaryField32b, BinaryField64b, BinaryField8b, ExtensionField, Field,
+		TowerField,
+	};
+	use bytemuck::Pod;
+	use proptest::{prelude::*, test_runner::TestError};
+	use sha2::{compress256, digest::generic_array::GenericArray};
+
+	use super::{
+		check_agreement, check_gadget, random_system, Config, Perturbation, SoundnessProbe, F, U,
+	};
+	use crate::{
+		arithmetic::{self, Flags},
+		bitwise,
+		builder::ConstraintSystemBuilder,
+		chacha20, fixed_point, float32,
+		keccakf::{self, KeccakfState},
+		lanes, mux, poly1305, sha256, tower,
+	};
+
+	/// Adds a committed column holding `values`, packed into `FS` scalars.
+	fn column<FS: TowerField, T: Pod>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: &str,
+		values: &[T],
+	) -> OracleId
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let log_rows =
+			values.len().ilog2() as usize + (8 * size_of::<T>()).ilog2() as usize - FS::TOWER_LEVEL;
+		let id = builder.add_committed(name, log_rows, FS::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<FS>(id)
+				.as_mut_slice::<T>()
+				.copy_from_slice(values);
+		}
+		id
+	}
+
+	fn values<FS: TowerField, T: Pod>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		id: OracleId,
+	) -> Result<Vec<T>, anyhow::Error>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let witness = builder.witness().expect("harness builds with a witness");
+		Ok(witness.get::<FS>(id)?.as_slice::<T>().to_vec())
+	}
+
+	fn pairs<T: Arbitrary>(len: usize) -> impl Strategy<Value = Vec<(T, T)>> {
+		prop::collection::vec(any::<(T, T)>(), len)
+	}
+
+	#[test]
+	fn test_bitwise() {
+		check_gadget(&Config::default(), pairs::<u32>(1 << 4), |builder, pairs| {
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let and = bitwise::and(builder, "and", x, y)?;
+			let xor = bitwise::xor(builder, "xor", x, y)?;
+			let or = bitwise::or(builder, "or", x, y)?;
+			let and = values::<BinaryField1b, u32>(builder, and)?;
+			let xor = values::<BinaryField1b, u32>(builder, xor)?;
+			let or = values::<BinaryField1b, u32>(builder, or)?;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(and[i] == x & y && xor[i] == x ^ y && or[i] == x | y);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_add() {
+		check_gadget(&Config::default(), pairs::<u32>(1 << 4), |builder, pairs| {
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let sum = arithmetic::u32::add(builder, "add", x, y, Flags::Unchecked)?;
+			let sum = values::<BinaryField1b, u32>(builder, sum)?;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(sum[i] == x.wrapping_add(y), "{x} + {y} != {}", sum[i]);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u64_bitops() {
+		let strategy = (prop::collection::vec(any::<u64>(), 1 << 3), 0..64usize);
+		check_gadget(&Config::default(), strategy, |builder, (xs, offset)| {
+			let x = column::<BinaryField1b, _>(builder, "x", xs);
+			let rotl = arithmetic::u64::rotl(builder, "rotl", x, *offset)?;
+			let shr = arithmetic::u64::shr(builder, "shr", x, *offset)?;
+			let not = arithmetic::u64::not(builder, "not", x)?;
+			let and = arithmetic::u64::and(builder, "and", rotl, not)?;
+			let and = values::<BinaryField1b, u64>(builder, and)?;
+			let rotl = values::<BinaryField1b, u64>(builder, rotl)?;
+			let shr = values::<BinaryField1b, u64>(builder, shr)?;
+			let not = values::<BinaryField1b, u64>(builder, not)?;
+			for (i, &x) in xs.iter().enumerate() {
+				anyhow::ensure!(rotl[i] == x.rotate_left(*offset as u32));
+				anyhow::ensure!(shr[i] == x >> offset && not[i] == !x);
+				anyhow::ensure!(and[i] == rotl[i] & not[i]);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_mux_choose() {
+		let strategy = prop::collection::vec(any::<(bool, u32, u32)>(), 1 << 8);
+		check_gadget(&Config::default(), strategy, |builder, rows| {
+			let mut bits = vec![0u8; rows.len() / 8];
+			for (row, &(bit, _, _)) in rows.iter().enumerate() {
+				bits[row / 8] |= (bit as u8) << (row % 8);
+			}
+			let selector = column::<BinaryField1b, _>(builder, "selector", &bits);
+			let if_true = rows.iter().map(|row| row.1).collect::<Vec<_>>();
+			let if_false = rows.iter().map(|row| row.2).collect::<Vec<_>>();
+			let if_true = column::<BinaryField32b, _>(builder, "if_true", &if_true);
+			let if_false = column::<BinaryField32b, _>(builder, "if_false", &if_false);
+			let output = mux::choose::<_, _, BinaryField32b>(
+				builder,
+				"choose",
+				selector,
+				&[if_true],
+				&[if_false],
+			)?;
+			let output = values::<BinaryField32b, u32>(builder, output[0])?;
+			for (row, &(bit, t, f)) in rows.iter().enumerate() {
+				anyhow::ensure!(output[row] == if bit { t } else { f });
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	/// Adds one committed `u32` column per word of `rows`, holding that word of every row.
+	fn word_columns<const N: usize>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: &str,
+		rows: &[[u32; N]],
+	) -> [OracleId; N] {
+		array::from_fn(|i| {
+			let words = rows.iter().map(|row| row[i]).collect::<Vec<_>>();
+			column::<BinaryField1b, _>(builder, &format!("{name}{i}"), &words)
+		})
+	}
+
+	fn word_values<const N: usize>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		ids: [OracleId; N],
+	) -> Result<Vec<[u32; N]>, anyhow::Error> {
+		let columns = ids
+			.into_iter()
+			.map(|id| values::<BinaryField1b, u32>(builder, id))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok((0..columns[0].len())
+			.map(|row| array::from_fn(|i| columns[i][row]))
+			.collect())
+	}
+
+	/// The `i`-th bit of a column with one bit per row.
+	fn bit(bits: &[u8], i: usize) -> bool {
+		(bits[i / 8] >> (i % 8)) & 1 == 1
+	}
+
+	#[test]
+	fn test_u8_arithmetic() {
+		check_gadget(&Config::default(), pairs::<u8>(1 << 5), |builder, pairs| {
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let sum = arithmetic::u8::add(builder, "add", x, y, Flags::Saturating)?;
+			let diff = arithmetic::u8::sub(builder, "sub", x, y, Flags::Saturating)?;
+			let product = arithmetic::u8::mul(builder, "mul", x, y, Flags::Unchecked)?;
+			let (_, carry) = arithmetic::u8::add_with_carry(builder, "add_with_carry", x, y)?;
+			let (_, borrow) = arithmetic::u8::sub_with_borrow(builder, "sub_with_borrow", x, y)?;
+			let sum = values::<BinaryField1b, u8>(builder, sum)?;
+			let diff = values::<BinaryField1b, u8>(builder, diff)?;
+			let product = values::<BinaryField1b, u8>(builder, product)?;
+			let carry = values::<BinaryField1b, u8>(builder, carry)?;
+			let borrow = values::<BinaryField1b, u8>(builder, borrow)?;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(sum[i] == x.saturating_add(y) && diff[i] == x.saturating_sub(y));
+				anyhow::ensure!(product[i] == x.wrapping_mul(y), "{x} * {y} != {}", product[i]);
+				anyhow::ensure!(bit(&carry, i) == x.checked_add(y).is_none());
+				anyhow::ensure!(bit(&borrow, i) == (x < y));
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u16_arithmetic() {
+		check_gadget(&Config::default(), pairs::<u16>(1 << 4), |builder, pairs| {
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let sum = arithmetic::u16::add(builder, "add", x, y, Flags::Saturating)?;
+			let diff = arithmetic::u16::sub(builder, "sub", x, y, Flags::Saturating)?;
+			let product = arithmetic::u16::mul(builder, "mul", x, y, Flags::Unchecked)?;
+			let (_, carry) = arithmetic::u16::add_with_carry(builder, "add_with_carry", x, y)?;
+			let (_, borrow) = arithmetic::u16::sub_with_borrow(builder, "sub_with_borrow", x, y)?;
+			let sum = values::<BinaryField1b, u16>(builder, sum)?;
+			let diff = values::<BinaryField1b, u16>(builder, diff)?;
+			let product = values::<BinaryField1b, u16>(builder, product)?;
+			let carry = values::<BinaryField1b, u8>(builder, carry)?;
+			let borrow = values::<BinaryField1b, u8>(builder, borrow)?;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(sum[i] == x.saturating_add(y) && diff[i] == x.saturating_sub(y));
+				anyhow::ensure!(product[i] == x.wrapping_mul(y), "{x} * {y} != {}", product[i]);
+				anyhow::ensure!(bit(&carry, i) == x.checked_add(y).is_none());
+				anyhow::ensure!(bit(&borrow, i) == (x < y));
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_arithmetic() {
+		let strategy = (pairs::<u32>(1 << 4), 0..32usize, any::<u32>());
+		check_gadget(&Config::default(), strategy, |builder, (pairs, offset, factor)| {
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let diff = arithmetic::u32::sub(builder, "sub", x, y, Flags::Unchecked)?;
+			let scaled = arithmetic::u32::mul_const(builder, "mul", x, *factor, Flags::Unchecked)?;
+			let shl = arithmetic::u32::shl(builder, "shl", x, *offset)?;
+			let shr = arithmetic::u32::shr(builder, "shr", x, *offset)?;
+			let rotl = arithmetic::u32::rotl(builder, "rotl", x, *offset)?;
+			let bswap = arithmetic::u32::bswap(builder, "bswap", x)?;
+			let popcount = arithmetic::u32::popcount(builder, "popcount", x)?;
+			let clz = arithmetic::u32::clz(builder, "clz", x)?;
+			let ctz = arithmetic::u32::ctz(builder, "ctz", x)?;
+			let [diff, scaled, shl, shr, rotl, bswap, popcount, clz, ctz] =
+				[diff, scaled, shl, shr, rotl, bswap, popcount, clz, ctz]
+					.try_map(|id| values::<BinaryField1b, u32>(builder, id))?;
+			let offset = *offset as u32;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(diff[i] == x.wrapping_sub(y));
+				anyhow::ensure!(scaled[i] == x.wrapping_mul(*factor));
+				anyhow::ensure!(shl[i] == x << offset && shr[i] == x >> offset);
+				anyhow::ensure!(rotl[i] == x.rotate_left(offset));
+				anyhow::ensure!(bswap[i] == x.swap_bytes());
+				anyhow::ensure!(popcount[i] == x.count_ones());
+				anyhow::ensure!(clz[i] == x.leading_zeros() && ctz[i] == x.trailing_zeros());
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u64_arithmetic() {
+		check_gadget(&Config::default(), pairs::<u64>(1 << 3), |builder, pairs| {
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let sum = arithmetic::u64::add(builder, "add", x, y, Flags::Saturating)?;
+			let diff = arithmetic::u64::sub(builder, "sub", x, y, Flags::Unchecked)?;
+			let sum = values::<BinaryField1b, u64>(builder, sum)?;
+			let diff = values::<BinaryField1b, u64>(builder, diff)?;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(sum[i] == x.saturating_add(y) && diff[i] == x.wrapping_sub(y));
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	/// Zeros and normal values whose sums and products are zero or normal as well.
+	fn float32_operand() -> impl Strategy<Value = f32> {
+		prop_oneof![
+			1 => Just(0.0f32),
+			1 => Just(-0.0f32),
+			14 => (any::<u32>(), 100u32..156).prop_map(|(bits, exponent)| {
+				f32::from_bits(bits & 0x807f_ffff | exponent << 23)
+			}),
+		]
+	}
+
+	#[test]
+	fn test_float32() {
+		let config = Config {
+			cases: 8,
+			..Config::default()
+		};
+		let strategy = prop::collection::vec((float32_operand(), float32_operand()), 1 << 5);
+		check_gadget(&config, strategy, |builder, pairs| {
+			let xs = pairs.iter().map(|(x, _)| x.to_bits()).collect::<Vec<_>>();
+			let ys = pairs.iter().map(|(_, y)| y.to_bits()).collect::<Vec<_>>();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let sum = float32::add(builder, "add", x, y)?;
+			let product = float32::mul(builder, "mul", x, y)?;
+			let sum = values::<BinaryField1b, u32>(builder, sum)?;
+			let product = values::<BinaryField1b, u32>(builder, product)?;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(
+					sum[i] == (x + y).to_bits(),
+					"{x} + {y} != {}",
+					f32::from_bits(sum[i])
+				);
+				anyhow::ensure!(
+					product[i] == (x * y).to_bits(),
+					"{x} * {y} != {}",
+					f32::from_bits(product[i])
+				);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_fixed_point() {
+		const FRAC_BITS: usize = 16;
+		let config = Config {
+			cases: 8,
+			..Config::default()
+		};
+		let strategy = (pairs::<i32>(1 << 4), any::<(i32, i32)>());
+		check_gadget(&config, strategy, |builder, (pairs, bounds)| {
+			let (min, max) = (bounds.0.min(bounds.1), bounds.0.max(bounds.1));
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let flags = Flags::Unchecked;
+			let sum = fixed_point::add(builder, "add", x, y, flags)?;
+			let product = fixed_point::mul(builder, "mul", x, y, FRAC_BITS, flags)?;
+			let relu = fixed_point::relu(builder, "relu", x)?;
+			let clamped = fixed_point::clamp(builder, "clamp", x, min, max)?;
+			let dot = fixed_point::dot_product(builder, "dot", &[x, x], &[y, x], FRAC_BITS, flags)?;
+			let [sum, product, relu, clamped, dot] = [sum, product, relu, clamped, dot]
+				.try_map(|id| values::<BinaryField1b, i32>(builder, id))?;
+			let wide = |x: i32, y: i32| x as i64 * y as i64;
+			for (i, &(x, y)) in pairs.iter().enumerate() {
+				anyhow::ensure!(sum[i] == x.wrapping_add(y));
+				anyhow::ensure!(product[i] == (wide(x, y) >> FRAC_BITS) as i32);
+				anyhow::ensure!(relu[i] == x.max(0) && clamped[i] == x.clamp(min, max));
+				anyhow::ensure!(
+					dot[i] == (wide(x, y).wrapping_add(wide(x, x)) >> FRAC_BITS) as i32
+				);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_tower_arithmetic() {
+		let strategy = prop::collection::vec((any::<u128>(), 1..=u128::MAX, any::<u32>()), 1 << 3);
+		check_gadget(&Config::default(), strategy, |builder, rows| {
+			let xs = rows.iter().map(|row| row.0).collect::<Vec<_>>();
+			let ys = rows.iter().map(|row| row.1).collect::<Vec<_>>();
+			let smalls = rows.iter().map(|row| row.2).collect::<Vec<_>>();
+			let x = column::<F, _>(builder, "x", &xs);
+			let y = column::<F, _>(builder, "y", &ys);
+			let small = column::<BinaryField32b, _>(builder, "small", &smalls);
+			let product = tower::mul::<_, _, F>(builder, "mul", x, y)?;
+			let square = tower::square::<_, _, F>(builder, "square", x)?;
+			let inverse = tower::inverse::<_, _, F>(builder, "inverse", y)?;
+			let small_product =
+				tower::mul::<_, _, BinaryField32b>(builder, "small_mul", small, small)?;
+			let [product, square, inverse] =
+				[product, square, inverse].try_map(|id| values::<F, F>(builder, id))?;
+			let small_product = values::<BinaryField32b, BinaryField32b>(builder, small_product)?;
+			for (i, &(x, y, small)) in rows.iter().enumerate() {
+				let (x, y, small) = (F::new(x), F::new(y), BinaryField32b::new(small));
+				anyhow::ensure!(product[i] == x * y && square[i] == x * x);
+				anyhow::ensure!(inverse[i] * y == F::ONE && small_product[i] == small * small);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lanes() {
+		let strategy = prop::collection::vec(any::<[u32; 4]>(), 1 << 2);
+		check_gadget(&Config::default(), strategy, |builder, rows| {
+			let inputs = word_columns(builder, "in", rows);
+			let a = lanes::pack_lanes(builder, "a", &inputs[..2])?;
+			let b = lanes::pack_lanes(builder, "b", &inputs[2..])?;
+			let sum = lanes::u32add_lanes(builder, "add", a, b, 1, Flags::Unchecked)?;
+			let xor = lanes::u32xor_lanes(builder, "xor", a, b, 1)?;
+			for index in 0..2 {
+				let sum = lanes::lane(builder, format!("sum_{index}"), sum, 1, index)?;
+				let xor = lanes::lane(builder, format!("xor_{index}"), xor, 1, index)?;
+				let sum = values::<BinaryField1b, u32>(builder, sum)?;
+				let xor = values::<BinaryField1b, u32>(builder, xor)?;
+				for (i, row) in rows.iter().enumerate() {
+					let (a, b) = (row[index], row[2 + index]);
+					anyhow::ensure!(sum[i] == a.wrapping_add(b) && xor[i] == a ^ b);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_sha256_compress() {
+		let config = Config {
+			cases: 4,
+			..Config::default()
+		};
+		let strategy = prop::collection::vec(any::<([u32; 8], [u32; 16])>(), 1 << 2);
+		check_gadget(&config, strategy, |builder, rows| {
+			let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+			let states = rows.iter().map(|row| row.0).collect::<Vec<_>>();
+			let blocks = rows.iter().map(|row| row.1).collect::<Vec<_>>();
+			let state = word_columns(builder, "state", &states);
+			let block = word_columns(builder, "block", &blocks);
+			let output = sha256::compress(builder, state, block, log_size)?;
+			let output = word_values(builder, output)?;
+			for (i, (state, block)) in rows.iter().enumerate() {
+				let mut expected = *state;
+				let bytes = block.iter().flat_map(|word| word.to_be_bytes());
+				compress256(&mut expected, &[GenericArray::from_iter(bytes)]);
+				anyhow::ensure!(output[i] == expected, "row {i}");
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	/// The ChaCha20 block function of RFC 8439, section 2.3.
+	fn chacha20_reference(key: [u32; 8], counter: u32, nonce: [u32; 3]) -> [u32; 16] {
+		let mut state = [0; 16];
+		state[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+		state[4..12].copy_from_slice(&key);
+		state[12] = counter;
+		state[13..].copy_from_slice(&nonce);
+		let initial = state;
+		let mut quarter_round = |a: usize, b: usize, c: usize, d: usize| {
+			for (rotate_d, rotate_b) in [(16, 12), (8, 7)] {
+				state[a] = state[a].wrapping_add(state[b]);
+				state[d] = (state[d] ^ state[a]).rotate_left(rotate_d);
+				state[c] = state[c].wrapping_add(state[d]);
+				state[b] = (state[b] ^ state[c]).rotate_left(rotate_b);
+			}
+		};
+		for _ in 0..10 {
+			quarter_round(0, 4, 8, 12);
+			quarter_round(1, 5, 9, 13);
+			quarter_round(2, 6, 10, 14);
+			quarter_round(3, 7, 11, 15);
+			quarter_round(0, 5, 10, 15);
+			quarter_round(1, 6, 11, 12);
+			quarter_round(2, 7, 8, 13);
+			quarter_round(3, 4, 9, 14);
+		}
+		array::from_fn(|i| state[i].wrapping_add(initial[i]))
+	}
+
+	#[test]
+	fn test_chacha20_block() {
+		let config = Config {
+			cases: 8,
+			..Config::default()
+		};
+		let strategy = prop::collection::vec(any::<([u32; 8], u32, [u32; 3])>(), 1 << 2);
+		check_gadget(&config, strategy, |builder, rows| {
+			let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+			let keys = rows.iter().map(|row| row.0).collect::<Vec<_>>();
+			let counters = rows.iter().map(|row| [row.1]).collect::<Vec<_>>();
+			let nonces = rows.iter().map(|row| row.2).collect::<Vec<_>>();
+			let key = word_columns(builder, "key", &keys);
+			let [counter] = word_columns(builder, "counter", &counters);
+			let nonce = word_columns(builder, "nonce", &nonces);
+			let block =
+				chacha20::chacha20_block(builder, "chacha20", key, counter, nonce, log_size)?;
+			let block = word_values(builder, block)?;
+			for (i, &(key, counter, nonce)) in rows.iter().enumerate() {
+				anyhow::ensure!(block[i] == chacha20_reference(key, counter, nonce), "row {i}");
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_keccakf() {
+		let config = Config {
+			cases: 8,
+			..Config::default()
+		};
+		let log_size = 3;
+		let strategy = prop::collection::vec(any::<[u64; 25]>(), 1 << log_size);
+		check_gadget(&config, strategy, |builder, states| {
+			let input = states.iter().copied().map(KeccakfState).collect::<Vec<_>>();
+			let output = keccakf::keccakf(builder, Some(input), log_size)?.output;
+			let output = output.try_map(|id| values::<BinaryField64b, u64>(builder, id))?;
+			for (i, state) in states.iter().enumerate() {
+				let mut expected = *state;
+				tiny_keccak::keccakf(&mut expected);
+				anyhow::ensure!(output
+					.iter()
+					.zip(expected)
+					.all(|(lane, expected)| lane[i] == expected));
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	/// The Poly1305 tag of RFC 8439, section 2.5, on 44-bit limbs.
+	fn poly1305_tag(key: &[u8; 32], message: &[u8]) -> [u8; 16] {
+		const MASK_44: u64 = (1 << 44) - 1;
+		const MASK_42: u64 = (1 << 42) - 1;
+		let le_u64 = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().unwrap());
+		let limbs = |lo: u64, hi: u64| [lo & MASK_44, (lo >> 44 | hi << 20) & MASK_44, hi >> 24];
+
+		let [r0, r1, r2] = limbs(le_u64(&key[..8]), le_u64(&key[8..16]));
+		let (r0, r1, r2) = (r0 & 0xffc0fffffff, r1 & 0xfffffc0ffff, r2 & 0x00ffffffc0f);
+		let (s1, s2) = (r1 * 20, r2 * 20);
+
+		let mut h = [0u64; 3];
+		let carry = |h: &mut [u64; 3]| {
+			h[1] += h[0] >> 44;
+			h[0] &= MASK_44;
+			h[2] += h[1] >> 44;
+			h[1] &= MASK_44;
+			h[0] += (h[2] >> 42) * 5;
+			h[2] &= MASK_42;
+		};
+		for chunk in message.chunks(16) {
+			let mut block = [0u8; 17];
+			block[..chunk.len()].copy_from_slice(chunk);
+			block[chunk.len()] = 1;
+			let [m0, m1, m2] = limbs(le_u64(&block[..8]), le_u64(&block[8..16]));
+			h[0] += m0;
+			h[1] += m1;
+			h[2] += m2 | (block[16] as u64) << 40;
+
+			let [h0, h1, h2] = h.map(u128::from);
+			let [r0, r1, r2, s1, s2] = [r0, r1, r2, s1, s2].map(u128::from);
+			let d0 = h0 * r0 + h1 * s2 + h2 * s1;
+			let d1 = h0 * r1 + h1 * r0 + h2 * s2 + (d0 >> 44);
+			let d2 = h0 * r2 + h1 * r1 + h2 * r0 + (d1 >> 44);
+			h = [
+				d0 as u64 & MASK_44,
+				d1 as u64 & MASK_44,
+				d2 as u64 & MASK_42,
+			];
+			h[0] += (d2 >> 42) as u64 * 5;
+			h[1] += h[0] >> 44;
+			h[0] &= MASK_44;
+		}
+		carry(&mut h);
+		carry(&mut h);
+		carry(&mut h);
+
+		// Subtract the prime if the accumulator is not below it.
+		let mut g = [h[0] + 5, h[1], h[2]];
+		g[1] += g[0] >> 44;
+		g[0] &= MASK_44;
+		g[2] += g[1] >> 44;
+		g[1] &= MASK_44;
+		if g[2] >> 42 != 0 {
+			h = [g[0], g[1], g[2] & MASK_42];
+		}
+
+		let [s0, s1, s2] = limbs(le_u64(&key[16..24]), le_u64(&key[24..32]));
+		h[0] += s0;
+		h[1] += s1 + (h[0] >> 44);
+		h[2] += s2 + (h[1] >> 44);
+		let lo = h[0] & MASK_44 | h[1] << 44;
+		let hi = (h[1] & MASK_44) >> 20 | h[2] << 24;
+		let mut tag = [0; 16];
+		tag[..8].copy_from_slice(&lo.to_le_bytes());
+		tag[8..].copy_from_slice(&hi.to_le_bytes());
+		tag
+	}
+
+	#[test]
+	fn test_poly1305() {
+		// RFC 8439, section 2.5.2
+		let key = *b"\x85\xd6\xbe\x78\x57\x55\x6d\x33\x7f\x44\x52\xfe\x42\xd5\x06\xa8\
+			\x01\x03\x80\x8a\xfb\x0d\xb2\xfd\x4a\xbf\xf6\xaf\x41\x49\xf5\x1b";
+		let tag = *b"\xa8\x06\x1d\xc1\x30\x51\x36\xc6\xc2\x2b\x8b\xaf\x0c\x01\x27\xa9";
+		assert_eq!(poly1305_tag(&key, b"Cryptographic Forum Research Group"), tag);
+
+		// Proving the lookups of a single case takes minutes, so the cases are only validated.
+		let config = Config {
+			cases: 8,
+			prove_cases: 0,
+			..Config::default()
+		};
+		let log_bytes = 4;
+		let strategy = prop::collection::vec(any::<([u8; 32], [u8; 20])>(), 1 << log_bytes);
+		check_gadget(&config, strategy, |builder, rows| {
+			let keys = rows
+				.iter()
+				.map(|(key, _)| {
+					array::from_fn(|i| u32::from_le_bytes(key[4 * i..][..4].try_into().unwrap()))
+				})
+				.collect::<Vec<[u32; 8]>>();
+			let key = word_columns(builder, "key", &keys);
+			let message = (0..20)
+				.map(|i| {
+					let bytes = rows
+						.iter()
+						.map(|(_, message)| message[i])
+						.collect::<Vec<_>>();
+					column::<BinaryField8b, _>(builder, &format!("message{i}"), &bytes)
+				})
+				.collect::<Vec<_>>();
+			let tag = poly1305::poly1305(builder, "poly1305", key, &message, log_bytes + 5)?;
+			let tag = tag.try_map(|id| values::<BinaryField8b, u8>(builder, id))?;
+			for (i, (key, message)) in rows.iter().enumerate() {
+				let expected = poly1305_tag(key, message);
+				anyhow::ensure!(tag
+					.iter()
+					.zip(expected)
+					.all(|(byte, expected)| byte[i] == expected));
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_validation_agrees_with_protocol() {
+		let config = Config {
+			cases: 8,
+			..Config::default()
+		};
+		check_agreement(&config, random_system(), |builder, system| system.build(builder)).unwrap();
+	}
+
+	#[test]
+	fn test_soundness_probe() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let x = column::<BinaryField1b, u32>(&mut builder, "x", &[0x0f0f_00ff; 4]);
+		let y = column::<BinaryField1b, u32>(&mut builder, "y", &[0x1234_5678; 4]);
+		let z = column::<BinaryField1b, u32>(&mut builder, "z", &[0x1234_5678; 4]);
+		// A broken copy gadget that only binds `z` to `y` on the rows where `x` is set.
+		builder.assert_zero(
+			"copy",
+			[x, y, z],
+			binius_macros::arith_expr!([x, y, z] = x * (y - z)).convert_field(),
+		);
+		let sum = arithmetic::u32::add(&mut builder, "add", x, y, Flags::Unchecked).unwrap();
+
+		let mut probe = SoundnessProbe::new(builder, vec![]).unwrap();
+		probe.check(Perturbation::flip_bit(z, 0, 0)).unwrap();
+		probe.check(Perturbation::flip_bit(sum, 37, 0)).unwrap();
+		let err = probe.check(Perturbation::flip_bit(z, 8, 0)).unwrap_err();
+		assert!(err.to_string().contains("accept"), "{err}");
+		assert!(probe.check_bits(z).is_err());
+		assert!(probe.check_bits(sum).is_ok());
+
+		probe
+			.check_proof(&Config::default(), Perturbation::flip_bit(sum, 37, 0))
+			.unwrap();
+	}
+
+	#[test]
+	fn test_shrinks_to_minimal_input() {
+		let config = Config {
+			prove_cases: 0,
+			..Config::default()
+		};
+		// A broken AND gadget that fills its output with the OR of the inputs.
+		let result = check_gadget(&config, pairs::<u32>(1 << 4), |builder, pairs| {
+			let (xs, ys): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+			let zs = pairs.iter().map(|(x, y)| x | y).collect::<Vec<_>>();
+			let x = column::<BinaryField1b, _>(builder, "x", &xs);
+			let y = column::<BinaryField1b, _>(builder, "y", &ys);
+			let z = column::<BinaryField1b, _>(builder, "z", &zs);
+			builder.assert_zero(
+				"and",
+				[x, y, z],
+				binius_macros::arith_expr!([x, y, z] = x * y - z).convert_field(),
+			);
+			Ok(vec![])
+		});
+
+		let Err(TestError::Fail(reason, input)) = result else {
+			panic!("expected the broken gadget to fail");
+		};
+		assert!(reason.message().contains("and"));
+		let failing = input
+			.into_iter()
+			.filter(|&pair| pair != (0, 0))
+			.collect::<Vec<_>>();
+		assert!(failing == [(0, 1)] || failing == [(1, 0)], "not minimal: {failing:?}");
+	}
+}
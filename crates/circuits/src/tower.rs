@@ -0,0 +1,208 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Arithmetic on columns of binary tower field elements.
+//!
+//! Every row of a column holds one element of the tower field `FS`, like a `BinaryField128b`
+//! element of a binius transcript. The constraints are evaluated over the top field `F`, of which
+//! `FS` is a subfield, so a product of elements is a single degree-2 constraint instead of a
+//! circuit over their bits. GHASH operands can be brought into the tower basis with the
+//! `BinaryField128bPolyval` to `BinaryField128b` isomorphism, which preserves products.
+
+use binius_core::oracle::OracleId;
+use binius_field::{as_packed_field::PackScalar, ExtensionField, TowerField};
+use binius_math::ArithExpr;
+use binius_utils::rayon::prelude::*;
+use bytemuck::Pod;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Multiplies two columns of `FS` elements row by row.
+pub fn mul<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+	yin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin, yin])?;
+	let zout = builder.add_committed("zout", log_rows, FS::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<FS>(xin)?.as_slice::<FS>(),
+			witness.get::<FS>(yin)?.as_slice::<FS>(),
+			witness.new_column::<FS>(zout).as_mut_slice::<FS>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, yin, zout)| *zout = *xin * *yin);
+	}
+	builder.assert_zero(
+		"mul",
+		[xin, yin, zout],
+		ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+	);
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Squares a column of `FS` elements row by row.
+pub fn square<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin])?;
+	let zout = builder.add_committed("zout", log_rows, FS::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<FS>(xin)?.as_slice::<FS>(),
+			witness.new_column::<FS>(zout).as_mut_slice::<FS>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, zout)| *zout = xin.square());
+	}
+	builder.assert_zero("square", [xin, zout], ArithExpr::Var(0).pow(2) - ArithExpr::Var(1));
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+/// Inverts a column of `FS` elements row by row.
+///
+/// The constraint `x * inverse = 1` also asserts that no element of the column is zero, so the
+/// witness only validates if every row is invertible.
+pub fn inverse<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	xin: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<F> + PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([xin])?;
+	let zout = builder.add_committed("zout", log_rows, FS::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<FS>(xin)?.as_slice::<FS>(),
+			witness.new_column::<FS>(zout).as_mut_slice::<FS>(),
+		)
+			.into_par_iter()
+			.for_each(|(xin, zout)| *zout = xin.invert_or_zero());
+	}
+	builder.assert_zero(
+		"inverse",
+		[xin, zout],
+		ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::one(),
+	);
+	builder.pop_namespace();
+	Ok(zout)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{
+		arch::OptimalUnderlier, as_packed_field::PackScalar, BinaryField128b,
+		BinaryField128bPolyval, BinaryField32b, ExtensionField, Field, TowerField,
+	};
+	use bytemuck::Pod;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use crate::{builder::ConstraintSystemBuilder, tower};
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+
+	fn column<FS>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		name: &str,
+		values: &[FS],
+	) -> OracleId
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+		FS: TowerField + Pod,
+	{
+		let id = builder.add_committed(name, values.len().ilog2() as usize, FS::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<FS>(id)
+				.as_mut_slice::<FS>()
+				.copy_from_slice(values);
+		}
+		id
+	}
+
+	fn values<FS>(builder: &mut ConstraintSystemBuilder<U, F>, id: OracleId) -> Vec<FS>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+		FS: TowerField + Pod,
+	{
+		let witness = builder.witness().unwrap();
+		witness.get::<FS>(id).unwrap().as_slice::<FS>().to_vec()
+	}
+
+	#[test]
+	fn test_tower_arithmetic() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+		let xs = (0..1 << 8).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+		let ys = (0..1 << 8).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+		let small = (0..1 << 8)
+			.map(|_| BinaryField32b::random(&mut rng))
+			.collect::<Vec<_>>();
+		let x = column(&mut builder, "x", &xs);
+		let y = column(&mut builder, "y", &ys);
+		let s = column(&mut builder, "s", &small);
+
+		let product = tower::mul::<_, _, F>(&mut builder, "mul", x, y).unwrap();
+		let square = tower::square::<_, _, F>(&mut builder, "square", x).unwrap();
+		let inverse = tower::inverse::<_, _, F>(&mut builder, "inverse", x).unwrap();
+		let small_product =
+			tower::mul::<_, _, BinaryField32b>(&mut builder, "small_mul", s, s).unwrap();
+
+		let products = values::<F>(&mut builder, product);
+		let squares = values::<F>(&mut builder, square);
+		let inverses = values::<F>(&mut builder, inverse);
+		let small_products = values::<BinaryField32b>(&mut builder, small_product);
+		for i in 0..1 << 8 {
+			assert_eq!(products[i], xs[i] * ys[i]);
+			assert_eq!(squares[i], xs[i] * xs[i]);
+			assert_eq!(inverses[i] * xs[i], F::ONE);
+			assert_eq!(small_products[i], small[i] * small[i]);
+			// Products of GHASH elements carry over through the isomorphism to the tower.
+			let (a, b) = (BinaryField128bPolyval::from(xs[i]), BinaryField128bPolyval::from(ys[i]));
+			assert_eq!(F::from(a * b), products[i]);
+		}
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_inverse_of_zero_fails_validation() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let x = column(&mut builder, "x", &[F::ZERO; 1 << 4]);
+		tower::inverse::<_, _, F>(&mut builder, "inverse", x).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+}
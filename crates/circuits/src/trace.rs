@@ -0,0 +1,295 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Witness generation from execution traces.
+//!
+//! An execution trace is a list of rows, each a struct of unsigned integers. A [`TraceAdapter`]
+//! maps such a trace into witness columns, one per field of the row, replacing hand-written code
+//! that casts every column to a slice of integers and copies the trace into it. The adapter is
+//! usually derived with [`binius_macros::TraceColumns`]:
+//!
+//! ```ignore
+//! #[derive(TraceColumns)]
+//! struct AddRow {
+//!     x: u32,
+//!     y: u32,
+//!     z: u32,
+//! }
+//!
+//! let columns = AddRow::add_columns(&mut builder, log_rows);
+//! arithmetic::u32::add(&mut builder, "add", columns.x, columns.y, Flags::Unchecked)?;
+//! if let Some(witness) = builder.witness() {
+//!     AddRow::fill_witness(&trace, &columns, witness)?;
+//! }
+//! ```
+//!
+//! Every field of type `T` is a committed column of [`BinaryField1b`] elements holding the bits of
+//! the values, which is the layout the arithmetic and bitwise gadgets expect for integers. The
+//! derive macro also defines a `{Row}Columns` struct holding the oracle IDs of the columns, which
+//! can be constructed from the oracles of an existing gadget layout instead of adding new columns.
+
+use anyhow::{ensure, Error};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar, transpose_bits, underlier::UnderlierType, BinaryField1b,
+	TowerField,
+};
+use bytemuck::Pod;
+
+use crate::builder::{witness, ConstraintSystemBuilder};
+
+/// An unsigned integer stored in a trace column.
+pub trait TraceValue: Pod {
+	/// The binary logarithm of the number of bits of a value.
+	const LOG_BITS: usize;
+}
+
+impl TraceValue for u8 {
+	const LOG_BITS: usize = 3;
+}
+
+impl TraceValue for u16 {
+	const LOG_BITS: usize = 4;
+}
+
+impl TraceValue for u32 {
+	const LOG_BITS: usize = 5;
+}
+
+impl TraceValue for u64 {
+	const LOG_BITS: usize = 6;
+}
+
+impl TraceValue for u128 {
+	const LOG_BITS: usize = 7;
+}
+
+/// An underlier of witness columns that traces can be written to.
+pub trait TraceUnderlier<F: TowerField>:
+	UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>
+{
+}
+
+impl<U, F> TraceUnderlier<F> for U
+where
+	U: UnderlierType + Pod + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+}
+
+/// Maps the rows of an execution trace into witness columns.
+pub trait TraceAdapter: Sized {
+	/// The oracle IDs of the columns of the trace.
+	type Columns;
+
+	/// Adds a committed column with `2^log_rows` values for every column of the trace, named after
+	/// the column in the current namespace of the builder.
+	fn add_columns<U, F>(
+		builder: &mut ConstraintSystemBuilder<U, F>,
+		log_rows: usize,
+	) -> Self::Columns
+	where
+		U: TraceUnderlier<F>,
+		F: TowerField;
+
+	/// Writes the rows of the trace into the witness columns. Rows beyond the end of the trace are
+	/// zero.
+	fn fill_witness<U, F>(
+		rows: &[Self],
+		columns: &Self::Columns,
+		witness: &mut witness::Builder<U, F>,
+	) -> Result<(), Error>
+	where
+		U: TraceUnderlier<F>,
+		F: TowerField;
+}
+
+/// Adds the committed column of a trace column of values of type `T`.
+pub fn add_column<U, F, T>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_rows: usize,
+) -> OracleId
+where
+	U: TraceUnderlier<F>,
+	F: TowerField,
+	T: TraceValue,
+{
+	builder.add_committed(name, log_rows + T::LOG_BITS, BinaryField1b::TOWER_LEVEL)
+}
+
+/// Writes the values of a trace column into the witness of the committed column `id`.
+pub fn fill_column<U, F, Row, T>(
+	witness: &mut witness::Builder<U, F>,
+	id: OracleId,
+	rows: &[Row],
+	value: impl Fn(&Row) -> T,
+) -> Result<(), Error>
+where
+	U: TraceUnderlier<F>,
+	F: TowerField,
+	T: TraceValue,
+{
+	let mut column = witness.new_column::<BinaryField1b>(id);
+	let values = column.as_mut_slice::<T>();
+	ensure!(
+		rows.len() <= values.len(),
+		"the trace has {} rows, but column {id} only has {}",
+		rows.len(),
+		values.len()
+	);
+	for (dst, row) in values.iter_mut().zip(rows) {
+		*dst = value(row);
+	}
+	Ok(())
+}
+
+/// Writes a trace of bit flags into the committed columns `ids`, which hold one bit per row.
+///
+/// Every row of the trace is `row_bytes` bytes of flags, and bit `j` of a row, bit `j % 8` of its
+/// byte `j / 8`, goes into column `ids[j]`. The whole trace is transposed at once, which is much
+/// faster than extracting the flags column by column. The number of rows of the trace must be a
+/// multiple of 8; rows beyond its end are zero.
+pub fn fill_bit_columns<U, F>(
+	witness: &mut witness::Builder<U, F>,
+	ids: &[OracleId],
+	rows: &[u8],
+	row_bytes: usize,
+) -> Result<(), Error>
+where
+	U: TraceUnderlier<F>,
+	F: TowerField,
+{
+	ensure!(
+		ids.len() <= 8 * row_bytes,
+		"{} columns do not fit in rows of {row_bytes} bytes",
+		ids.len()
+	);
+	let mut columns = vec![0u8; rows.len()];
+	transpose_bits(rows, row_bytes, &mut columns)?;
+
+	let column_bytes = rows.len() / row_bytes / 8;
+	for (&id, flags) in ids.iter().zip(columns.chunks_exact(column_bytes.max(1))) {
+		let mut column = witness.new_column::<BinaryField1b>(id);
+		ensure!(
+			8 * column_bytes <= 1 << column.log_rows(),
+			"the trace has {} rows, but column {id} only has {}",
+			8 * column_bytes,
+			1 << column.log_rows()
+		);
+		column.as_mut_slice::<u8>()[..column_bytes].copy_from_slice(&flags[..column_bytes]);
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{arch::OptimalUnderlier, BinaryField128b, Field};
+	use binius_macros::TraceColumns;
+
+	use super::*;
+	use crate::arithmetic::{self, Flags};
+
+	#[derive(TraceColumns)]
+	struct AddRow {
+		x: u32,
+		y: u32,
+		z: u32,
+		tag: u8,
+	}
+
+	fn trace(n_rows: usize) -> Vec<AddRow> {
+		(0..n_rows as u32)
+			.map(|i| {
+				let (x, y) = (i.wrapping_mul(0x9e3779b9), i ^ 0xdeadbeef);
+				AddRow {
+					x,
+					y,
+					z: x.wrapping_add(y),
+					tag: i as u8,
+				}
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_trace_columns() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder =
+			ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new_with_witness(
+				&allocator,
+			);
+
+		let log_rows = 6;
+		let rows = trace(50);
+		let columns = AddRow::add_columns(&mut builder, log_rows);
+		assert_eq!(builder.log_rows([columns.x, columns.y, columns.z]).unwrap(), log_rows + 5);
+		assert_eq!(builder.log_rows([columns.tag]).unwrap(), log_rows + 3);
+		AddRow::fill_witness(&rows, &columns, builder.witness().unwrap()).unwrap();
+
+		let z = arithmetic::u32::add(&mut builder, "add", columns.x, columns.y, Flags::Unchecked)
+			.unwrap();
+		builder.assert_zero(
+			"z",
+			[z, columns.z],
+			binius_macros::arith_expr!([a, b] = a - b).convert_field(),
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let tag = witness.get_multilin_poly(columns.tag).unwrap();
+		assert_eq!(tag.n_vars(), log_rows + 3);
+	}
+
+	#[test]
+	fn test_trace_too_long() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder =
+			ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new_with_witness(
+				&allocator,
+			);
+		let columns = AddRow::add_columns(&mut builder, 4);
+		assert!(AddRow::fill_witness(&trace(17), &columns, builder.witness().unwrap()).is_err());
+	}
+
+	#[test]
+	fn test_fill_bit_columns() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder =
+			ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new_with_witness(
+				&allocator,
+			);
+
+		let log_rows = 8;
+		let ids = (0..12)
+			.map(|i| {
+				builder.add_committed(format!("flag{i}"), log_rows, BinaryField1b::TOWER_LEVEL)
+			})
+			.collect::<Vec<_>>();
+		// 40 rows of two bytes, the flags of row i are the bits of i * 0x0101 ^ 0x5a3.
+		let rows = (0..40u16)
+			.flat_map(|i| (i.wrapping_mul(0x0101) ^ 0x5a3).to_le_bytes())
+			.collect::<Vec<_>>();
+		fill_bit_columns(builder.witness().unwrap(), &ids, &rows, 2).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		for (j, &id) in ids.iter().enumerate() {
+			let column = witness.get_multilin_poly(id).unwrap();
+			for i in 0..1 << log_rows {
+				let expected = i < 40 && ((i as u16).wrapping_mul(0x0101) ^ 0x5a3) >> j & 1 == 1;
+				let value = column.evaluate_on_hypercube(i).unwrap();
+				assert_eq!(value == BinaryField128b::ONE, expected, "flag {j} of row {i}");
+			}
+		}
+
+		let mut builder =
+			ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new_with_witness(
+				&allocator,
+			);
+		let ids = [builder.add_committed("flag", 3, BinaryField1b::TOWER_LEVEL)];
+		assert!(fill_bit_columns(builder.witness().unwrap(), &ids, &[0u8; 16], 1).is_err());
+		assert!(fill_bit_columns(builder.witness().unwrap(), &ids, &[0u8; 12], 1).is_err());
+	}
+}
@@ -3,9 +3,11 @@
 use binius_core::{oracle::OracleId, transparent};
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
+	packed::set_packed_slice,
 	underlier::UnderlierType,
 	BinaryField1b, ExtensionField, PackedField, TowerField,
 };
+use binius_math::ArithExpr;
 use bytemuck::Pod;
 
 use crate::builder::ConstraintSystemBuilder;
@@ -46,6 +48,74 @@ where
 	Ok(id)
 }
 
+/// A selector that is 1 in the rows `lo..hi` and 0 elsewhere.
+pub fn interval<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	lo: usize,
+	hi: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	let interval = transparent::interval::Interval::new(log_size, lo, hi)?;
+	let id = builder.add_transparent(name, interval.clone())?;
+	if let Some(witness) = builder.witness() {
+		interval.populate(witness.new_column::<BinaryField1b>(id).packed());
+	}
+	Ok(id)
+}
+
+/// A selector that is 1 in the rows `offset`, `offset + k`, `offset + 2k`, and so on, where `k`
+/// must be a power of two.
+pub fn every_kth<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+	k: usize,
+	offset: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	anyhow::ensure!(k.is_power_of_two(), "k must be a power of two, got {k}");
+	let every_kth = transparent::every_kth::EveryKth::new(log_size, k.ilog2() as usize, offset)?;
+	let id = builder.add_transparent(name, every_kth.clone())?;
+	if let Some(witness) = builder.witness() {
+		every_kth.populate(witness.new_column::<BinaryField1b>(id).packed());
+	}
+	Ok(id)
+}
+
+/// A selector that is 1 in the even rows and 0 in the odd rows.
+pub fn even_rows<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	every_kth(builder, name, log_size, 2, 0)
+}
+
+/// A selector that is 1 in the odd rows and 0 in the even rows.
+pub fn odd_rows<U, F>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<BinaryField1b> + Pod,
+	F: TowerField,
+{
+	every_kth(builder, name, log_size, 2, 1)
+}
+
 pub fn constant<U, F, FS>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
 	name: impl ToString,
@@ -68,6 +138,39 @@ where
 	Ok(id)
 }
 
+/// A column whose value at row $i$ is the element of `FS` with the bit decomposition of $i$.
+pub fn enumeration<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	if log_size > FS::N_BITS {
+		anyhow::bail!("cannot enumerate 2^{log_size} values of a {}-bit field", FS::N_BITS);
+	}
+	let expr = (0..log_size)
+		.map(|i| {
+			let bit = <FS as ExtensionField<BinaryField1b>>::basis(i)?;
+			Ok(ArithExpr::Var(i) * ArithExpr::Const(bit))
+		})
+		.sum::<Result<ArithExpr<FS>, binius_field::Error>>()?;
+	let id = builder
+		.add_transparent(name, transparent::index_expr::IndexExpr::<F>::new(log_size, &expr)?)?;
+	if let Some(witness) = builder.witness() {
+		let values = transparent::index_expr::IndexExpr::<FS>::new::<FS>(log_size, &expr)?;
+		let mut column = witness.new_column::<FS>(id);
+		let packed = column.packed();
+		for i in 0..1 << log_size {
+			set_packed_slice(packed, i, values.evaluate_on_hypercube(i));
+		}
+	}
+	Ok(id)
+}
+
 pub fn make_transparent<U, F, FS>(
 	builder: &mut ConstraintSystemBuilder<U, F>,
 	name: impl ToString,
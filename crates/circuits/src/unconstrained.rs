@@ -3,9 +3,9 @@ use binius_core::oracle::OracleId;
 use binius_field::{
 	as_packed_field::PackScalar, underlier::UnderlierType, ExtensionField, TowerField,
 };
+use binius_utils::rayon::prelude::*;
 use bytemuck::Pod;
 use rand::{thread_rng, Rng};
-use rayon::prelude::*;
 
 use crate::builder::ConstraintSystemBuilder;
 
@@ -0,0 +1,161 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets for validating byte columns as UTF-8 or ASCII text.
+
+use std::ops::RangeInclusive;
+
+use anyhow::Result;
+use binius_core::{constraint_system::channel::Boundary, oracle::OracleId};
+use binius_field::{
+	as_packed_field::PackScalar, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	TowerField,
+};
+use bytemuck::Pod;
+
+use crate::{
+	automaton::{run_automaton, Automaton, Transition},
+	builder::ConstraintSystemBuilder,
+};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// The transitions `(state, bytes, next state)` of the automaton recognizing UTF-8 as specified by
+/// RFC 3629, which rules out overlong encodings, surrogates and code points above U+10FFFF.
+///
+/// State 0 is the initial and only accepting state. States 1 to 3 expect as many continuation
+/// bytes, and states 4 to 7 expect the restricted second byte of the sequences starting with E0,
+/// ED, F0 and F4 respectively.
+const UTF8_TRANSITIONS: [(u8, RangeInclusive<u8>, u8); 16] = [
+	(0, 0x00..=0x7F, 0),
+	(0, 0xC2..=0xDF, 1),
+	(0, 0xE0..=0xE0, 4),
+	(0, 0xE1..=0xEC, 2),
+	(0, 0xED..=0xED, 5),
+	(0, 0xEE..=0xEF, 2),
+	(0, 0xF0..=0xF0, 6),
+	(0, 0xF1..=0xF3, 3),
+	(0, 0xF4..=0xF4, 7),
+	(1, 0x80..=0xBF, 0),
+	(2, 0x80..=0xBF, 1),
+	(3, 0x80..=0xBF, 2),
+	(4, 0xA0..=0xBF, 1),
+	(5, 0x80..=0x9F, 1),
+	(6, 0x90..=0xBF, 2),
+	(7, 0x80..=0x8F, 2),
+];
+
+/// The text accepted by [`assert_text`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Charset {
+	/// Well-formed UTF-8.
+	Utf8,
+	/// The ASCII characters whose bit is set in the mask, bit `c` for the character with code `c`.
+	Ascii(u128),
+}
+
+impl Charset {
+	pub const ASCII: Self = Self::Ascii(u128::MAX);
+	/// The printable ASCII characters, from space to tilde.
+	pub const PRINTABLE_ASCII: Self = Self::Ascii(((1 << 95) - 1) << 0x20);
+
+	/// The ASCII characters satisfying `predicate`.
+	pub fn ascii(predicate: impl Fn(u8) -> bool) -> Self {
+		Self::Ascii(
+			(0..0x80)
+				.filter(|&c| predicate(c))
+				.fold(0, |mask, c| mask | 1 << c),
+		)
+	}
+
+	/// An automaton recognizing the text, with the initial state as its only accepting state.
+	pub fn automaton(self) -> Result<Automaton> {
+		let transitions = match self {
+			Self::Utf8 => UTF8_TRANSITIONS
+				.into_iter()
+				.flat_map(|(state, bytes, next)| {
+					bytes.map(move |byte| Transition {
+						state,
+						byte,
+						next,
+						output: 0,
+					})
+				})
+				.collect(),
+			Self::Ascii(mask) => (0..0x80)
+				.filter(|&c| (mask >> c) & 1 == 1)
+				.map(|byte| Transition {
+					state: 0,
+					byte,
+					next: 0,
+					output: 0,
+				})
+				.collect(),
+		};
+		Automaton::new(transitions)
+	}
+}
+
+/// Asserts that the first `count` bytes of `input` are text in `charset`.
+///
+/// The bytes are run through the automaton of the charset with [`run_automaton`], which looks up
+/// every transition in a transparent table of about 500 rows for UTF-8. The state after the last
+/// byte is constrained to be the initial state, so the text can't end in the middle of a
+/// character. The rows past `count` are not constrained.
+///
+/// Like [`crate::plain_lookup::plain_lookup`], every transition may occur less than
+/// `1 << LOG_MAX_MULTIPLICITY` times, and the returned boundary must be supplied to the prover and
+/// the verifier.
+pub fn assert_text<U, F, const LOG_MAX_MULTIPLICITY: usize>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	input: OracleId,
+	count: usize,
+	charset: Charset,
+) -> Result<Boundary<F>>
+where
+	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<B8> + PackScalar<B32> + Pod,
+	F: TowerField + ExtensionField<B8> + ExtensionField<B32>,
+{
+	let automaton = charset.automaton()?;
+	let run =
+		run_automaton::<U, F, LOG_MAX_MULTIPLICITY>(builder, name, input, count, &automaton, true)?;
+	Ok(run.boundary)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_utf8_automaton_matches_std() {
+		let automaton = Charset::Utf8.automaton().unwrap();
+		// Past the second byte, only whether a byte is a continuation byte matters
+		let rests: [&[u8]; 5] = [&[], &[0x80], &[0x7F], &[0x80, 0xBF], &[0xBF, 0xC0]];
+		for first in 0..=u8::MAX {
+			assert_eq!(automaton.accepts(&[first]), std::str::from_utf8(&[first]).is_ok());
+			for second in 0..=u8::MAX {
+				for rest in rests {
+					let text = [&[first, second][..], rest].concat();
+					assert_eq!(
+						automaton.accepts(&text),
+						std::str::from_utf8(&text).is_ok(),
+						"{text:x?}"
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_ascii_subsets() {
+		let accepts = |charset: Charset, text: &[u8]| charset.automaton().unwrap().accepts(text);
+		assert!(accepts(Charset::ASCII, b"{\"a\": 1}\n"));
+		assert!(!accepts(Charset::ASCII, "caf\u{e9}".as_bytes()));
+		assert!(accepts(Charset::PRINTABLE_ASCII, b"https://example.com/?q=1"));
+		assert!(!accepts(Charset::PRINTABLE_ASCII, b"line\n"));
+		let digits = Charset::ascii(|c| c.is_ascii_digit());
+		assert!(accepts(digits, b"2025"));
+		assert!(!accepts(digits, b"20x5"));
+	}
+}
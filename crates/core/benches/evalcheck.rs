@@ -0,0 +1,147 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	oracle::{MultilinearOracleSet, OracleId},
+	protocols::evalcheck::{EvalcheckMultilinearClaim, EvalcheckProver, EvalcheckVerifier},
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{
+	underlier::WithUnderlier, BinaryField128b, Field, PackedBinaryField1x128b, PackedField,
+	TowerField,
+};
+use binius_hal::{make_portable_backend, ComputationBackendExt};
+use binius_math::MultilinearExtension;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, SeedableRng};
+
+type F = BinaryField128b;
+type P = PackedBinaryField1x128b;
+type U = <P as WithUnderlier>::Underlier;
+
+/// A binary tree of linear combinations of depth `depth` over committed leaves.
+struct Tree {
+	oracles: MultilinearOracleSet<F>,
+	root: OracleId,
+	witnesses: Vec<(OracleId, MultilinearExtension<P>)>,
+}
+
+fn make_tree(n_vars: usize, depth: usize, rng: &mut StdRng) -> Tree {
+	let mut oracles = MultilinearOracleSet::new();
+	let mut witnesses = Vec::new();
+	let mut level = repeat_with(|| {
+		let id = oracles.add_committed(n_vars, F::TOWER_LEVEL);
+		let evals = repeat_with(|| P::random(&mut *rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		(id, evals)
+	})
+	.take(1 << depth)
+	.collect::<Vec<_>>();
+	witnesses.extend(
+		level
+			.iter()
+			.map(|(id, evals)| (*id, MultilinearExtension::from_values(evals.clone()).unwrap())),
+	);
+
+	let (left_coeff, right_coeff) = (F::new(2), F::new(3));
+	while level.len() > 1 {
+		level = level
+			.chunks_exact(2)
+			.map(|pair| {
+				let [(left, left_evals), (right, right_evals)] = pair else {
+					unreachable!("chunks have two elements")
+				};
+				let id = oracles
+					.add_linear_combination(n_vars, [(*left, left_coeff), (*right, right_coeff)])
+					.unwrap();
+				let evals = left_evals
+					.iter()
+					.zip(right_evals)
+					.map(|(&left, &right)| left * left_coeff + right * right_coeff)
+					.collect::<Vec<_>>();
+				witnesses.push((id, MultilinearExtension::from_values(evals.clone()).unwrap()));
+				(id, evals)
+			})
+			.collect();
+	}
+
+	Tree {
+		oracles,
+		root: level[0].0,
+		witnesses,
+	}
+}
+
+/// Proves and verifies the evaluation of the root of a tree of linear combinations, which
+/// evalcheck reduces to evaluations of all leaves.
+fn bench_evalcheck_tree(c: &mut Criterion) {
+	let mut group = c.benchmark_group("evalcheck");
+	let backend = make_portable_backend();
+	let n_vars = 12;
+
+	for depth in [2, 4, 6] {
+		let mut rng = StdRng::seed_from_u64(0);
+		let tree = make_tree(n_vars, depth, &mut rng);
+		let eval_point = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let query = backend.multilinear_query::<P>(&eval_point).unwrap();
+		let root_witness = &tree
+			.witnesses
+			.iter()
+			.find(|(id, _)| *id == tree.root)
+			.expect("the root has a witness")
+			.1;
+		let claim = EvalcheckMultilinearClaim {
+			poly: tree.oracles.oracle(tree.root),
+			eval_point: eval_point.into(),
+			eval: root_witness.evaluate(query.to_ref()).unwrap(),
+		};
+		let witness_index = || {
+			let mut witness_index = MultilinearExtensionIndex::<U, F>::new();
+			witness_index
+				.update_multilin_poly(
+					tree.witnesses
+						.iter()
+						.map(|(id, witness)| (*id, witness.clone().specialize_arc_dyn())),
+				)
+				.unwrap();
+			witness_index
+		};
+
+		group.bench_function(BenchmarkId::new("prove", depth), |bench| {
+			bench.iter_batched(
+				|| (tree.oracles.clone(), witness_index()),
+				|(mut oracles, mut witness_index)| {
+					let mut prover =
+						EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+					prover.prove(vec![claim.clone()]).unwrap()
+				},
+				BatchSize::LargeInput,
+			);
+		});
+
+		let mut oracles = tree.oracles.clone();
+		let mut witness = witness_index();
+		let proof = EvalcheckProver::new(&mut oracles, &mut witness, &backend)
+			.prove(vec![claim.clone()])
+			.unwrap();
+		group.bench_function(BenchmarkId::new("verify", depth), |bench| {
+			bench.iter_batched(
+				|| (tree.oracles.clone(), proof.clone()),
+				|(mut oracles, proof)| {
+					EvalcheckVerifier::<F>::new(&mut oracles)
+						.verify(vec![claim.clone()], proof)
+						.unwrap()
+				},
+				BatchSize::SmallInput,
+			);
+		});
+	}
+	group.finish()
+}
+
+criterion_main!(evalcheck_tree);
+criterion_group!(evalcheck_tree, bench_evalcheck_tree);
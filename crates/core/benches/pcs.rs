@@ -0,0 +1,140 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	fiat_shamir::HasherChallenger,
+	merkle_tree::BinaryMerkleTreeProver,
+	piop::{self, make_commit_params_with_optimal_arity, CommitMeta, PIOPSumcheckClaim},
+	polynomial::MultivariatePoly,
+	protocols::fri::CommitOutput,
+	transcript::{AdviceWriter, CanRead, CanWrite, Proof, TranscriptWriter},
+	transparent::MultilinearExtensionTransparent,
+};
+use binius_field::{
+	BinaryField128b, BinaryField32b, BinaryField8b, PackedBinaryField2x128b, PackedField,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_math::{
+	DefaultEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
+};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, SeedableRng};
+
+type F = BinaryField128b;
+type P = PackedBinaryField2x128b;
+type Challenger = HasherChallenger<Groestl256>;
+
+const SECURITY_BITS: usize = 100;
+const LOG_INV_RATE: usize = 1;
+
+/// Commits to one multilinear and opens it with a sumcheck against a transparent multilinear.
+fn bench_pcs_opening(c: &mut Criterion) {
+	let mut group = c.benchmark_group("pcs");
+	group.sample_size(10);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let merkle_scheme = merkle_prover.scheme();
+	let domain_factory = DefaultEvaluationDomainFactory::<BinaryField8b>::default();
+	let backend = make_portable_backend();
+
+	for n_vars in [12, 16, 20] {
+		let commit_meta = CommitMeta::with_vars([n_vars]);
+		let fri_params = make_commit_params_with_optimal_arity::<_, BinaryField32b, _>(
+			&commit_meta,
+			merkle_scheme,
+			SECURITY_BITS,
+			LOG_INV_RATE,
+		)
+		.unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut random_multilinear = || {
+			let evals = repeat_with(|| P::random(&mut rng))
+				.take(1 << n_vars.saturating_sub(P::LOG_WIDTH))
+				.collect::<Vec<_>>();
+			MultilinearExtension::new(n_vars, evals).unwrap()
+		};
+		let committed = [MLEDirectAdapter::from(random_multilinear())];
+		let transparent_mle = random_multilinear();
+		let transparent = [MLEDirectAdapter::from(transparent_mle.clone())];
+		let sum = (0..1 << n_vars)
+			.map(|i| {
+				committed[0].evaluate_on_hypercube(i).unwrap()
+					* transparent[0].evaluate_on_hypercube(i).unwrap()
+			})
+			.sum::<F>();
+		let sumcheck_claims = [PIOPSumcheckClaim {
+			n_vars,
+			committed: 0,
+			transparent: 0,
+			sum,
+		}];
+
+		group.bench_function(BenchmarkId::new("commit", n_vars), |bench| {
+			bench.iter(|| piop::commit(&fri_params, &merkle_prover, &committed).unwrap());
+		});
+
+		let CommitOutput {
+			commitment,
+			committed: committed_tree,
+			codeword,
+		} = piop::commit(&fri_params, &merkle_prover, &committed).unwrap();
+		let open = || {
+			let mut proof = Proof {
+				transcript: TranscriptWriter::<Challenger>::default(),
+				advice: AdviceWriter::default(),
+			};
+			proof.transcript.write_slice(&commitment);
+			piop::prove(
+				&fri_params,
+				&merkle_prover,
+				domain_factory.clone(),
+				&commit_meta,
+				&committed_tree,
+				&codeword,
+				&committed,
+				&transparent,
+				&sumcheck_claims,
+				&mut proof,
+				&backend,
+			)
+			.unwrap();
+			proof
+		};
+		group.bench_function(BenchmarkId::new("open", n_vars), |bench| {
+			bench.iter(&open);
+		});
+
+		let transparent_poly = MultilinearExtensionTransparent::<P, P>::from_values_and_mu(
+			transparent_mle.evals().to_vec(),
+			n_vars,
+		)
+		.unwrap();
+		let transparent_polys = [&transparent_poly as &dyn MultivariatePoly<F>];
+		group.bench_function(BenchmarkId::new("verify", n_vars), |bench| {
+			bench.iter_batched(
+				|| open().into_verifier(),
+				|mut proof| {
+					let commitment = proof.transcript.read_vec(commitment.len()).unwrap();
+					piop::verify(
+						&commit_meta,
+						merkle_scheme,
+						&fri_params,
+						&commitment,
+						&transparent_polys,
+						&sumcheck_claims,
+						&mut proof,
+					)
+					.unwrap()
+				},
+				BatchSize::LargeInput,
+			);
+		});
+	}
+	group.finish()
+}
+
+criterion_main!(pcs_opening);
+criterion_group!(pcs_opening, bench_pcs_opening);
@@ -0,0 +1,164 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	fiat_shamir::HasherChallenger,
+	protocols::{
+		sumcheck::{self, prove::RegularSumcheckProver, CompositeSumClaim, SumcheckClaim},
+		test_utils::TestProductComposition,
+	},
+	transcript::{TranscriptReader, TranscriptWriter},
+};
+use binius_field::{
+	arch::OptimalUnderlier128b, as_packed_field::PackedType, BinaryField128b, BinaryField8b,
+	PackedField,
+};
+use binius_hal::{make_portable_backend, RoundEvalStrategy};
+use binius_math::{IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, SeedableRng};
+
+type F = BinaryField128b;
+type P = PackedType<OptimalUnderlier128b, F>;
+type Challenger = HasherChallenger<Groestl256>;
+
+/// Proves and verifies the sum of a product of multilinears, round by round.
+fn bench_sumcheck(c: &mut Criterion) {
+	let mut group = c.benchmark_group("sumcheck");
+	let n_multilinears = 3;
+	let composition = TestProductComposition::new(n_multilinears);
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let backend = make_portable_backend();
+
+	for n_vars in [12, 16, 20] {
+		let mut rng = StdRng::seed_from_u64(0);
+		let multilinears = repeat_with(|| {
+			let evals = repeat_with(|| P::random(&mut rng))
+				.take(1 << n_vars)
+				.collect::<Vec<_>>();
+			MultilinearExtension::from_values(evals).unwrap()
+		})
+		.take(n_multilinears)
+		.collect::<Vec<_>>();
+		let sum = (0..1 << n_vars)
+			.map(|i| {
+				multilinears
+					.iter()
+					.map(|multilinear| multilinear.evals()[i].get(0))
+					.product::<F>()
+			})
+			.sum::<F>();
+		let prove = || {
+			let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+				multilinears
+					.iter()
+					.cloned()
+					.map(MLEDirectAdapter::from)
+					.collect(),
+				[CompositeSumClaim {
+					composition: &composition,
+					sum,
+				}],
+				domain_factory.clone(),
+				|_| 1,
+				&backend,
+			)
+			.unwrap();
+			let mut transcript = TranscriptWriter::<Challenger>::default();
+			sumcheck::batch_prove(vec![prover], &mut transcript).unwrap();
+			transcript.finalize()
+		};
+
+		group.throughput(Throughput::Elements(1 << n_vars));
+		group.bench_function(BenchmarkId::new("prove", n_vars), |bench| {
+			bench.iter(&prove);
+		});
+
+		let proof = prove();
+		let claim = SumcheckClaim::new(
+			n_vars,
+			n_multilinears,
+			vec![CompositeSumClaim {
+				composition: &composition,
+				sum,
+			}],
+		)
+		.unwrap();
+		group.bench_function(BenchmarkId::new("verify", n_vars), |bench| {
+			bench.iter_batched(
+				|| TranscriptReader::<Challenger>::new(proof.clone()),
+				|mut reader| sumcheck::batch_verify(&[claim.clone()], &mut reader).unwrap(),
+				BatchSize::SmallInput,
+			);
+		});
+	}
+	group.finish()
+}
+
+/// Proves the sum of products of multilinears of increasing degree with each round evaluation
+/// strategy.
+fn bench_round_eval_strategies(c: &mut Criterion) {
+	let mut group = c.benchmark_group("sumcheck_round_eval_strategies");
+	let n_vars = 14;
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let backend = make_portable_backend();
+
+	for degree in [2, 4, 8] {
+		let composition = TestProductComposition::new(degree);
+		let mut rng = StdRng::seed_from_u64(0);
+		let multilinears = repeat_with(|| {
+			let evals = repeat_with(|| P::random(&mut rng))
+				.take(1 << n_vars)
+				.collect::<Vec<_>>();
+			MultilinearExtension::from_values(evals).unwrap()
+		})
+		.take(degree)
+		.collect::<Vec<_>>();
+		let sum = (0..1 << n_vars)
+			.map(|i| {
+				multilinears
+					.iter()
+					.map(|multilinear| multilinear.evals()[i].get(0))
+					.product::<F>()
+			})
+			.sum::<F>();
+
+		group.throughput(Throughput::Elements(1 << n_vars));
+		for strategy in [
+			RoundEvalStrategy::Naive,
+			RoundEvalStrategy::SlopeReuse,
+			RoundEvalStrategy::Karatsuba,
+		] {
+			let id = BenchmarkId::new(format!("{strategy:?}"), format!("degree={degree}"));
+			group.bench_function(id, |bench| {
+				bench.iter(|| {
+					let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+						multilinears
+							.iter()
+							.cloned()
+							.map(MLEDirectAdapter::from)
+							.collect(),
+						[CompositeSumClaim {
+							composition: &composition,
+							sum,
+						}],
+						domain_factory.clone(),
+						|_| 1,
+						&backend,
+					)
+					.unwrap()
+					.with_round_eval_strategy(strategy);
+					let mut transcript = TranscriptWriter::<Challenger>::default();
+					sumcheck::batch_prove(vec![prover], &mut transcript).unwrap();
+					transcript.finalize()
+				});
+			});
+		}
+	}
+	group.finish()
+}
+
+criterion_main!(sumcheck_rounds);
+criterion_group!(sumcheck_rounds, bench_sumcheck, bench_round_eval_strategies);
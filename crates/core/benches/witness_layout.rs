@@ -0,0 +1,99 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	tower::CanonicalTowerFamily,
+	witness::{MultilinearWitness, WitnessLayout},
+};
+use binius_field::{
+	arch::OptimalUnderlier,
+	as_packed_field::{PackScalar, PackedType},
+	BinaryField128b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField, PackedField,
+	RepackedExtension, TowerField,
+};
+use binius_math::{MultilinearExtension, MultilinearPoly};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::ThreadRng, thread_rng};
+
+type U = OptimalUnderlier;
+type P = PackedType<U, BinaryField128b>;
+
+fn random_column<FS>(n_vars: usize, rng: &mut ThreadRng) -> MultilinearWitness<'static, P>
+where
+	FS: TowerField,
+	BinaryField128b: ExtensionField<FS>,
+	U: PackScalar<FS>,
+	P: RepackedExtension<PackedType<U, FS>>,
+{
+	let values = repeat_with(|| PackedType::<U, FS>::random(&mut *rng))
+		.take(1 << n_vars.saturating_sub(PackedType::<U, FS>::LOG_WIDTH))
+		.collect::<Vec<_>>();
+	MultilinearExtension::new(n_vars, values)
+		.unwrap()
+		.specialize_arc_dyn()
+}
+
+/// Reads all columns of a table of mixed field widths subcube by subcube, as the round
+/// evaluations of zerocheck do.
+///
+/// The univariate round reads the columns embedded into its base field, 32 bits here, and the
+/// later rounds read them as extension field elements.
+fn bench_table_subcube_reads(c: &mut Criterion) {
+	let mut group = c.benchmark_group("witness_layout");
+	let mut rng = thread_rng();
+	let (n_vars, subcube_vars) = (14usize, 8usize);
+	for n_columns in [16, 256] {
+		let columns = (0..n_columns)
+			.map(|i| match i % 4 {
+				0 | 1 => random_column::<BinaryField1b>(n_vars, &mut rng),
+				2 => random_column::<BinaryField8b>(n_vars, &mut rng),
+				_ => random_column::<BinaryField32b>(n_vars, &mut rng),
+			})
+			.collect::<Vec<_>>();
+
+		for log_embedding_degree in [0, 2] {
+			for layout in [WitnessLayout::ColumnMajor, WitnessLayout::RowMajor] {
+				let table = layout
+					.arrange_table::<CanonicalTowerFamily, _>(n_vars, columns.clone())
+					.unwrap();
+				group.bench_with_input(
+					BenchmarkId::new(
+						format!("{layout:?}/log_embedding_degree={log_embedding_degree}"),
+						n_columns,
+					),
+					&table,
+					|bench, table| {
+						let mut evals = vec![
+							P::zero();
+							1 << subcube_vars.saturating_sub(
+								log_embedding_degree + P::LOG_WIDTH
+							)
+						];
+						bench.iter(|| {
+							let mut sum = P::zero();
+							for subcube_index in 0..1 << (n_vars - subcube_vars) {
+								for column in table {
+									column
+										.subcube_evals(
+											subcube_vars,
+											subcube_index,
+											log_embedding_degree,
+											&mut evals,
+										)
+										.unwrap();
+									sum += evals.iter().copied().sum::<P>();
+								}
+							}
+							sum
+						});
+					},
+				);
+			}
+		}
+	}
+	group.finish()
+}
+
+criterion_group!(witness_layout, bench_table_subcube_reads);
+criterion_main!(witness_layout);
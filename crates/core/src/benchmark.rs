@@ -0,0 +1,408 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Baselines of benchmark timings and comparisons of benchmark runs against them.
+//!
+//! The benchmarks of the workspace are criterion benchmarks: `sumcheck`, `evalcheck` and `pcs` in
+//! this crate cover the protocols, and `prove_verify` in `binius_circuits` covers full proofs of
+//! standard circuits. Criterion writes the estimates of every benchmark of a run below
+//! `target/criterion`, from where [`Baseline::from_criterion_dir`] collects their mean times.
+//!
+//! A baseline is stored as a JSON object holding the mean times in nanoseconds by benchmark id,
+//! and metadata describing the machine and build profile they were measured with, see
+//! [`Baseline::to_json`]. Timings are only comparable between runs with the same metadata.
+//! [`Baseline::compare`] reports the benchmarks of a run that are slower or faster than the
+//! baseline by more than a relative threshold, and the metadata in which the run differs. The
+//! `bench_baseline` binary, built with the `benchmark` feature, records and compares baselines
+//! from the command line:
+//!
+//! ```text
+//! cargo bench -p binius_core -p binius_circuits --bench sumcheck --bench evalcheck --bench pcs --bench prove_verify
+//! cargo run -p binius_core --features benchmark --bin bench_baseline -- record target/criterion crates/core/benches/baseline.json
+//! cargo run -p binius_core --features benchmark --bin bench_baseline -- compare crates/core/benches/baseline.json target/criterion 0.1
+//! ```
+//!
+//! The checked-in `crates/core/benches/baseline.json` was recorded this way, on the machine its
+//! metadata describes.
+
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	fmt::{self, Display},
+	fs, io,
+	path::{Path, PathBuf},
+};
+
+use serde_json::{Map, Number, Value};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("I/O error: {0}")]
+	Io(#[from] io::Error),
+	#[error("JSON error: {0}")]
+	Json(#[from] serde_json::Error),
+	#[error("the estimates in {path} have no mean point estimate")]
+	MissingMean { path: PathBuf },
+	#[error("a baseline must be a JSON object of metadata strings and benchmark mean times")]
+	InvalidBaseline,
+}
+
+/// The mean times of a set of benchmarks, in nanoseconds, by benchmark id.
+///
+/// The metadata describes the environment of the run, such as the CPU and the build profile.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Baseline {
+	metadata: BTreeMap<String, String>,
+	means: BTreeMap<String, f64>,
+}
+
+impl Baseline {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn insert(&mut self, id: impl Into<String>, mean_ns: f64) {
+		self.means.insert(id.into(), mean_ns);
+	}
+
+	pub fn get(&self, id: &str) -> Option<f64> {
+		self.means.get(id).copied()
+	}
+
+	pub fn len(&self) -> usize {
+		self.means.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.means.is_empty()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+		self.means.iter().map(|(id, &mean)| (id.as_str(), mean))
+	}
+
+	pub fn set_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
+		self.metadata.insert(key.into(), value.into());
+	}
+
+	pub fn metadata(&self, key: &str) -> Option<&str> {
+		self.metadata.get(key).map(String::as_str)
+	}
+
+	/// Collects the latest run of every benchmark in a criterion output directory.
+	///
+	/// Criterion keeps the latest run of a benchmark in a `new` directory holding its estimates.
+	/// The benchmark id is the `full_id` criterion records next to them, or the path of the
+	/// benchmark directory relative to `dir` if there is none.
+	pub fn from_criterion_dir(dir: impl AsRef<Path>) -> Result<Self, Error> {
+		let mut baseline = Self::new();
+		collect_criterion_runs(dir.as_ref(), dir.as_ref(), &mut baseline)?;
+		Ok(baseline)
+	}
+
+	pub fn from_json(json: &str) -> Result<Self, Error> {
+		let value: Value = serde_json::from_str(json)?;
+		let object = |key| match value.get(key) {
+			Some(Value::Object(object)) => Ok(object.iter()),
+			_ => Err(Error::InvalidBaseline),
+		};
+		let metadata = object("metadata")?
+			.map(|(key, value)| {
+				Ok((key.clone(), value.as_str().ok_or(Error::InvalidBaseline)?.into()))
+			})
+			.collect::<Result<_, Error>>()?;
+		let means = object("benchmarks")?
+			.map(|(id, mean)| Ok((id.clone(), mean.as_f64().ok_or(Error::InvalidBaseline)?)))
+			.collect::<Result<_, Error>>()?;
+		Ok(Self { metadata, means })
+	}
+
+	pub fn to_json(&self) -> String {
+		let metadata = self
+			.metadata
+			.iter()
+			.map(|(key, value)| (key.clone(), Value::String(value.clone())))
+			.collect::<Map<_, _>>();
+		let means = self
+			.means
+			.iter()
+			.filter_map(|(id, &mean)| Some((id.clone(), Value::Number(Number::from_f64(mean)?))))
+			.collect::<Map<_, _>>();
+		let object = Map::from_iter([
+			("metadata".to_string(), Value::Object(metadata)),
+			("benchmarks".to_string(), Value::Object(means)),
+		]);
+		serde_json::to_string_pretty(&Value::Object(object)).expect("a JSON value serializes")
+	}
+
+	/// Compares the benchmarks of `run` with this baseline.
+	pub fn compare(&self, run: &Baseline, thresholds: &Thresholds) -> Comparison {
+		let mut comparison = Comparison::default();
+		for (id, &baseline_ns) in &self.means {
+			let Some(current_ns) = run.get(id) else {
+				comparison.missing.push(id.clone());
+				continue;
+			};
+			let change = Change {
+				id: id.clone(),
+				baseline_ns,
+				current_ns,
+			};
+			let threshold = thresholds.threshold(id);
+			if change.ratio() > 1.0 + threshold {
+				comparison.regressions.push(change);
+			} else if change.ratio() < 1.0 / (1.0 + threshold) {
+				comparison.improvements.push(change);
+			} else {
+				comparison.unchanged.push(change);
+			}
+		}
+		comparison.added = run
+			.means
+			.keys()
+			.filter(|id| !self.means.contains_key(*id))
+			.cloned()
+			.collect();
+		let keys = self.metadata.keys().chain(run.metadata.keys());
+		comparison.metadata = keys
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.filter_map(|key| {
+				let (baseline, current) = (self.metadata(key), run.metadata(key));
+				(baseline != current).then(|| MetadataChange {
+					key: key.clone(),
+					baseline: baseline.map(str::to_string),
+					current: current.map(str::to_string),
+				})
+			})
+			.collect();
+		comparison
+	}
+}
+
+fn collect_criterion_runs(root: &Path, dir: &Path, baseline: &mut Baseline) -> Result<(), Error> {
+	let run = dir.join("new");
+	let estimates = run.join("estimates.json");
+	if estimates.is_file() {
+		let value: Value = serde_json::from_str(&fs::read_to_string(&estimates)?)?;
+		let mean = value
+			.pointer("/mean/point_estimate")
+			.and_then(Value::as_f64)
+			.ok_or_else(|| Error::MissingMean {
+				path: estimates.clone(),
+			})?;
+		let full_id = fs::read_to_string(run.join("benchmark.json"))
+			.ok()
+			.and_then(|json| serde_json::from_str::<Value>(&json).ok())
+			.and_then(|value| value.get("full_id")?.as_str().map(str::to_string));
+		let id = full_id.unwrap_or_else(|| {
+			let relative = dir.strip_prefix(root).unwrap_or(dir);
+			relative
+				.components()
+				.map(|component| component.as_os_str().to_string_lossy())
+				.collect::<Vec<_>>()
+				.join("/")
+		});
+		baseline.insert(id, mean);
+		return Ok(());
+	}
+
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+		// Criterion writes its HTML reports next to the benchmarks.
+		if path.is_dir() && path.file_name().is_some_and(|name| name != "report") {
+			collect_criterion_runs(root, &path, baseline)?;
+		}
+	}
+	Ok(())
+}
+
+/// The relative slowdowns up to which a benchmark is not a regression.
+///
+/// A threshold of 0.1 reports benchmarks more than 10% slower than the baseline as regressions
+/// and those more than 10% faster as improvements. Thresholds can be overridden for the
+/// benchmarks whose ids start with a prefix, the longest matching prefix taking precedence.
+#[derive(Debug, Clone)]
+pub struct Thresholds {
+	default: f64,
+	by_prefix: Vec<(String, f64)>,
+}
+
+impl Thresholds {
+	pub fn new(default: f64) -> Self {
+		Self {
+			default,
+			by_prefix: Vec::new(),
+		}
+	}
+
+	pub fn with_prefix(mut self, prefix: impl Into<String>, threshold: f64) -> Self {
+		self.by_prefix.push((prefix.into(), threshold));
+		self
+	}
+
+	pub fn threshold(&self, id: &str) -> f64 {
+		self.by_prefix
+			.iter()
+			.filter(|(prefix, _)| id.starts_with(prefix.as_str()))
+			.max_by_key(|(prefix, _)| prefix.len())
+			.map_or(self.default, |&(_, threshold)| threshold)
+	}
+}
+
+impl Default for Thresholds {
+	fn default() -> Self {
+		Self::new(0.1)
+	}
+}
+
+/// The mean time of a benchmark in a baseline and in a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+	pub id: String,
+	pub baseline_ns: f64,
+	pub current_ns: f64,
+}
+
+impl Change {
+	/// The time of the run relative to the baseline.
+	pub fn ratio(&self) -> f64 {
+		self.current_ns / self.baseline_ns
+	}
+}
+
+/// A metadata entry that differs between a baseline and a run, or is missing in one of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataChange {
+	pub key: String,
+	pub baseline: Option<String>,
+	pub current: Option<String>,
+}
+
+/// The result of [`Baseline::compare`].
+#[derive(Debug, Clone, Default)]
+pub struct Comparison {
+	pub regressions: Vec<Change>,
+	pub improvements: Vec<Change>,
+	pub unchanged: Vec<Change>,
+	/// The benchmarks of the baseline that are not in the run.
+	pub missing: Vec<String>,
+	/// The benchmarks of the run that are not in the baseline.
+	pub added: Vec<String>,
+	/// The metadata in which the run differs from the baseline, which makes its timings
+	/// incomparable.
+	pub metadata: Vec<MetadataChange>,
+}
+
+impl Comparison {
+	pub fn has_regressions(&self) -> bool {
+		!self.regressions.is_empty()
+	}
+}
+
+impl Display for Comparison {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for change in &self.metadata {
+			writeln!(
+				f,
+				"{:>9} {}: {} -> {}",
+				"differs",
+				change.key,
+				change.baseline.as_deref().unwrap_or("<none>"),
+				change.current.as_deref().unwrap_or("<none>")
+			)?;
+		}
+		for (label, changes) in [
+			("regressed", &self.regressions),
+			("improved", &self.improvements),
+			("unchanged", &self.unchanged),
+		] {
+			for change in changes {
+				writeln!(
+					f,
+					"{label:>9} {}: {:.0} ns -> {:.0} ns ({:+.1}%)",
+					change.id,
+					change.baseline_ns,
+					change.current_ns,
+					(change.ratio() - 1.0) * 100.0
+				)?;
+			}
+		}
+		for id in &self.missing {
+			writeln!(f, "{:>9} {id}", "missing")?;
+		}
+		for id in &self.added {
+			writeln!(f, "{:>9} {id}", "added")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_compare_with_thresholds() {
+		let mut baseline = Baseline::new();
+		baseline.insert("sumcheck/n_vars=16", 1000.0);
+		baseline.insert("sumcheck/n_vars=20", 1000.0);
+		baseline.insert("pcs/open", 1000.0);
+		baseline.insert("evalcheck/depth=4", 1000.0);
+		baseline.set_metadata("cpu", "Xeon");
+		baseline.set_metadata("profile", "bench");
+
+		let mut run = Baseline::new();
+		run.insert("sumcheck/n_vars=16", 1150.0);
+		run.insert("sumcheck/n_vars=20", 850.0);
+		run.insert("pcs/open", 1150.0);
+		run.insert("prove_verify/keccakf", 1000.0);
+		run.set_metadata("cpu", "EPYC");
+		run.set_metadata("profile", "bench");
+
+		let thresholds = Thresholds::new(0.1).with_prefix("pcs/", 0.2);
+		let comparison = baseline.compare(&run, &thresholds);
+		let ids = |changes: &[Change]| changes.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+		assert_eq!(ids(&comparison.regressions), ["sumcheck/n_vars=16"]);
+		assert_eq!(ids(&comparison.improvements), ["sumcheck/n_vars=20"]);
+		assert_eq!(ids(&comparison.unchanged), ["pcs/open"]);
+		assert_eq!(comparison.missing, ["evalcheck/depth=4"]);
+		assert_eq!(comparison.added, ["prove_verify/keccakf"]);
+		assert!(comparison.has_regressions());
+		assert_eq!(
+			comparison.metadata,
+			[MetadataChange {
+				key: "cpu".into(),
+				baseline: Some("Xeon".into()),
+				current: Some("EPYC".into()),
+			}]
+		);
+
+		assert_eq!(Baseline::from_json(&baseline.to_json()).unwrap(), baseline);
+		assert!(matches!(Baseline::from_json("[1, 2]"), Err(Error::InvalidBaseline)));
+	}
+
+	#[test]
+	fn test_from_criterion_dir() {
+		let root = std::env::temp_dir().join(format!("binius_benchmark_{}", std::process::id()));
+		let write = |dir: &str, estimates: &str, benchmark: Option<&str>| {
+			let run = root.join(dir).join("new");
+			fs::create_dir_all(&run).unwrap();
+			fs::write(run.join("estimates.json"), estimates).unwrap();
+			if let Some(benchmark) = benchmark {
+				fs::write(run.join("benchmark.json"), benchmark).unwrap();
+			}
+		};
+		write(
+			"sumcheck/n_vars_16",
+			r#"{"mean": {"point_estimate": 1500.5}}"#,
+			Some(r#"{"full_id": "sumcheck/n_vars=16"}"#),
+		);
+		write("pcs/open", r#"{"mean": {"point_estimate": 20.0}}"#, None);
+		fs::create_dir_all(root.join("report")).unwrap();
+
+		let baseline = Baseline::from_criterion_dir(&root).unwrap();
+		fs::remove_dir_all(&root).unwrap();
+		assert_eq!(baseline.len(), 2);
+		assert_eq!(baseline.get("sumcheck/n_vars=16"), Some(1500.5));
+		assert_eq!(baseline.get("pcs/open"), Some(20.0));
+	}
+}
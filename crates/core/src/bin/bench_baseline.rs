@@ -0,0 +1,89 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Records benchmark baselines and compares benchmark runs against them, see
+//! [`binius_core::benchmark`].
+//!
+//! Both commands describe the machine they run on in the metadata of the run, so they must run on
+//! the machine that ran the benchmarks. The build profile is taken from `BENCH_PROFILE`, which
+//! defaults to `bench`, the profile of `cargo bench`.
+
+use std::{
+	fs,
+	process::{Command, ExitCode},
+};
+
+use binius_core::benchmark::{Baseline, Error, Thresholds};
+
+const USAGE: &str = "usage:
+  bench_baseline record <criterion dir> <baseline json>
+  bench_baseline compare <baseline json> <criterion dir> [threshold]";
+
+fn main() -> ExitCode {
+	let args = std::env::args().skip(1).collect::<Vec<_>>();
+	let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+	let result = match args.as_slice() {
+		["record", criterion_dir, baseline] => record(criterion_dir, baseline),
+		["compare", baseline, criterion_dir] => compare(baseline, criterion_dir, None),
+		["compare", baseline, criterion_dir, threshold] => match threshold.parse() {
+			Ok(threshold) => compare(baseline, criterion_dir, Some(threshold)),
+			Err(_) => {
+				eprintln!("invalid threshold {threshold}\n{USAGE}");
+				return ExitCode::FAILURE;
+			}
+		},
+		_ => {
+			eprintln!("{USAGE}");
+			return ExitCode::FAILURE;
+		}
+	};
+	match result {
+		Ok(true) => ExitCode::SUCCESS,
+		Ok(false) => ExitCode::FAILURE,
+		Err(err) => {
+			eprintln!("error: {err}");
+			ExitCode::FAILURE
+		}
+	}
+}
+
+/// Reads the benchmarks of a criterion output directory, with the metadata of this machine.
+fn criterion_run(criterion_dir: &str) -> Result<Baseline, Error> {
+	let mut run = Baseline::from_criterion_dir(criterion_dir)?;
+	let cpu = fs::read_to_string("/proc/cpuinfo")
+		.ok()
+		.and_then(|cpuinfo| {
+			cpuinfo.lines().find_map(|line| {
+				let (key, value) = line.split_once(':')?;
+				(key.trim() == "model name").then(|| value.trim().to_string())
+			})
+		});
+	run.set_metadata("cpu", cpu.unwrap_or_else(|| "unknown".into()));
+	let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+	run.set_metadata("threads", threads.to_string());
+	run.set_metadata("os", std::env::consts::OS);
+	run.set_metadata("arch", std::env::consts::ARCH);
+	let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+	if let Ok(output) = Command::new(rustc).arg("--version").output() {
+		run.set_metadata("rustc", String::from_utf8_lossy(&output.stdout).trim());
+	}
+	run.set_metadata("profile", std::env::var("BENCH_PROFILE").unwrap_or_else(|_| "bench".into()));
+	run.set_metadata("rustflags", std::env::var("RUSTFLAGS").unwrap_or_default());
+	Ok(run)
+}
+
+fn record(criterion_dir: &str, baseline: &str) -> Result<bool, Error> {
+	let run = criterion_run(criterion_dir)?;
+	fs::write(baseline, run.to_json())?;
+	println!("recorded {} benchmarks to {baseline}", run.len());
+	Ok(true)
+}
+
+/// Returns whether the run has no regressions.
+fn compare(baseline: &str, criterion_dir: &str, threshold: Option<f64>) -> Result<bool, Error> {
+	let baseline = Baseline::from_json(&fs::read_to_string(baseline)?)?;
+	let run = criterion_run(criterion_dir)?;
+	let thresholds = threshold.map_or_else(Thresholds::default, Thresholds::new);
+	let comparison = baseline.compare(&run, &thresholds);
+	print!("{comparison}");
+	Ok(!comparison.has_regressions())
+}
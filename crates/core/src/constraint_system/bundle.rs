@@ -0,0 +1,1377 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Circuit artifact bundles.
+//!
+//! A [`Bundle`] holds everything a prover or verifier deployment needs to know about a circuit
+//! besides its witness: the constraint system with the names of its oracles, the parameters of
+//! the polynomial commitment scheme, the schema of the public inputs and the expected proof
+//! configuration. It is saved as a single `.binius` file, so deployments pass around one artifact
+//! instead of rebuilding the circuit from source.
+//!
+//! The format starts with [`BUNDLE_MAGIC`] and a little-endian `u32` version, followed by the
+//! tower level of the field. All integers are little-endian `u64`s unless stated otherwise, and
+//! field elements use their canonical encoding, so a bundle saved with one tower basis can be
+//! loaded with an isomorphic one.
+//!
+//! Oracles are stored in the order of their IDs and re-added in that order, as in
+//! [`MultilinearOracleSet::isomorphic`], so every oracle keeps its ID. The transparent
+//! polynomials of [`crate::transparent`] that are given by a few parameters, such as
+//! [`EqIndPartialEval`] or [`StepDown`], are stored as their type and parameters and rebuilt on
+//! load. Other transparents have no serializable description. They are stored as their
+//! evaluations over the hypercube and loaded as a multilinear extension of those, which is the
+//! same multilinear polynomial, so they are limited to [`MAX_TABULATED_N_VARS`] variables.
+//!
+//! Version 2 of the format added the parameterized transparents. Bundles of version 1 are still
+//! loaded, as the format is otherwise unchanged.
+
+use std::{
+	fs,
+	io::{self, Read, Write},
+	path::Path,
+	sync::Arc,
+};
+
+use binius_field::{deserialize_canonical, serialize_canonical, TowerField};
+use binius_math::ArithExpr;
+use binius_utils::bail;
+use bytes::BufMut;
+
+use super::{
+	channel::{BalancingPolicy, ChannelInfo, ChannelRegistry, Flush, FlushDirection},
+	AdviceCompression, ConstraintSystem, MatMul, PCSParams, Proof, ProofEncoding, PublicInputs,
+};
+use crate::{
+	oracle::{
+		self, Constraint, ConstraintPredicate, ConstraintSet, MultilinearOracleSet,
+		MultilinearPolyOracle, OracleId, ProjectionVariant, ShiftVariant,
+	},
+	polynomial::{self, MultivariatePoly},
+	transcript::ScalarEncoding,
+	transparent::{
+		constant::Constant, eq_ind::EqIndPartialEval, every_kth::EveryKth, interval::Interval,
+		powers::Powers, select_row::SelectRow, shift_ind::ShiftIndPartialEval, step_down::StepDown,
+		step_up::StepUp, tower_basis::TowerBasis,
+	},
+};
+
+/// The tag that starts every bundle.
+pub const BUNDLE_MAGIC: &[u8] = b"binius-bundle";
+/// The version of the bundle format written by [`Bundle::to_bytes`].
+pub const BUNDLE_VERSION: u32 = 2;
+/// The maximum number of variables of a transparent oracle that is stored by its evaluations.
+pub const MAX_TABULATED_N_VARS: usize = 20;
+/// The maximum nesting depth of the arithmetic expressions of constraints in a bundle.
+const MAX_EXPR_DEPTH: usize = 1 << 12;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+	#[error("I/O error: {0}")]
+	Io(#[from] io::Error),
+	#[error("malformed bundle: {0}")]
+	Malformed(&'static str),
+	#[error("unsupported bundle version {0}")]
+	UnsupportedVersion(u32),
+	#[error("the bundle is over a field of tower level {got}, expected {expected}")]
+	TowerLevelMismatch { expected: usize, got: usize },
+	#[error("transparent oracle {id} is not of a parameterized type and has {n_vars} variables, at most {MAX_TABULATED_N_VARS} can be stored")]
+	TransparentTooLarge { id: OracleId, n_vars: usize },
+	#[error("public input slot {slot} ({name}) has {got} bytes, expected {expected}")]
+	PublicInputLength {
+		slot: usize,
+		name: String,
+		expected: usize,
+		got: usize,
+	},
+	#[error("the bundle declares {expected} public input slots, got {got}")]
+	PublicInputCount { expected: usize, got: usize },
+	#[error("the proof does not match the verifier configuration of the bundle: {0}")]
+	ProofConfigMismatch(&'static str),
+	#[error("oracle error: {0}")]
+	Oracle(#[from] oracle::Error),
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] polynomial::Error),
+}
+
+/// A named public input slot, see [`PublicInputs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputSlot {
+	pub name: String,
+	/// The length of the slot in bytes, if it is fixed.
+	pub len: Option<usize>,
+}
+
+/// The public inputs a circuit is proven against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicInputSchema {
+	pub slots: Vec<PublicInputSlot>,
+}
+
+impl PublicInputSchema {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn with_slot(mut self, name: impl Into<String>, len: Option<usize>) -> Self {
+		self.slots.push(PublicInputSlot {
+			name: name.into(),
+			len,
+		});
+		self
+	}
+
+	/// Checks that public inputs have the declared number of slots and slot lengths.
+	pub fn check(&self, public_inputs: &PublicInputs) -> Result<(), BundleError> {
+		if public_inputs.len() != self.slots.len() {
+			bail!(BundleError::PublicInputCount {
+				expected: self.slots.len(),
+				got: public_inputs.len(),
+			});
+		}
+		for (slot, (schema, value)) in self.slots.iter().zip(public_inputs.slots()).enumerate() {
+			if let Some(expected) = schema.len {
+				if value.len() != expected {
+					bail!(BundleError::PublicInputLength {
+						slot,
+						name: schema.name.clone(),
+						expected,
+						got: value.len(),
+					});
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// The configuration proofs of a bundle are generated and verified with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifierConfig {
+	/// The name of the hash function the prover and verifier are instantiated with, for example
+	/// `"groestl256"`. The bundle does not interpret it.
+	pub hash: String,
+	/// The encoding proofs are generated with.
+	pub encoding: ProofEncoding,
+	/// The claim version proofs must carry in their metadata, if any.
+	pub claim_version: Option<u32>,
+}
+
+impl VerifierConfig {
+	/// Checks that a proof was generated with this configuration.
+	pub fn check(&self, proof: &Proof) -> Result<(), BundleError> {
+		if proof.encoding != self.encoding {
+			bail!(BundleError::ProofConfigMismatch("proof encoding"));
+		}
+		if let Some(claim_version) = self.claim_version {
+			let Some(metadata) = &proof.metadata else {
+				bail!(BundleError::ProofConfigMismatch("missing proof metadata"));
+			};
+			if metadata.claim_version != claim_version {
+				bail!(BundleError::ProofConfigMismatch("claim version"));
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A circuit artifact, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct Bundle<F: TowerField> {
+	pub constraint_system: ConstraintSystem<F>,
+	pub pcs_params: PCSParams,
+	pub public_inputs: PublicInputSchema,
+	pub verifier: VerifierConfig,
+}
+
+impl<F: TowerField> Bundle<F> {
+	pub fn new(constraint_system: ConstraintSystem<F>, pcs_params: PCSParams) -> Self {
+		Self {
+			constraint_system,
+			pcs_params,
+			public_inputs: PublicInputSchema::default(),
+			verifier: VerifierConfig::default(),
+		}
+	}
+
+	pub fn with_public_inputs(mut self, public_inputs: PublicInputSchema) -> Self {
+		self.public_inputs = public_inputs;
+		self
+	}
+
+	pub fn with_verifier_config(mut self, verifier: VerifierConfig) -> Self {
+		self.verifier = verifier;
+		self
+	}
+
+	/// The ID of the first oracle with the given name.
+	pub fn oracle_id(&self, name: &str) -> Option<OracleId> {
+		self.constraint_system
+			.oracles
+			.iter()
+			.find(|oracle| oracle.name() == Some(name))
+			.map(|oracle| oracle.id())
+	}
+
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BundleError> {
+		Ok(fs::write(path, self.to_bytes()?)?)
+	}
+
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, BundleError> {
+		Self::from_bytes(&fs::read(path)?)
+	}
+
+	pub fn write(&self, mut writer: impl Write) -> Result<(), BundleError> {
+		Ok(writer.write_all(&self.to_bytes()?)?)
+	}
+
+	pub fn read(mut reader: impl Read) -> Result<Self, BundleError> {
+		let mut bytes = Vec::new();
+		reader.read_to_end(&mut bytes)?;
+		Self::from_bytes(&bytes)
+	}
+
+	pub fn to_bytes(&self) -> Result<Vec<u8>, BundleError> {
+		let mut out = Vec::new();
+		out.put_slice(BUNDLE_MAGIC);
+		out.put_u32_le(BUNDLE_VERSION);
+		out.put_u8(F::TOWER_LEVEL as u8);
+
+		let PCSParams {
+			security_bits,
+			log_inv_rate,
+			fold_arity,
+			n_test_queries,
+			merkle_log_arity,
+			merkle_cap_height,
+		} = self.pcs_params;
+		put_usize(&mut out, security_bits);
+		put_usize(&mut out, log_inv_rate);
+		put_option(&mut out, fold_arity);
+		put_option(&mut out, n_test_queries);
+		put_usize(&mut out, merkle_log_arity);
+		put_usize(&mut out, merkle_cap_height);
+
+		put_usize(&mut out, self.public_inputs.slots.len());
+		for slot in &self.public_inputs.slots {
+			put_bytes(&mut out, slot.name.as_bytes());
+			put_option(&mut out, slot.len);
+		}
+
+		let VerifierConfig {
+			hash,
+			encoding,
+			claim_version,
+		} = &self.verifier;
+		put_bytes(&mut out, hash.as_bytes());
+		put_encoding(&mut out, encoding);
+		match claim_version {
+			None => out.put_u8(0),
+			Some(version) => {
+				out.put_u8(1);
+				out.put_u32_le(*version);
+			}
+		}
+
+		put_constraint_system(&mut out, &self.constraint_system)?;
+		Ok(out)
+	}
+
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, BundleError> {
+		let mut reader = Reader(bytes);
+		if reader.take(BUNDLE_MAGIC.len())? != BUNDLE_MAGIC {
+			bail!(BundleError::Malformed("not a bundle"));
+		}
+		let version = u32::from_le_bytes(reader.array()?);
+		if !(1..=BUNDLE_VERSION).contains(&version) {
+			bail!(BundleError::UnsupportedVersion(version));
+		}
+		let tower_level = reader.u8()? as usize;
+		if tower_level != F::TOWER_LEVEL {
+			bail!(BundleError::TowerLevelMismatch {
+				expected: F::TOWER_LEVEL,
+				got: tower_level,
+			});
+		}
+
+		let pcs_params = PCSParams {
+			security_bits: reader.usize()?,
+			log_inv_rate: reader.usize()?,
+			fold_arity: reader.option()?,
+			n_test_queries: reader.option()?,
+			merkle_log_arity: reader.usize()?,
+			merkle_cap_height: reader.usize()?,
+		};
+
+		let public_inputs = PublicInputSchema {
+			slots: reader.vec(|reader| {
+				Ok(PublicInputSlot {
+					name: reader.string()?,
+					len: reader.option()?,
+				})
+			})?,
+		};
+
+		let verifier = VerifierConfig {
+			hash: reader.string()?,
+			encoding: reader.encoding()?,
+			claim_version: match reader.u8()? {
+				0 => None,
+				1 => Some(u32::from_le_bytes(reader.array()?)),
+				_ => bail!(BundleError::Malformed("invalid claim version")),
+			},
+		};
+
+		let constraint_system = reader.constraint_system()?;
+		if !reader.0.is_empty() {
+			bail!(BundleError::Malformed("trailing bytes"));
+		}
+
+		Ok(Self {
+			constraint_system,
+			pcs_params,
+			public_inputs,
+			verifier,
+		})
+	}
+}
+
+fn put_usize(out: &mut Vec<u8>, value: usize) {
+	out.put_u64_le(value as u64);
+}
+
+fn put_option(out: &mut Vec<u8>, value: Option<usize>) {
+	match value {
+		None => out.put_u8(0),
+		Some(value) => {
+			out.put_u8(1);
+			put_usize(out, value);
+		}
+	}
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+	put_usize(out, bytes.len());
+	out.put_slice(bytes);
+}
+
+fn put_name(out: &mut Vec<u8>, name: Option<&str>) {
+	match name {
+		None => out.put_u8(0),
+		Some(name) => {
+			out.put_u8(1);
+			put_bytes(out, name.as_bytes());
+		}
+	}
+}
+
+fn put_field<F: TowerField>(out: &mut Vec<u8>, value: F) {
+	serialize_canonical(value, out).expect("Vec<u8> has infinite capacity");
+}
+
+fn put_ids(out: &mut Vec<u8>, ids: &[OracleId]) {
+	put_usize(out, ids.len());
+	for &id in ids {
+		put_usize(out, id);
+	}
+}
+
+fn put_encoding(out: &mut Vec<u8>, encoding: &ProofEncoding) {
+	out.put_u8(match encoding.scalars {
+		ScalarEncoding::Canonical => 0,
+		ScalarEncoding::Compact => 1,
+	});
+	match encoding.advice_compression {
+		AdviceCompression::None => out.put_slice(&[0, 0]),
+		AdviceCompression::Deflate { level } => out.put_slice(&[1, level]),
+	}
+}
+
+fn put_expr<F: TowerField>(out: &mut Vec<u8>, expr: &ArithExpr<F>) {
+	match expr {
+		ArithExpr::Const(value) => {
+			out.put_u8(0);
+			put_field(out, *value);
+		}
+		ArithExpr::Var(index) => {
+			out.put_u8(1);
+			put_usize(out, *index);
+		}
+		ArithExpr::Add(lhs, rhs) => {
+			out.put_u8(2);
+			put_expr(out, lhs);
+			put_expr(out, rhs);
+		}
+		ArithExpr::Mul(lhs, rhs) => {
+			out.put_u8(3);
+			put_expr(out, lhs);
+			put_expr(out, rhs);
+		}
+		ArithExpr::Pow(base, exp) => {
+			out.put_u8(4);
+			put_expr(out, base);
+			out.put_u64_le(*exp);
+		}
+	}
+}
+
+fn put_constraint_system<F: TowerField>(
+	out: &mut Vec<u8>,
+	constraint_system: &ConstraintSystem<F>,
+) -> Result<(), BundleError> {
+	let ConstraintSystem {
+		oracles,
+		table_constraints,
+		non_zero_oracle_ids,
+		flushes,
+		matmuls,
+		max_channel_id,
+		channels,
+	} = constraint_system;
+
+	put_usize(out, oracles.size());
+	for oracle in oracles.iter() {
+		put_oracle(out, &oracle)?;
+	}
+
+	put_usize(out, table_constraints.len());
+	for constraint_set in table_constraints {
+		put_usize(out, constraint_set.n_vars);
+		put_ids(out, &constraint_set.oracle_ids);
+		put_usize(out, constraint_set.constraints.len());
+		for constraint in &constraint_set.constraints {
+			put_bytes(out, constraint.name.as_bytes());
+			put_expr(out, &constraint.composition);
+			match constraint.predicate {
+				ConstraintPredicate::Zero => out.put_u8(0),
+				ConstraintPredicate::Sum(sum) => {
+					out.put_u8(1);
+					put_field(out, sum);
+				}
+			}
+		}
+	}
+
+	put_ids(out, non_zero_oracle_ids);
+
+	put_usize(out, flushes.len());
+	for flush in flushes {
+		put_ids(out, &flush.oracles);
+		put_usize(out, flush.channel_id);
+		out.put_u8(match flush.direction {
+			FlushDirection::Push => 0,
+			FlushDirection::Pull => 1,
+		});
+		put_usize(out, flush.count);
+		out.put_u64_le(flush.multiplicity);
+	}
+
+	put_usize(out, matmuls.len());
+	for matmul in matmuls {
+		for value in [
+			matmul.a,
+			matmul.b,
+			matmul.c,
+			matmul.log_rows,
+			matmul.log_inner,
+			matmul.log_cols,
+		] {
+			put_usize(out, value);
+		}
+	}
+
+	put_usize(out, *max_channel_id);
+
+	put_usize(out, channels.len());
+	for id in 0..channels.len() {
+		let info = channels.get(id).expect("id is in range");
+		put_name(out, info.name.as_deref());
+		put_option(out, info.arity);
+		out.put_u8(match info.policy {
+			BalancingPolicy::WithBoundaries => 0,
+			BalancingPolicy::Closed => 1,
+		});
+	}
+	Ok(())
+}
+
+fn put_oracle<F: TowerField>(
+	out: &mut Vec<u8>,
+	oracle: &MultilinearPolyOracle<F>,
+) -> Result<(), BundleError> {
+	use MultilinearPolyOracle::*;
+
+	put_name(out, oracle.name());
+	match oracle {
+		Transparent { id, inner, .. } => {
+			let poly = inner.poly();
+			if put_transparent(out, &**poly) {
+				return Ok(());
+			}
+			let n_vars = poly.n_vars();
+			if n_vars > MAX_TABULATED_N_VARS {
+				bail!(BundleError::TransparentTooLarge { id: *id, n_vars });
+			}
+			out.put_u8(0);
+			put_usize(out, n_vars);
+			put_usize(out, poly.degree());
+			put_usize(out, poly.binary_tower_level());
+			let mut query = vec![F::ZERO; n_vars];
+			for index in 0..1 << n_vars {
+				for (i, coord) in query.iter_mut().enumerate() {
+					*coord = if (index >> i) & 1 == 1 {
+						F::ONE
+					} else {
+						F::ZERO
+					};
+				}
+				put_field(out, poly.evaluate(&query)?);
+			}
+		}
+		Committed {
+			n_vars,
+			tower_level,
+			..
+		} => {
+			out.put_u8(1);
+			put_usize(out, *n_vars);
+			put_usize(out, *tower_level);
+		}
+		Repeating {
+			inner, log_count, ..
+		} => {
+			out.put_u8(2);
+			put_usize(out, inner.id());
+			put_usize(out, *log_count);
+		}
+		Projected { projected, .. } => {
+			out.put_u8(3);
+			put_usize(out, projected.inner().id());
+			out.put_u8(match projected.projection_variant() {
+				ProjectionVariant::FirstVars => 0,
+				ProjectionVariant::LastVars => 1,
+			});
+			put_usize(out, projected.values().len());
+			for &value in projected.values() {
+				put_field(out, value);
+			}
+		}
+		Shifted { shifted, .. } => {
+			out.put_u8(4);
+			put_usize(out, shifted.inner().id());
+			put_usize(out, shifted.shift_offset());
+			put_usize(out, shifted.block_size());
+			put_shift_variant(out, shifted.shift_variant());
+		}
+		Packed { packed, .. } => {
+			out.put_u8(5);
+			put_usize(out, packed.inner().id());
+			put_usize(out, packed.log_degree());
+		}
+		Univariate { univariate, .. } => {
+			out.put_u8(6);
+			put_usize(out, univariate.inner().id());
+			put_usize(out, univariate.log_domain_size());
+		}
+		LinearCombination {
+			linear_combination, ..
+		} => {
+			out.put_u8(7);
+			put_usize(out, linear_combination.n_vars());
+			put_field(out, linear_combination.offset());
+			put_usize(out, linear_combination.n_polys());
+			for (poly, coeff) in linear_combination
+				.polys()
+				.zip(linear_combination.coefficients())
+			{
+				put_usize(out, poly.id());
+				put_field(out, coeff);
+			}
+		}
+		ZeroPadded { inner, n_vars, .. } => {
+			out.put_u8(8);
+			put_usize(out, inner.id());
+			put_usize(out, *n_vars);
+		}
+		Concatenated { concatenated, .. } => {
+			out.put_u8(9);
+			put_usize(out, concatenated.inner_n_vars());
+			let ids = concatenated
+				.polys()
+				.map(|poly| poly.id())
+				.collect::<Vec<_>>();
+			put_ids(out, &ids);
+		}
+	}
+	Ok(())
+}
+
+fn put_shift_variant(out: &mut Vec<u8>, variant: ShiftVariant) {
+	out.put_u8(match variant {
+		ShiftVariant::CircularLeft => 0,
+		ShiftVariant::CircularRight => 1,
+		ShiftVariant::LogicalLeft => 2,
+		ShiftVariant::LogicalRight => 3,
+	});
+}
+
+/// Writes a transparent polynomial of a known type as its type and parameters, returning whether
+/// the type is known.
+fn put_transparent<F: TowerField>(out: &mut Vec<u8>, poly: &dyn MultivariatePoly<F>) -> bool {
+	let Some(poly) = poly.as_any() else {
+		return false;
+	};
+	if let Some(eq_ind) = poly.downcast_ref::<EqIndPartialEval<F>>() {
+		out.put_slice(&[10, 0]);
+		put_usize(out, eq_ind.n_vars());
+		put_usize(out, eq_ind.r().len());
+		for &coord in eq_ind.r() {
+			put_field(out, coord);
+		}
+	} else if let Some(step_down) = poly.downcast_ref::<StepDown>() {
+		out.put_slice(&[10, 1]);
+		put_usize(out, step_down.n_vars());
+		put_usize(out, step_down.index());
+	} else if let Some(step_up) = poly.downcast_ref::<StepUp>() {
+		out.put_slice(&[10, 2]);
+		put_usize(out, step_up.n_vars());
+		put_usize(out, step_up.index());
+	} else if let Some(select_row) = poly.downcast_ref::<SelectRow>() {
+		out.put_slice(&[10, 3]);
+		put_usize(out, select_row.n_vars());
+		put_usize(out, select_row.index());
+	} else if let Some(powers) = poly.downcast_ref::<Powers<F>>() {
+		out.put_slice(&[10, 4]);
+		put_usize(out, powers.n_vars());
+		put_field(out, powers.base());
+	} else if let Some(shift_ind) = poly.downcast_ref::<ShiftIndPartialEval<F>>() {
+		out.put_slice(&[10, 5]);
+		put_usize(out, shift_ind.block_size());
+		put_usize(out, shift_ind.shift_offset());
+		put_shift_variant(out, shift_ind.shift_variant());
+		put_usize(out, shift_ind.r().len());
+		for &coord in shift_ind.r() {
+			put_field(out, coord);
+		}
+	} else if let Some(every_kth) = poly.downcast_ref::<EveryKth>() {
+		out.put_slice(&[10, 6]);
+		put_usize(out, every_kth.n_vars());
+		put_usize(out, every_kth.log_k());
+		put_usize(out, every_kth.offset());
+	} else if let Some(interval) = poly.downcast_ref::<Interval>() {
+		out.put_slice(&[10, 7]);
+		put_usize(out, interval.n_vars());
+		put_usize(out, interval.lo());
+		put_usize(out, interval.hi());
+	} else if let Some(constant) = poly.downcast_ref::<Constant<F>>() {
+		out.put_slice(&[10, 8]);
+		put_usize(out, constant.n_vars());
+		put_field(out, constant.value());
+		put_usize(out, MultivariatePoly::<F>::binary_tower_level(constant));
+	} else if let Some(tower_basis) = poly.downcast_ref::<TowerBasis<F>>() {
+		out.put_slice(&[10, 9]);
+		put_usize(out, tower_basis.k());
+		put_usize(out, tower_basis.iota());
+	} else {
+		return false;
+	}
+	true
+}
+
+/// A transparent polynomial loaded from a bundle, given by its evaluations over the hypercube.
+#[derive(Debug)]
+struct TabulatedPoly<F> {
+	n_vars: usize,
+	degree: usize,
+	tower_level: usize,
+	values: Vec<F>,
+}
+
+impl<F: TowerField> MultivariatePoly<F> for TabulatedPoly<F> {
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn degree(&self) -> usize {
+		self.degree
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, polynomial::Error> {
+		if query.len() != self.n_vars {
+			bail!(polynomial::Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+		// Fold the lowest variable first, as it selects the least significant bit of the index
+		let mut values = self.values.clone();
+		for (i, &coord) in query.iter().enumerate() {
+			let half = 1 << (self.n_vars - i - 1);
+			for j in 0..half {
+				values[j] = values[2 * j] + coord * (values[2 * j + 1] - values[2 * j]);
+			}
+		}
+		Ok(values[0])
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.tower_level
+	}
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+	fn take(&mut self, len: usize) -> Result<&'a [u8], BundleError> {
+		if self.0.len() < len {
+			bail!(BundleError::Malformed("unexpected end of bundle"));
+		}
+		let (head, tail) = self.0.split_at(len);
+		self.0 = tail;
+		Ok(head)
+	}
+
+	fn array<const N: usize>(&mut self) -> Result<[u8; N], BundleError> {
+		Ok(self.take(N)?.try_into().expect("slice has length N"))
+	}
+
+	fn u8(&mut self) -> Result<u8, BundleError> {
+		Ok(self.take(1)?[0])
+	}
+
+	fn usize(&mut self) -> Result<usize, BundleError> {
+		usize::try_from(u64::from_le_bytes(self.array()?))
+			.map_err(|_| BundleError::Malformed("integer out of range"))
+	}
+
+	fn option(&mut self) -> Result<Option<usize>, BundleError> {
+		match self.u8()? {
+			0 => Ok(None),
+			1 => Ok(Some(self.usize()?)),
+			_ => Err(BundleError::Malformed("invalid option")),
+		}
+	}
+
+	fn string(&mut self) -> Result<String, BundleError> {
+		let len = self.usize()?;
+		String::from_utf8(self.take(len)?.to_vec())
+			.map_err(|_| BundleError::Malformed("string is not UTF-8"))
+	}
+
+	fn name(&mut self) -> Result<Option<String>, BundleError> {
+		match self.u8()? {
+			0 => Ok(None),
+			1 => Ok(Some(self.string()?)),
+			_ => Err(BundleError::Malformed("invalid name")),
+		}
+	}
+
+	fn field<F: TowerField>(&mut self) -> Result<F, BundleError> {
+		deserialize_canonical(&mut self.0)
+			.map_err(|_| BundleError::Malformed("unexpected end of bundle"))
+	}
+
+	/// Reads a length-prefixed sequence, without trusting the length for the allocation.
+	fn vec<T>(
+		&mut self,
+		mut read: impl FnMut(&mut Self) -> Result<T, BundleError>,
+	) -> Result<Vec<T>, BundleError> {
+		let len = self.usize()?;
+		let mut items = Vec::with_capacity(len.min(self.0.len()));
+		for _ in 0..len {
+			items.push(read(self)?);
+		}
+		Ok(items)
+	}
+
+	fn ids(&mut self) -> Result<Vec<OracleId>, BundleError> {
+		self.vec(Self::usize)
+	}
+
+	fn encoding(&mut self) -> Result<ProofEncoding, BundleError> {
+		let scalars = match self.u8()? {
+			0 => ScalarEncoding::Canonical,
+			1 => ScalarEncoding::Compact,
+			_ => bail!(BundleError::Malformed("invalid scalar encoding")),
+		};
+		let advice_compression = match (self.u8()?, self.u8()?) {
+			(0, 0) => AdviceCompression::None,
+			(1, level) => AdviceCompression::Deflate { level },
+			_ => bail!(BundleError::Malformed("invalid advice compression")),
+		};
+		Ok(ProofEncoding {
+			scalars,
+			advice_compression,
+		})
+	}
+
+	fn shift_variant(&mut self) -> Result<ShiftVariant, BundleError> {
+		let variant = match self.u8()? {
+			0 => ShiftVariant::CircularLeft,
+			1 => ShiftVariant::CircularRight,
+			2 => ShiftVariant::LogicalLeft,
+			3 => ShiftVariant::LogicalRight,
+			_ => bail!(BundleError::Malformed("invalid shift variant")),
+		};
+		Ok(variant)
+	}
+
+	fn expr<F: TowerField>(&mut self, depth: usize) -> Result<ArithExpr<F>, BundleError> {
+		if depth > MAX_EXPR_DEPTH {
+			bail!(BundleError::Malformed("expression is nested too deeply"));
+		}
+		let expr = match self.u8()? {
+			0 => ArithExpr::Const(self.field()?),
+			1 => ArithExpr::Var(self.usize()?),
+			2 => ArithExpr::Add(Box::new(self.expr(depth + 1)?), Box::new(self.expr(depth + 1)?)),
+			3 => ArithExpr::Mul(Box::new(self.expr(depth + 1)?), Box::new(self.expr(depth + 1)?)),
+			4 => ArithExpr::Pow(Box::new(self.expr(depth + 1)?), u64::from_le_bytes(self.array()?)),
+			_ => bail!(BundleError::Malformed("invalid expression")),
+		};
+		Ok(expr)
+	}
+
+	fn oracle<F: TowerField>(
+		&mut self,
+		oracles: &mut MultilinearOracleSet<F>,
+	) -> Result<OracleId, BundleError> {
+		let name = self.name()?;
+		let addition = match name {
+			Some(name) => oracles.add_named(name),
+			None => oracles.add(),
+		};
+		let id = match self.u8()? {
+			0 => {
+				let n_vars = self.usize()?;
+				if n_vars > MAX_TABULATED_N_VARS {
+					bail!(BundleError::Malformed("transparent oracle is too large"));
+				}
+				let degree = self.usize()?;
+				let tower_level = self.usize()?;
+				let values = (0..1 << n_vars)
+					.map(|_| self.field())
+					.collect::<Result<_, _>>()?;
+				addition.transparent(TabulatedPoly {
+					n_vars,
+					degree,
+					tower_level,
+					values,
+				})?
+			}
+			1 => {
+				let n_vars = self.usize()?;
+				let tower_level = self.usize()?;
+				if n_vars > oracle::MAX_N_VARS || tower_level > F::TOWER_LEVEL {
+					bail!(BundleError::Malformed("invalid committed oracle"));
+				}
+				addition.committed(n_vars, tower_level)
+			}
+			2 => addition.repeating(self.usize()?, self.usize()?)?,
+			3 => {
+				let inner = self.usize()?;
+				let variant = match self.u8()? {
+					0 => ProjectionVariant::FirstVars,
+					1 => ProjectionVariant::LastVars,
+					_ => bail!(BundleError::Malformed("invalid projection variant")),
+				};
+				let values = self.vec(Self::field)?;
+				addition.projected(inner, values, variant)?
+			}
+			4 => {
+				let inner = self.usize()?;
+				let offset = self.usize()?;
+				let block_bits = self.usize()?;
+				let variant = self.shift_variant()?;
+				addition.shifted(inner, offset, block_bits, variant)?
+			}
+			5 => addition.packed(self.usize()?, self.usize()?)?,
+			6 => addition.univariate(self.usize()?, self.usize()?)?,
+			7 => {
+				let n_vars = self.usize()?;
+				let offset = self.field()?;
+				let inner = self.vec(|reader| Ok((reader.usize()?, reader.field()?)))?;
+				addition.linear_combination_with_offset(n_vars, offset, inner)?
+			}
+			8 => addition.zero_padded(self.usize()?, self.usize()?)?,
+			9 => {
+				let n_vars = self.usize()?;
+				addition.concatenated(n_vars, self.ids()?)?
+			}
+			10 => match self.u8()? {
+				0 => addition
+					.transparent(EqIndPartialEval::new(self.usize()?, self.vec(Self::field)?)?)?,
+				1 => addition.transparent(StepDown::new(self.usize()?, self.usize()?)?)?,
+				2 => addition.transparent(StepUp::new(self.usize()?, self.usize()?)?)?,
+				3 => addition.transparent(SelectRow::new(self.usize()?, self.usize()?)?)?,
+				4 => {
+					let n_vars = self.usize()?;
+					if n_vars > oracle::MAX_N_VARS {
+						bail!(BundleError::Malformed("invalid powers transparent"));
+					}
+					addition.transparent(Powers::new(n_vars, self.field()?))?
+				}
+				5 => addition.transparent(ShiftIndPartialEval::new(
+					self.usize()?,
+					self.usize()?,
+					self.shift_variant()?,
+					self.vec(Self::field)?,
+				)?)?,
+				6 => addition.transparent(EveryKth::new(
+					self.usize()?,
+					self.usize()?,
+					self.usize()?,
+				)?)?,
+				7 => addition.transparent(Interval::new(
+					self.usize()?,
+					self.usize()?,
+					self.usize()?,
+				)?)?,
+				8 => addition.transparent(Constant::with_tower_level(
+					self.usize()?,
+					self.field()?,
+					self.usize()?,
+				)?)?,
+				9 => addition.transparent(TowerBasis::<F>::new(self.usize()?, self.usize()?)?)?,
+				_ => bail!(BundleError::Malformed("invalid transparent kind")),
+			},
+			_ => bail!(BundleError::Malformed("invalid oracle kind")),
+		};
+		Ok(id)
+	}
+
+	fn constraint_system<F: TowerField>(&mut self) -> Result<ConstraintSystem<F>, BundleError> {
+		let n_oracles = self.usize()?;
+		let mut oracles = MultilinearOracleSet::new();
+		for _ in 0..n_oracles {
+			self.oracle(&mut oracles)?;
+		}
+		let check_id = |id: OracleId| {
+			if oracles.is_valid_oracle_id(id) {
+				Ok(id)
+			} else {
+				Err(BundleError::Malformed("invalid oracle ID"))
+			}
+		};
+
+		let table_constraints = self.vec(|reader| {
+			let n_vars = reader.usize()?;
+			let oracle_ids = reader
+				.ids()?
+				.into_iter()
+				.map(check_id)
+				.collect::<Result<_, _>>()?;
+			let constraints = reader.vec(|reader| {
+				let name = Arc::from(reader.string()?);
+				let composition = reader.expr(0)?;
+				let predicate = match reader.u8()? {
+					0 => ConstraintPredicate::Zero,
+					1 => ConstraintPredicate::Sum(reader.field()?),
+					_ => bail!(BundleError::Malformed("invalid constraint predicate")),
+				};
+				Ok(Constraint {
+					name,
+					composition,
+					predicate,
+				})
+			})?;
+			Ok(ConstraintSet {
+				n_vars,
+				oracle_ids,
+				constraints,
+			})
+		})?;
+
+		let non_zero_oracle_ids = self
+			.ids()?
+			.into_iter()
+			.map(check_id)
+			.collect::<Result<_, _>>()?;
+
+		let flushes = self.vec(|reader| {
+			let oracles = reader
+				.ids()?
+				.into_iter()
+				.map(check_id)
+				.collect::<Result<_, _>>()?;
+			let channel_id = reader.usize()?;
+			let direction = match reader.u8()? {
+				0 => FlushDirection::Push,
+				1 => FlushDirection::Pull,
+				_ => bail!(BundleError::Malformed("invalid flush direction")),
+			};
+			Ok(Flush {
+				oracles,
+				channel_id,
+				direction,
+				count: reader.usize()?,
+				multiplicity: u64::from_le_bytes(reader.array()?),
+			})
+		})?;
+
+		let matmuls = self.vec(|reader| {
+			Ok(MatMul {
+				a: check_id(reader.usize()?)?,
+				b: check_id(reader.usize()?)?,
+				c: check_id(reader.usize()?)?,
+				log_rows: reader.usize()?,
+				log_inner: reader.usize()?,
+				log_cols: reader.usize()?,
+			})
+		})?;
+
+		let max_channel_id = self.usize()?;
+
+		let mut channels = ChannelRegistry::new();
+		for info in self.vec(|reader| {
+			let name = reader.name()?;
+			let arity = reader.option()?;
+			let policy = match reader.u8()? {
+				0 => BalancingPolicy::WithBoundaries,
+				1 => BalancingPolicy::Closed,
+				_ => bail!(BundleError::Malformed("invalid balancing policy")),
+			};
+			Ok(ChannelInfo {
+				name,
+				arity,
+				policy,
+			})
+		})? {
+			channels.add(info);
+		}
+
+		Ok(ConstraintSystem {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids,
+			flushes,
+			matmuls,
+			max_channel_id,
+			channels,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField32b, Field};
+	use binius_hash::compress::Groestl256ByteCompression;
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::{
+		constraint_system::{
+			tests::{fixture, pcs_params, prove_fixture},
+			verify,
+		},
+		fiat_shamir::HasherChallenger,
+		tower::CanonicalTowerFamily,
+		transparent::disjoint_product::DisjointProduct,
+	};
+
+	type F = BinaryField128b;
+
+	fn constraint_system() -> ConstraintSystem<F> {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let committed = oracles.add_named("committed").committed(4, 5);
+		let step_down = oracles
+			.add_named("step_down")
+			.transparent(StepDown::new(4, 11).unwrap())
+			.unwrap();
+		let shifted = oracles
+			.add()
+			.shifted(committed, 1, 4, ShiftVariant::LogicalLeft)
+			.unwrap();
+		let combination = oracles
+			.add_named("combination")
+			.linear_combination_with_offset(
+				4,
+				F::ONE,
+				[
+					(committed, F::new(3)),
+					(shifted, BinaryField32b::new(7).into()),
+				],
+			)
+			.unwrap();
+		oracles.add().packed(committed, 2).unwrap();
+
+		let mut channels = ChannelRegistry::new();
+		let channel_id = channels.add(ChannelInfo {
+			name: Some("values".to_string()),
+			arity: Some(1),
+			policy: BalancingPolicy::Closed,
+		});
+
+		ConstraintSystem {
+			oracles,
+			table_constraints: vec![ConstraintSet {
+				n_vars: 4,
+				oracle_ids: vec![committed, step_down, combination],
+				constraints: vec![Constraint {
+					name: Arc::from("product"),
+					composition: ArithExpr::Var(0) * ArithExpr::Var(1)
+						+ ArithExpr::Var(2).pow(2)
+						+ ArithExpr::Const(F::new(5)),
+					predicate: ConstraintPredicate::Zero,
+				}],
+			}],
+			non_zero_oracle_ids: vec![combination],
+			flushes: vec![Flush {
+				oracles: vec![committed],
+				channel_id,
+				direction: FlushDirection::Push,
+				count: 16,
+				multiplicity: 1,
+			}],
+			matmuls: vec![],
+			max_channel_id: channel_id,
+			channels,
+		}
+	}
+
+	#[test]
+	fn test_bundle_roundtrip() {
+		let bundle = Bundle::new(constraint_system(), PCSParams::new(2, 100).with_fold_arity(3))
+			.with_public_inputs(
+				PublicInputSchema::new()
+					.with_slot("root", Some(32))
+					.with_slot("memo", None),
+			)
+			.with_verifier_config(VerifierConfig {
+				hash: "groestl256".to_string(),
+				encoding: ProofEncoding::compact(),
+				claim_version: Some(2),
+			});
+
+		let mut bytes = Vec::new();
+		bundle.write(&mut bytes).unwrap();
+		let loaded = Bundle::<F>::read(bytes.as_slice()).unwrap();
+
+		assert!(loaded
+			.constraint_system
+			.structurally_equal(&bundle.constraint_system));
+		assert_eq!(loaded.pcs_params, bundle.pcs_params);
+		assert_eq!(loaded.public_inputs, bundle.public_inputs);
+		assert_eq!(loaded.verifier, bundle.verifier);
+		assert_eq!(loaded.oracle_id("combination"), Some(3));
+		assert_eq!(loaded.oracle_id("missing"), None);
+		assert_eq!(loaded.constraint_system.oracles.tower_level(1), 0);
+		assert_eq!(loaded.constraint_system.channels.label(0), "values (id=0)");
+		assert_eq!(loaded.to_bytes().unwrap(), bytes);
+	}
+
+	/// Stores a transparent oracle and loads it back, returning the stored bytes and the loaded
+	/// polynomial.
+	fn reload_transparent(
+		poly: impl MultivariatePoly<F> + 'static,
+	) -> (Vec<u8>, Arc<dyn MultivariatePoly<F>>) {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		oracles.add().transparent(poly).unwrap();
+		let mut out = Vec::new();
+		put_oracle(&mut out, &oracles.oracle(0)).unwrap();
+
+		let mut loaded = MultilinearOracleSet::<F>::new();
+		let mut reader = Reader(&out);
+		reader.oracle(&mut loaded).unwrap();
+		assert!(reader.0.is_empty());
+		let MultilinearPolyOracle::Transparent { inner, .. } = loaded.oracle(0) else {
+			panic!("expected a transparent oracle");
+		};
+		let mut reloaded = Vec::new();
+		put_oracle(&mut reloaded, &loaded.oracle(0)).unwrap();
+		assert_eq!(reloaded, out);
+		(out, inner.poly().clone())
+	}
+
+	fn assert_same_evaluations(lhs: &dyn MultivariatePoly<F>, rhs: &dyn MultivariatePoly<F>) {
+		assert_eq!(lhs.n_vars(), rhs.n_vars());
+		assert_eq!(lhs.degree(), rhs.degree());
+		assert_eq!(lhs.binary_tower_level(), rhs.binary_tower_level());
+		let query = (1..=lhs.n_vars() as u128)
+			.map(|i| F::new(i * 0x1234567))
+			.collect::<Vec<_>>();
+		assert_eq!(lhs.evaluate(&query).unwrap(), rhs.evaluate(&query).unwrap());
+	}
+
+	#[test]
+	fn test_tabulated_poly_matches_transparent() {
+		let product = || DisjointProduct(StepDown::new(2, 3).unwrap(), StepUp::new(3, 5).unwrap());
+		let (out, loaded) = reload_transparent(product());
+		// An unnamed oracle stored by its evaluations
+		assert_eq!(out[..2], [0, 0]);
+		assert_same_evaluations(&*loaded, &product());
+	}
+
+	#[test]
+	fn test_parameterized_transparents() {
+		let r = (1..=6).map(|i| F::new(i * 0x89abcdef)).collect::<Vec<_>>();
+		let polys: Vec<Arc<dyn MultivariatePoly<F>>> = vec![
+			Arc::new(EqIndPartialEval::new(6, r.clone()).unwrap()),
+			Arc::new(StepDown::new(6, 37).unwrap()),
+			Arc::new(StepUp::new(6, 37).unwrap()),
+			Arc::new(SelectRow::new(6, 37).unwrap()),
+			Arc::new(Powers::new(6, F::new(0x1234))),
+			Arc::new(ShiftIndPartialEval::new(6, 5, ShiftVariant::LogicalRight, r).unwrap()),
+			Arc::new(EveryKth::new(6, 2, 1).unwrap()),
+			Arc::new(Interval::new(6, 7, 50).unwrap()),
+			Arc::new(Constant::<F>::new(6, BinaryField32b::new(0x5678))),
+			Arc::new(TowerBasis::<F>::new(3, 2).unwrap()),
+		];
+		for (kind, poly) in polys.iter().enumerate() {
+			let (out, loaded) = reload_transparent(SharedPoly(poly.clone()));
+			assert_eq!(out[..3], [0, 10, kind as u8]);
+			assert_same_evaluations(&*loaded, &**poly);
+		}
+	}
+
+	/// Forwards to a shared polynomial, so the known transparents can be passed by the test as
+	/// trait objects.
+	#[derive(Debug)]
+	struct SharedPoly(Arc<dyn MultivariatePoly<F>>);
+
+	impl MultivariatePoly<F> for SharedPoly {
+		fn n_vars(&self) -> usize {
+			self.0.n_vars()
+		}
+
+		fn degree(&self) -> usize {
+			self.0.degree()
+		}
+
+		fn evaluate(&self, query: &[F]) -> Result<F, polynomial::Error> {
+			self.0.evaluate(query)
+		}
+
+		fn binary_tower_level(&self) -> usize {
+			self.0.binary_tower_level()
+		}
+
+		fn as_any(&self) -> Option<&dyn std::any::Any> {
+			self.0.as_any()
+		}
+	}
+
+	#[test]
+	fn test_large_transparents() {
+		let n_vars = MAX_TABULATED_N_VARS + 4;
+		let (_, loaded) = reload_transparent(StepDown::new(n_vars, 12345).unwrap());
+		assert_same_evaluations(&*loaded, &StepDown::new(n_vars, 12345).unwrap());
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		oracles
+			.add()
+			.transparent(DisjointProduct(
+				StepDown::new(n_vars - 1, 3).unwrap(),
+				StepUp::new(1, 1).unwrap(),
+			))
+			.unwrap();
+		assert_matches!(
+			put_oracle(&mut Vec::new(), &oracles.oracle(0)),
+			Err(BundleError::TransparentTooLarge { id: 0, n_vars: n }) if n == n_vars
+		);
+	}
+
+	#[test]
+	fn test_reject_malformed_bundles() {
+		let bytes = Bundle::new(constraint_system(), PCSParams::new(1, 100))
+			.to_bytes()
+			.unwrap();
+
+		assert_matches!(
+			Bundle::<F>::from_bytes(&bytes[..bytes.len() - 1]),
+			Err(BundleError::Malformed(_))
+		);
+		assert_matches!(
+			Bundle::<F>::from_bytes(&[bytes.as_slice(), &[0]].concat()),
+			Err(BundleError::Malformed("trailing bytes"))
+		);
+		assert_matches!(
+			Bundle::<BinaryField32b>::from_bytes(&bytes),
+			Err(BundleError::TowerLevelMismatch {
+				expected: 5,
+				got: 7
+			})
+		);
+
+		let mut wrong_version = bytes.clone();
+		wrong_version[BUNDLE_MAGIC.len()] = 3;
+		assert_matches!(
+			Bundle::<F>::from_bytes(&wrong_version),
+			Err(BundleError::UnsupportedVersion(3))
+		);
+		wrong_version[BUNDLE_MAGIC.len()] = 0;
+		assert_matches!(
+			Bundle::<F>::from_bytes(&wrong_version),
+			Err(BundleError::UnsupportedVersion(0))
+		);
+	}
+
+	#[test]
+	fn test_load_version_1() {
+		// Version 1 stored every transparent by its evaluations, which version 2 still reads
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		oracles
+			.add_named("product")
+			.transparent(DisjointProduct(StepDown::new(2, 3).unwrap(), StepUp::new(2, 1).unwrap()))
+			.unwrap();
+		let constraint_system = ConstraintSystem {
+			oracles,
+			table_constraints: vec![],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			matmuls: vec![],
+			max_channel_id: 0,
+			channels: ChannelRegistry::new(),
+		};
+		let mut bytes = Bundle::new(constraint_system, PCSParams::new(1, 100))
+			.to_bytes()
+			.unwrap();
+		bytes[BUNDLE_MAGIC.len()] = 1;
+		let loaded = Bundle::<F>::from_bytes(&bytes).unwrap();
+		assert_eq!(loaded.oracle_id("product"), Some(0));
+	}
+
+	#[test]
+	fn test_public_input_schema() {
+		let schema = PublicInputSchema::new()
+			.with_slot("root", Some(2))
+			.with_slot("memo", None);
+		schema
+			.check(
+				&PublicInputs::new()
+					.with_slot([1, 2])
+					.with_slot(vec![0; 100]),
+			)
+			.unwrap();
+		assert_matches!(
+			schema.check(&PublicInputs::new().with_slot([1, 2])),
+			Err(BundleError::PublicInputCount {
+				expected: 2,
+				got: 1
+			})
+		);
+		assert_matches!(
+			schema.check(&PublicInputs::new().with_slot([1]).with_slot([])),
+			Err(BundleError::PublicInputLength { slot: 0, .. })
+		);
+	}
+
+	#[test]
+	fn test_prove_verify_from_bundle() {
+		let (constraint_system, witness, boundaries) = fixture();
+		let path = std::env::temp_dir().join(format!("fib-{}.binius", std::process::id()));
+		Bundle::new(constraint_system, pcs_params())
+			.save(&path)
+			.unwrap();
+		let bundle = Bundle::<F>::load(&path).unwrap();
+		std::fs::remove_file(&path).unwrap();
+
+		let proof = prove_fixture(&bundle.constraint_system, witness, &Default::default()).unwrap();
+		bundle.verifier.check(&proof).unwrap();
+		verify::<
+			OptimalUnderlier,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&bundle.constraint_system, &bundle.pcs_params, boundaries, proof)
+		.unwrap();
+	}
+}
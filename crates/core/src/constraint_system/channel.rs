@@ -13,6 +13,12 @@
 //! multiset check. If you want any kind of ordering, you have to
 //! use polynomial constraints to additionally constraint this.
 //!
+//! When proving, every flush is compressed into a single multilinear by mixing its columns with
+//! verifier challenges, and the product of its values over the flushed rows is proven with the
+//! GKR grand product argument in [`crate::protocols::gkr_gpa`]. The verifier only checks that the
+//! products of the pushes and pulls on each channel agree, and then follows the grand product
+//! argument through a logarithmic number of layers, so its cost does not grow with table size.
+//!
 //! The example below shows a channel with width=2, with multiple inputs
 //! and outputs.
 //! ```txt
@@ -75,23 +81,168 @@ pub struct Boundary<F: TowerField> {
 	pub multiplicity: u64,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl<F: TowerField> Boundary<F> {
+	pub fn isomorphic<FI: TowerField + From<F>>(&self) -> Boundary<FI> {
+		Boundary {
+			values: self.values.iter().copied().map(FI::from).collect(),
+			channel_id: self.channel_id,
+			direction: self.direction,
+			multiplicity: self.multiplicity,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FlushDirection {
 	Push,
 	Pull,
 }
 
+/// How the values flushed into a channel are expected to balance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BalancingPolicy {
+	/// The flushes balance together with the boundary values of the statement.
+	#[default]
+	WithBoundaries,
+	/// The flushes balance among themselves, and boundary values on the channel are rejected.
+	Closed,
+}
+
+/// Metadata describing a channel.
+///
+/// All fields are optional refinements: a channel with default metadata accepts flushes of any
+/// consistent width and boundary values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChannelInfo {
+	/// A human-readable name used in error messages.
+	pub name: Option<String>,
+	/// The number of values in every tuple flushed into the channel.
+	pub arity: Option<usize>,
+	pub policy: BalancingPolicy,
+}
+
+/// The channels of a constraint system along with their metadata, indexed by [`ChannelId`].
+///
+/// Channels that are used by flushes but have no entry in the registry are treated as if they
+/// had default metadata.
+#[derive(Debug, Clone, Default)]
+pub struct ChannelRegistry {
+	channels: Vec<ChannelInfo>,
+}
+
+impl ChannelRegistry {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Registers a new channel and returns its ID.
+	pub fn add(&mut self, info: ChannelInfo) -> ChannelId {
+		self.channels.push(info);
+		self.channels.len() - 1
+	}
+
+	pub fn get(&self, id: ChannelId) -> Option<&ChannelInfo> {
+		self.channels.get(id)
+	}
+
+	pub fn len(&self) -> usize {
+		self.channels.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.channels.is_empty()
+	}
+
+	/// Removes all channels with an id of `len` or more.
+	pub fn truncate(&mut self, len: usize) {
+		self.channels.truncate(len);
+	}
+
+	/// A description of the channel for error messages, including its name if it has one.
+	pub fn label(&self, id: ChannelId) -> String {
+		match self.get(id).and_then(|info| info.name.as_ref()) {
+			Some(name) => format!("{name} (id={id})"),
+			None => format!("id={id}"),
+		}
+	}
+
+	/// Checks that all flushes into a channel have the same width, matching its arity if declared.
+	pub fn validate_flushes(&self, flushes: &[Flush]) -> Result<(), Error> {
+		let mut widths = HashMap::new();
+		for flush in flushes {
+			self.check_width(&mut widths, flush.channel_id, flush.oracles.len())?;
+		}
+		Ok(())
+	}
+
+	/// Checks that the boundary values agree with the arity and balancing policy of their channels.
+	pub fn validate_boundaries<F: TowerField>(
+		&self,
+		boundaries: &[Boundary<F>],
+	) -> Result<(), Error> {
+		let mut widths = HashMap::new();
+		for boundary in boundaries {
+			let policy = self
+				.get(boundary.channel_id)
+				.map(|info| info.policy)
+				.unwrap_or_default();
+			if policy == BalancingPolicy::Closed {
+				return Err(Error::ChannelUnexpectedBoundary {
+					channel_id: boundary.channel_id,
+					channel: self.label(boundary.channel_id),
+				});
+			}
+			self.check_width(&mut widths, boundary.channel_id, boundary.values.len())?;
+		}
+		Ok(())
+	}
+
+	fn check_width(
+		&self,
+		widths: &mut HashMap<ChannelId, usize>,
+		channel_id: ChannelId,
+		width: usize,
+	) -> Result<(), Error> {
+		if let Some(arity) = self.get(channel_id).and_then(|info| info.arity) {
+			if width != arity {
+				return Err(Error::ChannelArityMismatch {
+					channel_id,
+					channel: self.label(channel_id),
+					expected: arity,
+					got: width,
+				});
+			}
+		}
+		let expected = *widths.entry(channel_id).or_insert(width);
+		if width != expected {
+			return Err(Error::ChannelFlushWidthMismatch {
+				channel_id,
+				channel: self.label(channel_id),
+				expected,
+				got: width,
+			});
+		}
+		Ok(())
+	}
+}
+
 pub fn validate_witness<U, F>(
 	witness: &MultilinearExtensionIndex<U, F>,
 	flushes: &[Flush],
 	boundaries: &[Boundary<F>],
 	max_channel_id: ChannelId,
+	registry: &ChannelRegistry,
 ) -> Result<(), Error>
 where
 	U: UnderlierType + PackScalar<F>,
 	F: TowerField,
 {
-	let mut channels = vec![Channel::<F>::new(); max_channel_id + 1];
+	registry.validate_flushes(flushes)?;
+	registry.validate_boundaries(boundaries)?;
+
+	let mut channels = (0..=max_channel_id)
+		.map(|id| Channel::<F>::new(id, registry.label(id)))
+		.collect::<Vec<_>>();
 
 	for boundary in boundaries.iter().cloned() {
 		let Boundary {
@@ -135,9 +286,11 @@ where
 		// Ensure that all the polys in a single flush have the same n_vars
 		if let Some(first_poly) = polys.first() {
 			let n_vars = first_poly.n_vars();
-			for poly in &polys {
+			for (id, poly) in oracles.iter().zip(&polys) {
 				if poly.n_vars() != n_vars {
 					return Err(Error::ChannelFlushNvarsMismatch {
+						channel_id: *channel_id,
+						id: *id,
 						expected: n_vars,
 						got: poly.n_vars(),
 					});
@@ -148,6 +301,7 @@ where
 			if *count > 1 << n_vars {
 				let id = oracles.first().expect("polys is not empty");
 				return Err(Error::FlushCountExceedsOracleSize {
+					channel_id: *channel_id,
 					id: *id,
 					count: *count,
 				});
@@ -163,24 +317,35 @@ where
 		}
 	}
 
-	for (id, channel) in channels.iter().enumerate() {
+	for channel in &channels {
 		if !channel.is_balanced() {
-			return Err(VerificationError::ChannelUnbalanced { id }.into());
+			return Err(VerificationError::ChannelUnbalanced {
+				channel_id: channel.id,
+				channel: channel.label.clone(),
+			}
+			.into());
 		}
 	}
 
 	Ok(())
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 struct Channel<F: TowerField> {
+	id: ChannelId,
+	label: String,
 	width: Option<usize>,
 	multiplicities: HashMap<Vec<F>, i64>,
 }
 
 impl<F: TowerField> Channel<F> {
-	fn new() -> Self {
-		Self::default()
+	fn new(id: ChannelId, label: String) -> Self {
+		Self {
+			id,
+			label,
+			width: None,
+			multiplicities: HashMap::new(),
+		}
 	}
 
 	fn _print_unbalanced_values(&self) {
@@ -201,6 +366,8 @@ impl<F: TowerField> Channel<F> {
 			self.width = Some(values.len());
 		} else if self.width.unwrap() != values.len() {
 			return Err(Error::ChannelFlushWidthMismatch {
+				channel_id: self.id,
+				channel: self.label.clone(),
 				expected: self.width.unwrap(),
 				got: values.len(),
 			});
@@ -217,3 +384,44 @@ impl<F: TowerField> Channel<F> {
 		self.multiplicities.iter().all(|(_, m)| *m == 0)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn flush(channel_id: ChannelId, oracles: Vec<OracleId>) -> Flush {
+		Flush {
+			oracles,
+			channel_id,
+			direction: FlushDirection::Push,
+			count: 1,
+			multiplicity: 1,
+		}
+	}
+
+	#[test]
+	fn test_errors_carry_channel_context() {
+		let mut registry = ChannelRegistry::new();
+		registry.add(ChannelInfo::default());
+		let channel_id = registry.add(ChannelInfo {
+			name: Some("lookup".into()),
+			..Default::default()
+		});
+
+		let err = registry
+			.validate_flushes(&[flush(channel_id, vec![0, 1]), flush(channel_id, vec![2])])
+			.unwrap_err();
+		assert!(matches!(
+			&err,
+			Error::ChannelFlushWidthMismatch {
+				channel_id: 1,
+				expected: 2,
+				got: 1,
+				..
+			}
+		));
+		assert_eq!(err.channel_id(), Some(channel_id));
+		assert_eq!(err.oracle_id(), None);
+		assert!(err.to_string().contains("lookup (id=1)"));
+	}
+}
@@ -0,0 +1,384 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::PackedFieldIndexable;
+use binius_hal::ComputationBackend;
+use binius_hash::PseudoCompressionFunction;
+use binius_math::{EvaluationDomainFactory, MultilinearExtension, MultilinearPoly};
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+
+use super::common::{FDomain, FEncode, FExt};
+use crate::{
+	fiat_shamir::{CanSample, CanSampleBits},
+	merkle_tree::{
+		BinaryMerkleTreeProver, MaybeStreamedMerkleTree, MaybeStreamedMerkleTreeProver,
+		MerkleTreeProver, MerkleTreeScheme, StreamedBinaryMerkleTreeProver, StreamingOptions,
+	},
+	piop::{self, CommitMeta, PCSParams, PIOPSumcheckClaim},
+	polynomial::MultivariatePoly,
+	protocols::fri::{self, CommitOutput, FRIParams},
+	tower::{PackedTop, TowerFamily},
+	transcript::{CanRead, CanWrite, Proof},
+};
+
+/// A polynomial commitment scheme for the committed multilinears of a constraint system.
+///
+/// The constraint system protocol reduces all committed evaluation claims to a batch of
+/// [`PIOPSumcheckClaim`]s, which are sums over the hypercube of products of a committed and a
+/// transparent multilinear. A commitment scheme commits to the batch of committed multilinears
+/// described by a [`CommitMeta`] and later proves these sumcheck claims.
+///
+/// [`FRIPCS`] is the FRI-Binius scheme that [`super::prove`] and [`super::verify`] use.
+/// [`TrivialPCS`] sends the committed multilinears in the clear and is only useful for testing.
+/// Other schemes can be plugged in with [`super::prove_with_pcs`] and [`super::verify_with_pcs`].
+pub trait CommitmentScheme<Tower: TowerFamily> {
+	/// Parameters derived from the layout of the committed batch, shared by prover and verifier.
+	type Setup;
+	/// The commitment as read by the verifier.
+	type Commitment;
+	/// The prover state kept from the commitment until the opening.
+	type Committed<P>;
+
+	/// Derives the setup for the committed batch described by `commit_meta`.
+	fn setup(&self, commit_meta: &CommitMeta) -> Result<Self::Setup, piop::Error>;
+
+	/// Commits to a batch of multilinears and writes the commitment to the transcript.
+	///
+	/// The multilinears are in the order given by `commit_meta`, ascending by number of packed
+	/// variables.
+	fn commit<P, M, Transcript>(
+		&self,
+		setup: &Self::Setup,
+		multilins: &[M],
+		transcript: &mut Transcript,
+	) -> Result<Self::Committed<P>, piop::Error>
+	where
+		P: PackedTop<Tower> + PackedFieldIndexable,
+		M: MultilinearPoly<P>,
+		Transcript: CanWrite;
+
+	/// Reads the commitment written by [`Self::commit`] from the transcript.
+	fn read_commitment<Transcript>(
+		&self,
+		setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		transcript: &mut Transcript,
+	) -> Result<Self::Commitment, piop::Error>
+	where
+		Transcript: CanRead;
+
+	/// Proves the sumcheck claims over the committed and transparent multilinears.
+	///
+	/// The committed state is borrowed, so that one commitment can back several proofs, see
+	/// [`super::SharedCommitment`].
+	#[allow(clippy::too_many_arguments)]
+	fn prove<P, M, DomainFactory, Transcript, Advice, Backend>(
+		&self,
+		setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		committed: &Self::Committed<P>,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<FExt<Tower>>],
+		domain_factory: DomainFactory,
+		proof: &mut Proof<Transcript, Advice>,
+		backend: &Backend,
+	) -> Result<(), piop::Error>
+	where
+		P: PackedTop<Tower> + PackedFieldIndexable,
+		M: MultilinearPoly<P> + Send + Sync,
+		DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+		Transcript: CanSample<FExt<Tower>> + CanWrite + CanSampleBits<usize>,
+		Advice: CanWrite,
+		Backend: ComputationBackend;
+
+	/// Verifies the sumcheck claims over the committed and transparent multilinears.
+	fn verify<'a, Transcript, Advice>(
+		&self,
+		setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[Box<dyn MultivariatePoly<FExt<Tower>> + 'a>],
+		claims: &[PIOPSumcheckClaim<FExt<Tower>>],
+		proof: &mut Proof<Transcript, Advice>,
+	) -> Result<(), piop::Error>
+	where
+		Transcript: CanSample<FExt<Tower>> + CanRead + CanSampleBits<usize>,
+		Advice: CanRead;
+}
+
+/// The FRI-Binius polynomial commitment scheme from [DP24], with a binary Merkle tree.
+///
+/// By default the Merkle tree over the codeword is built in memory. Memory-constrained provers can
+/// stream it instead with [`Self::with_streamed_merkle_tree`], which produces identical proofs,
+/// provided that the tree is binary and its Merkle cap is within the retained layers.
+///
+/// The arity and the cap of the Merkle trees are set by [`PCSParams`], and the commitment is the
+/// Merkle cap of the codeword.
+///
+/// [DP24]: <https://eprint.iacr.org/2024/504>
+#[derive(Debug, Clone)]
+pub struct FRIPCS<Hash, Compress> {
+	params: PCSParams,
+	streaming: Option<StreamingOptions>,
+	_marker: PhantomData<fn() -> (Hash, Compress)>,
+}
+
+impl<Hash, Compress> FRIPCS<Hash, Compress> {
+	pub fn new(params: PCSParams) -> Self {
+		Self {
+			params,
+			streaming: None,
+			_marker: PhantomData,
+		}
+	}
+
+	/// Builds the Merkle tree over the codeword with a [`StreamedBinaryMerkleTreeProver`].
+	pub fn with_streamed_merkle_tree(mut self, options: StreamingOptions) -> Self {
+		self.streaming = Some(options);
+		self
+	}
+
+	pub fn params(&self) -> &PCSParams {
+		&self.params
+	}
+
+	pub fn streaming(&self) -> Option<&StreamingOptions> {
+		self.streaming.as_ref()
+	}
+}
+
+/// The setup of [`FRIPCS`] for a committed batch.
+#[derive(Debug)]
+pub struct FRISetup<Tower: TowerFamily, Hash, Compress> {
+	merkle_prover: MaybeStreamedMerkleTreeProver<FExt<Tower>, Hash, Compress>,
+	fri_params: FRIParams<FExt<Tower>, FEncode<Tower>>,
+}
+
+impl<Tower: TowerFamily, Hash, Compress> FRISetup<Tower, Hash, Compress> {
+	pub fn fri_params(&self) -> &FRIParams<FExt<Tower>, FEncode<Tower>> {
+		&self.fri_params
+	}
+}
+
+impl<Tower, Hash, Compress> CommitmentScheme<Tower> for FRIPCS<Hash, Compress>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	type Setup = FRISetup<Tower, Hash, Compress>;
+	type Commitment = Vec<Output<Hash>>;
+	type Committed<P> = CommitOutput<P, Vec<Output<Hash>>, MaybeStreamedMerkleTree<Output<Hash>>>;
+
+	fn setup(&self, commit_meta: &CommitMeta) -> Result<Self::Setup, piop::Error> {
+		let merkle_scheme = self.params.merkle_scheme(Compress::default())?;
+		let merkle_prover = match &self.streaming {
+			Some(options) => MaybeStreamedMerkleTreeProver::Streamed(
+				StreamedBinaryMerkleTreeProver::from_scheme(merkle_scheme, options.clone()),
+			),
+			None => MaybeStreamedMerkleTreeProver::InMemory(BinaryMerkleTreeProver::from_scheme(
+				merkle_scheme,
+			)),
+		};
+		let fri_params = piop::make_commit_params::<_, FEncode<Tower>, _>(
+			commit_meta,
+			merkle_prover.scheme(),
+			&self.params,
+		)?;
+		Ok(FRISetup {
+			merkle_prover,
+			fri_params,
+		})
+	}
+
+	fn commit<P, M, Transcript>(
+		&self,
+		setup: &Self::Setup,
+		multilins: &[M],
+		transcript: &mut Transcript,
+	) -> Result<Self::Committed<P>, piop::Error>
+	where
+		P: PackedTop<Tower> + PackedFieldIndexable,
+		M: MultilinearPoly<P>,
+		Transcript: CanWrite,
+	{
+		let output = piop::commit(&setup.fri_params, &setup.merkle_prover, multilins)?;
+		transcript.write_slice(&output.commitment);
+		Ok(output)
+	}
+
+	fn read_commitment<Transcript>(
+		&self,
+		setup: &Self::Setup,
+		_commit_meta: &CommitMeta,
+		transcript: &mut Transcript,
+	) -> Result<Self::Commitment, piop::Error>
+	where
+		Transcript: CanRead,
+	{
+		let tree_depth = fri::vcs_tree_depths_iter(&setup.fri_params)
+			.next()
+			.expect("the codeword is committed with a Merkle tree");
+		let cap_depth = setup.merkle_prover.scheme().cap_depth(tree_depth);
+		let commitment = transcript
+			.read_vec(1 << cap_depth)
+			.map_err(piop::VerificationError::Transcript)?;
+		Ok(commitment)
+	}
+
+	fn prove<P, M, DomainFactory, Transcript, Advice, Backend>(
+		&self,
+		setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		committed: &Self::Committed<P>,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<FExt<Tower>>],
+		domain_factory: DomainFactory,
+		proof: &mut Proof<Transcript, Advice>,
+		backend: &Backend,
+	) -> Result<(), piop::Error>
+	where
+		P: PackedTop<Tower> + PackedFieldIndexable,
+		M: MultilinearPoly<P> + Send + Sync,
+		DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+		Transcript: CanSample<FExt<Tower>> + CanWrite + CanSampleBits<usize>,
+		Advice: CanWrite,
+		Backend: ComputationBackend,
+	{
+		let CommitOutput {
+			committed,
+			codeword,
+			..
+		} = committed;
+		piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _, _>(
+			&setup.fri_params,
+			&setup.merkle_prover,
+			domain_factory,
+			commit_meta,
+			committed,
+			codeword,
+			committed_multilins,
+			transparent_multilins,
+			claims,
+			proof,
+			backend,
+		)
+	}
+
+	fn verify<'a, Transcript, Advice>(
+		&self,
+		setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[Box<dyn MultivariatePoly<FExt<Tower>> + 'a>],
+		claims: &[PIOPSumcheckClaim<FExt<Tower>>],
+		proof: &mut Proof<Transcript, Advice>,
+	) -> Result<(), piop::Error>
+	where
+		Transcript: CanSample<FExt<Tower>> + CanRead + CanSampleBits<usize>,
+		Advice: CanRead,
+	{
+		piop::verify(
+			commit_meta,
+			setup.merkle_prover.scheme(),
+			&setup.fri_params,
+			commitment,
+			transparents,
+			claims,
+			proof,
+		)
+	}
+}
+
+/// A commitment scheme that sends the committed multilinears in the clear.
+///
+/// The proof size is linear in the size of the committed data and the verifier reads all of it,
+/// so this is only useful for testing the rest of the protocol, see [`piop::trivial`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TrivialPCS;
+
+impl<Tower: TowerFamily> CommitmentScheme<Tower> for TrivialPCS {
+	type Setup = ();
+	type Commitment = Vec<MultilinearExtension<FExt<Tower>>>;
+	type Committed<P> = ();
+
+	fn setup(&self, _commit_meta: &CommitMeta) -> Result<Self::Setup, piop::Error> {
+		Ok(())
+	}
+
+	fn commit<P, M, Transcript>(
+		&self,
+		_setup: &Self::Setup,
+		multilins: &[M],
+		transcript: &mut Transcript,
+	) -> Result<Self::Committed<P>, piop::Error>
+	where
+		P: PackedTop<Tower> + PackedFieldIndexable,
+		M: MultilinearPoly<P>,
+		Transcript: CanWrite,
+	{
+		piop::trivial::commit(multilins, transcript)
+	}
+
+	fn read_commitment<Transcript>(
+		&self,
+		_setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		transcript: &mut Transcript,
+	) -> Result<Self::Commitment, piop::Error>
+	where
+		Transcript: CanRead,
+	{
+		piop::trivial::read_commitment(commit_meta, transcript)
+	}
+
+	fn prove<P, M, DomainFactory, Transcript, Advice, Backend>(
+		&self,
+		_setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		_committed: &Self::Committed<P>,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<FExt<Tower>>],
+		domain_factory: DomainFactory,
+		proof: &mut Proof<Transcript, Advice>,
+		backend: &Backend,
+	) -> Result<(), piop::Error>
+	where
+		P: PackedTop<Tower> + PackedFieldIndexable,
+		M: MultilinearPoly<P> + Send + Sync,
+		DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+		Transcript: CanSample<FExt<Tower>> + CanWrite + CanSampleBits<usize>,
+		Advice: CanWrite,
+		Backend: ComputationBackend,
+	{
+		piop::trivial::prove::<_, FDomain<Tower>, _, _, _, _, _>(
+			domain_factory,
+			commit_meta,
+			committed_multilins,
+			transparent_multilins,
+			claims,
+			&mut proof.transcript,
+			backend,
+		)
+	}
+
+	fn verify<'a, Transcript, Advice>(
+		&self,
+		_setup: &Self::Setup,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[Box<dyn MultivariatePoly<FExt<Tower>> + 'a>],
+		claims: &[PIOPSumcheckClaim<FExt<Tower>>],
+		proof: &mut Proof<Transcript, Advice>,
+	) -> Result<(), piop::Error>
+	where
+		Transcript: CanSample<FExt<Tower>> + CanRead + CanSampleBits<usize>,
+		Advice: CanRead,
+	{
+		piop::trivial::verify(commit_meta, commitment, transparents, claims, &mut proof.transcript)
+	}
+}
@@ -0,0 +1,248 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Continuations: proofs of long computations split into segments.
+//!
+//! The witness of a computation is held in memory while it is proven, so a computation of
+//! unbounded length cannot be proven at once. A continuation splits it into segments that are all
+//! proven with the same constraint system, so the prover only needs the witness of one segment at
+//! a time.
+//!
+//! The state carried from one segment to the next is exchanged over a state channel of the
+//! constraint system. Every segment pulls its entry state from the channel and pushes its exit
+//! state into it, and the verifier balances the channel with the boundaries returned by
+//! [`segment_boundaries`]. The index of the segment and both states are bound to the transcript of
+//! the segment proof as public inputs, see [`segment_public_inputs`].
+//!
+//! [`SegmentedProof::link`] links the segment proofs into a proof of the whole computation,
+//! checking that every segment starts in the exit state of the previous one, and
+//! [`verify_continuation`] verifies the segments against a single prepared constraint system.
+//!
+//! The segment proofs are not aggregated: the linked proof consists of all of them, so its size and
+//! verification time grow linearly with the number of segments. Continuations bound the memory of
+//! the prover, not the cost of the verifier.
+//!
+//! The final aggregation proof of the continuation framework is not implemented here. Aggregating
+//! the segments into one proof of constant size means proving the segment verifier inside a
+//! constraint system, which needs a recursive verifier circuit that does not exist yet. It is
+//! tracked as a request of its own, and [`SegmentedProof`] is the input it will aggregate.
+
+use binius_field::{
+	as_packed_field::PackedType, PackedExtension, PackedFieldIndexable, RepackedExtension,
+	TowerField,
+};
+use binius_hal::ComputationBackend;
+use binius_hash::PseudoCompressionFunction;
+use binius_math::EvaluationDomainFactory;
+use binius_utils::bail;
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+
+use super::{
+	channel::{Boundary, ChannelId, FlushDirection},
+	common::{FDomain, FExt},
+	error::{Error, VerificationError},
+	prove::prove_with_options,
+	verify::verify_prepared_with_public_inputs,
+	ConstraintSystem, PCSParams, PreparedConstraintSystem, Proof, ProveOptions, PublicInputs,
+	FRIPCS,
+};
+use crate::{
+	fiat_shamir::Challenger,
+	tower::{PackedTop, TowerFamily, TowerUnderlier},
+	witness::MultilinearExtensionIndex,
+};
+
+/// The proof of one segment of a continuation.
+#[derive(Debug, Clone)]
+pub struct SegmentProof<F: TowerField> {
+	/// The state the segment pulls from the state channel.
+	pub entry: Vec<F>,
+	/// The state the segment pushes into the state channel.
+	pub exit: Vec<F>,
+	/// A proof generated with the public inputs of [`segment_public_inputs`].
+	pub proof: Proof,
+}
+
+/// The proofs of all segments of a continuation, in execution order.
+///
+/// This is not an aggregated proof: every segment proof is kept and verified on its own.
+#[derive(Debug, Clone)]
+pub struct SegmentedProof<F: TowerField> {
+	segments: Vec<SegmentProof<F>>,
+}
+
+impl<F: TowerField> SegmentedProof<F> {
+	/// Links the proofs of consecutive segments into a proof of the whole computation.
+	pub fn link(segments: Vec<SegmentProof<F>>) -> Result<Self, Error> {
+		if segments.is_empty() {
+			bail!(VerificationError::EmptyContinuation);
+		}
+		check_links(&segments)?;
+		Ok(Self { segments })
+	}
+
+	pub fn segments(&self) -> &[SegmentProof<F>] {
+		&self.segments
+	}
+
+	/// The state the computation starts in.
+	pub fn entry(&self) -> &[F] {
+		&self.segments[0].entry
+	}
+
+	/// The state the computation ends in.
+	pub fn exit(&self) -> &[F] {
+		&self.segments[self.segments.len() - 1].exit
+	}
+
+	pub fn get_proof_size(&self) -> usize {
+		self.segments
+			.iter()
+			.map(|segment| segment.proof.get_proof_size())
+			.sum()
+	}
+}
+
+fn check_links<F: TowerField>(segments: &[SegmentProof<F>]) -> Result<(), Error> {
+	for (index, pair) in segments.windows(2).enumerate() {
+		if pair[0].exit != pair[1].entry {
+			bail!(VerificationError::SegmentNotLinked { segment: index + 1 });
+		}
+	}
+	Ok(())
+}
+
+/// The boundaries balancing the state channel of a segment.
+///
+/// The entry state is pushed into the channel for the segment to pull, and the exit state is
+/// pulled from the channel after the segment pushed it.
+pub fn segment_boundaries<F: TowerField>(
+	state_channel: ChannelId,
+	entry: &[F],
+	exit: &[F],
+) -> Vec<Boundary<F>> {
+	vec![
+		Boundary {
+			values: entry.to_vec(),
+			channel_id: state_channel,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		},
+		Boundary {
+			values: exit.to_vec(),
+			channel_id: state_channel,
+			direction: FlushDirection::Pull,
+			multiplicity: 1,
+		},
+	]
+}
+
+/// The public inputs binding the index and the states of a segment to its proof.
+///
+/// The slots hold the index of the segment, the length of the entry state, and the values of the
+/// entry and exit states.
+pub fn segment_public_inputs<F: TowerField>(index: usize, entry: &[F], exit: &[F]) -> PublicInputs {
+	entry.iter().chain(exit).fold(
+		PublicInputs::new()
+			.with_slot((index as u64).to_le_bytes())
+			.with_slot((entry.len() as u64).to_le_bytes()),
+		|inputs, &value| inputs.with_scalar(value),
+	)
+}
+
+/// Proves segment `index` of a continuation with the standard FRI PCS.
+///
+/// `witness` only holds the segment, which pulls `entry` from the state channel and pushes `exit`
+/// into it. The public inputs of `options` are replaced by the ones of the segment.
+#[allow(clippy::too_many_arguments)]
+pub fn prove_segment<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	index: usize,
+	entry: Vec<FExt<Tower>>,
+	exit: Vec<FExt<Tower>>,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+	options: &ProveOptions,
+) -> Result<SegmentProof<FExt<Tower>>, Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>> + Send,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B16>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B32>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B64>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+{
+	let options = options
+		.clone()
+		.with_public_inputs(segment_public_inputs(index, &entry, &exit));
+	let proof = prove_with_options::<U, Tower, _, Hash, Compress, Challenger_, _>(
+		constraint_system,
+		pcs_params,
+		witness,
+		domain_factory,
+		backend,
+		&options,
+	)?;
+	Ok(SegmentProof { entry, exit, proof })
+}
+
+/// Verifies a continuation from the `entry` to the `exit` state with the standard FRI PCS.
+///
+/// The constraint system is prepared once for all segments.
+pub fn verify_continuation<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	state_channel: ChannelId,
+	entry: &[FExt<Tower>],
+	exit: &[FExt<Tower>],
+	proof: SegmentedProof<FExt<Tower>>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	if proof.entry() != entry || proof.exit() != exit {
+		bail!(VerificationError::ContinuationEndpointMismatch);
+	}
+	check_links(proof.segments())?;
+
+	let prepared = PreparedConstraintSystem::<Tower, FRIPCS<Hash, Compress>>::new(
+		constraint_system,
+		pcs_params,
+	)?;
+	for (index, segment) in proof.segments.into_iter().enumerate() {
+		let SegmentProof { entry, exit, proof } = segment;
+		verify_prepared_with_public_inputs::<U, Tower, _, Challenger_>(
+			&prepared,
+			segment_boundaries(state_channel, &entry, &exit),
+			&segment_public_inputs(index, &entry, &exit),
+			proof,
+		)?;
+	}
+	Ok(())
+}
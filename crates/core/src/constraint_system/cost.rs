@@ -0,0 +1,736 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Verifier cost accounting for the constraint system protocol.
+//!
+//! This module estimates the work done by [`super::verify`] for a concrete [`ConstraintSystem`]
+//! and PCS parameterization, in units that drive native verification time: hash invocations,
+//! hashed bytes, field multiplications, and the sizes of the transcript and advice tapes.
+//!
+//! For an on-chain verifier, [`VerifierCost::evm_gas`] prices the parts of verification whose gas
+//! the EVM fixes independently of the verifier contract: the proof as calldata and the Keccak-256
+//! hashing. The gas of the field arithmetic depends on how a contract implements it, and is not
+//! estimated.
+//!
+//! The counts follow the structure of the verifier rather than instrumenting it, so they are
+//! estimates. Field additions, which are XORs, and the evaluation of transparent multilinears are
+//! not counted.
+
+use std::{
+	fmt,
+	iter::Sum,
+	ops::{Add, AddAssign, Mul},
+};
+
+use binius_field::{BinaryField, TowerField};
+use binius_hash::PseudoCompressionFunction;
+use binius_math::ArithExpr;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use digest::{core_api::BlockSizeUser, Digest, Output};
+use itertools::Itertools;
+
+use super::{
+	common::FExt,
+	error::Error,
+	shape::{ProtocolShape, ZerocheckShape},
+	ConstraintSystem, PCSParams,
+};
+use crate::{
+	merkle_tree::MerkleTreeScheme, oracle::MultilinearPolyOracle, protocols::fri,
+	tower::TowerFamily,
+};
+
+/// Counts of the operations performed by a verifier.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierCost {
+	/// Invocations of the hash function, i.e. challenger squeezes, Merkle leaf hashes and hashes
+	/// of inner Merkle tree nodes.
+	pub hash_invocations: usize,
+	/// The total number of bytes input to the hash function.
+	pub hashed_bytes: usize,
+	/// Multiplications in the extension field.
+	pub field_muls: usize,
+	/// The number of bytes read from the transcript tape.
+	pub transcript_bytes: usize,
+	/// The number of bytes read from the advice tape.
+	pub advice_bytes: usize,
+}
+
+impl VerifierCost {
+	/// The total size of the proof.
+	pub fn proof_bytes(&self) -> usize {
+		self.transcript_bytes + self.advice_bytes
+	}
+
+	/// Estimates the EVM gas of passing the proof as calldata and of hashing with the `KECCAK256`
+	/// opcode, assuming Keccak-256 is the hash function.
+	///
+	/// Proof bytes are priced as uniformly random, so one in 256 is zero. Every hash is charged
+	/// for its whole words, with the hashed bytes spread evenly over the invocations.
+	pub fn evm_gas(&self) -> EvmGas {
+		let proof_bytes = self.proof_bytes();
+		let zero_bytes = proof_bytes / 256;
+		let words_per_hash = self
+			.hashed_bytes
+			.div_ceil(self.hash_invocations.max(1) * EVM_WORD_BYTES);
+		EvmGas {
+			calldata: zero_bytes * CALLDATA_ZERO_BYTE_GAS
+				+ (proof_bytes - zero_bytes) * CALLDATA_NONZERO_BYTE_GAS,
+			keccak: self.hash_invocations * (KECCAK256_GAS + words_per_hash * KECCAK256_WORD_GAS),
+		}
+	}
+}
+
+/// Gas charged per zero byte of calldata, per EIP-2028.
+const CALLDATA_ZERO_BYTE_GAS: usize = 4;
+/// Gas charged per non-zero byte of calldata, per EIP-2028.
+const CALLDATA_NONZERO_BYTE_GAS: usize = 16;
+/// Static gas of the `KECCAK256` opcode.
+const KECCAK256_GAS: usize = 30;
+/// Gas of the `KECCAK256` opcode per word of input.
+const KECCAK256_WORD_GAS: usize = 6;
+/// The size of an EVM word in bytes.
+const EVM_WORD_BYTES: usize = 32;
+
+/// EVM gas of the parts of on-chain verification that do not depend on the verifier contract.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EvmGas {
+	/// Gas of the transcript and advice tapes as transaction calldata.
+	pub calldata: usize,
+	/// Gas of the `KECCAK256` opcodes computing the verifier's hashes.
+	pub keccak: usize,
+}
+
+impl EvmGas {
+	/// The total gas.
+	pub fn total(&self) -> usize {
+		self.calldata + self.keccak
+	}
+}
+
+/// The gas of passing the given bytes as transaction calldata, per EIP-2028.
+pub fn calldata_gas(data: &[u8]) -> usize {
+	data.iter()
+		.map(|&byte| {
+			if byte == 0 {
+				CALLDATA_ZERO_BYTE_GAS
+			} else {
+				CALLDATA_NONZERO_BYTE_GAS
+			}
+		})
+		.sum()
+}
+
+impl Add for VerifierCost {
+	type Output = Self;
+
+	fn add(mut self, rhs: Self) -> Self {
+		self += rhs;
+		self
+	}
+}
+
+impl AddAssign for VerifierCost {
+	fn add_assign(&mut self, rhs: Self) {
+		self.hash_invocations += rhs.hash_invocations;
+		self.hashed_bytes += rhs.hashed_bytes;
+		self.field_muls += rhs.field_muls;
+		self.transcript_bytes += rhs.transcript_bytes;
+		self.advice_bytes += rhs.advice_bytes;
+	}
+}
+
+impl Mul<usize> for VerifierCost {
+	type Output = Self;
+
+	fn mul(self, rhs: usize) -> Self {
+		Self {
+			hash_invocations: self.hash_invocations * rhs,
+			hashed_bytes: self.hashed_bytes * rhs,
+			field_muls: self.field_muls * rhs,
+			transcript_bytes: self.transcript_bytes * rhs,
+			advice_bytes: self.advice_bytes * rhs,
+		}
+	}
+}
+
+impl Sum for VerifierCost {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::default(), Add::add)
+	}
+}
+
+/// Estimated verifier cost of each sub-protocol of the constraint system proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostReport {
+	/// GKR-based grand product arguments for both the flushes and the non-zero checks, including
+	/// the sampling of the channel challenges.
+	pub grand_product: VerifierCost,
+	/// Sumchecks reducing the final grand product layer claims to flushed oracle evaluations.
+	pub flush_sumcheck: VerifierCost,
+	/// Zerocheck of the table constraints, including the univariate skip round and univariatizing
+	/// reduction.
+	pub zerocheck: VerifierCost,
+	/// Matrix multiplication checks.
+	pub matmul: VerifierCost,
+	/// Sumchecks reducing evaluation claims on shifted and packed virtual oracles.
+	pub evalcheck: VerifierCost,
+	/// The FRI-Binius polynomial commitment scheme, including reading the commitment.
+	pub pcs: VerifierCost,
+}
+
+impl CostReport {
+	/// The total cost of verification.
+	pub fn total(&self) -> VerifierCost {
+		self.grand_product
+			+ self.flush_sumcheck
+			+ self.zerocheck
+			+ self.matmul
+			+ self.evalcheck
+			+ self.pcs
+	}
+}
+
+impl fmt::Display for CostReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let rows = [
+			("grand product", self.grand_product),
+			("flush sumcheck", self.flush_sumcheck),
+			("zerocheck", self.zerocheck),
+			("matmul", self.matmul),
+			("evalcheck", self.evalcheck),
+			("pcs", self.pcs),
+			("total", self.total()),
+		];
+		writeln!(
+			f,
+			"  {:<16} {:>10} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+			"",
+			"hashes",
+			"hashed B",
+			"field muls",
+			"transcript B",
+			"advice B",
+			"calldata gas",
+			"keccak gas"
+		)?;
+		for (i, (name, cost)) in rows.into_iter().enumerate() {
+			if i > 0 {
+				writeln!(f)?;
+			}
+			let gas = cost.evm_gas();
+			write!(
+				f,
+				"  {name:<16} {:>10} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+				cost.hash_invocations,
+				cost.hashed_bytes,
+				cost.field_muls,
+				cost.transcript_bytes,
+				cost.advice_bytes,
+				gas.calldata,
+				gas.keccak
+			)?;
+		}
+		Ok(())
+	}
+}
+
+/// Estimates the verifier cost of each sub-protocol of [`super::verify`] for the given constraint
+/// system and security parameters.
+///
+/// The PCS parameters are derived exactly as the prover and verifier derive them, hence the type
+/// parameters match those of [`super::verify`].
+pub fn cost_report<Tower, Hash, Compress>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+) -> Result<CostReport, Error>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	let ConstraintSystem {
+		oracles,
+		table_constraints,
+		matmuls,
+		..
+	} = constraint_system;
+	let ProtocolShape {
+		n_products,
+		max_product_n_vars,
+		flushes: flush_shapes,
+		zerocheck:
+			ZerocheckShape {
+				n_claims: n_zerocheck_claims,
+				max_n_vars,
+				skip_rounds,
+				max_degree,
+			},
+		evalcheck_n_vars,
+		commit_meta,
+		merkle_scheme,
+		fri_params,
+		..
+	} = ProtocolShape::<Tower, Hash, Compress>::new(constraint_system, pcs_params)?;
+
+	let scalar_bytes = FExt::<Tower>::N_BITS / 8;
+	let digest_bytes = <Hash as Digest>::output_size();
+	let tally = || Tally::new(scalar_bytes, digest_bytes);
+
+	// Grand products. The verifier samples the mixing and permutation challenges, reads the
+	// product of every flush and of every batch of non-zero oracles, and then verifies all
+	// products layer by layer. Every layer reads two evaluations per product and samples the
+	// batching and line reduction challenges.
+	let mut grand_product = tally();
+	if n_products > 0 {
+		grand_product.sample(2);
+		grand_product.read(n_products);
+		// Computing the flushed values' fingerprints and the products of every channel.
+		grand_product.muls(n_products);
+		for layer in 0..max_product_n_vars {
+			grand_product.sumcheck(n_products, layer, 3, 2 * n_products, n_products);
+			grand_product.sample(1);
+			grand_product.muls(2 * n_products);
+		}
+	}
+
+	let mut flush_sumcheck = tally();
+	for flush in &flush_shapes {
+		flush_sumcheck.sumcheck(1, flush.n_vars, 3, flush.width + 1, 2);
+		flush_sumcheck.muls(flush.width);
+	}
+
+	let mut zerocheck = tally();
+	if n_zerocheck_claims > 0 {
+		let n_multilinears = table_constraints
+			.iter()
+			.map(|constraint_set| constraint_set.oracle_ids.len())
+			.sum::<usize>();
+		let composition_muls = table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+			.map(|constraint| composition_muls(&constraint.composition) + 1)
+			.sum::<usize>();
+
+		zerocheck.sample(max_n_vars);
+		// The univariate skip round polynomial is sent in Lagrange form on the extended domain,
+		// omitting the evaluations on the skipped subcube, which are zero, and evaluated at the
+		// univariate challenge with barycentric weights.
+		let domain_size = max_degree << skip_rounds;
+		zerocheck.read(domain_size - (1 << skip_rounds));
+		zerocheck.sample(n_zerocheck_claims + 1);
+		zerocheck.muls(3 * domain_size);
+		zerocheck.sumcheck(
+			n_zerocheck_claims,
+			max_n_vars - skip_rounds,
+			max_degree + 1,
+			n_multilinears,
+			composition_muls,
+		);
+		zerocheck.sumcheck(1, skip_rounds, 2, n_multilinears, 2 * n_multilinears);
+	}
+
+	// Matrix multiplications: the product matrix is evaluated at a random point, then a degree-2
+	// sumcheck over the inner dimension.
+	let mut matmul = tally();
+	for mm in matmuls {
+		matmul.sample(mm.log_rows + mm.log_cols);
+		matmul.read(1);
+		matmul.sumcheck(1, mm.log_inner, 2, 2, 1);
+	}
+
+	let mut evalcheck = tally();
+	for n_vars in evalcheck_n_vars {
+		evalcheck.sumcheck(1, n_vars, 2, 2, 1);
+	}
+
+	let tree_depths = fri::vcs_tree_depths_iter(&fri_params).collect::<Vec<_>>();
+	let cap_len = |tree_depth| 1 << merkle_scheme.cap_depth(tree_depth);
+
+	let mut pcs = tally();
+	pcs.read_digests(cap_len(tree_depths[0]));
+	// Ring-switching. Each evaluation claim on an oracle over a subfield of the tower is reduced
+	// with a tensor algebra element, shared by claims on oracles at the same point. Oracles with
+	// the same number of variables and tower level are assumed to be evaluated at the same point.
+	let committed = oracles
+		.iter()
+		.filter_map(|oracle| match oracle {
+			MultilinearPolyOracle::Committed {
+				n_vars,
+				tower_level,
+				..
+			} => Some((n_vars, FExt::<Tower>::TOWER_LEVEL.saturating_sub(tower_level))),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+	let max_kappa = committed.iter().map(|&(_, kappa)| kappa).max().unwrap_or(0);
+	pcs.sample(log2_ceil_usize(committed.len()) + max_kappa);
+	pcs.read(committed.len());
+	for (_, kappa) in committed.into_iter().unique() {
+		// The tensor algebra element is evaluated at the point prefix and folded with the
+		// row-batching challenges.
+		pcs.read(1 << kappa);
+		pcs.muls(2 << kappa);
+	}
+	// The batched claim on the interleaved codeword is reduced with a degree-2 sumcheck
+	// interleaved with the FRI folding rounds.
+	pcs.sumcheck(1, commit_meta.total_vars(), 2, 2, 1);
+	pcs.read_digests(tree_depths[1..].iter().copied().map(cap_len).sum());
+
+	// The terminate codeword is read whole and folded to the final value.
+	let log_batch_size = fri_params.log_batch_size();
+	let log_inv_rate = fri_params.rs_code().log_inv_rate();
+	let n_final_challenges = fri_params.n_final_challenges();
+	let terminate_len = 1 << (n_final_challenges + log_inv_rate);
+	pcs.advice(terminate_len);
+	pcs.leaf_hashes(1 << n_final_challenges, 1 << log_inv_rate);
+	pcs.merkle_fold(
+		merkle_scheme.fold_steps(n_final_challenges, merkle_scheme.cap_depth(n_final_challenges)),
+	);
+	if fri_params.n_oracles() == 0 {
+		let log_dim = fri_params.rs_code().log_dim();
+		pcs.muls(1 << log_batch_size);
+		pcs.muls(fold_chunk_muls(log_dim) + (terminate_len >> log_dim));
+	} else {
+		pcs.muls((terminate_len >> n_final_challenges) * fold_chunk_muls(n_final_challenges));
+	}
+
+	// The Merkle tree layers that the queries are opened against.
+	let layer_depths =
+		fri::vcs_optimal_layers_depths_iter(&fri_params, &merkle_scheme).collect::<Vec<_>>();
+	for (&tree_depth, &depth) in tree_depths.iter().zip(&layer_depths) {
+		pcs.advice_digests(1 << depth);
+		pcs.merkle_fold(merkle_scheme.fold_steps(depth, merkle_scheme.cap_depth(tree_depth)));
+	}
+
+	// The test queries, each opening a coset of every oracle and folding it.
+	let n_test_queries = fri_params.n_test_queries();
+	pcs.sample_bits(n_test_queries, fri_params.index_bits());
+	let mut log_n_cosets = fri_params.log_len();
+	for (i, (&arity, &depth)) in fri_params
+		.fold_arities()
+		.iter()
+		.zip(&layer_depths)
+		.enumerate()
+	{
+		log_n_cosets -= arity;
+		let mut query = tally();
+		query.advice(1 << arity);
+		query.leaf_hashes(1, 1 << arity);
+		query.merkle_path(merkle_scheme.fold_steps(log_n_cosets, depth));
+		if i == 0 {
+			// Mixing the interleaved codewords with the tensor of the batching challenges.
+			query.muls(1 << arity);
+			query.muls(fold_chunk_muls(arity - log_batch_size));
+		} else {
+			query.muls(fold_chunk_muls(arity));
+		}
+		pcs.cost += query.cost * n_test_queries;
+	}
+	pcs.muls(1 << log_batch_size);
+
+	Ok(CostReport {
+		grand_product: grand_product.cost,
+		flush_sumcheck: flush_sumcheck.cost,
+		zerocheck: zerocheck.cost,
+		matmul: matmul.cost,
+		evalcheck: evalcheck.cost,
+		pcs: pcs.cost,
+	})
+}
+
+/// The number of multiplications to evaluate a composition polynomial, with powers computed by
+/// repeated squaring.
+fn composition_muls<F: TowerField>(expr: &ArithExpr<F>) -> usize {
+	match expr {
+		ArithExpr::Const(_) | ArithExpr::Var(_) => 0,
+		ArithExpr::Add(a, b) => composition_muls(a) + composition_muls(b),
+		ArithExpr::Mul(a, b) => composition_muls(a) + composition_muls(b) + 1,
+		ArithExpr::Pow(base, exp) => {
+			let exp_muls = match *exp {
+				0 => 0,
+				exp => exp.ilog2() as usize + exp.count_ones() as usize - 1,
+			};
+			composition_muls(base) + exp_muls
+		}
+	}
+}
+
+/// The number of multiplications to fold a coset of `2^log_size` values to one value, with two
+/// per butterfly and extrapolation.
+fn fold_chunk_muls(log_size: usize) -> usize {
+	2 * ((1 << log_size) - 1)
+}
+
+/// Accumulates the cost of a verifier following the Fiat-Shamir transcript.
+struct Tally {
+	scalar_bytes: usize,
+	digest_bytes: usize,
+	cost: VerifierCost,
+}
+
+impl Tally {
+	fn new(scalar_bytes: usize, digest_bytes: usize) -> Self {
+		Self {
+			scalar_bytes,
+			digest_bytes,
+			cost: VerifierCost::default(),
+		}
+	}
+
+	/// Reads scalars from the transcript, which are absorbed by the challenger.
+	fn read(&mut self, n: usize) {
+		self.cost.transcript_bytes += n * self.scalar_bytes;
+		self.cost.hashed_bytes += n * self.scalar_bytes;
+	}
+
+	/// Reads digests from the transcript, which are absorbed by the challenger.
+	fn read_digests(&mut self, n: usize) {
+		self.cost.transcript_bytes += n * self.digest_bytes;
+		self.cost.hashed_bytes += n * self.digest_bytes;
+	}
+
+	/// Samples consecutive scalar challenges. Every squeeze hashes the previous digest.
+	fn sample(&mut self, n: usize) {
+		self.squeeze(n * self.scalar_bytes);
+	}
+
+	/// Samples `n` consecutive integers of `bits` bits each.
+	fn sample_bits(&mut self, n: usize, bits: usize) {
+		self.squeeze(n * bits.div_ceil(8));
+	}
+
+	fn squeeze(&mut self, n_bytes: usize) {
+		let n_squeezes = n_bytes.div_ceil(self.digest_bytes);
+		self.cost.hash_invocations += n_squeezes;
+		self.cost.hashed_bytes += n_squeezes * self.digest_bytes;
+	}
+
+	fn advice(&mut self, n: usize) {
+		self.cost.advice_bytes += n * self.scalar_bytes;
+	}
+
+	fn advice_digests(&mut self, n: usize) {
+		self.cost.advice_bytes += n * self.digest_bytes;
+	}
+
+	fn muls(&mut self, n: usize) {
+		self.cost.field_muls += n;
+	}
+
+	fn leaf_hashes(&mut self, n: usize, leaf_len: usize) {
+		self.cost.hash_invocations += n;
+		self.cost.hashed_bytes += n * leaf_len * self.scalar_bytes;
+	}
+
+	/// Hashes `n` inner Merkle tree nodes with `n_children` children each.
+	fn node_hashes(&mut self, n: usize, n_children: usize) {
+		self.cost.hash_invocations += n;
+		self.cost.hashed_bytes += n * n_children * self.digest_bytes;
+	}
+
+	/// Hashes a whole Merkle tree layer up to a layer above it, given the steps between the two.
+	fn merkle_fold(&mut self, steps: impl Iterator<Item = (usize, usize)>) {
+		for (depth, log_children) in steps {
+			self.node_hashes(1 << (depth - log_children), 1 << log_children);
+		}
+	}
+
+	/// Hashes a Merkle opening path up to a layer above it, reading the siblings from the advice.
+	fn merkle_path(&mut self, steps: impl Iterator<Item = (usize, usize)>) {
+		for (_, log_children) in steps {
+			self.advice_digests((1 << log_children) - 1);
+			self.node_hashes(1, 1 << log_children);
+		}
+	}
+
+	/// A batched sumcheck of `n_claims` claims over `n_rounds` variables with round polynomials
+	/// of degree `degree`, finishing with reading `n_multilinears` evaluations and evaluating the
+	/// compositions, which take `composition_muls` multiplications in total.
+	fn sumcheck(
+		&mut self,
+		n_claims: usize,
+		n_rounds: usize,
+		degree: usize,
+		n_multilinears: usize,
+		composition_muls: usize,
+	) {
+		// Batching coefficients.
+		self.sample(1);
+		self.muls(n_claims);
+		for _ in 0..n_rounds {
+			// The round message omits the highest coefficient, which the verifier recovers.
+			self.read(degree);
+			self.sample(1);
+			self.muls(degree);
+		}
+		self.read(n_multilinears);
+		self.muls(composition_muls + n_claims);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField1b};
+	use binius_hash::compress::Groestl256ByteCompression;
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::{
+		constraint_system::{
+			channel::{ChannelRegistry, Flush, FlushDirection},
+			tests::{self, fixture, prove_fixture},
+			ProveOptions,
+		},
+		oracle::{ConstraintSetBuilder, MultilinearOracleSet},
+		tower::CanonicalTowerFamily,
+	};
+
+	type F = BinaryField128b;
+
+	fn test_constraint_system(n_vars: usize) -> ConstraintSystem<F> {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(n_vars, BinaryField1b::TOWER_LEVEL);
+		let b = oracles.add_committed(n_vars, BinaryField1b::TOWER_LEVEL);
+		let c = oracles.add_committed(n_vars, BinaryField1b::TOWER_LEVEL);
+
+		let mut constraints = ConstraintSetBuilder::new();
+		constraints.add_zerocheck(
+			"and",
+			[a, b, c],
+			ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+		);
+		let table_constraints = constraints.build(&oracles).unwrap();
+
+		let flushes = vec![
+			Flush {
+				oracles: vec![a, b],
+				channel_id: 0,
+				direction: FlushDirection::Push,
+				count: 1 << n_vars,
+				multiplicity: 1,
+			},
+			Flush {
+				oracles: vec![b, a],
+				channel_id: 0,
+				direction: FlushDirection::Pull,
+				count: 1 << n_vars,
+				multiplicity: 1,
+			},
+		];
+
+		ConstraintSystem {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids: vec![],
+			flushes,
+			matmuls: vec![],
+			max_channel_id: 0,
+			channels: ChannelRegistry::default(),
+		}
+	}
+
+	fn report(n_vars: usize, pcs_params: PCSParams) -> CostReport {
+		cost_report::<CanonicalTowerFamily, Groestl256, Groestl256ByteCompression>(
+			&test_constraint_system(n_vars),
+			&pcs_params,
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn test_composition_muls() {
+		let x = ArithExpr::<F>::Var(0);
+		let y = ArithExpr::<F>::Var(1);
+		assert_eq!(composition_muls(&(x.clone() + y.clone())), 0);
+		assert_eq!(composition_muls(&(x.clone() * y.clone() - x.clone())), 1);
+		assert_eq!(composition_muls(&x.clone().pow(1)), 0);
+		assert_eq!(composition_muls(&x.clone().pow(2)), 1);
+		// x^7 = x^4 * x^2 * x
+		assert_eq!(composition_muls(&(x.pow(7) * y)), 5);
+	}
+
+	#[test]
+	fn test_cost_report() {
+		let report = report(12, PCSParams::new(1, 100));
+
+		assert_eq!(report.matmul, VerifierCost::default());
+		assert_eq!(report.evalcheck, VerifierCost::default());
+		assert!(report.zerocheck.field_muls > 0);
+		assert!(report.grand_product.transcript_bytes > 0);
+		// Queries and layers are only read from the advice tape.
+		assert!(report.pcs.advice_bytes > 0);
+		assert_eq!(
+			report.total().advice_bytes,
+			report.pcs.advice_bytes,
+			"only the PCS uses the advice tape"
+		);
+		assert!(!report.to_string().is_empty());
+	}
+
+	#[test]
+	fn test_calldata_gas_matches_proofs() {
+		let (constraint_system, witness, _) = fixture();
+		let proof = prove_fixture(&constraint_system, witness, &ProveOptions::default()).unwrap();
+		let measured = calldata_gas(&proof.transcript) + calldata_gas(&proof.advice);
+
+		let estimated = cost_report::<CanonicalTowerFamily, Groestl256, Groestl256ByteCompression>(
+			&constraint_system,
+			&tests::pcs_params(),
+		)
+		.unwrap()
+		.total()
+		.evm_gas()
+		.calldata;
+		// Within 5% of the gas of the actual proof.
+		assert!(estimated.abs_diff(measured) * 20 < measured);
+	}
+
+	#[test]
+	fn test_evm_gas() {
+		let cost = VerifierCost {
+			hash_invocations: 2,
+			hashed_bytes: 80,
+			transcript_bytes: 512,
+			..Default::default()
+		};
+		// Two zero bytes in 512, and two hashes of 40 bytes, which take two words each.
+		assert_eq!(
+			cost.evm_gas(),
+			EvmGas {
+				calldata: 2 * 4 + 510 * 16,
+				keccak: 2 * (30 + 2 * 6),
+			}
+		);
+		assert_eq!(calldata_gas(&[0, 1, 0xff]), 4 + 16 + 16);
+	}
+
+	#[test]
+	fn test_cost_grows_with_security() {
+		let low = report(20, PCSParams::new(1, 32)).total();
+		let high = report(20, PCSParams::new(1, 100)).total();
+		assert!(high.hash_invocations > low.hash_invocations);
+		assert!(high.advice_bytes > low.advice_bytes);
+		assert!(high.field_muls > low.field_muls);
+	}
+
+	#[test]
+	fn test_cost_grows_with_size() {
+		let small = report(10, PCSParams::new(1, 100)).total();
+		let large = report(16, PCSParams::new(1, 100)).total();
+		assert!(large.transcript_bytes > small.transcript_bytes);
+		assert!(large.field_muls > small.field_muls);
+	}
+
+	#[test]
+	fn test_merkle_cap_and_arity_trade_proof_size_for_hashing() {
+		let params = PCSParams::new(1, 100);
+		let binary = report(16, params).pcs;
+
+		let capped = report(16, params.with_merkle_cap_height(4)).pcs;
+		assert!(capped.hash_invocations < binary.hash_invocations);
+		assert!(capped.transcript_bytes > binary.transcript_bytes);
+
+		let quaternary = report(16, params.with_merkle_log_arity(2)).pcs;
+		assert!(quaternary.hash_invocations < binary.hash_invocations);
+	}
+}
@@ -0,0 +1,504 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Schema diffs between versions of a constraint system.
+//!
+//! Proofs are only valid for the exact constraint system they were generated for. When a circuit
+//! evolves, [`ConstraintSystem::diff`] lists the changes between the old and the new version, so
+//! that an upgrade can be reviewed and the reason an old proof no longer verifies can be found.
+
+use std::{collections::HashMap, fmt, hash::Hash};
+
+use binius_field::TowerField;
+
+use super::{
+	channel::{ChannelId, ChannelInfo, ChannelRegistry, Flush, FlushDirection},
+	equivalence::{canonical_oracle_ids, flush_key, multiset, normalize, predicate_key},
+	ConstraintSystem,
+};
+use crate::oracle::OracleId;
+
+/// A change between two versions of a constraint system.
+///
+/// Oracle and flush indices of additions refer to the new system, those of removals to the old
+/// one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChange {
+	OracleAdded {
+		id: OracleId,
+		label: String,
+	},
+	OracleRemoved {
+		id: OracleId,
+		label: String,
+	},
+	ConstraintAdded {
+		name: String,
+		n_vars: usize,
+	},
+	ConstraintRemoved {
+		name: String,
+		n_vars: usize,
+	},
+	/// A constraint with the same name and number of variables exists in both systems, but with a
+	/// different predicate or composition.
+	ConstraintChanged {
+		name: String,
+		n_vars: usize,
+	},
+	NonZeroOracleAdded {
+		id: OracleId,
+		label: String,
+	},
+	NonZeroOracleRemoved {
+		id: OracleId,
+		label: String,
+	},
+	FlushAdded {
+		index: usize,
+		channel: String,
+		direction: FlushDirection,
+	},
+	FlushRemoved {
+		index: usize,
+		channel: String,
+		direction: FlushDirection,
+	},
+	MatMulAdded {
+		index: usize,
+	},
+	MatMulRemoved {
+		index: usize,
+	},
+	ChannelAdded {
+		id: ChannelId,
+		info: ChannelInfo,
+	},
+	ChannelRemoved {
+		id: ChannelId,
+		info: ChannelInfo,
+	},
+	ChannelChanged {
+		id: ChannelId,
+		old: ChannelInfo,
+		new: ChannelInfo,
+	},
+	MaxChannelIdChanged {
+		old: ChannelId,
+		new: ChannelId,
+	},
+}
+
+impl fmt::Display for SchemaChange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::OracleAdded { id, label } => write!(f, "+ oracle {label} (id={id})"),
+			Self::OracleRemoved { id, label } => write!(f, "- oracle {label} (id={id})"),
+			Self::ConstraintAdded { name, n_vars } => {
+				write!(f, "+ constraint {name} over {n_vars} variables")
+			}
+			Self::ConstraintRemoved { name, n_vars } => {
+				write!(f, "- constraint {name} over {n_vars} variables")
+			}
+			Self::ConstraintChanged { name, n_vars } => {
+				write!(f, "~ constraint {name} over {n_vars} variables")
+			}
+			Self::NonZeroOracleAdded { id, label } => {
+				write!(f, "+ non-zero check on {label} (id={id})")
+			}
+			Self::NonZeroOracleRemoved { id, label } => {
+				write!(f, "- non-zero check on {label} (id={id})")
+			}
+			Self::FlushAdded {
+				index,
+				channel,
+				direction,
+			} => write!(f, "+ flush {index} ({direction:?}) into channel {channel}"),
+			Self::FlushRemoved {
+				index,
+				channel,
+				direction,
+			} => write!(f, "- flush {index} ({direction:?}) into channel {channel}"),
+			Self::MatMulAdded { index } => write!(f, "+ matrix multiplication {index}"),
+			Self::MatMulRemoved { index } => write!(f, "- matrix multiplication {index}"),
+			Self::ChannelAdded { id, info } => write!(f, "+ channel id={id}: {info:?}"),
+			Self::ChannelRemoved { id, info } => write!(f, "- channel id={id}: {info:?}"),
+			Self::ChannelChanged { id, old, new } => {
+				write!(f, "~ channel id={id}: {old:?} -> {new:?}")
+			}
+			Self::MaxChannelIdChanged { old, new } => {
+				write!(f, "~ maximum channel ID: {old} -> {new}")
+			}
+		}
+	}
+}
+
+/// The list of changes from an old to a new version of a constraint system.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintSystemDiff {
+	pub changes: Vec<SchemaChange>,
+}
+
+impl ConstraintSystemDiff {
+	/// Whether the two systems are equivalent, in the sense of
+	/// [`ConstraintSystem::check_equivalent`], and have the same channel metadata.
+	pub fn is_empty(&self) -> bool {
+		self.changes.is_empty()
+	}
+
+	pub fn len(&self) -> usize {
+		self.changes.len()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &SchemaChange> {
+		self.changes.iter()
+	}
+}
+
+impl fmt::Display for ConstraintSystemDiff {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, change) in self.changes.iter().enumerate() {
+			if i > 0 {
+				writeln!(f)?;
+			}
+			write!(f, "{change}")?;
+		}
+		Ok(())
+	}
+}
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Lists the changes from an older version of the constraint system to this one.
+	///
+	/// The systems are compared in the same way as by [`Self::check_equivalent`]: oracles by their
+	/// definitions, constraints by their normalized compositions, and flushes and matrix
+	/// multiplications as multisets, so renaming or reordering doesn't show up as a change. An
+	/// empty diff means that proofs for one system are accepted for the other, up to the order in
+	/// which the prover commits and flushes.
+	///
+	/// A constraint that is removed and added with the same name and number of variables is
+	/// reported as changed.
+	pub fn diff(&self, old: &Self) -> ConstraintSystemDiff {
+		let mut interner = HashMap::new();
+		let new_ids = canonical_oracle_ids(&self.oracles, &mut interner);
+		let old_ids = canonical_oracle_ids(&old.oracles, &mut interner);
+
+		let mut changes = Vec::new();
+
+		let (added, removed) = unmatched(&new_ids, &old_ids);
+		changes.extend(added.into_iter().map(|id| SchemaChange::OracleAdded {
+			id,
+			label: self.oracles.label(id),
+		}));
+		changes.extend(removed.into_iter().map(|id| SchemaChange::OracleRemoved {
+			id,
+			label: old.oracles.label(id),
+		}));
+
+		let constraints = |cs: &Self, ids: &[usize]| {
+			cs.table_constraints
+				.iter()
+				.flat_map(|constraint_set| {
+					let vars = constraint_set
+						.oracle_ids
+						.iter()
+						.map(|&id| ids[id])
+						.collect::<Vec<_>>();
+					constraint_set.constraints.iter().map(move |constraint| {
+						let key = (
+							constraint_set.n_vars,
+							predicate_key(&constraint.predicate),
+							normalize(&constraint.composition, &vars),
+						);
+						((constraint.name.to_string(), constraint_set.n_vars), key)
+					})
+				})
+				.unzip::<_, _, Vec<_>, Vec<_>>()
+		};
+		let (new_names, new_keys) = constraints(self, &new_ids);
+		let (old_names, old_keys) = constraints(old, &old_ids);
+		let (added, removed) = unmatched(&new_keys, &old_keys);
+		let mut removed = removed
+			.into_iter()
+			.map(|i| old_names[i].clone())
+			.collect::<Vec<_>>();
+		for i in added {
+			let (name, n_vars) = new_names[i].clone();
+			match removed
+				.iter()
+				.position(|old| old.0 == name && old.1 == n_vars)
+			{
+				Some(position) => {
+					removed.remove(position);
+					changes.push(SchemaChange::ConstraintChanged { name, n_vars });
+				}
+				None => changes.push(SchemaChange::ConstraintAdded { name, n_vars }),
+			}
+		}
+		changes.extend(
+			removed
+				.into_iter()
+				.map(|(name, n_vars)| SchemaChange::ConstraintRemoved { name, n_vars }),
+		);
+
+		let non_zero = |cs: &Self, ids: &[usize]| {
+			cs.non_zero_oracle_ids
+				.iter()
+				.map(|&id| ids[id])
+				.collect::<Vec<_>>()
+		};
+		let (added, removed) = unmatched(&non_zero(self, &new_ids), &non_zero(old, &old_ids));
+		changes.extend(added.into_iter().map(|i| {
+			let id = self.non_zero_oracle_ids[i];
+			SchemaChange::NonZeroOracleAdded {
+				id,
+				label: self.oracles.label(id),
+			}
+		}));
+		changes.extend(removed.into_iter().map(|i| {
+			let id = old.non_zero_oracle_ids[i];
+			SchemaChange::NonZeroOracleRemoved {
+				id,
+				label: old.oracles.label(id),
+			}
+		}));
+
+		let flushes = |cs: &Self, ids: &[usize]| {
+			cs.flushes
+				.iter()
+				.map(|flush| flush_key(flush, |id| ids[id]))
+				.collect::<Vec<_>>()
+		};
+		let (added, removed) = unmatched(&flushes(self, &new_ids), &flushes(old, &old_ids));
+		let flush_channel = |flushes: &[Flush], channels: &ChannelRegistry, index: usize| {
+			let flush = &flushes[index];
+			(channels.label(flush.channel_id), flush.direction)
+		};
+		changes.extend(added.into_iter().map(|index| {
+			let (channel, direction) = flush_channel(&self.flushes, &self.channels, index);
+			SchemaChange::FlushAdded {
+				index,
+				channel,
+				direction,
+			}
+		}));
+		changes.extend(removed.into_iter().map(|index| {
+			let (channel, direction) = flush_channel(&old.flushes, &old.channels, index);
+			SchemaChange::FlushRemoved {
+				index,
+				channel,
+				direction,
+			}
+		}));
+
+		let matmuls = |cs: &Self, ids: &[usize]| {
+			cs.matmuls
+				.iter()
+				.map(|matmul| {
+					(
+						[ids[matmul.a], ids[matmul.b], ids[matmul.c]],
+						[matmul.log_rows, matmul.log_inner, matmul.log_cols],
+					)
+				})
+				.collect::<Vec<_>>()
+		};
+		let (added, removed) = unmatched(&matmuls(self, &new_ids), &matmuls(old, &old_ids));
+		changes.extend(
+			added
+				.into_iter()
+				.map(|index| SchemaChange::MatMulAdded { index }),
+		);
+		changes.extend(
+			removed
+				.into_iter()
+				.map(|index| SchemaChange::MatMulRemoved { index }),
+		);
+
+		for id in 0..self.channels.len().max(old.channels.len()) {
+			match (self.channels.get(id), old.channels.get(id)) {
+				(Some(new), None) => changes.push(SchemaChange::ChannelAdded {
+					id,
+					info: new.clone(),
+				}),
+				(None, Some(old)) => changes.push(SchemaChange::ChannelRemoved {
+					id,
+					info: old.clone(),
+				}),
+				(Some(new), Some(old)) if new != old => {
+					changes.push(SchemaChange::ChannelChanged {
+						id,
+						old: old.clone(),
+						new: new.clone(),
+					})
+				}
+				_ => {}
+			}
+		}
+		if self.max_channel_id != old.max_channel_id {
+			changes.push(SchemaChange::MaxChannelIdChanged {
+				old: old.max_channel_id,
+				new: self.max_channel_id,
+			});
+		}
+
+		ConstraintSystemDiff { changes }
+	}
+}
+
+/// Matches the items of two lists by equal keys, and returns the indices of the unmatched items of
+/// each list, in order.
+fn unmatched<K: Hash + Eq + Clone>(new: &[K], old: &[K]) -> (Vec<usize>, Vec<usize>) {
+	let unmatched_in = |items: &[K], others: &[K]| {
+		let mut remaining = multiset(others.iter().cloned());
+		items
+			.iter()
+			.enumerate()
+			.filter_map(|(index, key)| match remaining.get_mut(key) {
+				Some(count) if *count > 0 => {
+					*count -= 1;
+					None
+				}
+				_ => Some(index),
+			})
+			.collect()
+	};
+	(unmatched_in(new, old), unmatched_in(old, new))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use binius_field::BinaryField128b;
+	use binius_math::ArithExpr;
+
+	use super::*;
+	use crate::oracle::{Constraint, ConstraintPredicate, ConstraintSet, MultilinearOracleSet};
+
+	type F = BinaryField128b;
+
+	/// Builds a system with committed oracles `a` and `b` and the constraint `a * b = 0`.
+	fn build() -> ConstraintSystem<F> {
+		let mut oracles = MultilinearOracleSet::new();
+		let a = oracles.add_named("a").committed(8, 0);
+		let b = oracles.add_named("b").committed(8, 0);
+		let mut channels = ChannelRegistry::new();
+		let channel_id = channels.add(ChannelInfo {
+			name: Some("values".to_string()),
+			..Default::default()
+		});
+
+		ConstraintSystem {
+			oracles,
+			table_constraints: vec![ConstraintSet {
+				n_vars: 8,
+				oracle_ids: vec![a, b],
+				constraints: vec![Constraint {
+					name: Arc::from("and"),
+					composition: ArithExpr::Var(0) * ArithExpr::Var(1),
+					predicate: ConstraintPredicate::Zero,
+				}],
+			}],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![Flush {
+				oracles: vec![a],
+				channel_id,
+				direction: FlushDirection::Push,
+				count: 256,
+				multiplicity: 1,
+			}],
+			matmuls: vec![],
+			max_channel_id: channel_id,
+			channels,
+		}
+	}
+
+	#[test]
+	fn test_diff_identical() {
+		let diff = build().diff(&build());
+		assert!(diff.is_empty());
+		assert_eq!(diff.to_string(), "");
+	}
+
+	#[test]
+	fn test_diff_added_column_and_constraint() {
+		let old = build();
+		let mut new = build();
+		let c = new.oracles.add_named("c").committed(8, 0);
+		new.table_constraints[0].oracle_ids.push(c);
+		new.table_constraints[0].constraints.push(Constraint {
+			name: Arc::from("c_zero"),
+			composition: ArithExpr::Var(2),
+			predicate: ConstraintPredicate::Zero,
+		});
+
+		let diff = new.diff(&old);
+		assert_eq!(
+			diff.changes,
+			vec![
+				SchemaChange::OracleAdded {
+					id: c,
+					label: new.oracles.label(c),
+				},
+				SchemaChange::ConstraintAdded {
+					name: "c_zero".to_string(),
+					n_vars: 8,
+				},
+			]
+		);
+		assert_eq!(diff.to_string().lines().count(), 2);
+
+		// The reverse diff lists removals, with the IDs of the system diffed against.
+		let reverse = old.diff(&new);
+		assert!(matches!(
+			reverse.changes.as_slice(),
+			[
+				SchemaChange::OracleRemoved { id, .. },
+				SchemaChange::ConstraintRemoved { .. },
+			] if *id == c
+		));
+	}
+
+	#[test]
+	fn test_diff_changed_constraint() {
+		let old = build();
+		let mut new = build();
+		new.table_constraints[0].constraints[0].composition =
+			ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(0);
+
+		assert_eq!(
+			new.diff(&old).changes,
+			vec![SchemaChange::ConstraintChanged {
+				name: "and".to_string(),
+				n_vars: 8,
+			}]
+		);
+	}
+
+	#[test]
+	fn test_diff_channels() {
+		let old = build();
+		let mut new = build();
+		new.flushes[0].direction = FlushDirection::Pull;
+		new.channels = ChannelRegistry::new();
+		new.channels.add(ChannelInfo {
+			arity: Some(1),
+			..Default::default()
+		});
+
+		let changes = new.diff(&old).changes;
+		assert_eq!(changes.len(), 3);
+		assert!(matches!(
+			&changes[0],
+			SchemaChange::FlushAdded {
+				index: 0,
+				direction: FlushDirection::Pull,
+				..
+			}
+		));
+		assert!(matches!(
+			&changes[1],
+			SchemaChange::FlushRemoved { index: 0, channel, .. } if channel == "values (id=0)"
+		));
+		assert!(matches!(&changes[2], SchemaChange::ChannelChanged { id: 0, .. }));
+	}
+}
@@ -0,0 +1,275 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Variants of a constraint system with some constraints or flushes disabled.
+//!
+//! Disabling constraints isolates their contribution to the prover cost, and disabling a
+//! constraint that a witness violates gives a targeted negative test. A system with disabled
+//! constraints or flushes is weaker than the original one, and disabling only one side of a
+//! channel makes it unbalanced for any witness.
+
+use std::collections::{HashMap, HashSet};
+
+use binius_field::TowerField;
+use itertools::Itertools;
+
+use super::{channel::ChannelId, ConstraintSystem};
+
+/// Selects the constraints and flushes to disable in [`ConstraintSystem::with_disabled`].
+#[derive(Debug, Clone, Default)]
+pub struct DisabledConstraints {
+	constraints: HashSet<String>,
+	channels: HashSet<String>,
+	flushes: HashSet<usize>,
+}
+
+impl DisabledConstraints {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Disables all table constraints with the given name.
+	pub fn constraint(mut self, name: impl ToString) -> Self {
+		self.constraints.insert(name.to_string());
+		self
+	}
+
+	/// Disables all flushes into the channel registered with the given name.
+	pub fn channel(mut self, name: impl ToString) -> Self {
+		self.channels.insert(name.to_string());
+		self
+	}
+
+	/// Disables the flush at the given index of [`ConstraintSystem::flushes`].
+	pub fn flush(mut self, index: usize) -> Self {
+		self.flushes.insert(index);
+		self
+	}
+}
+
+/// What [`ConstraintSystem::with_disabled`] removed from a constraint system.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisabledReport {
+	/// The names and number of variables of the removed table constraints.
+	pub constraints: Vec<(String, usize)>,
+	/// The indices of the removed flushes in the original system, with their channels.
+	pub flushes: Vec<(usize, ChannelId)>,
+	/// Selected constraint names, channel names and flush indices that matched nothing, which
+	/// usually indicates a typo.
+	pub unmatched: Vec<String>,
+}
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Returns a copy of the constraint system with the selected table constraints and flushes
+	/// removed, along with a report of what was removed.
+	///
+	/// Constraint sets left without constraints are removed. Oracles, non-zero checks and matrix
+	/// multiplications are kept, so oracle IDs, and hence witnesses, remain valid for the variant.
+	pub fn with_disabled(&self, disabled: &DisabledConstraints) -> (Self, DisabledReport) {
+		let mut report = DisabledReport::default();
+		let mut system = self.clone();
+
+		for constraint_set in &mut system.table_constraints {
+			let n_vars = constraint_set.n_vars;
+			constraint_set.constraints.retain(|constraint| {
+				let keep = !disabled.constraints.contains(&*constraint.name);
+				if !keep {
+					report
+						.constraints
+						.push((constraint.name.to_string(), n_vars));
+				}
+				keep
+			});
+		}
+		system
+			.table_constraints
+			.retain(|constraint_set| !constraint_set.constraints.is_empty());
+
+		let mut channels_by_name = HashMap::<&str, Vec<ChannelId>>::new();
+		for id in 0..self.channels.len() {
+			if let Some(name) = self.channels.get(id).and_then(|info| info.name.as_deref()) {
+				channels_by_name.entry(name).or_default().push(id);
+			}
+		}
+		let disabled_channels = disabled
+			.channels
+			.iter()
+			.filter_map(|name| channels_by_name.get(name.as_str()))
+			.flatten()
+			.copied()
+			.collect::<HashSet<_>>();
+		system.flushes = self
+			.flushes
+			.iter()
+			.enumerate()
+			.filter_map(|(index, flush)| {
+				if disabled.flushes.contains(&index)
+					|| disabled_channels.contains(&flush.channel_id)
+				{
+					report.flushes.push((index, flush.channel_id));
+					None
+				} else {
+					Some(flush.clone())
+				}
+			})
+			.collect();
+
+		let sorted = |names: Vec<String>| names.into_iter().sorted();
+		report.unmatched.extend(sorted(
+			disabled
+				.constraints
+				.iter()
+				.filter(|name| {
+					!report
+						.constraints
+						.iter()
+						.any(|(removed, _)| removed == *name)
+				})
+				.map(|name| format!("constraint {name}"))
+				.collect(),
+		));
+		report.unmatched.extend(sorted(
+			disabled
+				.channels
+				.iter()
+				.filter(|name| !channels_by_name.contains_key(name.as_str()))
+				.map(|name| format!("channel {name}"))
+				.collect(),
+		));
+		report.unmatched.extend(
+			disabled
+				.flushes
+				.iter()
+				.filter(|&&index| index >= self.flushes.len())
+				.sorted()
+				.map(|index| format!("flush {index}")),
+		);
+
+		(system, report)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use binius_field::BinaryField128b;
+	use binius_math::ArithExpr;
+
+	use super::*;
+	use crate::{
+		constraint_system::channel::{ChannelInfo, ChannelRegistry, Flush, FlushDirection},
+		oracle::{Constraint, ConstraintPredicate, ConstraintSet, MultilinearOracleSet},
+	};
+
+	type F = BinaryField128b;
+
+	fn constraint(name: &str, composition: ArithExpr<F>) -> Constraint<F> {
+		Constraint {
+			name: Arc::from(name),
+			composition,
+			predicate: ConstraintPredicate::Zero,
+		}
+	}
+
+	fn build() -> ConstraintSystem<F> {
+		let mut oracles = MultilinearOracleSet::new();
+		let a = oracles.add_committed(8, 0);
+		let b = oracles.add_committed(8, 0);
+		let c = oracles.add_committed(4, 0);
+		let mut channels = ChannelRegistry::new();
+		let values = channels.add(ChannelInfo {
+			name: Some("values".to_string()),
+			..Default::default()
+		});
+		let lookups = channels.add(ChannelInfo::default());
+		let flush = |oracle, channel_id, direction| Flush {
+			oracles: vec![oracle],
+			channel_id,
+			direction,
+			count: 16,
+			multiplicity: 1,
+		};
+
+		ConstraintSystem {
+			oracles,
+			table_constraints: vec![
+				ConstraintSet {
+					n_vars: 8,
+					oracle_ids: vec![a, b],
+					constraints: vec![
+						constraint("and", ArithExpr::Var(0) * ArithExpr::Var(1)),
+						constraint(
+							"a_bit",
+							ArithExpr::Var(0) * ArithExpr::Var(0) - ArithExpr::Var(0),
+						),
+					],
+				},
+				ConstraintSet {
+					n_vars: 4,
+					oracle_ids: vec![c],
+					constraints: vec![constraint("and", ArithExpr::Var(0))],
+				},
+			],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![
+				flush(a, values, FlushDirection::Push),
+				flush(b, values, FlushDirection::Pull),
+				flush(c, lookups, FlushDirection::Push),
+			],
+			matmuls: vec![],
+			max_channel_id: lookups,
+			channels,
+		}
+	}
+
+	#[test]
+	fn test_disable_constraints() {
+		let original = build();
+		let (system, report) =
+			original.with_disabled(&DisabledConstraints::new().constraint("and"));
+
+		assert_eq!(report.constraints, vec![("and".to_string(), 8), ("and".to_string(), 4)]);
+		assert!(report.flushes.is_empty());
+		assert!(report.unmatched.is_empty());
+
+		// The second constraint set is left empty and removed.
+		assert_eq!(system.table_constraints.len(), 1);
+		assert_eq!(system.table_constraints[0].constraints.len(), 1);
+		assert_eq!(&*system.table_constraints[0].constraints[0].name, "a_bit");
+		assert_eq!(system.oracles.size(), original.oracles.size());
+		assert_eq!(system.flushes.len(), 3);
+	}
+
+	#[test]
+	fn test_disable_flushes() {
+		let (system, report) = build().with_disabled(
+			&DisabledConstraints::new()
+				.channel("values")
+				.flush(2)
+				.flush(7)
+				.channel("missing")
+				.constraint("missing"),
+		);
+
+		assert_eq!(report.flushes, vec![(0, 0), (1, 0), (2, 1)]);
+		assert!(report.constraints.is_empty());
+		assert_eq!(
+			report.unmatched,
+			vec![
+				"constraint missing".to_string(),
+				"channel missing".to_string(),
+				"flush 7".to_string()
+			]
+		);
+		assert!(system.flushes.is_empty());
+		assert_eq!(system.table_constraints.len(), 2);
+	}
+
+	#[test]
+	fn test_disable_nothing() {
+		let original = build();
+		let (system, report) = original.with_disabled(&DisabledConstraints::new());
+		assert_eq!(report, DisabledReport::default());
+		assert!(system.structurally_equal(&original));
+	}
+}
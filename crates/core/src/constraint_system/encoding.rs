@@ -0,0 +1,281 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use bytes::{Buf, BufMut, Bytes};
+
+use super::{error::Error, Proof, ProofMetadata};
+use crate::transcript::{self, ProofStream, ScalarEncoding, Tape};
+
+/// The largest advice tape, in bytes, the verifier decompresses.
+pub const MAX_DECOMPRESSED_ADVICE_SIZE: usize = 1 << 30;
+
+/// A general-purpose compressor applied to the advice tape after proving.
+///
+/// The transcript tape is never compressed, since its bytes are observed by the Fiat-Shamir
+/// challenger and are close to uniformly random anyway. The advice tape, on the other hand,
+/// contains Merkle openings and codeword values that often have a lot of redundancy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AdviceCompression {
+	#[default]
+	None,
+	/// DEFLATE compression with the given level, from 0 to 10.
+	Deflate { level: u8 },
+}
+
+/// The encoding of a [`super::Proof`].
+///
+/// The default encoding writes field elements at the full width of their type and doesn't
+/// compress the advice, which is the most efficient to prove and verify. The verifier reads the
+/// encoding from the proof.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProofEncoding {
+	/// The encoding of field elements on both the transcript and advice tapes.
+	pub scalars: ScalarEncoding,
+	/// The compression applied to the advice tape.
+	pub advice_compression: AdviceCompression,
+}
+
+impl ProofEncoding {
+	/// The encoding producing the smallest proofs.
+	pub fn compact() -> Self {
+		Self {
+			scalars: ScalarEncoding::Compact,
+			advice_compression: AdviceCompression::Deflate { level: 9 },
+		}
+	}
+
+	pub(super) fn compress_advice(&self, advice: Vec<u8>) -> Vec<u8> {
+		match self.advice_compression {
+			AdviceCompression::None => advice,
+			AdviceCompression::Deflate { level } => {
+				miniz_oxide::deflate::compress_to_vec(&advice, level)
+			}
+		}
+	}
+
+	pub(super) fn decompress_advice(&self, advice: Vec<u8>) -> Result<Vec<u8>, Error> {
+		match self.advice_compression {
+			AdviceCompression::None => Ok(advice),
+			AdviceCompression::Deflate { .. } => {
+				miniz_oxide::inflate::decompress_to_vec_with_limit(
+					&advice,
+					MAX_DECOMPRESSED_ADVICE_SIZE,
+				)
+				.map_err(|err| Error::AdviceDecompression(err.to_string()))
+			}
+		}
+	}
+}
+
+impl Proof {
+	/// The tapes of the proof in a single [`ProofStream`].
+	///
+	/// The header section holds the encoding, metadata and number of public inputs of the proof,
+	/// and the transcript is split into the phases the prover recorded in [`Proof::phases`].
+	pub fn to_stream(&self) -> Result<ProofStream, Error> {
+		let stream =
+			ProofStream::new(&self.encode_header(), &self.transcript, &self.phases, &self.advice)?;
+		Ok(stream)
+	}
+
+	/// Serializes the proof as a [`ProofStream`].
+	pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+		Ok(self.to_stream()?.to_bytes())
+	}
+
+	/// Parses a proof serialized with [`Proof::to_bytes`].
+	pub fn from_bytes(bytes: impl Into<Bytes>) -> Result<Self, Error> {
+		Self::from_stream(&ProofStream::from_bytes(bytes)?)
+	}
+
+	/// Reads a proof from the tapes of a [`ProofStream`].
+	pub fn from_stream(stream: &ProofStream) -> Result<Self, Error> {
+		let mut header = stream.tape(Tape::Header);
+		let (encoding, metadata, n_public_inputs) = decode_header(&mut header)
+			.ok_or(transcript::Error::InvalidStream("malformed proof header"))?;
+		if header.has_remaining() {
+			return Err(transcript::Error::InvalidStream("malformed proof header").into());
+		}
+
+		Ok(Self {
+			transcript: stream.tape(Tape::Transcript).to_vec(),
+			advice: stream.tape(Tape::Advice).to_vec(),
+			encoding,
+			metadata,
+			n_public_inputs,
+			phases: stream.phases(),
+		})
+	}
+
+	fn encode_header(&self) -> Vec<u8> {
+		let mut header = Vec::new();
+		header.put_u8(match self.encoding.scalars {
+			ScalarEncoding::Canonical => 0,
+			ScalarEncoding::Compact => 1,
+		});
+		match self.encoding.advice_compression {
+			AdviceCompression::None => header.put_slice(&[0, 0]),
+			AdviceCompression::Deflate { level } => header.put_slice(&[1, level]),
+		}
+		header.put_u64_le(self.n_public_inputs as u64);
+		match &self.metadata {
+			None => header.put_u8(0),
+			Some(metadata) => {
+				header.put_u8(1);
+				header.put_u32_le(metadata.claim_version);
+				for field in [
+					metadata.prover_version.as_bytes(),
+					&metadata.circuit_digest,
+					&metadata.public_input_hash,
+				] {
+					header.put_u64_le(field.len() as u64);
+					header.put_slice(field);
+				}
+			}
+		}
+		header
+	}
+}
+
+/// Decodes the header written by [`Proof::encode_header`], or returns `None` if it is malformed.
+fn decode_header(header: &mut Bytes) -> Option<(ProofEncoding, Option<ProofMetadata>, usize)> {
+	let scalars = match take::<1>(header)?[0] {
+		0 => ScalarEncoding::Canonical,
+		1 => ScalarEncoding::Compact,
+		_ => return None,
+	};
+	let advice_compression = match (take::<1>(header)?[0], take::<1>(header)?[0]) {
+		(0, 0) => AdviceCompression::None,
+		(1, level) => AdviceCompression::Deflate { level },
+		_ => return None,
+	};
+	let n_public_inputs = usize::try_from(u64::from_le_bytes(take(header)?)).ok()?;
+	let metadata = match take::<1>(header)?[0] {
+		0 => None,
+		1 => {
+			let claim_version = u32::from_le_bytes(take(header)?);
+			let prover_version = String::from_utf8(take_vec(header)?).ok()?;
+			let circuit_digest = take_vec(header)?;
+			let public_input_hash = take_vec(header)?;
+			Some(ProofMetadata {
+				claim_version,
+				prover_version,
+				circuit_digest,
+				public_input_hash,
+			})
+		}
+		_ => return None,
+	};
+
+	Some((
+		ProofEncoding {
+			scalars,
+			advice_compression,
+		},
+		metadata,
+		n_public_inputs,
+	))
+}
+
+fn take<const N: usize>(header: &mut Bytes) -> Option<[u8; N]> {
+	(header.remaining() >= N).then(|| {
+		let mut bytes = [0; N];
+		header.copy_to_slice(&mut bytes);
+		bytes
+	})
+}
+
+fn take_vec(header: &mut Bytes) -> Option<Vec<u8>> {
+	let len = usize::try_from(u64::from_le_bytes(take(header)?)).ok()?;
+	(header.remaining() >= len).then(|| header.split_to(len).to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::constraint_system::{
+		phase,
+		tests::{fixture, prove_fixture, verify_fixture},
+		ProveOptions,
+	};
+
+	#[test]
+	fn test_advice_compression_roundtrip() {
+		let advice = (0..4096u32)
+			.flat_map(|i| (i % 17).to_le_bytes())
+			.collect::<Vec<_>>();
+
+		let encoding = ProofEncoding::compact();
+		let compressed = encoding.compress_advice(advice.clone());
+		assert!(compressed.len() < advice.len());
+		assert_eq!(encoding.decompress_advice(compressed).unwrap(), advice);
+
+		let encoding = ProofEncoding::default();
+		assert_eq!(encoding.compress_advice(advice.clone()), advice);
+	}
+
+	#[test]
+	fn test_advice_decompression_rejects_garbage() {
+		let encoding = ProofEncoding::compact();
+		assert!(matches!(
+			encoding.decompress_advice(vec![0xff; 32]),
+			Err(Error::AdviceDecompression(_))
+		));
+	}
+
+	#[test]
+	fn test_proof_bytes_roundtrip() {
+		let proof = Proof {
+			transcript: (0..40).collect(),
+			advice: vec![7; 24],
+			encoding: ProofEncoding::compact(),
+			metadata: Some(ProofMetadata::new(3).with_circuit_digest([1, 2, 3])),
+			n_public_inputs: 2,
+			phases: vec![("commitment".to_string(), 8), ("zerocheck".to_string(), 30)],
+		};
+		let bytes = proof.to_bytes().unwrap();
+		let decoded = Proof::from_bytes(bytes.clone()).unwrap();
+		assert_eq!(decoded.transcript, proof.transcript);
+		assert_eq!(decoded.advice, proof.advice);
+		assert_eq!(decoded.encoding, proof.encoding);
+		assert_eq!(decoded.metadata, proof.metadata);
+		assert_eq!(decoded.n_public_inputs, proof.n_public_inputs);
+		assert_eq!(decoded.phases, proof.phases);
+
+		// The sections of the transcript can be read without parsing the proof.
+		let stream = ProofStream::from_bytes(bytes).unwrap();
+		assert_eq!(stream.section(Tape::Transcript, "zerocheck").unwrap(), &proof.transcript[30..]);
+	}
+
+	#[test]
+	fn test_proof_stream_roundtrip() {
+		let (constraint_system, witness, boundaries) = fixture();
+		let options = ProveOptions::default().with_encoding(ProofEncoding::compact());
+		let proof = prove_fixture(&constraint_system, witness, &options).unwrap();
+		assert!(proof
+			.phases
+			.iter()
+			.any(|(label, _)| label == phase::ZEROCHECK));
+
+		let bytes = proof.to_bytes().unwrap();
+		let decoded = Proof::from_bytes(bytes).unwrap();
+		assert_eq!(decoded.transcript, proof.transcript);
+		assert_eq!(decoded.advice, proof.advice);
+		assert_eq!(decoded.phases, proof.phases);
+
+		// A verifier that disagrees with the prover on where a phase starts rejects the proof,
+		// even though the challenges are the same.
+		let mut shifted = decoded.clone();
+		shifted.phases.last_mut().unwrap().1 += 1;
+		assert!(verify_fixture(&constraint_system, &boundaries, shifted).is_err());
+		verify_fixture(&constraint_system, &boundaries, decoded).unwrap();
+	}
+
+	#[test]
+	fn test_prove_legacy_format() {
+		let (constraint_system, witness, boundaries) = fixture();
+		let options = ProveOptions::default().with_legacy_format();
+		let proof = prove_fixture(&constraint_system, witness, &options).unwrap();
+		assert!(proof.phases.is_empty());
+		verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+	}
+}
@@ -0,0 +1,602 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Comparison of constraint systems, for asserting that a refactored circuit builder still
+//! produces the same system.
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	hash::Hash,
+	iter::repeat_with,
+};
+
+use binius_field::TowerField;
+use binius_math::ArithExpr;
+use rand::{rngs::StdRng, SeedableRng};
+
+use super::{
+	channel::{ChannelId, Flush, FlushDirection},
+	ConstraintSystem,
+};
+use crate::oracle::{
+	ConstraintPredicate, ConstraintSet, MultilinearOracleSet, MultilinearPolyOracle, OracleId,
+	ProjectionVariant, ShiftVariant,
+};
+
+/// The number of random points transparent polynomials are compared at.
+const TRANSPARENT_FINGERPRINT_POINTS: usize = 4;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EquivalenceError {
+	#[error("the oracle sets differ")]
+	Oracles,
+	#[error("the table constraints differ")]
+	Constraints,
+	#[error("the flushes differ")]
+	Flushes,
+	#[error("the non-zero oracles differ")]
+	NonZeroOracles,
+	#[error("the matrix multiplications differ")]
+	MatMuls,
+	#[error("the maximum channel IDs differ: {0} and {1}")]
+	MaxChannelId(ChannelId, ChannelId),
+}
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Whether two constraint systems are identical, up to the names of oracles and constraints.
+	///
+	/// Oracles must have the same IDs, and constraint sets and flushes must be in the same order.
+	/// Transparent oracles are compared by their evaluations at a few fixed pseudo-random points.
+	pub fn structurally_equal(&self, other: &Self) -> bool {
+		let shapes = |oracles: &MultilinearOracleSet<F>| oracle_shapes(oracles).collect::<Vec<_>>();
+		let constraint_set_eq = |a: &ConstraintSet<F>, b: &ConstraintSet<F>| {
+			a.n_vars == b.n_vars
+				&& a.oracle_ids == b.oracle_ids
+				&& a.constraints.len() == b.constraints.len()
+				&& a.constraints.iter().zip(&b.constraints).all(|(a, b)| {
+					a.composition == b.composition
+						&& predicate_key(&a.predicate) == predicate_key(&b.predicate)
+				})
+		};
+
+		shapes(&self.oracles) == shapes(&other.oracles)
+			&& self.table_constraints.len() == other.table_constraints.len()
+			&& self
+				.table_constraints
+				.iter()
+				.zip(&other.table_constraints)
+				.all(|(a, b)| constraint_set_eq(a, b))
+			&& self.flushes.len() == other.flushes.len()
+			&& self
+				.flushes
+				.iter()
+				.zip(&other.flushes)
+				.all(|(a, b)| flush_key(a, |id| id) == flush_key(b, |id| id))
+			&& self.non_zero_oracle_ids == other.non_zero_oracle_ids
+			&& self.matmuls == other.matmuls
+			&& self.max_channel_id == other.max_channel_id
+	}
+
+	/// Checks that two constraint systems are semantically equivalent.
+	///
+	/// This is a weaker notion than [`Self::structurally_equal`] that is robust to refactors of
+	/// circuit builders:
+	///
+	/// * Oracles are compared up to renaming. Derived oracles are identified by their definition in
+	///   terms of the oracles they depend on, so they may be declared in a different order, be
+	///   named differently, or be declared twice. Committed oracles are identified by their shape
+	///   and the order in which they are declared relative to each other.
+	/// * Constraints are compared after normalizing their compositions to sums of monomials over
+	///   the oracles they reference, so the grouping of constraints into constraint sets, the order
+	///   of constraints, and the form of the arithmetic expressions don't matter.
+	/// * Flushes are compared as a multiset, which is how channels balance.
+	/// * Matrix multiplications are compared as a multiset.
+	pub fn check_equivalent(&self, other: &Self) -> Result<(), EquivalenceError> {
+		if self.max_channel_id != other.max_channel_id {
+			return Err(EquivalenceError::MaxChannelId(self.max_channel_id, other.max_channel_id));
+		}
+
+		let mut interner = HashMap::new();
+		let self_ids = canonical_oracle_ids(&self.oracles, &mut interner);
+		let other_ids = canonical_oracle_ids(&other.oracles, &mut interner);
+
+		if multiset(self_ids.iter().copied()) != multiset(other_ids.iter().copied()) {
+			return Err(EquivalenceError::Oracles);
+		}
+
+		let non_zero =
+			|ids: &[usize], cs: &Self| multiset(cs.non_zero_oracle_ids.iter().map(|&id| ids[id]));
+		if non_zero(&self_ids, self) != non_zero(&other_ids, other) {
+			return Err(EquivalenceError::NonZeroOracles);
+		}
+
+		let flushes = |ids: &[usize], cs: &Self| {
+			multiset(
+				cs.flushes
+					.iter()
+					.map(|flush| flush_key(flush, |id| ids[id])),
+			)
+		};
+		if flushes(&self_ids, self) != flushes(&other_ids, other) {
+			return Err(EquivalenceError::Flushes);
+		}
+
+		let constraints = |ids: &[usize], cs: &Self| {
+			multiset(cs.table_constraints.iter().flat_map(|constraint_set| {
+				let vars = constraint_set
+					.oracle_ids
+					.iter()
+					.map(|&id| ids[id])
+					.collect::<Vec<_>>();
+				constraint_set.constraints.iter().map(move |constraint| {
+					(
+						constraint_set.n_vars,
+						predicate_key(&constraint.predicate),
+						normalize(&constraint.composition, &vars),
+					)
+				})
+			}))
+		};
+		if constraints(&self_ids, self) != constraints(&other_ids, other) {
+			return Err(EquivalenceError::Constraints);
+		}
+
+		let matmuls = |ids: &[usize], cs: &Self| {
+			multiset(cs.matmuls.iter().map(|matmul| {
+				(
+					[ids[matmul.a], ids[matmul.b], ids[matmul.c]],
+					[matmul.log_rows, matmul.log_inner, matmul.log_cols],
+				)
+			}))
+		};
+		if matmuls(&self_ids, self) != matmuls(&other_ids, other) {
+			return Err(EquivalenceError::MatMuls);
+		}
+
+		Ok(())
+	}
+}
+
+/// The definition of an oracle, with references to other oracles replaced by some index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) enum OracleShape<F> {
+	Transparent {
+		n_vars: usize,
+		tower_level: usize,
+		fingerprint: Vec<Option<F>>,
+	},
+	Committed {
+		n_vars: usize,
+		tower_level: usize,
+		/// The position of the oracle among the committed oracles of the set.
+		index: usize,
+	},
+	Repeating {
+		inner: usize,
+		log_count: usize,
+	},
+	Projected {
+		inner: usize,
+		values: Vec<F>,
+		variant: ProjectionVariant,
+	},
+	Shifted {
+		inner: usize,
+		offset: usize,
+		block_size: usize,
+		variant: ShiftVariant,
+	},
+	Packed {
+		inner: usize,
+		log_degree: usize,
+	},
+	Univariate {
+		inner: usize,
+		log_domain_size: usize,
+	},
+	LinearCombination {
+		n_vars: usize,
+		offset: F,
+		inner: Vec<(usize, F)>,
+	},
+	ZeroPadded {
+		inner: usize,
+		n_vars: usize,
+	},
+	Concatenated {
+		inner_n_vars: usize,
+		inner: Vec<usize>,
+	},
+}
+
+/// Returns the shapes of all oracles in a set in the order of their IDs, with inner oracles
+/// referenced by their IDs.
+fn oracle_shapes<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+) -> impl Iterator<Item = OracleShape<F>> + '_ {
+	let mut n_committed = 0;
+	oracles.iter().map(move |oracle| match oracle {
+		MultilinearPolyOracle::Transparent { inner, .. } => {
+			let poly = inner.poly();
+			// Seed by the number of variables only, so equal polynomials get equal fingerprints.
+			let mut rng = StdRng::seed_from_u64(poly.n_vars() as u64);
+			let fingerprint = repeat_with(|| {
+				let point = repeat_with(|| F::random(&mut rng))
+					.take(poly.n_vars())
+					.collect::<Vec<_>>();
+				poly.evaluate(&point).ok()
+			})
+			.take(TRANSPARENT_FINGERPRINT_POINTS)
+			.collect();
+			OracleShape::Transparent {
+				n_vars: poly.n_vars(),
+				tower_level: poly.binary_tower_level(),
+				fingerprint,
+			}
+		}
+		MultilinearPolyOracle::Committed {
+			n_vars,
+			tower_level,
+			..
+		} => {
+			n_committed += 1;
+			OracleShape::Committed {
+				n_vars,
+				tower_level,
+				index: n_committed - 1,
+			}
+		}
+		MultilinearPolyOracle::Repeating {
+			inner, log_count, ..
+		} => OracleShape::Repeating {
+			inner: inner.id(),
+			log_count,
+		},
+		MultilinearPolyOracle::Projected { projected, .. } => OracleShape::Projected {
+			inner: projected.inner().id(),
+			values: projected.values().clone(),
+			variant: projected.projection_variant(),
+		},
+		MultilinearPolyOracle::Shifted { shifted, .. } => OracleShape::Shifted {
+			inner: shifted.inner().id(),
+			offset: shifted.shift_offset(),
+			block_size: shifted.block_size(),
+			variant: shifted.shift_variant(),
+		},
+		MultilinearPolyOracle::Packed { packed, .. } => OracleShape::Packed {
+			inner: packed.inner().id(),
+			log_degree: packed.log_degree(),
+		},
+		MultilinearPolyOracle::Univariate { univariate, .. } => OracleShape::Univariate {
+			inner: univariate.inner().id(),
+			log_domain_size: univariate.log_domain_size(),
+		},
+		MultilinearPolyOracle::LinearCombination {
+			linear_combination, ..
+		} => OracleShape::LinearCombination {
+			n_vars: linear_combination.n_vars(),
+			offset: linear_combination.offset(),
+			inner: linear_combination
+				.polys()
+				.map(|poly| poly.id())
+				.zip(linear_combination.coefficients())
+				.collect(),
+		},
+		MultilinearPolyOracle::ZeroPadded { inner, n_vars, .. } => OracleShape::ZeroPadded {
+			inner: inner.id(),
+			n_vars,
+		},
+		MultilinearPolyOracle::Concatenated { concatenated, .. } => OracleShape::Concatenated {
+			inner_n_vars: concatenated.inner_n_vars(),
+			inner: concatenated.polys().map(|poly| poly.id()).collect(),
+		},
+	})
+}
+
+/// Maps every oracle ID of the set to the index of its shape in the interner.
+///
+/// Inner oracles are referenced by their interned index, so that two oracles, possibly from
+/// different sets, get the same index if and only if they have the same definition.
+pub(super) fn canonical_oracle_ids<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	interner: &mut HashMap<OracleShape<F>, usize>,
+) -> Vec<usize> {
+	let mut ids = Vec::with_capacity(oracles.size());
+	// Inner oracles always have smaller IDs than the oracles referencing them.
+	for shape in oracle_shapes(oracles) {
+		let shape = reindex_shape(shape, &ids);
+		let next_index = interner.len();
+		ids.push(*interner.entry(shape).or_insert(next_index));
+	}
+	ids
+}
+
+fn reindex_shape<F>(shape: OracleShape<F>, ids: &[usize]) -> OracleShape<F> {
+	match shape {
+		OracleShape::Repeating { inner, log_count } => OracleShape::Repeating {
+			inner: ids[inner],
+			log_count,
+		},
+		OracleShape::Projected {
+			inner,
+			values,
+			variant,
+		} => OracleShape::Projected {
+			inner: ids[inner],
+			values,
+			variant,
+		},
+		OracleShape::Shifted {
+			inner,
+			offset,
+			block_size,
+			variant,
+		} => OracleShape::Shifted {
+			inner: ids[inner],
+			offset,
+			block_size,
+			variant,
+		},
+		OracleShape::Packed { inner, log_degree } => OracleShape::Packed {
+			inner: ids[inner],
+			log_degree,
+		},
+		OracleShape::Univariate {
+			inner,
+			log_domain_size,
+		} => OracleShape::Univariate {
+			inner: ids[inner],
+			log_domain_size,
+		},
+		OracleShape::LinearCombination {
+			n_vars,
+			offset,
+			inner,
+		} => OracleShape::LinearCombination {
+			n_vars,
+			offset,
+			inner: inner
+				.into_iter()
+				.map(|(inner, coeff)| (ids[inner], coeff))
+				.collect(),
+		},
+		OracleShape::ZeroPadded { inner, n_vars } => OracleShape::ZeroPadded {
+			inner: ids[inner],
+			n_vars,
+		},
+		OracleShape::Concatenated {
+			inner_n_vars,
+			inner,
+		} => OracleShape::Concatenated {
+			inner_n_vars,
+			inner: inner.into_iter().map(|inner| ids[inner]).collect(),
+		},
+		shape @ (OracleShape::Transparent { .. } | OracleShape::Committed { .. }) => shape,
+	}
+}
+
+pub(super) fn predicate_key<F: TowerField>(predicate: &ConstraintPredicate<F>) -> Option<F> {
+	match predicate {
+		ConstraintPredicate::Sum(sum) => Some(*sum),
+		ConstraintPredicate::Zero => None,
+	}
+}
+
+pub(super) fn flush_key(
+	flush: &Flush,
+	index: impl Fn(OracleId) -> usize,
+) -> (ChannelId, bool, usize, u64, Vec<usize>) {
+	(
+		flush.channel_id,
+		matches!(flush.direction, FlushDirection::Push),
+		flush.count,
+		flush.multiplicity,
+		flush.oracles.iter().map(|&id| index(id)).collect(),
+	)
+}
+
+pub(super) fn multiset<T: Hash + Eq>(items: impl IntoIterator<Item = T>) -> HashMap<T, usize> {
+	let mut counts = HashMap::new();
+	for item in items {
+		*counts.entry(item).or_default() += 1;
+	}
+	counts
+}
+
+/// A monomial, as a sorted list of variables with their non-zero exponents.
+pub(super) type Monomial = Vec<(usize, u64)>;
+
+/// Expands an arithmetic expression into a sorted list of monomials with non-zero coefficients.
+///
+/// Variable `i` of the expression is renamed to `vars[i]`.
+pub(super) fn normalize<F: TowerField>(expr: &ArithExpr<F>, vars: &[usize]) -> Vec<(Monomial, F)> {
+	let mut terms = expand(expr, vars)
+		.into_iter()
+		.filter(|(_, coeff)| *coeff != F::ZERO)
+		.collect::<Vec<_>>();
+	terms.sort_by(|(a, _), (b, _)| a.cmp(b));
+	terms
+}
+
+fn expand<F: TowerField>(expr: &ArithExpr<F>, vars: &[usize]) -> HashMap<Monomial, F> {
+	match expr {
+		ArithExpr::Const(value) => HashMap::from([(Monomial::new(), *value)]),
+		ArithExpr::Var(index) => HashMap::from([(vec![(vars[*index], 1)], F::ONE)]),
+		ArithExpr::Add(left, right) => {
+			let mut terms = expand(left, vars);
+			for (monomial, coeff) in expand(right, vars) {
+				*terms.entry(monomial).or_insert(F::ZERO) += coeff;
+			}
+			terms
+		}
+		ArithExpr::Mul(left, right) => mul_terms(&expand(left, vars), &expand(right, vars)),
+		ArithExpr::Pow(base, exp) => {
+			let base = expand(base, vars);
+			let mut result = HashMap::from([(Monomial::new(), F::ONE)]);
+			for _ in 0..*exp {
+				result = mul_terms(&result, &base);
+			}
+			result
+		}
+	}
+}
+
+fn mul_terms<F: TowerField>(
+	left: &HashMap<Monomial, F>,
+	right: &HashMap<Monomial, F>,
+) -> HashMap<Monomial, F> {
+	let mut terms = HashMap::new();
+	for (left_monomial, left_coeff) in left {
+		for (right_monomial, right_coeff) in right {
+			let mut degrees = left_monomial.iter().copied().collect::<BTreeMap<_, _>>();
+			for &(var, degree) in right_monomial {
+				*degrees.entry(var).or_default() += degree;
+			}
+			*terms
+				.entry(degrees.into_iter().collect::<Monomial>())
+				.or_insert(F::ZERO) += *left_coeff * right_coeff;
+		}
+	}
+	terms
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use binius_field::{BinaryField128b, BinaryField32b, Field};
+
+	use super::*;
+	use crate::{
+		constraint_system::channel::ChannelRegistry,
+		oracle::Constraint,
+		transparent::{constant::Constant, step_down::StepDown},
+	};
+
+	type F = BinaryField128b;
+
+	fn zero_constraint(
+		n_vars: usize,
+		oracle_ids: Vec<OracleId>,
+		composition: ArithExpr<F>,
+	) -> ConstraintSet<F> {
+		ConstraintSet {
+			n_vars,
+			oracle_ids,
+			constraints: vec![Constraint {
+				name: Arc::from("constraint"),
+				composition,
+				predicate: ConstraintPredicate::Zero,
+			}],
+		}
+	}
+
+	/// Builds a system with committed oracles `a` and `b`, a shifted `c = a >> 1` and a
+	/// transparent step-down `s`, and the constraint `(a * b - c) * s = 0`.
+	///
+	/// `reordered` declares the virtual oracles in a different order and writes the constraint in
+	/// a different form.
+	fn build(reordered: bool, extra_constant: bool) -> ConstraintSystem<F> {
+		let mut oracles = MultilinearOracleSet::new();
+		let a = oracles.add_named("a").committed(8, 5);
+		let b = oracles.add_named("b").committed(8, 5);
+
+		let (c, s) = if reordered {
+			let s = oracles
+				.add_transparent(StepDown::new(8, 100).unwrap())
+				.unwrap();
+			let c = oracles
+				.add_named("shifted")
+				.shifted(a, 1, 8, ShiftVariant::LogicalRight)
+				.unwrap();
+			(c, s)
+		} else {
+			let c = oracles
+				.add_shifted(a, 1, 8, ShiftVariant::LogicalRight)
+				.unwrap();
+			let s = oracles
+				.add_transparent(StepDown::new(8, 100).unwrap())
+				.unwrap();
+			(c, s)
+		};
+
+		let table_constraints = if reordered {
+			// s * c + b * a * s in characteristic 2
+			vec![zero_constraint(
+				8,
+				vec![s, c, b, a],
+				ArithExpr::Var(0) * ArithExpr::Var(1)
+					+ ArithExpr::Var(2) * ArithExpr::Var(3) * ArithExpr::Var(0),
+			)]
+		} else {
+			vec![zero_constraint(
+				8,
+				vec![a, b, c, s],
+				(ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2)) * ArithExpr::Var(3),
+			)]
+		};
+
+		let mut non_zero_oracle_ids = vec![];
+		if extra_constant {
+			let one = oracles
+				.add_transparent(Constant::new(8, BinaryField32b::ONE))
+				.unwrap();
+			non_zero_oracle_ids.push(one);
+		}
+
+		ConstraintSystem {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids,
+			flushes: vec![Flush {
+				oracles: vec![a],
+				channel_id: 0,
+				direction: FlushDirection::Push,
+				count: 256,
+				multiplicity: 1,
+			}],
+			matmuls: vec![],
+			max_channel_id: 0,
+			channels: ChannelRegistry::default(),
+		}
+	}
+
+	#[test]
+	fn test_structurally_equal() {
+		assert!(build(false, false).structurally_equal(&build(false, false)));
+		assert!(!build(false, false).structurally_equal(&build(true, false)));
+		assert!(!build(false, false).structurally_equal(&build(false, true)));
+	}
+
+	#[test]
+	fn test_equivalent_up_to_renaming_and_normalization() {
+		build(false, false)
+			.check_equivalent(&build(true, false))
+			.unwrap();
+		build(true, true)
+			.check_equivalent(&build(false, true))
+			.unwrap();
+	}
+
+	#[test]
+	fn test_not_equivalent() {
+		assert!(matches!(
+			build(false, false).check_equivalent(&build(false, true)),
+			Err(EquivalenceError::Oracles)
+		));
+
+		let mut changed = build(true, false);
+		changed.table_constraints[0].constraints[0].composition =
+			ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(2) * ArithExpr::Var(3);
+		assert!(matches!(
+			build(false, false).check_equivalent(&changed),
+			Err(EquivalenceError::Constraints)
+		));
+
+		let mut changed = build(false, false);
+		changed.flushes[0].count = 128;
+		assert!(matches!(
+			build(false, false).check_equivalent(&changed),
+			Err(EquivalenceError::Flushes)
+		));
+	}
+}
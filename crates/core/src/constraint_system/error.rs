@@ -14,43 +14,111 @@ pub enum Error {
 	#[error("flushes must have a non-empty list of oracles")]
 	EmptyFlushOracles,
 
-	#[error("All flushes within a channel must have the same width. Expected flushed values with length {expected}, got {got}")]
-	ChannelFlushWidthMismatch { expected: usize, got: usize },
+	#[error("All flushes within a channel must have the same width. Channel {channel} expected flushed values with length {expected}, got {got}")]
+	ChannelFlushWidthMismatch {
+		channel_id: ChannelId,
+		channel: String,
+		expected: usize,
+		got: usize,
+	},
+
+	#[error("Channel {channel} has arity {expected}, got flushed values with length {got}")]
+	ChannelArityMismatch {
+		channel_id: ChannelId,
+		channel: String,
+		expected: usize,
+		got: usize,
+	},
 
-	#[error("All oracles within a single flush must have the same n_vars. Expected oracle with n_vars={expected} got {got}")]
-	ChannelFlushNvarsMismatch { expected: usize, got: usize },
+	#[error("Channel {channel} is closed and does not accept boundary values")]
+	ChannelUnexpectedBoundary {
+		channel_id: ChannelId,
+		channel: String,
+	},
+
+	#[error("All oracles within a single flush must have the same n_vars. Expected oracle with n_vars={expected} got {got} for oracle {id} flushed into channel {channel_id}")]
+	ChannelFlushNvarsMismatch {
+		channel_id: ChannelId,
+		id: OracleId,
+		expected: usize,
+		got: usize,
+	},
 
 	#[error("Channel id out of range. Got {got}, expected max={max}")]
 	ChannelIdOutOfRange { max: ChannelId, got: ChannelId },
 
-	#[error("{oracle} failed witness validation at index={index}. {reason}")]
+	#[error("{oracle} failed witness validation at index={index}. Expected {expected}, got {got}")]
 	VirtualOracleEvalMismatch {
+		id: OracleId,
 		oracle: String,
 		index: usize,
-		reason: String,
+		expected: String,
+		got: String,
 	},
 
 	#[error("{oracle} witness has unexpected n_vars={witness_num_vars}. Expected n_vars={oracle_num_vars}")]
 	VirtualOracleNvarsMismatch {
+		id: OracleId,
 		oracle: String,
 		oracle_num_vars: usize,
 		witness_num_vars: usize,
 	},
 
-	#[error("cannot flush {count} rows of oracle {id}")]
-	FlushCountExceedsOracleSize { id: OracleId, count: usize },
+	#[error("matrix multiplication shape mismatch: {reason}")]
+	MatMulShapeMismatch { reason: String },
+
+	#[error("cannot flush {count} rows of oracle {id} into channel {channel_id}")]
+	FlushCountExceedsOracleSize {
+		channel_id: ChannelId,
+		id: OracleId,
+		count: usize,
+	},
 
 	#[error("Non-zero oracles contain zeros")]
 	Zeros,
 
+	#[error("proving was cancelled")]
+	Cancelled,
+
+	#[error("interaction out of order, expected {expected}")]
+	InteractionOutOfOrder { expected: &'static str },
+
+	#[error("failed to build the prover thread pool: {0}")]
+	ThreadPoolBuild(#[from] binius_utils::rayon::ThreadPoolBuildError),
+
+	#[error("pinning prover threads to cores is not supported on this platform")]
+	ThreadPinningUnsupported,
+
+	#[error("thread pinning and fold chunk lengths require the rayon feature")]
+	ThreadPoolSettingsUnsupported,
+
+	#[error("the fold chunk length 2^{0} exceeds 2^{max}", max = binius_math::MAX_FOLD_LOG_CHUNK_LEN)]
+	FoldLogChunkLenTooLarge(usize),
+
+	#[error("failed to decompress the advice: {0}")]
+	AdviceDecompression(String),
+
 	#[error("False eq evaluation claim")]
 	FalseEqEvaluationClaim,
 
-	#[error("cannot commit tower level {tower_level}")]
-	CannotCommitTowerLevel { tower_level: usize },
+	#[error("cannot commit oracle {id} with tower level {tower_level}, the maximum is {max}")]
+	CannotCommitTowerLevel {
+		id: OracleId,
+		tower_level: usize,
+		max: usize,
+	},
+
+	#[error("the committed oracles do not match those of the shared commitment")]
+	CommittedOraclesMismatch,
+
+	#[error("the witness of committed oracle {id} differs from the shared commitment")]
+	CommittedWitnessMismatch { id: OracleId },
+
+	#[error("the witness content hash differs from the expected one")]
+	WitnessHashMismatch { expected: Vec<u8>, actual: Vec<u8> },
 
 	#[error("{oracle} underlier witness data does not match")]
-	PackedUnderlierMismatch { oracle: String },
+	PackedUnderlierMismatch { id: OracleId, oracle: String },
 
 	#[error("witness error: {0}")]
 	Witness(#[from] witness::Error),
@@ -101,8 +169,59 @@ pub enum VerificationError {
 	IncorrectNumberOfCommitments,
 	#[error("the number of flush products must equal the number of flushes")]
 	IncorrectNumberOfFlushProducts,
+	#[error("the proof does not open the shared commitment")]
+	SharedCommitmentMismatch,
+	#[error("the proof commits to {got} public input slots, but {expected} were given")]
+	PublicInputCountMismatch { expected: usize, got: usize },
+	#[error("the proof was not generated for the given public inputs")]
+	PublicInputMismatch,
+	#[error("the proof does not contain the messages of the recorded interaction")]
+	InteractionMismatch,
+	#[error("a continuation must have at least one segment")]
+	EmptyContinuation,
+	#[error("segment {segment} does not start in the exit state of the previous segment")]
+	SegmentNotLinked { segment: usize },
+	#[error("the continuation does not start and end in the given states")]
+	ContinuationEndpointMismatch,
 	#[error(
-		"Channel with id={id} is not balanced. Pushes and pulls do not contain the same elements"
+		"Channel {channel} is not balanced. Pushes and pulls do not contain the same elements"
 	)]
-	ChannelUnbalanced { id: ChannelId },
+	ChannelUnbalanced {
+		channel_id: ChannelId,
+		channel: String,
+	},
+}
+
+impl Error {
+	/// The oracle the error refers to, if any.
+	pub fn oracle_id(&self) -> Option<OracleId> {
+		match self {
+			Self::ChannelFlushNvarsMismatch { id, .. }
+			| Self::VirtualOracleEvalMismatch { id, .. }
+			| Self::VirtualOracleNvarsMismatch { id, .. }
+			| Self::FlushCountExceedsOracleSize { id, .. }
+			| Self::CannotCommitTowerLevel { id, .. }
+			| Self::CommittedWitnessMismatch { id }
+			| Self::PackedUnderlierMismatch { id, .. } => Some(*id),
+			_ => None,
+		}
+	}
+
+	/// The channel the error refers to, if any.
+	pub fn channel_id(&self) -> Option<ChannelId> {
+		match self {
+			Self::ChannelFlushWidthMismatch { channel_id, .. }
+			| Self::ChannelArityMismatch { channel_id, .. }
+			| Self::ChannelUnexpectedBoundary { channel_id, .. }
+			| Self::ChannelFlushNvarsMismatch { channel_id, .. }
+			| Self::FlushCountExceedsOracleSize { channel_id, .. }
+			| Self::ChannelIdOutOfRange {
+				got: channel_id, ..
+			}
+			| Self::Verification(VerificationError::ChannelUnbalanced { channel_id, .. }) => {
+				Some(*channel_id)
+			}
+			_ => None,
+		}
+	}
 }
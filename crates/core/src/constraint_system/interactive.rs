@@ -0,0 +1,303 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Interactive execution of the constraint system protocol.
+//!
+//! The protocol is written against a Fiat-Shamir transcript, so interaction is implemented with an
+//! [`ExternalChallenger`], whose randomness is supplied by the verifier. The prover runs
+//! [`super::prove_with_challenger`] on its own thread and [`InteractiveProver`] exposes it as a
+//! state machine, which hands out the prover messages and accepts the challenges in between.
+//!
+//! The protocol is public-coin, so the verifier does not need to check anything until the
+//! interaction is over. [`InteractiveVerifier`] records the messages and challenges, and finally
+//! verifies the proof by replaying the recorded challenges, checking that the proof contains the
+//! exact messages the challenges were sent for.
+//!
+//! The challenges are opaque bytes. For the protocol to be sound, they must be unpredictable to
+//! the prover until it has sent the preceding message, for example sampled by the verifier or by
+//! a randomness beacon after the message was published.
+
+use std::{
+	mem, panic,
+	sync::mpsc::{self, Receiver, Sender},
+	thread::{Scope, ScopedJoinHandle},
+};
+
+use binius_field::{as_packed_field::PackedType, PackedFieldIndexable, RepackedExtension};
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::bail;
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+
+use super::{
+	channel::Boundary,
+	common::FExt,
+	error::{Error, VerificationError},
+	verify::verify_with_challenger,
+	ConstraintSystem, PCSParams, Proof,
+};
+use crate::{
+	fiat_shamir::{ChallengeSource, ExternalChallenger},
+	tower::{PackedTop, TowerFamily, TowerUnderlier},
+};
+
+/// A message of an [`InteractiveProver`].
+#[derive(Debug)]
+pub enum ProverMessage {
+	/// A prover message, which must be answered with a challenge.
+	Message(Vec<u8>),
+	/// The proof, after the last challenge. It contains the prover messages in order, along with
+	/// the advice, which does not influence the challenges and so is only sent at the end.
+	Proof(Proof),
+}
+
+/// The challenge source of a prover run by an [`InteractiveProver`].
+#[derive(Debug)]
+pub struct ProverChannel {
+	messages: Sender<Vec<u8>>,
+	challenges: Receiver<Vec<u8>>,
+}
+
+impl ChallengeSource for ProverChannel {
+	fn challenge(&mut self, message: &[u8]) -> Vec<u8> {
+		// Once the interactive prover is dropped, the proof is discarded and any challenge will do
+		if self.messages.send(message.to_vec()).is_err() {
+			return Vec::new();
+		}
+		self.challenges.recv().unwrap_or_default()
+	}
+}
+
+/// A prover of the constraint system protocol that is driven by externally supplied challenges.
+///
+/// The interaction must alternate calls to [`Self::next_message`] and
+/// [`Self::process_challenge`], until [`Self::next_message`] returns the proof.
+#[derive(Debug)]
+pub struct InteractiveProver<'scope> {
+	messages: Receiver<Vec<u8>>,
+	challenges: Sender<Vec<u8>>,
+	handle: Option<ScopedJoinHandle<'scope, Result<Proof, Error>>>,
+	awaiting_challenge: bool,
+}
+
+impl<'scope> InteractiveProver<'scope> {
+	/// Spawns the prover on a scoped thread.
+	///
+	/// `prove` must prove the statement with an [`ExternalChallenger`] over the given channel,
+	/// typically with [`super::prove_with_challenger`].
+	pub fn spawn<'env, Prove>(scope: &'scope Scope<'scope, 'env>, prove: Prove) -> Self
+	where
+		Prove: FnOnce(ProverChannel) -> Result<Proof, Error> + Send + 'scope,
+	{
+		let (message_sender, messages) = mpsc::channel();
+		let (challenges, challenge_receiver) = mpsc::channel();
+		let channel = ProverChannel {
+			messages: message_sender,
+			challenges: challenge_receiver,
+		};
+		Self {
+			messages,
+			challenges,
+			handle: Some(scope.spawn(move || prove(channel))),
+			awaiting_challenge: false,
+		}
+	}
+
+	/// Waits for the next prover message, or for the proof once the prover has finished.
+	pub fn next_message(&mut self) -> Result<ProverMessage, Error> {
+		if self.awaiting_challenge {
+			bail!(Error::InteractionOutOfOrder {
+				expected: "a challenge"
+			});
+		}
+		if let Ok(message) = self.messages.recv() {
+			self.awaiting_challenge = true;
+			return Ok(ProverMessage::Message(message));
+		}
+
+		// The channel is disconnected once the prover has finished
+		let handle = self.handle.take().ok_or(Error::InteractionOutOfOrder {
+			expected: "no further interaction",
+		})?;
+		match handle.join() {
+			Ok(proof) => Ok(ProverMessage::Proof(proof?)),
+			Err(payload) => panic::resume_unwind(payload),
+		}
+	}
+
+	/// Answers the last prover message with a challenge.
+	pub fn process_challenge(&mut self, challenge: Vec<u8>) -> Result<(), Error> {
+		if !mem::take(&mut self.awaiting_challenge) {
+			bail!(Error::InteractionOutOfOrder {
+				expected: "a prover message"
+			});
+		}
+		// The prover waits for the challenge, so sending only fails if it has panicked, which
+		// the next message reports
+		let _ = self.challenges.send(challenge);
+		Ok(())
+	}
+}
+
+/// A verifier of the constraint system protocol that receives externally supplied challenges.
+///
+/// The interaction must alternate calls to [`Self::process_message`] and
+/// [`Self::process_challenge`], followed by [`Self::finish`] with the proof.
+#[derive(Debug, Default)]
+pub struct InteractiveVerifier {
+	interaction: Vec<(Vec<u8>, Vec<u8>)>,
+	message: Option<Vec<u8>>,
+}
+
+impl InteractiveVerifier {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Receives a prover message.
+	pub fn process_message(&mut self, message: Vec<u8>) -> Result<(), Error> {
+		if self.message.is_some() {
+			bail!(Error::InteractionOutOfOrder {
+				expected: "a challenge"
+			});
+		}
+		self.message = Some(message);
+		Ok(())
+	}
+
+	/// Records the challenge sent in response to the last prover message.
+	pub fn process_challenge(&mut self, challenge: Vec<u8>) -> Result<(), Error> {
+		let message = self.message.take().ok_or(Error::InteractionOutOfOrder {
+			expected: "a prover message",
+		})?;
+		self.interaction.push((message, challenge));
+		Ok(())
+	}
+
+	/// Verifies the proof sent after the last challenge.
+	///
+	/// The challenges are derived with `Hash`, like the challenges of
+	/// [`crate::fiat_shamir::HasherChallenger`].
+	pub fn finish<U, Tower, Hash, Compress>(
+		self,
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		pcs_params: &PCSParams,
+		boundaries: Vec<Boundary<FExt<Tower>>>,
+		proof: Proof,
+	) -> Result<(), Error>
+	where
+		U: TowerUnderlier<Tower>,
+		Tower: TowerFamily,
+		Tower::B128: PackedTop<Tower>,
+		Hash: Digest + BlockSizeUser + FixedOutputReset + Default,
+		Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+		PackedType<U, Tower::B128>:
+			PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+	{
+		if self.message.is_some() {
+			bail!(Error::InteractionOutOfOrder {
+				expected: "a challenge"
+			});
+		}
+
+		let mut interaction = self.interaction.into_iter();
+		let mut diverged = false;
+		let replay = |message: &[u8]| match interaction.next() {
+			Some((expected, challenge)) if expected == message => challenge,
+			_ => {
+				diverged = true;
+				Vec::new()
+			}
+		};
+		verify_with_challenger::<U, Tower, Hash, Compress, _>(
+			constraint_system,
+			pcs_params,
+			boundaries,
+			proof,
+			ExternalChallenger::<Hash, _>::new(replay),
+		)?;
+
+		if diverged || interaction.next().is_some() {
+			bail!(VerificationError::InteractionMismatch);
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::arch::OptimalUnderlier;
+	use binius_hal::make_portable_backend;
+	use binius_hash::compress::Groestl256ByteCompression;
+	use binius_math::DefaultEvaluationDomainFactory;
+	use groestl_crypto::Groestl256;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		constraint_system::{
+			prove_with_challenger,
+			tests::{fixture, pcs_params},
+		},
+		tower::CanonicalTowerFamily,
+	};
+
+	#[test]
+	fn test_prove_verify_interactive() {
+		let (constraint_system, witness, boundaries) = fixture();
+		let mut rng = StdRng::seed_from_u64(0);
+		let (interaction, proof) = std::thread::scope(|scope| {
+			let mut prover = InteractiveProver::spawn(scope, |channel| {
+				prove_with_challenger::<
+					OptimalUnderlier,
+					CanonicalTowerFamily,
+					_,
+					Groestl256,
+					Groestl256ByteCompression,
+					_,
+					_,
+				>(
+					&constraint_system,
+					&pcs_params(),
+					witness,
+					DefaultEvaluationDomainFactory::default(),
+					&make_portable_backend(),
+					ExternalChallenger::<Groestl256, _>::new(channel),
+				)
+			});
+
+			let mut interaction = Vec::new();
+			loop {
+				match prover.next_message().unwrap() {
+					ProverMessage::Message(message) => {
+						let challenge = rng.gen::<[u8; 32]>().to_vec();
+						prover.process_challenge(challenge.clone()).unwrap();
+						interaction.push((message, challenge));
+					}
+					ProverMessage::Proof(proof) => break (interaction, proof),
+				}
+			}
+		});
+		assert!(interaction.len() > 1);
+
+		let verify = |interaction: &[(Vec<u8>, Vec<u8>)]| {
+			let mut verifier = InteractiveVerifier::new();
+			for (message, challenge) in interaction {
+				verifier.process_message(message.clone()).unwrap();
+				verifier.process_challenge(challenge.clone()).unwrap();
+			}
+			verifier
+				.finish::<OptimalUnderlier, CanonicalTowerFamily, Groestl256, Groestl256ByteCompression>(
+					&constraint_system,
+					&pcs_params(),
+					boundaries.clone(),
+					proof.clone(),
+				)
+		};
+		verify(&interaction).unwrap();
+
+		// The proof is only valid for the challenges it was generated with
+		let mut tampered = interaction.clone();
+		tampered[1].1[0] ^= 1;
+		assert!(verify(&tampered).is_err());
+		assert!(verify(&interaction[..interaction.len() - 1]).is_err());
+	}
+}
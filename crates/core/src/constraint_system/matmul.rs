@@ -0,0 +1,238 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Matrix multiplication constraints.
+//!
+//! A constraint $C = A \cdot B$ between an $m \times k$ matrix $A$, a $k \times n$ matrix $B$ and
+//! an $m \times n$ matrix $C$ is proven with a single sumcheck instead of one constraint per
+//! entry. The matrices are multilinears laid out in row-major order, so the column index is given
+//! by the low variables and the row index by the high variables. For random points $r_i$ and
+//! $r_j$ sampled by the verifier,
+//!
+//! $$\tilde{C}(r_j, r_i) = \sum_{v \in B_k} \tilde{A}(v, r_i) \cdot \tilde{B}(r_j, v),$$
+//!
+//! where both factors on the right are projections of $A$ and $B$. The prover sends
+//! $\tilde{C}(r_j, r_i)$, after which the sum is a regular sumcheck over $\log k$ variables whose
+//! final evaluation claims, along with the claim on $C$, are reduced by evalcheck.
+
+use std::cmp::Reverse;
+
+use binius_field::{
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, TowerField,
+};
+use binius_math::{ArithExpr, MLEDirectAdapter, MultilinearPoly, MultilinearQuery};
+use binius_utils::bail;
+
+use super::error::Error;
+use crate::{
+	oracle::{
+		Constraint, ConstraintPredicate, ConstraintSet, MultilinearOracleSet, OracleId,
+		ProjectionVariant,
+	},
+	protocols::evalcheck::EvalcheckMultilinearClaim,
+	witness::MultilinearExtensionIndex,
+};
+
+/// A constraint that the matrix `c` is the product of the matrices `a` and `b`.
+///
+/// `a` has $2^{\text{log\_rows}}$ rows and $2^{\text{log\_inner}}$ columns, `b` has
+/// $2^{\text{log\_inner}}$ rows and $2^{\text{log\_cols}}$ columns, and `c` has
+/// $2^{\text{log\_rows}}$ rows and $2^{\text{log\_cols}}$ columns, all in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatMul {
+	pub a: OracleId,
+	pub b: OracleId,
+	pub c: OracleId,
+	pub log_rows: usize,
+	pub log_inner: usize,
+	pub log_cols: usize,
+}
+
+impl MatMul {
+	/// Checks that the oracle sizes match the matrix dimensions.
+	pub fn validate<F: TowerField>(&self, oracles: &MultilinearOracleSet<F>) -> Result<(), Error> {
+		if self.log_inner == 0 {
+			bail!(Error::MatMulShapeMismatch {
+				reason: "the inner dimension must have at least two entries".into(),
+			});
+		}
+		for (name, id, expected) in [
+			("A", self.a, self.log_rows + self.log_inner),
+			("B", self.b, self.log_inner + self.log_cols),
+			("C", self.c, self.log_rows + self.log_cols),
+		] {
+			if !oracles.is_valid_oracle_id(id) {
+				bail!(Error::MatMulShapeMismatch {
+					reason: format!("{name} refers to the unknown oracle {id}"),
+				});
+			}
+			let n_vars = oracles.n_vars(id);
+			if n_vars != expected {
+				bail!(Error::MatMulShapeMismatch {
+					reason: format!("{name} has n_vars={n_vars}, expected n_vars={expected}"),
+				});
+			}
+		}
+		Ok(())
+	}
+
+	fn n_challenges(&self) -> usize {
+		self.log_rows + self.log_cols
+	}
+}
+
+/// Sorts matrix multiplications in descending order by inner dimension, which is the order of
+/// their sumcheck claims in the batch.
+pub(super) fn sort_matmuls(matmuls: &mut [MatMul]) {
+	matmuls.sort_by_key(|matmul| Reverse(matmul.log_inner));
+}
+
+/// The number of challenges sampled for the evaluation points of the product matrices.
+pub(super) fn n_matmul_challenges(matmuls: &[MatMul]) -> usize {
+	matmuls.iter().map(MatMul::n_challenges).sum()
+}
+
+/// Splits the sampled challenges into the evaluation point of every product matrix.
+fn eval_points<'a, F>(
+	matmuls: &'a [MatMul],
+	challenges: &'a [F],
+) -> impl Iterator<Item = (&'a MatMul, &'a [F])> {
+	matmuls.iter().scan(challenges, |challenges, matmul| {
+		let (point, rest) = challenges.split_at(matmul.n_challenges());
+		*challenges = rest;
+		Some((matmul, point))
+	})
+}
+
+/// Adds the projections of the factor matrices onto the sampled points to the oracle set.
+///
+/// Returns the sumcheck constraint set for every matrix multiplication along with the evalcheck
+/// claims on the product matrices.
+#[allow(clippy::type_complexity)]
+pub(super) fn make_matmul_sumchecks<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	matmuls: &[MatMul],
+	challenges: &[F],
+	product_evals: &[F],
+) -> Result<(Vec<ConstraintSet<F>>, Vec<EvalcheckMultilinearClaim<F>>), Error> {
+	let mut constraint_sets = Vec::with_capacity(matmuls.len());
+	let mut product_eval_claims = Vec::with_capacity(matmuls.len());
+	for ((matmul, point), &eval) in eval_points(matmuls, challenges).zip(product_evals) {
+		let (col_point, row_point) = point.split_at(matmul.log_cols);
+		let a_row = match matmul.log_rows {
+			0 => matmul.a,
+			_ => {
+				oracles.add_projected(matmul.a, row_point.to_vec(), ProjectionVariant::LastVars)?
+			}
+		};
+		let b_col = match matmul.log_cols {
+			0 => matmul.b,
+			_ => {
+				oracles.add_projected(matmul.b, col_point.to_vec(), ProjectionVariant::FirstVars)?
+			}
+		};
+
+		constraint_sets.push(ConstraintSet {
+			n_vars: matmul.log_inner,
+			oracle_ids: vec![a_row, b_col],
+			constraints: vec![Constraint {
+				name: "matmul".into(),
+				composition: ArithExpr::Var(0) * ArithExpr::Var(1),
+				predicate: ConstraintPredicate::Sum(eval),
+			}],
+		});
+		product_eval_claims.push(EvalcheckMultilinearClaim {
+			poly: oracles.oracle(matmul.c),
+			eval_point: point.to_vec().into(),
+			eval,
+		});
+	}
+	Ok((constraint_sets, product_eval_claims))
+}
+
+/// Evaluates the product matrices at the sampled points.
+pub(super) fn product_evals<U, F>(
+	witness: &MultilinearExtensionIndex<U, F>,
+	matmuls: &[MatMul],
+	challenges: &[F],
+) -> Result<Vec<F>, Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	eval_points(matmuls, challenges)
+		.map(|(matmul, point)| {
+			let query = MultilinearQuery::expand(point);
+			Ok(witness
+				.get_multilin_poly(matmul.c)?
+				.evaluate(query.to_ref())?)
+		})
+		.collect()
+}
+
+/// Adds the witnesses of the projections created by [`make_matmul_sumchecks`].
+pub(super) fn make_projection_witnesses<U, F>(
+	oracles: &MultilinearOracleSet<F>,
+	witness: &mut MultilinearExtensionIndex<U, F>,
+	constraint_sets: &[ConstraintSet<F>],
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	let mut projections = Vec::new();
+	for constraint_set in constraint_sets {
+		for &id in &constraint_set.oracle_ids {
+			let crate::oracle::MultilinearPolyOracle::Projected { projected, .. } =
+				oracles.oracle(id)
+			else {
+				continue;
+			};
+			let inner = witness.get_multilin_poly(projected.inner().id())?;
+			let query = MultilinearQuery::expand(projected.values());
+			let projection = match projected.projection_variant() {
+				ProjectionVariant::FirstVars => inner.evaluate_partial_low(query.to_ref())?,
+				ProjectionVariant::LastVars => inner.evaluate_partial_high(query.to_ref())?,
+			};
+			projections.push((id, MLEDirectAdapter::from(projection).upcast_arc_dyn()));
+		}
+	}
+	witness.update_multilin_poly(projections)?;
+	Ok(())
+}
+
+/// Checks that the witnesses of the product matrices are the products of the factor matrices.
+pub(super) fn validate_witness<U, F>(
+	witness: &MultilinearExtensionIndex<U, F>,
+	matmuls: &[MatMul],
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	for matmul in matmuls {
+		let a = witness.get_multilin_poly(matmul.a)?;
+		let b = witness.get_multilin_poly(matmul.b)?;
+		let c = witness.get_multilin_poly(matmul.c)?;
+		for row in 0..1 << matmul.log_rows {
+			for col in 0..1 << matmul.log_cols {
+				let mut expected = F::ZERO;
+				for inner in 0..1 << matmul.log_inner {
+					expected += a.evaluate_on_hypercube(row << matmul.log_inner | inner)?
+						* b.evaluate_on_hypercube(inner << matmul.log_cols | col)?;
+				}
+				let index = row << matmul.log_cols | col;
+				let got = c.evaluate_on_hypercube(index)?;
+				if got != expected {
+					bail!(Error::VirtualOracleEvalMismatch {
+						id: matmul.c,
+						oracle: format!("matmul product {}", matmul.c),
+						index,
+						expected: expected.to_string(),
+						got: got.to_string(),
+					});
+				}
+			}
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,142 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Proof-of-origin metadata attached to a proof.
+//!
+//! Services that route and audit proofs need to know what a proof claims and where it came from
+//! before they run the verifier. A [`ProofMetadata`] set with [`super::ProveOptions::with_metadata`]
+//! is stored in the clear in [`super::Proof::metadata`], and its [`ProofMetadata::frame`] is
+//! absorbed into the Fiat-Shamir state before the first prover message. The verifier absorbs the
+//! frame of the metadata it finds in the proof, so a proof only verifies with the metadata it was
+//! generated with.
+//!
+//! The metadata is not interpreted by the protocol. In particular the verifier does not check that
+//! the circuit digest or public input hash match the constraint system and boundaries it verifies
+//! against, which is up to the application defining them.
+
+use binius_field::{serialize_canonical, TowerField};
+use digest::Digest;
+
+use super::channel::{Boundary, FlushDirection};
+
+/// The tag that starts the frame of proof metadata, see [`ProofMetadata::frame`].
+pub const METADATA_TAG: &[u8] = b"binius-metadata";
+
+/// The version of this crate, the default prover version of [`ProofMetadata`].
+pub const PROVER_VERSION: &str = concat!("binius_core/", env!("CARGO_PKG_VERSION"));
+
+/// Authenticated metadata describing the origin of a proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofMetadata {
+	/// The version of the statement the proof claims, as defined by the application.
+	pub claim_version: u32,
+	/// The name and version of the software that generated the proof.
+	pub prover_version: String,
+	/// A digest identifying the constraint system, as defined by the application.
+	pub circuit_digest: Vec<u8>,
+	/// A hash of the public inputs, for example computed with [`hash_boundaries`].
+	pub public_input_hash: Vec<u8>,
+}
+
+impl ProofMetadata {
+	pub fn new(claim_version: u32) -> Self {
+		Self {
+			claim_version,
+			prover_version: PROVER_VERSION.to_string(),
+			circuit_digest: Vec::new(),
+			public_input_hash: Vec::new(),
+		}
+	}
+
+	pub fn with_prover_version(mut self, prover_version: impl Into<String>) -> Self {
+		self.prover_version = prover_version.into();
+		self
+	}
+
+	pub fn with_circuit_digest(mut self, circuit_digest: impl Into<Vec<u8>>) -> Self {
+		self.circuit_digest = circuit_digest.into();
+		self
+	}
+
+	pub fn with_public_input_hash(mut self, public_input_hash: impl Into<Vec<u8>>) -> Self {
+		self.public_input_hash = public_input_hash.into();
+		self
+	}
+
+	/// The bytes a transcript absorbs into its Fiat-Shamir state for this metadata.
+	///
+	/// The frame consists of [`METADATA_TAG`], the claim version as a little-endian `u32`, and the
+	/// prover version, circuit digest and public input hash, each prefixed with its length as a
+	/// little-endian `u64`. Frames are only absorbed, never written to the transcript tape.
+	pub fn frame(&self) -> Vec<u8> {
+		let mut frame = [METADATA_TAG, &self.claim_version.to_le_bytes()].concat();
+		for field in [
+			self.prover_version.as_bytes(),
+			&self.circuit_digest,
+			&self.public_input_hash,
+		] {
+			frame.extend_from_slice(&(field.len() as u64).to_le_bytes());
+			frame.extend_from_slice(field);
+		}
+		frame
+	}
+}
+
+/// Hashes the boundary values of a statement, to be used as [`ProofMetadata::public_input_hash`].
+///
+/// Every boundary is hashed as its channel ID and multiplicity as little-endian `u64`s, a byte
+/// for its direction, the number of values as a little-endian `u64` and the values in their
+/// canonical encoding.
+pub fn hash_boundaries<H: Digest, F: TowerField>(boundaries: &[Boundary<F>]) -> Vec<u8> {
+	let mut hasher = H::new();
+	for boundary in boundaries {
+		let direction = match boundary.direction {
+			FlushDirection::Push => 0u8,
+			FlushDirection::Pull => 1u8,
+		};
+		let mut bytes = [
+			&(boundary.channel_id as u64).to_le_bytes()[..],
+			&boundary.multiplicity.to_le_bytes(),
+			&[direction],
+			&(boundary.values.len() as u64).to_le_bytes(),
+		]
+		.concat();
+		for &value in &boundary.values {
+			serialize_canonical(value, &mut bytes).expect("Vec<u8> has infinite capacity");
+		}
+		hasher.update(&bytes);
+	}
+	hasher.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+
+	#[test]
+	fn test_frame_separates_fields() {
+		let a = ProofMetadata::new(1).with_circuit_digest(b"ab".to_vec());
+		let b = ProofMetadata::new(1)
+			.with_circuit_digest(b"a".to_vec())
+			.with_public_input_hash(b"b".to_vec());
+		assert_ne!(a.frame(), b.frame());
+		assert!(a.frame().starts_with(METADATA_TAG));
+		assert_ne!(ProofMetadata::new(1).frame(), ProofMetadata::new(2).frame());
+	}
+
+	#[test]
+	fn test_hash_boundaries() {
+		let boundary = |multiplicity| Boundary {
+			values: vec![BinaryField128b::new(7)],
+			channel_id: 0,
+			direction: FlushDirection::Push,
+			multiplicity,
+		};
+		let hash = hash_boundaries::<Groestl256, _>(&[boundary(1)]);
+		assert_eq!(hash.len(), 32);
+		assert_eq!(hash, hash_boundaries::<Groestl256, _>(&[boundary(1)]));
+		assert_ne!(hash, hash_boundaries::<Groestl256, _>(&[boundary(2)]));
+	}
+}
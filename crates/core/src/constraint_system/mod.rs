@@ -1,23 +1,65 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+pub mod bundle;
 pub mod channel;
+mod commitment_scheme;
 mod common;
+pub mod continuation;
+pub mod cost;
+pub mod diff;
+pub mod disable;
+mod encoding;
+pub mod equivalence;
 pub mod error;
+pub mod interactive;
+mod matmul;
+pub mod metadata;
+mod options;
+pub mod phase;
+mod prepared;
+pub mod profile;
 mod prove;
+mod public_input;
+mod shape;
+mod shared;
+pub mod soundness;
+#[cfg(test)]
+mod tests;
 pub mod validate;
 mod verify;
 
 use binius_field::TowerField;
-use channel::{ChannelId, Flush};
-pub use prove::prove;
-pub use verify::verify;
+pub use bundle::Bundle;
+use channel::{ChannelId, ChannelRegistry, Flush};
+pub use commitment_scheme::{CommitmentScheme, FRISetup, TrivialPCS, FRIPCS};
+pub use encoding::{AdviceCompression, ProofEncoding, MAX_DECOMPRESSED_ADVICE_SIZE};
+pub use matmul::MatMul;
+pub use metadata::ProofMetadata;
+pub use options::{
+	CancellationToken, ConstraintSetShape, ProveOptions, RoundEvalSelector, ThreadPoolConfig,
+};
+pub use prepared::PreparedConstraintSystem;
+pub use prove::{
+	prove, prove_streaming, prove_with_challenger, prove_with_options, prove_with_pcs,
+};
+pub use public_input::{PublicInputs, PUBLIC_INPUT_TAG};
+pub use shared::{verify_shared, SharedCommitment};
+#[cfg(feature = "diagnostics")]
+pub use verify::verify_prepared_with_diagnostics;
+pub use verify::{
+	public_inputs_match, verify, verify_isomorphic, verify_prepared,
+	verify_prepared_with_advice_layout, verify_prepared_with_public_inputs, verify_with_challenger,
+	verify_with_pcs, verify_with_public_inputs,
+};
 
-use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
+use crate::oracle::{ConstraintSet, Error as OracleError, MultilinearOracleSet, OracleId};
+pub use crate::piop::{PCSParams, PCSPreset};
 
-/// Contains the 3 things that place constraints on witness data in Binius
+/// Contains the things that place constraints on witness data in Binius
 /// - virtual oracles
 /// - polynomial constraints
 /// - channel flushes
+/// - matrix multiplications
 ///
 /// As a result, a ConstraintSystem allows us to validate all of these
 /// constraints against a witness, as well as enabling generic prove/verify
@@ -27,7 +69,9 @@ pub struct ConstraintSystem<F: TowerField> {
 	pub table_constraints: Vec<ConstraintSet<F>>,
 	pub non_zero_oracle_ids: Vec<OracleId>,
 	pub flushes: Vec<Flush>,
+	pub matmuls: Vec<MatMul>,
 	pub max_channel_id: ChannelId,
+	pub channels: ChannelRegistry,
 }
 
 impl<F: TowerField> ConstraintSystem<F> {
@@ -37,9 +81,35 @@ impl<F: TowerField> ConstraintSystem<F> {
 			table_constraints: self.table_constraints,
 			non_zero_oracle_ids: self.non_zero_oracle_ids,
 			flushes: self.flushes,
+			matmuls: self.matmuls,
 			max_channel_id: self.max_channel_id,
+			channels: self.channels,
 		}
 	}
+
+	/// Maps the constraint system to an isomorphic tower field.
+	///
+	/// Oracle IDs and channel layout are preserved, so a witness or proof for the converted
+	/// constraint system corresponds to one for the original.
+	pub fn isomorphic<FI>(&self) -> Result<ConstraintSystem<FI>, OracleError>
+	where
+		F: From<FI>,
+		FI: TowerField + From<F>,
+	{
+		Ok(ConstraintSystem {
+			oracles: self.oracles.isomorphic()?,
+			table_constraints: self
+				.table_constraints
+				.iter()
+				.map(ConstraintSet::isomorphic)
+				.collect(),
+			non_zero_oracle_ids: self.non_zero_oracle_ids.clone(),
+			flushes: self.flushes.clone(),
+			matmuls: self.matmuls.clone(),
+			max_channel_id: self.max_channel_id,
+			channels: self.channels.clone(),
+		})
+	}
 }
 
 /// Constraint system proof that has been serialized into bytes
@@ -47,6 +117,17 @@ impl<F: TowerField> ConstraintSystem<F> {
 pub struct Proof {
 	pub transcript: Vec<u8>,
 	pub advice: Vec<u8>,
+	pub encoding: ProofEncoding,
+	/// Proof-of-origin metadata, absorbed into the transcript ahead of the first prover message.
+	pub metadata: Option<ProofMetadata>,
+	/// The number of public input slots the proof commits to, see [`PublicInputs`].
+	pub n_public_inputs: usize,
+	/// The phases of the transcript with their start positions, as recorded by the prover.
+	///
+	/// When not empty, the verifier checks that it begins the same phases at the same positions,
+	/// and [`Proof::to_bytes`] splits the transcript into one section per phase. Proofs generated
+	/// with [`ProveOptions::with_legacy_format`] leave it empty.
+	pub phases: Vec<(String, usize)>,
 }
 
 impl Proof {
@@ -0,0 +1,259 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{
+	fmt,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+use binius_hal::RoundEvalStrategy;
+use binius_math::{set_fold_log_chunk_len, MAX_FOLD_LOG_CHUNK_LEN};
+use binius_utils::rayon::{pin_current_thread, ThreadPool, ThreadPoolBuilder, PARALLEL};
+
+use super::{error::Error, profile::ProverProfiler, ProofEncoding, ProofMetadata, PublicInputs};
+
+/// A shared flag used to request cooperative cancellation of a running prover.
+///
+/// Clones share the same flag. The prover checks the flag between protocol phases and between the
+/// rounds of the sumcheck, GKR and FRI protocols, so after [`Self::cancel`] is called it stops
+/// within the current round and returns [`Error::Cancelled`].
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn cancel(&self) {
+		self.0.store(true, Ordering::Relaxed);
+	}
+
+	pub fn is_cancelled(&self) -> bool {
+		self.0.load(Ordering::Relaxed)
+	}
+
+	pub(super) fn check(&self) -> Result<(), Error> {
+		if self.is_cancelled() {
+			return Err(Error::Cancelled);
+		}
+		Ok(())
+	}
+}
+
+/// The dimensions of a table constraint set, used to pick its sumcheck round evaluation strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstraintSetShape {
+	/// The number of variables of the constraint set, i.e. the binary logarithm of its row count.
+	pub n_vars: usize,
+	/// The number of multilinears the constraints are composed of.
+	pub n_multilinears: usize,
+	/// The maximum degree of the constraint compositions.
+	pub max_degree: usize,
+	/// The number of constraints in the set.
+	pub n_constraints: usize,
+}
+
+/// Picks the sumcheck round evaluation strategy for every table constraint set.
+///
+/// The strategy only affects prover performance, the proof is the same for all of them.
+#[derive(Clone)]
+pub struct RoundEvalSelector(Arc<dyn Fn(ConstraintSetShape) -> RoundEvalStrategy + Send + Sync>);
+
+impl RoundEvalSelector {
+	pub fn new(
+		select: impl Fn(ConstraintSetShape) -> RoundEvalStrategy + Send + Sync + 'static,
+	) -> Self {
+		Self(Arc::new(select))
+	}
+
+	pub fn select(&self, shape: ConstraintSetShape) -> RoundEvalStrategy {
+		(self.0)(shape)
+	}
+}
+
+impl Default for RoundEvalSelector {
+	fn default() -> Self {
+		Self::new(|_| RoundEvalStrategy::default())
+	}
+}
+
+impl fmt::Debug for RoundEvalSelector {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("RoundEvalSelector").finish_non_exhaustive()
+	}
+}
+
+/// Settings of a thread pool dedicated to the prover.
+///
+/// A prover running inside a server process otherwise shares the global rayon pool with the rest
+/// of the process, and its bursts of parallel work delay everything else scheduled on the pool.
+///
+/// Without the `rayon` feature of [`binius_utils::rayon`] the prover runs on the calling thread, so
+/// there are no threads to pin or configure, and [`Self::build`] rejects [`Self::pin_to_cores`]
+/// and [`Self::fold_log_chunk_len`].
+#[derive(Debug, Clone, Default)]
+pub struct ThreadPoolConfig {
+	/// The number of threads, by default the number of logical CPUs.
+	pub num_threads: Option<usize>,
+	/// CPU cores to pin the threads to, thread `i` runs on core `i % pin_to_cores.len()`.
+	///
+	/// Pinning is only supported on Linux.
+	pub pin_to_cores: Option<Vec<usize>>,
+	/// The binary logarithm of the number of packed elements a fold task processes, see
+	/// [`binius_math::set_fold_log_chunk_len`]. Larger chunks keep the memory of each thread
+	/// contiguous on NUMA machines. At most [`MAX_FOLD_LOG_CHUNK_LEN`].
+	pub fold_log_chunk_len: Option<usize>,
+}
+
+impl ThreadPoolConfig {
+	pub fn with_num_threads(mut self, num_threads: usize) -> Self {
+		self.num_threads = Some(num_threads);
+		self
+	}
+
+	pub fn with_pinned_cores(mut self, cores: impl IntoIterator<Item = usize>) -> Self {
+		self.pin_to_cores = Some(cores.into_iter().collect());
+		self
+	}
+
+	pub fn with_fold_log_chunk_len(mut self, log_chunk_len: usize) -> Self {
+		self.fold_log_chunk_len = Some(log_chunk_len);
+		self
+	}
+
+	/// Builds the thread pool.
+	///
+	/// A thread that fails to pin itself logs a warning and keeps running unpinned.
+	pub fn build(&self) -> Result<ThreadPool, Error> {
+		if !PARALLEL && (self.pin_to_cores.is_some() || self.fold_log_chunk_len.is_some()) {
+			return Err(Error::ThreadPoolSettingsUnsupported);
+		}
+		if self.pin_to_cores.is_some() && !cfg!(target_os = "linux") {
+			return Err(Error::ThreadPinningUnsupported);
+		}
+		if let Some(log_chunk_len) = self.fold_log_chunk_len {
+			if log_chunk_len > MAX_FOLD_LOG_CHUNK_LEN {
+				return Err(Error::FoldLogChunkLenTooLarge(log_chunk_len));
+			}
+		}
+
+		let mut builder =
+			ThreadPoolBuilder::new().thread_name(|index| format!("binius-prover-{index}"));
+		if let Some(num_threads) = self.num_threads {
+			builder = builder.num_threads(num_threads);
+		}
+
+		let pin_to_cores = self.pin_to_cores.clone().filter(|cores| !cores.is_empty());
+		let fold_log_chunk_len = self.fold_log_chunk_len;
+		let builder = builder.start_handler(move |index| {
+			if let Some(cores) = &pin_to_cores {
+				let core = cores[index % cores.len()];
+				if let Err(err) = pin_current_thread(core) {
+					tracing::warn!(thread = index, core, %err, "failed to pin prover thread");
+				}
+			}
+			if let Some(log_chunk_len) = fold_log_chunk_len {
+				set_fold_log_chunk_len(log_chunk_len)
+					.expect("the chunk length is checked before building the pool");
+			}
+		});
+		Ok(builder.build()?)
+	}
+}
+
+/// Options controlling how [`super::prove_with_options`] runs the prover.
+#[derive(Debug, Clone, Default)]
+pub struct ProveOptions {
+	/// Token checked between protocol phases and protocol rounds to abort the proof early.
+	pub cancellation: Option<CancellationToken>,
+	/// Thread pool to run all parallel work of the prover on, instead of the global rayon pool.
+	pub thread_pool: Option<Arc<ThreadPool>>,
+	/// Encoding of the produced proof.
+	pub encoding: ProofEncoding,
+	/// Selects the sumcheck round evaluation strategy of each table constraint set's zerocheck.
+	pub round_eval_strategy: RoundEvalSelector,
+	/// Guarantees byte-identical proofs for the same witness across runs and thread counts.
+	///
+	/// Field arithmetic is exact, so parallel reductions give the same result in any order, and
+	/// the prover only collects parallel results in input order. In deterministic mode it also
+	/// runs the steps whose scheduling would otherwise vary with the thread count, such as
+	/// evalcheck query memoization, in a fixed order.
+	pub deterministic: bool,
+	/// Proof-of-origin metadata to attach to the proof and bind to its transcript.
+	pub metadata: Option<ProofMetadata>,
+	/// Public inputs to bind to the transcript, which the verifier must be given to accept.
+	pub public_inputs: PublicInputs,
+	/// Profiler to report the time spent proving each table constraint set to.
+	pub profiler: Option<ProverProfiler>,
+	/// Content hash of the witness, see [`crate::witness::MultilinearExtensionIndex::content_hash`],
+	/// computed with the prover's hash function. The prover checks it before starting and fails
+	/// with [`Error::WitnessHashMismatch`] if the witness differs, which catches nondeterministic
+	/// trace generation before a proof of the wrong witness is computed.
+	pub expected_witness_hash: Option<Vec<u8>>,
+	/// Leaves [`super::Proof::phases`] empty, as in proofs generated before the phases were
+	/// recorded, for consumers that compare proofs with the old format.
+	pub legacy_format: bool,
+}
+
+impl ProveOptions {
+	pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+		self.cancellation = Some(cancellation);
+		self
+	}
+
+	pub fn with_thread_pool(mut self, thread_pool: Arc<ThreadPool>) -> Self {
+		self.thread_pool = Some(thread_pool);
+		self
+	}
+
+	/// Runs the prover on a new thread pool built from `config`.
+	pub fn with_dedicated_thread_pool(self, config: &ThreadPoolConfig) -> Result<Self, Error> {
+		Ok(self.with_thread_pool(Arc::new(config.build()?)))
+	}
+
+	pub fn with_encoding(mut self, encoding: ProofEncoding) -> Self {
+		self.encoding = encoding;
+		self
+	}
+
+	pub fn with_metadata(mut self, metadata: ProofMetadata) -> Self {
+		self.metadata = Some(metadata);
+		self
+	}
+
+	pub fn with_public_inputs(mut self, public_inputs: PublicInputs) -> Self {
+		self.public_inputs = public_inputs;
+		self
+	}
+
+	pub fn with_round_eval_strategy(
+		mut self,
+		select: impl Fn(ConstraintSetShape) -> RoundEvalStrategy + Send + Sync + 'static,
+	) -> Self {
+		self.round_eval_strategy = RoundEvalSelector::new(select);
+		self
+	}
+
+	pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+		self.deterministic = deterministic;
+		self
+	}
+
+	pub fn with_profiler(mut self, profiler: ProverProfiler) -> Self {
+		self.profiler = Some(profiler);
+		self
+	}
+
+	pub fn with_expected_witness_hash(mut self, hash: impl Into<Vec<u8>>) -> Self {
+		self.expected_witness_hash = Some(hash.into());
+		self
+	}
+
+	pub fn with_legacy_format(mut self) -> Self {
+		self.legacy_format = true;
+		self
+	}
+}
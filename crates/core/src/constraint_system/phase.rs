@@ -0,0 +1,128 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Labels of the phases of a constraint system proof, in the order they appear in the transcript.
+//!
+//! The prover and verifier begin every phase with [`crate::transcript::CanWrite::begin_phase`] and
+//! [`crate::transcript::CanRead::begin_phase`], which absorbs the label into the Fiat-Shamir state.
+//! The public input phase is only begun by proofs with public inputs, and the matrix
+//! multiplication phase only by systems that have matrix multiplications.
+//!
+//! The prover also runs every phase inside a `debug` level `tracing` span named after its label,
+//! with fields describing the size of the phase, and emits a summary event with the phase timings
+//! at the end of the proof.
+
+use std::time::{Duration, Instant};
+
+use tracing::{span::EnteredSpan, Span};
+
+/// The check value binding the public inputs, see [`super::PublicInputs`].
+pub const PUBLIC_INPUT: &str = "public_input";
+/// The commitment to the committed oracles.
+pub const COMMITMENT: &str = "commitment";
+/// The grand products of the non-zero checks and channel flushes and their GKR proofs.
+pub const GRAND_PRODUCT: &str = "grand_product";
+/// The sumcheck reducing the flush grand product claims to evaluation claims.
+pub const FLUSH_SUMCHECK: &str = "flush_sumcheck";
+/// The zerocheck of the table constraints and its univariatizing reduction.
+pub const ZEROCHECK: &str = "zerocheck";
+/// The products of the matrix multiplications and their sumchecks.
+pub const MATMUL: &str = "matmul";
+/// The evalcheck reducing all evaluation claims to claims on committed oracles.
+pub const EVALCHECK: &str = "evalcheck";
+/// The ring-switching reduction of the committed evaluation claims.
+pub const RING_SWITCH: &str = "ring_switch";
+/// The opening proof of the polynomial commitment scheme.
+pub const PCS_OPENING: &str = "pcs_opening";
+
+/// Times the phases of a proof, each inside a `tracing` span carrying the phase's metadata.
+///
+/// [`Self::finish`] emits a summary event with the duration of every phase in microseconds,
+/// which is zero for phases that did not run.
+pub(super) struct PhaseTimer {
+	start: Instant,
+	current: Option<(&'static str, Instant, EnteredSpan)>,
+	timings: Vec<(&'static str, Duration)>,
+	witness_memory: Vec<(&'static str, usize)>,
+}
+
+impl PhaseTimer {
+	pub fn new() -> Self {
+		Self {
+			start: Instant::now(),
+			current: None,
+			timings: Vec::new(),
+			witness_memory: Vec::new(),
+		}
+	}
+
+	/// Records the bytes of witness columns held at the start of the current phase.
+	pub fn record_witness_memory(&mut self, bytes: usize) {
+		if let Some((phase, ..)) = self.current {
+			self.witness_memory.push((phase, bytes));
+		}
+	}
+
+	/// The witness memory recorded with [`Self::record_witness_memory`], in the order the phases
+	/// ran.
+	pub fn witness_memory(&self) -> &[(&'static str, usize)] {
+		&self.witness_memory
+	}
+
+	/// Ends the current phase and enters `span` for the phase with the given label.
+	pub fn begin(&mut self, phase: &'static str, span: Span) {
+		self.end_current();
+		self.current = Some((phase, Instant::now(), span.entered()));
+	}
+
+	fn end_current(&mut self) {
+		if let Some((phase, start, span)) = self.current.take() {
+			self.timings.push((phase, start.elapsed()));
+			drop(span);
+		}
+	}
+
+	fn micros(&self, phase: &str) -> u128 {
+		self.timings
+			.iter()
+			.filter(|(label, _)| *label == phase)
+			.map(|(_, duration)| duration.as_micros())
+			.sum()
+	}
+
+	/// Ends the last phase, emits the summary event and returns the phases in the order they ran.
+	pub fn finish(mut self) -> Vec<(&'static str, Duration)> {
+		self.end_current();
+		tracing::debug!(
+			total_us = self.start.elapsed().as_micros(),
+			commitment_us = self.micros(COMMITMENT),
+			grand_product_us = self.micros(GRAND_PRODUCT),
+			flush_sumcheck_us = self.micros(FLUSH_SUMCHECK),
+			zerocheck_us = self.micros(ZEROCHECK),
+			matmul_us = self.micros(MATMUL),
+			evalcheck_us = self.micros(EVALCHECK),
+			ring_switch_us = self.micros(RING_SWITCH),
+			pcs_opening_us = self.micros(PCS_OPENING),
+			"proof phase timings"
+		);
+		self.timings
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_phase_timer_records_phases_in_order() {
+		let mut timer = PhaseTimer::new();
+		timer.begin(COMMITMENT, tracing::debug_span!("commitment", n_polys = 3));
+		timer.begin(ZEROCHECK, tracing::debug_span!("zerocheck", n_vars = 10));
+		timer.begin(PCS_OPENING, tracing::debug_span!("pcs_opening"));
+		let phases = timer
+			.finish()
+			.into_iter()
+			.map(|(phase, _)| phase)
+			.collect::<Vec<_>>();
+		assert_eq!(phases, [COMMITMENT, ZEROCHECK, PCS_OPENING]);
+	}
+}
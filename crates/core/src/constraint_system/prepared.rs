@@ -0,0 +1,153 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::cmp::Reverse;
+
+use binius_field::BinaryField;
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::sparse_index::SparseIndex;
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+use tracing::instrument;
+
+use super::{
+	channel::{ChannelId, ChannelRegistry, Flush},
+	common::{FDomain, FExt},
+	error::Error,
+	matmul::{sort_matmuls, MatMul},
+	verify::max_n_vars_and_skip_rounds,
+	CommitmentScheme, ConstraintSystem, FRIPCS,
+};
+use crate::{
+	oracle::{MultilinearOracleSet, OracleId},
+	piop::{self, CommitMeta, PCSParams},
+	polynomial::ArithCircuitPoly,
+	protocols::sumcheck::{constraint_set_zerocheck_claim, OracleClaimMeta, ZerocheckClaim},
+	tower::TowerFamily,
+};
+
+/// A constraint system with all proof-independent verifier state computed ahead of time.
+///
+/// Verifying a proof with [`super::verify`] first derives a number of structures from the
+/// constraint system that only depend on the constraint system and the security parameters: the
+/// commitment layout and the setup of the polynomial commitment scheme, the zerocheck claims for the table constraints with their
+/// compiled compositions, and the univariate skip parameters derived from the constraint degrees.
+/// This type computes them once, so that services verifying many proofs of the same circuit can
+/// use [`super::verify_prepared`] and skip that setup for every proof.
+#[derive(Debug)]
+pub struct PreparedConstraintSystem<Tower: TowerFamily, PCS: CommitmentScheme<Tower>> {
+	pub(super) oracles: MultilinearOracleSet<FExt<Tower>>,
+	pub(super) non_zero_oracle_ids: Vec<OracleId>,
+	/// Flushes sorted by channel ID.
+	pub(super) flushes: Vec<Flush>,
+	/// Matrix multiplications in descending order by inner dimension.
+	pub(super) matmuls: Vec<MatMul>,
+	pub(super) max_channel_id: ChannelId,
+	pub(super) channels: ChannelRegistry,
+	pub(super) pcs: PCS,
+	pub(super) pcs_setup: PCS::Setup,
+	pub(super) commit_meta: CommitMeta,
+	pub(super) oracle_to_commit_index: SparseIndex<usize>,
+	/// Zerocheck claims for the table constraints, in descending order by number of variables.
+	pub(super) zerocheck_claims: Vec<ZerocheckClaim<FExt<Tower>, ArithCircuitPoly<FExt<Tower>>>>,
+	pub(super) zerocheck_oracle_metas: Vec<OracleClaimMeta>,
+	pub(super) max_n_vars: usize,
+	pub(super) skip_rounds: usize,
+}
+
+impl<Tower, Hash, Compress> PreparedConstraintSystem<Tower, FRIPCS<Hash, Compress>>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	/// Prepares a constraint system for verification with the FRI polynomial commitment scheme.
+	pub fn new(
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		pcs_params: &PCSParams,
+	) -> Result<Self, Error> {
+		Self::with_pcs(constraint_system, FRIPCS::new(*pcs_params))
+	}
+
+	/// The polynomial commitment parameters the system was prepared with.
+	pub fn pcs_params(&self) -> &PCSParams {
+		self.pcs.params()
+	}
+
+	/// The negative binary logarithm of the Reed–Solomon code rate the system was prepared with.
+	pub fn log_inv_rate(&self) -> usize {
+		self.pcs_params().log_inv_rate
+	}
+
+	/// The target security level the system was prepared with.
+	pub fn security_bits(&self) -> usize {
+		self.pcs_params().security_bits
+	}
+}
+
+impl<Tower, PCS> PreparedConstraintSystem<Tower, PCS>
+where
+	Tower: TowerFamily,
+	PCS: CommitmentScheme<Tower>,
+{
+	/// Prepares a constraint system for verification with the given polynomial commitment scheme.
+	#[instrument("constraint_system::prepare", skip_all, level = "debug")]
+	pub fn with_pcs(
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		pcs: PCS,
+	) -> Result<Self, Error> {
+		let ConstraintSystem {
+			oracles,
+			mut table_constraints,
+			mut flushes,
+			mut matmuls,
+			non_zero_oracle_ids,
+			max_channel_id,
+			channels,
+		} = constraint_system.clone();
+
+		oracles.check_n_vars()?;
+		channels.validate_flushes(&flushes)?;
+		for matmul in &matmuls {
+			matmul.validate(&oracles)?;
+		}
+
+		// Stable sort constraint sets in descending order by number of variables.
+		table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
+		flushes.sort_by_key(|flush| flush.channel_id);
+		sort_matmuls(&mut matmuls);
+
+		let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+		let pcs_setup = pcs.setup(&commit_meta)?;
+
+		let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
+			.into_iter()
+			.map(constraint_set_zerocheck_claim)
+			.collect::<Result<Vec<_>, _>>()?
+			.into_iter()
+			.unzip::<_, _, Vec<_>, Vec<_>>();
+
+		let (max_n_vars, skip_rounds) =
+			max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
+
+		Ok(Self {
+			oracles,
+			non_zero_oracle_ids,
+			flushes,
+			matmuls,
+			max_channel_id,
+			channels,
+			pcs,
+			pcs_setup,
+			commit_meta,
+			oracle_to_commit_index,
+			zerocheck_claims,
+			zerocheck_oracle_metas,
+			max_n_vars,
+			skip_rounds,
+		})
+	}
+
+	/// The polynomial commitment scheme the system was prepared with.
+	pub fn pcs(&self) -> &PCS {
+		&self.pcs
+	}
+}
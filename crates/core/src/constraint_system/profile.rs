@@ -0,0 +1,197 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Attribution of prover time to table constraint sets.
+//!
+//! The zerocheck batches the provers of all table constraint sets into a single sumcheck, so the
+//! duration of its phase does not tell which constraints are expensive to prove. When the
+//! [`super::ProveOptions`] of a proof carry a [`ProverProfiler`], the prover times every call into
+//! the zerocheck prover of each constraint set, including its univariatizing reduction, and
+//! records the totals next to the durations of the phases. Circuit builders map the constraint
+//! sets back to the parts of the circuit they were built from.
+//!
+//! The profile also records the memory of the witness columns the prover holds at the start of
+//! every phase, which shows where the prover frees columns after their last use.
+
+use std::{
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc, Mutex,
+	},
+	time::{Duration, Instant},
+};
+
+use binius_field::Field;
+
+use crate::protocols::sumcheck::{
+	prove::{SumcheckProver, UnivariateZerocheckProver},
+	univariate::LagrangeRoundEvals,
+	Error, RoundCoeffs,
+};
+
+/// The prover time and witness memory of a proof, recorded by a [`ProverProfiler`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProverProfile {
+	/// The time spent in the zerocheck prover of each table constraint set, in the order of
+	/// [`super::ConstraintSystem::table_constraints`].
+	pub constraint_sets: Vec<Duration>,
+	/// The duration of every phase of the proof, in the order the phases ran.
+	pub phases: Vec<(&'static str, Duration)>,
+	/// The number of bytes of the witness columns the prover held at the start of every phase, in
+	/// the order the phases ran.
+	///
+	/// Columns shared between several oracles are counted once. Columns that are computed on
+	/// demand are only counted while the prover holds them.
+	pub witness_memory: Vec<(&'static str, usize)>,
+}
+
+impl ProverProfile {
+	/// The total duration of the phase with the given label, see [`super::phase`].
+	pub fn phase(&self, phase: &str) -> Duration {
+		self.phases
+			.iter()
+			.filter(|(label, _)| *label == phase)
+			.map(|(_, duration)| *duration)
+			.sum()
+	}
+
+	/// The largest number of bytes of witness columns held at the start of a phase.
+	pub fn peak_witness_memory(&self) -> usize {
+		self.witness_memory
+			.iter()
+			.map(|&(_, bytes)| bytes)
+			.max()
+			.unwrap_or(0)
+	}
+}
+
+/// A handle to which the prover reports the [`ProverProfile`] of a proof.
+///
+/// Clones share the same profile, so a clone passed to the prover through
+/// [`super::ProveOptions::with_profiler`] can be read from after the proof.
+#[derive(Debug, Clone, Default)]
+pub struct ProverProfiler(Arc<Mutex<Option<ProverProfile>>>);
+
+impl ProverProfiler {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The profile of the last completed proof run with this profiler, if any.
+	pub fn profile(&self) -> Option<ProverProfile> {
+		self.0.lock().expect("profile lock is not poisoned").clone()
+	}
+
+	pub(super) fn record(&self, profile: ProverProfile) {
+		*self.0.lock().expect("profile lock is not poisoned") = Some(profile);
+	}
+}
+
+/// Accumulates the time spent in the calls it measures, across clones.
+#[derive(Debug, Clone, Default)]
+pub(super) struct Stopwatch(Arc<AtomicU64>);
+
+impl Stopwatch {
+	fn time<R>(&self, f: impl FnOnce() -> R) -> R {
+		let start = Instant::now();
+		let result = f();
+		self.0
+			.fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+		result
+	}
+
+	pub fn elapsed(&self) -> Duration {
+		Duration::from_nanos(self.0.load(Ordering::Relaxed))
+	}
+}
+
+/// A sumcheck prover whose calls are measured by a [`Stopwatch`].
+pub(super) struct Timed<P> {
+	inner: P,
+	stopwatch: Stopwatch,
+}
+
+impl<P> Timed<P> {
+	pub fn new(inner: P, stopwatch: Stopwatch) -> Self {
+		Self { inner, stopwatch }
+	}
+}
+
+impl<F: Field, P: SumcheckProver<F>> SumcheckProver<F> for Timed<P> {
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn execute(&mut self, batch_coeff: F) -> Result<RoundCoeffs<F>, Error> {
+		self.stopwatch.time(|| self.inner.execute(batch_coeff))
+	}
+
+	fn fold(&mut self, challenge: F) -> Result<(), Error> {
+		self.stopwatch.time(|| self.inner.fold(challenge))
+	}
+
+	fn finish(self: Box<Self>) -> Result<Vec<F>, Error> {
+		let Self { inner, stopwatch } = *self;
+		stopwatch.time(|| Box::new(inner).finish())
+	}
+}
+
+impl<'a, F, P> UnivariateZerocheckProver<'a, F> for Timed<P>
+where
+	F: Field,
+	P: UnivariateZerocheckProver<'a, F>,
+{
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn domain_size(&self, skip_rounds: usize) -> usize {
+		self.inner.domain_size(skip_rounds)
+	}
+
+	fn execute_univariate_round(
+		&mut self,
+		skip_rounds: usize,
+		max_domain_size: usize,
+		batch_coeff: F,
+	) -> Result<LagrangeRoundEvals<F>, Error> {
+		self.stopwatch.time(|| {
+			self.inner
+				.execute_univariate_round(skip_rounds, max_domain_size, batch_coeff)
+		})
+	}
+
+	fn fold_univariate_round(
+		self: Box<Self>,
+		challenge: F,
+	) -> Result<Box<dyn SumcheckProver<F> + 'a>, Error> {
+		let Self { inner, stopwatch } = *self;
+		let folded = stopwatch.time(|| Box::new(inner).fold_univariate_round(challenge))?;
+		Ok(Box::new(Timed::new(folded, stopwatch)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_profile_phase_sums_repeated_phases() {
+		let profile = ProverProfile {
+			constraint_sets: vec![Duration::from_millis(3)],
+			phases: vec![
+				("zerocheck", Duration::from_millis(2)),
+				("evalcheck", Duration::from_millis(5)),
+				("zerocheck", Duration::from_millis(1)),
+			],
+			witness_memory: vec![("zerocheck", 4096), ("evalcheck", 1024)],
+		};
+		assert_eq!(profile.phase("zerocheck"), Duration::from_millis(3));
+		assert_eq!(profile.phase("matmul"), Duration::ZERO);
+		assert_eq!(profile.peak_witness_memory(), 4096);
+
+		let profiler = ProverProfiler::new();
+		assert_eq!(profiler.profile(), None);
+		profiler.clone().record(profile.clone());
+		assert_eq!(profiler.profile(), Some(profile));
+	}
+}
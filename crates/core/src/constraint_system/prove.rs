@@ -1,43 +1,48 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cmp::Reverse, env, marker::PhantomData};
+use std::{
+	cmp::Reverse, collections::HashSet, env, io, iter, marker::PhantomData, mem, time::Duration,
+};
 
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
 	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
 	RepackedExtension, TowerField,
 };
-use binius_hal::ComputationBackend;
+use binius_hal::{ComputationBackend, RoundEvalStrategy};
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{
 	ArithExpr, EvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*, sparse_index::SparseIndex};
 use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 use either::Either;
 use itertools::{chain, izip};
-use rayon::prelude::*;
 use tracing::instrument;
 
 use super::{
 	error::Error,
+	matmul,
+	phase::{self, PhaseTimer},
+	profile::{ProverProfile, ProverProfiler, Stopwatch, Timed},
+	public_input::write_public_inputs,
 	verify::{
-		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles,
+		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles, make_non_zero_oracles,
 		max_n_vars_and_skip_rounds, reorder_for_flushing_by_n_vars,
 	},
-	ConstraintSystem, Proof,
+	CancellationToken, CommitmentScheme, ConstraintSetShape, ConstraintSystem, PCSParams, Proof,
+	ProofEncoding, ProveOptions, RoundEvalSelector, FRIPCS,
 };
 use crate::{
 	constraint_system::{
-		common::{FDomain, FEncode, FExt},
+		common::{FDomain, FExt},
 		verify::{get_flush_dedup_sumcheck_metas, FlushSumcheckMeta, StepDownMeta},
 	},
-	fiat_shamir::{CanSample, Challenger},
-	merkle_tree::BinaryMerkleTreeProver,
+	fiat_shamir::{CanSample, CanSampleBits, Challenger},
 	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyOracle, OracleId},
-	piop,
+	piop::{self, CommitMeta},
 	protocols::{
-		fri::CommitOutput,
 		gkr_gpa::{
 			self, gpa_sumcheck::prove::GPAProver, GrandProductBatchProveOutput,
 			GrandProductWitness, LayerClaim,
@@ -51,17 +56,17 @@ use crate::{
 	},
 	ring_switch,
 	tower::{PackedTop, TowerFamily, TowerUnderlier},
-	transcript::{AdviceWriter, CanWrite, Proof as ProofWriter, TranscriptWriter},
+	transcript::{
+		AdviceWriter, CanWrite, Proof as ProofWriter, StreamingTranscriptWriter, TranscriptWriter,
+	},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
 pub fn prove<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
-	log_inv_rate: usize,
-	security_bits: usize,
-	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	pcs_params: &PCSParams,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
 	domain_factory: DomainFactory,
 	backend: &Backend,
 ) -> Result<Proof, Error>
@@ -91,55 +96,516 @@ where
 	PackedType<U, Tower::B64>: PackedFieldIndexable
 		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
 {
-	tracing::debug!(
-		arch = env::consts::ARCH,
-		rayon_threads = rayon::current_num_threads(),
-		"using computation backend: {backend:?}"
-	);
+	prove_with_pcs::<U, Tower, _, _, Challenger_, _>(
+		constraint_system,
+		&FRIPCS::<Hash, Compress>::new(*pcs_params),
+		witness,
+		domain_factory,
+		backend,
+	)
+}
 
+/// Generates a proof that a witness satisfies a constraint system with the given polynomial
+/// commitment scheme.
+///
+/// The proof must be verified with [`super::verify_with_pcs`] and the same commitment scheme.
+pub fn prove_with_pcs<U, Tower, PCS, DomainFactory, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: &PCS,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B16>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B32>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B64>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+{
 	let mut transcript = TranscriptWriter::<Challenger_>::default();
 	let mut advice = AdviceWriter::default();
+	prove_with_transcript::<U, Tower, _, _, _, _>(
+		constraint_system,
+		pcs,
+		witness,
+		domain_factory,
+		backend,
+		&mut transcript,
+		&mut advice,
+		None,
+		&RoundEvalSelector::default(),
+		false,
+		None,
+	)?;
 
-	let ConstraintSystem {
-		mut oracles,
-		mut table_constraints,
-		mut flushes,
-		non_zero_oracle_ids,
-		max_channel_id,
-	} = constraint_system.clone();
+	let phases = transcript.phases().to_vec();
+	Ok(Proof {
+		transcript: transcript.finalize(),
+		advice: advice.finalize(),
+		encoding: ProofEncoding::default(),
+		metadata: None,
+		n_public_inputs: 0,
+		phases,
+	})
+}
 
-	// Stable sort constraint sets in descending order by number of variables.
-	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
+/// Generates a proof like [`prove`], but streams the transcript to `sink` instead of collecting
+/// it in memory.
+///
+/// Only a bounded amount of the transcript is buffered, see [`StreamingTranscriptWriter`]. The
+/// bytes written to `sink` are identical to [`Proof::transcript`] of the proof returned by
+/// [`prove`]. Returns the sink and the advice tape, which is still collected in memory.
+pub fn prove_streaming<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend, W>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+	sink: W,
+) -> Result<(W, Vec<u8>), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	W: io::Write,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B16>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B32>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B64>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+{
+	let mut transcript = StreamingTranscriptWriter::<Challenger_, W>::new(sink);
+	let mut advice = AdviceWriter::default();
+	prove_with_transcript::<U, Tower, _, _, _, _>(
+		constraint_system,
+		&FRIPCS::<Hash, Compress>::new(*pcs_params),
+		witness,
+		domain_factory,
+		backend,
+		&mut transcript,
+		&mut advice,
+		None,
+		&RoundEvalSelector::default(),
+		false,
+		None,
+	)?;
 
-	// Commit polynomials
-	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
-	let merkle_scheme = merkle_prover.scheme();
+	Ok((transcript.finalize()?, advice.finalize()))
+}
 
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+/// Generates a proof like [`prove`], sampling the challenges from the given challenger.
+///
+/// This is the entry point for custom transcript integrations. In particular, with an
+/// [`crate::fiat_shamir::ExternalChallenger`] the challenges are supplied by an external verifier
+/// and the protocol runs interactively, see [`super::interactive`]. The proof must be verified
+/// with [`super::verify_with_challenger`] and a challenger in the same state.
+pub fn prove_with_challenger<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+	challenger: Challenger_,
+) -> Result<Proof, Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B16>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B32>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B64>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+{
+	let mut transcript = TranscriptWriter::with_challenger(challenger);
+	let mut advice = AdviceWriter::default();
+	prove_with_transcript::<U, Tower, _, _, _, _>(
+		constraint_system,
+		&FRIPCS::<Hash, Compress>::new(*pcs_params),
+		witness,
+		domain_factory,
+		backend,
+		&mut transcript,
+		&mut advice,
+		None,
+		&RoundEvalSelector::default(),
+		false,
+		None,
+	)?;
+
+	let phases = transcript.phases().to_vec();
+	Ok(Proof {
+		transcript: transcript.finalize(),
+		advice: advice.finalize(),
+		encoding: ProofEncoding::default(),
+		metadata: None,
+		n_public_inputs: 0,
+		phases,
+	})
+}
+
+/// Generates a proof like [`prove`], with control over cancellation, parallelism and encoding.
+///
+/// If a cancellation token is given, the prover checks it between protocol phases and between the
+/// rounds of the sumcheck, GKR and FRI protocols, and returns [`Error::Cancelled`] once it is set.
+/// If a thread pool is given, the prover runs on it, which bounds the number of threads it uses
+/// to the size of the pool. The proof is written with the encoding given in the options.
+pub fn prove_with_options<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+	options: &ProveOptions,
+) -> Result<Proof, Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>> + Send,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B16>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B32>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B64>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+{
+	let run = move || {
+		if let Some(expected) = &options.expected_witness_hash {
+			let actual = witness.content_hash::<Hash>()?;
+			if actual.as_slice() != expected.as_slice() {
+				bail!(Error::WitnessHashMismatch {
+					expected: expected.clone(),
+					actual: actual.to_vec(),
+				});
+			}
+		}
+
+		let encoding = options.encoding;
+		let mut transcript =
+			TranscriptWriter::<Challenger_>::default().with_scalar_encoding(encoding.scalars);
+		if let Some(cancellation) = &options.cancellation {
+			transcript = transcript.with_cancellation(cancellation.clone());
+		}
+		let mut advice = AdviceWriter::default().with_scalar_encoding(encoding.scalars);
+		if let Some(metadata) = &options.metadata {
+			transcript.absorb(&metadata.frame());
+		}
+		write_public_inputs::<FExt<Tower>, _>(&mut transcript, &options.public_inputs);
+		prove_with_transcript::<U, Tower, _, _, _, _>(
+			constraint_system,
+			&FRIPCS::<Hash, Compress>::new(*pcs_params),
+			witness,
+			domain_factory,
+			backend,
+			&mut transcript,
+			&mut advice,
+			options.cancellation.as_ref(),
+			&options.round_eval_strategy,
+			options.deterministic,
+			options.profiler.as_ref(),
+		)
+		// Protocols cancelled between rounds fail with a transcript error wrapped in their own
+		.map_err(|err| match &options.cancellation {
+			Some(cancellation) if cancellation.is_cancelled() => Error::Cancelled,
+			_ => err,
+		})?;
+
+		let phases = if options.legacy_format {
+			Vec::new()
+		} else {
+			transcript.phases().to_vec()
+		};
+		Ok(Proof {
+			transcript: transcript.finalize(),
+			advice: encoding.compress_advice(advice.finalize()),
+			encoding,
+			metadata: options.metadata.clone(),
+			n_public_inputs: options.public_inputs.len(),
+			phases,
+		})
+	};
+
+	match &options.thread_pool {
+		Some(thread_pool) => thread_pool.install(run),
+		None => run(),
+	}
+}
+
+#[instrument(
+	"constraint_system::prove",
+	skip_all,
+	level = "debug",
+	fields(
+		n_oracles = constraint_system.oracles.size(),
+		n_constraint_sets = constraint_system.table_constraints.len(),
+		n_non_zero = constraint_system.non_zero_oracle_ids.len(),
+		n_flushes = constraint_system.flushes.len(),
+		n_matmuls = constraint_system.matmuls.len(),
+	)
+)]
+#[allow(clippy::too_many_arguments)]
+fn prove_with_transcript<U, Tower, PCS, DomainFactory, Backend, Transcript>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: &PCS,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+	mut transcript: Transcript,
+	advice: &mut AdviceWriter,
+	cancellation: Option<&CancellationToken>,
+	round_eval_selector: &RoundEvalSelector,
+	deterministic: bool,
+	profiler: Option<&ProverProfiler>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Backend: ComputationBackend,
+	Transcript: CanWrite + CanSample<FExt<Tower>> + CanSampleBits<usize>,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B16>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B32>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B64>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+{
+	tracing::debug!(
+		arch = env::consts::ARCH,
+		rayon_threads = binius_utils::rayon::current_num_threads(),
+		"using computation backend: {backend:?}"
+	);
+
+	let check_cancelled = || cancellation.map_or(Ok(()), CancellationToken::check);
+	check_cancelled()?;
+	constraint_system.oracles.check_n_vars()?;
+
+	// Commit polynomials
+	let (commit_meta, oracle_to_commit_index) =
+		piop::make_oracle_commit_meta(&constraint_system.oracles)?;
 	let committed_multilins = piop::collect_committed_witnesses(
 		&commit_meta,
 		&oracle_to_commit_index,
-		&oracles,
+		&constraint_system.oracles,
 		&witness,
 	)?;
 
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+	let pcs_setup = pcs.setup(&commit_meta)?;
+	// Observe polynomial commitment
+	let mut timer = PhaseTimer::new();
+	timer.begin(
+		phase::COMMITMENT,
+		tracing::debug_span!(
+			"commitment",
+			n_polys = commit_meta.total_multilins(),
+			max_n_vars = commit_meta.max_n_vars(),
+			total_n_vars = commit_meta.total_vars(),
+		),
+	);
+	timer.record_witness_memory(witness_memory(
+		witness.materialized_multilins().chain(&committed_multilins),
+	));
+	transcript.begin_phase(phase::COMMITMENT);
+	let committed = pcs.commit(&pcs_setup, &committed_multilins, &mut transcript)?;
+
+	let committed_multilins = release_lazy_committed(
+		&oracle_to_commit_index,
+		&constraint_system.oracles,
+		&witness,
+		committed_multilins,
+	);
+
+	prove_committed::<U, Tower, _, _, _, _>(
+		constraint_system,
+		pcs,
+		&pcs_setup,
 		&commit_meta,
-		merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-	let CommitOutput {
-		commitment,
-		committed,
-		codeword,
-	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins)?;
+		oracle_to_commit_index,
+		&committed_multilins,
+		&committed,
+		witness,
+		domain_factory,
+		backend,
+		transcript,
+		advice,
+		cancellation,
+		round_eval_selector,
+		deterministic,
+		profiler,
+		timer,
+	)
+}
 
-	// Observe polynomial commitment
-	transcript.write(&commitment);
+/// Proves everything after the commitment of the witness, which has been written to the
+/// transcript already while `timer` was in the commitment phase.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub(super) fn prove_committed<U, Tower, PCS, DomainFactory, Backend, Transcript>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: &PCS,
+	pcs_setup: &PCS::Setup,
+	commit_meta: &CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+	committed_multilins: &[Option<MultilinearWitness<PackedType<U, FExt<Tower>>>>],
+	committed: &PCS::Committed<PackedType<U, FExt<Tower>>>,
+	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+	mut transcript: Transcript,
+	advice: &mut AdviceWriter,
+	cancellation: Option<&CancellationToken>,
+	round_eval_selector: &RoundEvalSelector,
+	deterministic: bool,
+	profiler: Option<&ProverProfiler>,
+	mut timer: PhaseTimer,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Backend: ComputationBackend,
+	Transcript: CanWrite + CanSample<FExt<Tower>> + CanSampleBits<usize>,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B16>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B32>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	PackedType<U, Tower::B64>: PackedFieldIndexable
+		+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+{
+	let check_cancelled = || cancellation.map_or(Ok(()), CancellationToken::check);
+	check_cancelled()?;
+
+	// Free the uncommitted witnesses that are only needed up to the commitment
+	witness.drop_marked(|id| oracle_to_commit_index.get(id).is_some());
+
+	let ConstraintSystem {
+		mut oracles,
+		table_constraints,
+		mut flushes,
+		mut matmuls,
+		non_zero_oracle_ids,
+		max_channel_id,
+		..
+	} = constraint_system.clone();
+
+	for matmul in &matmuls {
+		matmul.validate(&oracles)?;
+	}
+
+	// Stable sort constraint sets in descending order by number of variables, remembering the
+	// original position of every set for the profile.
+	let mut constraint_set_order = (0..table_constraints.len()).collect::<Vec<_>>();
+	constraint_set_order.sort_by_key(|&index| Reverse(table_constraints[index].n_vars));
+	let table_constraints = constraint_set_order
+		.iter()
+		.map(|&index| table_constraints[index].clone())
+		.collect::<Vec<_>>();
 
 	// Grand product arguments
+	timer.begin(
+		phase::GRAND_PRODUCT,
+		tracing::debug_span!(
+			"grand_product",
+			n_non_zero = non_zero_oracle_ids.len(),
+			n_flushes = flushes.len(),
+			n_channels = max_channel_id + 1,
+		),
+	);
+	timer.record_witness_memory(held_witness_memory(&witness, committed_multilins));
+	transcript.begin_phase(phase::GRAND_PRODUCT);
 	// Grand products for non-zero checking
+	let non_zero_oracle_ids = make_non_zero_oracles(&mut oracles, &non_zero_oracle_ids)?;
+	make_non_zero_witnesses(&oracles, &mut witness, &non_zero_oracle_ids)?;
 	let non_zero_prodcheck_witnesses =
 		gkr_gpa::construct_grand_product_witnesses(&non_zero_oracle_ids, &witness)?;
 	let non_zero_products =
@@ -199,6 +665,8 @@ where
 	let non_zero_final_layer_claims = final_layer_claims.split_off(flush_oracle_ids.len());
 	let flush_final_layer_claims = final_layer_claims;
 
+	check_cancelled()?;
+
 	// Reduce non_zero_final_layer_claims to evalcheck claims
 	let non_zero_prodcheck_eval_claims =
 		gkr_gpa::make_eval_claims(&oracles, non_zero_oracle_ids, non_zero_final_layer_claims)?;
@@ -222,9 +690,17 @@ where
 			backend,
 		)?;
 
+	timer.begin(
+		phase::FLUSH_SUMCHECK,
+		tracing::debug_span!("flush_sumcheck", batch_size = flush_sumcheck_provers.len()),
+	);
+	timer.record_witness_memory(held_witness_memory(&witness, committed_multilins));
+	transcript.begin_phase(phase::FLUSH_SUMCHECK);
 	let flush_sumcheck_output =
 		sumcheck::prove::batch_prove(flush_sumcheck_provers, &mut transcript)?;
 
+	check_cancelled()?;
+
 	let flush_eval_claims = get_post_flush_sumcheck_eval_claims_without_eq(
 		&oracles,
 		&all_step_down_metas,
@@ -244,6 +720,17 @@ where
 	let (max_n_vars, skip_rounds) =
 		max_n_vars_and_skip_rounds(&zerocheck_claims, FDomain::<Tower>::N_BITS);
 
+	timer.begin(
+		phase::ZEROCHECK,
+		tracing::debug_span!(
+			"zerocheck",
+			n_constraint_sets = table_constraints.len(),
+			max_n_vars,
+			skip_rounds,
+		),
+	);
+	timer.record_witness_memory(held_witness_memory(&witness, committed_multilins));
+	transcript.begin_phase(phase::ZEROCHECK);
 	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
 
 	let switchover_fn = standard_switchover_heuristic(-2);
@@ -251,13 +738,21 @@ where
 	let mut univariate_provers = Vec::new();
 	let mut tail_regular_zerocheck_provers = Vec::new();
 	let mut univariatized_multilinears = Vec::new();
-
-	for constraint_set in table_constraints {
-		let skip_challenges = (max_n_vars - constraint_set.n_vars).saturating_sub(skip_rounds);
+	// Cloning a stopwatch shares its total, so every constraint set needs its own.
+	let stopwatches = iter::repeat_with(Stopwatch::default)
+		.take(table_constraints.len())
+		.collect::<Vec<_>>();
+
+	for (constraint_set, stopwatch) in izip!(table_constraints, &stopwatches) {
+		let constraint_set_n_vars = constraint_set.n_vars;
+		let skip_challenges = (max_n_vars - constraint_set_n_vars).saturating_sub(skip_rounds);
 		let univariate_decider = |n_vars| n_vars > max_n_vars - skip_rounds;
 
 		let (constraints, multilinears) =
 			sumcheck::prove::split_constraint_set(constraint_set, &witness)?;
+		let multilinears = witness
+			.layout()
+			.arrange_table::<Tower, _>(constraint_set_n_vars, multilinears)?;
 
 		let base_tower_level = chain!(
 			multilinears
@@ -270,8 +765,28 @@ where
 		.max()
 		.unwrap_or(0);
 
+		let _span = tracing::debug_span!(
+			"constraint_set",
+			n_vars = constraint_set_n_vars,
+			n_multilinears = multilinears.len(),
+			n_constraints = constraints.len(),
+			base_tower_level,
+		)
+		.entered();
+
 		univariatized_multilinears.push(multilinears.clone());
 
+		let round_eval_strategy = round_eval_selector.select(ConstraintSetShape {
+			n_vars: constraint_set_n_vars,
+			n_multilinears: multilinears.len(),
+			max_degree: constraints
+				.iter()
+				.map(|constraint| constraint.composition.degree())
+				.max()
+				.unwrap_or(0),
+			n_constraints: constraints.len(),
+		});
+
 		let constructor =
 			ZerocheckProverConstructor::<PackedType<U, FExt<Tower>>, FDomain<Tower>, _, _, _> {
 				constraints,
@@ -279,6 +794,7 @@ where
 				domain_factory: &domain_factory,
 				switchover_fn,
 				zerocheck_challenges: &zerocheck_challenges[skip_challenges..],
+				round_eval_strategy,
 				backend,
 				_fdomain_marker: PhantomData,
 			};
@@ -293,10 +809,13 @@ where
 		};
 
 		match either_prover {
-			Either::Left(univariate_prover) => univariate_provers.push(univariate_prover),
-			Either::Right(zerocheck_prover) => {
-				tail_regular_zerocheck_provers.push(zerocheck_prover)
+			Either::Left(univariate_prover) => {
+				univariate_provers.push(Box::new(Timed::new(univariate_prover, stopwatch.clone()))
+					as TypeErasedUnivariateZerocheck<'_, _>)
 			}
+			Either::Right(zerocheck_prover) => tail_regular_zerocheck_provers
+				.push(Box::new(Timed::new(zerocheck_prover, stopwatch.clone()))
+					as TypeErasedSumcheck<'_, _>),
 		}
 	}
 
@@ -325,8 +844,8 @@ where
 	let mut reduction_claims = Vec::with_capacity(univariate_cnt);
 	let mut reduction_provers = Vec::with_capacity(univariate_cnt);
 
-	for (univariatized_multilinear_evals, multilinears) in
-		izip!(&zerocheck_output.multilinear_evals, univariatized_multilinears)
+	for (univariatized_multilinear_evals, multilinears, stopwatch) in
+		izip!(&zerocheck_output.multilinear_evals, univariatized_multilinears, &stopwatches)
 	{
 		let claim_n_vars = multilinears
 			.first()
@@ -353,7 +872,7 @@ where
 			)?;
 
 		reduction_claims.push(reduction_claim);
-		reduction_provers.push(reduction_prover);
+		reduction_provers.push(Timed::new(reduction_prover, stopwatch.clone()));
 	}
 
 	let univariatizing_output = sumcheck::prove::batch_prove(reduction_provers, &mut transcript)?;
@@ -368,28 +887,101 @@ where
 	let zerocheck_eval_claims =
 		sumcheck::make_eval_claims(&oracles, zerocheck_oracle_metas, multilinear_zerocheck_output)?;
 
+	check_cancelled()?;
+
+	// Matrix multiplications
+	let matmul_eval_claims = if matmuls.is_empty() {
+		Vec::new()
+	} else {
+		matmul::sort_matmuls(&mut matmuls);
+		timer.begin(phase::MATMUL, tracing::debug_span!("matmul", n_matmuls = matmuls.len()));
+		timer.record_witness_memory(held_witness_memory(&witness, committed_multilins));
+		transcript.begin_phase(phase::MATMUL);
+		let matmul_challenges = transcript.sample_vec(matmul::n_matmul_challenges(&matmuls));
+		let product_evals = matmul::product_evals(&witness, &matmuls, &matmul_challenges)?;
+		transcript.write_scalar_slice(&product_evals);
+
+		let (matmul_constraint_sets, product_eval_claims) = matmul::make_matmul_sumchecks(
+			&mut oracles,
+			&matmuls,
+			&matmul_challenges,
+			&product_evals,
+		)?;
+		matmul::make_projection_witnesses(&oracles, &mut witness, &matmul_constraint_sets)?;
+
+		let sumcheck::prove::oracles::SumcheckProversWithMetas { provers, metas } =
+			sumcheck::prove::oracles::constraint_sets_sumcheck_provers_metas(
+				matmul_constraint_sets,
+				&witness,
+				&domain_factory,
+				switchover_fn,
+				backend,
+			)?;
+		let matmul_sumcheck_output = sumcheck::prove::batch_prove(provers, &mut transcript)?;
+
+		let mut matmul_eval_claims =
+			sumcheck::make_eval_claims(&oracles, metas, matmul_sumcheck_output)?;
+		matmul_eval_claims.extend(product_eval_claims);
+		matmul_eval_claims
+	};
+
+	check_cancelled()?;
+
 	// Prove evaluation claims
+	timer.begin(
+		phase::EVALCHECK,
+		tracing::debug_span!(
+			"evalcheck",
+			n_oracles = oracles.size(),
+			n_claims = non_zero_prodcheck_eval_claims.len()
+				+ flush_eval_claims.len()
+				+ zerocheck_eval_claims.len()
+				+ matmul_eval_claims.len(),
+		),
+	);
+	timer.record_witness_memory(held_witness_memory(&witness, committed_multilins));
+	transcript.begin_phase(phase::EVALCHECK);
 	let eval_claims = greedy_evalcheck::prove::<_, _, FDomain<Tower>, _, _>(
 		&mut oracles,
 		&mut witness,
 		[non_zero_prodcheck_eval_claims, flush_eval_claims]
 			.concat()
 			.into_iter()
-			.chain(zerocheck_eval_claims),
+			.chain(zerocheck_eval_claims)
+			.chain(matmul_eval_claims),
 		switchover_fn,
 		&mut transcript,
-		&mut advice,
+		advice,
 		&domain_factory,
 		backend,
+		deterministic,
 	)?;
 
+	check_cancelled()?;
+
+	let committed_multilins = materialize_lazy_committed(
+		&oracle_to_commit_index,
+		&constraint_system.oracles,
+		&witness,
+		committed_multilins,
+	)?;
+	// Evalcheck is the last phase that reads the witness, so free the columns that are not
+	// committed
+	drop(witness);
+
 	// Reduce committed evaluation claims to PIOP sumcheck claims
 	let system =
-		ring_switch::EvalClaimSystem::new(&commit_meta, oracle_to_commit_index, &eval_claims)?;
+		ring_switch::EvalClaimSystem::new(commit_meta, oracle_to_commit_index, &eval_claims)?;
 
+	timer.begin(
+		phase::RING_SWITCH,
+		tracing::debug_span!("ring_switch", n_eval_claims = eval_claims.len()),
+	);
+	timer.record_witness_memory(witness_memory(&committed_multilins));
+	transcript.begin_phase(phase::RING_SWITCH);
 	let mut proof_writer = ProofWriter {
 		transcript: &mut transcript,
-		advice: &mut advice,
+		advice,
 	};
 	let ring_switch::ReducedWitness {
 		transparents: transparent_multilins,
@@ -401,25 +993,45 @@ where
 		backend,
 	)?;
 
+	check_cancelled()?;
+
 	// Prove evaluation claims using PIOP compiler
-	piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _, _>(
-		&fri_params,
-		&merkle_prover,
-		domain_factory,
-		&commit_meta,
+	timer.begin(
+		phase::PCS_OPENING,
+		tracing::debug_span!(
+			"pcs_opening",
+			n_sumcheck_claims = piop_sumcheck_claims.len(),
+			n_transparents = transparent_multilins.len(),
+		),
+	);
+	timer.record_witness_memory(witness_memory(&committed_multilins));
+	proof_writer.transcript.begin_phase(phase::PCS_OPENING);
+	pcs.prove(
+		pcs_setup,
+		commit_meta,
 		committed,
-		&codeword,
 		&committed_multilins,
 		&transparent_multilins,
 		&piop_sumcheck_claims,
+		domain_factory,
 		&mut proof_writer,
-		&backend,
+		backend,
 	)?;
 
-	Ok(Proof {
-		transcript: transcript.finalize(),
-		advice: advice.finalize(),
-	})
+	let witness_memory = timer.witness_memory().to_vec();
+	let phases = timer.finish();
+	if let Some(profiler) = profiler {
+		let mut constraint_sets = vec![Duration::ZERO; stopwatches.len()];
+		for (&index, stopwatch) in izip!(&constraint_set_order, &stopwatches) {
+			constraint_sets[index] = stopwatch.elapsed();
+		}
+		profiler.record(ProverProfile {
+			constraint_sets,
+			phases,
+			witness_memory,
+		});
+	}
+	Ok(())
 }
 
 fn arith_expr_base_tower_level<Tower: TowerFamily>(composition: &ArithExpr<FExt<Tower>>) -> usize {
@@ -460,6 +1072,7 @@ where
 	domain_factory: DomainFactory,
 	switchover_fn: SwitchoverFn,
 	zerocheck_challenges: &'a [P::Scalar],
+	round_eval_strategy: RoundEvalStrategy,
 	backend: &'a Backend,
 	_fdomain_marker: PhantomData<FDomain>,
 }
@@ -491,7 +1104,8 @@ where
 			self.switchover_fn,
 			self.zerocheck_challenges,
 			self.backend,
-		)?;
+		)?
+		.with_round_eval_strategy(self.round_eval_strategy);
 
 		let type_erased_prover = if is_univariate(univariate_prover.n_vars()) {
 			let type_erased_univariate_prover =
@@ -510,6 +1124,90 @@ where
 	}
 }
 
+/// The number of bytes of the given witness columns, counting columns shared between several
+/// oracles once.
+fn witness_memory<'b, 'a: 'b, P: PackedField>(
+	multilins: impl IntoIterator<Item = &'b MultilinearWitness<'a, P>>,
+) -> usize {
+	let mut seen = HashSet::new();
+	multilins
+		.into_iter()
+		.filter_map(|multilin| multilin.packed_evals())
+		.filter(|evals| seen.insert(evals.as_ptr()))
+		.map(mem::size_of_val)
+		.sum()
+}
+
+/// The number of bytes of the witness columns held by the index and the commitment.
+fn held_witness_memory<'a, U, F>(
+	witness: &MultilinearExtensionIndex<'a, U, F>,
+	committed_multilins: &[Option<MultilinearWitness<'a, PackedType<U, F>>>],
+) -> usize
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	witness_memory(
+		witness
+			.materialized_multilins()
+			.chain(committed_multilins.iter().flatten()),
+	)
+}
+
+/// Releases the committed witnesses of lazy columns, so that they are freed after the commitment.
+///
+/// The released entries are `None` and are materialized again by [`materialize_lazy_committed`].
+fn release_lazy_committed<'a, U, F>(
+	oracle_to_commit_index: &SparseIndex<usize>,
+	oracles: &MultilinearOracleSet<F>,
+	witness: &MultilinearExtensionIndex<'a, U, F>,
+	committed_multilins: Vec<MultilinearWitness<'a, PackedType<U, F>>>,
+) -> Vec<Option<MultilinearWitness<'a, PackedType<U, F>>>>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	let mut committed_multilins = committed_multilins
+		.into_iter()
+		.map(Some)
+		.collect::<Vec<_>>();
+	for oracle_id in 0..oracles.size() {
+		if let Some(&commit_index) = oracle_to_commit_index.get(oracle_id) {
+			if witness.is_lazy(oracle_id) {
+				committed_multilins[commit_index] = None;
+			}
+		}
+	}
+	committed_multilins
+}
+
+/// Materializes the committed witnesses released by [`release_lazy_committed`] for the opening
+/// proof.
+#[instrument(skip_all, level = "debug")]
+fn materialize_lazy_committed<'a, U, F>(
+	oracle_to_commit_index: &SparseIndex<usize>,
+	oracles: &MultilinearOracleSet<F>,
+	witness: &MultilinearExtensionIndex<'a, U, F>,
+	committed_multilins: &[Option<MultilinearWitness<'a, PackedType<U, F>>>],
+) -> Result<Vec<MultilinearWitness<'a, PackedType<U, F>>>, Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	let mut committed_multilins = committed_multilins.to_vec();
+	for oracle_id in 0..oracles.size() {
+		if let Some(&commit_index) = oracle_to_commit_index.get(oracle_id) {
+			if committed_multilins[commit_index].is_none() {
+				committed_multilins[commit_index] = Some(witness.get_multilin_poly(oracle_id)?);
+			}
+		}
+	}
+	Ok(committed_multilins
+		.into_iter()
+		.map(|multilin| multilin.expect("oracle_to_commit_index is surjective"))
+		.collect())
+}
+
 #[allow(clippy::type_complexity)]
 #[instrument(skip_all, level = "debug")]
 fn make_unmasked_flush_witnesses<'a, U, Tower>(
@@ -568,6 +1266,60 @@ where
 	Ok(())
 }
 
+/// Computes the witnesses of the concatenated oracles added by [`make_non_zero_oracles`].
+#[instrument(skip_all, level = "debug")]
+fn make_non_zero_witnesses<'a, U, Tower>(
+	oracles: &MultilinearOracleSet<FExt<Tower>>,
+	witness: &mut MultilinearExtensionIndex<'a, U, FExt<Tower>>,
+	non_zero_oracle_ids: &[OracleId],
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+{
+	let non_zero_witnesses = non_zero_oracle_ids
+		.par_iter()
+		.filter_map(|&oracle_id| match oracles.oracle(oracle_id) {
+			MultilinearPolyOracle::Concatenated { concatenated, .. } => {
+				Some((oracle_id, concatenated))
+			}
+			_ => None,
+		})
+		.map(|(oracle_id, concatenated)| {
+			let polys = concatenated
+				.polys()
+				.map(|oracle| witness.get_multilin_poly(oracle.id()))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let inner_n_vars = concatenated.inner_n_vars();
+			let n_vars = inner_n_vars + concatenated.log_count();
+			let packed_len = 1 << n_vars.saturating_sub(<PackedType<U, FExt<Tower>>>::LOG_WIDTH);
+			let data = (0..packed_len)
+				.into_par_iter()
+				.map(|i| {
+					<PackedType<U, FExt<Tower>>>::from_fn(|j| {
+						let index = i << <PackedType<U, FExt<Tower>>>::LOG_WIDTH | j;
+						polys
+							.get(index >> inner_n_vars)
+							.and_then(|poly| {
+								poly.evaluate_on_hypercube(index & ((1 << inner_n_vars) - 1))
+									.ok()
+							})
+							.unwrap_or(<FExt<Tower>>::ZERO)
+					})
+				})
+				.collect::<Vec<_>>();
+			let concatenated_poly = MultilinearExtension::new(n_vars, data)
+				.expect("data is constructed with the correct length with respect to n_vars");
+
+			Ok((oracle_id, MLEDirectAdapter::from(concatenated_poly).upcast_arc_dyn()))
+		})
+		.collect::<Result<Vec<(OracleId, MultilinearWitness<'a, _>)>, Error>>()?;
+
+	witness.update_multilin_poly(non_zero_witnesses)?;
+	Ok(())
+}
+
 #[allow(clippy::type_complexity)]
 #[instrument(skip_all, level = "debug")]
 fn make_masked_flush_witnesses<'a, U, Tower>(
@@ -0,0 +1,154 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Public inputs bound to the transcript of a proof.
+//!
+//! A proof generated with [`super::ProveOptions::with_public_inputs`] commits to a vector of public
+//! input slots. Right before the commitment phase, the prover absorbs [`PublicInputs::frame`] into
+//! the Fiat-Shamir state, samples a check value and writes it to the transcript. The verifier is
+//! given the public inputs out of band, absorbs their frame at the same point and compares the
+//! check value it samples with the one in the proof, so verifying against other public inputs
+//! fails with [`super::error::VerificationError::PublicInputMismatch`] before any other work.
+//!
+//! The public inputs are not interpreted by the protocol. Applications relate them to the
+//! statement, for example by deriving the boundaries from them.
+
+use binius_field::{serialize_canonical, TowerField};
+use binius_utils::bail;
+
+use super::{
+	error::{Error, VerificationError},
+	phase,
+};
+use crate::{
+	fiat_shamir::CanSample,
+	transcript::{CanRead, CanWrite},
+};
+
+/// The tag that starts the frame of public inputs, see [`PublicInputs::frame`].
+pub const PUBLIC_INPUT_TAG: &[u8] = b"binius-public-input";
+
+/// A vector of public input slots, each an opaque byte string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicInputs {
+	slots: Vec<Vec<u8>>,
+}
+
+impl PublicInputs {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates public inputs with one slot per field element, holding its canonical encoding.
+	pub fn from_scalars<F: TowerField>(values: &[F]) -> Self {
+		values
+			.iter()
+			.fold(Self::new(), |inputs, &value| inputs.with_scalar(value))
+	}
+
+	pub fn with_slot(mut self, slot: impl Into<Vec<u8>>) -> Self {
+		self.slots.push(slot.into());
+		self
+	}
+
+	/// Appends a slot holding the canonical encoding of a field element.
+	pub fn with_scalar<F: TowerField>(self, value: F) -> Self {
+		let mut slot = Vec::new();
+		serialize_canonical(value, &mut slot).expect("Vec<u8> has infinite capacity");
+		self.with_slot(slot)
+	}
+
+	pub fn slots(&self) -> &[Vec<u8>] {
+		&self.slots
+	}
+
+	pub fn len(&self) -> usize {
+		self.slots.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.slots.is_empty()
+	}
+
+	/// The bytes a transcript absorbs into its Fiat-Shamir state for these public inputs.
+	///
+	/// The frame consists of [`PUBLIC_INPUT_TAG`], the number of slots as a little-endian `u64`,
+	/// and every slot prefixed with its length as a little-endian `u64`.
+	pub fn frame(&self) -> Vec<u8> {
+		let mut frame = [PUBLIC_INPUT_TAG, &(self.slots.len() as u64).to_le_bytes()].concat();
+		for slot in &self.slots {
+			frame.extend_from_slice(&(slot.len() as u64).to_le_bytes());
+			frame.extend_from_slice(slot);
+		}
+		frame
+	}
+}
+
+/// Binds the public inputs to the transcript of the prover.
+pub(super) fn write_public_inputs<F, Transcript>(
+	transcript: &mut Transcript,
+	public_inputs: &PublicInputs,
+) where
+	F: TowerField,
+	Transcript: CanWrite + CanSample<F>,
+{
+	if public_inputs.is_empty() {
+		return;
+	}
+	transcript.begin_phase(phase::PUBLIC_INPUT);
+	transcript.absorb(&public_inputs.frame());
+	let check: F = transcript.sample();
+	transcript.write_scalar(check);
+}
+
+/// Binds the public inputs to the transcript of the verifier, checking that the proof was
+/// generated for them.
+pub(super) fn read_public_inputs<F, Transcript>(
+	transcript: &mut Transcript,
+	n_public_inputs: usize,
+	public_inputs: &PublicInputs,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	Transcript: CanRead + CanSample<F>,
+{
+	if n_public_inputs != public_inputs.len() {
+		bail!(VerificationError::PublicInputCountMismatch {
+			expected: public_inputs.len(),
+			got: n_public_inputs,
+		});
+	}
+	if public_inputs.is_empty() {
+		return Ok(());
+	}
+	transcript.begin_phase(phase::PUBLIC_INPUT);
+	transcript.absorb(&public_inputs.frame());
+	let check: F = transcript.sample();
+	if transcript.read_scalar::<F>()? != check {
+		bail!(VerificationError::PublicInputMismatch);
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField32b;
+
+	use super::*;
+
+	#[test]
+	fn test_frame_separates_slots() {
+		let a = PublicInputs::new().with_slot(b"ab".to_vec());
+		let b = PublicInputs::new()
+			.with_slot(b"a".to_vec())
+			.with_slot(b"b".to_vec());
+		assert_ne!(a.frame(), b.frame());
+		assert_ne!(PublicInputs::new().frame(), PublicInputs::new().with_slot(vec![]).frame());
+	}
+
+	#[test]
+	fn test_from_scalars() {
+		let inputs = PublicInputs::from_scalars(&[BinaryField32b::new(1), BinaryField32b::new(2)]);
+		assert_eq!(inputs.len(), 2);
+		assert_eq!(inputs.slots()[1], 2u32.to_le_bytes());
+	}
+}
@@ -0,0 +1,172 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The shape of the constraint system protocol for a concrete [`ConstraintSystem`] and PCS
+//! parameterization: the number of rounds, degrees and batch sizes of its sub-protocols.
+//!
+//! The soundness and verifier cost estimates in [`super::soundness`] and [`super::cost`] are both
+//! derived from this, so that they follow the same protocol.
+
+use binius_field::BinaryField;
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use digest::{core_api::BlockSizeUser, Digest, Output};
+
+use super::{
+	common::{FDomain, FEncode, FExt},
+	error::Error,
+	verify::{max_n_vars_and_skip_rounds, non_zero_batches},
+	ConstraintSystem, PCSParams,
+};
+use crate::{
+	merkle_tree::BinaryMerkleTreeScheme,
+	oracle::MultilinearPolyOracle,
+	piop::{self, CommitMeta},
+	protocols::{fri::FRIParams, sumcheck::constraint_set_zerocheck_claim},
+	tower::TowerFamily,
+};
+
+/// The shape of the constraint system protocol, see the [module documentation](self).
+pub(super) struct ProtocolShape<Tower: TowerFamily, Hash, Compress> {
+	/// The number of variables of the product of each batch of non-zero oracles with the same
+	/// number of variables.
+	pub non_zero_n_vars: Vec<usize>,
+	/// The number of grand products, one per flush and one per batch of non-zero oracles.
+	pub n_products: usize,
+	/// The number of variables of the largest grand product, and so the number of GKR layers.
+	/// Layer k runs a degree-3 sumcheck over k variables.
+	pub max_product_n_vars: usize,
+	/// The flush sumchecks, of degree 3 (step down, flush oracle and eq indicator) over each
+	/// flushed oracle's variables.
+	pub flushes: Vec<FlushShape>,
+	/// The zerocheck of the table constraints.
+	pub zerocheck: ZerocheckShape,
+	/// The number of variables of the shifted, packed and univariate oracles, whose evaluation
+	/// claims evalcheck reduces with degree-2 sumchecks.
+	pub evalcheck_n_vars: Vec<usize>,
+	/// The committed batch, with exactly the parameters used by the prover and verifier.
+	pub commit_meta: CommitMeta,
+	pub merkle_scheme: BinaryMerkleTreeScheme<FExt<Tower>, Hash, Compress>,
+	pub fri_params: FRIParams<FExt<Tower>, FEncode<Tower>>,
+}
+
+/// A flush sumcheck.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct FlushShape {
+	pub n_vars: usize,
+	/// The number of flushed oracles.
+	pub width: usize,
+}
+
+/// The zerocheck of the table constraints, batched over all constraint sets.
+///
+/// It samples `max_n_vars` challenges, runs a univariate skip round over `skip_rounds`
+/// variables, then the remaining multilinear rounds with the compositions multiplied by the eq
+/// indicator, and finally the univariatizing reduction, a degree-2 sumcheck over the skipped
+/// variables.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ZerocheckShape {
+	/// The number of zerocheck claims, one per constraint set.
+	pub n_claims: usize,
+	pub max_n_vars: usize,
+	pub skip_rounds: usize,
+	/// The maximum individual degree of the constraint compositions.
+	pub max_degree: usize,
+}
+
+impl<Tower, Hash, Compress> ProtocolShape<Tower, Hash, Compress>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	pub fn new(
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		pcs_params: &PCSParams,
+	) -> Result<Self, Error> {
+		let ConstraintSystem {
+			oracles,
+			table_constraints,
+			flushes,
+			non_zero_oracle_ids,
+			..
+		} = constraint_system;
+
+		// All grand products are proven in one batch, layer by layer.
+		let non_zero_n_vars = non_zero_batches(oracles, non_zero_oracle_ids)
+			.iter()
+			.map(|batch| oracles.n_vars(batch[0]) + log2_ceil_usize(batch.len()))
+			.collect::<Vec<_>>();
+		let max_product_n_vars = flushes
+			.iter()
+			.filter_map(|flush| flush.oracles.first())
+			.map(|&id| oracles.n_vars(id))
+			.chain(non_zero_n_vars.iter().copied())
+			.max()
+			.unwrap_or(0);
+		let n_products = flushes.len() + non_zero_n_vars.len();
+
+		let flush_shapes = flushes
+			.iter()
+			.filter_map(|flush| {
+				flush.oracles.first().map(|&id| FlushShape {
+					n_vars: oracles.n_vars(id),
+					width: flush.oracles.len(),
+				})
+			})
+			.collect();
+
+		let zerocheck_claims = table_constraints
+			.iter()
+			.cloned()
+			.map(|constraint_set| {
+				constraint_set_zerocheck_claim(constraint_set).map(|(claim, _)| claim)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		let (max_n_vars, skip_rounds) =
+			max_n_vars_and_skip_rounds(&zerocheck_claims, FDomain::<Tower>::N_BITS);
+		let max_degree = zerocheck_claims
+			.iter()
+			.map(|claim| claim.max_individual_degree())
+			.max()
+			.unwrap_or(0);
+		let zerocheck = ZerocheckShape {
+			n_claims: zerocheck_claims.len(),
+			max_n_vars,
+			skip_rounds,
+			max_degree,
+		};
+
+		let evalcheck_n_vars = oracles
+			.iter()
+			.filter(|oracle| {
+				matches!(
+					oracle,
+					MultilinearPolyOracle::Shifted { .. }
+						| MultilinearPolyOracle::Packed { .. }
+						| MultilinearPolyOracle::Univariate { .. }
+				)
+			})
+			.map(|oracle| oracle.n_vars())
+			.collect();
+
+		let merkle_scheme = pcs_params.merkle_scheme::<FExt<Tower>, Hash, _>(Compress::default())?;
+		let (commit_meta, _) = piop::make_oracle_commit_meta(oracles)?;
+		let fri_params = piop::make_commit_params::<_, FEncode<Tower>, _>(
+			&commit_meta,
+			&merkle_scheme,
+			pcs_params,
+		)?;
+
+		Ok(Self {
+			non_zero_n_vars,
+			n_products,
+			max_product_n_vars,
+			flushes: flush_shapes,
+			zerocheck,
+			evalcheck_n_vars,
+			commit_meta,
+			merkle_scheme,
+			fri_params,
+		})
+	}
+}
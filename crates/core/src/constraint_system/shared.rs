@@ -0,0 +1,264 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Proofs of many statements about one committed witness.
+//!
+//! Data-availability style applications commit to a dataset once and let many consumers prove
+//! different predicates over it. [`SharedCommitment::commit`] commits to the committed oracles of
+//! a constraint system, and [`SharedCommitment::prove`] proves any constraint system that declares
+//! the same committed oracles, reusing the encoded codeword and Merkle tree of the commitment
+//! instead of computing them again for every statement.
+//!
+//! Every proof starts with the shared commitment and is an ordinary proof for its constraint
+//! system, so it verifies with [`super::verify_with_pcs`]. The openings depend on the challenges
+//! of each proof and are not shared. [`verify_shared`] additionally checks that a proof opens the
+//! published commitment.
+
+use binius_field::{
+	as_packed_field::PackedType, PackedExtension, PackedFieldIndexable, RepackedExtension,
+	TowerField,
+};
+use binius_hal::ComputationBackend;
+use binius_math::{EvaluationDomainFactory, MultilinearPoly};
+use binius_utils::{bail, sparse_index::SparseIndex};
+
+use super::{
+	channel::Boundary,
+	common::{FDomain, FExt},
+	error::{Error, VerificationError},
+	phase::{self, PhaseTimer},
+	prove::prove_committed,
+	verify::verify_with_pcs,
+	CommitmentScheme, ConstraintSystem, Proof, ProofEncoding, RoundEvalSelector,
+};
+use crate::{
+	fiat_shamir::Challenger,
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle, OracleId},
+	piop::{self, CommitMeta},
+	tower::{PackedTop, TowerFamily, TowerUnderlier},
+	transcript::{AdviceWriter, CanWrite, TranscriptWriter},
+	witness::{MultilinearExtensionIndex, MultilinearWitness},
+};
+
+/// A witness committed once, against which many constraint systems can be proven.
+///
+/// A statement must declare the committed oracles of the shared witness with the same IDs, number
+/// of variables and tower levels, for example by adding the data columns with the same builder
+/// code before its own constraints. It may constrain only some of them and add virtual oracles of
+/// its own.
+pub struct SharedCommitment<'a, U, Tower, PCS>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	PCS: CommitmentScheme<Tower>,
+{
+	pcs: PCS,
+	pcs_setup: PCS::Setup,
+	committed_oracles: Vec<CommittedOracle>,
+	commit_meta: CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+	committed_multilins: Vec<MultilinearWitness<'a, PackedType<U, FExt<Tower>>>>,
+	committed: PCS::Committed<PackedType<U, FExt<Tower>>>,
+	commitment: Vec<u8>,
+}
+
+/// The shape of a committed oracle, which determines its place in the commitment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CommittedOracle {
+	id: OracleId,
+	n_vars: usize,
+	tower_level: usize,
+}
+
+fn committed_oracles<F: TowerField>(oracles: &MultilinearOracleSet<F>) -> Vec<CommittedOracle> {
+	oracles
+		.iter()
+		.filter_map(|oracle| match oracle {
+			MultilinearPolyOracle::Committed {
+				oracle_id,
+				n_vars,
+				tower_level,
+				..
+			} => Some(CommittedOracle {
+				id: oracle_id,
+				n_vars,
+				tower_level,
+			}),
+			_ => None,
+		})
+		.collect()
+}
+
+impl<'a, U, Tower, PCS> SharedCommitment<'a, U, Tower, PCS>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	PackedType<U, Tower::B128>: PackedTop<Tower> + PackedFieldIndexable,
+{
+	/// Commits to the committed oracles of `constraint_system` with their values in `witness`.
+	///
+	/// Only the committed oracles are read from the witness, the constraints of the system are
+	/// ignored.
+	pub fn commit<Challenger_>(
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		pcs: PCS,
+		witness: &MultilinearExtensionIndex<'a, U, FExt<Tower>>,
+	) -> Result<Self, Error>
+	where
+		Challenger_: Challenger + Default,
+	{
+		constraint_system.oracles.check_n_vars()?;
+		let (commit_meta, oracle_to_commit_index) =
+			piop::make_oracle_commit_meta(&constraint_system.oracles)?;
+		let committed_multilins = piop::collect_committed_witnesses(
+			&commit_meta,
+			&oracle_to_commit_index,
+			&constraint_system.oracles,
+			witness,
+		)?;
+
+		let pcs_setup = pcs.setup(&commit_meta)?;
+		let mut transcript = TranscriptWriter::<Challenger_>::default();
+		let committed = pcs.commit(&pcs_setup, &committed_multilins, &mut transcript)?;
+
+		Ok(Self {
+			pcs,
+			pcs_setup,
+			committed_oracles: committed_oracles(&constraint_system.oracles),
+			commit_meta,
+			oracle_to_commit_index,
+			committed_multilins,
+			committed,
+			commitment: transcript.finalize(),
+		})
+	}
+
+	/// The commitment as it appears at the start of every proof, to be published to verifiers.
+	pub fn commitment(&self) -> &[u8] {
+		&self.commitment
+	}
+
+	pub fn pcs(&self) -> &PCS {
+		&self.pcs
+	}
+
+	/// Generates a proof that a witness satisfies a constraint system over the shared commitment.
+	///
+	/// The witness only needs to contain the oracles of the statement that are not committed,
+	/// missing committed oracles are taken from the shared commitment. Committed oracles that are
+	/// present must have the committed values.
+	pub fn prove<DomainFactory, Challenger_, Backend>(
+		&self,
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		mut witness: MultilinearExtensionIndex<'a, U, FExt<Tower>>,
+		domain_factory: DomainFactory,
+		backend: &Backend,
+	) -> Result<Proof, Error>
+	where
+		DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+		Challenger_: Challenger + Default,
+		Backend: ComputationBackend,
+		// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+		PackedType<U, Tower::B128>: RepackedExtension<PackedType<U, Tower::B8>>
+			+ RepackedExtension<PackedType<U, Tower::B16>>
+			+ RepackedExtension<PackedType<U, Tower::B32>>
+			+ RepackedExtension<PackedType<U, Tower::B64>>
+			+ RepackedExtension<PackedType<U, Tower::B128>>,
+		PackedType<U, Tower::B8>: PackedFieldIndexable
+			+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+		PackedType<U, Tower::B16>: PackedFieldIndexable
+			+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+		PackedType<U, Tower::B32>: PackedFieldIndexable
+			+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+		PackedType<U, Tower::B64>: PackedFieldIndexable
+			+ PackedExtension<FDomain<Tower>, PackedSubfield: PackedFieldIndexable>,
+	{
+		if committed_oracles(&constraint_system.oracles) != self.committed_oracles {
+			bail!(Error::CommittedOraclesMismatch);
+		}
+
+		for oracle in &self.committed_oracles {
+			let commit_index = *self
+				.oracle_to_commit_index
+				.get(oracle.id)
+				.expect("committed oracles are in the commit index");
+			let committed = &self.committed_multilins[commit_index];
+			if witness.has(oracle.id) {
+				let values = witness.get_multilin_poly(oracle.id)?;
+				if values.packed_evals() != committed.packed_evals() {
+					bail!(Error::CommittedWitnessMismatch { id: oracle.id });
+				}
+			} else {
+				witness.update_multilin_poly([(oracle.id, committed.clone())])?;
+			}
+		}
+
+		let mut timer = PhaseTimer::new();
+		timer.begin(
+			phase::COMMITMENT,
+			tracing::debug_span!("commitment", n_polys = self.commit_meta.total_multilins()),
+		);
+		let mut transcript = TranscriptWriter::<Challenger_>::default();
+		transcript.begin_phase(phase::COMMITMENT);
+		transcript.write_bytes(&self.commitment);
+		let mut advice = AdviceWriter::default();
+		prove_committed::<U, Tower, _, _, _, _>(
+			constraint_system,
+			&self.pcs,
+			&self.pcs_setup,
+			&self.commit_meta,
+			self.oracle_to_commit_index.clone(),
+			&self
+				.committed_multilins
+				.iter()
+				.cloned()
+				.map(Some)
+				.collect::<Vec<_>>(),
+			&self.committed,
+			witness,
+			domain_factory,
+			backend,
+			&mut transcript,
+			&mut advice,
+			None,
+			&RoundEvalSelector::default(),
+			false,
+			None,
+			timer,
+		)?;
+
+		let phases = transcript.phases().to_vec();
+		Ok(Proof {
+			transcript: transcript.finalize(),
+			advice: advice.finalize(),
+			encoding: ProofEncoding::default(),
+			metadata: None,
+			n_public_inputs: 0,
+			phases,
+		})
+	}
+}
+
+/// Verifies a proof generated with [`SharedCommitment::prove`] against the published commitment.
+pub fn verify_shared<U, Tower, PCS, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: PCS,
+	commitment: &[u8],
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	if !proof.transcript.starts_with(commitment) {
+		bail!(VerificationError::SharedCommitmentMismatch);
+	}
+	verify_with_pcs::<U, Tower, PCS, Challenger_>(constraint_system, pcs, boundaries, proof)
+}
@@ -0,0 +1,331 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Soundness error accounting for the constraint system protocol.
+//!
+//! The constraint system proof is a composition of several interactive sub-protocols, each of
+//! which contributes an additive term to the soundness error of the whole protocol. This module
+//! computes upper bounds on these terms for a concrete [`ConstraintSystem`] and PCS
+//! parameterization, so that the claimed security level can be audited.
+//!
+//! All bounds are derived with the Schwartz–Zippel lemma or taken from the referenced papers and
+//! are combined with a union bound. The Fiat–Shamir transformation and the hash function are not
+//! accounted for.
+
+use std::fmt;
+
+use binius_field::BinaryField;
+use binius_hash::PseudoCompressionFunction;
+use digest::{core_api::BlockSizeUser, Digest, Output};
+use itertools::Itertools;
+
+use super::{
+	common::FExt,
+	error::Error,
+	shape::{ProtocolShape, ZerocheckShape},
+	ConstraintSystem, PCSParams,
+};
+use crate::{
+	protocols::fri::{self, FRIErrorBound},
+	tower::TowerFamily,
+};
+
+/// Upper bounds on the soundness error contributed by each sub-protocol of the constraint system
+/// proof.
+///
+/// All values are probabilities over the verifier's randomness.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundnessReport {
+	/// Bit size of the field the verifier samples challenges from.
+	pub field_bits: usize,
+	/// The security level that the PCS parameters were chosen for.
+	pub target_security_bits: usize,
+	/// Multiset check of the channel flushes, with the fingerprint sampled from the mixing and
+	/// permutation challenges.
+	pub channel: f64,
+	/// GKR-based grand product arguments for both the flushes and the non-zero checks.
+	pub grand_product: f64,
+	/// Sumchecks reducing the final grand product layer claims to flushed oracle evaluations.
+	pub flush_sumcheck: f64,
+	/// Zerocheck of the table constraints, including the univariate skip round and univariatizing
+	/// reduction.
+	pub zerocheck: f64,
+	/// Matrix multiplication checks, each evaluating the product matrix at a random point and
+	/// running a degree-2 sumcheck over the inner dimension.
+	pub matmul: f64,
+	/// Random linear combinations used to batch sumcheck claims and composite claims.
+	pub sumcheck_batching: f64,
+	/// Sumchecks reducing evaluation claims on shifted and packed virtual oracles.
+	pub evalcheck: f64,
+	/// The FRI-Binius polynomial commitment scheme opening.
+	pub pcs: FRIErrorBound,
+}
+
+impl SoundnessReport {
+	/// The total soundness error, by union bound over all sub-protocols.
+	pub fn total(&self) -> f64 {
+		self.channel
+			+ self.grand_product
+			+ self.flush_sumcheck
+			+ self.zerocheck
+			+ self.matmul
+			+ self.sumcheck_batching
+			+ self.evalcheck
+			+ self.pcs.total()
+	}
+
+	/// The number of bits of security implied by the total soundness error.
+	pub fn security_bits(&self) -> usize {
+		error_to_bits(self.total())
+	}
+}
+
+impl fmt::Display for SoundnessReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let rows = [
+			("channel", self.channel),
+			("grand product", self.grand_product),
+			("flush sumcheck", self.flush_sumcheck),
+			("zerocheck", self.zerocheck),
+			("matmul", self.matmul),
+			("sumcheck batching", self.sumcheck_batching),
+			("evalcheck", self.evalcheck),
+			("pcs sumcheck", self.pcs.sumcheck),
+			("pcs folding", self.pcs.folding),
+			("pcs query", self.pcs.query),
+		];
+		writeln!(f, "soundness error over a {}-bit field:", self.field_bits)?;
+		for (name, err) in rows {
+			writeln!(f, "  {name:<18} 2^{:.2}", err.log2())?;
+		}
+		write!(
+			f,
+			"  {:<18} 2^{:.2} ({} bits, target {})",
+			"total",
+			self.total().log2(),
+			self.security_bits(),
+			self.target_security_bits
+		)
+	}
+}
+
+/// Computes the soundness error contributions of each sub-protocol of [`super::prove`] and
+/// [`super::verify`] for the given constraint system and security parameters.
+///
+/// The PCS parameters are derived exactly as the prover and verifier derive them, hence the type
+/// parameters match those of [`super::verify`].
+///
+/// Boundary values passed to the verifier are not part of the constraint system and add a term
+/// proportional to their multiplicities to the channel error, which is not included here.
+pub fn soundness_report<Tower, Hash, Compress>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+) -> Result<SoundnessReport, Error>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	let ConstraintSystem {
+		table_constraints,
+		flushes,
+		matmuls,
+		..
+	} = constraint_system;
+	let shape = ProtocolShape::<Tower, Hash, Compress>::new(constraint_system, pcs_params)?;
+
+	let field_bits = FExt::<Tower>::N_BITS;
+	let field_size = 2.0f64.powi(field_bits as i32);
+
+	// Channel fingerprint. For every channel, the difference of the pushed and pulled products is
+	// a polynomial in the permutation challenge of degree at most the total number of flushed
+	// rows, and in the mixing challenge of degree less than the flush width.
+	let channel = flushes
+		.iter()
+		.into_group_map_by(|flush| flush.channel_id)
+		.into_values()
+		.map(|channel_flushes| {
+			let width = channel_flushes
+				.iter()
+				.map(|flush| flush.oracles.len())
+				.max()
+				.unwrap_or(0);
+			let n_rows = channel_flushes
+				.iter()
+				.map(|flush| flush.count as f64 * flush.multiplicity as f64)
+				.sum::<f64>();
+			n_rows * width as f64
+		})
+		.sum::<f64>()
+		/ field_size;
+
+	// GKR grand product. Layer k runs a degree-3 sumcheck over k variables, followed by batching
+	// and a line reduction.
+	let grand_product = (0..shape.max_product_n_vars)
+		.map(|layer| (3 * layer + 2) as f64)
+		.sum::<f64>()
+		/ field_size;
+
+	let flush_sumcheck = shape
+		.flushes
+		.iter()
+		.map(|flush| 3 * flush.n_vars)
+		.sum::<usize>() as f64
+		/ field_size;
+
+	let ZerocheckShape {
+		n_claims: n_zerocheck_claims,
+		max_n_vars,
+		skip_rounds,
+		max_degree,
+	} = shape.zerocheck;
+	let zerocheck = if n_zerocheck_claims == 0 {
+		0.0
+	} else {
+		// Sampling the zerocheck challenges.
+		max_n_vars as f64
+			// The univariate skip round polynomial.
+			+ ((max_degree + 1) << skip_rounds) as f64
+			// The remaining multilinear rounds, with the composition multiplied by the eq indicator.
+			+ ((max_n_vars - skip_rounds) * (max_degree + 1)) as f64
+			// The univariatizing reduction.
+			+ (2 * skip_rounds) as f64
+	} / field_size;
+
+	// Matrix multiplications. The difference of the product matrix and the product of the factors
+	// is multilinear in the evaluation point, and the sumcheck over the inner dimension has degree
+	// 2.
+	let matmul = matmuls
+		.iter()
+		.map(|matmul| (matmul.log_rows + matmul.log_cols + 2 * matmul.log_inner) as f64)
+		.sum::<f64>()
+		/ field_size;
+
+	// Batching of sumcheck claims. Each claim in a batch gets an independent coefficient, while
+	// composite claims within a claim are batched with powers of one coefficient.
+	let n_composite_claims = table_constraints
+		.iter()
+		.map(|constraint_set| constraint_set.constraints.len().saturating_sub(1))
+		.sum::<usize>();
+	let n_batched_claims = n_zerocheck_claims * 2
+		+ shape.flushes.len()
+		+ shape.non_zero_n_vars.len()
+		+ matmuls.len()
+		+ shape.max_product_n_vars;
+	let sumcheck_batching = (n_composite_claims + n_batched_claims) as f64 / field_size;
+
+	let evalcheck = shape
+		.evalcheck_n_vars
+		.iter()
+		.map(|&n_vars| (2 * n_vars + 1) as f64)
+		.sum::<f64>()
+		/ field_size;
+
+	let pcs = fri::calculate_error_components::<FExt<Tower>, _>(
+		shape.fri_params.rs_code(),
+		shape.fri_params.n_test_queries(),
+	);
+
+	Ok(SoundnessReport {
+		field_bits,
+		target_security_bits: pcs_params.security_bits,
+		channel,
+		grand_product,
+		flush_sumcheck,
+		zerocheck,
+		matmul,
+		sumcheck_batching,
+		evalcheck,
+		pcs,
+	})
+}
+
+fn error_to_bits(err: f64) -> usize {
+	if err <= 0.0 {
+		return usize::MAX;
+	}
+	// Round down to a whole number of bits, without going negative for errors close to 1.
+	(-err.log2()).floor().max(0.0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField1b, TowerField};
+	use binius_hash::compress::Groestl256ByteCompression;
+	use binius_math::ArithExpr;
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::{
+		constraint_system::channel::{ChannelRegistry, Flush, FlushDirection},
+		oracle::{ConstraintSetBuilder, MultilinearOracleSet},
+		tower::CanonicalTowerFamily,
+	};
+
+	type F = BinaryField128b;
+
+	fn test_constraint_system() -> ConstraintSystem<F> {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(12, BinaryField1b::TOWER_LEVEL);
+		let b = oracles.add_committed(12, BinaryField1b::TOWER_LEVEL);
+		let c = oracles.add_committed(12, BinaryField1b::TOWER_LEVEL);
+
+		let mut constraints = ConstraintSetBuilder::new();
+		constraints.add_zerocheck(
+			"and",
+			[a, b, c],
+			ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+		);
+		let table_constraints = constraints.build(&oracles).unwrap();
+
+		let flushes = vec![
+			Flush {
+				oracles: vec![a, b],
+				channel_id: 0,
+				direction: FlushDirection::Push,
+				count: 1 << 12,
+				multiplicity: 1,
+			},
+			Flush {
+				oracles: vec![b, a],
+				channel_id: 0,
+				direction: FlushDirection::Pull,
+				count: 1 << 12,
+				multiplicity: 1,
+			},
+		];
+
+		ConstraintSystem {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids: vec![],
+			flushes,
+			matmuls: vec![],
+			max_channel_id: 0,
+			channels: ChannelRegistry::default(),
+		}
+	}
+
+	#[test]
+	fn test_soundness_report() {
+		let constraint_system = test_constraint_system();
+		let report =
+			soundness_report::<CanonicalTowerFamily, Groestl256, Groestl256ByteCompression>(
+				&constraint_system,
+				&PCSParams::new(1, 100),
+			)
+			.unwrap();
+
+		assert_eq!(report.field_bits, 128);
+		// Two flushes of 2^12 rows with width 2
+		assert_eq!(report.channel, (1 << 14) as f64 / 2.0f64.powi(128));
+		assert!(report.zerocheck > 0.0);
+		assert!(report.grand_product > 0.0);
+		assert_eq!(report.evalcheck, 0.0);
+		assert_eq!(report.matmul, 0.0);
+
+		// The PCS parameters are chosen to achieve the target, the remaining terms are tiny.
+		assert!(error_to_bits(report.pcs.total()) >= 100);
+		assert!(report.security_bits() >= 99);
+		assert!(report.security_bits() <= error_to_bits(report.pcs.total()));
+	}
+}
@@ -0,0 +1,926 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{iter, sync::Arc};
+
+use binius_field::{
+	arch::OptimalUnderlier,
+	as_packed_field::{PackScalar, PackedType},
+	packed::set_packed_slice,
+	BinaryField, BinaryField128b, BinaryField32b, Field, PackedExtension, PackedField,
+	RepackedExtension, TowerField,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::{aes::AesCompression, compress::Groestl256ByteCompression};
+use binius_math::{ArithExpr, DefaultEvaluationDomainFactory, MultilinearExtension};
+use binius_utils::rayon::{ThreadPool, ThreadPoolBuilder};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, SeedableRng};
+use sha2::Sha256;
+
+use super::{
+	channel::{Boundary, ChannelId, ChannelInfo, ChannelRegistry, Flush, FlushDirection},
+	continuation::{self, SegmentedProof},
+	error::{Error, VerificationError},
+	metadata::hash_boundaries,
+	phase,
+	profile::{ProverProfile, ProverProfiler},
+	prove, prove_streaming, prove_with_options, prove_with_pcs, public_inputs_match,
+	validate::validate_witness,
+	verify, verify_isomorphic, verify_prepared, verify_prepared_with_advice_layout, verify_shared,
+	verify_with_pcs, verify_with_public_inputs, CancellationToken, CommitmentScheme,
+	ConstraintSystem, PCSParams, PreparedConstraintSystem, Proof, ProofEncoding, ProofMetadata,
+	ProveOptions, PublicInputs, SharedCommitment, ThreadPoolConfig, TrivialPCS, FRIPCS,
+};
+use crate::{
+	fiat_shamir::HasherChallenger,
+	merkle_tree::StreamingOptions,
+	oracle::{ConstraintSetBuilder, MultilinearOracleSet, OracleId, ShiftVariant},
+	tower::{AESTowerFamily, CanonicalTowerFamily, TowerFamily, TowerUnderlier},
+	transcript::AdviceSection,
+	transparent::step_down::StepDown,
+	witness::MultilinearExtensionIndex,
+};
+
+type U = OptimalUnderlier;
+type F = BinaryField128b;
+type Witness = MultilinearExtensionIndex<'static, U, F>;
+type Prepared =
+	PreparedConstraintSystem<CanonicalTowerFamily, FRIPCS<Groestl256, Groestl256ByteCompression>>;
+
+const LOG_ROWS: usize = 10;
+
+pub(super) fn pcs_params() -> PCSParams {
+	PCSParams::new(1, 10)
+}
+
+fn column<FS>(values: &[FS]) -> MultilinearExtension<PackedType<U, FS>>
+where
+	FS: TowerField,
+	U: PackScalar<FS>,
+{
+	let mut packed = vec![PackedType::<U, FS>::zero(); values.len() / PackedType::<U, FS>::WIDTH];
+	for (i, &value) in values.iter().enumerate() {
+		set_packed_slice(&mut packed, i, value);
+	}
+	MultilinearExtension::from_values(packed).unwrap()
+}
+
+/// A Fibonacci sequence over `BinaryField32b`, where every row is the sum of the previous two, with
+/// its witness and boundaries.
+///
+/// The sequence is a committed column with its two shifts, and the step constraint is disabled by
+/// a step-down selector in the last two rows. The first two values are pushed into a channel and
+/// pulled by the boundaries.
+pub(super) fn fixture() -> (ConstraintSystem<F>, Witness, Vec<Boundary<F>>) {
+	fixture_in::<CanonicalTowerFamily>()
+}
+
+/// The [`fixture`] over the fields of `Tower`.
+#[allow(clippy::type_complexity)]
+fn fixture_in<Tower: TowerFamily>() -> (
+	ConstraintSystem<Tower::B128>,
+	MultilinearExtensionIndex<'static, U, Tower::B128>,
+	Vec<Boundary<Tower::B128>>,
+)
+where
+	U: TowerUnderlier<Tower>,
+	PackedType<U, Tower::B128>:
+		RepackedExtension<PackedType<U, Tower::B1>> + RepackedExtension<PackedType<U, Tower::B32>>,
+{
+	let mut oracles = MultilinearOracleSet::<Tower::B128>::new();
+	let current = oracles
+		.add_named("current")
+		.committed(LOG_ROWS, Tower::B32::TOWER_LEVEL);
+	let [next, next_next] = [(1, "next"), (2, "next_next")].map(|(offset, name)| {
+		oracles
+			.add_named(name)
+			.shifted(current, offset, LOG_ROWS, ShiftVariant::LogicalRight)
+			.unwrap()
+	});
+	let enabled_poly = StepDown::new(LOG_ROWS, (1 << LOG_ROWS) - 2).unwrap();
+	let enabled = oracles
+		.add_named("enabled")
+		.transparent(enabled_poly.clone())
+		.unwrap();
+
+	let mut constraints = ConstraintSetBuilder::new();
+	constraints.add_zerocheck(
+		"step",
+		[current, next, next_next, enabled],
+		(ArithExpr::Var(0) + ArithExpr::Var(1) - ArithExpr::Var(2)) * ArithExpr::Var(3),
+	);
+	let table_constraints = constraints.build(&oracles).unwrap();
+
+	let mut channels = ChannelRegistry::new();
+	let channel_id = channels.add(ChannelInfo {
+		name: Some("fib".to_string()),
+		..Default::default()
+	});
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut values = vec![Tower::B32::ZERO; 1 << LOG_ROWS];
+	values[0] = <Tower::B32 as Field>::random(&mut rng);
+	values[1] = <Tower::B32 as Field>::random(&mut rng);
+	for i in 2..values.len() {
+		values[i] = values[i - 1] + values[i - 2];
+	}
+	let shifted = |offset: usize| {
+		let mut shifted = values[offset..].to_vec();
+		shifted.resize(values.len(), Tower::B32::ZERO);
+		shifted
+	};
+	let mut enabled_values = vec![
+		PackedType::<U, Tower::B1>::zero();
+		1 << (LOG_ROWS - PackedType::<U, Tower::B1>::LOG_WIDTH)
+	];
+	enabled_poly.populate(&mut enabled_values);
+
+	let mut witness = MultilinearExtensionIndex::new();
+	witness
+		.update_multilin_poly([
+			(current, column(&values).specialize_arc_dyn()),
+			(next, column(&shifted(1)).specialize_arc_dyn()),
+			(next_next, column(&shifted(2)).specialize_arc_dyn()),
+			(
+				enabled,
+				MultilinearExtension::from_values(enabled_values)
+					.unwrap()
+					.specialize_arc_dyn(),
+			),
+		])
+		.unwrap();
+
+	let boundaries = values[..2]
+		.iter()
+		.map(|&value| Boundary {
+			values: vec![value.into()],
+			channel_id,
+			direction: FlushDirection::Pull,
+			multiplicity: 1,
+		})
+		.collect::<Vec<_>>();
+
+	let constraint_system = ConstraintSystem {
+		oracles,
+		table_constraints,
+		non_zero_oracle_ids: vec![],
+		flushes: vec![Flush {
+			oracles: vec![current],
+			channel_id,
+			direction: FlushDirection::Push,
+			count: 2,
+			multiplicity: 1,
+		}],
+		matmuls: vec![],
+		max_channel_id: channel_id,
+		channels,
+	};
+	validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	(constraint_system, witness, boundaries)
+}
+
+/// One segment of a continuation that multiplies its state by a generator on every row, with its
+/// witness, state channel and exit state.
+fn generator_segment(entry: BinaryField32b) -> (ConstraintSystem<F>, Witness, ChannelId, F) {
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let [current, next] = ["current", "next"].map(|name| {
+		oracles
+			.add_named(name)
+			.committed(LOG_ROWS, BinaryField32b::TOWER_LEVEL)
+	});
+	let generator = BinaryField32b::MULTIPLICATIVE_GENERATOR;
+
+	let mut constraints = ConstraintSetBuilder::new();
+	constraints.add_zerocheck(
+		"step",
+		[current, next],
+		ArithExpr::Var(0) * ArithExpr::Const(F::from(generator)) - ArithExpr::Var(1),
+	);
+	let table_constraints = constraints.build(&oracles).unwrap();
+
+	let mut channels = ChannelRegistry::new();
+	let state_channel = channels.add(ChannelInfo {
+		name: Some("state".to_string()),
+		..Default::default()
+	});
+
+	let mut states = (0..=1 << LOG_ROWS)
+		.scan(entry, |state, _| {
+			let current = *state;
+			*state *= generator;
+			Some(current)
+		})
+		.collect::<Vec<_>>();
+	let mut witness = Witness::new();
+	witness
+		.update_multilin_poly([
+			(next, column(&states[1..]).specialize_arc_dyn()),
+			(current, column(&states[..1 << LOG_ROWS]).specialize_arc_dyn()),
+		])
+		.unwrap();
+	let exit = states.pop().expect("states has 2^LOG_ROWS + 1 elements");
+
+	let constraint_system = ConstraintSystem {
+		oracles,
+		table_constraints,
+		non_zero_oracle_ids: vec![],
+		flushes: [
+			(current, FlushDirection::Pull),
+			(next, FlushDirection::Push),
+		]
+		.map(|(oracle, direction)| Flush {
+			oracles: vec![oracle],
+			channel_id: state_channel,
+			direction,
+			count: 1 << LOG_ROWS,
+			multiplicity: 1,
+		})
+		.to_vec(),
+		matmuls: vec![],
+		max_channel_id: state_channel,
+		channels,
+	};
+	let boundaries =
+		continuation::segment_boundaries(state_channel, &[entry.into()], &[exit.into()]);
+	validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	(constraint_system, witness, state_channel, exit.into())
+}
+
+/// The ID of the oracle of the fixture with the given name.
+fn fixture_oracle(constraint_system: &ConstraintSystem<F>, name: &str) -> OracleId {
+	constraint_system
+		.oracles
+		.iter()
+		.find(|oracle| oracle.name() == Some(name))
+		.expect("the fixture names this oracle")
+		.id()
+}
+
+pub(super) fn prove_fixture(
+	constraint_system: &ConstraintSystem<F>,
+	witness: Witness,
+	options: &ProveOptions,
+) -> Result<Proof, Error> {
+	prove_with_options::<
+		U,
+		CanonicalTowerFamily,
+		_,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		constraint_system,
+		&pcs_params(),
+		witness,
+		DefaultEvaluationDomainFactory::default(),
+		&make_portable_backend(),
+		options,
+	)
+}
+
+fn prove_fixture_with_pcs<PCS: CommitmentScheme<CanonicalTowerFamily>>(
+	constraint_system: &ConstraintSystem<F>,
+	pcs: &PCS,
+	witness: Witness,
+) -> Result<Proof, Error> {
+	prove_with_pcs::<U, CanonicalTowerFamily, _, _, HasherChallenger<Groestl256>, _>(
+		constraint_system,
+		pcs,
+		witness,
+		DefaultEvaluationDomainFactory::default(),
+		&make_portable_backend(),
+	)
+}
+
+pub(super) fn verify_fixture(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	proof: Proof,
+) -> Result<(), Error> {
+	verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(constraint_system, &pcs_params(), boundaries.to_vec(), proof)
+}
+
+#[test]
+fn test_prove_streaming() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let expected =
+		prove_fixture(&constraint_system, fixture().1, &ProveOptions::default()).unwrap();
+	let (transcript, advice) = prove_streaming::<
+		U,
+		CanonicalTowerFamily,
+		_,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+		_,
+	>(
+		&constraint_system,
+		&pcs_params(),
+		witness,
+		DefaultEvaluationDomainFactory::default(),
+		&make_portable_backend(),
+		Vec::new(),
+	)
+	.unwrap();
+	assert_eq!(transcript, expected.transcript);
+	assert_eq!(advice, expected.advice);
+
+	let proof = Proof {
+		transcript,
+		advice,
+		encoding: Default::default(),
+		metadata: None,
+		n_public_inputs: 0,
+		phases: Vec::new(),
+	};
+	verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+}
+
+#[test]
+fn test_prove_with_options() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let thread_pool = ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+	let options = ProveOptions::default()
+		.with_cancellation(CancellationToken::new())
+		.with_thread_pool(Arc::new(thread_pool));
+	let proof = prove_fixture(&constraint_system, witness, &options).unwrap();
+	verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+}
+
+#[test]
+fn test_prove_cancelled() {
+	let (constraint_system, witness, _) = fixture();
+	let cancellation = CancellationToken::new();
+	let options = ProveOptions::default().with_cancellation(cancellation.clone());
+	cancellation.cancel();
+	assert!(matches!(prove_fixture(&constraint_system, witness, &options), Err(Error::Cancelled)));
+}
+
+#[test]
+fn test_prove_compact_encoding() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let options = ProveOptions::default().with_encoding(ProofEncoding::compact());
+	let proof = prove_fixture(&constraint_system, witness, &options).unwrap();
+	assert_eq!(proof.encoding, ProofEncoding::compact());
+	verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+}
+
+#[test]
+fn test_prove_verify_with_metadata() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let metadata = ProofMetadata::new(1)
+		.with_circuit_digest(b"fib".to_vec())
+		.with_public_input_hash(hash_boundaries::<Groestl256, F>(&boundaries));
+	let options = ProveOptions::default().with_metadata(metadata.clone());
+	let proof = prove_fixture(&constraint_system, witness, &options).unwrap();
+	assert_eq!(proof.metadata.as_ref(), Some(&metadata));
+
+	// The metadata is bound to the transcript, so the proof does not verify without it or with
+	// different metadata.
+	let mut stripped = proof.clone();
+	stripped.metadata = None;
+	assert!(verify_fixture(&constraint_system, &boundaries, stripped).is_err());
+	let mut tampered = proof.clone();
+	tampered.metadata = Some(metadata.with_prover_version("other"));
+	assert!(verify_fixture(&constraint_system, &boundaries, tampered).is_err());
+
+	verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+}
+
+#[test]
+fn test_prove_verify_with_public_inputs() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let inputs = |seed: u32| {
+		PublicInputs::from_scalars(&[BinaryField32b::new(seed), BinaryField32b::new(seed + 1)])
+	};
+	let options = ProveOptions::default()
+		.with_metadata(ProofMetadata::new(1))
+		.with_public_inputs(inputs(1));
+	let proof = prove_fixture(&constraint_system, witness, &options).unwrap();
+	assert_eq!(proof.n_public_inputs, 2);
+
+	// The proof is matched to the public inputs it commits to among several alternatives.
+	let alternatives = [inputs(0), inputs(1), inputs(2), inputs(1).with_slot(vec![])];
+	let matching = alternatives
+		.iter()
+		.map(|inputs| {
+			public_inputs_match::<CanonicalTowerFamily, HasherChallenger<Groestl256>>(
+				&proof, inputs,
+			)
+			.unwrap()
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(matching, [false, true, false, false]);
+
+	let verify = |public_inputs: &PublicInputs, proof| {
+		verify_with_public_inputs::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, &pcs_params(), boundaries.clone(), public_inputs, proof)
+	};
+	assert!(matches!(
+		verify(&inputs(2), proof.clone()),
+		Err(Error::Verification(VerificationError::PublicInputMismatch))
+	));
+	assert!(matches!(
+		verify(&PublicInputs::new(), proof.clone()),
+		Err(Error::Verification(VerificationError::PublicInputCountMismatch {
+			expected: 0,
+			got: 2
+		}))
+	));
+	verify(&inputs(1), proof).unwrap();
+}
+
+#[test]
+fn test_verify_prepared() {
+	// The constraint system is prepared once and reused for a second proof.
+	let (constraint_system, _, boundaries) = fixture();
+	let prepared = Prepared::new(&constraint_system, &pcs_params()).unwrap();
+	for _ in 0..2 {
+		let proof =
+			prove_fixture(&constraint_system, fixture().1, &ProveOptions::default()).unwrap();
+		verify_prepared::<U, CanonicalTowerFamily, _, HasherChallenger<Groestl256>>(
+			&prepared,
+			boundaries.clone(),
+			proof,
+		)
+		.unwrap();
+	}
+}
+
+#[test]
+fn test_verify_advice_layout() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let proof = prove_fixture(&constraint_system, witness, &ProveOptions::default()).unwrap();
+	let prepared = Prepared::new(&constraint_system, &pcs_params()).unwrap();
+	let layout = verify_prepared_with_advice_layout::<
+		U,
+		CanonicalTowerFamily,
+		_,
+		HasherChallenger<Groestl256>,
+	>(&prepared, boundaries, &Default::default(), proof)
+	.unwrap();
+
+	// The sections are contiguous and cover the whole advice.
+	let mut position = 0;
+	for (_, range) in layout.sections() {
+		assert_eq!(range.start, position);
+		position = range.end;
+	}
+	assert_eq!(position, layout.bytes().len());
+
+	assert!(layout.count(AdviceSection::EvalcheckProofCount).unwrap() > Some(0));
+	assert!(layout
+		.count(AdviceSection::VirtualOpeningRoundCount)
+		.unwrap()
+		.is_some());
+	assert!(layout.get(AdviceSection::FriTerminateCodeword).is_some());
+	assert!(layout.get(AdviceSection::FriLayers).is_some());
+	assert!(layout.get(AdviceSection::FriQuery(0)).is_some());
+}
+
+#[test]
+fn test_prove_batched_non_zero() {
+	let prove = |non_zero: &[&str]| {
+		let (mut constraint_system, witness, boundaries) = fixture();
+		constraint_system.non_zero_oracle_ids = non_zero
+			.iter()
+			.map(|name| fixture_oracle(&constraint_system, name))
+			.collect();
+		let proof = prove_fixture(&constraint_system, witness, &ProveOptions::default())?;
+		verify_fixture(&constraint_system, &boundaries, proof)
+	};
+
+	// The sequence has no zeros, while its shifts are zero in their last rows.
+	prove(&["current"]).unwrap();
+	for non_zero in [&["current", "next"][..], &["next_next", "current"]] {
+		assert!(matches!(prove(non_zero), Err(Error::Zeros)));
+	}
+}
+
+#[test]
+fn test_prove_expected_witness_hash() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let hash = witness.content_hash::<Groestl256>().unwrap();
+	assert_eq!(fixture().1.content_hash::<Groestl256>().unwrap(), hash);
+	let mut other_witness = fixture().1;
+	let current = fixture_oracle(&constraint_system, "current");
+	other_witness
+		.update_multilin_poly([(
+			current,
+			column(&[BinaryField32b::ONE; 1 << LOG_ROWS]).specialize_arc_dyn(),
+		)])
+		.unwrap();
+	assert_ne!(other_witness.content_hash::<Groestl256>().unwrap(), hash);
+
+	let prove = |witness, expected_hash: &[u8]| {
+		let options = ProveOptions::default().with_expected_witness_hash(expected_hash);
+		prove_fixture(&constraint_system, witness, &options)
+	};
+	let mut wrong_hash = hash.to_vec();
+	wrong_hash[0] ^= 1;
+	assert!(matches!(prove(fixture().1, &wrong_hash), Err(Error::WitnessHashMismatch { .. })));
+
+	let proof = prove(witness, &hash).unwrap();
+	verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+}
+
+#[test]
+fn test_prove_with_hardware_hashers() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let proof =
+		prove::<U, CanonicalTowerFamily, _, Sha256, AesCompression, HasherChallenger<Sha256>, _>(
+			&constraint_system,
+			&pcs_params(),
+			witness,
+			DefaultEvaluationDomainFactory::default(),
+			&make_portable_backend(),
+		)
+		.unwrap();
+	verify::<U, CanonicalTowerFamily, Sha256, AesCompression, HasherChallenger<Sha256>>(
+		&constraint_system,
+		&pcs_params(),
+		boundaries,
+		proof,
+	)
+	.unwrap();
+}
+
+#[test]
+fn test_prove_lazy_column() {
+	let (constraint_system, mut witness, boundaries) = fixture();
+	let current = fixture_oracle(&constraint_system, "current");
+	let values = witness.get_multilin_poly(current).unwrap();
+	witness.update_lazy_multilin_poly(current, Arc::new(move || Ok(values.clone())));
+	assert!(witness.is_lazy(current));
+	validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+
+	let proof = prove_fixture(&constraint_system, witness, &ProveOptions::default()).unwrap();
+	verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+}
+
+/// The bytes of the packed evaluations of the witness for the given oracle.
+fn witness_bytes(witness: &Witness, id: OracleId) -> usize {
+	let multilin = witness.get_multilin_poly(id).unwrap();
+	std::mem::size_of_val(multilin.packed_evals().unwrap())
+}
+
+#[test]
+fn test_prove_drop_after_commit() {
+	let prove = |mark: &[&str]| {
+		let (mut constraint_system, mut witness, boundaries) = fixture();
+		let current = fixture_oracle(&constraint_system, "current");
+		// A copy of the sequence that no constraint uses, so the prover does not need it.
+		let helper = constraint_system
+			.oracles
+			.add_named("helper")
+			.linear_combination(LOG_ROWS, [(current, F::ONE)])
+			.unwrap();
+		let current_witness = witness.get_multilin_poly(current).unwrap();
+		let current_evals = <PackedType<U, F> as PackedExtension<BinaryField32b>>::cast_bases(
+			current_witness.packed_evals().unwrap(),
+		)
+		.to_vec();
+		witness
+			.update_multilin_poly([(
+				helper,
+				MultilinearExtension::from_values(current_evals)
+					.unwrap()
+					.specialize_arc_dyn(),
+			)])
+			.unwrap();
+		let helper_bytes = witness_bytes(&witness, helper);
+		// The shifted columns are virtual, only the sequence is committed.
+		let committed_bytes = witness_bytes(&witness, current);
+
+		for name in mark {
+			witness.drop_after_commit(fixture_oracle(&constraint_system, name));
+		}
+		let profiler = ProverProfiler::new();
+		let options = ProveOptions::default().with_profiler(profiler.clone());
+		let proof = prove_fixture(&constraint_system, witness, &options)?;
+		verify_fixture(&constraint_system, &boundaries, proof)?;
+		Ok::<_, Error>((profiler.profile().unwrap(), helper_bytes, committed_bytes))
+	};
+
+	let (kept, helper_bytes, committed_bytes) = prove(&[]).unwrap();
+	// Marking the committed sequence has no effect, as the opening proof reads it.
+	let (dropped, ..) = prove(&["helper", "current"]).unwrap();
+
+	let phases = |profile: &ProverProfile| {
+		profile
+			.witness_memory
+			.iter()
+			.map(|&(phase, _)| phase)
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(phases(&kept), phases(&dropped));
+	for (&(phase, kept_bytes), &(_, dropped_bytes)) in
+		iter::zip(&kept.witness_memory, &dropped.witness_memory)
+	{
+		match phase {
+			phase::COMMITMENT => assert_eq!(dropped_bytes, kept_bytes),
+			// Only the committed columns are held after evalcheck, the last phase that reads the
+			// witness.
+			phase::RING_SWITCH | phase::PCS_OPENING => {
+				assert_eq!(kept_bytes, committed_bytes);
+				assert_eq!(dropped_bytes, committed_bytes);
+			}
+			_ => assert_eq!(dropped_bytes, kept_bytes - helper_bytes),
+		}
+	}
+	// The prover allocates the witnesses of the flushes after the commitment.
+	assert_eq!(dropped.peak_witness_memory(), kept.peak_witness_memory() - helper_bytes);
+
+	// The transparent column is read by the zerocheck, so the prover fails without it.
+	assert!(prove(&["enabled"]).is_err());
+}
+
+#[test]
+fn test_prove_verify_trivial_pcs() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let proof = prove_fixture_with_pcs(&constraint_system, &TrivialPCS, witness).unwrap();
+	verify_with_pcs::<U, CanonicalTowerFamily, _, HasherChallenger<Groestl256>>(
+		&constraint_system,
+		TrivialPCS,
+		boundaries,
+		proof,
+	)
+	.unwrap();
+}
+
+/// Proves the fixture in deterministic mode on `thread_pool`.
+fn prove_fixture_deterministic(thread_pool: ThreadPool) -> Proof {
+	let (constraint_system, witness, _) = fixture();
+	let options = ProveOptions::default()
+		.with_thread_pool(Arc::new(thread_pool))
+		.with_deterministic(true);
+	prove_fixture(&constraint_system, witness, &options).unwrap()
+}
+
+#[test]
+fn test_prove_verify_streamed_merkle_tree() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let pcs = FRIPCS::<Groestl256, Groestl256ByteCompression>::new(pcs_params())
+		.with_streamed_merkle_tree(StreamingOptions {
+			spill_dir: Some(std::env::temp_dir()),
+			..Default::default()
+		});
+	let proof = prove_fixture_with_pcs(&constraint_system, &pcs, witness).unwrap();
+	// The streamed tree has the same digests, so the proof verifies against the default scheme.
+	verify_fixture(&constraint_system, &boundaries, proof).unwrap();
+}
+
+#[test]
+fn test_prove_verify_merkle_cap_and_arity() {
+	for (log_arity, cap_height) in [(1, 3), (2, 0), (3, 4)] {
+		let (constraint_system, witness, boundaries) = fixture();
+		let pcs_params = pcs_params()
+			.with_merkle_log_arity(log_arity)
+			.with_merkle_cap_height(cap_height);
+		let proof = prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			&pcs_params,
+			witness,
+			DefaultEvaluationDomainFactory::default(),
+			&make_portable_backend(),
+		)
+		.unwrap();
+		verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, &pcs_params, boundaries, proof)
+		.unwrap();
+	}
+}
+
+#[test]
+fn test_deterministic_proofs_across_thread_counts() {
+	let proofs = [1, 2, 4, 4].map(|num_threads| {
+		let thread_pool = ThreadPoolBuilder::new()
+			.num_threads(num_threads)
+			.build()
+			.unwrap();
+		prove_fixture_deterministic(thread_pool)
+	});
+	for proof in &proofs[1..] {
+		assert_eq!(proof.transcript, proofs[0].transcript);
+		assert_eq!(proof.advice, proofs[0].advice);
+	}
+}
+
+#[test]
+fn test_dedicated_thread_pool_proofs_match() {
+	let default_pool = ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+	let mut config = ThreadPoolConfig::default()
+		.with_num_threads(2)
+		.with_fold_log_chunk_len(4);
+	if cfg!(target_os = "linux") {
+		config = config.with_pinned_cores([0]);
+	}
+	let dedicated_pool = config.build().unwrap();
+	assert_eq!(
+		prove_fixture_deterministic(dedicated_pool).transcript,
+		prove_fixture_deterministic(default_pool).transcript
+	);
+}
+
+#[test]
+fn test_verify_aes_tower_proof_with_canonical_tower() {
+	let (aes_constraint_system, witness, aes_boundaries) = fixture_in::<AESTowerFamily>();
+	let proof = prove::<
+		U,
+		AESTowerFamily,
+		_,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&aes_constraint_system,
+		&pcs_params(),
+		witness,
+		DefaultEvaluationDomainFactory::default(),
+		&make_portable_backend(),
+	)
+	.unwrap();
+
+	let (constraint_system, _, _) = fixture();
+	let boundaries = aes_boundaries.iter().map(Boundary::isomorphic).collect();
+	verify_isomorphic::<
+		U,
+		CanonicalTowerFamily,
+		AESTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, &pcs_params(), boundaries, proof)
+	.unwrap();
+}
+
+#[test]
+fn test_verify_canonical_tower_proof_with_aes_tower() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let proof = prove_fixture(&constraint_system, witness, &ProveOptions::default()).unwrap();
+
+	let (aes_constraint_system, _, _) = fixture_in::<AESTowerFamily>();
+	let aes_boundaries = boundaries.iter().map(Boundary::isomorphic).collect();
+	verify_isomorphic::<
+		U,
+		AESTowerFamily,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&aes_constraint_system, &pcs_params(), aes_boundaries, proof)
+	.unwrap();
+}
+
+#[test]
+fn test_prove_many_statements_over_shared_commitment() {
+	let (constraint_system, witness, boundaries) = fixture();
+	let pcs = FRIPCS::<Groestl256, Groestl256ByteCompression>::new(pcs_params());
+	let shared = SharedCommitment::<U, CanonicalTowerFamily, _>::commit::<
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, pcs.clone(), &witness)
+	.unwrap();
+
+	// The second statement additionally checks that the committed sequence has no zeros.
+	let mut non_zero = constraint_system.clone();
+	non_zero.non_zero_oracle_ids = vec![fixture_oracle(&constraint_system, "current")];
+	let mut proofs = Vec::new();
+	for statement in [constraint_system, non_zero] {
+		let proof = shared
+			.prove::<_, HasherChallenger<Groestl256>, _>(
+				&statement,
+				fixture().1,
+				DefaultEvaluationDomainFactory::default(),
+				&make_portable_backend(),
+			)
+			.unwrap();
+		verify_shared::<U, CanonicalTowerFamily, _, HasherChallenger<Groestl256>>(
+			&statement,
+			pcs.clone(),
+			shared.commitment(),
+			boundaries.clone(),
+			proof.clone(),
+		)
+		.unwrap();
+		proofs.push((statement, proof));
+	}
+
+	// The proofs are ordinary proofs, but not of another commitment.
+	let (statement, proof) = &proofs[1];
+	verify_fixture(statement, &boundaries, proof.clone()).unwrap();
+	let mut other_commitment = shared.commitment().to_vec();
+	other_commitment[0] ^= 1;
+	assert!(verify_shared::<U, CanonicalTowerFamily, _, HasherChallenger<Groestl256>>(
+		statement,
+		pcs,
+		&other_commitment,
+		boundaries.clone(),
+		proof.clone(),
+	)
+	.is_err());
+
+	// A statement committing other data cannot use the shared commitment.
+	let (statement, witness, _, _) = generator_segment(BinaryField32b::ONE);
+	let result = shared.prove::<_, HasherChallenger<Groestl256>, _>(
+		&statement,
+		witness,
+		DefaultEvaluationDomainFactory::default(),
+		&make_portable_backend(),
+	);
+	assert!(matches!(result, Err(Error::CommittedOraclesMismatch)));
+}
+
+#[test]
+fn test_continuation() {
+	// Every segment is proven with its own witness.
+	let initial = F::ONE;
+	let mut state = initial;
+	let mut segments = Vec::new();
+	for index in 0..3 {
+		let (constraint_system, witness, _, exit) =
+			generator_segment(BinaryField32b::try_from(state).unwrap());
+		let segment = continuation::prove_segment::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			&pcs_params(),
+			index,
+			vec![state],
+			vec![exit],
+			witness,
+			DefaultEvaluationDomainFactory::default(),
+			&make_portable_backend(),
+			&ProveOptions::default(),
+		)
+		.unwrap();
+		segments.push(segment);
+		state = exit;
+	}
+
+	let (constraint_system, _, state_channel, _) = generator_segment(BinaryField32b::ONE);
+	let verify = |entry: F, exit: F, proof| {
+		continuation::verify_continuation::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, &pcs_params(), state_channel, &[entry], &[exit], proof)
+	};
+
+	let proof = SegmentedProof::link(segments.clone()).unwrap();
+	verify(initial, state, proof.clone()).unwrap();
+	assert!(matches!(
+		verify(initial, initial, proof),
+		Err(Error::Verification(VerificationError::ContinuationEndpointMismatch))
+	));
+
+	// A segment claiming another exit state does not verify.
+	let mut tampered = segments.clone();
+	tampered[2].exit = vec![F::ONE];
+	let proof = SegmentedProof::link(tampered).unwrap();
+	assert!(matches!(
+		verify(initial, F::ONE, proof),
+		Err(Error::Verification(VerificationError::PublicInputMismatch))
+	));
+
+	// Segments out of order are not linked.
+	segments.swap(1, 2);
+	assert!(matches!(
+		SegmentedProof::link(segments),
+		Err(Error::Verification(VerificationError::SegmentNotLinked { segment: 1 }))
+	));
+}
@@ -10,10 +10,10 @@ use binius_utils::bail;
 use super::{
 	channel::{self, Boundary},
 	error::Error,
-	ConstraintSystem,
+	matmul, ConstraintSystem,
 };
 use crate::{
-	oracle::{ConstraintPredicate, MultilinearPolyOracle},
+	oracle::{ConstraintPredicate, MultilinearPolyOracle, OracleId},
 	polynomial::{test_utils::decompose_index_to_hypercube_point, ArithCircuitPoly},
 	protocols::sumcheck::prove::zerocheck,
 	witness::MultilinearExtensionIndex,
@@ -65,8 +65,15 @@ where
 		&constraint_system.flushes,
 		boundaries,
 		constraint_system.max_channel_id,
+		&constraint_system.channels,
 	)?;
 
+	// Check that the product matrices are the products of their factors
+	for matmul in &constraint_system.matmuls {
+		matmul.validate(&constraint_system.oracles)?;
+	}
+	matmul::validate_witness(witness, &constraint_system.matmuls)?;
+
 	// Check consistency of virtual oracle witnesses (eg. that shift polynomials are actually shifts).
 	for oracle in constraint_system.oracles.iter() {
 		validate_virtual_oracle_witness(oracle, witness)?;
@@ -85,12 +92,19 @@ where
 {
 	use MultilinearPolyOracle::*;
 
+	if let Univariate { .. } = oracle {
+		// Univariate oracles are not multilinear and have no witness of their own.
+		return Ok(());
+	}
+
+	let oracle_id = oracle.id();
 	let oracle_label = &oracle.label();
 	let n_vars = oracle.n_vars();
 	let poly = witness.get_multilin_poly(oracle.id())?;
 
 	if poly.n_vars() != n_vars {
 		bail!(Error::VirtualOracleNvarsMismatch {
+			id: oracle_id,
 			oracle: oracle_label.into(),
 			oracle_num_vars: n_vars,
 			witness_num_vars: poly.n_vars(),
@@ -107,7 +121,7 @@ where
 				let expected = inner
 					.poly()
 					.evaluate(&decompose_index_to_hypercube_point(n_vars, i))?;
-				check_eval(oracle_label, i, expected, got)?;
+				check_eval(oracle_id, oracle_label, i, expected, got)?;
 			}
 		}
 		LinearCombination {
@@ -125,7 +139,7 @@ where
 					.try_fold(linear_combination.offset(), |acc, (coeff, poly)| {
 						Ok::<F, Error>(acc + poly.evaluate_on_hypercube_and_scale(i, coeff)?)
 					})?;
-				check_eval(oracle_label, i, expected, got)?;
+				check_eval(oracle_id, oracle_label, i, expected, got)?;
 			}
 		}
 		Repeating { inner, .. } => {
@@ -135,7 +149,7 @@ where
 				let got = poly.evaluate_on_hypercube(i)?;
 				let expected =
 					unrepeated_poly.evaluate_on_hypercube(i % (1 << unrepeated_n_vars))?;
-				check_eval(oracle_label, i, expected, got)?;
+				check_eval(oracle_id, oracle_label, i, expected, got)?;
 			}
 		}
 		Shifted { shifted, .. } => {
@@ -148,6 +162,7 @@ where
 					CircularLeft => {
 						for offset_after in 0..block_len {
 							check_eval(
+								oracle_id,
 								oracle_label,
 								block_start + offset_after,
 								unshifted_poly.evaluate_on_hypercube(
@@ -158,9 +173,23 @@ where
 							)?;
 						}
 					}
+					CircularRight => {
+						for offset_after in 0..block_len {
+							check_eval(
+								oracle_id,
+								oracle_label,
+								block_start + offset_after,
+								unshifted_poly.evaluate_on_hypercube(
+									block_start + (offset_after + shift_offset) % block_len,
+								)?,
+								poly.evaluate_on_hypercube(block_start + offset_after)?,
+							)?;
+						}
+					}
 					LogicalLeft => {
 						for offset_after in 0..shift_offset {
 							check_eval(
+								oracle_id,
 								oracle_label,
 								block_start + offset_after,
 								F::ZERO,
@@ -169,6 +198,7 @@ where
 						}
 						for offset_after in shift_offset..block_len {
 							check_eval(
+								oracle_id,
 								oracle_label,
 								block_start + offset_after,
 								unshifted_poly.evaluate_on_hypercube(
@@ -181,6 +211,7 @@ where
 					LogicalRight => {
 						for offset_after in 0..block_len - shift_offset {
 							check_eval(
+								oracle_id,
 								oracle_label,
 								block_start + offset_after,
 								unshifted_poly.evaluate_on_hypercube(
@@ -191,6 +222,7 @@ where
 						}
 						for offset_after in block_len - shift_offset..block_len {
 							check_eval(
+								oracle_id,
 								oracle_label,
 								block_start + offset_after,
 								F::ZERO,
@@ -212,6 +244,7 @@ where
 			};
 			for i in 0..1 << n_vars {
 				check_eval(
+					oracle_id,
 					oracle_label,
 					i,
 					projected_poly.evaluate_on_hypercube(i)?,
@@ -223,6 +256,7 @@ where
 			let unpadded_poly = witness.get_multilin_poly(inner.id())?;
 			for i in 0..1 << unpadded_poly.n_vars() {
 				check_eval(
+					oracle_id,
 					oracle_label,
 					i,
 					unpadded_poly.evaluate_on_hypercube(i)?,
@@ -230,7 +264,23 @@ where
 				)?;
 			}
 			for i in 1 << unpadded_poly.n_vars()..1 << n_vars {
-				check_eval(oracle_label, i, F::ZERO, poly.evaluate_on_hypercube(i)?)?;
+				check_eval(oracle_id, oracle_label, i, F::ZERO, poly.evaluate_on_hypercube(i)?)?;
+			}
+		}
+		Concatenated { concatenated, .. } => {
+			let inner_polys = concatenated
+				.polys()
+				.map(|oracle| witness.get_multilin_poly(oracle.id()))
+				.collect::<Result<Vec<_>, _>>()?;
+			let inner_n_vars = concatenated.inner_n_vars();
+			for i in 0..1 << n_vars {
+				let expected = match inner_polys.get(i >> inner_n_vars) {
+					Some(inner_poly) => {
+						inner_poly.evaluate_on_hypercube(i % (1 << inner_n_vars))?
+					}
+					None => F::ZERO,
+				};
+				check_eval(oracle_id, oracle_label, i, expected, poly.evaluate_on_hypercube(i)?)?;
 			}
 		}
 		Packed { id, packed, .. } => {
@@ -239,15 +289,18 @@ where
 			let got = witness.get_multilin_poly(id)?;
 			if expected.packed_evals() != got.packed_evals() {
 				return Err(Error::PackedUnderlierMismatch {
+					id,
 					oracle: oracle_label.into(),
 				});
 			}
 		}
+		Univariate { .. } => unreachable!("returned early above"),
 	}
 	Ok(())
 }
 
 fn check_eval<F: TowerField>(
+	oracle_id: OracleId,
 	oracle_label: &str,
 	index: usize,
 	expected: F,
@@ -257,9 +310,11 @@ fn check_eval<F: TowerField>(
 		Ok(())
 	} else {
 		Err(Error::VirtualOracleEvalMismatch {
+			id: oracle_id,
 			oracle: oracle_label.into(),
 			index,
-			reason: format!("Expected {expected}, got {got}"),
+			expected: expected.to_string(),
+			got: got.to_string(),
 		})
 	}
 }
@@ -267,8 +322,7 @@ fn check_eval<F: TowerField>(
 pub mod nonzerocheck {
 	use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, TowerField};
 	use binius_math::MultilinearPoly;
-	use binius_utils::bail;
-	use rayon::prelude::*;
+	use binius_utils::{bail, rayon::prelude::*};
 
 	use crate::{
 		oracle::{MultilinearOracleSet, OracleId},
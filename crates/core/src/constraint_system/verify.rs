@@ -9,25 +9,26 @@ use binius_field::{
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{ArithExpr, CompositionPolyOS};
 use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
-use digest::{core_api::BlockSizeUser, Digest, Output};
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 use itertools::{izip, multiunzip, Itertools};
 use tracing::instrument;
 
 use super::{
 	channel::Boundary,
 	error::{Error, VerificationError},
-	ConstraintSystem, Proof,
+	matmul, phase,
+	public_input::read_public_inputs,
+	CommitmentScheme, ConstraintSystem, PCSParams, PreparedConstraintSystem, Proof, PublicInputs,
+	FRIPCS,
 };
 use crate::{
 	composition::IndexComposition,
 	constraint_system::{
-		channel::{Flush, FlushDirection},
-		common::{FDomain, FEncode, FExt},
+		channel::{ChannelRegistry, Flush, FlushDirection},
+		common::FExt,
 	},
 	fiat_shamir::{CanSample, Challenger},
-	merkle_tree::BinaryMerkleTreeScheme,
 	oracle::{MultilinearOracleSet, OracleId},
-	piop,
 	polynomial::MultivariatePoly,
 	protocols::{
 		evalcheck::EvalcheckMultilinearClaim,
@@ -35,23 +36,30 @@ use crate::{
 		gkr_gpa::LayerClaim,
 		greedy_evalcheck,
 		sumcheck::{
-			self, constraint_set_zerocheck_claim,
+			self,
 			zerocheck::{self, ExtraProduct},
 			BatchSumcheckOutput, CompositeSumClaim, SumcheckClaim, ZerocheckClaim,
 		},
 	},
 	ring_switch,
 	tower::{PackedTop, TowerFamily, TowerUnderlier},
-	transcript::{AdviceReader, CanRead, Proof as ProofReader, TranscriptReader},
+	transcript::{AdviceLayout, AdviceReader, CanRead, Proof as ProofReader, TranscriptReader},
 	transparent::{eq_ind::EqIndPartialEval, step_down},
 };
+#[cfg(feature = "diagnostics")]
+use crate::{
+	constraint_system::metadata::PROVER_VERSION,
+	diagnostics::{self, FailureCategory, Rejection},
+};
 
 /// Verifies a proof against a constraint system.
-#[instrument("constraint_system::verify", skip_all, level = "debug")]
+///
+/// This prepares the constraint system on every call. When verifying many proofs against the same
+/// constraint system, prepare it once with [`PreparedConstraintSystem::new`] and use
+/// [`verify_prepared`] instead.
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
-	log_inv_rate: usize,
-	security_bits: usize,
+	pcs_params: &PCSParams,
 	boundaries: Vec<Boundary<FExt<Tower>>>,
 	proof: Proof,
 ) -> Result<(), Error>
@@ -59,43 +67,404 @@ where
 	U: TowerUnderlier<Tower>,
 	Tower: TowerFamily,
 	Tower::B128: PackedTop<Tower>,
-	Hash: Digest + BlockSizeUser,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
 	PackedType<U, Tower::B128>:
 		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
 {
-	let ConstraintSystem {
-		mut oracles,
-		mut table_constraints,
-		mut flushes,
-		non_zero_oracle_ids,
-		max_channel_id,
-		..
-	} = constraint_system.clone();
+	verify_with_pcs::<U, Tower, _, Challenger_>(
+		constraint_system,
+		FRIPCS::<Hash, Compress>::new(*pcs_params),
+		boundaries,
+		proof,
+	)
+}
 
-	// Stable sort constraint sets in descending order by number of variables.
-	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
+/// Verifies a proof generated with [`super::ProveOptions::with_public_inputs`] against a
+/// constraint system and the public inputs it must commit to.
+pub fn verify_with_public_inputs<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	public_inputs: &PublicInputs,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	let prepared = PreparedConstraintSystem::<Tower, _>::with_pcs(
+		constraint_system,
+		FRIPCS::<Hash, Compress>::new(*pcs_params),
+	)?;
+	verify_prepared_with_public_inputs::<U, Tower, _, Challenger_>(
+		&prepared,
+		boundaries,
+		public_inputs,
+		proof,
+	)
+}
 
-	let Proof { transcript, advice } = proof;
+/// Verifies a proof generated over an isomorphic tower against a constraint system over `Tower`.
+///
+/// The transcript writes and samples field elements in their canonical encoding, so the proof
+/// bytes do not depend on the tower basis. The commitment scheme and the protocol arithmetic do,
+/// however, so the constraint system and boundaries are mapped to `ProofTower` and the proof is
+/// verified there. This lets a verifier configured with the canonical tower accept proofs made
+/// with the AES tower, and vice versa.
+pub fn verify_isomorphic<U, Tower, ProofTower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<ProofTower>,
+	Tower: TowerFamily,
+	ProofTower: TowerFamily,
+	FExt<Tower>: From<FExt<ProofTower>>,
+	FExt<ProofTower>: From<FExt<Tower>>,
+	ProofTower::B128: PackedTop<ProofTower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	PackedType<U, ProofTower::B128>: PackedTop<ProofTower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, ProofTower::B128>>,
+{
+	let constraint_system = constraint_system.isomorphic()?;
+	let boundaries = boundaries
+		.iter()
+		.map(Boundary::isomorphic)
+		.collect::<Vec<_>>();
+	verify::<U, ProofTower, Hash, Compress, Challenger_>(
+		&constraint_system,
+		pcs_params,
+		boundaries,
+		proof,
+	)
+}
 
-	let mut transcript = TranscriptReader::<Challenger_>::new(transcript);
-	let mut advice = AdviceReader::new(advice);
+/// Verifies a proof generated by [`super::prove_with_pcs`] with the given polynomial commitment
+/// scheme.
+pub fn verify_with_pcs<U, Tower, PCS, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: PCS,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	let prepared = PreparedConstraintSystem::<Tower, PCS>::with_pcs(constraint_system, pcs)?;
+	verify_prepared::<U, Tower, PCS, Challenger_>(&prepared, boundaries, proof)
+}
+
+/// Verifies a proof against a prepared constraint system.
+pub fn verify_prepared<U, Tower, PCS, Challenger_>(
+	prepared: &PreparedConstraintSystem<Tower, PCS>,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	verify_prepared_with_public_inputs::<U, Tower, PCS, Challenger_>(
+		prepared,
+		boundaries,
+		&PublicInputs::default(),
+		proof,
+	)
+}
+
+/// Verifies a proof against a prepared constraint system and the public inputs it must commit to.
+///
+/// The public inputs are checked before anything else, so verifying a proof against alternative
+/// public inputs fails fast with [`VerificationError::PublicInputMismatch`] or
+/// [`VerificationError::PublicInputCountMismatch`] for all but the ones it was generated for.
+pub fn verify_prepared_with_public_inputs<U, Tower, PCS, Challenger_>(
+	prepared: &PreparedConstraintSystem<Tower, PCS>,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	public_inputs: &PublicInputs,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	verify_prepared_with_advice_layout::<U, Tower, PCS, Challenger_>(
+		prepared,
+		boundaries,
+		public_inputs,
+		proof,
+	)
+	.map(drop)
+}
+
+/// Verifies a proof like [`verify_prepared_with_public_inputs`], returning the context of a
+/// rejection, see [`crate::diagnostics`].
+///
+/// The rejection is boxed, as it is large compared to the unit result.
+#[cfg(feature = "diagnostics")]
+pub fn verify_prepared_with_diagnostics<U, Tower, PCS, Challenger_>(
+	prepared: &PreparedConstraintSystem<Tower, PCS>,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	public_inputs: &PublicInputs,
+	proof: Proof,
+) -> Result<(), Box<Rejection<Error>>>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	let prover_version = proof
+		.metadata
+		.as_ref()
+		.map(|metadata| metadata.prover_version.clone());
+	diagnostics::capture(
+		|| {
+			verify_prepared_with_public_inputs::<U, Tower, PCS, Challenger_>(
+				prepared,
+				boundaries,
+				public_inputs,
+				proof,
+			)
+		},
+		|error| categorize_rejection(error, prover_version.as_deref()),
+	)
+	.map_err(Box::new)
+}
 
-	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		&merkle_scheme,
-		security_bits,
-		log_inv_rate,
+#[cfg(feature = "diagnostics")]
+fn categorize_rejection(error: &Error, prover_version: Option<&str>) -> FailureCategory {
+	match error {
+		Error::Verification(
+			VerificationError::PublicInputMismatch
+			| VerificationError::PublicInputCountMismatch { .. },
+		) => return FailureCategory::StatementMismatch,
+		Error::AdviceDecompression(_) => return FailureCategory::Corruption,
+		_ => {}
+	}
+	if prover_version.is_some_and(|version| version != PROVER_VERSION) {
+		return FailureCategory::VersionSkew;
+	}
+	// Reading past the end of the transcript or leaving bytes unread means the proof is malformed.
+	let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error);
+	while let Some(err) = source {
+		if err.is::<crate::transcript::Error>() {
+			return FailureCategory::Corruption;
+		}
+		source = err.source();
+	}
+	FailureCategory::InvalidProof
+}
+
+/// Checks whether a proof commits to the given public inputs, without verifying it.
+///
+/// Only the public input check value at the start of the transcript is read, so this is a cheap
+/// way to select the public inputs a proof was generated for among several alternatives. A match
+/// does not imply that the proof is valid.
+pub fn public_inputs_match<Tower, Challenger_>(
+	proof: &Proof,
+	public_inputs: &PublicInputs,
+) -> Result<bool, Error>
+where
+	Tower: TowerFamily,
+	Challenger_: Challenger + Default,
+{
+	// The check value, plus the header byte of the compact scalar encoding
+	let prefix_len = (<FExt<Tower>>::N_BITS.div_ceil(8) + 1).min(proof.transcript.len());
+	let mut transcript =
+		TranscriptReader::<Challenger_>::new(proof.transcript[..prefix_len].to_vec())
+			.with_scalar_encoding(proof.encoding.scalars);
+	if let Some(metadata) = &proof.metadata {
+		transcript.absorb(&metadata.frame());
+	}
+	match read_public_inputs::<FExt<Tower>, _>(
+		&mut transcript,
+		proof.n_public_inputs,
+		public_inputs,
+	) {
+		Ok(()) => Ok(true),
+		Err(Error::Verification(
+			VerificationError::PublicInputCountMismatch { .. }
+			| VerificationError::PublicInputMismatch,
+		)) => Ok(false),
+		Err(err) => Err(err),
+	}
+}
+
+/// Verifies a proof against a prepared constraint system and returns the layout of its advice.
+///
+/// The layout splits the decompressed advice into the typed sections read by the verifier, so that
+/// tools and alternative verifiers can locate the advice of every sub-protocol.
+pub fn verify_prepared_with_advice_layout<U, Tower, PCS, Challenger_>(
+	prepared: &PreparedConstraintSystem<Tower, PCS>,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	public_inputs: &PublicInputs,
+	proof: Proof,
+) -> Result<AdviceLayout, Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	Challenger_: Challenger + Default,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	verify_prepared_with_challenger::<U, Tower, PCS, _>(
+		prepared,
+		boundaries,
+		public_inputs,
+		proof,
+		Challenger_::default(),
+	)
+}
+
+/// Verifies a proof generated by [`super::prove_with_challenger`], sampling the challenges from
+/// the given challenger.
+///
+/// The challenger must start in the same state as the one the proof was generated with. For an
+/// interaction recorded with an [`crate::fiat_shamir::ExternalChallenger`], this is a challenger
+/// replaying the recorded randomness, see [`super::interactive`].
+pub fn verify_with_challenger<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_params: &PCSParams,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	proof: Proof,
+	challenger: Challenger_,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	let prepared = PreparedConstraintSystem::<Tower, _>::with_pcs(
+		constraint_system,
+		FRIPCS::<Hash, Compress>::new(*pcs_params),
 	)?;
+	verify_prepared_with_challenger::<U, Tower, _, _>(
+		&prepared,
+		boundaries,
+		&PublicInputs::default(),
+		proof,
+		challenger,
+	)
+	.map(drop)
+}
+
+#[instrument(
+	"constraint_system::verify",
+	skip_all,
+	level = "debug",
+	fields(
+		n_oracles = prepared.oracles.size(),
+		n_committed = prepared.commit_meta.total_multilins(),
+		n_non_zero = prepared.non_zero_oracle_ids.len(),
+		n_flushes = prepared.flushes.len(),
+		n_matmuls = prepared.matmuls.len(),
+	)
+)]
+fn verify_prepared_with_challenger<U, Tower, PCS, Challenger_>(
+	prepared: &PreparedConstraintSystem<Tower, PCS>,
+	boundaries: Vec<Boundary<FExt<Tower>>>,
+	public_inputs: &PublicInputs,
+	proof: Proof,
+	challenger: Challenger_,
+) -> Result<AdviceLayout, Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: CommitmentScheme<Tower>,
+	Challenger_: Challenger,
+	PackedType<U, Tower::B128>:
+		PackedTop<Tower> + PackedFieldIndexable + RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	let PreparedConstraintSystem {
+		oracles,
+		non_zero_oracle_ids,
+		flushes,
+		matmuls,
+		max_channel_id,
+		channels,
+		pcs,
+		pcs_setup,
+		commit_meta,
+		oracle_to_commit_index,
+		zerocheck_claims,
+		zerocheck_oracle_metas,
+		max_n_vars,
+		skip_rounds,
+		..
+	} = prepared;
+	let mut oracles = oracles.clone();
+	let (max_n_vars, skip_rounds) = (*max_n_vars, *skip_rounds);
+
+	let Proof {
+		transcript,
+		advice,
+		encoding,
+		metadata,
+		n_public_inputs,
+		phases,
+	} = proof;
+
+	let mut transcript = TranscriptReader::with_challenger(transcript, challenger)
+		.with_scalar_encoding(encoding.scalars);
+	if !phases.is_empty() {
+		transcript = transcript.with_expected_phases(phases);
+	}
+	let mut advice = AdviceReader::new(encoding.decompress_advice(advice)?)
+		.with_scalar_encoding(encoding.scalars);
+
+	if let Some(metadata) = &metadata {
+		transcript.absorb(&metadata.frame());
+	}
+	read_public_inputs::<FExt<Tower>, _>(&mut transcript, n_public_inputs, public_inputs)?;
 
 	// Read polynomial commitment polynomials
-	let commitment = transcript.read::<Output<Hash>>()?;
+	transcript.begin_phase(phase::COMMITMENT);
+	let commitment = pcs.read_commitment(pcs_setup, commit_meta, &mut transcript)?;
 
 	// Grand product arguments
+	transcript.begin_phase(phase::GRAND_PRODUCT);
 	// Grand products for non-zero checks
+	let non_zero_oracle_ids = make_non_zero_oracles(&mut oracles, non_zero_oracle_ids)?;
 	let non_zero_products = transcript.read_scalar_slice(non_zero_oracle_ids.len())?;
 	if non_zero_products
 		.iter()
@@ -115,14 +484,14 @@ where
 	// TODO(cryptographers): Find a way to sample less randomness
 	let permutation_challenges = transcript.sample_vec(max_channel_id + 1);
 
-	flushes.sort_by_key(|flush| flush.channel_id);
 	let flush_oracle_ids =
-		make_flush_oracles(&mut oracles, &flushes, mixing_challenge, &permutation_challenges)?;
+		make_flush_oracles(&mut oracles, flushes, mixing_challenge, &permutation_challenges)?;
 	let flush_counts = flushes.iter().map(|flush| flush.count).collect::<Vec<_>>();
 
 	let flush_products = transcript.read_scalar_slice(flush_oracle_ids.len())?;
 	verify_channels_balance(
-		&flushes,
+		flushes,
+		channels,
 		&flush_products,
 		boundaries,
 		mixing_challenge,
@@ -163,6 +532,7 @@ where
 	let (flush_sumcheck_claims, gkr_eval_points, all_step_down_metas, flush_oracles_by_claim) =
 		get_flush_dedup_sumcheck_claims(flush_sumcheck_metas)?;
 
+	transcript.begin_phase(phase::FLUSH_SUMCHECK);
 	let flush_sumcheck_output = sumcheck::batch_verify(&flush_sumcheck_claims, &mut transcript)?;
 
 	let flush_eval_claims = get_post_flush_sumcheck_eval_claims_without_eq(
@@ -192,17 +562,7 @@ where
 	}
 
 	// Zerocheck
-	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
-		.iter()
-		.cloned()
-		.map(constraint_set_zerocheck_claim)
-		.collect::<Result<Vec<_>, _>>()?
-		.into_iter()
-		.unzip::<_, _, Vec<_>, Vec<_>>();
-
-	let (max_n_vars, skip_rounds) =
-		max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
-
+	transcript.begin_phase(phase::ZEROCHECK);
 	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
 
 	let univariate_cnt = zerocheck_claims
@@ -216,7 +576,7 @@ where
 
 	let univariate_challenge = univariate_output.univariate_challenge;
 
-	let sumcheck_claims = zerocheck::reduce_to_sumchecks(&zerocheck_claims)?;
+	let sumcheck_claims = zerocheck::reduce_to_sumchecks(zerocheck_claims)?;
 
 	let sumcheck_output = sumcheck::batch_verify_with_start(
 		univariate_output.batch_verify_start,
@@ -225,7 +585,7 @@ where
 	)?;
 
 	let zerocheck_output = zerocheck::verify_sumcheck_outputs(
-		&zerocheck_claims,
+		zerocheck_claims,
 		&zerocheck_challenges,
 		sumcheck_output,
 	)?;
@@ -235,7 +595,7 @@ where
 
 	let mut reduction_claims = Vec::with_capacity(univariate_cnt);
 	for (claim, univariatized_multilinear_evals) in
-		iter::zip(&zerocheck_claims, &zerocheck_output.multilinear_evals)
+		iter::zip(zerocheck_claims, &zerocheck_output.multilinear_evals)
 	{
 		let claim_skip_rounds = claim.n_vars().saturating_sub(max_n_vars - skip_rounds);
 
@@ -256,24 +616,58 @@ where
 		univariatizing_output,
 	)?;
 
-	let zerocheck_eval_claims =
-		sumcheck::make_eval_claims(&oracles, zerocheck_oracle_metas, multilinear_zerocheck_output)?;
+	let zerocheck_eval_claims = sumcheck::make_eval_claims(
+		&oracles,
+		zerocheck_oracle_metas.iter().cloned(),
+		multilinear_zerocheck_output,
+	)?;
+
+	// Matrix multiplications
+	let matmul_eval_claims = if matmuls.is_empty() {
+		Vec::new()
+	} else {
+		transcript.begin_phase(phase::MATMUL);
+		let matmul_challenges = transcript.sample_vec(matmul::n_matmul_challenges(matmuls));
+		let product_evals = transcript.read_scalar_slice(matmuls.len())?;
+
+		let (matmul_constraint_sets, product_eval_claims) = matmul::make_matmul_sumchecks(
+			&mut oracles,
+			matmuls,
+			&matmul_challenges,
+			&product_evals,
+		)?;
+
+		let sumcheck::SumcheckClaimsWithMeta { claims, metas } =
+			sumcheck::constraint_set_sumcheck_claims(matmul_constraint_sets)?;
+		let matmul_sumcheck_output = sumcheck::batch_verify(&claims, &mut transcript)?;
+
+		let mut matmul_eval_claims =
+			sumcheck::make_eval_claims(&oracles, metas, matmul_sumcheck_output)?;
+		matmul_eval_claims.extend(product_eval_claims);
+		matmul_eval_claims
+	};
 
 	// Evalcheck
+	transcript.begin_phase(phase::EVALCHECK);
 	let eval_claims = greedy_evalcheck::verify(
 		&mut oracles,
 		[non_zero_prodcheck_eval_claims, flush_eval_claims]
 			.concat()
 			.into_iter()
-			.chain(zerocheck_eval_claims),
+			.chain(zerocheck_eval_claims)
+			.chain(matmul_eval_claims),
 		&mut transcript,
 		&mut advice,
 	)?;
 
 	// Reduce committed evaluation claims to PIOP sumcheck claims
-	let system =
-		ring_switch::EvalClaimSystem::new(&commit_meta, oracle_to_commit_index, &eval_claims)?;
+	let system = ring_switch::EvalClaimSystem::new(
+		commit_meta,
+		oracle_to_commit_index.clone(),
+		&eval_claims,
+	)?;
 
+	transcript.begin_phase(phase::RING_SWITCH);
 	let mut proof_reader = ProofReader {
 		transcript: &mut transcript,
 		advice: &mut advice,
@@ -284,10 +678,10 @@ where
 	} = ring_switch::verify::<_, Tower, _, _>(&system, &mut proof_reader)?;
 
 	// Prove evaluation claims using PIOP compiler
-	piop::verify(
-		&commit_meta,
-		&merkle_scheme,
-		&fri_params,
+	proof_reader.transcript.begin_phase(phase::PCS_OPENING);
+	pcs.verify(
+		pcs_setup,
+		commit_meta,
 		&commitment,
 		&transparents,
 		&piop_sumcheck_claims,
@@ -295,9 +689,10 @@ where
 	)?;
 
 	transcript.finalize()?;
+	let advice_layout = advice.layout();
 	advice.finalize()?;
 
-	Ok(())
+	Ok(advice_layout)
 }
 
 pub fn max_n_vars_and_skip_rounds<F, Composition>(
@@ -341,6 +736,7 @@ where
 
 fn verify_channels_balance<F: TowerField>(
 	flushes: &[Flush],
+	channels: &ChannelRegistry,
 	flush_products: &[F],
 	boundaries: Vec<Boundary<F>>,
 	mixing_challenge: F,
@@ -349,6 +745,7 @@ fn verify_channels_balance<F: TowerField>(
 	if flush_products.len() != flushes.len() {
 		return Err(VerificationError::IncorrectNumberOfFlushProducts.into());
 	}
+	channels.validate_boundaries(&boundaries)?;
 
 	let mut flush_iter = flushes
 		.iter()
@@ -408,13 +805,67 @@ fn verify_channels_balance<F: TowerField>(
 				}
 			});
 		if pull_product != push_product {
-			return Err(VerificationError::ChannelUnbalanced { id: channel_id }.into());
+			#[cfg(feature = "diagnostics")]
+			crate::diagnostics::record_mismatch(
+				format!("channel {} balance", channels.label(channel_id)),
+				pull_product,
+				push_product,
+			);
+			return Err(VerificationError::ChannelUnbalanced {
+				channel_id,
+				channel: channels.label(channel_id),
+			}
+			.into());
 		}
 	}
 
 	Ok(())
 }
 
+/// Groups the non-zero oracles by their number of variables.
+///
+/// Every group is checked with a single grand product over the concatenation of its oracles. The
+/// concatenation pads with zeros up to a power-of-two number of oracles, so groups are padded by
+/// repeating their first oracle instead.
+pub fn non_zero_batches<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	non_zero_oracle_ids: &[OracleId],
+) -> Vec<Vec<OracleId>> {
+	non_zero_oracle_ids
+		.iter()
+		.copied()
+		.into_group_map_by(|&id| oracles.n_vars(id))
+		.into_iter()
+		.sorted_by_key(|&(n_vars, _)| n_vars)
+		.map(|(_, mut batch)| {
+			let first = batch[0];
+			batch.resize(batch.len().next_power_of_two(), first);
+			batch
+		})
+		.collect()
+}
+
+/// Adds the oracles whose grand products prove that all non-zero oracles have no zeros, one per
+/// batch returned by [`non_zero_batches`].
+pub fn make_non_zero_oracles<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	non_zero_oracle_ids: &[OracleId],
+) -> Result<Vec<OracleId>, Error> {
+	non_zero_batches(oracles, non_zero_oracle_ids)
+		.into_iter()
+		.map(|batch| {
+			if let [id] = batch[..] {
+				return Ok(id);
+			}
+			let n_vars = oracles.n_vars(batch[0]);
+			let id = oracles
+				.add_named(format!("non_zero n_vars={n_vars}"))
+				.concatenated(n_vars, batch)?;
+			Ok(id)
+		})
+		.collect()
+}
+
 pub fn make_flush_oracles<F: TowerField>(
 	oracles: &mut MultilinearOracleSet<F>,
 	flushes: &[Flush],
@@ -437,6 +888,8 @@ pub fn make_flush_oracles<F: TowerField>(
 						let oracle_n_vars = oracles.n_vars(oracle_id);
 						if oracle_n_vars != n_vars {
 							return Err(Error::ChannelFlushNvarsMismatch {
+								channel_id,
+								id: oracle_id,
 								expected: n_vars,
 								got: oracle_n_vars,
 							});
@@ -0,0 +1,211 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Structured reasons for rejected proofs.
+//!
+//! A verification error says which check failed, but not where in the proof it failed or with
+//! which values, which is what services need to tell corrupted proofs from proofs of another
+//! prover version or malicious ones. While [`capture`] runs a verification, the verifier records
+//! the transcript phase it is in, the round of the sumcheck it runs and, for the checks that
+//! compare a value claimed by the prover with one the verifier recomputes, both values. A failed
+//! verification is returned as a [`Rejection`] holding this context next to the error.
+//!
+//! Recording is per thread and only happens inside [`capture`], so verification outside of it is
+//! unaffected.
+
+use std::{cell::RefCell, fmt::Debug};
+
+/// The likely cause of a rejected proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureCategory {
+	/// The proof is malformed, for example truncated or with trailing bytes, as happens when it is
+	/// corrupted in transit or storage.
+	Corruption,
+	/// The proof was generated by another prover version, whose protocol may differ.
+	VersionSkew,
+	/// The proof is for another statement, for example other public inputs.
+	StatementMismatch,
+	/// The proof is well-formed but fails a check of the protocol.
+	InvalidProof,
+}
+
+/// A failed comparison of a value claimed by the prover with one recomputed by the verifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+	/// The name of the check.
+	pub check: String,
+	pub claimed: String,
+	pub recomputed: String,
+}
+
+/// A failed verification with the context the verifier recorded.
+#[derive(Debug)]
+pub struct Rejection<E> {
+	pub category: FailureCategory,
+	/// The transcript phase the verifier was in.
+	pub phase: Option<String>,
+	/// The round of the last sumcheck the verifier ran in that phase.
+	pub round: Option<usize>,
+	/// The first failed comparison, if the error was caused by one.
+	pub mismatch: Option<Mismatch>,
+	pub error: E,
+}
+
+#[derive(Debug, Default)]
+struct Recorder {
+	phase: Option<String>,
+	round: Option<usize>,
+	mismatch: Option<Mismatch>,
+}
+
+thread_local! {
+	static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+fn record(update: impl FnOnce(&mut Recorder)) {
+	RECORDER.with_borrow_mut(|recorder| {
+		if let Some(recorder) = recorder {
+			update(recorder);
+		}
+	});
+}
+
+/// Records the start of a transcript phase.
+pub(crate) fn record_phase(label: &str) {
+	record(|recorder| {
+		recorder.phase = Some(label.to_string());
+		recorder.round = None;
+	});
+}
+
+/// Records the start of a sumcheck round.
+pub(crate) fn record_round(round: usize) {
+	record(|recorder| recorder.round = Some(round));
+}
+
+/// Records a failed comparison, keeping the first one.
+pub(crate) fn record_mismatch(check: impl ToString, claimed: impl Debug, recomputed: impl Debug) {
+	record(|recorder| {
+		recorder.mismatch.get_or_insert_with(|| Mismatch {
+			check: check.to_string(),
+			claimed: format!("{claimed:?}"),
+			recomputed: format!("{recomputed:?}"),
+		});
+	});
+}
+
+/// Runs `verify`, recording the context of a failure on this thread, and categorizes its error
+/// with `categorize`.
+pub fn capture<T, E>(
+	verify: impl FnOnce() -> Result<T, E>,
+	categorize: impl FnOnce(&E) -> FailureCategory,
+) -> Result<T, Rejection<E>> {
+	let outer = RECORDER.replace(Some(Recorder::default()));
+	let result = verify();
+	let recorder = RECORDER
+		.replace(outer)
+		.expect("the recorder is installed above");
+	result.map_err(|error| Rejection {
+		category: categorize(&error),
+		phase: recorder.phase,
+		round: recorder.round,
+		mismatch: recorder.mismatch,
+		error,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		arch::OptimalUnderlier128b, as_packed_field::PackedType, BinaryField128b, BinaryField8b,
+		Field, PackedField,
+	};
+	use binius_hal::make_portable_backend;
+	use binius_math::{IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension};
+	use groestl_crypto::Groestl256;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		fiat_shamir::HasherChallenger,
+		protocols::{
+			sumcheck::{self, prove::RegularSumcheckProver, CompositeSumClaim, SumcheckClaim},
+			test_utils::TestProductComposition,
+		},
+		transcript::{TranscriptReader, TranscriptWriter},
+	};
+
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier128b, F>;
+	type Challenger = HasherChallenger<Groestl256>;
+
+	#[test]
+	fn test_capture_records_sumcheck_mismatch() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (n_vars, n_multilinears) = (4, 2);
+		let values = repeat_with(|| {
+			repeat_with(|| <F as Field>::random(&mut rng))
+				.take(1 << n_vars)
+				.collect::<Vec<_>>()
+		})
+		.take(n_multilinears)
+		.collect::<Vec<_>>();
+		let sum = (0..1 << n_vars)
+			.map(|x| values.iter().map(|values| values[x]).product::<F>())
+			.sum::<F>();
+
+		let composition = TestProductComposition::new(n_multilinears);
+		let backend = make_portable_backend();
+		let multilinears = values
+			.iter()
+			.map(|values| {
+				let values = values.iter().copied().map(P::broadcast).collect();
+				MLEDirectAdapter::from(MultilinearExtension::from_values(values).unwrap())
+			})
+			.collect();
+		let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+			multilinears,
+			[CompositeSumClaim {
+				composition: &composition,
+				sum,
+			}],
+			IsomorphicEvaluationDomainFactory::<BinaryField8b>::default(),
+			|_| 1,
+			&backend,
+		)
+		.unwrap();
+		let mut prover_transcript = TranscriptWriter::<Challenger>::default();
+		sumcheck::batch_prove(vec![prover], &mut prover_transcript).unwrap();
+		let transcript = prover_transcript.finalize();
+
+		let verify = |sum| {
+			let claim = SumcheckClaim::new(
+				n_vars,
+				n_multilinears,
+				vec![CompositeSumClaim {
+					composition: &composition,
+					sum,
+				}],
+			)
+			.unwrap();
+			let mut reader = TranscriptReader::<Challenger>::new(transcript.clone());
+			capture(
+				|| sumcheck::batch_verify(&[claim], &mut reader).map(drop),
+				|_| FailureCategory::InvalidProof,
+			)
+		};
+
+		assert!(verify(sum).is_ok());
+		let rejection = verify(sum + F::ONE).unwrap_err();
+		assert_eq!(rejection.category, FailureCategory::InvalidProof);
+		assert_eq!(rejection.round, Some(n_vars - 1));
+		let mismatch = rejection.mismatch.unwrap();
+		assert_eq!(mismatch.check, "sumcheck batch evaluation");
+		assert_ne!(mismatch.claimed, mismatch.recomputed);
+
+		// Nothing is recorded outside of `capture`.
+		record_round(0);
+		assert!(RECORDER.with_borrow(Option::is_none));
+	}
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 Irreducible Inc.
+
+use bytes::{Buf, BufMut};
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset};
+
+use super::{Challenger, HasherChallenger};
+
+/// The verifier side of an interactive protocol execution, which supplies the randomness of the
+/// challenges.
+pub trait ChallengeSource {
+	/// Returns fresh verifier randomness, given the prover messages sent since the previous call.
+	///
+	/// The message consists of all bytes the prover challenger observed, so the source can
+	/// forward it to a remote verifier, or record it.
+	fn challenge(&mut self, message: &[u8]) -> Vec<u8>;
+}
+
+impl<F: FnMut(&[u8]) -> Vec<u8>> ChallengeSource for F {
+	fn challenge(&mut self, message: &[u8]) -> Vec<u8> {
+		self(message)
+	}
+}
+
+/// A challenger that draws the randomness of its challenges from a [`ChallengeSource`] instead
+/// of deriving it from the prover messages alone.
+///
+/// Observed bytes are buffered until the next challenge is sampled. At that point the buffered
+/// message is handed to the source, and the message and the randomness returned by the source
+/// are both absorbed into a [`HasherChallenger`], which the challenges are sampled from until the
+/// prover sends another message. Challenges are thus bound to the whole interaction, like with
+/// Fiat-Shamir, and unpredictable to the prover as long as the source is.
+///
+/// This turns any protocol over a Fiat-Shamir transcript into an interactive protocol, where the
+/// source is the verifier. A verifier that records the interaction can check it afterwards by
+/// replaying the recorded randomness with another source.
+#[derive(Debug)]
+pub struct ExternalChallenger<H: Digest + BlockSizeUser, Source> {
+	source: Source,
+	message: Vec<u8>,
+	challenger: HasherChallenger<H>,
+	started: bool,
+}
+
+impl<H, Source> ExternalChallenger<H, Source>
+where
+	H: Digest + BlockSizeUser,
+{
+	pub fn new(source: Source) -> Self {
+		Self {
+			source,
+			message: Vec::new(),
+			challenger: HasherChallenger::default(),
+			started: false,
+		}
+	}
+
+	pub fn into_source(self) -> Source {
+		self.source
+	}
+}
+
+impl<H, Source> Challenger for ExternalChallenger<H, Source>
+where
+	H: Digest + BlockSizeUser + FixedOutputReset + Default,
+	Source: ChallengeSource,
+{
+	fn sampler(&mut self) -> &mut impl Buf {
+		if !self.started || !self.message.is_empty() {
+			let randomness = self.source.challenge(&self.message);
+			// Prefix both with their lengths, so that different interactions are absorbed
+			// differently
+			let observer = self.challenger.observer();
+			for data in [&self.message, &randomness] {
+				observer.put_u64_le(data.len() as u64);
+				observer.put_slice(data);
+			}
+			self.message.clear();
+			self.started = true;
+		}
+		self.challenger.sampler()
+	}
+
+	fn observer(&mut self) -> &mut impl BufMut {
+		&mut self.message
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::{
+		fiat_shamir::CanSample,
+		transcript::{CanRead, CanWrite, TranscriptReader, TranscriptWriter},
+	};
+
+	#[test]
+	fn test_replay_interaction() {
+		let mut interaction = Vec::new();
+		let source = |message: &[u8]| {
+			let randomness = vec![interaction.len() as u8; 16];
+			interaction.push((message.to_vec(), randomness.clone()));
+			randomness
+		};
+		let mut transcript =
+			TranscriptWriter::with_challenger(ExternalChallenger::<Groestl256, _>::new(source));
+		transcript.write_bytes(b"commitment");
+		let first: BinaryField128b = transcript.sample();
+		let second: BinaryField128b = transcript.sample();
+		transcript.write_bytes(b"response");
+		let third: BinaryField128b = transcript.sample();
+		let proof = transcript.finalize();
+
+		assert_eq!(
+			interaction,
+			[
+				(b"commitment".to_vec(), vec![0; 16]),
+				(b"response".to_vec(), vec![1; 16])
+			]
+		);
+		assert_ne!(first, second);
+
+		let mut replay = interaction.into_iter();
+		let source = |message: &[u8]| {
+			let (expected, randomness) = replay.next().unwrap();
+			assert_eq!(message, expected);
+			randomness
+		};
+		let mut transcript = TranscriptReader::with_challenger(
+			proof,
+			ExternalChallenger::<Groestl256, _>::new(source),
+		);
+		let mut buf = [0; 10];
+		transcript.read_bytes(&mut buf).unwrap();
+		assert_eq!(CanSample::<BinaryField128b>::sample(&mut transcript), first);
+		assert_eq!(CanSample::<BinaryField128b>::sample(&mut transcript), second);
+		let mut buf = [0; 8];
+		transcript.read_bytes(&mut buf).unwrap();
+		assert_eq!(CanSample::<BinaryField128b>::sample(&mut transcript), third);
+		transcript.finalize().unwrap();
+	}
+}
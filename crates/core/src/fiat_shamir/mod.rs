@@ -1,9 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod external;
 mod hasher_challenger;
 mod sampling;
 
 use bytes::{Buf, BufMut};
+pub use external::{ChallengeSource, ExternalChallenger};
 pub use hasher_challenger::HasherChallenger;
 pub use sampling::*;
 
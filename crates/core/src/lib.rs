@@ -12,8 +12,12 @@
 #![allow(clippy::suspicious_arithmetic_impl)]
 #![allow(clippy::suspicious_op_assign_impl)]
 
+#[cfg(feature = "benchmark")]
+pub mod benchmark;
 pub mod composition;
 pub mod constraint_system;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
 pub mod fiat_shamir;
 pub mod linear_code;
 pub mod merkle_tree;
@@ -23,6 +27,7 @@ pub mod polynomial;
 pub mod protocols;
 #[allow(clippy::module_inception)]
 pub mod reed_solomon;
+pub mod reference;
 pub mod ring_switch;
 pub mod tensor_algebra;
 pub mod tower;
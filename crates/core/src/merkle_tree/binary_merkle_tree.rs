@@ -1,31 +1,41 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, mem::MaybeUninit};
+use std::{fmt::Debug, iter, mem::MaybeUninit};
 
 use binius_field::{serialize_canonical, TowerField};
 use binius_hash::{HashBuffer, PseudoCompressionFunction};
-use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
+use binius_utils::{
+	bail,
+	checked_arithmetics::log2_strict_usize,
+	rayon::{prelude::*, slice::ParallelSlice},
+};
 use digest::{crypto_common::BlockSizeUser, Digest, FixedOutputReset, Output};
-use rayon::{prelude::*, slice::ParallelSlice};
 use tracing::instrument;
 
 use super::errors::Error;
 
-/// A binary Merkle tree that commits batches of vectors.
+/// A Merkle tree that commits batches of vectors.
 ///
 /// The vector entries at each index in a batch are hashed together into leaf digests. Then a
 /// Merkle tree is constructed over the leaf digests. The implementation requires that the vector
 /// lengths are all equal to each other and a power of two.
+///
+/// Every inner node has `2^log_arity` children, except for the nodes of the lowest inner layer
+/// when the height of the tree is not a multiple of `log_arity`. Hence the tree only stores the
+/// layers at depths that are multiples of `log_arity`, along with the leaves.
 #[derive(Debug, Clone)]
 pub struct BinaryMerkleTree<D> {
 	/// Base-2 logarithm of the number of leaves
 	pub log_len: usize,
+	/// Base-2 logarithm of the number of children of the inner nodes
+	pub log_arity: usize,
 	/// The inner nodes, arranged as a flattened array of layers with the root at the end
 	pub inner_nodes: Vec<D>,
 }
 
 pub fn build<F, H, C>(
 	compression: &C,
+	log_arity: usize,
 	elements: &[F],
 	batch_size: usize,
 ) -> Result<BinaryMerkleTree<Output<H>>, Error>
@@ -47,23 +57,30 @@ where
 	let log_len = log2_strict_usize(len);
 
 	internal_build(
-		compression,
+		|children| compress_children::<H, _>(compression, children),
 		|inner_nodes| hash_interleaved::<_, H>(elements, inner_nodes),
 		log_len,
+		log_arity,
 	)
 }
 
-fn internal_build<Digest, C>(
-	compression: &C,
+fn internal_build<Digest>(
+	compress: impl Fn(&[Digest]) -> Digest + Sync,
 	// Must either successfully initialize the passed in slice or return error
 	hash_leaves: impl FnOnce(&mut [MaybeUninit<Digest>]) -> Result<(), Error>,
 	log_len: usize,
+	log_arity: usize,
 ) -> Result<BinaryMerkleTree<Digest>, Error>
 where
 	Digest: Clone + Send + Sync,
-	C: PseudoCompressionFunction<Digest, 2> + Sync,
 {
-	let total_length = (1 << (log_len + 1)) - 1;
+	if log_arity == 0 {
+		bail!(Error::InvalidArity);
+	}
+
+	let total_length = layer_depths(log_len, log_arity)
+		.map(|depth| 1 << depth)
+		.sum();
 	let mut inner_nodes = Vec::with_capacity(total_length);
 
 	hash_leaves(&mut inner_nodes.spare_capacity_mut()[..(1 << log_len)])?;
@@ -74,11 +91,11 @@ where
 		// SAFETY: prev-layer was initialized by hash_leaves
 		slice_assume_init_mut(prev_layer)
 	};
-	for i in 1..(log_len + 1) {
-		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
+	for (depth, log_children) in layer_steps(log_len, 0, log_arity) {
+		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (depth - log_children));
 		remaining = next_remaining;
 
-		compress_layer(compression, prev_layer, next_layer);
+		compress_layer(&compress, prev_layer, next_layer, 1 << log_children);
 
 		prev_layer = unsafe {
 			// SAFETY: next_layer was just initialized by compress_layer
@@ -94,6 +111,7 @@ where
 	}
 	Ok(BinaryMerkleTree {
 		log_len,
+		log_arity,
 		inner_nodes,
 	})
 }
@@ -101,6 +119,7 @@ where
 #[instrument("BinaryMerkleTree::build", skip_all, level = "debug")]
 pub fn build_from_iterator<F, H, C, ParIter>(
 	compression: &C,
+	log_arity: usize,
 	iterated_chunks: ParIter,
 	log_len: usize,
 ) -> Result<BinaryMerkleTree<Output<H>>, Error>
@@ -111,9 +130,10 @@ where
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 {
 	internal_build(
-		compression,
+		|children| compress_children::<H, _>(compression, children),
 		|inner_nodes| hash_iterated::<F, H, _>(iterated_chunks, inner_nodes),
 		log_len,
+		log_arity,
 	)
 }
 
@@ -126,16 +146,23 @@ impl<D: Clone> BinaryMerkleTree<D> {
 	}
 
 	pub fn layer(&self, layer_depth: usize) -> Result<&[D], Error> {
-		if layer_depth > self.log_len {
+		if !is_layer_depth(self.log_len, self.log_arity, layer_depth) {
 			bail!(Error::IncorrectLayerDepth);
 		}
-		let range_start = self.inner_nodes.len() + 1 - (1 << (layer_depth + 1));
+		let range_start = self.inner_nodes.len()
+			- layer_depths(self.log_len, self.log_arity)
+				.take_while(|&depth| depth <= layer_depth)
+				.map(|depth| 1 << depth)
+				.sum::<usize>();
 
 		Ok(&self.inner_nodes[range_start..range_start + (1 << layer_depth)])
 	}
 
 	/// Get a Merkle branch for the given index
 	///
+	/// The branch holds the siblings of the nodes on the path from the leaf to the layer at
+	/// `layer_depth`, in order of their index among the children of their parent.
+	///
 	/// Throws if the index is out of range
 	pub fn branch(&self, index: usize, layer_depth: usize) -> Result<Vec<D>, Error> {
 		if index >= 1 << self.log_len || layer_depth > self.log_len {
@@ -143,29 +170,91 @@ impl<D: Clone> BinaryMerkleTree<D> {
 				max: (1 << self.log_len) - 1,
 			});
 		}
+		if !is_layer_depth(self.log_len, self.log_arity, layer_depth) {
+			bail!(Error::IncorrectLayerDepth);
+		}
 
-		let branch = (0..self.log_len - layer_depth)
-			.map(|j| {
-				let node_index = (((1 << j) - 1) << (self.log_len + 1 - j)) | (index >> j) ^ 1;
-				self.inner_nodes[node_index].clone()
-			})
-			.collect();
+		let mut branch = Vec::new();
+		for (depth, log_children) in layer_steps(self.log_len, layer_depth, self.log_arity) {
+			let layer = self.layer(depth)?;
+			let node_index = index >> (self.log_len - depth);
+			let first_child = node_index >> log_children << log_children;
+			branch.extend(
+				(first_child..first_child + (1 << log_children))
+					.filter(|&i| i != node_index)
+					.map(|i| layer[i].clone()),
+			);
+		}
 
 		Ok(branch)
 	}
 }
 
-#[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
-fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
+/// Returns whether a tree with `2^log_len` leaves stores the layer at `depth`.
+pub(super) fn is_layer_depth(log_len: usize, log_arity: usize, depth: usize) -> bool {
+	depth == log_len || (depth < log_len && depth % log_arity == 0)
+}
+
+/// Iterates over the depths of the layers stored in a tree, from the root to the leaves.
+fn layer_depths(log_len: usize, log_arity: usize) -> impl Iterator<Item = usize> {
+	(0..log_len).step_by(log_arity).chain(iter::once(log_len))
+}
+
+/// Iterates over the layers on the path from `from_depth` up to `to_depth`.
+///
+/// Every item is the depth of a layer paired with the base-2 logarithm of the number of its nodes
+/// that are hashed into one node of the next layer. Both depths must be stored in the tree.
+pub(super) fn layer_steps(
+	from_depth: usize,
+	to_depth: usize,
+	log_arity: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+	let mut depth = from_depth;
+	iter::from_fn(move || {
+		(depth > to_depth).then(|| {
+			let parent_depth = (depth - 1) / log_arity * log_arity;
+			let step = (depth, depth - parent_depth);
+			depth = parent_depth;
+			step
+		})
+	})
+}
+
+/// Hashes the children of an inner node into its digest.
+///
+/// Pairs of children are compressed with the compression function, as in a binary tree. Larger
+/// sets of children are hashed with `H` over their concatenated digests.
+pub(super) fn compress_children<H, C>(compression: &C, children: &[Output<H>]) -> Output<H>
 where
+	H: Digest,
+	C: PseudoCompressionFunction<Output<H>, 2>,
+{
+	match children {
+		[left, right] => compression.compress([left.clone(), right.clone()]),
+		_ => {
+			let mut hasher = H::new();
+			for child in children {
+				Digest::update(&mut hasher, child);
+			}
+			hasher.finalize()
+		}
+	}
+}
+
+#[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
+fn compress_layer<D>(
+	compress: &(impl Fn(&[D]) -> D + Sync),
+	prev_layer: &[D],
+	next_layer: &mut [MaybeUninit<D>],
+	n_children: usize,
+) where
 	D: Clone + Send + Sync,
-	C: PseudoCompressionFunction<D, 2> + Sync,
 {
 	prev_layer
-		.par_chunks_exact(2)
+		.par_chunks_exact(n_children)
 		.zip(next_layer.par_iter_mut())
-		.for_each(|(prev_pair, next_digest)| {
-			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
+		.for_each(|(children, next_digest)| {
+			next_digest.write(compress(children));
 		})
 }
 
@@ -194,7 +283,7 @@ where
 	)
 }
 
-fn hash_iterated<F, H, ParIter>(
+pub(super) fn hash_iterated<F, H, ParIter>(
 	iterated_chunks: ParIter,
 	digests: &mut [MaybeUninit<Output<H>>],
 ) -> Result<(), Error>
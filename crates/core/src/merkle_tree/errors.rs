@@ -14,6 +14,14 @@ pub enum Error {
 	PowerOfTwoLengthRequired,
 	#[error("The layer does not exist in the Merkle tree")]
 	IncorrectLayerDepth,
+	#[error("The Merkle tree arity must be at least two")]
+	InvalidArity,
+	#[error("The streamed Merkle tree only supports a binary tree, got log arity {log_arity}")]
+	UnsupportedStreamedArity { log_arity: usize },
+	#[error("The layer at depth {layer_depth} was not retained by the streamed Merkle tree")]
+	LayerNotRetained { layer_depth: usize },
+	#[error("IO error: {0}")]
+	Io(#[from] std::io::Error),
 	#[error("transcript error: {0}")]
 	Transcript(#[from] transcript::Error),
 	#[error("verification failure: {0}")]
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use rayon::iter::IndexedParallelIterator;
+use binius_utils::rayon::iter::IndexedParallelIterator;
 
 use super::errors::Error;
 use crate::transcript::{CanRead, CanWrite};
@@ -11,8 +11,9 @@ use crate::transcript::{CanRead, CanWrite};
 /// indistinguishability of leaf digests from inner node digests.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Commitment<Digest> {
-	/// The root digest of the Merkle tree.
-	pub root: Digest,
+	/// The digests of the layer at the cap depth of the Merkle tree, which is just the root
+	/// unless the scheme commits to a Merkle cap.
+	pub cap: Vec<Digest>,
 	/// The depth of the Merkle tree.
 	pub depth: usize,
 }
@@ -21,7 +22,15 @@ pub struct Commitment<Digest> {
 pub trait MerkleTreeScheme<T>: Sync {
 	type Digest: Clone + PartialEq + Eq;
 
+	/// Returns the depth of the layer that is committed to, the Merkle cap, in a tree of the given
+	/// depth.
+	///
+	/// A commitment consists of the `2^cap_depth` digests of this layer.
+	fn cap_depth(&self, tree_depth: usize) -> usize;
+
 	/// Returns the optimal layer that the verifier should verify only once.
+	///
+	/// This layer is never above the cap depth.
 	fn optimal_verify_layer(&self, n_queries: usize, tree_depth: usize) -> usize;
 
 	/// Returns the total byte-size of a proof for multiple opening queries.
@@ -35,7 +44,7 @@ pub trait MerkleTreeScheme<T>: Sync {
 	/// Verify the opening of the full vector.
 	fn verify_vector(
 		&self,
-		root: &Self::Digest,
+		cap: &[Self::Digest],
 		data: &[T],
 		batch_size: usize,
 	) -> Result<(), Error>;
@@ -44,10 +53,10 @@ pub trait MerkleTreeScheme<T>: Sync {
 	///
 	/// When a protocol requires verification of many openings at independent and randomly sampled
 	/// indices, it is more efficient for the verifier to verifier an internal layer once, then
-	/// verify all openings with respect to that layer.
+	/// verify all openings with respect to that layer. The layer must not be above the cap.
 	fn verify_layer(
 		&self,
-		root: &Self::Digest,
+		cap: &[Self::Digest],
 		layer_depth: usize,
 		layer_digests: &[Self::Digest],
 	) -> Result<(), Error>;
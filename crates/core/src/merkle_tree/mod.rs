@@ -6,6 +6,7 @@ mod errors;
 mod merkle_tree_vcs;
 mod prover;
 mod scheme;
+mod streamed;
 #[cfg(test)]
 mod tests;
 
@@ -13,3 +14,7 @@ pub use binary_merkle_tree::*;
 pub use merkle_tree_vcs::*;
 pub use prover::BinaryMerkleTreeProver;
 pub use scheme::BinaryMerkleTreeScheme;
+pub use streamed::{
+	build_streamed, MaybeStreamedMerkleTree, MaybeStreamedMerkleTreeProver,
+	StreamedBinaryMerkleTreeProver, StreamedMerkleTree, StreamingOptions,
+};
@@ -2,15 +2,15 @@
 
 use binius_field::TowerField;
 use binius_hash::PseudoCompressionFunction;
+use binius_utils::rayon::iter::IndexedParallelIterator;
 use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 use getset::Getters;
-use rayon::iter::IndexedParallelIterator;
 use tracing::instrument;
 
 use super::{
 	binary_merkle_tree::{self, BinaryMerkleTree},
 	errors::Error,
-	merkle_tree_vcs::{Commitment, MerkleTreeProver},
+	merkle_tree_vcs::{Commitment, MerkleTreeProver, MerkleTreeScheme},
 	scheme::BinaryMerkleTreeScheme,
 };
 use crate::transcript::CanWrite;
@@ -23,9 +23,29 @@ pub struct BinaryMerkleTreeProver<T, H, C> {
 
 impl<T, C, H> BinaryMerkleTreeProver<T, H, C> {
 	pub fn new(compression: C) -> Self {
-		Self {
-			scheme: BinaryMerkleTreeScheme::new(compression),
-		}
+		Self::from_scheme(BinaryMerkleTreeScheme::new(compression))
+	}
+
+	pub fn from_scheme(scheme: BinaryMerkleTreeScheme<T, H, C>) -> Self {
+		Self { scheme }
+	}
+}
+
+impl<F, H, C> BinaryMerkleTreeProver<F, H, C>
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+{
+	fn commitment(
+		&self,
+		tree: &BinaryMerkleTree<Output<H>>,
+	) -> Result<Commitment<Output<H>>, Error> {
+		let cap = tree.layer(self.scheme.cap_depth(tree.log_len))?;
+		Ok(Commitment {
+			cap: cap.to_vec(),
+			depth: tree.log_len,
+		})
 	}
 }
 
@@ -47,15 +67,14 @@ where
 		data: &[F],
 		batch_size: usize,
 	) -> Result<(Commitment<Output<H>>, Self::Committed), Error> {
-		let tree =
-			binary_merkle_tree::build::<_, H, _>(self.scheme.compression(), data, batch_size)?;
-
-		let commitment = Commitment {
-			root: tree.root().clone(),
-			depth: tree.log_len,
-		};
+		let tree = binary_merkle_tree::build::<_, H, _>(
+			self.scheme.compression(),
+			self.scheme.log_arity(),
+			data,
+			batch_size,
+		)?;
 
-		Ok((commitment, tree))
+		Ok((self.commitment(&tree)?, tree))
 	}
 
 	fn layer<'a>(
@@ -93,15 +112,11 @@ where
 	{
 		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _>(
 			self.scheme.compression(),
+			self.scheme.log_arity(),
 			iterated_chunks,
 			log_len,
 		)?;
 
-		let commitment = Commitment {
-			root: tree.root().clone(),
-			depth: tree.log_len,
-		};
-
-		Ok((commitment, tree))
+		Ok((self.commitment(&tree)?, tree))
 	}
 }
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, marker::PhantomData};
+use std::marker::PhantomData;
 
 use binius_field::{serialize_canonical, TowerField};
 use binius_hash::{HashBuffer, PseudoCompressionFunction};
@@ -9,18 +9,31 @@ use binius_utils::{
 	checked_arithmetics::{log2_ceil_usize, log2_strict_usize},
 };
 use digest::{core_api::BlockSizeUser, Digest, Output};
-use getset::Getters;
+use getset::{CopyGetters, Getters};
 
 use super::{
+	binary_merkle_tree::{compress_children, is_layer_depth, layer_steps},
 	errors::{Error, VerificationError},
 	merkle_tree_vcs::MerkleTreeScheme,
 };
 use crate::transcript::CanRead;
 
-#[derive(Debug, Getters)]
+/// A Merkle tree scheme over the tower field elements with a digest of `H`.
+///
+/// By default this is a binary tree committed to by its root. [`Self::with_log_arity`] makes the
+/// inner nodes hash `2^log_arity` children at once, which shortens the opening paths, and
+/// [`Self::with_cap_height`] commits to the `2^cap_height` nodes of a layer below the root
+/// instead, the Merkle cap, which the verifier need not recompute from every opening.
+#[derive(Debug, Getters, CopyGetters)]
 pub struct BinaryMerkleTreeScheme<T, H, C> {
 	#[getset(get = "pub")]
 	compression: C,
+	/// Base-2 logarithm of the number of children of the inner nodes.
+	#[getset(get_copy = "pub")]
+	log_arity: usize,
+	/// Base-2 logarithm of the number of nodes in the commitment.
+	#[getset(get_copy = "pub")]
+	cap_height: usize,
 	// This makes it so that `BinaryMerkleTreeScheme` remains Send + Sync
 	// See https://doc.rust-lang.org/nomicon/phantom-data.html#table-of-phantomdata-patterns
 	_phantom: PhantomData<fn() -> (T, H)>,
@@ -30,9 +43,69 @@ impl<T, H, C> BinaryMerkleTreeScheme<T, H, C> {
 	pub fn new(compression: C) -> Self {
 		BinaryMerkleTreeScheme {
 			compression,
+			log_arity: 1,
+			cap_height: 0,
 			_phantom: PhantomData,
 		}
 	}
+
+	/// Sets the base-2 logarithm of the number of children of the inner nodes.
+	///
+	/// ## Panics
+	///
+	/// * if `log_arity` is zero
+	pub fn with_log_arity(mut self, log_arity: usize) -> Self {
+		assert!(log_arity > 0, "the Merkle tree arity must be at least two");
+		self.log_arity = log_arity;
+		self
+	}
+
+	/// Sets the base-2 logarithm of the number of nodes in the commitment.
+	///
+	/// The cap is the highest layer with at least `2^cap_height` nodes stored in the tree, or the
+	/// leaves if the tree has fewer.
+	pub fn with_cap_height(mut self, cap_height: usize) -> Self {
+		self.cap_height = cap_height;
+		self
+	}
+
+	/// Iterates over the layers hashed on the way from the layer at `from_depth` up to the layer
+	/// at `to_depth`.
+	///
+	/// Every item is the depth of a layer paired with the base-2 logarithm of the number of its
+	/// nodes that are hashed into one node of the next layer.
+	pub fn fold_steps(
+		&self,
+		from_depth: usize,
+		to_depth: usize,
+	) -> impl Iterator<Item = (usize, usize)> {
+		layer_steps(from_depth, to_depth, self.log_arity)
+	}
+
+	/// Rounds a depth up to the closest layer stored in a tree of the given depth.
+	fn round_up_layer_depth(&self, depth: usize, tree_depth: usize) -> usize {
+		depth.next_multiple_of(self.log_arity).min(tree_depth)
+	}
+
+	/// Folds a layer of digests up to the layer at `to_depth`.
+	fn fold_layer(
+		&self,
+		mut digests: Vec<Output<H>>,
+		from_depth: usize,
+		to_depth: usize,
+	) -> Vec<Output<H>>
+	where
+		H: Digest,
+		C: PseudoCompressionFunction<Output<H>, 2>,
+	{
+		for (_, log_children) in self.fold_steps(from_depth, to_depth) {
+			digests = digests
+				.chunks(1 << log_children)
+				.map(|children| compress_children::<H, _>(&self.compression, children))
+				.collect();
+		}
+		digests
+	}
 }
 
 impl<F, H, C> MerkleTreeScheme<F> for BinaryMerkleTreeScheme<F, H, C>
@@ -43,9 +116,16 @@ where
 {
 	type Digest = Output<H>;
 
+	fn cap_depth(&self, tree_depth: usize) -> usize {
+		self.round_up_layer_depth(self.cap_height.min(tree_depth), tree_depth)
+	}
+
 	/// This layer allows minimizing the proof size.
 	fn optimal_verify_layer(&self, n_queries: usize, tree_depth: usize) -> usize {
-		log2_ceil_usize(n_queries).min(tree_depth)
+		let depth = log2_ceil_usize(n_queries)
+			.min(tree_depth)
+			.max(self.cap_depth(tree_depth));
+		self.round_up_layer_depth(depth, tree_depth)
 	}
 
 	fn proof_size(&self, len: usize, n_queries: usize, layer_depth: usize) -> Result<usize, Error> {
@@ -55,17 +135,19 @@ where
 
 		let log_len = log2_strict_usize(len);
 
-		if layer_depth > log_len {
+		if !is_layer_depth(log_len, self.log_arity, layer_depth) {
 			bail!(Error::IncorrectLayerDepth)
 		}
 
-		Ok(((log_len - layer_depth - 1) * n_queries + (1 << layer_depth))
-			* <H as Digest>::output_size())
+		let n_siblings = layer_steps(log_len, layer_depth, self.log_arity)
+			.map(|(_, log_children)| (1 << log_children) - 1)
+			.sum::<usize>();
+		Ok((n_siblings * n_queries + (1 << layer_depth)) * <H as Digest>::output_size())
 	}
 
 	fn verify_vector(
 		&self,
-		root: &Self::Digest,
+		cap: &[Self::Digest],
 		data: &[F],
 		batch_size: usize,
 	) -> Result<(), Error> {
@@ -73,13 +155,17 @@ where
 			bail!(Error::IncorrectBatchSize);
 		}
 
-		let mut digests = data
+		let digests = data
 			.chunks(batch_size)
 			.map(|chunk| hash_field_elems::<_, H>(chunk))
 			.collect::<Vec<_>>();
+		if !digests.len().is_power_of_two() {
+			bail!(Error::PowerOfTwoLengthRequired);
+		}
 
-		fold_digests_vector_inplace(&self.compression, &mut digests)?;
-		if digests[0] != *root {
+		let tree_depth = log2_strict_usize(digests.len());
+		let digests = self.fold_layer(digests, tree_depth, self.cap_depth(tree_depth));
+		if digests != cap {
 			bail!(VerificationError::InvalidProof)
 		}
 		Ok(())
@@ -87,19 +173,24 @@ where
 
 	fn verify_layer(
 		&self,
-		root: &Self::Digest,
+		cap: &[Self::Digest],
 		layer_depth: usize,
 		layer_digests: &[Self::Digest],
 	) -> Result<(), Error> {
 		if 1 << layer_depth != layer_digests.len() {
 			bail!(VerificationError::IncorrectVectorLength)
 		}
+		if !cap.len().is_power_of_two() {
+			bail!(VerificationError::IncorrectProofShape)
+		}
 
-		let mut digests = layer_digests.to_owned();
-
-		fold_digests_vector_inplace(&self.compression, &mut digests)?;
+		let cap_depth = log2_strict_usize(cap.len());
+		if layer_depth < cap_depth {
+			bail!(Error::IncorrectLayerDepth)
+		}
 
-		if digests[0] != *root {
+		let digests = self.fold_layer(layer_digests.to_owned(), layer_depth, cap_depth);
+		if digests != cap {
 			bail!(VerificationError::InvalidProof)
 		}
 		Ok(())
@@ -124,21 +215,20 @@ where
 			});
 		}
 
-		let leaf_digest = hash_field_elems::<_, H>(values);
-		let branch = proof.read_vec(tree_depth - layer_depth)?;
+		if !is_layer_depth(tree_depth, self.log_arity, layer_depth) {
+			bail!(Error::IncorrectLayerDepth)
+		}
 
+		let mut node = hash_field_elems::<_, H>(values);
 		let mut index = index;
-		let root = branch.into_iter().fold(leaf_digest, |node, branch_node| {
-			let next_node = if index & 1 == 0 {
-				self.compression.compress([node, branch_node])
-			} else {
-				self.compression.compress([branch_node, node])
-			};
-			index >>= 1;
-			next_node
-		});
-
-		if root == layer_digests[index] {
+		for (_, log_children) in layer_steps(tree_depth, layer_depth, self.log_arity) {
+			let mut children = proof.read_vec((1 << log_children) - 1)?;
+			children.insert(index % (1 << log_children), node);
+			node = compress_children::<H, _>(&self.compression, &children);
+			index >>= log_children;
+		}
+
+		if node == layer_digests[index] {
 			Ok(())
 		} else {
 			bail!(VerificationError::InvalidProof)
@@ -146,28 +236,6 @@ where
 	}
 }
 
-// Merkle-tree-like folding
-fn fold_digests_vector_inplace<C, D>(compression: &C, digests: &mut [D]) -> Result<(), Error>
-where
-	C: PseudoCompressionFunction<D, 2> + Sync,
-	D: Clone + Default + Send + Sync + Debug,
-{
-	if !digests.len().is_power_of_two() {
-		bail!(Error::PowerOfTwoLengthRequired);
-	}
-
-	let mut len = digests.len() / 2;
-
-	while len != 0 {
-		for i in 0..len {
-			digests[i] = compression.compress(array::from_fn(|j| digests[2 * i + j].clone()));
-		}
-		len /= 2;
-	}
-
-	Ok(())
-}
-
 /// Hashes a slice of tower field elements.
 fn hash_field_elems<F, H>(elems: &[F]) -> Output<H>
 where
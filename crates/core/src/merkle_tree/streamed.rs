@@ -0,0 +1,537 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	fs::{self, File, OpenOptions},
+	io::{BufWriter, Read, Seek, SeekFrom, Write},
+	path::{Path, PathBuf},
+	process,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Mutex,
+	},
+};
+
+use binius_field::TowerField;
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::{bail, checked_arithmetics::log2_strict_usize, rayon::prelude::*};
+use digest::{
+	core_api::BlockSizeUser,
+	generic_array::{ArrayLength, GenericArray},
+	Digest, FixedOutputReset, Output,
+};
+use getset::Getters;
+use tracing::instrument;
+
+use super::{
+	binary_merkle_tree::{hash_iterated, BinaryMerkleTree},
+	errors::Error,
+	merkle_tree_vcs::{Commitment, MerkleTreeProver, MerkleTreeScheme},
+	prover::BinaryMerkleTreeProver,
+	scheme::BinaryMerkleTreeScheme,
+};
+use crate::transcript::CanWrite;
+
+/// Settings for building a Merkle tree with bounded memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamingOptions {
+	/// The layers with at most `2^retained_depth` nodes are kept in memory. Opening proofs are
+	/// verified against one of these layers, so it should be at least the optimal verify layer.
+	pub retained_depth: usize,
+	/// The number of leaves hashed in parallel before they are folded into the tree.
+	pub block_len: usize,
+	/// A directory to spill the layers below `retained_depth` to.
+	///
+	/// Opening proofs need the sibling nodes on the path to a retained layer, so without spilling
+	/// only the commitment and the retained layers are available.
+	pub spill_dir: Option<PathBuf>,
+}
+
+impl Default for StreamingOptions {
+	fn default() -> Self {
+		Self {
+			retained_depth: 8,
+			block_len: 1 << 12,
+			spill_dir: None,
+		}
+	}
+}
+
+/// A binary Merkle tree built from streamed leaves, holding only its top layers in memory.
+///
+/// The tree has the same shape and digests as a [`BinaryMerkleTree`] over the same leaves; the
+/// layers below the retained depth are either discarded or read back from disk when generating
+/// opening proofs.
+#[derive(Debug)]
+pub struct StreamedMerkleTree<D> {
+	/// Base-2 logarithm of the number of leaves
+	pub log_len: usize,
+	/// The retained layers, indexed by depth
+	retained: Vec<Vec<D>>,
+	spilled: Option<SpilledLayers>,
+}
+
+impl<D: Clone> StreamedMerkleTree<D> {
+	pub fn root(&self) -> D {
+		self.retained[0][0].clone()
+	}
+
+	pub fn retained_depth(&self) -> usize {
+		self.retained.len() - 1
+	}
+
+	pub fn layer(&self, layer_depth: usize) -> Result<&[D], Error> {
+		if layer_depth > self.log_len {
+			bail!(Error::IncorrectLayerDepth);
+		}
+		match self.retained.get(layer_depth) {
+			Some(layer) => Ok(layer),
+			None => bail!(Error::LayerNotRetained { layer_depth }),
+		}
+	}
+}
+
+impl<N: ArrayLength<u8>> StreamedMerkleTree<GenericArray<u8, N>> {
+	/// Get a Merkle branch for the given index, reading spilled nodes back from disk.
+	///
+	/// Throws if the index is out of range, or if the branch passes through discarded layers.
+	pub fn branch(
+		&self,
+		index: usize,
+		layer_depth: usize,
+	) -> Result<Vec<GenericArray<u8, N>>, Error> {
+		if index >= 1 << self.log_len || layer_depth > self.log_len {
+			return Err(Error::IndexOutOfRange {
+				max: (1 << self.log_len) - 1,
+			});
+		}
+
+		(0..self.log_len - layer_depth)
+			.map(|level| {
+				let depth = self.log_len - level;
+				let sibling = (index >> level) ^ 1;
+				match (self.retained.get(depth), &self.spilled) {
+					(Some(layer), _) => Ok(layer[sibling].clone()),
+					(None, Some(spilled)) => spilled
+						.read(level, sibling)
+						.map(|bytes| GenericArray::clone_from_slice(&bytes)),
+					(None, None) => Err(Error::LayerNotRetained { layer_depth: depth }),
+				}
+			})
+			.collect()
+	}
+}
+
+/// Layers of a tree written to files, one per level, which are removed on drop.
+#[derive(Debug)]
+struct SpilledLayers {
+	paths: Vec<PathBuf>,
+	files: Vec<Mutex<File>>,
+	digest_len: usize,
+}
+
+impl SpilledLayers {
+	fn read(&self, level: usize, index: usize) -> Result<Vec<u8>, Error> {
+		let mut bytes = vec![0; self.digest_len];
+		let mut file = self.files[level]
+			.lock()
+			.expect("no thread panics while holding the lock");
+		file.seek(SeekFrom::Start((index * self.digest_len) as u64))?;
+		file.read_exact(&mut bytes)?;
+		Ok(bytes)
+	}
+}
+
+impl Drop for SpilledLayers {
+	fn drop(&mut self) {
+		for path in &self.paths {
+			let _ = fs::remove_file(path);
+		}
+	}
+}
+
+/// Distinguishes the spill files of trees built by the same process.
+static NEXT_SPILL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The right edge of a tree under construction, with at most one pending node per level.
+struct Frontier<'a, D, C> {
+	compression: &'a C,
+	log_len: usize,
+	n_leaves: usize,
+	pending: Vec<Option<D>>,
+	retained: Vec<Vec<D>>,
+	spill_paths: Vec<PathBuf>,
+	spill_writers: Vec<BufWriter<File>>,
+}
+
+impl<'a, D, C> Frontier<'a, D, C>
+where
+	D: Clone + AsRef<[u8]>,
+	C: PseudoCompressionFunction<D, 2>,
+{
+	fn new(compression: &'a C, log_len: usize, options: &StreamingOptions) -> Result<Self, Error> {
+		let retained_depth = options.retained_depth.min(log_len);
+		let spill_paths = match &options.spill_dir {
+			Some(dir) => {
+				let id = NEXT_SPILL_ID.fetch_add(1, Ordering::Relaxed);
+				(0..log_len - retained_depth)
+					.map(|level| spill_path(dir, id, level))
+					.collect()
+			}
+			None => Vec::new(),
+		};
+		let spill_writers = spill_paths
+			.iter()
+			.map(|path| {
+				let file = OpenOptions::new()
+					.read(true)
+					.write(true)
+					.create(true)
+					.truncate(true)
+					.open(path)?;
+				Ok(BufWriter::new(file))
+			})
+			.collect::<Result<_, Error>>()?;
+		Ok(Self {
+			compression,
+			log_len,
+			n_leaves: 0,
+			pending: vec![None; log_len + 1],
+			retained: (0..=retained_depth)
+				.map(|depth| Vec::with_capacity(1 << depth))
+				.collect(),
+			spill_paths,
+			spill_writers,
+		})
+	}
+
+	/// Appends a leaf digest, compressing every completed pair of nodes on the way to the root.
+	fn push(&mut self, leaf: D) -> Result<(), Error> {
+		if self.n_leaves == 1 << self.log_len {
+			bail!(Error::IncorrectVectorLen {
+				expected: 1 << self.log_len
+			});
+		}
+		self.n_leaves += 1;
+
+		let mut node = leaf;
+		for level in 0..=self.log_len {
+			let depth = self.log_len - level;
+			if depth < self.retained.len() {
+				self.retained[depth].push(node.clone());
+			} else if let Some(writer) = self.spill_writers.get_mut(level) {
+				writer.write_all(node.as_ref())?;
+			}
+			match self.pending[level].take() {
+				Some(left) => node = self.compression.compress([left, node]),
+				None => {
+					self.pending[level] = Some(node);
+					break;
+				}
+			}
+		}
+		Ok(())
+	}
+
+	fn finish(self) -> Result<StreamedMerkleTree<D>, Error> {
+		if self.n_leaves != 1 << self.log_len {
+			bail!(Error::IncorrectVectorLen {
+				expected: 1 << self.log_len
+			});
+		}
+		let spilled = if self.spill_paths.is_empty() {
+			None
+		} else {
+			let files = self
+				.spill_writers
+				.into_iter()
+				.map(|writer| Ok(Mutex::new(writer.into_inner().map_err(|err| err.into_error())?)))
+				.collect::<Result<_, Error>>()?;
+			Some(SpilledLayers {
+				paths: self.spill_paths,
+				files,
+				digest_len: self.retained[0][0].as_ref().len(),
+			})
+		};
+		Ok(StreamedMerkleTree {
+			log_len: self.log_len,
+			retained: self.retained,
+			spilled,
+		})
+	}
+}
+
+fn spill_path(dir: &Path, id: usize, level: usize) -> PathBuf {
+	dir.join(format!("binius-merkle-{}-{id}-{level}.bin", process::id()))
+}
+
+/// Builds a Merkle tree over leaves streamed from an iterator of chunks.
+///
+/// Every chunk is hashed into one leaf. Leaves are folded into the tree as soon as their sibling
+/// is known, so apart from the retained layers only one node per level and a block of
+/// [`StreamingOptions::block_len`] chunks are held in memory at a time.
+#[instrument("StreamedMerkleTree::build", skip_all, level = "debug")]
+pub fn build_streamed<F, H, C, Chunks>(
+	compression: &C,
+	chunks: Chunks,
+	log_len: usize,
+	options: &StreamingOptions,
+) -> Result<StreamedMerkleTree<Output<H>>, Error>
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	Chunks: IntoIterator<Item: IntoIterator<Item = F> + Send>,
+{
+	let mut frontier = Frontier::new(compression, log_len, options)?;
+	let mut chunks = chunks.into_iter();
+	let block_len = options.block_len.max(1);
+	loop {
+		let block = chunks.by_ref().take(block_len).collect::<Vec<_>>();
+		if block.is_empty() {
+			break;
+		}
+		for leaf in hash_leaves::<F, H, _>(block.into_par_iter())? {
+			frontier.push(leaf)?;
+		}
+	}
+	frontier.finish()
+}
+
+fn hash_leaves<F, H, ParIter>(iterated_chunks: ParIter) -> Result<Vec<Output<H>>, Error>
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+{
+	let len = iterated_chunks.len();
+	let mut digests = Vec::with_capacity(len);
+	hash_iterated::<F, H, _>(iterated_chunks, &mut digests.spare_capacity_mut()[..len])?;
+	unsafe {
+		// SAFETY: hash_iterated initialized one digest per chunk
+		digests.set_len(len);
+	}
+	Ok(digests)
+}
+
+/// A Merkle tree prover that streams leaves into a [`StreamedMerkleTree`].
+#[derive(Debug, Getters)]
+pub struct StreamedBinaryMerkleTreeProver<T, H, C> {
+	#[getset(get = "pub")]
+	scheme: BinaryMerkleTreeScheme<T, H, C>,
+	#[getset(get = "pub")]
+	options: StreamingOptions,
+}
+
+impl<T, H, C> StreamedBinaryMerkleTreeProver<T, H, C> {
+	pub fn new(compression: C, options: StreamingOptions) -> Self {
+		Self::from_scheme(BinaryMerkleTreeScheme::new(compression), options)
+	}
+
+	/// Creates a prover for the given scheme, which must have binary arity.
+	///
+	/// The Merkle cap of the scheme must be within the retained layers.
+	pub fn from_scheme(scheme: BinaryMerkleTreeScheme<T, H, C>, options: StreamingOptions) -> Self {
+		Self { scheme, options }
+	}
+}
+
+impl<F, H, C> StreamedBinaryMerkleTreeProver<F, H, C>
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+{
+	fn check_arity(&self) -> Result<(), Error> {
+		if self.scheme.log_arity() != 1 {
+			bail!(Error::UnsupportedStreamedArity {
+				log_arity: self.scheme.log_arity()
+			});
+		}
+		Ok(())
+	}
+
+	fn commitment(
+		&self,
+		tree: &StreamedMerkleTree<Output<H>>,
+	) -> Result<Commitment<Output<H>>, Error> {
+		let cap = tree.layer(self.scheme.cap_depth(tree.log_len))?;
+		Ok(Commitment {
+			cap: cap.to_vec(),
+			depth: tree.log_len,
+		})
+	}
+}
+
+impl<F, H, C> MerkleTreeProver<F> for StreamedBinaryMerkleTreeProver<F, H, C>
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+{
+	type Scheme = BinaryMerkleTreeScheme<F, H, C>;
+	type Committed = StreamedMerkleTree<Output<H>>;
+
+	fn scheme(&self) -> &Self::Scheme {
+		&self.scheme
+	}
+
+	fn commit(
+		&self,
+		data: &[F],
+		batch_size: usize,
+	) -> Result<(Commitment<Output<H>>, Self::Committed), Error> {
+		self.check_arity()?;
+		if batch_size == 0 || data.len() % batch_size != 0 {
+			bail!(Error::IncorrectBatchSize);
+		}
+		let len = data.len() / batch_size;
+		if !len.is_power_of_two() {
+			bail!(Error::PowerOfTwoLengthRequired);
+		}
+
+		let tree = build_streamed::<F, H, C, _>(
+			self.scheme.compression(),
+			data.chunks(batch_size).map(|chunk| chunk.iter().copied()),
+			log2_strict_usize(len),
+			&self.options,
+		)?;
+		Ok((self.commitment(&tree)?, tree))
+	}
+
+	/// Commits interleaved elements from a parallel iterator.
+	///
+	/// The leaves are hashed in parallel up front, so unlike [`Self::commit`] this holds all leaf
+	/// digests, though not the chunks nor the inner nodes, in memory at once.
+	#[instrument(skip_all, level = "debug")]
+	fn commit_iterated<ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		log_len: usize,
+	) -> Result<(Commitment<Output<H>>, Self::Committed), Error>
+	where
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		self.check_arity()?;
+		if iterated_chunks.len() != 1 << log_len {
+			bail!(Error::IncorrectVectorLen {
+				expected: 1 << log_len
+			});
+		}
+		let mut frontier = Frontier::new(self.scheme.compression(), log_len, &self.options)?;
+		for leaf in hash_leaves::<F, H, _>(iterated_chunks)? {
+			frontier.push(leaf)?;
+		}
+		let tree = frontier.finish()?;
+		Ok((self.commitment(&tree)?, tree))
+	}
+
+	fn layer<'a>(
+		&self,
+		committed: &'a Self::Committed,
+		layer_depth: usize,
+	) -> Result<&'a [Output<H>], Error> {
+		committed.layer(layer_depth)
+	}
+
+	fn prove_opening<Proof: CanWrite>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		index: usize,
+		mut proof: Proof,
+	) -> Result<(), Error> {
+		let branch = committed.branch(index, layer_depth)?;
+		proof.write_slice(&branch);
+		Ok(())
+	}
+}
+
+/// A binary Merkle tree prover that either builds the whole tree in memory or streams it.
+#[derive(Debug)]
+pub enum MaybeStreamedMerkleTreeProver<T, H, C> {
+	InMemory(BinaryMerkleTreeProver<T, H, C>),
+	Streamed(StreamedBinaryMerkleTreeProver<T, H, C>),
+}
+
+/// The tree committed by a [`MaybeStreamedMerkleTreeProver`].
+#[derive(Debug)]
+pub enum MaybeStreamedMerkleTree<D> {
+	InMemory(BinaryMerkleTree<D>),
+	Streamed(StreamedMerkleTree<D>),
+}
+
+impl<F, H, C> MerkleTreeProver<F> for MaybeStreamedMerkleTreeProver<F, H, C>
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+{
+	type Scheme = BinaryMerkleTreeScheme<F, H, C>;
+	type Committed = MaybeStreamedMerkleTree<Output<H>>;
+
+	fn scheme(&self) -> &Self::Scheme {
+		match self {
+			Self::InMemory(prover) => prover.scheme(),
+			Self::Streamed(prover) => prover.scheme(),
+		}
+	}
+
+	fn commit(
+		&self,
+		data: &[F],
+		batch_size: usize,
+	) -> Result<(Commitment<Output<H>>, Self::Committed), Error> {
+		match self {
+			Self::InMemory(prover) => prover
+				.commit(data, batch_size)
+				.map(|(commitment, tree)| (commitment, MaybeStreamedMerkleTree::InMemory(tree))),
+			Self::Streamed(prover) => prover
+				.commit(data, batch_size)
+				.map(|(commitment, tree)| (commitment, MaybeStreamedMerkleTree::Streamed(tree))),
+		}
+	}
+
+	fn commit_iterated<ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		log_len: usize,
+	) -> Result<(Commitment<Output<H>>, Self::Committed), Error>
+	where
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		match self {
+			Self::InMemory(prover) => prover
+				.commit_iterated(iterated_chunks, log_len)
+				.map(|(commitment, tree)| (commitment, MaybeStreamedMerkleTree::InMemory(tree))),
+			Self::Streamed(prover) => prover
+				.commit_iterated(iterated_chunks, log_len)
+				.map(|(commitment, tree)| (commitment, MaybeStreamedMerkleTree::Streamed(tree))),
+		}
+	}
+
+	fn layer<'a>(
+		&self,
+		committed: &'a Self::Committed,
+		layer_depth: usize,
+	) -> Result<&'a [Output<H>], Error> {
+		match committed {
+			MaybeStreamedMerkleTree::InMemory(tree) => tree.layer(layer_depth),
+			MaybeStreamedMerkleTree::Streamed(tree) => tree.layer(layer_depth),
+		}
+	}
+
+	fn prove_opening<Proof: CanWrite>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		index: usize,
+		mut proof: Proof,
+	) -> Result<(), Error> {
+		let branch = match committed {
+			MaybeStreamedMerkleTree::InMemory(tree) => tree.branch(index, layer_depth)?,
+			MaybeStreamedMerkleTree::Streamed(tree) => tree.branch(index, layer_depth)?,
+		};
+		proof.write_slice(&branch);
+		Ok(())
+	}
+}
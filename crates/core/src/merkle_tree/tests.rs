@@ -8,7 +8,11 @@ use binius_hash::compress::Groestl256ByteCompression;
 use groestl_crypto::Groestl256;
 use rand::{rngs::StdRng, SeedableRng};
 
-use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme};
+use super::{
+	errors::{Error, VerificationError},
+	BinaryMerkleTreeProver, BinaryMerkleTreeScheme, MerkleTreeProver, MerkleTreeScheme,
+	StreamedBinaryMerkleTreeProver, StreamingOptions,
+};
 use crate::transcript::AdviceWriter;
 
 #[test]
@@ -22,7 +26,7 @@ fn test_binary_merkle_vcs_commit_prove_open_correctly() {
 		.collect::<Vec<BinaryField16b>>();
 	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
 
-	assert_eq!(commitment.root, tree.root());
+	assert_eq!(commitment.cap, [tree.root()]);
 
 	for (i, value) in data.iter().enumerate() {
 		let mut proof_writer = AdviceWriter::new();
@@ -33,7 +37,7 @@ fn test_binary_merkle_vcs_commit_prove_open_correctly() {
 		let mut proof_reader = proof_writer.into_reader();
 		mr_prover
 			.scheme()
-			.verify_opening(i, slice::from_ref(value), 0, 4, &[commitment.root], &mut proof_reader)
+			.verify_opening(i, slice::from_ref(value), 0, 4, &commitment.cap, &mut proof_reader)
 			.unwrap();
 	}
 }
@@ -49,12 +53,12 @@ fn test_binary_merkle_vcs_commit_layer_prove_open_correctly() {
 		.collect::<Vec<BinaryField16b>>();
 	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
 
-	assert_eq!(commitment.root, tree.root());
+	assert_eq!(commitment.cap, [tree.root()]);
 	for layer_depth in 0..5 {
 		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
 		mr_prover
 			.scheme()
-			.verify_layer(&commitment.root, layer_depth, layer)
+			.verify_layer(&commitment.cap, layer_depth, layer)
 			.unwrap();
 		for (i, value) in data.iter().enumerate() {
 			let mut proof_writer = AdviceWriter::new();
@@ -84,6 +88,189 @@ fn test_binary_merkle_vcs_verify_vector() {
 
 	mr_prover
 		.scheme()
-		.verify_vector(&commitment.root, &data, 1)
+		.verify_vector(&commitment.cap, &data, 1)
 		.unwrap();
 }
+
+#[test]
+fn test_higher_arity_merkle_tree_with_cap() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(2 << 7)
+		.collect::<Vec<BinaryField16b>>();
+
+	for log_arity in 1..=3 {
+		for cap_height in [0, 2, 3] {
+			let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::from_scheme(
+				BinaryMerkleTreeScheme::new(Groestl256ByteCompression)
+					.with_log_arity(log_arity)
+					.with_cap_height(cap_height),
+			);
+			let (commitment, tree) = mr_prover.commit(&data, 2).unwrap();
+
+			// The cap is rounded up to the next layer stored in the tree.
+			let cap_depth = mr_prover.scheme().cap_depth(7);
+			assert_eq!(cap_depth, cap_height.next_multiple_of(log_arity));
+			assert_eq!(commitment.cap, mr_prover.layer(&tree, cap_depth).unwrap());
+			mr_prover
+				.scheme()
+				.verify_vector(&commitment.cap, &data, 2)
+				.unwrap();
+
+			let layer_depth = mr_prover.scheme().optimal_verify_layer(16, 7);
+			let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+			mr_prover
+				.scheme()
+				.verify_layer(&commitment.cap, layer_depth, layer)
+				.unwrap();
+
+			for (i, values) in data.chunks(2).enumerate() {
+				let mut proof_writer = AdviceWriter::new();
+				mr_prover
+					.prove_opening(&tree, layer_depth, i, &mut proof_writer)
+					.unwrap();
+
+				let mut proof_reader = proof_writer.into_reader();
+				mr_prover
+					.scheme()
+					.verify_opening(i, values, layer_depth, 7, layer, &mut proof_reader)
+					.unwrap();
+			}
+		}
+	}
+}
+
+#[test]
+fn test_merkle_cap_rejects_tampered_vector() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mut data = repeat_with(|| Field::random(&mut rng))
+		.take(32)
+		.collect::<Vec<BinaryField16b>>();
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::from_scheme(
+		BinaryMerkleTreeScheme::new(Groestl256ByteCompression)
+			.with_log_arity(2)
+			.with_cap_height(2),
+	);
+	let (commitment, _) = mr_prover.commit(&data, 1).unwrap();
+	assert_eq!(commitment.cap.len(), 4);
+
+	data[17] += BinaryField16b::ONE;
+	assert!(matches!(
+		mr_prover.scheme().verify_vector(&commitment.cap, &data, 1),
+		Err(Error::Verification(VerificationError::InvalidProof))
+	));
+}
+
+#[test]
+fn test_streamed_merkle_tree_matches_in_memory_tree() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(3 << 6)
+		.collect::<Vec<BinaryField16b>>();
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let (commitment, tree) = mr_prover.commit(&data, 3).unwrap();
+
+	let options = StreamingOptions {
+		retained_depth: 2,
+		block_len: 5,
+		spill_dir: Some(std::env::temp_dir()),
+	};
+	let streamed_prover =
+		StreamedBinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression, options);
+	let (streamed_commitment, streamed_tree) = streamed_prover.commit(&data, 3).unwrap();
+	assert_eq!(streamed_commitment, commitment);
+
+	for layer_depth in 0..=2 {
+		assert_eq!(
+			streamed_prover.layer(&streamed_tree, layer_depth).unwrap(),
+			mr_prover.layer(&tree, layer_depth).unwrap()
+		);
+	}
+	assert!(matches!(
+		streamed_prover.layer(&streamed_tree, 3),
+		Err(Error::LayerNotRetained { layer_depth: 3 })
+	));
+
+	for layer_depth in 0..=2 {
+		for i in 0..1 << 6 {
+			let mut proof_writer = AdviceWriter::new();
+			mr_prover
+				.prove_opening(&tree, layer_depth, i, &mut proof_writer)
+				.unwrap();
+			let mut streamed_proof_writer = AdviceWriter::new();
+			streamed_prover
+				.prove_opening(&streamed_tree, layer_depth, i, &mut streamed_proof_writer)
+				.unwrap();
+			assert_eq!(streamed_proof_writer.finalize(), proof_writer.finalize());
+		}
+	}
+}
+
+#[test]
+fn test_streamed_merkle_tree_without_spilling_only_commits() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(32)
+		.collect::<Vec<BinaryField16b>>();
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let (commitment, _) = mr_prover.commit(&data, 1).unwrap();
+
+	let streamed_prover = StreamedBinaryMerkleTreeProver::<_, Groestl256, _>::new(
+		Groestl256ByteCompression,
+		StreamingOptions::default(),
+	);
+	let (streamed_commitment, streamed_tree) = streamed_prover.commit(&data, 1).unwrap();
+	assert_eq!(streamed_commitment, commitment);
+
+	// The default retained depth exceeds the tree height, so the whole tree is kept.
+	streamed_prover
+		.prove_opening(&streamed_tree, 0, 7, AdviceWriter::new())
+		.unwrap();
+
+	let streamed_prover = StreamedBinaryMerkleTreeProver::<_, Groestl256, _>::new(
+		Groestl256ByteCompression,
+		StreamingOptions {
+			retained_depth: 1,
+			..Default::default()
+		},
+	);
+	let (_, streamed_tree) = streamed_prover.commit(&data, 1).unwrap();
+	assert!(matches!(
+		streamed_prover.prove_opening(&streamed_tree, 1, 7, AdviceWriter::new()),
+		Err(Error::LayerNotRetained { layer_depth: 5 })
+	));
+}
+
+#[test]
+fn test_streamed_merkle_tree_with_cap() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(32)
+		.collect::<Vec<BinaryField16b>>();
+	let scheme = || BinaryMerkleTreeScheme::new(Groestl256ByteCompression).with_cap_height(2);
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::from_scheme(scheme());
+	let (commitment, _) = mr_prover.commit(&data, 1).unwrap();
+
+	let options = StreamingOptions {
+		retained_depth: 2,
+		..Default::default()
+	};
+	let streamed_prover =
+		StreamedBinaryMerkleTreeProver::<_, Groestl256, _>::from_scheme(scheme(), options.clone());
+	let (streamed_commitment, _) = streamed_prover.commit(&data, 1).unwrap();
+	assert_eq!(streamed_commitment, commitment);
+
+	let streamed_prover = StreamedBinaryMerkleTreeProver::<_, Groestl256, _>::from_scheme(
+		scheme().with_log_arity(2),
+		options,
+	);
+	assert!(matches!(
+		streamed_prover.commit(&data, 1),
+		Err(Error::UnsupportedStreamedArity { log_arity: 2 })
+	));
+}
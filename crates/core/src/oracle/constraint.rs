@@ -30,6 +30,25 @@ pub enum ConstraintPredicate<F: Field> {
 	Zero,
 }
 
+impl<F: Field> ConstraintPredicate<F> {
+	pub fn isomorphic<FI: Field + From<F>>(&self) -> ConstraintPredicate<FI> {
+		match self {
+			Self::Sum(sum) => ConstraintPredicate::Sum(FI::from(*sum)),
+			Self::Zero => ConstraintPredicate::Zero,
+		}
+	}
+}
+
+impl<F: Field> Constraint<F> {
+	pub fn isomorphic<FI: Field + From<F>>(&self) -> Constraint<FI> {
+		Constraint {
+			name: self.name.clone(),
+			composition: self.composition.convert_field(),
+			predicate: self.predicate.isomorphic(),
+		}
+	}
+}
+
 /// Constraint set is a group of constraints that operate over the same set of oracle-identified multilinears
 #[derive(Debug, Clone)]
 pub struct ConstraintSet<F: Field> {
@@ -38,6 +57,20 @@ pub struct ConstraintSet<F: Field> {
 	pub constraints: Vec<Constraint<F>>,
 }
 
+impl<F: Field> ConstraintSet<F> {
+	pub fn isomorphic<FI: Field + From<F>>(&self) -> ConstraintSet<FI> {
+		ConstraintSet {
+			n_vars: self.n_vars,
+			oracle_ids: self.oracle_ids.clone(),
+			constraints: self
+				.constraints
+				.iter()
+				.map(Constraint::isomorphic)
+				.collect(),
+		}
+	}
+}
+
 // A deferred constraint constructor that instantiates index composition after the superset of oracles is known
 #[allow(clippy::type_complexity)]
 struct UngroupedConstraint<F: Field> {
@@ -47,6 +80,17 @@ struct UngroupedConstraint<F: Field> {
 	predicate: ConstraintPredicate<F>,
 }
 
+/// Simplifies the composition of a constraint as it is registered.
+///
+/// Compositions that fold to a constant are kept as they are, so that trivially satisfied and
+/// unsatisfiable constraints are still checked over their oracles.
+fn simplify_composition<F: Field>(composition: ArithExpr<F>) -> ArithExpr<F> {
+	match composition.clone().simplify() {
+		ArithExpr::Const(_) => composition,
+		simplified => simplified,
+	}
+}
+
 /// A builder struct that turns individual compositions over oraclized multilinears into a set of
 /// type erased `IndexComposition` instances operating over a superset of oracles of all constraints.
 #[derive(Default)]
@@ -61,6 +105,20 @@ impl<F: Field> ConstraintSetBuilder<F> {
 		}
 	}
 
+	/// The number of constraints added so far.
+	pub fn len(&self) -> usize {
+		self.constraints.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.constraints.is_empty()
+	}
+
+	/// Removes all but the first `len` constraints.
+	pub fn truncate(&mut self, len: usize) {
+		self.constraints.truncate(len);
+	}
+
 	pub fn add_sumcheck(
 		&mut self,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
@@ -70,7 +128,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 		self.constraints.push(UngroupedConstraint {
 			name: "sumcheck".into(),
 			oracle_ids: oracle_ids.into_iter().collect(),
-			composition,
+			composition: simplify_composition(composition),
 			predicate: ConstraintPredicate::Sum(sum),
 		});
 	}
@@ -84,7 +142,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 		self.constraints.push(UngroupedConstraint {
 			name: name.to_string().into(),
 			oracle_ids: oracle_ids.into_iter().collect(),
-			composition,
+			composition: simplify_composition(composition),
 			predicate: ConstraintPredicate::Zero,
 		});
 	}
@@ -107,6 +165,9 @@ impl<F: Field> ConstraintSetBuilder<F> {
 			if !oracles.is_valid_oracle_id(*id) {
 				bail!(Error::InvalidOracleId(*id));
 			}
+			if let MultilinearPolyOracle::Univariate { .. } = oracles.oracle(*id) {
+				bail!(Error::UnivariateInConstraint(*id));
+			}
 		}
 		oracle_ids.sort();
 		oracle_ids.dedup();
@@ -199,6 +260,9 @@ impl<F: Field> ConstraintSetBuilder<F> {
 					if !oracles.is_valid_oracle_id(*id) {
 						bail!(Error::InvalidOracleId(*id));
 					}
+					if let MultilinearPolyOracle::Univariate { .. } = oracles.oracle(*id) {
+						bail!(Error::UnivariateInConstraint(*id));
+					}
 				}
 				let n_vars = constraint
 					.oracle_ids
@@ -279,3 +343,52 @@ fn positions<T: Eq>(subset: &[T], superset: &[T]) -> Option<Vec<usize>> {
 		})
 		.collect()
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+
+	use super::*;
+
+	type F = BinaryField128b;
+
+	#[test]
+	fn test_compositions_simplified_on_registration() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, 0);
+		let b = oracles.add_committed(4, 0);
+
+		let mut builder = ConstraintSetBuilder::<F>::new();
+		let (x, y) = (ArithExpr::Var(0), ArithExpr::Var(1));
+		builder.add_zerocheck("cancel", [a, b], x.clone() * y.clone() + y.clone() - y * x.clone());
+		builder.add_zerocheck("trivial", [a], x.clone() - x);
+		let constraint_set = builder.build_one(&oracles).unwrap();
+
+		let [cancel, trivial] = &constraint_set.constraints[..] else {
+			panic!("expected two constraints");
+		};
+		assert_eq!(cancel.composition, ArithExpr::Var(1));
+		// Compositions folding to a constant are registered unchanged.
+		assert_eq!(trivial.composition.degree(), 1);
+	}
+
+	#[test]
+	fn test_univariate_oracles_rejected() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let (committed, univariate) = oracles.add_committed_univariate(4, 2, 0).unwrap();
+
+		let builder = || {
+			let mut builder = ConstraintSetBuilder::<F>::new();
+			builder.add_zerocheck("univariate", [committed, univariate], ArithExpr::Var(1));
+			builder
+		};
+		assert!(matches!(
+			builder().build_one(&oracles),
+			Err(Error::UnivariateInConstraint(id)) if id == univariate
+		));
+		assert!(matches!(
+			builder().build(&oracles),
+			Err(Error::UnivariateInConstraint(id)) if id == univariate
+		));
+	}
+}
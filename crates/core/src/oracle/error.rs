@@ -18,8 +18,25 @@ pub enum Error {
 		"n_vars ({n_vars}) must be at least as big as the requested log_degree ({log_degree})"
 	)]
 	NotEnoughVarsForPacking { n_vars: usize, log_degree: usize },
+	#[error(
+		"univariate domain log size ({log_domain_size}) must be positive and at most n_vars ({n_vars})"
+	)]
+	InvalidUnivariateDomain {
+		n_vars: usize,
+		log_domain_size: usize,
+	},
+	#[error("oracle {0} is univariate and cannot be used in constraints")]
+	UnivariateInConstraint(OracleId),
+	#[error("a concatenation must have at least one inner polynomial")]
+	EmptyConcatenation,
 	#[error("no oracle exists in this MultilinearOracleSet with id {0}")]
 	InvalidOracleId(OracleId),
+	#[error("oracle {id} has {n_vars} variables, the maximum is {max}")]
+	TooManyVariables {
+		id: OracleId,
+		n_vars: usize,
+		max: usize,
+	},
 	#[error("tower_level ({tower_level}) exceeds maximum")]
 	TowerLevelTooHigh { tower_level: usize },
 	#[error("constraint set is empty")]
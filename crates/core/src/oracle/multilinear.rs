@@ -3,17 +3,27 @@
 use std::{array, fmt::Debug, sync::Arc};
 
 use binius_field::{Field, TowerField};
-use binius_utils::bail;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
 use getset::{CopyGetters, Getters};
 
 use crate::{
 	oracle::{CompositePolyOracle, Error},
-	polynomial::{Error as PolynomialError, IdentityCompositionPoly, MultivariatePoly},
+	polynomial::{
+		Error as PolynomialError, IdentityCompositionPoly, IsomorphicPoly, MultivariatePoly,
+	},
 };
 
 /// Identifier for a multilinear oracle in a [`MultilinearOracleSet`].
 pub type OracleId = usize;
 
+/// The maximum number of variables of a multilinear oracle.
+///
+/// Rows are indexed with `usize`, and some oracles take a row index or row count up to $2^n$ as a
+/// parameter, so the row count of an oracle must fit in a `usize`. On 64-bit targets this allows
+/// oracles with far more than $2^{32}$ rows, which transparent and lazily computed columns can
+/// have without ever being stored in full.
+pub const MAX_N_VARS: usize = usize::BITS as usize - 1;
+
 /// Meta struct that lets you add optional `name` for the Multilinear before adding to the
 /// [`MultilinearOracleSet`]
 pub struct MultilinearOracleSetAddition<'a, F: TowerField> {
@@ -143,6 +153,46 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	pub fn univariate(self, inner_id: OracleId, log_domain_size: usize) -> Result<OracleId, Error> {
+		if inner_id >= self.mut_ref.oracles.len() {
+			bail!(Error::InvalidOracleId(inner_id));
+		}
+
+		let univariate = Univariate::new(self.mut_ref.get_from_set(inner_id), log_domain_size)?;
+
+		let oracle = |id: OracleId| MultilinearPolyOracle::Univariate {
+			id,
+			univariate,
+			name: self.name,
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
+	/// Adds a committed column of univariate polynomials in Lagrange basis, see [`Univariate`].
+	///
+	/// The Lagrange basis evaluations are committed as a multilinear oracle of `n_vars` variables,
+	/// which is returned together with the univariate oracle reading it. The witness is populated
+	/// for the committed oracle.
+	pub fn committed_univariate(
+		mut self,
+		n_vars: usize,
+		log_domain_size: usize,
+		tower_level: usize,
+	) -> Result<(OracleId, OracleId), Error> {
+		if log_domain_size == 0 || log_domain_size > n_vars {
+			bail!(Error::InvalidUnivariateDomain {
+				n_vars,
+				log_domain_size
+			});
+		}
+
+		let committed_name = self.name.as_ref().map(|name| format!("{name}_evals"));
+		let committed = self.add_committed_with_name(n_vars, tower_level, committed_name);
+		let univariate = self.univariate(committed, log_domain_size)?;
+		Ok((committed, univariate))
+	}
+
 	pub fn projected(
 		self,
 		inner_id: OracleId,
@@ -230,6 +280,35 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	pub fn concatenated(
+		self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, Error> {
+		let inner = inner
+			.into_iter()
+			.map(|inner_id| {
+				if inner_id >= self.mut_ref.oracles.len() {
+					return Err(Error::InvalidOracleId(inner_id));
+				}
+				if self.mut_ref.n_vars(inner_id) != n_vars {
+					return Err(Error::IncorrectNumberOfVariables { expected: n_vars });
+				}
+				Ok(self.mut_ref.get_from_set(inner_id))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let concatenated = Concatenated::new(n_vars, inner)?;
+
+		let oracle = |id: OracleId| MultilinearPolyOracle::Concatenated {
+			id,
+			concatenated,
+			name: self.name,
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
 	fn add_committed_with_name(
 		&mut self,
 		n_vars: usize,
@@ -271,6 +350,13 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.oracles.len()
 	}
 
+	/// Removes all oracles with an id of `size` or more.
+	///
+	/// Oracles only refer to oracles with smaller ids, so the remaining set is consistent.
+	pub fn truncate(&mut self, size: usize) {
+		self.oracles.truncate(size);
+	}
+
 	pub fn iter(&self) -> impl Iterator<Item = MultilinearPolyOracle<F>> + '_ {
 		(0..self.oracles.len()).map(|id| self.oracle(id))
 	}
@@ -314,6 +400,12 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().transparent(poly)
 	}
 
+	/// Adds a committed oracle with values in the binary tower field of the given level.
+	///
+	/// The tower level must not exceed `F::TOWER_LEVEL`, which committing the oracle checks, and
+	/// `n_vars` must not exceed [`MAX_N_VARS`], which [`Self::check_n_vars`] checks. The
+	/// tower tops out at [`BinaryField128b`](binius_field::BinaryField128b), so wider values, like
+	/// 256-bit EVM words, are committed as several oracles, for instance one per 128-bit limb.
 	pub fn add_committed(&mut self, n_vars: usize, tower_level: usize) -> OracleId {
 		self.add().committed(n_vars, tower_level)
 	}
@@ -344,6 +436,24 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().packed(id, log_degree)
 	}
 
+	pub fn add_univariate(
+		&mut self,
+		id: OracleId,
+		log_domain_size: usize,
+	) -> Result<OracleId, Error> {
+		self.add().univariate(id, log_domain_size)
+	}
+
+	pub fn add_committed_univariate(
+		&mut self,
+		n_vars: usize,
+		log_domain_size: usize,
+		tower_level: usize,
+	) -> Result<(OracleId, OracleId), Error> {
+		self.add()
+			.committed_univariate(n_vars, log_domain_size, tower_level)
+	}
+
 	pub fn add_projected(
 		&mut self,
 		id: OracleId,
@@ -371,6 +481,14 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 			.linear_combination_with_offset(n_vars, offset, inner)
 	}
 
+	pub fn add_concatenated(
+		&mut self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, Error> {
+		self.add().concatenated(n_vars, inner)
+	}
+
 	pub fn add_zero_padded(&mut self, id: OracleId, n_vars: usize) -> Result<OracleId, Error> {
 		self.add().zero_padded(id, n_vars)
 	}
@@ -387,10 +505,96 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.oracles[id].label()
 	}
 
+	/// Checks that no oracle has more than [`MAX_N_VARS`] variables.
+	///
+	/// Oracles derived from others, like repeating or zero-padded ones, can have more variables
+	/// than any committed oracle, so the check covers every oracle of the set.
+	pub fn check_n_vars(&self) -> Result<(), Error> {
+		if let Some(oracle) = self
+			.oracles
+			.iter()
+			.find(|oracle| oracle.n_vars() > MAX_N_VARS)
+		{
+			bail!(Error::TooManyVariables {
+				id: oracle.id(),
+				n_vars: oracle.n_vars(),
+				max: MAX_N_VARS,
+			});
+		}
+		Ok(())
+	}
+
 	/// Maximum tower level of the oracle's values over the boolean hypercube.
 	pub fn tower_level(&self, id: OracleId) -> usize {
 		self.oracles[id].binary_tower_level()
 	}
+
+	/// Maps the oracle set to an isomorphic tower field.
+	///
+	/// The oracles are re-added in order, so every oracle keeps its ID. Field constants are mapped
+	/// with the isomorphism and transparent polynomials are wrapped in an [`IsomorphicPoly`].
+	pub fn isomorphic<FI>(&self) -> Result<MultilinearOracleSet<FI>, Error>
+	where
+		F: From<FI>,
+		FI: TowerField + From<F>,
+	{
+		use MultilinearPolyOracle::*;
+
+		let mut result = MultilinearOracleSet::<FI>::new();
+		for oracle in self.oracles.iter() {
+			let addition = MultilinearOracleSetAddition {
+				name: oracle.name().map(ToString::to_string),
+				mut_ref: &mut result,
+			};
+			let id = match oracle.as_ref() {
+				Transparent { inner, .. } => {
+					addition.transparent(IsomorphicPoly::<F, FI>::new(inner.poly().clone()))?
+				}
+				Committed {
+					n_vars,
+					tower_level,
+					..
+				} => addition.committed(*n_vars, *tower_level),
+				Repeating {
+					inner, log_count, ..
+				} => addition.repeating(inner.id(), *log_count)?,
+				Projected { projected, .. } => addition.projected(
+					projected.inner().id(),
+					projected.values().iter().copied().map(FI::from).collect(),
+					projected.projection_variant(),
+				)?,
+				Shifted { shifted, .. } => addition.shifted(
+					shifted.inner().id(),
+					shifted.shift_offset(),
+					shifted.block_size(),
+					shifted.shift_variant(),
+				)?,
+				Packed { packed, .. } => {
+					addition.packed(packed.inner().id(), packed.log_degree())?
+				}
+				Univariate { univariate, .. } => {
+					addition.univariate(univariate.inner().id(), univariate.log_domain_size())?
+				}
+				LinearCombination {
+					linear_combination, ..
+				} => addition.linear_combination_with_offset(
+					linear_combination.n_vars(),
+					FI::from(linear_combination.offset()),
+					linear_combination
+						.polys()
+						.map(|poly| poly.id())
+						.zip(linear_combination.coefficients().map(FI::from)),
+				)?,
+				ZeroPadded { inner, n_vars, .. } => addition.zero_padded(inner.id(), *n_vars)?,
+				Concatenated { concatenated, .. } => addition.concatenated(
+					concatenated.inner_n_vars(),
+					concatenated.polys().map(|poly| poly.id()),
+				)?,
+			};
+			debug_assert_eq!(id, oracle.id());
+		}
+		Ok(result)
+	}
 }
 
 /// A multilinear polynomial oracle in the polynomial IOP model.
@@ -412,6 +616,9 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 ///    instead admits an interactive reduction for evaluation queries to evaluation queries to
 ///    other oracles. This is formalized in [DP23] Section 4.
 ///
+/// The [`Self::Univariate`] variant is the one exception to multilinearity: it is a virtual
+/// oracle that is univariate in its first variable, see [`Univariate`].
+///
 /// [DP23]: <https://eprint.iacr.org/2023/1784>
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MultilinearPolyOracle<F: Field> {
@@ -447,6 +654,11 @@ pub enum MultilinearPolyOracle<F: Field> {
 		packed: Packed<F>,
 		name: Option<String>,
 	},
+	Univariate {
+		id: OracleId,
+		univariate: Univariate<F>,
+		name: Option<String>,
+	},
 	LinearCombination {
 		id: OracleId,
 		linear_combination: LinearCombination<F>,
@@ -458,6 +670,11 @@ pub enum MultilinearPolyOracle<F: Field> {
 		n_vars: usize,
 		name: Option<String>,
 	},
+	Concatenated {
+		id: OracleId,
+		concatenated: Concatenated<F>,
+		name: Option<String>,
+	},
 }
 
 /// A transparent multilinear polynomial oracle.
@@ -495,7 +712,7 @@ impl<F: Field> PartialEq for TransparentPolyOracle<F> {
 
 impl<F: Field> Eq for TransparentPolyOracle<F> {}
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ProjectionVariant {
 	FirstVars,
 	LastVars,
@@ -534,9 +751,11 @@ impl<F: Field> Projected<F> {
 	}
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ShiftVariant {
 	CircularLeft,
+	/// Cyclic rotation towards lower indices, the inverse of [`ShiftVariant::CircularLeft`].
+	CircularRight,
 	LogicalLeft,
 	LogicalRight,
 }
@@ -599,6 +818,51 @@ pub struct Packed<F: Field> {
 	log_degree: usize,
 }
 
+/// A column whose blocks of $2^k$ consecutive values are univariate polynomials in Lagrange basis.
+///
+/// The inner oracle is read as a sequence of blocks over its lowest $k$ variables, each block
+/// being the evaluations of a univariate polynomial of degree less than $2^k$ over the domain
+/// used by univariate skip zerocheck. The univariate oracle replaces those $k$ variables with a
+/// single univariate variable, so that its evaluation at $(z, r)$ is
+/// $\sum_{i} L_i(z) \cdot \mathsf{inner}(i, r)$, where $L_i$ are the Lagrange basis polynomials of
+/// the domain.
+///
+/// Evaluation claims are reduced to multilinear claims on the inner oracle with a sumcheck against
+/// the [`LagrangeBasis`](crate::transparent::lagrange_basis::LagrangeBasis) transparent, which is
+/// how small field data committed at a high packing factor is opened at univariate points. A
+/// univariate column is committed with [`MultilinearOracleSet::add_committed_univariate`], which
+/// commits its inner oracle. Being univariate in its first variable, the oracle has no multilinear
+/// witness and can only appear in evaluation claims, not in constraints or as an inner oracle of
+/// other virtual oracles.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct Univariate<F: Field> {
+	#[get = "pub"]
+	inner: Arc<MultilinearPolyOracle<F>>,
+	/// The binary logarithm of the univariate domain size, called $k$ above.
+	#[get_copy = "pub"]
+	log_domain_size: usize,
+}
+
+impl<F: Field> Univariate<F> {
+	fn new(inner: Arc<MultilinearPolyOracle<F>>, log_domain_size: usize) -> Result<Self, Error> {
+		let n_vars = inner.n_vars();
+		if log_domain_size == 0 || log_domain_size > n_vars {
+			bail!(Error::InvalidUnivariateDomain {
+				n_vars,
+				log_domain_size
+			});
+		}
+		Ok(Self {
+			inner,
+			log_domain_size,
+		})
+	}
+
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars() - self.log_domain_size + 1
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
 pub struct LinearCombination<F: Field> {
 	#[get_copy = "pub"]
@@ -639,6 +903,59 @@ impl<F: Field> LinearCombination<F> {
 	}
 }
 
+/// The concatenation of several multilinears with the same number of variables.
+///
+/// The inner polynomials are laid out one after another, selected by the highest
+/// $\lceil \log_2 m \rceil$ variables, where $m$ is the number of inner polynomials. When $m$ is
+/// not a power of two, the remaining segments are zero. The evaluation at $(r, z)$ is therefore
+/// $\sum_i \widetilde{eq}(i, z) \cdot \mathsf{inner}_i(r)$.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct Concatenated<F: Field> {
+	/// The number of variables of each inner polynomial.
+	#[get_copy = "pub"]
+	inner_n_vars: usize,
+	inner: Vec<Arc<MultilinearPolyOracle<F>>>,
+}
+
+impl<F: Field> Concatenated<F> {
+	fn new(
+		inner_n_vars: usize,
+		inner: impl IntoIterator<Item = Arc<MultilinearPolyOracle<F>>>,
+	) -> Result<Self, Error> {
+		let inner = inner.into_iter().collect::<Vec<_>>();
+
+		if inner.is_empty() {
+			bail!(Error::EmptyConcatenation);
+		}
+		if !inner.iter().all(|poly| poly.n_vars() == inner_n_vars) {
+			bail!(Error::IncorrectNumberOfVariables {
+				expected: inner_n_vars
+			});
+		}
+		Ok(Self {
+			inner_n_vars,
+			inner,
+		})
+	}
+
+	pub fn n_polys(&self) -> usize {
+		self.inner.len()
+	}
+
+	pub fn polys(&self) -> impl Iterator<Item = &MultilinearPolyOracle<F>> {
+		self.inner.iter().map(|poly| poly.as_ref())
+	}
+
+	/// The number of high variables selecting the inner polynomial.
+	pub fn log_count(&self) -> usize {
+		log2_ceil_usize(self.inner.len())
+	}
+
+	fn n_vars(&self) -> usize {
+		self.inner_n_vars + self.log_count()
+	}
+}
+
 impl<F: Field> MultilinearPolyOracle<F> {
 	pub fn id(&self) -> OracleId {
 		use MultilinearPolyOracle::*;
@@ -649,8 +966,10 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Projected { id, .. } => *id,
 			Shifted { id, .. } => *id,
 			Packed { id, .. } => *id,
+			Univariate { id, .. } => *id,
 			LinearCombination { id, .. } => *id,
 			ZeroPadded { id, .. } => *id,
+			Concatenated { id, .. } => *id,
 		}
 	}
 
@@ -670,8 +989,10 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Projected { name, .. } => name.as_deref(),
 			Shifted { name, .. } => name.as_deref(),
 			Packed { name, .. } => name.as_deref(),
+			Univariate { name, .. } => name.as_deref(),
 			LinearCombination { name, .. } => name.as_deref(),
 			ZeroPadded { name, .. } => name.as_deref(),
+			Concatenated { name, .. } => name.as_deref(),
 		}
 	}
 
@@ -684,8 +1005,10 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Projected { .. } => "Projected",
 			Shifted { .. } => "Shifted",
 			Packed { .. } => "Packed",
+			Univariate { .. } => "Univariate",
 			LinearCombination { .. } => "LinearCombination",
 			ZeroPadded { .. } => "ZeroPadded",
+			Concatenated { .. } => "Concatenated",
 		}
 	}
 
@@ -700,10 +1023,12 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Projected { projected, .. } => projected.n_vars(),
 			Shifted { shifted, .. } => shifted.inner().n_vars(),
 			Packed { packed, .. } => packed.inner().n_vars() - packed.log_degree(),
+			Univariate { univariate, .. } => univariate.n_vars(),
 			LinearCombination {
 				linear_combination, ..
 			} => linear_combination.n_vars,
 			ZeroPadded { n_vars, .. } => *n_vars,
+			Concatenated { concatenated, .. } => concatenated.n_vars(),
 		}
 	}
 
@@ -718,6 +1043,7 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Projected { projected, .. } => projected.inner().binary_tower_level(),
 			Shifted { shifted, .. } => shifted.inner().binary_tower_level(),
 			Packed { packed, .. } => packed.log_degree + packed.inner().binary_tower_level(),
+			Univariate { univariate, .. } => univariate.inner().binary_tower_level(),
 			LinearCombination {
 				linear_combination, ..
 			} => linear_combination
@@ -727,6 +1053,11 @@ impl<F: Field> MultilinearPolyOracle<F> {
 				.max()
 				.unwrap_or(0),
 			ZeroPadded { inner, .. } => inner.binary_tower_level(),
+			Concatenated { concatenated, .. } => concatenated
+				.polys()
+				.map(|poly| poly.binary_tower_level())
+				.max()
+				.unwrap_or(0),
 		}
 	}
 
@@ -741,7 +1072,10 @@ impl<F: Field> MultilinearPolyOracle<F> {
 mod tests {
 	use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
 
-	use super::{MultilinearOracleSet, ProjectionVariant};
+	use super::{
+		MultilinearOracleSet, MultilinearPolyOracle, ProjectionVariant, ShiftVariant, MAX_N_VARS,
+	};
+	use crate::oracle::Error;
 
 	#[test]
 	fn add_projection_with_all_vars() {
@@ -757,4 +1091,49 @@ mod tests {
 			.unwrap();
 		let _ = oracles.oracle(projected);
 	}
+
+	#[test]
+	fn check_n_vars_of_tall_oracles() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let tall = oracles.add_committed(40, BinaryField1b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(tall, (1 << 33) + 5, 40, ShiftVariant::LogicalLeft)
+			.unwrap();
+		assert_eq!(oracles.n_vars(shifted), 40);
+		assert!(oracles
+			.add_shifted(tall, 1 << 40, 40, ShiftVariant::LogicalLeft)
+			.is_err());
+		let packed = oracles.add_packed(tall, 7).unwrap();
+		assert_eq!(oracles.n_vars(packed), 33);
+		oracles.check_n_vars().unwrap();
+
+		let repeated = oracles.add_repeating(tall, MAX_N_VARS - 39).unwrap();
+		assert!(matches!(
+			oracles.check_n_vars(),
+			Err(Error::TooManyVariables { id, n_vars, .. }) if id == repeated && n_vars == MAX_N_VARS + 1
+		));
+	}
+
+	#[test]
+	fn add_committed_univariate() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let (committed, univariate) = oracles
+			.add_named("column")
+			.committed_univariate(8, 3, BinaryField1b::TOWER_LEVEL)
+			.unwrap();
+		assert!(matches!(oracles.oracle(committed), MultilinearPolyOracle::Committed { .. }));
+		assert_eq!(oracles.oracle(committed).name(), Some("column_evals"));
+		assert_eq!(oracles.n_vars(univariate), 6);
+		assert_eq!(oracles.tower_level(univariate), BinaryField1b::TOWER_LEVEL);
+
+		// An invalid domain does not leave a committed oracle behind.
+		let size = oracles.size();
+		assert!(matches!(
+			oracles.add_committed_univariate(3, 4, BinaryField1b::TOWER_LEVEL),
+			Err(Error::InvalidUnivariateDomain { .. })
+		));
+		assert_eq!(oracles.size(), size);
+	}
 }
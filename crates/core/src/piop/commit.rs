@@ -112,16 +112,25 @@ fn n_packed_vars_for_committed_oracle<F: TowerField>(
 ) -> Result<usize, Error> {
 	let n_vars = oracle.n_vars();
 	let tower_level = oracle.binary_tower_level();
+	let log_extension_degree =
+		F::TOWER_LEVEL
+			.checked_sub(tower_level)
+			.ok_or_else(|| Error::OracleTowerLevelTooHigh {
+				id: oracle.id(),
+				tower_level,
+				max: F::TOWER_LEVEL,
+			})?;
 	n_vars
-		.checked_sub(F::TOWER_LEVEL - tower_level)
+		.checked_sub(log_extension_degree)
 		.ok_or_else(|| Error::OracleTooSmall {
 			id: oracle.id(),
-			min_vars: F::TOWER_LEVEL - tower_level,
+			min_vars: log_extension_degree,
 		})
 }
 
 #[cfg(test)]
 mod tests {
+	use assert_matches::assert_matches;
 	use binius_field::BinaryField128b;
 
 	use super::*;
@@ -156,4 +165,35 @@ mod tests {
 		assert_eq!(index.get(batch_2_2_ids[1]).cloned(), Some(11));
 		assert_eq!(index.get(repeat).cloned(), None);
 	}
+
+	#[test]
+	fn test_make_oracle_commit_meta_mixed_tower_levels() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+
+		// All oracles have 8 packed variables, so they are batched in a single bucket.
+		let b1 = oracles.add_committed(15, 0);
+		let b8 = oracles.add_committed(12, 3);
+		let b32 = oracles.add_committed(10, 5);
+		let b128 = oracles.add_committed(8, 7);
+
+		let (commit_meta, index) = make_oracle_commit_meta(&oracles).unwrap();
+		assert_eq!(commit_meta.total_multilins(), 4);
+		assert_eq!(commit_meta.total_vars(), 10);
+		assert_eq!(commit_meta.range_by_vars(8), 0..4);
+		for (i, id) in [b1, b8, b32, b128].into_iter().enumerate() {
+			assert_eq!(index.get(id).cloned(), Some(i));
+		}
+	}
+
+	#[test]
+	fn test_make_oracle_commit_meta_tower_level_too_high() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+		oracles.add_committed(8, 7);
+		let b256 = oracles.add_committed(8, 8);
+
+		assert_matches!(
+			make_oracle_commit_meta(&oracles),
+			Err(Error::OracleTowerLevelTooHigh { id, tower_level: 8, max: 7 }) if id == b256
+		);
+	}
 }
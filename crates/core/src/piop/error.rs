@@ -11,6 +11,12 @@ use crate::{
 pub enum Error {
 	#[error("committed oracle {id} has too few variables, must be at least {min_vars}")]
 	OracleTooSmall { id: OracleId, min_vars: usize },
+	#[error("committed oracle {id} has tower level {tower_level}, above the maximum {max}")]
+	OracleTowerLevelTooHigh {
+		id: OracleId,
+		tower_level: usize,
+		max: usize,
+	},
 	#[error("committed polynomials are not sorted in ascending order by number of variables")]
 	CommittedsNotSorted,
 	#[error("transparent polynomials are not sorted in ascending order by number of variables")]
@@ -25,6 +31,14 @@ pub enum Error {
 	OracleToCommitIndexMalformed { id: OracleId },
 	#[error("the number of variables of the polynomials in sumcheck claim {index} do not match")]
 	SumcheckClaimVariablesMismatch { index: usize },
+	#[error("the FRI fold arity must be positive")]
+	InvalidFoldArity,
+	#[error(
+		"{n_test_queries} FRI test queries do not reach the security level, which needs {min}"
+	)]
+	InsufficientTestQueries { n_test_queries: usize, min: usize },
+	#[error("the Merkle tree log arity must be positive")]
+	InvalidMerkleArity,
 	#[error("binius_math error: {0}")]
 	Math(#[from] binius_math::Error),
 	#[error("Polynomial error: {0}")]
@@ -37,6 +51,8 @@ pub enum Error {
 	Witness(#[from] witness::Error),
 	#[error("NTT error: {0}")]
 	NTT(#[from] binius_ntt::Error),
+	#[error("Transcript error: {0}")]
+	Transcript(#[from] transcript::Error),
 	#[error("verification error: {0}")]
 	VerificationError(#[from] VerificationError),
 }
@@ -29,17 +29,31 @@
 //! At the end of the interleaved sumcheck-FRI invocation, the verifier tests consistency of the
 //! claimed piecewise evaluations against the final FRI output.
 //!
+//! Committed multilinears over subfields of $\mathcal{T}_\tau$ are batched together with the
+//! others, regardless of their tower level. A multilinear with $n$ variables over
+//! $\mathcal{T}_\iota$ is viewed as a multilinear with $n - \tau + \iota$ variables over
+//! $\mathcal{T}_\tau$ by packing its coefficients, and is placed in the batch by that packed
+//! number of variables (see [`make_oracle_commit_meta`]). A proof therefore contains a single
+//! Merkle root and a single FRI opening for all committed columns, however many tower levels are
+//! used.
+//!
 //! [DP24]: <https://eprint.iacr.org/2024/504>
 
 pub mod commit;
 mod error;
+mod params;
 mod prove;
 #[cfg(test)]
 mod tests;
+pub mod trivial;
 mod util;
 mod verify;
 
 pub use commit::*;
 pub use error::*;
+pub use params::*;
 pub use prove::*;
-pub use verify::{make_commit_params_with_optimal_arity, verify, CommitMeta, PIOPSumcheckClaim};
+pub use verify::{
+	make_commit_params, make_commit_params_with_optimal_arity, verify, CommitMeta,
+	PIOPSumcheckClaim,
+};
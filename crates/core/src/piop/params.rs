@@ -0,0 +1,108 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_utils::bail;
+
+use super::error::Error;
+use crate::merkle_tree::BinaryMerkleTreeScheme;
+
+/// Parameters of the FRI polynomial commitment scheme.
+///
+/// A lower code rate makes the prover encode a longer codeword, but lets the verifier reach the
+/// same security level with fewer test queries, each of which costs Merkle opening proofs. The
+/// folding arity trades the number of committed FRI oracles against the size of each query
+/// opening. The presets in [`PCSPreset`] pick these for common trade-offs.
+///
+/// The Merkle trees committing the FRI oracles are binary and committed to by their roots by
+/// default. A higher Merkle arity shortens the opening paths, and a Merkle cap of `2^k` nodes in
+/// place of the root saves the verifier the hashing above the cap on every opening, each at the
+/// cost of a few more digests in the proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PCSParams {
+	/// The target security level in bits, which determines the number of test queries.
+	pub security_bits: usize,
+	/// The negative binary logarithm of the Reed–Solomon code rate.
+	pub log_inv_rate: usize,
+	/// The FRI folding arity, estimated to minimize the proof size if not set.
+	pub fold_arity: Option<usize>,
+	/// The number of FRI test queries, the minimum reaching `security_bits` if not set. Counts
+	/// below that minimum are rejected.
+	pub n_test_queries: Option<usize>,
+	/// The base-2 logarithm of the number of children of the inner Merkle tree nodes.
+	pub merkle_log_arity: usize,
+	/// The base-2 logarithm of the number of nodes in a Merkle tree commitment.
+	pub merkle_cap_height: usize,
+}
+
+/// Named trade-offs between prover time and proof size.
+///
+/// The prover's commitment cost is dominated by encoding the codeword, which doubles with every
+/// halving of the rate, and by hashing the FRI oracles into Merkle trees. The proof size is
+/// dominated by the test queries, whose number roughly halves with every halving of the rate, and
+/// by the Merkle paths opened for each of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PCSPreset {
+	/// Rate 1/2 and a fold arity of 4, the cheapest encoding, with few FRI oracles to commit, and
+	/// the largest proofs.
+	ProverFast,
+	/// Rate 1/4 and the fold arity estimated to minimize the proof size.
+	#[default]
+	Balanced,
+	/// Rate 1/8, the fold arity estimated to minimize the proof size, and a Merkle cap of 16
+	/// digests, which the many openings of each tree amortize. This is the most expensive encoding
+	/// with the smallest proofs.
+	ProofSmall,
+}
+
+impl PCSParams {
+	pub fn new(log_inv_rate: usize, security_bits: usize) -> Self {
+		Self {
+			security_bits,
+			log_inv_rate,
+			fold_arity: None,
+			n_test_queries: None,
+			merkle_log_arity: 1,
+			merkle_cap_height: 0,
+		}
+	}
+
+	pub fn preset(preset: PCSPreset, security_bits: usize) -> Self {
+		match preset {
+			PCSPreset::ProverFast => Self::new(1, security_bits).with_fold_arity(4),
+			PCSPreset::Balanced => Self::new(2, security_bits),
+			PCSPreset::ProofSmall => Self::new(3, security_bits).with_merkle_cap_height(4),
+		}
+	}
+
+	pub fn with_fold_arity(mut self, fold_arity: usize) -> Self {
+		self.fold_arity = Some(fold_arity);
+		self
+	}
+
+	pub fn with_n_test_queries(mut self, n_test_queries: usize) -> Self {
+		self.n_test_queries = Some(n_test_queries);
+		self
+	}
+
+	pub fn with_merkle_log_arity(mut self, merkle_log_arity: usize) -> Self {
+		self.merkle_log_arity = merkle_log_arity;
+		self
+	}
+
+	pub fn with_merkle_cap_height(mut self, merkle_cap_height: usize) -> Self {
+		self.merkle_cap_height = merkle_cap_height;
+		self
+	}
+
+	/// Makes the Merkle tree scheme committing the FRI oracles with the given compression function.
+	pub fn merkle_scheme<F, H, C>(
+		&self,
+		compression: C,
+	) -> Result<BinaryMerkleTreeScheme<F, H, C>, Error> {
+		if self.merkle_log_arity == 0 {
+			bail!(Error::InvalidMerkleArity);
+		}
+		Ok(BinaryMerkleTreeScheme::new(compression)
+			.with_log_arity(self.merkle_log_arity)
+			.with_cap_height(self.merkle_cap_height))
+	}
+}
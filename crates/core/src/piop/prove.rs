@@ -9,14 +9,18 @@ use binius_math::{
 	EvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
 use binius_ntt::{NTTOptions, ThreadingSettings};
-use binius_utils::{bail, serialization::SerializeBytes, sorting::is_sorted_ascending};
+use binius_utils::{
+	bail,
+	rayon::{iter::IntoParallelIterator, prelude::*},
+	serialization::SerializeBytes,
+	sorting::is_sorted_ascending,
+};
 use either::Either;
 use itertools::{chain, Itertools};
-use rayon::{iter::IntoParallelIterator, prelude::*};
 
 use super::{
 	error::Error,
-	verify::{make_sumcheck_claim_descs, PIOPSumcheckClaim},
+	verify::{make_sumcheck_claim_descs, PIOPSumcheckClaim, SumcheckClaimDesc},
 };
 use crate::{
 	fiat_shamir::{CanSample, CanSampleBits},
@@ -94,12 +98,13 @@ where
 /// * `multilins` - a batch of multilinear polynomials to commit. The multilinears provided may be
 ///     defined over subfields of `F`. They must be in ascending order by the number of variables
 ///     in the packed multilinear (ie. number of variables minus log extension degree).
+#[allow(clippy::type_complexity)]
 #[tracing::instrument("piop::commit", skip_all)]
 pub fn commit<F, FEncode, P, M, MTScheme, MTProver>(
 	fri_params: &FRIParams<F, FEncode>,
 	merkle_prover: &MTProver,
 	multilins: &[M],
-) -> Result<fri::CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
+) -> Result<fri::CommitOutput<P, Vec<MTScheme::Digest>, MTProver::Committed>, Error>
 where
 	F: BinaryField + ExtensionField<FEncode>,
 	FEncode: BinaryField,
@@ -169,7 +174,7 @@ pub fn prove<
 	merkle_prover: &MTProver,
 	domain_factory: DomainFactory,
 	commit_meta: &CommitMeta,
-	committed: MTProver::Committed,
+	committed: &MTProver::Committed,
 	codeword: &[P],
 	committed_multilins: &[M],
 	transparent_multilins: &[M],
@@ -197,10 +202,41 @@ where
 		claims,
 	)?;
 
-	// The committed multilinears provided by argument are committed *small field* multilinears.
-	// Create multilinears representing the packed polynomials here. Eventually, we would like to
-	// refactor the calling code so that the PIOP only handles *big field* multilinear witnesses.
-	let packed_committed_multilins = committed_multilins
+	let packed_committed_multilins = make_packed_committed_multilins(committed_multilins)?;
+	let sumcheck_provers = make_sumcheck_provers(
+		&sumcheck_claim_descs,
+		&packed_committed_multilins,
+		transparent_multilins,
+		&domain_factory,
+		backend,
+	)?;
+
+	prove_interleaved_fri_sumcheck(
+		commit_meta.total_vars(),
+		fri_params,
+		merkle_prover,
+		sumcheck_provers,
+		codeword,
+		committed,
+		proof,
+	)?;
+
+	Ok(())
+}
+
+/// Creates multilinears over `F` representing the packed committed multilinears.
+///
+/// The committed multilinears provided by argument are committed *small field* multilinears.
+/// Eventually, we would like to refactor the calling code so that the PIOP only handles *big
+/// field* multilinear witnesses.
+pub(super) fn make_packed_committed_multilins<P, M>(
+	committed_multilins: &[M],
+) -> Result<Vec<MLEDirectAdapter<P, &[P]>>, Error>
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	committed_multilins
 		.iter()
 		.enumerate()
 		.map(|(i, committed_multilin)| {
@@ -210,15 +246,30 @@ where
 			let packed_multilin = MultilinearExtension::from_values_slice(packed_evals)?;
 			Ok::<_, Error>(MLEDirectAdapter::from(packed_multilin))
 		})
-		.collect::<Result<Vec<_>, _>>()?;
+		.collect()
+}
 
-	let non_empty_sumcheck_descs = sumcheck_claim_descs
+/// Creates a sumcheck prover for each non-empty sumcheck claim description, in ascending order by
+/// number of variables.
+pub(super) fn make_sumcheck_provers<'a, F, FDomain, P, M, DomainFactory, Backend>(
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+	packed_committed_multilins: &'a [MLEDirectAdapter<P, &'a [P]>],
+	transparent_multilins: &'a [M],
+	domain_factory: DomainFactory,
+	backend: &'a Backend,
+) -> Result<Vec<impl SumcheckProver<F> + 'a>, Error>
+where
+	F: TowerField + ExtensionField<FDomain>,
+	FDomain: Field,
+	P: PackedFieldIndexable<Scalar = F> + PackedExtension<FDomain>,
+	M: MultilinearPoly<P> + Send + Sync,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	Backend: ComputationBackend,
+{
+	sumcheck_claim_descs
 		.iter()
-		.enumerate()
-		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty());
-	let sumcheck_provers = non_empty_sumcheck_descs
-		.clone()
-		.map(|(_n_vars, desc)| {
+		.filter(|desc| !desc.composite_sums.is_empty())
+		.map(|desc| {
 			let multilins = chain!(
 				packed_committed_multilins[desc.committed_indices.clone()]
 					.iter()
@@ -235,20 +286,9 @@ where
 				immediate_switchover_heuristic,
 				backend,
 			)
+			.map_err(Error::from)
 		})
-		.collect::<Result<Vec<_>, _>>()?;
-
-	prove_interleaved_fri_sumcheck(
-		commit_meta.total_vars(),
-		fri_params,
-		merkle_prover,
-		sumcheck_provers,
-		codeword,
-		committed,
-		proof,
-	)?;
-
-	Ok(())
+		.collect()
 }
 
 fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Transcript, Advice>(
@@ -257,7 +297,7 @@ fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Transcript,
 	merkle_prover: &MTProver,
 	sumcheck_provers: Vec<impl SumcheckProver<F>>,
 	codeword: &[P],
-	committed: MTProver::Committed,
+	committed: &MTProver::Committed,
 	proof: &mut Proof<Transcript, Advice>,
 ) -> Result<(), Error>
 where
@@ -270,12 +310,13 @@ where
 	Advice: CanWrite,
 {
 	let mut fri_prover =
-		FRIFolder::new(fri_params, merkle_prover, P::unpack_scalars(codeword), &committed)?;
+		FRIFolder::new(fri_params, merkle_prover, P::unpack_scalars(codeword), committed)?;
 
 	let mut sumcheck_batch_prover =
 		SumcheckBatchProver::new(sumcheck_provers, &mut proof.transcript)?;
 
 	for _ in 0..n_rounds {
+		proof.transcript.check_cancelled()?;
 		sumcheck_batch_prover.send_round_proof(&mut proof.transcript)?;
 		let challenge = proof.transcript.sample();
 		sumcheck_batch_prover.receive_challenge(challenge)?;
@@ -283,7 +324,7 @@ where
 		match fri_prover.execute_fold_round(challenge)? {
 			FoldRoundOutput::NoCommitment => {}
 			FoldRoundOutput::Commitment(round_commitment) => {
-				proof.transcript.write(&round_commitment);
+				proof.transcript.write_slice(&round_commitment);
 			}
 		}
 	}
@@ -3,8 +3,8 @@
 use std::iter::repeat_with;
 
 use binius_field::{
-	BinaryField, BinaryField16b, BinaryField8b, ExtensionField, Field, PackedBinaryField2x128b,
-	PackedExtension, PackedField, PackedFieldIndexable, TowerField,
+	BinaryField, BinaryField128b, BinaryField16b, BinaryField8b, ExtensionField, Field,
+	PackedBinaryField2x128b, PackedExtension, PackedField, PackedFieldIndexable, TowerField,
 };
 use binius_hal::make_portable_backend;
 use binius_hash::compress::Groestl256ByteCompression;
@@ -19,12 +19,14 @@ use super::{
 	prove,
 	prove::commit,
 	verify,
-	verify::{make_commit_params_with_optimal_arity, CommitMeta},
-	PIOPSumcheckClaim,
+	verify::{make_commit_params, make_commit_params_with_optimal_arity, CommitMeta},
+	Error, PCSParams, PCSPreset, PIOPSumcheckClaim,
 };
 use crate::{
 	fiat_shamir::HasherChallenger,
-	merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme},
+	merkle_tree::{
+		BinaryMerkleTreeProver, BinaryMerkleTreeScheme, MerkleTreeProver, MerkleTreeScheme,
+	},
 	polynomial::MultivariatePoly,
 	protocols::fri::CommitOutput,
 	transcript::{AdviceWriter, CanRead, CanWrite, Proof, TranscriptWriter},
@@ -154,7 +156,7 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		transcript: TranscriptWriter::<HasherChallenger<Groestl256>>::default(),
 		advice: AdviceWriter::default(),
 	};
-	proof.transcript.write(&commitment);
+	proof.transcript.write_slice(&commitment);
 
 	let domain_factory = DefaultEvaluationDomainFactory::<FDomain>::default();
 	prove(
@@ -162,7 +164,7 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		merkle_prover,
 		domain_factory,
 		commit_meta,
-		committed,
+		&committed,
 		&codeword,
 		&committed_multilins,
 		&transparent_multilins,
@@ -189,7 +191,7 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		.map(|poly| poly as &dyn MultivariatePoly<F>)
 		.collect::<Vec<_>>();
 
-	let commitment = proof.transcript.read().unwrap();
+	let commitment = proof.transcript.read_vec(commitment.len()).unwrap();
 	verify(
 		commit_meta,
 		merkle_scheme,
@@ -255,3 +257,52 @@ fn test_commit_prove_verify() {
 		log_inv_rate,
 	);
 }
+
+#[test]
+fn test_make_commit_params() {
+	type F = BinaryField128b;
+
+	let commit_meta = CommitMeta::with_vars([12, 14]);
+	let merkle_scheme = BinaryMerkleTreeScheme::<F, Groestl256, _>::new(Groestl256ByteCompression);
+	let make_params = |params: PCSParams| {
+		make_commit_params::<F, BinaryField16b, _>(&commit_meta, &merkle_scheme, &params)
+	};
+
+	// Lower rates need fewer queries for the same security level.
+	let n_test_queries = [
+		PCSPreset::ProverFast,
+		PCSPreset::Balanced,
+		PCSPreset::ProofSmall,
+	]
+	.map(|preset| {
+		let params = PCSParams::preset(preset, 100);
+		let fri_params = make_params(params).unwrap();
+		assert_eq!(
+			fri_params.rs_code().log_len() - fri_params.rs_code().log_dim(),
+			params.log_inv_rate
+		);
+		fri_params.n_test_queries()
+	});
+	assert!(n_test_queries[0] > n_test_queries[1]);
+	assert!(n_test_queries[1] > n_test_queries[2]);
+
+	let fast_params = make_params(PCSParams::preset(PCSPreset::ProverFast, 100)).unwrap();
+	assert!(fast_params.fold_arities().iter().all(|&arity| arity == 4));
+
+	// Explicit query counts may raise the security level, but not lower it.
+	let params = PCSParams::new(1, 100).with_fold_arity(2);
+	let min_test_queries = make_params(params).unwrap().n_test_queries();
+	let fri_params = make_params(params.with_n_test_queries(min_test_queries + 7)).unwrap();
+	assert!(fri_params.fold_arities().iter().all(|&arity| arity == 2));
+	assert_eq!(fri_params.n_test_queries(), min_test_queries + 7);
+	assert!(matches!(
+		make_params(params.with_n_test_queries(min_test_queries - 1)),
+		Err(Error::InsufficientTestQueries { n_test_queries, min })
+			if n_test_queries == min_test_queries - 1 && min == min_test_queries
+	));
+
+	assert!(matches!(
+		make_params(PCSParams::new(1, 100).with_fold_arity(0)),
+		Err(Error::InvalidFoldArity)
+	));
+}
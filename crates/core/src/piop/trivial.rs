@@ -0,0 +1,216 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! A trivial compilation of the multilinear polynomial IOP, which sends the committed multilinears
+//! in the clear.
+//!
+//! The "commitment" is the full list of packed evaluations of the committed multilinears, written
+//! to the transcript. The sumcheck claims are proven with the same front-loaded batched sumcheck
+//! as in the FRI-based compiler, without interleaving FRI folding, and the verifier checks the
+//! resulting committed evaluations directly against the multilinears it read.
+//!
+//! This is not succinct, so it is only useful for testing and for comparing against other
+//! commitment schemes.
+
+use std::{borrow::Borrow, iter};
+
+use binius_field::{ExtensionField, Field, PackedExtension, PackedFieldIndexable, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{
+	EvaluationDomainFactory, MultilinearExtension, MultilinearPoly, MultilinearQuery,
+};
+use binius_utils::sorting::is_sorted_ascending;
+
+use super::{
+	error::{Error, VerificationError},
+	prove::{make_packed_committed_multilins, make_sumcheck_provers},
+	verify::{
+		make_sumcheck_claim_descs, make_sumcheck_claims, verify_transparent_evals, CommitMeta,
+		PIOPSumcheckClaim,
+	},
+};
+use crate::{
+	fiat_shamir::CanSample,
+	polynomial::MultivariatePoly,
+	protocols::sumcheck::{
+		front_loaded::BatchVerifier as SumcheckBatchVerifier,
+		prove::front_loaded::BatchProver as SumcheckBatchProver,
+	},
+	transcript::{CanRead, CanWrite},
+};
+
+/// Commits a batch of multilinear polynomials by writing their packed evaluations to the
+/// transcript.
+///
+/// The multilinears must satisfy the same requirements as for [`super::commit()`].
+pub fn commit<F, P, M, Transcript>(
+	multilins: &[M],
+	transcript: &mut Transcript,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	P: PackedFieldIndexable<Scalar = F>,
+	M: MultilinearPoly<P>,
+	Transcript: CanWrite,
+{
+	for (i, multilin) in multilins.iter().enumerate() {
+		if multilin.n_vars() < multilin.log_extension_degree() {
+			return Err(Error::OracleTooSmall {
+				id: i,
+				min_vars: multilin.log_extension_degree(),
+			});
+		}
+	}
+
+	let n_packed_vars = multilins
+		.iter()
+		.map(|multilin| multilin.n_vars() - multilin.log_extension_degree());
+	if !is_sorted_ascending(n_packed_vars.clone()) {
+		return Err(Error::CommittedsNotSorted);
+	}
+
+	for (i, (multilin, n_packed_vars)) in iter::zip(multilins, n_packed_vars).enumerate() {
+		let packed_evals = multilin
+			.packed_evals()
+			.ok_or(Error::CommittedPackedEvaluationsMissing { id: i })?;
+		let evals = P::unpack_scalars(packed_evals);
+		transcript.write_scalar_slice(&evals[..1 << n_packed_vars]);
+	}
+	Ok(())
+}
+
+/// Reads the packed committed multilinears written by [`commit`] from the transcript.
+pub fn read_commitment<F, Transcript>(
+	commit_meta: &CommitMeta,
+	transcript: &mut Transcript,
+) -> Result<Vec<MultilinearExtension<F>>, Error>
+where
+	F: TowerField,
+	Transcript: CanRead,
+{
+	let mut multilins = Vec::with_capacity(commit_meta.total_multilins());
+	for (n_vars, &n_multilins) in commit_meta.n_multilins_by_vars().iter().enumerate() {
+		for _ in 0..n_multilins {
+			let evals = transcript
+				.read_scalar_slice(1 << n_vars)
+				.map_err(VerificationError::Transcript)?;
+			multilins.push(MultilinearExtension::from_values(evals)?);
+		}
+	}
+	Ok(multilins)
+}
+
+/// Proves a batch of sumcheck claims that are products of committed polynomials from a batch
+/// committed with [`commit`] and transparent polynomials.
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument("piop::trivial::prove", skip_all)]
+pub fn prove<F, FDomain, P, M, DomainFactory, Transcript, Backend>(
+	domain_factory: DomainFactory,
+	commit_meta: &CommitMeta,
+	committed_multilins: &[M],
+	transparent_multilins: &[M],
+	claims: &[PIOPSumcheckClaim<F>],
+	transcript: &mut Transcript,
+	backend: &Backend,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FDomain>,
+	FDomain: Field,
+	P: PackedFieldIndexable<Scalar = F> + PackedExtension<FDomain>,
+	M: MultilinearPoly<P> + Send + Sync,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	Transcript: CanSample<F> + CanWrite,
+	Backend: ComputationBackend,
+{
+	let sumcheck_claim_descs = make_sumcheck_claim_descs(
+		commit_meta,
+		transparent_multilins.iter().map(|poly| poly.n_vars()),
+		claims,
+	)?;
+
+	let packed_committed_multilins = make_packed_committed_multilins(committed_multilins)?;
+	let sumcheck_provers = make_sumcheck_provers(
+		&sumcheck_claim_descs,
+		&packed_committed_multilins,
+		transparent_multilins,
+		&domain_factory,
+		backend,
+	)?;
+
+	let mut sumcheck_batch_prover = SumcheckBatchProver::new(sumcheck_provers, transcript)?;
+	for _ in 0..commit_meta.max_n_vars() {
+		sumcheck_batch_prover.send_round_proof(transcript)?;
+		let challenge = transcript.sample();
+		sumcheck_batch_prover.receive_challenge(challenge)?;
+	}
+	sumcheck_batch_prover.finish(transcript)?;
+	Ok(())
+}
+
+/// Verifies a batch of sumcheck claims that are products of committed polynomials from a batch
+/// read with [`read_commitment`] and transparent polynomials.
+#[tracing::instrument("piop::trivial::verify", skip_all)]
+pub fn verify<'a, F, Transcript>(
+	commit_meta: &CommitMeta,
+	committed: &[MultilinearExtension<F>],
+	transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+	claims: &[PIOPSumcheckClaim<F>],
+	transcript: &mut Transcript,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	Transcript: CanSample<F> + CanRead,
+{
+	let sumcheck_claim_descs = make_sumcheck_claim_descs(
+		commit_meta,
+		transparents.iter().map(|poly| poly.borrow().n_vars()),
+		claims,
+	)?;
+	let sumcheck_claims = make_sumcheck_claims(&sumcheck_claim_descs)?;
+
+	let n_rounds = commit_meta.max_n_vars();
+	let mut sumcheck_verifier = SumcheckBatchVerifier::new(&sumcheck_claims, transcript)?;
+	let mut multilinear_evals = Vec::with_capacity(sumcheck_claims.len());
+	let mut challenges = Vec::with_capacity(n_rounds);
+	for _ in 0..n_rounds {
+		while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(transcript)? {
+			multilinear_evals.push(claim_multilinear_evals);
+		}
+		sumcheck_verifier.receive_round_proof(transcript)?;
+
+		let challenge = transcript.sample();
+		challenges.push(challenge);
+
+		sumcheck_verifier.finish_round(challenge)?;
+	}
+	while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(transcript)? {
+		multilinear_evals.push(claim_multilinear_evals);
+	}
+	sumcheck_verifier.finish()?;
+
+	let non_empty_sumcheck_descs = sumcheck_claim_descs
+		.iter()
+		.enumerate()
+		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty());
+	let committed_evals = verify_transparent_evals(
+		commit_meta,
+		non_empty_sumcheck_descs.clone(),
+		multilinear_evals,
+		transparents,
+		&challenges,
+	)?;
+
+	// Verify the committed evals against the multilinears sent in the clear.
+	let committed_indices = non_empty_sumcheck_descs.flat_map(|(n_vars, desc)| {
+		desc.committed_indices
+			.clone()
+			.map(move |index| (n_vars, index))
+	});
+	for ((n_vars, index), claimed_eval) in iter::zip(committed_indices, committed_evals) {
+		let query = MultilinearQuery::<F>::expand(&challenges[..n_vars]);
+		if committed[index].evaluate(query.to_ref())? != claimed_eval {
+			return Err(VerificationError::IncorrectSumcheckEvaluation.into());
+		}
+	}
+
+	Ok(())
+}
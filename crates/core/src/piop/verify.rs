@@ -9,7 +9,10 @@ use binius_utils::{bail, serialization::DeserializeBytes};
 use getset::CopyGetters;
 use tracing::instrument;
 
-use super::error::{Error, VerificationError};
+use super::{
+	error::{Error, VerificationError},
+	PCSParams,
+};
 use crate::{
 	composition::{BivariateProduct, IndexComposition},
 	fiat_shamir::{CanSample, CanSampleBits},
@@ -129,6 +132,7 @@ fn make_commit_params_with_constant_arity<F, FEncode>(
 	security_bits: usize,
 	log_inv_rate: usize,
 	arity: usize,
+	n_test_queries: Option<usize>,
 ) -> Result<FRIParams<F, FEncode>, Error>
 where
 	F: BinaryField + ExtensionField<FEncode>,
@@ -147,14 +151,24 @@ where
 	let log_dim = commit_meta.total_vars - log_batch_size;
 
 	let rs_code = ReedSolomonCode::new(log_dim, log_inv_rate, NTTOptions::default())?;
-	let n_test_queries = fri::calculate_n_test_queries::<F, _>(security_bits, &rs_code)?;
+	let min_test_queries = fri::calculate_n_test_queries::<F, _>(security_bits, &rs_code)?;
+	let n_test_queries = match n_test_queries {
+		Some(n_test_queries) if n_test_queries < min_test_queries => {
+			bail!(Error::InsufficientTestQueries {
+				n_test_queries,
+				min: min_test_queries,
+			})
+		}
+		Some(n_test_queries) => n_test_queries,
+		None => min_test_queries,
+	};
 	let fri_params = FRIParams::new(rs_code, log_batch_size, fold_arities, n_test_queries)?;
 	Ok(fri_params)
 }
 
 pub fn make_commit_params_with_optimal_arity<F, FEncode, MTScheme>(
 	commit_meta: &CommitMeta,
-	_merkle_scheme: &MTScheme,
+	merkle_scheme: &MTScheme,
 	security_bits: usize,
 	log_inv_rate: usize,
 ) -> Result<FRIParams<F, FEncode>, Error>
@@ -163,12 +177,36 @@ where
 	FEncode: BinaryField,
 	MTScheme: MerkleTreeScheme<F>,
 {
-	let arity = estimate_optimal_arity(
-		commit_meta.total_vars + log_inv_rate,
-		size_of::<MTScheme::Digest>(),
-		size_of::<F>(),
-	);
-	make_commit_params_with_constant_arity(commit_meta, security_bits, log_inv_rate, arity)
+	make_commit_params(commit_meta, merkle_scheme, &PCSParams::new(log_inv_rate, security_bits))
+}
+
+/// Makes the FRI parameters for a committed batch with the given PCS parameters.
+pub fn make_commit_params<F, FEncode, MTScheme>(
+	commit_meta: &CommitMeta,
+	_merkle_scheme: &MTScheme,
+	params: &PCSParams,
+) -> Result<FRIParams<F, FEncode>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	let arity = match params.fold_arity {
+		Some(0) => bail!(Error::InvalidFoldArity),
+		Some(arity) => arity,
+		None => estimate_optimal_arity(
+			commit_meta.total_vars + params.log_inv_rate,
+			size_of::<MTScheme::Digest>(),
+			size_of::<F>(),
+		),
+	};
+	make_commit_params_with_constant_arity(
+		commit_meta,
+		params.security_bits,
+		params.log_inv_rate,
+		arity,
+		params.n_test_queries,
+	)
 }
 
 /// A description of a sumcheck claim arising from a FRI PCS sumcheck.
@@ -274,6 +312,28 @@ pub fn make_sumcheck_claim_descs<F: Field>(
 	Ok(sumcheck_claim_descs)
 }
 
+/// Makes a single sumcheck claim for each non-empty sumcheck claim description, in ascending order
+/// by number of variables.
+pub(super) fn make_sumcheck_claims<F: Field>(
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+) -> Result<Vec<SumcheckClaim<F, IndexComposition<BivariateProduct, 2>>>, Error> {
+	sumcheck_claim_descs
+		.iter()
+		.enumerate()
+		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty())
+		.map(|(n_vars, desc)| {
+			// Make a single sumcheck claim with compositions of the committed and transparent
+			// polynomials with `n_vars` variables
+			SumcheckClaim::new(
+				n_vars,
+				desc.committed_indices.len() + desc.transparent_indices.len(),
+				desc.composite_sums.clone(),
+			)
+			.map_err(Error::from)
+		})
+		.collect()
+}
+
 /// Verifies a batch of sumcheck claims that are products of committed polynomials from a committed
 /// batch and transparent polynomials.
 ///
@@ -291,7 +351,7 @@ pub fn verify<'a, F, FEncode, Transcript, Advice, MTScheme>(
 	commit_meta: &CommitMeta,
 	merkle_scheme: &MTScheme,
 	fri_params: &FRIParams<F, FEncode>,
-	commitment: &MTScheme::Digest,
+	commitment: &[MTScheme::Digest],
 	transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
 	claims: &[PIOPSumcheckClaim<F>],
 	proof: &mut Proof<Transcript, Advice>,
@@ -314,18 +374,7 @@ where
 		.iter()
 		.enumerate()
 		.filter(|(_n_vars, desc)| !desc.composite_sums.is_empty());
-	let sumcheck_claims = non_empty_sumcheck_descs
-		.clone()
-		.map(|(n_vars, desc)| {
-			// Make a single sumcheck claim with compositions of the committed and transparent
-			// polynomials with `n_vars` variables
-			SumcheckClaim::new(
-				n_vars,
-				desc.committed_indices.len() + desc.transparent_indices.len(),
-				desc.composite_sums.clone(),
-			)
-		})
-		.collect::<Result<Vec<_>, _>>()?;
+	let sumcheck_claims = make_sumcheck_claims(&sumcheck_claim_descs)?;
 
 	// Interleaved front-loaded sumcheck
 	let BatchInterleavedSumcheckFRIOutput {
@@ -366,7 +415,7 @@ where
 
 // Verify the transparent evals and collect the committed evals.
 #[instrument(skip_all, level = "debug")]
-fn verify_transparent_evals<'a, 'b, F: Field>(
+pub(super) fn verify_transparent_evals<'a, 'b, F: Field>(
 	commit_meta: &CommitMeta,
 	sumcheck_descs: impl Iterator<Item = (usize, &'a SumcheckClaimDesc<F>)>,
 	multilinear_evals: Vec<Vec<F>>,
@@ -415,7 +464,7 @@ fn verify_interleaved_fri_sumcheck<F, FEncode, Transcript, Advice, MTScheme>(
 	fri_params: &FRIParams<F, FEncode>,
 	merkle_scheme: &MTScheme,
 	claims: &[SumcheckClaim<F, IndexComposition<BivariateProduct, 2>>],
-	codeword_commitment: &MTScheme::Digest,
+	codeword_commitment: &[MTScheme::Digest],
 	proof: &mut Proof<Transcript, Advice>,
 ) -> Result<BatchInterleavedSumcheckFRIOutput<F>, Error>
 where
@@ -426,6 +475,7 @@ where
 	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes>,
 {
 	let mut arities_iter = fri_params.fold_arities().iter();
+	let mut round_tree_depths = fri::vcs_tree_depths_iter(fri_params).skip(1);
 	let mut fri_commitments = Vec::with_capacity(fri_params.n_oracles());
 	let mut next_commit_round = arities_iter.next().copied();
 
@@ -447,9 +497,12 @@ where
 
 		let observe_fri_comm = next_commit_round.is_some_and(|round| round == round_no + 1);
 		if observe_fri_comm {
+			let tree_depth = round_tree_depths
+				.next()
+				.expect("there is a Merkle tree for every round oracle");
 			let comm = proof
 				.transcript
-				.read()
+				.read_vec(1 << merkle_scheme.cap_depth(tree_depth))
 				.map_err(VerificationError::Transcript)?;
 			fri_commitments.push(comm);
 			next_commit_round = arities_iter.next().map(|arity| round_no + 1 + arity);
@@ -0,0 +1,69 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use binius_field::Field;
+
+use super::{Error, MultivariatePoly};
+
+/// A multivariate polynomial over `F` viewed as a polynomial over an isomorphic field `FI`.
+///
+/// Queries are mapped to `F` with the field isomorphism, and the evaluation is mapped back.
+#[derive(Debug)]
+pub struct IsomorphicPoly<F: Field, FI: Field> {
+	inner: Arc<dyn MultivariatePoly<F>>,
+	_marker: PhantomData<fn() -> FI>,
+}
+
+impl<F: Field, FI: Field> IsomorphicPoly<F, FI> {
+	pub fn new(inner: Arc<dyn MultivariatePoly<F>>) -> Self {
+		Self {
+			inner,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<F, FI> MultivariatePoly<FI> for IsomorphicPoly<F, FI>
+where
+	F: Field + From<FI>,
+	FI: Field + From<F>,
+{
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.inner.degree()
+	}
+
+	fn evaluate(&self, query: &[FI]) -> Result<FI, Error> {
+		let query = query.iter().copied().map(F::from).collect::<Vec<_>>();
+		self.inner.evaluate(&query).map(FI::from)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.inner.binary_tower_level()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{AESTowerField128b, BinaryField128b};
+
+	use super::*;
+	use crate::transparent::eq_ind::EqIndPartialEval;
+
+	#[test]
+	fn test_isomorphic_poly_evaluation() {
+		let point = [3, 5, 7].map(BinaryField128b::new).to_vec();
+		let query = [11, 13, 17].map(BinaryField128b::new);
+		let eq_ind = EqIndPartialEval::new(3, point).unwrap();
+		let expected = eq_ind.evaluate(&query).unwrap();
+
+		let isomorphic =
+			IsomorphicPoly::<BinaryField128b, AESTowerField128b>::new(Arc::new(eq_ind));
+		let query = query.map(AESTowerField128b::from);
+		assert_eq!(isomorphic.evaluate(&query).unwrap(), AESTowerField128b::from(expected));
+	}
+}
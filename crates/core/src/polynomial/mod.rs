@@ -3,6 +3,7 @@
 mod arith_circuit;
 mod cached;
 mod error;
+mod isomorphic;
 mod multivariate;
 #[allow(dead_code)]
 #[doc(hidden)]
@@ -11,4 +12,5 @@ pub mod test_utils;
 pub use arith_circuit::*;
 pub use cached::*;
 pub use error::*;
+pub use isomorphic::*;
 pub use multivariate::*;
@@ -1,6 +1,8 @@
 // Copyright 2023-2025 Irreducible Inc.
 
-use std::{borrow::Borrow, fmt::Debug, iter::repeat_with, marker::PhantomData, sync::Arc};
+use std::{
+	any::Any, borrow::Borrow, fmt::Debug, iter::repeat_with, marker::PhantomData, sync::Arc,
+};
 
 use binius_field::{Field, PackedField};
 use binius_math::{
@@ -28,6 +30,13 @@ pub trait MultivariatePoly<P>: Debug + Send + Sync {
 
 	/// Returns the maximum binary tower level of all constants in the arithmetic expression.
 	fn binary_tower_level(&self) -> usize;
+
+	/// Returns the polynomial as [`Any`] if it is a type that consumers may recognize, such as
+	/// the transparent polynomials of [`crate::transparent`] that circuit bundles describe by
+	/// their parameters.
+	fn as_any(&self) -> Option<&dyn Any> {
+		None
+	}
 }
 
 /// Identity composition function $g(X) = X$.
@@ -7,6 +7,7 @@ use std::{
 };
 
 use binius_field::{Field, TowerField};
+use bytes::Buf;
 
 use super::error::Error;
 use crate::{
@@ -34,6 +35,8 @@ enum EvalcheckNumerics {
 	Repeating,
 	LinearCombination,
 	ZeroPadded,
+	Univariate,
+	Concatenated,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -47,6 +50,10 @@ pub enum EvalcheckProof<F: Field> {
 		subproofs: Vec<(F, EvalcheckProof<F>)>,
 	},
 	ZeroPadded(F, Box<EvalcheckProof<F>>),
+	Univariate,
+	Concatenated {
+		subproofs: Vec<(F, EvalcheckProof<F>)>,
+	},
 }
 
 impl<F: Field> EvalcheckProof<F> {
@@ -68,6 +75,13 @@ impl<F: Field> EvalcheckProof<F> {
 			EvalcheckProof::ZeroPadded(eval, proof) => {
 				EvalcheckProof::ZeroPadded(eval.into(), Box::new(proof.isomorphic()))
 			}
+			EvalcheckProof::Univariate => EvalcheckProof::Univariate,
+			EvalcheckProof::Concatenated { subproofs } => EvalcheckProof::Concatenated {
+				subproofs: subproofs
+					.into_iter()
+					.map(|(eval, proof)| (eval.into(), proof.isomorphic()))
+					.collect::<Vec<_>>(),
+			},
 		}
 	}
 }
@@ -82,6 +96,8 @@ impl EvalcheckNumerics {
 			5 => Ok(EvalcheckNumerics::Repeating),
 			6 => Ok(EvalcheckNumerics::LinearCombination),
 			7 => Ok(EvalcheckNumerics::ZeroPadded),
+			8 => Ok(EvalcheckNumerics::Univariate),
+			9 => Ok(EvalcheckNumerics::Concatenated),
 			_ => Err(Error::EvalcheckSerializationError),
 		}
 	}
@@ -123,6 +139,18 @@ pub fn serialize_evalcheck_proof<Transcript: CanWrite, F: TowerField>(
 			transcript.write_scalar(*val);
 			serialize_evalcheck_proof(transcript, subproof.as_ref());
 		}
+		EvalcheckProof::Univariate => {
+			transcript.write_bytes(&[EvalcheckNumerics::Univariate as u8]);
+		}
+		EvalcheckProof::Concatenated { subproofs } => {
+			transcript.write_bytes(&[EvalcheckNumerics::Concatenated as u8]);
+			let len_u64 = subproofs.len() as u64;
+			transcript.write_bytes(&len_u64.to_le_bytes());
+			for (scalar, subproof) in subproofs {
+				transcript.write_scalar(*scalar);
+				serialize_evalcheck_proof(transcript, subproof)
+			}
+		}
 	}
 }
 
@@ -159,6 +187,23 @@ pub fn deserialize_evalcheck_proof<Transcript: CanRead, F: TowerField>(
 			let subproof = deserialize_evalcheck_proof(transcript)?;
 			Ok(EvalcheckProof::ZeroPadded(scalar, Box::new(subproof)))
 		}
+		EvalcheckNumerics::Univariate => Ok(EvalcheckProof::Univariate),
+		EvalcheckNumerics::Concatenated => {
+			let mut len = [0u8; 8];
+			transcript.read_bytes(&mut len)?;
+			let len = u64::from_le_bytes(len);
+			// Every subproof takes at least a byte, so a longer count is malformed
+			if len > transcript.buffer().remaining() as u64 {
+				return Err(Error::EvalcheckSerializationError);
+			}
+			let mut subproofs = Vec::with_capacity(len as usize);
+			for _ in 0..len {
+				let scalar = transcript.read_scalar()?;
+				let subproof = deserialize_evalcheck_proof(transcript)?;
+				subproofs.push((scalar, subproof));
+			}
+			Ok(EvalcheckProof::Concatenated { subproofs })
+		}
 	}
 }
 
@@ -7,9 +7,9 @@ use binius_field::{
 };
 use binius_hal::ComputationBackend;
 use binius_math::MultilinearExtension;
+use binius_utils::rayon::prelude::*;
 use getset::{Getters, MutGetters};
 use itertools::izip;
-use rayon::prelude::*;
 use tracing::instrument;
 
 use super::{
@@ -25,7 +25,7 @@ use crate::{
 	},
 	protocols::evalcheck::subclaims::{
 		packed_sumcheck_meta, process_packed_sumcheck, process_shifted_sumcheck,
-		shifted_sumcheck_meta,
+		process_univariate_sumcheck, shifted_sumcheck_meta, univariate_sumcheck_meta,
 	},
 	witness::MultilinearExtensionIndex,
 };
@@ -60,6 +60,7 @@ where
 	new_sumchecks_constraints: Vec<ConstraintSetBuilder<F>>,
 	memoized_queries: MemoizedQueries<PackedType<U, F>, Backend>,
 	backend: &'a Backend,
+	deterministic: bool,
 }
 
 impl<'a, 'b, U, F, Backend> EvalcheckProver<'a, 'b, U, F, Backend>
@@ -90,9 +91,17 @@ where
 			memoized_queries: MemoizedQueries::new(),
 			backend,
 			incomplete_proof_claims: EvalPointOracleIdMap::new(),
+			deterministic: false,
 		}
 	}
 
+	/// Memoizes evaluation queries sequentially, so that their allocation order does not depend on
+	/// the number of threads. The proof is the same either way.
+	pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+		self.deterministic = deterministic;
+		self
+	}
+
 	/// A helper method to move out sumcheck constraints
 	pub fn take_new_sumchecks_constraints(&mut self) -> Result<Vec<ConstraintSet<F>>, OracleError> {
 		self.new_sumchecks_constraints
@@ -167,8 +176,13 @@ where
 				.map(|(_, eval_point)| eval_point.as_ref())
 				.collect::<Vec<_>>();
 
-			self.memoized_queries
-				.memoize_query_par(deduplicated_eval_points, self.backend)?;
+			if self.deterministic {
+				self.memoized_queries
+					.memoize_query_seq(deduplicated_eval_points, self.backend)?;
+			} else {
+				self.memoized_queries
+					.memoize_query_par(deduplicated_eval_points, self.backend)?;
+			}
 
 			// Make new evaluation claims in parallel.
 			let subclaims = deduplicated_claims_without_evals
@@ -325,6 +339,14 @@ where
 				);
 			}
 
+			Univariate { .. } => {
+				self.finalized_proofs.insert(
+					multilinear_id,
+					eval_point,
+					(eval, EvalcheckProof::Univariate),
+				);
+			}
+
 			Projected { projected, .. } => {
 				let (inner, values) = (projected.inner(), projected.values());
 				let new_eval_point = match projected.projection_variant() {
@@ -389,6 +411,16 @@ where
 				self.incomplete_proof_claims
 					.insert(multilinear_id, eval_point, evalcheck_claim);
 			}
+
+			Concatenated { concatenated, .. } => {
+				let inner_eval_point = eval_point.slice(0..concatenated.inner_n_vars());
+				for suboracle in concatenated.polys() {
+					self.claims_without_evals
+						.push((suboracle.clone(), inner_eval_point.clone()));
+				}
+				self.incomplete_proof_claims
+					.insert(multilinear_id, eval_point, evalcheck_claim);
+			}
 		};
 	}
 
@@ -474,6 +506,25 @@ where
 						);
 					})
 			}
+
+			Concatenated { concatenated, .. } => {
+				let inner_eval_point = &evalcheck_claim.eval_point[..concatenated.inner_n_vars()];
+				concatenated
+					.polys()
+					.map(|suboracle| {
+						self.finalized_proofs
+							.get(suboracle.id(), inner_eval_point)
+							.map(|(eval, subproof)| (*eval, subproof.clone()))
+					})
+					.collect::<Option<Vec<_>>>()
+					.map(|subproofs| {
+						self.finalized_proofs.insert(
+							evalcheck_claim.poly.id(),
+							eval_point,
+							(eval, EvalcheckProof::Concatenated { subproofs }),
+						);
+					})
+			}
 			_ => unreachable!(),
 		};
 		res.is_some()
@@ -533,6 +584,7 @@ where
 			}
 			Shifted { .. } => self.projected_bivariate_claims.push(evalcheck_claim),
 			Packed { .. } => self.projected_bivariate_claims.push(evalcheck_claim),
+			Univariate { .. } => self.projected_bivariate_claims.push(evalcheck_claim),
 			LinearCombination {
 				linear_combination, ..
 			} => {
@@ -565,6 +617,21 @@ where
 				};
 				self.collect_projected_committed(subclaim);
 			}
+			Concatenated { concatenated, .. } => {
+				let inner_eval_point = eval_point.slice(0..concatenated.inner_n_vars());
+				for poly in concatenated.polys().cloned() {
+					let (eval, _) = self
+						.finalized_proofs
+						.get(poly.id(), &inner_eval_point)
+						.expect("finalized_proofs contains all the proofs");
+					let subclaim = EvalcheckMultilinearClaim {
+						poly,
+						eval_point: inner_eval_point.clone(),
+						eval: *eval,
+					};
+					self.collect_projected_committed(subclaim);
+				}
+			}
 			_ => {}
 		}
 	}
@@ -585,6 +652,10 @@ where
 			Shifted { shifted, .. } => shifted_sumcheck_meta(oracles, shifted, eval_point),
 
 			Packed { packed, .. } => packed_sumcheck_meta(oracles, packed, eval_point),
+
+			Univariate { univariate, .. } => {
+				univariate_sumcheck_meta(oracles, univariate, eval_point)
+			}
 			_ => unreachable!(),
 		}
 	}
@@ -623,6 +694,16 @@ where
 				&mut self.new_sumchecks_constraints,
 				projected,
 			)?,
+
+			Univariate { univariate, .. } => process_univariate_sumcheck(
+				&univariate,
+				meta,
+				&eval_point,
+				eval,
+				self.witness_index,
+				&mut self.new_sumchecks_constraints,
+				projected,
+			)?,
 			_ => unreachable!(),
 		};
 		Ok(())
@@ -3,10 +3,12 @@
 //! This module contains helpers to create bivariate sumcheck instances originating from:
 //!  * products with shift indicators (shifted virtual polynomials)
 //!  * products with tower basis (packed virtual polynomials)
+//!  * products with Lagrange basis (univariate virtual polynomials)
 //!
 //! All of them have common traits:
 //!  * they are always a product of two multilins (composition polynomial is `BivariateProduct`)
-//!  * one multilin (the multiplier) is transparent (`shift_ind`, `eq_ind`, or tower basis)
+//!  * one multilin (the multiplier) is transparent (`shift_ind`, `eq_ind`, tower basis, or
+//!    Lagrange basis)
 //!  * other multilin is a projection of one of the evalcheck claim multilins to its first variables
 
 use std::collections::HashSet;
@@ -20,15 +22,14 @@ use binius_hal::{ComputationBackend, ComputationBackendExt};
 use binius_math::{
 	ArithExpr, EvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearQuery,
 };
-use binius_utils::bail;
-use rayon::prelude::*;
+use binius_utils::{bail, rayon::prelude::*};
 
 use super::{error::Error, evalcheck::EvalcheckMultilinearClaim};
 use crate::{
 	fiat_shamir::CanSample,
 	oracle::{
 		ConstraintSet, ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
-		Packed, ProjectionVariant, Shifted,
+		Packed, ProjectionVariant, Shifted, Univariate,
 	},
 	polynomial::MultivariatePoly,
 	protocols::sumcheck::{
@@ -37,7 +38,9 @@ use crate::{
 		Error as SumcheckError,
 	},
 	transcript::CanWrite,
-	transparent::{shift_ind::ShiftIndPartialEval, tower_basis::TowerBasis},
+	transparent::{
+		lagrange_basis::LagrangeBasis, shift_ind::ShiftIndPartialEval, tower_basis::TowerBasis,
+	},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
@@ -182,6 +185,57 @@ where
 	Ok(())
 }
 
+/// Create oracles for the bivariate product of an inner oracle with the Lagrange basis.
+///
+/// Projects to first `log_domain_size()` vars.
+/// Returns metadata object with oracle identifiers.
+pub fn univariate_sumcheck_meta<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	univariate: &Univariate<F>,
+	eval_point: &[F],
+) -> Result<ProjectedBivariateMeta, Error> {
+	let log_domain_size = univariate.log_domain_size();
+
+	// NB. projected_n_vars = 1 because the first coordinate of eval_point is the univariate one,
+	// which replaces the log_domain_size lowest variables of the inner oracle
+	projected_bivariate_meta(oracles, univariate.inner().id(), 1, eval_point, |univariate_point| {
+		Ok(LagrangeBasis::new(log_domain_size, univariate_point[0])?)
+	})
+}
+
+/// Creates bivariate witness and adds them to the witness index, and add bivariate sumcheck constraint to the [`ConstraintSetBuilder`]
+#[allow(clippy::too_many_arguments)]
+pub fn process_univariate_sumcheck<U, F>(
+	univariate: &Univariate<F>,
+	meta: ProjectedBivariateMeta,
+	eval_point: &[F],
+	eval: F,
+	witness_index: &mut MultilinearExtensionIndex<U, F>,
+	constraint_builders: &mut Vec<ConstraintSetBuilder<F>>,
+	projected: MultilinearExtension<PackedType<U, F>>,
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	let log_domain_size = univariate.log_domain_size();
+
+	process_projected_bivariate_witness(
+		witness_index,
+		meta,
+		eval_point,
+		|univariate_point| {
+			let lagrange_basis = LagrangeBasis::new(log_domain_size, univariate_point[0])?;
+			let lagrange_basis_mle = lagrange_basis.multilinear_extension::<PackedType<U, F>>()?;
+			Ok(MLEDirectAdapter::from(lagrange_basis_mle).upcast_arc_dyn())
+		},
+		projected,
+	)?;
+
+	add_bivariate_sumcheck_to_constraints(meta, constraint_builders, log_domain_size, eval);
+	Ok(())
+}
+
 #[derive(Clone, Copy)]
 pub struct ProjectedBivariateMeta {
 	inner_id: OracleId,
@@ -377,7 +431,13 @@ impl<P: PackedField, Backend: ComputationBackend> MemoizedQueries<P, Backend> {
 		eval_points: Vec<&[P::Scalar]>,
 		backend: &Backend,
 	) -> Result<(), Error> {
-		let deduplicated_eval_points = eval_points.into_iter().collect::<HashSet<_>>();
+		// Deduplicate in order of first occurrence, so that the memo layout does not depend on
+		// hashing or scheduling.
+		let mut seen = HashSet::new();
+		let deduplicated_eval_points = eval_points
+			.into_iter()
+			.filter(|ep| seen.insert(*ep))
+			.collect::<Vec<_>>();
 
 		let new_queries = deduplicated_eval_points
 			.into_par_iter()
@@ -394,6 +454,22 @@ impl<P: PackedField, Backend: ComputationBackend> MemoizedQueries<P, Backend> {
 
 		Ok(())
 	}
+
+	/// Memoizes the queries for the given evaluation points one after another, in order of first
+	/// occurrence.
+	///
+	/// Each query is still computed with the backend's parallelism, but the order in which queries
+	/// are computed and allocated does not depend on the number of threads.
+	pub fn memoize_query_seq(
+		&mut self,
+		eval_points: Vec<&[P::Scalar]>,
+		backend: &Backend,
+	) -> Result<(), Error> {
+		for eval_point in eval_points {
+			self.full_query(eval_point, backend)?;
+		}
+		Ok(())
+	}
 }
 
 type SumcheckProofEvalcheckClaims<F> = Vec<EvalcheckMultilinearClaim<F>>;
@@ -6,23 +6,32 @@ use assert_matches::assert_matches;
 use binius_field::{
 	packed::{get_packed_slice, len_packed_slice, set_packed_slice},
 	underlier::WithUnderlier,
-	BinaryField128b, Field, PackedBinaryField128x1b, PackedBinaryField16x8b,
+	BinaryField128b, BinaryField8b, Field, PackedBinaryField128x1b, PackedBinaryField16x8b,
 	PackedBinaryField1x128b, PackedField, TowerField,
 };
 use binius_hal::{make_portable_backend, ComputationBackendExt};
-use binius_math::{extrapolate_line, MultilinearExtension, MultilinearPoly, MultilinearQuery};
+use binius_math::{
+	extrapolate_line, IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearPoly,
+	MultilinearQuery,
+};
 use bytemuck::cast_slice_mut;
-use itertools::Either;
+use itertools::{izip, Either};
 use rand::{rngs::StdRng, thread_rng, SeedableRng};
 
 use crate::{
+	fiat_shamir::HasherChallenger,
 	oracle::{MultilinearOracleSet, ShiftVariant},
 	polynomial::MultivariatePoly,
-	protocols::evalcheck::{
-		deserialize_evalcheck_proof, serialize_evalcheck_proof, EvalcheckMultilinearClaim,
-		EvalcheckProof, EvalcheckProver, EvalcheckVerifier,
+	protocols::{
+		evalcheck::{
+			deserialize_evalcheck_proof, serialize_evalcheck_proof, Error,
+			EvalcheckMultilinearClaim, EvalcheckProof, EvalcheckProver, EvalcheckVerifier,
+		},
+		greedy_evalcheck,
+		sumcheck::standard_switchover_heuristic,
 	},
-	transparent::select_row::SelectRow,
+	transcript::{AdviceWriter, CanWrite, TranscriptWriter},
+	transparent::{lagrange_basis::LagrangeBasis, select_row::SelectRow},
 	witness::MultilinearExtensionIndex,
 };
 
@@ -43,6 +52,10 @@ fn shift_one<P: PackedField>(evals: &mut [P], bits: usize, variant: ShiftVariant
 				let last = get_packed_slice(evals, range.end - 1);
 				(Either::Left(range), last)
 			}
+			ShiftVariant::CircularRight => {
+				let first = get_packed_slice(evals, range.start);
+				(Either::Right(range.rev()), first)
+			}
 		};
 
 		for i in range {
@@ -181,6 +194,94 @@ fn test_shifted_evaluation_subcube() {
 	assert_eq!(verifier_state.committed_eval_claims().len(), 1);
 }
 
+#[test]
+fn test_evalcheck_rotated() {
+	type P = PackedBinaryField16x8b;
+
+	let n_vars = 8;
+	let block_bits = 5;
+	let offset = 7;
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let poly_id = oracles.add_committed(n_vars, <P as PackedField>::Scalar::TOWER_LEVEL);
+	let rotated_id = oracles
+		.add_shifted(poly_id, offset, block_bits, ShiftVariant::CircularRight)
+		.unwrap();
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+
+	let poly_witness = MultilinearExtension::from_values(
+		repeat_with(|| P::random(&mut rng))
+			.take(1 << (n_vars - P::LOG_WIDTH))
+			.collect(),
+	)
+	.unwrap();
+
+	let mut rotated_evals = poly_witness.evals().to_vec();
+	for _ in 0..offset {
+		shift_one(&mut rotated_evals, block_bits, ShiftVariant::CircularRight);
+	}
+	let rotated_witness = MultilinearExtension::from_values(rotated_evals).unwrap();
+
+	let backend = make_portable_backend();
+	let query = backend
+		.multilinear_query::<FExtension>(&eval_point)
+		.unwrap();
+	let claim = EvalcheckMultilinearClaim {
+		poly: oracles.oracle(rotated_id),
+		eval_point: eval_point.into(),
+		eval: rotated_witness.evaluate(query.to_ref()).unwrap(),
+	};
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(vec![
+			(poly_id, poly_witness.to_ref().specialize_arc_dyn::<PExtension>()),
+			(rotated_id, rotated_witness.to_ref().specialize_arc_dyn::<PExtension>()),
+		])
+		.unwrap();
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let mut prover_oracles = oracles.clone();
+	let mut transcript =
+		TranscriptWriter::<HasherChallenger<groestl_crypto::Groestl256>>::default();
+	let mut advice = AdviceWriter::default();
+	let prover_committed_claims = greedy_evalcheck::prove::<_, _, BinaryField8b, _, _>(
+		&mut prover_oracles,
+		&mut witness_index,
+		[claim.clone()],
+		standard_switchover_heuristic(-2),
+		&mut transcript,
+		&mut advice,
+		domain_factory,
+		&backend,
+		false,
+	)
+	.unwrap();
+
+	// The rotation is reduced to a single claim on the committed inner oracle.
+	assert_eq!(prover_committed_claims.len(), 1);
+	let committed_claim = &prover_committed_claims[0];
+	assert_eq!(committed_claim.poly.id(), poly_id);
+	let committed_query = backend
+		.multilinear_query::<FExtension>(&committed_claim.eval_point)
+		.unwrap();
+	assert_eq!(poly_witness.evaluate(committed_query.to_ref()).unwrap(), committed_claim.eval);
+
+	let mut transcript = transcript.into_reader();
+	let mut advice = advice.into_reader();
+	let verifier_committed_claims =
+		greedy_evalcheck::verify(&mut oracles, [claim], &mut transcript, &mut advice).unwrap();
+	assert_eq!(verifier_committed_claims.len(), 1);
+	assert_eq!(verifier_committed_claims[0].eval, committed_claim.eval);
+
+	transcript.finalize().unwrap();
+	advice.finalize().unwrap();
+}
+
 #[test]
 fn test_evalcheck_linear_combination() {
 	let n_vars = 8;
@@ -463,6 +564,175 @@ fn test_evalcheck_zero_padded() {
 	verifier_state.verify(vec![claim], proof).unwrap();
 }
 
+#[test]
+fn test_evalcheck_concatenated() {
+	type P = PackedBinaryField16x8b;
+
+	let inner_n_vars = 6;
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let inner_ids = oracles.add_committed_multiple::<3>(inner_n_vars, BinaryField8b::TOWER_LEVEL);
+	let concatenated_id = oracles.add_concatenated(inner_n_vars, inner_ids).unwrap();
+	assert_eq!(oracles.n_vars(concatenated_id), inner_n_vars + 2);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let inner_witnesses = inner_ids.map(|_| {
+		MultilinearExtension::from_values(
+			repeat_with(|| P::random(&mut rng))
+				.take(1 << (inner_n_vars - P::LOG_WIDTH))
+				.collect(),
+		)
+		.unwrap()
+	});
+
+	// The fourth segment is implicitly zero.
+	let concatenated_witness = MultilinearExtension::from_values(
+		inner_witnesses
+			.iter()
+			.flat_map(|witness| witness.evals().iter().copied())
+			.chain(repeat_with(P::zero).take(1 << (inner_n_vars - P::LOG_WIDTH)))
+			.collect(),
+	)
+	.unwrap();
+
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(inner_n_vars + 2)
+		.collect::<Vec<_>>();
+
+	let backend = make_portable_backend();
+	let query = backend
+		.multilinear_query::<FExtension>(&eval_point)
+		.unwrap();
+	let eval = concatenated_witness.evaluate(query.to_ref()).unwrap();
+
+	let claim = EvalcheckMultilinearClaim {
+		poly: oracles.oracle(concatenated_id),
+		eval_point: eval_point.into(),
+		eval,
+	};
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(
+			izip!(inner_ids, &inner_witnesses)
+				.map(|(id, witness)| (id, witness.to_ref().specialize_arc_dyn::<PExtension>())),
+		)
+		.unwrap();
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+	let proof = prover_state.prove(vec![claim.clone()]).unwrap();
+	assert_matches!(&proof[0], EvalcheckProof::Concatenated { subproofs } if subproofs.len() == 3);
+	assert_eq!(prover_state.committed_eval_claims().len(), 3);
+
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state
+		.verify(vec![claim.clone()], proof.clone())
+		.unwrap();
+	assert_eq!(verifier_state.committed_eval_claims().len(), 3);
+
+	let wrong_claim = EvalcheckMultilinearClaim {
+		eval: claim.eval + FExtension::ONE,
+		..claim
+	};
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	assert!(verifier_state.verify(vec![wrong_claim], proof).is_err());
+}
+
+#[test]
+fn test_evalcheck_univariate() {
+	type P = PackedBinaryField16x8b;
+
+	let n_vars = 8;
+	let log_domain_size = 3;
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let (poly_id, univariate_id) = oracles
+		.add_committed_univariate(n_vars, log_domain_size, <P as PackedField>::Scalar::TOWER_LEVEL)
+		.unwrap();
+	assert_eq!(oracles.n_vars(univariate_id), n_vars - log_domain_size + 1);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars - log_domain_size + 1)
+		.collect::<Vec<_>>();
+
+	let poly_witness = MultilinearExtension::from_values(
+		repeat_with(|| P::random(&mut rng))
+			.take(1 << (n_vars - P::LOG_WIDTH))
+			.collect(),
+	)
+	.unwrap();
+
+	// Project the high variables, then combine each block with the Lagrange basis.
+	let backend = make_portable_backend();
+	let query = backend
+		.multilinear_query::<FExtension>(&eval_point[1..])
+		.unwrap();
+	let projected = poly_witness.evaluate_partial_high(query.to_ref()).unwrap();
+	let lagrange_basis = LagrangeBasis::new(log_domain_size, eval_point[0])
+		.unwrap()
+		.multilinear_extension::<FExtension>()
+		.unwrap();
+	let eval = (0..1 << log_domain_size)
+		.map(|i| {
+			projected.evaluate_on_hypercube(i).unwrap()
+				* lagrange_basis.evaluate_on_hypercube(i).unwrap()
+		})
+		.sum::<FExtension>();
+
+	let claim = EvalcheckMultilinearClaim {
+		poly: oracles.oracle(univariate_id),
+		eval_point: eval_point.into(),
+		eval,
+	};
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(vec![(
+			poly_id,
+			poly_witness.to_ref().specialize_arc_dyn::<PExtension>(),
+		)])
+		.unwrap();
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let mut prover_oracles = oracles.clone();
+	let mut transcript =
+		TranscriptWriter::<HasherChallenger<groestl_crypto::Groestl256>>::default();
+	let mut advice = AdviceWriter::default();
+	let prover_committed_claims = greedy_evalcheck::prove::<_, _, BinaryField8b, _, _>(
+		&mut prover_oracles,
+		&mut witness_index,
+		[claim.clone()],
+		standard_switchover_heuristic(-2),
+		&mut transcript,
+		&mut advice,
+		domain_factory,
+		&backend,
+		false,
+	)
+	.unwrap();
+
+	// The univariate claim is reduced to a single claim on the committed inner oracle.
+	assert_eq!(prover_committed_claims.len(), 1);
+	let committed_claim = &prover_committed_claims[0];
+	assert_eq!(committed_claim.poly.id(), poly_id);
+	let committed_query = backend
+		.multilinear_query::<FExtension>(&committed_claim.eval_point)
+		.unwrap();
+	assert_eq!(poly_witness.evaluate(committed_query.to_ref()).unwrap(), committed_claim.eval);
+
+	let mut transcript = transcript.into_reader();
+	let mut advice = advice.into_reader();
+	let verifier_committed_claims =
+		greedy_evalcheck::verify(&mut oracles, [claim], &mut transcript, &mut advice).unwrap();
+	assert_eq!(verifier_committed_claims.len(), 1);
+	assert_eq!(verifier_committed_claims[0].eval, committed_claim.eval);
+	assert_eq!(*verifier_committed_claims[0].eval_point, *committed_claim.eval_point);
+
+	transcript.finalize().unwrap();
+	advice.finalize().unwrap();
+}
+
 // Test evalcheck serialization
 #[test]
 fn test_evalcheck_serialization() {
@@ -514,3 +784,19 @@ fn test_evalcheck_serialization() {
 
 	transcript.finalize().unwrap()
 }
+
+#[test]
+fn test_evalcheck_deserialize_concatenated_count_exceeds_input() {
+	let mut transcript = crate::transcript::TranscriptWriter::<
+		crate::fiat_shamir::HasherChallenger<groestl_crypto::Groestl256>,
+	>::default();
+	// The tag of a concatenated proof, followed by a subproof count far beyond the input
+	transcript.write_bytes(&[9]);
+	transcript.write_bytes(&u64::MAX.to_le_bytes());
+	let mut transcript = transcript.into_reader();
+
+	assert_matches!(
+		deserialize_evalcheck_proof::<_, BinaryField128b>(&mut transcript),
+		Err(Error::EvalcheckSerializationError)
+	);
+}
@@ -3,7 +3,7 @@
 use std::mem;
 
 use binius_field::{util::inner_product_unchecked, TowerField};
-use binius_math::extrapolate_line_scalar;
+use binius_math::{eq_ind_partial_eval, extrapolate_line_scalar};
 use getset::{Getters, MutGetters};
 use tracing::instrument;
 
@@ -12,6 +12,7 @@ use super::{
 	evalcheck::{EvalcheckMultilinearClaim, EvalcheckProof},
 	subclaims::{
 		add_bivariate_sumcheck_to_constraints, packed_sumcheck_meta, shifted_sumcheck_meta,
+		univariate_sumcheck_meta,
 	},
 };
 use crate::oracle::{
@@ -188,6 +189,21 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 				)
 			}
 
+			MultilinearPolyOracle::Univariate { univariate, .. } => {
+				match evalcheck_proof {
+					EvalcheckProof::Univariate => {}
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+
+				let meta = univariate_sumcheck_meta(self.oracles, &univariate, &eval_point)?;
+				add_bivariate_sumcheck_to_constraints(
+					meta,
+					&mut self.new_sumcheck_constraints,
+					univariate.log_domain_size(),
+					eval,
+				)
+			}
+
 			MultilinearPolyOracle::LinearCombination {
 				id,
 				linear_combination,
@@ -261,6 +277,46 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 					subclaim_eval_point,
 				)?;
 			}
+			MultilinearPolyOracle::Concatenated {
+				id,
+				concatenated,
+				name,
+			} => {
+				let subproofs = match evalcheck_proof {
+					EvalcheckProof::Concatenated { subproofs } => subproofs,
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+
+				if subproofs.len() != concatenated.n_polys() {
+					return Err(VerificationError::SubproofMismatch.into());
+				}
+
+				// The high variables select the segment, missing trailing segments are zero
+				let (subclaim_eval_point, zs) = eval_point.split_at(concatenated.inner_n_vars());
+				let actual_eval = inner_product_unchecked::<F, F>(
+					subproofs.iter().map(|(eval, _)| *eval),
+					eq_ind_partial_eval::<F>(zs),
+				);
+
+				if actual_eval != eval {
+					return Err(VerificationError::IncorrectEvaluation(
+						name.unwrap_or(id.to_string()),
+					)
+					.into());
+				}
+
+				subproofs
+					.into_iter()
+					.zip(concatenated.polys())
+					.try_for_each(|((eval, subproof), suboracle)| {
+						self.verify_multilinear_subclaim(
+							eval,
+							subproof,
+							suboracle.clone(),
+							subclaim_eval_point,
+						)
+					})?;
+			}
 		}
 
 		Ok(())
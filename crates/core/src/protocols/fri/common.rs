@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::marker::PhantomData;
+use std::{iter, marker::PhantomData};
 
 use binius_field::{util::inner_product_unchecked, BinaryField, ExtensionField, PackedField};
 use binius_math::extrapolate_line_scalar;
@@ -240,13 +240,15 @@ where
 	}
 }
 
-/// This layer allows minimizing the proof size.
-pub fn vcs_optimal_layers_depths_iter<'a, F, FA, VCS>(
-	fri_params: &'a FRIParams<F, FA>,
-	vcs: &'a VCS,
-) -> impl Iterator<Item = usize> + 'a
+/// The depths of the Merkle trees committing the codeword and then every round oracle.
+///
+/// The leaves of every tree are the cosets of the oracle that are folded together in the next
+/// step, and the leaves of the last tree are the cosets of the terminate codeword that decode to
+/// the same value.
+pub fn vcs_tree_depths_iter<F, FA>(
+	fri_params: &FRIParams<F, FA>,
+) -> impl Iterator<Item = usize> + '_
 where
-	VCS: MerkleTreeScheme<F>,
 	F: BinaryField + ExtensionField<FA>,
 	FA: BinaryField,
 {
@@ -255,8 +257,24 @@ where
 		.iter()
 		.scan(fri_params.log_len(), |log_n_cosets, arity| {
 			*log_n_cosets -= arity;
-			Some(vcs.optimal_verify_layer(fri_params.n_test_queries(), *log_n_cosets))
+			Some(*log_n_cosets)
 		})
+		.chain(iter::once(fri_params.n_final_challenges()))
+}
+
+/// This layer allows minimizing the proof size.
+pub fn vcs_optimal_layers_depths_iter<'a, F, FA, VCS>(
+	fri_params: &'a FRIParams<F, FA>,
+	vcs: &'a VCS,
+) -> impl Iterator<Item = usize> + 'a
+where
+	VCS: MerkleTreeScheme<F>,
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	vcs_tree_depths_iter(fri_params)
+		.take(fri_params.fold_arities().len())
+		.map(|tree_depth| vcs.optimal_verify_layer(fri_params.n_test_queries(), tree_depth))
 }
 
 /// The type of the termination round codeword in the FRI protocol.
@@ -286,19 +304,58 @@ where
 }
 
 fn calculate_error_bound<F, PS>(code: &ReedSolomonCode<PS>, n_queries: usize) -> usize
+where
+	F: BinaryField + ExtensionField<PS::Scalar>,
+	PS: PackedField<Scalar: BinaryField>,
+{
+	let total_err = calculate_error_components::<F, _>(code, n_queries).total();
+	-total_err.log2() as usize
+}
+
+/// Upper bounds on the soundness error of the FRI-Binius opening protocol, broken down by source.
+///
+/// All values are probabilities. See [DP24], Section 4 for the derivation.
+///
+/// [DP24]: <https://eprint.iacr.org/2024/504>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FRIErrorBound {
+	/// Error of the sumcheck interleaved with the FRI folding rounds.
+	pub sumcheck: f64,
+	/// Error of the folding rounds, i.e. the probability that folding a far codeword lands close
+	/// to the code.
+	pub folding: f64,
+	/// Error of the query phase for the given number of test queries.
+	pub query: f64,
+}
+
+impl FRIErrorBound {
+	pub fn total(&self) -> f64 {
+		self.sumcheck + self.folding + self.query
+	}
+}
+
+/// Calculates the soundness error contributions of the FRI-Binius opening protocol for a code and
+/// number of test queries.
+pub fn calculate_error_components<F, PS>(
+	code: &ReedSolomonCode<PS>,
+	n_queries: usize,
+) -> FRIErrorBound
 where
 	F: BinaryField + ExtensionField<PS::Scalar>,
 	PS: PackedField<Scalar: BinaryField>,
 {
 	let field_size = 2.0_f64.powi(F::N_BITS as i32);
 	// ℓ' / |T_{τ}|
-	let sumcheck_err = code.log_dim() as f64 / field_size;
+	let sumcheck = code.log_dim() as f64 / field_size;
 	// 2^{ℓ' + R} / |T_{τ}|
-	let folding_err = code.len() as f64 / field_size;
+	let folding = code.len() as f64 / field_size;
 	let per_query_err = 0.5 * (1.0 + 2.0f64.powi(-(code.log_inv_rate() as i32)));
-	let query_err = per_query_err.powi(n_queries as i32);
-	let total_err = sumcheck_err + folding_err + query_err;
-	-total_err.log2() as usize
+	let query = per_query_err.powi(n_queries as i32);
+	FRIErrorBound {
+		sumcheck,
+		folding,
+		query,
+	}
 }
 
 /// Heuristic for estimating the optimal FRI folding arity that minimizes proof size.
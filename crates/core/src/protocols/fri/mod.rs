@@ -30,7 +30,11 @@ mod prove;
 mod tests;
 mod verify;
 
-pub use common::{calculate_n_test_queries, estimate_optimal_arity, FRIParams, TerminateCodeword};
+pub use common::{
+	calculate_error_components, calculate_n_test_queries, estimate_optimal_arity,
+	vcs_optimal_layers_depths_iter, vcs_tree_depths_iter, FRIErrorBound, FRIParams,
+	TerminateCodeword,
+};
 pub use error::*;
 pub use prove::*;
 pub use verify::*;
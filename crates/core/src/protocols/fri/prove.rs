@@ -2,10 +2,9 @@
 
 use binius_field::{BinaryField, ExtensionField, PackedExtension, PackedField, TowerField};
 use binius_hal::{make_portable_backend, ComputationBackend};
-use binius_utils::{bail, serialization::SerializeBytes};
+use binius_utils::{bail, rayon::prelude::*, serialization::SerializeBytes};
 use bytemuck::zeroed_vec;
 use itertools::izip;
-use rayon::prelude::*;
 use tracing::instrument;
 
 use super::{
@@ -167,12 +166,13 @@ where
 /// * `merkle_prover` - the merke tree prover to use for committing
 /// * `message` - the interleaved message to encode and commit
 #[instrument(skip_all, level = "debug")]
+#[allow(clippy::type_complexity)]
 pub fn commit_interleaved<F, FA, P, PA, MerkleProver, VCS>(
 	rs_code: &ReedSolomonCode<PA>,
 	params: &FRIParams<F, FA>,
 	merkle_prover: &MerkleProver,
 	message: &[P],
-) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+) -> Result<CommitOutput<P, Vec<VCS::Digest>, MerkleProver::Committed>, Error>
 where
 	F: BinaryField + ExtensionField<FA>,
 	FA: BinaryField,
@@ -202,12 +202,13 @@ where
 /// * `merkle_prover` - the Merkle tree prover to use for committing
 /// * `message_writer` - a closure that writes the interleaved message to encode and commit
 #[instrument(skip_all, level = "debug")]
+#[allow(clippy::type_complexity)]
 pub fn commit_interleaved_with<F, FA, P, PA, MerkleProver, VCS>(
 	rs_code: &ReedSolomonCode<PA>,
 	params: &FRIParams<F, FA>,
 	merkle_prover: &MerkleProver,
 	message_writer: impl FnOnce(&mut [P]),
-) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+) -> Result<CommitOutput<P, Vec<VCS::Digest>, MerkleProver::Committed>, Error>
 where
 	F: BinaryField + ExtensionField<FA>,
 	FA: BinaryField,
@@ -251,7 +252,7 @@ where
 	};
 
 	Ok(CommitOutput {
-		commitment: commitment.root,
+		commitment: commitment.cap,
 		committed: vcs_committed,
 		codeword: encoded,
 	})
@@ -336,7 +337,7 @@ where
 	pub fn execute_fold_round(
 		&mut self,
 		challenge: F,
-	) -> Result<FoldRoundOutput<VCS::Digest>, Error> {
+	) -> Result<FoldRoundOutput<Vec<VCS::Digest>>, Error> {
 		self.unprocessed_challenges.push(challenge);
 		self.curr_round += 1;
 
@@ -389,7 +390,7 @@ where
 			let arity = self.params.fold_arities().get(self.round_committed.len())?;
 			Some(next_commit_round + arity)
 		});
-		Ok(FoldRoundOutput::Commitment(commitment.root))
+		Ok(FoldRoundOutput::Commitment(commitment.cap))
 	}
 
 	/// Finalizes the FRI folding process.
@@ -13,9 +13,9 @@ use binius_hal::{make_portable_backend, ComputationBackendExt};
 use binius_hash::compress::Groestl256ByteCompression;
 use binius_math::MultilinearExtension;
 use binius_ntt::NTTOptions;
+use binius_utils::rayon::prelude::ParallelIterator;
 use groestl_crypto::Groestl256;
 use rand::prelude::*;
-use rayon::prelude::ParallelIterator;
 
 use super::to_par_scalar_big_chunks;
 use crate::{
@@ -89,7 +89,9 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		transcript: TranscriptWriter::<HasherChallenger<Groestl256>>::default(),
 		advice: AdviceWriter::default(),
 	};
-	prover_challenger.transcript.write(&codeword_commitment);
+	prover_challenger
+		.transcript
+		.write_slice(&codeword_commitment);
 	let mut round_commitments = Vec::with_capacity(params.n_oracles());
 	for _i in 0..params.n_fold_rounds() {
 		let challenge = prover_challenger.transcript.sample();
@@ -97,7 +99,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		match fold_round_output {
 			FoldRoundOutput::NoCommitment => {}
 			FoldRoundOutput::Commitment(round_commitment) => {
-				prover_challenger.transcript.write(&round_commitment);
+				prover_challenger.transcript.write_slice(&round_commitment);
 				round_commitments.push(round_commitment);
 			}
 		}
@@ -108,7 +110,10 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		.unwrap();
 	// Now run the verifier
 	let mut verifier_challenger = prover_challenger.into_verifier();
-	codeword_commitment = verifier_challenger.transcript.read().unwrap();
+	codeword_commitment = verifier_challenger
+		.transcript
+		.read_vec(codeword_commitment.len())
+		.unwrap();
 	let mut verifier_challenges = Vec::with_capacity(params.n_fold_rounds());
 
 	assert_eq!(round_commitments.len(), n_round_commitments);
@@ -118,8 +123,11 @@ fn test_commit_prove_verify_success<U, F, FA>(
 				.transcript
 				.sample_vec(params.fold_arities()[i]),
 		);
-		let mut _commitment = *commitment;
-		_commitment = verifier_challenger.transcript.read().unwrap();
+		let mut _commitment = commitment.clone();
+		_commitment = verifier_challenger
+			.transcript
+			.read_vec(commitment.len())
+			.unwrap();
 	}
 
 	verifier_challenges.append(
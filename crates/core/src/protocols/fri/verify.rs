@@ -13,7 +13,7 @@ use crate::{
 	fiat_shamir::CanSampleBits,
 	merkle_tree::MerkleTreeScheme,
 	protocols::fri::common::{fold_chunk, fold_interleaved_chunk, FRIParams},
-	transcript::CanRead,
+	transcript::{AdviceSection, CanRead},
 };
 
 /// A verifier for the FRI query phase.
@@ -29,10 +29,10 @@ where
 {
 	vcs: &'a VCS,
 	params: &'a FRIParams<F, FA>,
-	/// Received commitment to the codeword.
-	codeword_commitment: &'a VCS::Digest,
-	/// Received commitments to the round messages.
-	round_commitments: &'a [VCS::Digest],
+	/// Received Merkle cap of the codeword.
+	codeword_commitment: &'a [VCS::Digest],
+	/// Received Merkle caps of the round messages.
+	round_commitments: &'a [Vec<VCS::Digest>],
 	/// The challenges for each round.
 	interleave_tensor: Vec<F>,
 	/// The challenges for each round.
@@ -49,8 +49,8 @@ where
 	pub fn new(
 		params: &'a FRIParams<F, FA>,
 		vcs: &'a VCS,
-		codeword_commitment: &'a VCS::Digest,
-		round_commitments: &'a [VCS::Digest],
+		codeword_commitment: &'a [VCS::Digest],
+		round_commitments: &'a [Vec<VCS::Digest>],
 		challenges: &'a [F],
 	) -> Result<Self, Error> {
 		if round_commitments.len() != params.n_oracles() {
@@ -103,17 +103,20 @@ where
 		// Verify that the last oracle sent is a codeword.
 		let terminate_codeword_len =
 			1 << (self.params.n_final_challenges() + self.params.rs_code().log_inv_rate());
+		advice.begin_section(AdviceSection::FriTerminateCodeword);
 		let terminate_codeword = advice
 			.read_scalar_slice(terminate_codeword_len)
 			.map_err(Error::TranscriptError)?;
 		let final_value = self.verify_last_oracle(&terminate_codeword)?;
 
 		// Verify that the provided layers match the commitments.
+		advice.begin_section(AdviceSection::FriLayers);
 		let layers = vcs_optimal_layers_depths_iter(self.params, self.vcs)
 			.map(|layer_depth| advice.read_vec(1 << layer_depth))
 			.collect::<Result<Vec<_>, _>>()?;
 		for (commitment, layer_depth, layer) in izip!(
-			iter::once(self.codeword_commitment).chain(self.round_commitments),
+			iter::once(self.codeword_commitment)
+				.chain(self.round_commitments.iter().map(Vec::as_slice)),
 			vcs_optimal_layers_depths_iter(self.params, self.vcs),
 			&layers
 		) {
@@ -127,7 +130,8 @@ where
 			.take(self.params.n_test_queries());
 
 		let mut scratch_buffer = self.create_scratch_buffer();
-		for index in indexes_iter {
+		for (query, index) in indexes_iter.enumerate() {
+			advice.begin_section(AdviceSection::FriQuery(query));
 			self.verify_query_internal(
 				index,
 				&terminate_codeword,
@@ -148,7 +152,7 @@ where
 			.verify_vector(
 				self.round_commitments
 					.last()
-					.unwrap_or(self.codeword_commitment),
+					.map_or(self.codeword_commitment, Vec::as_slice),
 				terminate_codeword,
 				1 << self.params.rs_code().log_inv_rate(),
 			)
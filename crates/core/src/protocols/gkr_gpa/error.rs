@@ -46,4 +46,6 @@ pub enum Error {
 	HalError(#[from] binius_hal::Error),
 	#[error("Math error: {0}")]
 	MathError(#[from] binius_math::Error),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] crate::transcript::Error),
 }
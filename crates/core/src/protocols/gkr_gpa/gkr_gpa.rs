@@ -3,9 +3,8 @@
 use std::slice;
 
 use binius_field::{packed::get_packed_slice, Field, PackedField};
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use bytemuck::zeroed_vec;
-use rayon::prelude::*;
 use tracing::{debug_span, instrument};
 
 use super::{packed_field_storage::PackedFieldStorage, Error};
@@ -7,11 +7,10 @@ use binius_field::{
 };
 use binius_hal::{ComputationBackend, SumcheckEvaluator};
 use binius_math::{
-	CompositionPolyOS, EvaluationDomainFactory, InterpolationDomain, MultilinearPoly,
+	ArithExpr, CompositionPolyOS, EvaluationDomainFactory, InterpolationDomain, MultilinearPoly,
 };
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use itertools::izip;
-use rayon::prelude::*;
 use stackalloc::stackalloc_with_default;
 use tracing::{debug_span, instrument};
 
@@ -171,14 +170,6 @@ where
 		self.eq_ind_eval *= eq(alpha, challenge);
 	}
 
-	#[instrument(skip_all, name = "GPAProver::fold_partial_eq_ind", level = "trace")]
-	fn fold_partial_eq_ind(&mut self) {
-		common::fold_partial_eq_ind::<P, Backend>(
-			self.n_rounds_remaining(),
-			&mut self.partial_eq_ind_evals,
-		);
-	}
-
 	fn round(&self) -> usize {
 		self.n_vars - self.n_rounds_remaining()
 	}
@@ -251,7 +242,7 @@ where
 	fn fold(&mut self, challenge: F) -> Result<(), SumcheckError> {
 		self.update_eq_ind_eval(challenge);
 		let n_rounds_remaining = self.n_rounds_remaining();
-		rayon::join(
+		binius_utils::rayon::join(
 			|| self.state.fold(challenge),
 			|| {
 				common::fold_partial_eq_ind::<P, Backend>(
@@ -319,15 +310,27 @@ where
 				.batch_evaluate(batch_query, evals)
 				.expect("correct by query construction invariant");
 
-			let subcube_start = subcube_index << subcube_vars.saturating_sub(P::LOG_WIDTH);
-			for (i, eval) in evals.iter_mut().enumerate() {
-				*eval *= self.partial_eq_ind_evals[subcube_start + i];
-			}
-
-			evals.iter().copied().sum::<P>()
+			self.process_subcube_composition_evals(subcube_vars, subcube_index, batch_query, evals)
 		})
 	}
 
+	fn process_subcube_composition_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		_batch_query: &[&[P]],
+		composition_evals: &[P],
+	) -> P {
+		let subcube_start = subcube_index << subcube_vars.saturating_sub(P::LOG_WIDTH);
+		izip!(composition_evals, &self.partial_eq_ind_evals[subcube_start..])
+			.map(|(&eval, &eq_ind)| eval * eq_ind)
+			.sum::<P>()
+	}
+
+	fn base_expression(&self) -> ArithExpr<F> {
+		self.composition.expression()
+	}
+
 	fn composition(&self) -> &Composition {
 		self.composition
 	}
@@ -106,6 +106,12 @@ pub fn verify_sumcheck_outputs<F: Field>(
 		.expect("checked above that multilinear_evals length is at least 1");
 
 	if eq_ind_eval != multilinear_evals_last {
+		#[cfg(feature = "diagnostics")]
+		crate::diagnostics::record_mismatch(
+			"grand product eq indicator evaluation",
+			multilinear_evals_last,
+			eq_ind_eval,
+		);
 		return Err(VerificationError::IncorrectEqIndEvaluation.into());
 	}
 
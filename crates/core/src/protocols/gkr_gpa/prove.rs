@@ -78,6 +78,8 @@ where
 	let mut reverse_sorted_final_layer_claims = Vec::with_capacity(n_claims);
 
 	for layer_no in 0..max_n_vars {
+		transcript.check_cancelled()?;
+
 		// Step 1: Process finished provers
 		process_finished_provers(
 			layer_no,
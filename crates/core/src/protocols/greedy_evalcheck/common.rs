@@ -0,0 +1,83 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_field::Field;
+use binius_utils::bail;
+
+use super::error::Error;
+use crate::protocols::evalcheck::{EvalPointOracleIdMap, EvalcheckMultilinearClaim};
+
+/// Drops every claim on a committed polynomial whose oracle, point and evaluation equal those of an
+/// earlier claim, keeping all other claims in order, and fails if two claims on the same oracle
+/// and point disagree on the evaluation.
+///
+/// Different virtual polynomials often reduce to the same committed polynomial at the same point,
+/// most commonly after a batched sumcheck. Each claim left after evalcheck costs a row-batched
+/// evaluation, a transparent polynomial and a PIOP sumcheck claim in the opening proof.
+///
+/// Claims on different polynomials at the same point are all kept. The ring-switching reduction
+/// already sends one partial evaluation per shared point prefix for all of them, see
+/// [`crate::ring_switch`], but every claim needs its own mixing coefficient there, and with it its
+/// own transparent polynomial. The reduction does open claims on the same polynomial at points
+/// that share a prefix jointly.
+pub fn dedup_committed_claims<F: Field>(
+	claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+) -> Result<Vec<EvalcheckMultilinearClaim<F>>, Error> {
+	let mut seen = EvalPointOracleIdMap::<F, F>::new();
+	let mut deduped = Vec::new();
+	for claim in claims {
+		let id = claim.poly.id();
+		match seen.get(id, &claim.eval_point) {
+			Some(&eval) if eval == claim.eval => {}
+			Some(_) => bail!(Error::ConflictingCommittedEvalClaims { id }),
+			None => {
+				seen.insert(id, claim.eval_point.clone(), claim.eval);
+				deduped.push(claim);
+			}
+		}
+	}
+	Ok(deduped)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+
+	use super::*;
+	use crate::oracle::MultilinearOracleSet;
+
+	type F = BinaryField128b;
+
+	#[test]
+	fn test_dedup_committed_claims() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(3, 0);
+		let b = oracles.add_committed(3, 0);
+
+		let point = vec![F::new(1), F::new(2), F::new(3)];
+		let other_point = vec![F::new(4), F::new(5), F::new(6)];
+		let claim = |id, point: &[F], eval| EvalcheckMultilinearClaim {
+			poly: oracles.oracle(id),
+			eval_point: point.into(),
+			eval: F::new(eval),
+		};
+
+		let deduped = dedup_committed_claims([
+			claim(a, &point, 7),
+			claim(b, &point, 8),
+			claim(a, &point, 7),
+			claim(a, &other_point, 9),
+			claim(b, &point, 8),
+		])
+		.unwrap();
+		let summary = deduped
+			.iter()
+			.map(|claim| (claim.poly.id(), claim.eval))
+			.collect::<Vec<_>>();
+		assert_eq!(summary, [(a, F::new(7)), (b, F::new(8)), (a, F::new(9))]);
+
+		assert!(matches!(
+			dedup_committed_claims([claim(a, &point, 7), claim(a, &point, 1)]),
+			Err(Error::ConflictingCommittedEvalClaims { id }) if id == a
+		));
+	}
+}
@@ -1,6 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use crate::protocols::{evalcheck, sumcheck};
+use crate::{
+	oracle::OracleId,
+	protocols::{evalcheck, sumcheck},
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -16,6 +19,8 @@ pub enum Error {
 	ExtraBatchOpeningProof,
 	#[error("proof is missing a batch opening proof")]
 	MissingBatchOpeningProof,
+	#[error("conflicting evaluation claims on committed oracle {id} at the same point")]
+	ConflictingCommittedEvalClaims { id: OracleId },
 	#[error("evalcheck error: {0}")]
 	Evalcheck(#[from] evalcheck::Error),
 	#[error("sumcheck error: {0}")]
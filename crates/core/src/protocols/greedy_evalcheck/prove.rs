@@ -9,7 +9,7 @@ use binius_hal::ComputationBackend;
 use binius_math::EvaluationDomainFactory;
 use tracing::instrument;
 
-use super::error::Error;
+use super::{common::dedup_committed_claims, error::Error};
 use crate::{
 	fiat_shamir::CanSample,
 	oracle::MultilinearOracleSet,
@@ -17,7 +17,7 @@ use crate::{
 		serialize_evalcheck_proof, subclaims::prove_bivariate_sumchecks_with_switchover,
 		EvalcheckMultilinearClaim, EvalcheckProver,
 	},
-	transcript::{write_u64, AdviceWriter, CanWrite},
+	transcript::{AdviceWriter, CanWrite},
 	witness::MultilinearExtensionIndex,
 };
 
@@ -32,6 +32,7 @@ pub fn prove<U, F, DomainField, Transcript, Backend>(
 	advice: &mut AdviceWriter,
 	domain_factory: impl EvaluationDomainFactory<DomainField>,
 	backend: &Backend,
+	deterministic: bool,
 ) -> Result<Vec<EvalcheckMultilinearClaim<F>>, Error>
 where
 	U: UnderlierType + PackScalar<F> + PackScalar<DomainField>,
@@ -42,13 +43,14 @@ where
 	Backend: ComputationBackend,
 {
 	let mut evalcheck_prover =
-		EvalcheckProver::<U, F, Backend>::new(oracles, witness_index, backend);
+		EvalcheckProver::<U, F, Backend>::new(oracles, witness_index, backend)
+			.with_deterministic(deterministic);
 
 	let claims: Vec<_> = claims.into_iter().collect();
 
 	// Prove the initial evalcheck claims
 	let evalcheck_proofs = evalcheck_prover.prove(claims)?;
-	write_u64(advice, evalcheck_proofs.len() as u64);
+	advice.write_count(evalcheck_proofs.len());
 	for evalcheck_proof in evalcheck_proofs.iter() {
 		serialize_evalcheck_proof(transcript, evalcheck_proof)
 	}
@@ -79,11 +81,8 @@ where
 		}
 		virtual_opening_proofs_len += 1;
 	}
-	write_u64(advice, virtual_opening_proofs_len);
+	advice.write_count(virtual_opening_proofs_len);
 
-	let committed_claims = evalcheck_prover
-		.committed_eval_claims_mut()
-		.drain(..)
-		.collect::<Vec<_>>();
-	Ok(committed_claims)
+	let committed_claims = evalcheck_prover.committed_eval_claims_mut().drain(..);
+	dedup_committed_claims(committed_claims)
 }
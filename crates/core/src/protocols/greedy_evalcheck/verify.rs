@@ -3,7 +3,7 @@
 use binius_field::TowerField;
 use binius_utils::bail;
 
-use super::error::Error;
+use super::{common::dedup_committed_claims, error::Error};
 use crate::{
 	fiat_shamir::CanSample,
 	oracle::MultilinearOracleSet,
@@ -11,7 +11,7 @@ use crate::{
 		evalcheck::{deserialize_evalcheck_proof, EvalcheckMultilinearClaim, EvalcheckVerifier},
 		sumcheck::{self, batch_verify, constraint_set_sumcheck_claims, SumcheckClaimsWithMeta},
 	},
-	transcript::{read_u64, AdviceReader, CanRead},
+	transcript::{AdviceReader, AdviceSection, CanRead},
 };
 
 pub fn verify<F, Transcript>(
@@ -29,7 +29,7 @@ where
 	// Verify the initial evalcheck claims
 	let claims = claims.into_iter().collect::<Vec<_>>();
 
-	let len_initial_evalcheck_proofs = read_u64(advice)? as usize;
+	let len_initial_evalcheck_proofs = advice.read_count(AdviceSection::EvalcheckProofCount)?;
 	let mut initial_evalcheck_proofs = Vec::with_capacity(len_initial_evalcheck_proofs);
 	for _ in 0..len_initial_evalcheck_proofs {
 		let eval_check_proof = deserialize_evalcheck_proof(transcript)?;
@@ -38,7 +38,7 @@ where
 
 	evalcheck_verifier.verify(claims, initial_evalcheck_proofs)?;
 
-	let len_virtual_opening_proofs = read_u64(advice)? as usize;
+	let len_virtual_opening_proofs = advice.read_count(AdviceSection::VirtualOpeningRoundCount)?;
 	for _ in 0..len_virtual_opening_proofs {
 		let SumcheckClaimsWithMeta { claims, metas } = constraint_set_sumcheck_claims(
 			evalcheck_verifier.take_new_sumcheck_constraints().unwrap(),
@@ -68,9 +68,6 @@ where
 		bail!(Error::MissingVirtualOpeningProof);
 	}
 
-	let committed_claims = evalcheck_verifier
-		.committed_eval_claims_mut()
-		.drain(..)
-		.collect::<Vec<_>>();
-	Ok(committed_claims)
+	let committed_claims = evalcheck_verifier.committed_eval_claims_mut().drain(..);
+	dedup_committed_claims(committed_claims)
 }
@@ -4,7 +4,7 @@ use std::ops::{Add, AddAssign, Mul, MulAssign};
 
 use binius_field::{
 	util::{inner_product_unchecked, powers},
-	ExtensionField, Field, PackedField,
+	ExtensionField, Field, PackedField, TowerField,
 };
 use binius_math::{CompositionPolyOS, MultilinearPoly};
 use binius_utils::bail;
@@ -12,6 +12,7 @@ use getset::{CopyGetters, Getters};
 use tracing::instrument;
 
 use super::error::Error;
+use crate::transcript::DerivedMessage;
 
 /// A claim about the sum of the values of a multilinear composite polynomial over the boolean
 /// hypercube.
@@ -110,6 +111,21 @@ impl<F: Field> RoundCoeffs<F> {
 	}
 }
 
+/// Round polynomials are written without their highest-degree coefficient, which the reader
+/// recovers from the claimed sum, see [`RoundProof::recover`].
+impl<F: TowerField> DerivedMessage for RoundCoeffs<F> {
+	type Scalar = F;
+	type Context = F;
+
+	fn sent_values(&self) -> &[F] {
+		&self.0[..self.0.len().saturating_sub(1)]
+	}
+
+	fn derive(sent_values: Vec<F>, sum: F) -> Self {
+		RoundProof(RoundCoeffs(sent_values)).recover(sum)
+	}
+}
+
 impl<F: Field> Add<&Self> for RoundCoeffs<F> {
 	type Output = RoundCoeffs<F>;
 
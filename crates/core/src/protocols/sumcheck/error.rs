@@ -25,6 +25,10 @@ pub enum Error {
 	ExpectedFinish,
 	#[error("expected call to execute")]
 	ExpectedExecution,
+	#[error("expected a round message before the round challenge")]
+	ExpectedRoundMessage,
+	#[error("expected a challenge, not a round message")]
+	UnexpectedRoundMessage,
 	#[error("expected call to fold")]
 	ExpectedFold,
 	#[error("the number of variables for the prover multilinears must all be equal")]
@@ -101,6 +105,8 @@ pub enum Error {
 pub enum VerificationError {
 	#[error("number of coefficients in round {round} proof is incorrect, expected {expected}")]
 	NumberOfCoefficients { round: usize, expected: usize },
+	#[error("the round {round} polynomial does not sum to the claimed sum")]
+	RoundSumMismatch { round: usize },
 	#[error("incorrect number of rounds")]
 	NumberOfRounds,
 	#[error("the number of final evaluations must match the number of instances")]
@@ -10,7 +10,7 @@ use super::{
 	common::batch_weighted_value,
 	error::{Error, VerificationError},
 	verify::compute_expected_batch_composite_evaluation_single_claim,
-	RoundCoeffs, RoundProof,
+	RoundCoeffs,
 };
 use crate::{fiat_shamir::CanSample, protocols::sumcheck::SumcheckClaim, transcript::CanRead};
 
@@ -202,9 +202,8 @@ where
 		match self.last_coeffs_or_sum {
 			CoeffsOrSums::Coeffs(_) => Err(Error::ExpectedFinishRound),
 			CoeffsOrSums::Sum(sum) => {
-				let proof_vals = transcript.read_scalar_slice(degree)?;
-				let round_proof = RoundProof(RoundCoeffs(proof_vals));
-				self.last_coeffs_or_sum = CoeffsOrSums::Coeffs(round_proof.recover(sum));
+				let round_coeffs = transcript.read_derived(degree, sum)?;
+				self.last_coeffs_or_sum = CoeffsOrSums::Coeffs(round_coeffs);
 				Ok(())
 			}
 		}
@@ -120,7 +120,7 @@ pub struct BatchProveStart<F: Field, Prover> {
 #[instrument(skip_all, name = "sumcheck::batch_prove")]
 pub fn batch_prove_with_start<F, Prover, Transcript>(
 	start: BatchProveStart<F, Prover>,
-	mut provers: Vec<Prover>,
+	provers: Vec<Prover>,
 	mut transcript: Transcript,
 ) -> Result<BatchSumcheckOutput<F>, Error>
 where
@@ -128,93 +128,166 @@ where
 	Prover: SumcheckProver<F>,
 	Transcript: CanSample<F> + CanWrite,
 {
-	let BatchProveStart {
-		mut batch_coeffs,
-		reduction_provers,
-	} = start;
-
-	provers.splice(0..0, reduction_provers);
-
-	if provers.is_empty() {
-		return Ok(BatchSumcheckOutput {
-			challenges: Vec::new(),
-			multilinear_evals: Vec::new(),
-		});
+	let mut prover = BatchProver::new(start, provers)?;
+	while !prover.is_finished() {
+		transcript.check_cancelled()?;
+		if let Some(round_coeffs) = prover.next_message()? {
+			transcript.write_derived(&round_coeffs);
+		}
+		prover.process_challenge(transcript.sample())?;
 	}
 
-	// Check that the provers are in descending order by n_vars
-	if !is_sorted_ascending(provers.iter().map(|prover| prover.n_vars()).rev()) {
-		bail!(Error::ClaimsOutOfOrder);
+	let output = prover.finish()?;
+	for multilinear_evals in output.multilinear_evals.iter() {
+		transcript.write_scalar_slice(multilinear_evals);
 	}
 
-	if batch_coeffs.len() > provers.len() {
-		bail!(Error::TooManyPrebatchedCoeffs);
-	}
+	Ok(output)
+}
+
+/// A batched sumcheck prover driven by externally supplied challenges.
+///
+/// This is the state machine behind [`batch_prove`], for running the protocol interactively or
+/// over a custom transcript. The challenges are the batching coefficients, which are sampled
+/// just-in-time when the provers over a given number of variables join the batch, and the round
+/// challenges, each of which follows a round message. The caller must
+///
+/// 1. construct a new prover with [`BatchProver::new`]
+/// 2. call [`BatchProver::next_message`] and send the round message to the verifier, if there is
+///    one
+/// 3. call [`BatchProver::process_challenge`] with the next challenge
+/// 4. repeat from step 2 until [`BatchProver::is_finished`], then call [`BatchProver::finish`] and
+///    send the multilinear evaluations to the verifier
+#[derive(Debug)]
+pub struct BatchProver<F: Field, Prover> {
+	provers: Vec<Prover>,
+	batch_coeffs: Vec<F>,
+	/// The number of provers that have joined the batch, which are the first ones.
+	active_index: usize,
+	n_rounds: usize,
+	challenges: Vec<F>,
+	/// Whether the round message of the current round has been computed.
+	executed: bool,
+}
+
+impl<F, Prover> BatchProver<F, Prover>
+where
+	F: Field,
+	Prover: SumcheckProver<F>,
+{
+	pub fn new(start: BatchProveStart<F, Prover>, mut provers: Vec<Prover>) -> Result<Self, Error> {
+		let BatchProveStart {
+			batch_coeffs,
+			reduction_provers,
+		} = start;
+
+		provers.splice(0..0, reduction_provers);
+
+		if !provers.is_empty() {
+			// Check that the provers are in descending order by n_vars
+			if !is_sorted_ascending(provers.iter().map(|prover| prover.n_vars()).rev()) {
+				bail!(Error::ClaimsOutOfOrder);
+			}
 
-	let n_rounds = provers
-		.iter()
-		.map(|prover| prover.n_vars())
-		.max()
-		.unwrap_or(0);
-
-	// active_index is an index into the provers slice.
-	let mut active_index = batch_coeffs.len();
-	let mut challenges = Vec::with_capacity(n_rounds);
-	for round_no in 0..n_rounds {
-		let n_vars = n_rounds - round_no;
-
-		// Activate new provers
-		while let Some(prover) = provers.get(active_index) {
-			if prover.n_vars() != n_vars {
-				break;
+			if batch_coeffs.len() > provers.len() {
+				bail!(Error::TooManyPrebatchedCoeffs);
 			}
+		}
+
+		let n_rounds = provers
+			.iter()
+			.map(|prover| prover.n_vars())
+			.max()
+			.unwrap_or(0);
+
+		Ok(Self {
+			provers,
+			active_index: batch_coeffs.len(),
+			batch_coeffs,
+			n_rounds,
+			challenges: Vec::with_capacity(n_rounds),
+			executed: false,
+		})
+	}
 
-			let next_batch_coeff = transcript.sample();
-			batch_coeffs.push(next_batch_coeff);
-			active_index += 1;
+	/// Whether the next challenge is the batching coefficient of a prover joining the batch.
+	fn expects_batch_coeff(&self) -> bool {
+		let round = self.challenges.len();
+		self.provers.get(self.active_index).is_some_and(|prover| {
+			// Provers for 0-variate (ie. constant) polynomials join after the last round
+			round == self.n_rounds || prover.n_vars() == self.n_rounds - round
+		})
+	}
+
+	/// Whether all challenges have been processed.
+	pub fn is_finished(&self) -> bool {
+		!self.expects_batch_coeff() && self.challenges.len() == self.n_rounds
+	}
+
+	/// Computes the round message that precedes the next challenge.
+	///
+	/// Returns `None` if the next challenge is a batching coefficient, which is not preceded by a
+	/// message.
+	pub fn next_message(&mut self) -> Result<Option<RoundCoeffs<F>>, Error> {
+		if self.expects_batch_coeff() {
+			return Ok(None);
+		}
+		if self.challenges.len() == self.n_rounds {
+			bail!(Error::ExpectedFinish);
+		}
+		if self.executed {
+			bail!(Error::ExpectedFold);
 		}
 
-		// Process the active provers
 		let mut round_coeffs = RoundCoeffs::default();
 		for (&batch_coeff, prover) in
-			iter::zip(batch_coeffs.iter(), provers[..active_index].iter_mut())
+			iter::zip(self.batch_coeffs.iter(), self.provers[..self.active_index].iter_mut())
 		{
 			let prover_coeffs = prover.execute(batch_coeff)?;
 			round_coeffs += &(prover_coeffs * batch_coeff);
 		}
+		self.executed = true;
+		Ok(Some(round_coeffs))
+	}
 
-		let round_proof = round_coeffs.truncate();
-		transcript.write_scalar_slice(round_proof.coeffs());
-
-		let challenge = transcript.sample();
-		challenges.push(challenge);
+	/// Processes the next challenge, which is either a batching coefficient or a round challenge.
+	pub fn process_challenge(&mut self, challenge: F) -> Result<(), Error> {
+		if self.expects_batch_coeff() {
+			self.batch_coeffs.push(challenge);
+			self.active_index += 1;
+			return Ok(());
+		}
+		if self.challenges.len() == self.n_rounds {
+			bail!(Error::IncorrectNumberOfChallenges);
+		}
+		if !self.executed {
+			bail!(Error::ExpectedExecution);
+		}
 
-		for prover in provers[..active_index].iter_mut() {
+		for prover in self.provers[..self.active_index].iter_mut() {
 			prover.fold(challenge)?;
 		}
+		self.challenges.push(challenge);
+		self.executed = false;
+		Ok(())
 	}
 
-	// sample next_batch_coeffs for 0-variate (ie. constant) provers to match with verify
-	while let Some(prover) = provers.get(active_index) {
-		debug_assert_eq!(prover.n_vars(), 0);
-
-		let _next_batch_coeff = transcript.sample();
-		active_index += 1;
-	}
+	/// Finishes the protocol, returning the round challenges and the evaluations of all
+	/// multilinears at the challenge point.
+	pub fn finish(self) -> Result<BatchSumcheckOutput<F>, Error> {
+		if !self.is_finished() {
+			bail!(Error::IncorrectNumberOfChallenges);
+		}
 
-	let multilinear_evals = provers
-		.into_iter()
-		.map(|prover| Box::new(prover).finish())
-		.collect::<Result<Vec<_>, _>>()?;
+		let multilinear_evals = self
+			.provers
+			.into_iter()
+			.map(|prover| Box::new(prover).finish())
+			.collect::<Result<Vec<_>, _>>()?;
 
-	for multilinear_evals in multilinear_evals.iter() {
-		transcript.write_scalar_slice(multilinear_evals);
+		Ok(BatchSumcheckOutput {
+			challenges: self.challenges,
+			multilinear_evals,
+		})
 	}
-
-	let output = BatchSumcheckOutput {
-		challenges,
-		multilinear_evals,
-	};
-
-	Ok(output)
 }
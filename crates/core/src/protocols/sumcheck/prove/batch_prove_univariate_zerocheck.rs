@@ -149,7 +149,7 @@ where
 		bail!(Error::IncorrectZerosPrefixLen);
 	}
 
-	transcript.write_scalar_slice(&round_evals.evals);
+	transcript.write_derived(&round_evals);
 	let univariate_challenge = transcript.sample();
 
 	let mut reduction_provers = Vec::with_capacity(provers.len());
@@ -89,8 +89,7 @@ where
 			round_coeffs += &(prover_coeffs * *batch_coeff);
 		}
 
-		let round_proof = round_coeffs.truncate();
-		transcript.write_scalar_slice(round_proof.coeffs());
+		transcript.write_derived(&round_coeffs);
 		Ok(())
 	}
 
@@ -11,7 +11,9 @@ pub mod regular_sumcheck;
 pub mod univariate;
 pub mod zerocheck;
 
-pub use batch_prove::{batch_prove, batch_prove_with_start, SumcheckProver};
+pub use batch_prove::{
+	batch_prove, batch_prove_with_start, BatchProveStart, BatchProver, SumcheckProver,
+};
 pub use batch_prove_univariate_zerocheck::{
 	batch_prove_zerocheck_univariate_round, UnivariateZerocheckProver,
 };
@@ -19,7 +21,7 @@ pub use concrete_prover::ConcreteProver;
 pub use oracles::{
 	constraint_set_sumcheck_prover, constraint_set_zerocheck_prover, split_constraint_set,
 };
-pub use prover_state::{ProverState, SumcheckInterpolator};
+pub use prover_state::{FoldStats, ProverState, SumcheckInterpolator};
 pub use regular_sumcheck::RegularSumcheckProver;
 pub use univariate::{reduce_to_skipped_projection, univariatizing_reduction_prover};
 pub use zerocheck::{UnivariateZerocheck, ZerocheckProver};
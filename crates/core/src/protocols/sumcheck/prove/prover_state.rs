@@ -2,20 +2,21 @@
 
 use std::{
 	iter,
-	sync::atomic::{AtomicBool, Ordering},
+	sync::atomic::{AtomicBool, AtomicUsize, Ordering},
 };
 
 use binius_field::{
 	util::powers, ExtensionField, Field, PackedExtension, PackedField, RepackedExtension,
 };
-use binius_hal::{ComputationBackend, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+use binius_hal::{
+	ComputationBackend, RoundEvalStrategy, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
+};
 use binius_math::{
 	evaluate_univariate, CompositionPolyOS, MLEDirectAdapter, MultilinearPoly, MultilinearQuery,
 };
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use getset::CopyGetters;
 use itertools::izip;
-use rayon::prelude::*;
 use tracing::instrument;
 
 use crate::{
@@ -39,6 +40,19 @@ pub trait SumcheckInterpolator<F: Field> {
 	) -> Result<Vec<F>, PolynomialError>;
 }
 
+/// Statistics on the multilinears a [`ProverState`] detected to be constant while folding.
+///
+/// Multilinears over zero-padded or repeating oracles often become constant after a few rounds.
+/// Such multilinears switch to a [`SumcheckMultilinear::Constant`] representation, which is not
+/// folded in the remaining rounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FoldStats {
+	/// The number of multilinears switched to a constant representation.
+	pub constant_switches: usize,
+	/// The number of multilinear folds skipped because the multilinear was constant.
+	pub skipped_folds: usize,
+}
+
 #[derive(Debug)]
 enum ProverStateCoeffsOrSums<F: Field> {
 	Coeffs(Vec<RoundCoeffs<F>>),
@@ -66,6 +80,12 @@ where
 	evaluation_points: Vec<FDomain>,
 	tensor_query: Option<MultilinearQuery<P>>,
 	last_coeffs_or_sums: ProverStateCoeffsOrSums<P::Scalar>,
+	/// The strategy used to compute round evaluations, [`RoundEvalStrategy::Gruen`] by default.
+	#[getset(get_copy = "pub")]
+	round_eval_strategy: RoundEvalStrategy,
+	/// Statistics on the multilinears detected to be constant so far.
+	#[getset(get_copy = "pub")]
+	fold_stats: FoldStats,
 	backend: &'a Backend,
 }
 
@@ -128,10 +148,16 @@ where
 			evaluation_points,
 			tensor_query: Some(tensor_query),
 			last_coeffs_or_sums: ProverStateCoeffsOrSums::Sums(claimed_sums),
+			round_eval_strategy: RoundEvalStrategy::default(),
+			fold_stats: FoldStats::default(),
 			backend,
 		})
 	}
 
+	pub fn set_round_eval_strategy(&mut self, strategy: RoundEvalStrategy) {
+		self.round_eval_strategy = strategy;
+	}
+
 	#[instrument(skip_all, name = "ProverState::fold", level = "debug")]
 	pub fn fold(&mut self, challenge: F) -> Result<(), Error> {
 		if self.n_vars == 0 {
@@ -161,6 +187,8 @@ where
 		// * all writes can only update this value in the same direction of false->true
 		// * the barrier at the end of rayon "parallel for" is a big enough synchronization point to be Relaxed about memory ordering of accesses to this Atomic.
 		let any_transparent_left = AtomicBool::new(false);
+		let constant_switches = AtomicUsize::new(0);
+		let skipped_folds = AtomicUsize::new(0);
 		self.multilinears
 			.par_iter_mut()
 			.try_for_each(|multilinear| {
@@ -197,10 +225,27 @@ where
 							large_field_folded_multilinear.evaluate_zeroth_variable(challenge)?,
 						);
 					}
+					SumcheckMultilinear::Constant { .. } => {
+						skipped_folds.fetch_add(1, Ordering::Relaxed);
+					}
 				};
+
+				// A folded multilinear that is constant stays constant in all later rounds.
+				if let SumcheckMultilinear::Folded {
+					large_field_folded_multilinear,
+				} = multilinear
+				{
+					if let Some(value) = constant_value(large_field_folded_multilinear) {
+						*multilinear = SumcheckMultilinear::Constant { value };
+						constant_switches.fetch_add(1, Ordering::Relaxed);
+					}
+				}
 				Ok::<(), Error>(())
 			})?;
 
+		self.fold_stats.constant_switches += constant_switches.into_inner();
+		self.fold_stats.skipped_folds += skipped_folds.into_inner();
+
 		if !any_transparent_left.load(Ordering::Relaxed) {
 			self.tensor_query = None;
 		}
@@ -238,6 +283,7 @@ where
 					SumcheckMultilinear::Folded {
 						large_field_folded_multilinear,
 					} => large_field_folded_multilinear.evaluate(empty_query.to_ref()),
+					SumcheckMultilinear::Constant { value } => Ok(value),
 				}
 				.map_err(Error::MathError)
 			})
@@ -261,6 +307,7 @@ where
 			&self.multilinears,
 			evaluators,
 			&self.evaluation_points,
+			self.round_eval_strategy,
 		)?)
 	}
 
@@ -283,6 +330,7 @@ where
 			&self.multilinears,
 			evaluators,
 			&self.evaluation_points,
+			self.round_eval_strategy,
 		)?)
 	}
 
@@ -327,3 +375,17 @@ where
 		Ok(batched_coeffs)
 	}
 }
+
+/// Returns the value of a multilinear on at least one variable if it is constant.
+///
+/// The scan stops at the first evaluation that differs from the first one, so it is cheap for the
+/// common case of non-constant multilinears.
+fn constant_value<P: PackedField>(multilinear: &MLEDirectAdapter<P>) -> Option<P::Scalar> {
+	if multilinear.n_vars() == 0 {
+		return None;
+	}
+	let evals = multilinear.packed_evals()?;
+	let mut scalars = P::iter_slice(evals).take(1 << multilinear.n_vars());
+	let first = scalars.next()?;
+	scalars.all(|scalar| scalar == first).then_some(first)
+}
@@ -3,17 +3,19 @@
 use std::{marker::PhantomData, ops::Range};
 
 use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
-use binius_hal::{ComputationBackend, SumcheckEvaluator};
+use binius_hal::{ComputationBackend, RoundEvalStrategy, SumcheckEvaluator};
 use binius_math::{
-	CompositionPolyOS, EvaluationDomainFactory, InterpolationDomain, MultilinearPoly,
+	ArithExpr, CompositionPolyOS, EvaluationDomainFactory, InterpolationDomain, MultilinearPoly,
 };
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use itertools::izip;
-use rayon::prelude::*;
 use stackalloc::stackalloc_with_default;
 use tracing::instrument;
 
-use super::{batch_prove::SumcheckProver, prover_state::ProverState};
+use super::{
+	batch_prove::SumcheckProver,
+	prover_state::{FoldStats, ProverState},
+};
 use crate::{
 	polynomial::{Error as PolynomialError, MultilinearComposite},
 	protocols::sumcheck::{
@@ -161,6 +163,17 @@ where
 			domains,
 		})
 	}
+
+	/// Sets the strategy for computing the round evaluations.
+	pub fn with_round_eval_strategy(mut self, strategy: RoundEvalStrategy) -> Self {
+		self.state.set_round_eval_strategy(strategy);
+		self
+	}
+
+	/// Returns statistics on the multilinears detected to be constant while folding.
+	pub fn fold_stats(&self) -> FoldStats {
+		self.state.fold_stats()
+	}
 }
 
 impl<F, FDomain, P, Composition, M, Backend> SumcheckProver<F>
@@ -244,6 +257,20 @@ where
 		})
 	}
 
+	fn process_subcube_composition_evals(
+		&self,
+		_subcube_vars: usize,
+		_subcube_index: usize,
+		_batch_query: &[&[P]],
+		composition_evals: &[P],
+	) -> P {
+		composition_evals.iter().copied().sum()
+	}
+
+	fn base_expression(&self) -> ArithExpr<F> {
+		self.composition.expression()
+	}
+
 	fn composition(&self) -> &Composition {
 		self.composition
 	}
@@ -7,16 +7,15 @@ use binius_field::{
 	ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable, RepackedExtension,
 	TowerField,
 };
-use binius_hal::{ComputationBackend, SumcheckEvaluator};
+use binius_hal::{ComputationBackend, RoundEvalStrategy, SumcheckEvaluator};
 use binius_math::{
-	CompositionPolyOS, EvaluationDomainFactory, InterpolationDomain, MLEDirectAdapter,
-	MultilinearPoly, MultilinearQuery,
+	eq_ind_partial_eval, ArithExpr, CompositionPolyOS, EvaluationDomainFactory,
+	InterpolationDomain, MLEDirectAdapter, MultilinearExtension, MultilinearPoly, MultilinearQuery,
 };
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use bytemuck::zeroed_vec;
 use getset::Getters;
 use itertools::izip;
-use rayon::prelude::*;
 use stackalloc::stackalloc_with_default;
 use tracing::instrument;
 
@@ -59,21 +58,35 @@ where
 		}
 	}
 
-	let multilinears = multilinears.iter().collect::<Vec<_>>();
+	let zero_claims = zero_claims.into_iter().collect::<Vec<_>>();
+	for (_, composition) in zero_claims.iter() {
+		MultilinearComposite::new(n_vars, composition, multilinears.iter().collect())?;
+	}
 
-	for (name, composition) in zero_claims.into_iter() {
-		let witness = MultilinearComposite::new(n_vars, composition, multilinears.clone())?;
-		(0..(1 << n_vars)).into_par_iter().try_for_each(|j| {
-			if witness.evaluate_on_hypercube(j)? != F::ZERO {
-				return Err(Error::ZerocheckNaiveValidationFailure {
-					composition_name: name.to_string(),
-					vertex_index: j,
-				});
+	// Evaluate every multilinear once per vertex rather than once per composition, since large
+	// constraint sets have thousands of both. The reported failure is the first failing
+	// composition at the lowest failing vertex, independent of thread scheduling.
+	let failure = (0..(1 << n_vars)).into_par_iter().find_map_first(|j| {
+		let check = || -> Result<(), Error> {
+			let evals = multilinears
+				.iter()
+				.map(|multilinear| {
+					Ok::<P, Error>(P::set_single(multilinear.evaluate_on_hypercube(j)?))
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+			for (name, composition) in zero_claims.iter() {
+				if composition.evaluate(&evals)?.get(0) != F::ZERO {
+					return Err(Error::ZerocheckNaiveValidationFailure {
+						composition_name: name.to_string(),
+						vertex_index: j,
+					});
+				}
 			}
 			Ok(())
-		})?;
-	}
-	Ok(())
+		};
+		check().err()
+	});
+	failure.map_or(Ok(()), Err)
 }
 
 /// A prover that is capable of performing univariate skip.
@@ -102,6 +115,9 @@ where
 	compositions: Vec<(Arc<str>, CompositionBase, Composition)>,
 	zerocheck_challenges: Vec<P::Scalar>,
 	domains: Vec<InterpolationDomain<FDomain>>,
+	/// Domains for round polynomials that are not divided by the equality indicator.
+	naive_domains: Vec<InterpolationDomain<FDomain>>,
+	round_eval_strategy: RoundEvalStrategy,
 	backend: &'a Backend,
 	univariate_evals_output: Option<ZerocheckUnivariateEvalsOutput<P::Scalar, P, Backend>>,
 	_p_base_marker: PhantomData<PBase>,
@@ -156,15 +172,19 @@ where
 		let switchover_rounds = determine_switchovers(&multilinears, switchover_fn);
 		let zerocheck_challenges = zerocheck_challenges.to_vec();
 
-		let domains = compositions
+		let (domains, naive_domains) = compositions
 			.iter()
 			.map(|(_, _, composition)| {
 				let degree = composition.degree();
 				let domain = evaluation_domain_factory.create(degree + 1)?;
-				Ok(domain.into())
+				let naive_domain = evaluation_domain_factory.create(degree + 2)?;
+				Ok((domain.into(), naive_domain.into()))
 			})
-			.collect::<Result<Vec<InterpolationDomain<FDomain>>, _>>()
-			.map_err(Error::MathError)?;
+			.collect::<Result<Vec<(InterpolationDomain<FDomain>, InterpolationDomain<FDomain>)>, _>>(
+			)
+			.map_err(Error::MathError)?
+			.into_iter()
+			.unzip();
 
 		Ok(Self {
 			n_vars,
@@ -173,6 +193,8 @@ where
 			compositions,
 			zerocheck_challenges,
 			domains,
+			naive_domains,
+			round_eval_strategy: RoundEvalStrategy::default(),
 			backend,
 			univariate_evals_output: None,
 			_p_base_marker: PhantomData,
@@ -180,6 +202,14 @@ where
 		})
 	}
 
+	/// Sets the strategy for computing the round evaluations of the multilinear rounds.
+	///
+	/// The univariate round of a univariatized zerocheck is not affected by the strategy.
+	pub fn with_round_eval_strategy(mut self, strategy: RoundEvalStrategy) -> Self {
+		self.round_eval_strategy = strategy;
+		self
+	}
+
 	#[instrument(skip_all, level = "debug")]
 	#[allow(clippy::type_complexity)]
 	pub fn into_regular_zerocheck(
@@ -205,7 +235,7 @@ where
 		// REVIEW: this may result in "double boxing" if M is already a trait object;
 		//         consider implementing MultilinearPoly on an Either, or
 		//         supporting two different SumcheckProver<F> types in batch_prove
-		let multilinears = self
+		let mut multilinears = self
 			.multilinears
 			.into_iter()
 			.map(|multilinear| Arc::new(multilinear) as MultilinearWitness<'_, P>)
@@ -221,17 +251,28 @@ where
 			validate_witness(&multilinears, &compositions)?;
 		}
 
-		// Evaluate zerocheck partial indicator in variables 1..n_vars
-		let start = self.n_vars.min(1);
-		let partial_eq_ind_evals = self
-			.backend
-			.tensor_product_full_query(&self.zerocheck_challenges[start..])?;
+		let mut switchover_rounds = self.switchover_rounds;
+		let (partial_eq_ind_evals, domains) = if self.round_eval_strategy.factors_eq_ind() {
+			// Evaluate zerocheck partial indicator in variables 1..n_vars
+			let start = self.n_vars.min(1);
+			let partial_eq_ind_evals = self
+				.backend
+				.tensor_product_full_query(&self.zerocheck_challenges[start..])?;
+			(partial_eq_ind_evals, self.domains)
+		} else {
+			push_eq_ind_multilinear(
+				&mut multilinears,
+				&mut switchover_rounds,
+				&self.zerocheck_challenges,
+			)?;
+			(Backend::to_hal_slice(Vec::new()), self.naive_domains)
+		};
 		let claimed_sums = vec![F::ZERO; self.compositions.len()];
 
 		// This is a regular multilinear zerocheck constructor, split over two creation stages.
 		ZerocheckProver::new(
 			multilinears,
-			self.switchover_rounds,
+			switchover_rounds,
 			self.compositions
 				.into_iter()
 				.map(|(_, a, b)| (a, b))
@@ -239,8 +280,9 @@ where
 			partial_eq_ind_evals,
 			self.zerocheck_challenges,
 			claimed_sums,
-			self.domains,
+			domains,
 			RegularFirstRound::BaseField,
+			self.round_eval_strategy,
 			self.backend,
 		)
 	}
@@ -363,7 +405,7 @@ where
 		let lagrange_coeffs_query =
 			MultilinearQuery::with_expansion(skip_rounds, packed_subcube_lagrange_coeffs)?;
 
-		let partial_low_multilinears = self
+		let mut partial_low_multilinears = self
 			.multilinears
 			.into_par_iter()
 			.map(|multilinear| -> Result<_, Error> {
@@ -374,7 +416,7 @@ where
 			})
 			.collect::<Result<Vec<_>, _>>()?;
 
-		let switchover_rounds = self
+		let mut switchover_rounds = self
 			.switchover_rounds
 			.into_iter()
 			.map(|switchover_round| switchover_round.saturating_sub(skip_rounds))
@@ -382,6 +424,17 @@ where
 
 		let zerocheck_challenges = self.zerocheck_challenges.to_vec();
 
+		let (partial_eq_ind_evals, domains) = if self.round_eval_strategy.factors_eq_ind() {
+			(partial_eq_ind_evals, self.domains)
+		} else {
+			push_eq_ind_multilinear(
+				&mut partial_low_multilinears,
+				&mut switchover_rounds,
+				&zerocheck_challenges,
+			)?;
+			(Backend::to_hal_slice(Vec::new()), self.naive_domains)
+		};
+
 		// This is also regular multilinear zerocheck constructor, but "jump started" in round
 		// `skip_rounds` while using witness with a projected univariate round.
 		// NB: first round evaluator has to be overriden due to issues proving
@@ -397,8 +450,9 @@ where
 			partial_eq_ind_evals,
 			zerocheck_challenges,
 			claimed_prime_sums,
-			self.domains,
+			domains,
 			RegularFirstRound::LargeField,
+			self.round_eval_strategy,
 			self.backend,
 		)?;
 
@@ -425,6 +479,11 @@ enum RegularFirstRound {
 /// When "jump starting" a zerocheck prover in a middle of zerocheck, pay attention that
 /// `claimed_prime_sums` are on "prime" polynomial, and not on full zerocheck polynomial.
 ///
+/// The above only applies to [`RoundEvalStrategy::Gruen`]. With the other strategies the
+/// equality indicator is appended to the multilinears as a regular witness, and the full
+/// zerocheck composition of one degree higher is evaluated directly. At the start of the
+/// multilinear rounds the "prime" sums coincide with the full sums, so the claims are the same.
+///
 /// [Gruen24]: <https://eprint.iacr.org/2024/108>
 #[derive(Debug)]
 pub struct ZerocheckProver<'a, FDomain, PBase, P, CompositionBase, Composition, M, Backend>
@@ -443,6 +502,7 @@ where
 	compositions: Vec<(CompositionBase, Composition)>,
 	domains: Vec<InterpolationDomain<FDomain>>,
 	first_round: RegularFirstRound,
+	round_eval_strategy: RoundEvalStrategy,
 	_p_base_marker: PhantomData<PBase>,
 }
 
@@ -468,6 +528,7 @@ where
 		claimed_prime_sums: Vec<F>,
 		domains: Vec<InterpolationDomain<FDomain>>,
 		first_round: RegularFirstRound,
+		round_eval_strategy: RoundEvalStrategy,
 		backend: &'a Backend,
 	) -> Result<Self, Error> {
 		let evaluation_points = domains
@@ -479,13 +540,14 @@ where
 			bail!(Error::IncorrectClaimedPrimeSumsLength);
 		}
 
-		let state = ProverState::new_with_switchover_rounds(
+		let mut state = ProverState::new_with_switchover_rounds(
 			multilinears,
 			&switchover_rounds,
 			claimed_prime_sums,
 			evaluation_points,
 			backend,
 		)?;
+		state.set_round_eval_strategy(round_eval_strategy);
 		let n_vars = state.n_vars();
 
 		if zerocheck_challenges.len() != n_vars {
@@ -494,7 +556,9 @@ where
 
 		// Only one value of the expanded zerocheck equality indicator is used per each
 		// 1-variable subcube, thus it should be twice smaller.
-		if partial_eq_ind_evals.len() != 1 << n_vars.saturating_sub(1 + P::LOG_WIDTH) {
+		if round_eval_strategy.factors_eq_ind()
+			&& partial_eq_ind_evals.len() != 1 << n_vars.saturating_sub(1 + P::LOG_WIDTH)
+		{
 			bail!(Error::IncorrectZerocheckPartialEqIndSize);
 		}
 
@@ -509,6 +573,7 @@ where
 			compositions,
 			domains,
 			first_round,
+			round_eval_strategy,
 			_p_base_marker: PhantomData,
 		})
 	}
@@ -560,6 +625,10 @@ where
 		self.update_eq_ind_eval(challenge);
 		self.state.fold(challenge)?;
 
+		if !self.round_eval_strategy.factors_eq_ind() {
+			return Ok(());
+		}
+
 		// This must happen after state fold, which decrements n_rounds_remaining.
 		self.fold_partial_eq_ind();
 
@@ -569,6 +638,22 @@ where
 	#[instrument(skip_all, name = "ZerocheckProver::execute", level = "debug")]
 	fn execute(&mut self, batch_coeff: F) -> Result<RoundCoeffs<F>, Error> {
 		let round = self.round();
+
+		if !self.round_eval_strategy.factors_eq_ind() {
+			// The equality indicator is the last multilinear, so the round polynomial is
+			// computed directly and needs no conversion.
+			let evaluators = izip!(&self.compositions, &self.domains)
+				.map(|((_, composition), interpolation_domain)| ZerocheckNaiveRoundEvaluator {
+					composition,
+					interpolation_domain,
+				})
+				.collect::<Vec<_>>();
+			let evals = self.state.calculate_later_round_evals(&evaluators)?;
+			return self
+				.state
+				.calculate_round_coeffs_from_evals(&evaluators, batch_coeff, evals);
+		}
+
 		let base_field_first_round =
 			round == 0 && matches!(self.first_round, RegularFirstRound::BaseField);
 		let coeffs = if base_field_first_round {
@@ -622,7 +707,10 @@ where
 	#[instrument(skip_all, name = "ZerocheckProver::finish", level = "debug")]
 	fn finish(self: Box<Self>) -> Result<Vec<F>, Error> {
 		let mut evals = self.state.finish()?;
-		evals.push(self.eq_ind_eval);
+		// Otherwise the equality indicator evaluation is already the last one.
+		if self.round_eval_strategy.factors_eq_ind() {
+			evals.push(self.eq_ind_eval);
+		}
 		Ok(evals)
 	}
 }
@@ -676,17 +764,31 @@ where
 				.batch_evaluate(batch_query, evals)
 				.expect("correct by query construction invariant");
 
-			let subcube_start = subcube_index << subcube_vars.saturating_sub(P::LOG_WIDTH);
-			let partial_eq_ind_evals_slice = &self.partial_eq_ind_evals[subcube_start..];
-			let field_sum = PackedField::iter_slice(partial_eq_ind_evals_slice)
-				.zip(PackedField::iter_slice(evals))
-				.map(|(eq_ind_scalar, base_scalar)| eq_ind_scalar * base_scalar)
-				.sum();
-
-			P::set_single(field_sum)
+			self.process_subcube_composition_evals(subcube_vars, subcube_index, batch_query, evals)
 		})
 	}
 
+	fn process_subcube_composition_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		_batch_query: &[&[PBase]],
+		composition_evals: &[PBase],
+	) -> P {
+		let subcube_start = subcube_index << subcube_vars.saturating_sub(P::LOG_WIDTH);
+		let partial_eq_ind_evals_slice = &self.partial_eq_ind_evals[subcube_start..];
+		let field_sum = PackedField::iter_slice(partial_eq_ind_evals_slice)
+			.zip(PackedField::iter_slice(composition_evals))
+			.map(|(eq_ind_scalar, base_scalar)| eq_ind_scalar * base_scalar)
+			.sum();
+
+		P::set_single(field_sum)
+	}
+
+	fn base_expression(&self) -> ArithExpr<PBase::Scalar> {
+		self.composition_base.expression()
+	}
+
 	fn composition(&self) -> &Composition {
 		self.composition
 	}
@@ -766,15 +868,27 @@ where
 				.batch_evaluate(batch_query, evals)
 				.expect("correct by query construction invariant");
 
-			let subcube_start = subcube_index << subcube_vars.saturating_sub(P::LOG_WIDTH);
-			for (i, eval) in evals.iter_mut().enumerate() {
-				*eval *= self.partial_eq_ind_evals[subcube_start + i];
-			}
-
-			evals.iter().copied().sum::<P>()
+			self.process_subcube_composition_evals(subcube_vars, subcube_index, batch_query, evals)
 		})
 	}
 
+	fn process_subcube_composition_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		_batch_query: &[&[P]],
+		composition_evals: &[P],
+	) -> P {
+		let subcube_start = subcube_index << subcube_vars.saturating_sub(P::LOG_WIDTH);
+		izip!(composition_evals, &self.partial_eq_ind_evals[subcube_start..])
+			.map(|(&eval, &eq_ind)| eval * eq_ind)
+			.sum::<P>()
+	}
+
+	fn base_expression(&self) -> ArithExpr<F> {
+		self.composition.expression()
+	}
+
 	fn composition(&self) -> &Composition {
 		self.composition
 	}
@@ -813,3 +927,121 @@ where
 		Ok(coeffs)
 	}
 }
+
+struct ZerocheckNaiveRoundEvaluator<'a, FDomain, Composition>
+where
+	FDomain: Field,
+{
+	composition: &'a Composition,
+	interpolation_domain: &'a InterpolationDomain<FDomain>,
+}
+
+impl<F, P, FDomain, Composition> SumcheckEvaluator<P, P, Composition>
+	for ZerocheckNaiveRoundEvaluator<'_, FDomain, Composition>
+where
+	F: Field + ExtensionField<FDomain>,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Composition: CompositionPolyOS<P>,
+{
+	fn eval_point_indices(&self) -> Range<usize> {
+		// The round polynomial is the composition multiplied by the equality indicator, which
+		// has degree d + 1. As in a regular sumcheck, r(0) is derived from the claimed sum.
+		1..self.composition.degree() + 2
+	}
+
+	fn process_subcube_at_eval_point(
+		&self,
+		_subcube_vars: usize,
+		_subcube_index: usize,
+		batch_query: &[&[P]],
+	) -> P {
+		// If the composition is a linear polynomial, then the composite multivariate polynomial
+		// is multilinear. If the prover is honest, then this multilinear is identically zero,
+		// hence the sum over the subcube is zero.
+		if self.composition.degree() == 1 {
+			return P::zero();
+		}
+		let (eq_ind, batch_query) = batch_query
+			.split_last()
+			.expect("equality indicator is the last multilinear");
+		let row_len = eq_ind.len();
+
+		stackalloc_with_default(row_len, |evals| {
+			self.composition
+				.batch_evaluate(batch_query, evals)
+				.expect("correct by query construction invariant");
+
+			izip!(evals.iter(), eq_ind.iter())
+				.map(|(&eval, &eq_ind)| eval * eq_ind)
+				.sum::<P>()
+		})
+	}
+
+	fn process_subcube_composition_evals(
+		&self,
+		_subcube_vars: usize,
+		_subcube_index: usize,
+		batch_query: &[&[P]],
+		composition_evals: &[P],
+	) -> P {
+		let eq_ind = batch_query
+			.last()
+			.expect("equality indicator is the last multilinear");
+		izip!(composition_evals, eq_ind.iter())
+			.map(|(&eval, &eq_ind)| eval * eq_ind)
+			.sum::<P>()
+	}
+
+	fn base_expression(&self) -> ArithExpr<F> {
+		self.composition.expression()
+	}
+
+	fn composition(&self) -> &Composition {
+		self.composition
+	}
+
+	fn eq_ind_partial_eval(&self) -> Option<&[P]> {
+		None
+	}
+}
+
+impl<F, FDomain, Composition> SumcheckInterpolator<F>
+	for ZerocheckNaiveRoundEvaluator<'_, FDomain, Composition>
+where
+	F: Field + ExtensionField<FDomain>,
+	FDomain: Field,
+{
+	fn round_evals_to_coeffs(
+		&self,
+		last_round_sum: F,
+		mut round_evals: Vec<F>,
+	) -> Result<Vec<F>, PolynomialError> {
+		// Given $r(1), \ldots, r(d+1)$, letting $s$ be the current round's claimed sum,
+		// we can compute $r(0)$ using the identity $r(0) = s - r(1)$
+		round_evals.insert(0, last_round_sum - round_evals[0]);
+
+		let coeffs = self.interpolation_domain.interpolate(&round_evals)?;
+		Ok(coeffs)
+	}
+}
+
+/// Appends the expanded zerocheck equality indicator to the multilinears as a regular witness.
+///
+/// The indicator is already in large field, hence it is folded from the first round.
+fn push_eq_ind_multilinear<'a, P>(
+	multilinears: &mut Vec<MultilinearWitness<'a, P>>,
+	switchover_rounds: &mut Vec<usize>,
+	zerocheck_challenges: &[P::Scalar],
+) -> Result<(), Error>
+where
+	P: PackedField,
+{
+	let eq_ind = MultilinearExtension::new(
+		zerocheck_challenges.len(),
+		eq_ind_partial_eval::<P>(zerocheck_challenges),
+	)?;
+	multilinears.push(Arc::new(MLEDirectAdapter::from(eq_ind)) as MultilinearWitness<'a, P>);
+	switchover_rounds.push(0);
+	Ok(())
+}
@@ -1,10 +1,13 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use std::{
+	cell::Cell,
 	iter::{self, repeat_with},
+	rc::Rc,
 	sync::Arc,
 };
 
+use assert_matches::assert_matches;
 use binius_field::{
 	arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 	as_packed_field::{PackScalar, PackedType},
@@ -19,30 +22,34 @@ use binius_math::{
 	ArithExpr, CompositionPolyOS, EvaluationDomainFactory, IsomorphicEvaluationDomainFactory,
 	MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly, MultilinearQuery,
 };
-use binius_utils::checked_arithmetics::log2_ceil_usize;
+use binius_utils::{
+	checked_arithmetics::log2_ceil_usize,
+	rayon::{current_num_threads, prelude::*},
+};
 use groestl_crypto::Groestl256;
 use itertools::izip;
 use rand::{rngs::StdRng, Rng, SeedableRng};
-use rayon::{current_num_threads, prelude::*};
 
 use super::{
 	common::CompositeSumClaim,
 	front_loaded::BatchVerifier as FrontLoadedBatchVerifier,
 	prove::{
-		batch_prove, front_loaded::BatchProver as FrontLoadedBatchProver, RegularSumcheckProver,
+		batch_prove, front_loaded::BatchProver as FrontLoadedBatchProver, BatchProveStart,
+		BatchProver, FoldStats, RegularSumcheckProver,
 	},
-	verify::batch_verify,
-	BatchSumcheckOutput, SumcheckClaim,
+	verify::{batch_verify, BatchVerifier, BatchVerifyStart},
+	BatchSumcheckOutput, Error, RoundCoeffs, SumcheckClaim, VerificationError,
 };
 use crate::{
 	composition::index_composition,
+	constraint_system::CancellationToken,
 	fiat_shamir::{CanSample, HasherChallenger},
 	polynomial::{IdentityCompositionPoly, MultilinearComposite},
 	protocols::{
 		sumcheck::prove::SumcheckProver,
 		test_utils::{AddOneComposition, TestProductComposition},
 	},
-	transcript::TranscriptWriter,
+	transcript::{self, TranscriptWriter},
 };
 
 #[derive(Debug, Clone)]
@@ -214,6 +221,130 @@ fn test_sumcheck_prove_verify_interaction_basic() {
 	}
 }
 
+/// Round polynomials are written without their highest-degree coefficient, saving one 128-bit
+/// element per round compared to writing all coefficients.
+#[test]
+fn test_round_proof_omits_derived_coefficient() {
+	let n_vars = 8;
+	let n_multilinears = 3;
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let multilins =
+		generate_random_multilinears::<PackedType<OptimalUnderlier128b, BinaryField128b>>(
+			&mut rng,
+			n_vars,
+			n_multilinears,
+		)
+		.into_iter()
+		.map(MLEEmbeddingAdapter::<_, PackedType<OptimalUnderlier128b, BinaryField128b>, _>::from)
+		.collect::<Vec<_>>();
+	let composition = TestProductComposition::new(n_multilinears);
+	let sum = compute_composite_sum(&multilins, &composition);
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+		multilins.iter().collect(),
+		[CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+		domain_factory,
+		|_| 0,
+		&backend,
+	)
+	.unwrap();
+
+	let mut transcript = TranscriptWriter::<HasherChallenger<Groestl256>>::default();
+	batch_prove(vec![prover], &mut transcript).unwrap();
+
+	let elem_size = BinaryField128b::N_BITS / 8;
+	let round_proofs_size = n_vars * n_multilinears * elem_size;
+	let evals_size = n_multilinears * elem_size;
+	let full_size = n_vars * (n_multilinears + 1) * elem_size + evals_size;
+	let proof_size = transcript.finalize().len();
+	assert_eq!(proof_size, round_proofs_size + evals_size);
+	assert_eq!(full_size - proof_size, n_vars * elem_size);
+}
+
+/// A multilinear repeating with a period of `2^period_vars` becomes constant after folding its
+/// low `period_vars` variables and is not folded in the remaining rounds.
+#[test]
+fn test_constant_multilinears_skip_folds() {
+	type F = BinaryField128b;
+	type P = PackedType<OptimalUnderlier128b, F>;
+
+	let (n_vars, period_vars) = (6, 2);
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let pattern = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(1 << period_vars)
+		.collect::<Vec<_>>();
+	let periodic = (0..1 << n_vars)
+		.map(|i| P::broadcast(pattern[i % pattern.len()]))
+		.collect::<Vec<_>>();
+	let multilins = iter::once(MultilinearExtension::new(n_vars, periodic).unwrap())
+		.chain(generate_random_multilinears::<P>(&mut rng, n_vars, 1))
+		.map(MLEEmbeddingAdapter::<_, P, _>::from)
+		.collect::<Vec<_>>();
+	let composition = TestProductComposition::new(multilins.len());
+	let sum = compute_composite_sum(&multilins, &composition);
+
+	let backend = make_portable_backend();
+	let make_prover = || {
+		RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+			multilins.iter().collect(),
+			[CompositeSumClaim {
+				composition: &composition,
+				sum,
+			}],
+			IsomorphicEvaluationDomainFactory::<BinaryField8b>::default(),
+			|_| 1,
+			&backend,
+		)
+		.unwrap()
+	};
+
+	let mut prover = make_prover();
+	let challenges = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+	for &challenge in &challenges {
+		prover.execute(F::ONE).unwrap();
+		prover.fold(challenge).unwrap();
+	}
+	assert_eq!(
+		prover.fold_stats(),
+		FoldStats {
+			constant_switches: 1,
+			skipped_folds: n_vars - period_vars,
+		}
+	);
+
+	let query = backend.multilinear_query(&challenges).unwrap();
+	let evals = Box::new(prover).finish().unwrap();
+	for (multilinear, &eval) in iter::zip(&multilins, &evals) {
+		assert_eq!(multilinear.evaluate(query.to_ref()).unwrap(), eval);
+	}
+
+	// The round polynomials of the constant multilinear are unchanged.
+	let claim = SumcheckClaim::new(
+		n_vars,
+		multilins.len(),
+		vec![CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+	)
+	.unwrap();
+	let mut prover_transcript = TranscriptWriter::<HasherChallenger<Groestl256>>::default();
+	let prover_output = batch_prove(vec![make_prover()], &mut prover_transcript).unwrap();
+	let mut verifier_transcript = prover_transcript.into_reader();
+	let verifier_output = batch_verify(&[claim], &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(verifier_output, prover_output);
+}
+
 /// For small numbers of variables, the [`test_prove_verify_interaction_basic'] test may have so
 /// few vertices to process that each vertex is processed on a separate thread. This ensures that
 /// each Rayon task processes more than one vertex and that accumulation is handled correctly in
@@ -420,6 +551,176 @@ fn test_prove_verify_batch_constant_polys() {
 	]);
 }
 
+/// A prover that cancels a token once it has folded a given number of rounds.
+struct CancellingProver<Prover> {
+	inner: Prover,
+	cancellation: CancellationToken,
+	cancel_after: usize,
+	n_rounds: Rc<Cell<usize>>,
+}
+
+impl<F: Field, Prover: SumcheckProver<F>> SumcheckProver<F> for CancellingProver<Prover> {
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn execute(&mut self, batch_coeff: F) -> Result<RoundCoeffs<F>, Error> {
+		self.n_rounds.set(self.n_rounds.get() + 1);
+		self.inner.execute(batch_coeff)
+	}
+
+	fn fold(&mut self, challenge: F) -> Result<(), Error> {
+		if self.n_rounds.get() == self.cancel_after {
+			self.cancellation.cancel();
+		}
+		self.inner.fold(challenge)
+	}
+
+	fn finish(self: Box<Self>) -> Result<Vec<F>, Error> {
+		Box::new(self.inner).finish()
+	}
+}
+
+#[test]
+fn test_prove_batch_cancelled_between_rounds() {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+	let (_, _, prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+		&TestSumcheckClaimShape {
+			n_vars: 8,
+			degree: 3,
+		},
+		&mut rng,
+		&domain_factory,
+		&backend,
+	);
+
+	let cancellation = CancellationToken::new();
+	let n_rounds = Rc::new(Cell::new(0));
+	let prover = CancellingProver {
+		inner: prover,
+		cancellation: cancellation.clone(),
+		cancel_after: 3,
+		n_rounds: n_rounds.clone(),
+	};
+
+	let mut prover_transcript =
+		TranscriptWriter::<HasherChallenger<Groestl256>>::default().with_cancellation(cancellation);
+	let result = batch_prove(vec![prover], &mut prover_transcript);
+	assert_matches!(result, Err(Error::TranscriptError(transcript::Error::Cancelled)));
+	assert_eq!(n_rounds.get(), 3);
+}
+
+fn verify_start<F: Field>() -> BatchVerifyStart<F> {
+	BatchVerifyStart {
+		batch_coeffs: Vec::new(),
+		sum: F::ZERO,
+		max_degree: 0,
+		skip_rounds: 0,
+	}
+}
+
+#[test]
+fn test_prove_verify_batch_interactive() {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+	let mut claims = Vec::new();
+	let mut provers = Vec::new();
+	for (n_vars, degree) in [(6, 3), (4, 2), (0, 2)] {
+		let (_, claim, prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+			&TestSumcheckClaimShape { n_vars, degree },
+			&mut rng,
+			&domain_factory,
+			&backend,
+		);
+		claims.push(claim);
+		provers.push(prover);
+	}
+
+	let start = BatchProveStart {
+		batch_coeffs: Vec::new(),
+		reduction_provers: Vec::new(),
+	};
+	let mut prover = BatchProver::new(start, provers).unwrap();
+	let mut verifier = BatchVerifier::new(verify_start(), &claims).unwrap();
+	let mut n_messages = 0;
+	while !prover.is_finished() {
+		let message = prover.next_message().unwrap();
+		assert_eq!(message.is_some(), verifier.expects_message());
+		if let Some(round_coeffs) = message {
+			verifier.process_message(round_coeffs).unwrap();
+			n_messages += 1;
+		} else {
+			assert_matches!(verifier.process_message(RoundCoeffs::default()), Err(_));
+		}
+
+		let challenge = <FE as Field>::random(&mut rng);
+		prover.process_challenge(challenge).unwrap();
+		verifier.process_challenge(challenge).unwrap();
+	}
+	assert!(verifier.is_finished());
+	assert_eq!(n_messages, 6);
+
+	let prover_output = prover.finish().unwrap();
+	let verifier_output = verifier
+		.finish(prover_output.multilinear_evals.clone())
+		.unwrap();
+	assert_eq!(prover_output, verifier_output);
+}
+
+#[test]
+fn test_verify_batch_interactive_rejects_round_message() {
+	type FE = BinaryField128b;
+	let (_, claim, _) = make_test_sumcheck::<
+		FE,
+		BinaryField8b,
+		PackedBinaryField4x32b,
+		PackedBinaryField1x128b,
+		_,
+	>(
+		&TestSumcheckClaimShape {
+			n_vars: 2,
+			degree: 2,
+		},
+		StdRng::seed_from_u64(0),
+		&IsomorphicEvaluationDomainFactory::<BinaryField8b>::default(),
+		&make_portable_backend(),
+	);
+	let claims = [claim];
+
+	let mut verifier = BatchVerifier::new(verify_start(), &claims).unwrap();
+	assert!(!verifier.expects_message());
+	verifier.process_challenge(FE::ONE).unwrap();
+	assert!(verifier.expects_message());
+	assert_matches!(verifier.process_challenge(FE::ONE), Err(Error::ExpectedRoundMessage));
+
+	let degree = verifier.max_degree();
+	assert_matches!(
+		verifier.process_message(RoundCoeffs(vec![FE::ONE; degree])),
+		Err(Error::Verification(VerificationError::NumberOfCoefficients { round: 0, .. }))
+	);
+	let mut coeffs = vec![FE::ZERO; degree + 1];
+	coeffs[1] = verifier.sum() + FE::ONE;
+	assert_matches!(
+		verifier.process_message(RoundCoeffs(coeffs)),
+		Err(Error::Verification(VerificationError::RoundSumMismatch { round: 0 }))
+	);
+}
+
 fn prove_verify_batch_front_loaded(claim_shapes: &[TestSumcheckClaimShape]) {
 	type P = PackedBinaryField4x32b;
 	type FDomain = BinaryField8b;
@@ -20,6 +20,7 @@ use crate::{
 	protocols::sumcheck::{
 		BatchSumcheckOutput, CompositeSumClaim, Error, SumcheckClaim, VerificationError,
 	},
+	transcript::DerivedMessage,
 };
 
 /// A univariate polynomial in Lagrange basis.
@@ -70,6 +71,24 @@ impl<F: Field> LagrangeRoundEvals<F> {
 	}
 }
 
+/// Univariate round polynomials are written without their zeros prefix, whose length the reader
+/// knows from the claims.
+impl<F: TowerField> DerivedMessage for LagrangeRoundEvals<F> {
+	type Scalar = F;
+	type Context = usize;
+
+	fn sent_values(&self) -> &[F] {
+		&self.evals
+	}
+
+	fn derive(evals: Vec<F>, zeros_prefix_len: usize) -> Self {
+		LagrangeRoundEvals {
+			zeros_prefix_len,
+			evals,
+		}
+	}
+}
+
 impl<F: Field> Mul<F> for LagrangeRoundEvals<F> {
 	type Output = LagrangeRoundEvals<F>;
 
@@ -225,8 +244,8 @@ mod tests {
 		arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 		as_packed_field::{PackScalar, PackedType},
 		underlier::UnderlierType,
-		AESTowerField128b, AESTowerField16b, AESTowerField8b, BinaryField128b, BinaryField16b,
-		Field, PackedBinaryField1x128b, PackedBinaryField4x32b, PackedExtension,
+		AESTowerField128b, AESTowerField16b, AESTowerField8b, BinaryField, BinaryField128b,
+		BinaryField16b, Field, PackedBinaryField1x128b, PackedBinaryField4x32b, PackedExtension,
 		PackedFieldIndexable, RepackedExtension, TowerField,
 	};
 	use binius_hal::ComputationBackend;
@@ -251,12 +270,13 @@ mod tests {
 					SumcheckProver, UnivariateZerocheck,
 				},
 				standard_switchover_heuristic,
+				univariate_zerocheck::domain_size,
 				zerocheck::reduce_to_sumchecks,
 				ZerocheckClaim,
 			},
 			test_utils::generate_zero_product_multilinears,
 		},
-		transcript::{AdviceWriter, Proof, TranscriptWriter},
+		transcript::{AdviceWriter, Proof, TranscriptReader, TranscriptWriter},
 	};
 
 	#[test]
@@ -409,6 +429,56 @@ mod tests {
 		>()
 	}
 
+	/// The univariate round polynomial is written without the evaluations on the skipped
+	/// hypercube, which are zero for an honest prover, compared to writing the whole domain.
+	#[test]
+	fn test_univariate_round_omits_zeros_prefix() {
+		type P = PackedType<OptimalUnderlier128b, AESTowerField128b>;
+		type PBase = PackedType<OptimalUnderlier128b, AESTowerField16b>;
+		type PWitness = PackedType<OptimalUnderlier128b, AESTowerField8b>;
+
+		let skip_rounds = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+		let backend = make_portable_backend();
+
+		let pair = Arc::new(IndexComposition::new(2, [0, 1], ProductComposition::<2> {}).unwrap());
+		let multilinears =
+			generate_zero_product_multilinears::<PWitness, P>(&mut rng, skip_rounds, 2);
+		let prover = UnivariateZerocheck::<AESTowerField16b, PBase, P, _, _, _, _>::new(
+			multilinears,
+			[(
+				"pair".into(),
+				pair.clone() as Arc<dyn CompositionPolyOS<PBase>>,
+				pair.clone() as Arc<dyn CompositionPolyOS<P>>,
+			)],
+			&[],
+			IsomorphicEvaluationDomainFactory::<AESTowerField16b>::default(),
+			standard_switchover_heuristic(-2),
+			&backend,
+		)
+		.unwrap();
+
+		let mut transcript = TranscriptWriter::<HasherChallenger<Groestl256>>::default();
+		batch_prove_zerocheck_univariate_round(vec![prover], skip_rounds, &mut transcript).unwrap();
+
+		let claim = ZerocheckClaim::<BinaryField128b, _>::new(
+			skip_rounds,
+			2,
+			vec![pair as Arc<dyn CompositionPolyOS<BinaryField128b>>],
+		)
+		.unwrap();
+		let elem_size = BinaryField128b::N_BITS / 8;
+		let max_domain_size = domain_size(claim.max_individual_degree(), skip_rounds);
+		let full_size = max_domain_size * elem_size;
+
+		let proof = transcript.finalize();
+		assert_eq!(full_size - proof.len(), (1 << skip_rounds) * elem_size);
+
+		let mut transcript = TranscriptReader::<HasherChallenger<Groestl256>>::new(proof);
+		batch_verify_zerocheck_univariate_round(&[claim], skip_rounds, &mut transcript).unwrap();
+		transcript.finalize().unwrap();
+	}
+
 	fn test_univariatized_zerocheck_end_to_end_helper<U, F, FI, FDomain, FBase, FWitness>()
 	where
 		U: UnderlierType
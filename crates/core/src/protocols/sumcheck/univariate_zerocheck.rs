@@ -7,6 +7,7 @@ use tracing::instrument;
 
 use super::{
 	error::{Error, VerificationError},
+	univariate::LagrangeRoundEvals,
 	verify::BatchVerifyStart,
 	zerocheck::ZerocheckClaim,
 };
@@ -79,7 +80,10 @@ where
 		max_degree = max_degree.max(claim.max_individual_degree() + 1);
 	}
 
-	let round_evals = transcript.read_scalar_slice(max_domain_size - zeros_prefix_len)?;
+	let round_evals = transcript.read_derived::<LagrangeRoundEvals<F>>(
+		max_domain_size - zeros_prefix_len,
+		zeros_prefix_len,
+	)?;
 	let univariate_challenge = transcript.sample();
 
 	let evaluation_domain = EvaluationDomainFactory::<F>::create(
@@ -89,8 +93,10 @@ where
 
 	let lagrange_coeffs = evaluation_domain.lagrange_evals(univariate_challenge);
 	let sum = inner_product_unchecked::<F, F>(
-		round_evals,
-		lagrange_coeffs[zeros_prefix_len..].iter().copied(),
+		round_evals.evals,
+		lagrange_coeffs[round_evals.zeros_prefix_len..]
+			.iter()
+			.copied(),
 	);
 
 	let batch_verify_start = BatchVerifyStart {
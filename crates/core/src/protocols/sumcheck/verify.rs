@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::iter;
+
 use binius_field::{Field, TowerField};
 use binius_math::{evaluate_univariate, CompositionPolyOS};
 use binius_utils::{bail, sorting::is_sorted_ascending};
@@ -69,102 +71,219 @@ where
 	Composition: CompositionPolyOS<F>,
 	Transcript: CanSample<F> + CanRead,
 {
-	let BatchVerifyStart {
-		mut batch_coeffs,
-		mut sum,
-		mut max_degree,
-		skip_rounds,
-	} = start;
-
-	// Check that the claims are in descending order by n_vars
-	if !is_sorted_ascending(claims.iter().map(|claim| claim.n_vars()).rev()) {
-		bail!(Error::ClaimsOutOfOrder);
+	let mut verifier = BatchVerifier::new(start, claims)?;
+	while !verifier.is_finished() {
+		if verifier.expects_message() {
+			#[cfg(feature = "diagnostics")]
+			crate::diagnostics::record_round(verifier.round());
+			let round_coeffs = transcript.read_derived(verifier.max_degree(), verifier.sum())?;
+			verifier.process_message(round_coeffs)?;
+		}
+		verifier.process_challenge(transcript.sample())?;
+	}
+
+	let multilinear_evals = claims
+		.iter()
+		.map(|claim| transcript.read_scalar_slice::<F>(claim.n_multilinears()))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	verifier.finish(multilinear_evals)
+}
+
+/// A batched sumcheck verifier driven by externally supplied challenges.
+///
+/// This is the state machine behind [`batch_verify`], for running the protocol interactively or
+/// over a custom transcript. The challenges are processed in the same order as by
+/// [`crate::protocols::sumcheck::prove::BatchProver`]. The caller must
+///
+/// 1. construct a new verifier with [`BatchVerifier::new`]
+/// 2. if [`BatchVerifier::expects_message`], receive the round message from the prover and call
+///    [`BatchVerifier::process_message`] with it
+/// 3. call [`BatchVerifier::process_challenge`] with the next challenge
+/// 4. repeat from step 2 until [`BatchVerifier::is_finished`], then receive the multilinear
+///    evaluations from the prover and call [`BatchVerifier::finish`] with them
+#[derive(Debug)]
+pub struct BatchVerifier<'a, F: Field, Composition> {
+	claims: &'a [SumcheckClaim<F, Composition>],
+	batch_coeffs: Vec<F>,
+	sum: F,
+	max_degree: usize,
+	/// The number of claims that have been batched, which are the first ones.
+	active_index: usize,
+	n_rounds: usize,
+	/// The current round, counting the skipped rounds.
+	round: usize,
+	challenges: Vec<F>,
+	/// The round message of the current round, if it has been received.
+	round_coeffs: Option<RoundCoeffs<F>>,
+}
+
+impl<'a, F, Composition> BatchVerifier<'a, F, Composition>
+where
+	F: TowerField,
+	Composition: CompositionPolyOS<F>,
+{
+	pub fn new(
+		start: BatchVerifyStart<F>,
+		claims: &'a [SumcheckClaim<F, Composition>],
+	) -> Result<Self, Error> {
+		let BatchVerifyStart {
+			batch_coeffs,
+			sum,
+			max_degree,
+			skip_rounds,
+		} = start;
+
+		// Check that the claims are in descending order by n_vars
+		if !is_sorted_ascending(claims.iter().map(|claim| claim.n_vars()).rev()) {
+			bail!(Error::ClaimsOutOfOrder);
+		}
+
+		if batch_coeffs.len() > claims.len() {
+			bail!(Error::TooManyPrebatchedCoeffs);
+		}
+
+		let n_rounds = claims.iter().map(|claim| claim.n_vars()).max().unwrap_or(0);
+
+		if skip_rounds > n_rounds {
+			return Err(VerificationError::IncorrectSkippedRoundsCount.into());
+		}
+
+		Ok(Self {
+			claims,
+			active_index: batch_coeffs.len(),
+			batch_coeffs,
+			sum,
+			max_degree,
+			n_rounds,
+			round: skip_rounds,
+			challenges: Vec::with_capacity(n_rounds - skip_rounds),
+			round_coeffs: None,
+		})
 	}
 
-	if batch_coeffs.len() > claims.len() {
-		bail!(Error::TooManyPrebatchedCoeffs);
+	/// Whether the next challenge is the batching coefficient of a claim joining the batch.
+	fn expects_batch_coeff(&self) -> bool {
+		self.claims.get(self.active_index).is_some_and(|claim| {
+			// Claims for 0-variate (ie. constant) polynomials join after the last round
+			self.round == self.n_rounds || claim.n_vars() == self.n_rounds - self.round
+		})
 	}
 
-	let n_rounds = claims.iter().map(|claim| claim.n_vars()).max().unwrap_or(0);
+	/// Whether all challenges have been processed.
+	pub fn is_finished(&self) -> bool {
+		!self.expects_batch_coeff() && self.round == self.n_rounds
+	}
 
-	if skip_rounds > n_rounds {
-		return Err(VerificationError::IncorrectSkippedRoundsCount.into());
+	/// Whether the next challenge must be preceded by a round message.
+	pub fn expects_message(&self) -> bool {
+		!self.expects_batch_coeff() && self.round < self.n_rounds && self.round_coeffs.is_none()
 	}
 
-	// active_index is an index into the claims slice. Claims before the active index have already
-	// been batched into the instance and claims after the index have not.
-	let mut active_index = batch_coeffs.len();
-	let mut challenges = Vec::with_capacity(n_rounds - skip_rounds);
-	for round_no in skip_rounds..n_rounds {
-		let n_vars = n_rounds - round_no;
+	/// The current round, counting the skipped rounds.
+	pub fn round(&self) -> usize {
+		self.round
+	}
+
+	/// The degree of the round polynomial of the current round.
+	pub fn max_degree(&self) -> usize {
+		self.max_degree
+	}
 
-		while let Some(claim) = claims.get(active_index) {
-			if claim.n_vars() != n_vars {
-				break;
+	/// The sum of the current round polynomial over the boolean domain.
+	pub fn sum(&self) -> F {
+		self.sum
+	}
+
+	/// Receives the round message of the current round.
+	pub fn process_message(&mut self, round_coeffs: RoundCoeffs<F>) -> Result<(), Error> {
+		if !self.expects_message() {
+			bail!(Error::UnexpectedRoundMessage);
+		}
+
+		let RoundCoeffs(coeffs) = &round_coeffs;
+		if coeffs.len() != self.max_degree + 1 {
+			return Err(VerificationError::NumberOfCoefficients {
+				round: self.round,
+				expected: self.max_degree + 1,
 			}
+			.into());
+		}
+		// The round polynomial must evaluate to the claimed sum over the points 0 and 1
+		if coeffs[0] + coeffs.iter().sum::<F>() != self.sum {
+			return Err(VerificationError::RoundSumMismatch { round: self.round }.into());
+		}
 
-			let next_batch_coeff = transcript.sample();
-			batch_coeffs.push(next_batch_coeff);
+		self.round_coeffs = Some(round_coeffs);
+		Ok(())
+	}
+
+	/// Processes the next challenge, which is either a batching coefficient or a round challenge.
+	pub fn process_challenge(&mut self, challenge: F) -> Result<(), Error> {
+		if self.expects_batch_coeff() {
+			let claim = &self.claims[self.active_index];
+			self.batch_coeffs.push(challenge);
 
 			// Batch the next claimed sum into the batched sum.
-			sum += batch_weighted_value(
-				next_batch_coeff,
+			self.sum += batch_weighted_value(
+				challenge,
 				claim
 					.composite_sums()
 					.iter()
 					.map(|inner_claim| inner_claim.sum),
 			);
-			max_degree = max_degree.max(claim.max_individual_degree());
-			active_index += 1;
+			self.max_degree = self.max_degree.max(claim.max_individual_degree());
+			self.active_index += 1;
+			return Ok(());
+		}
+		if self.round == self.n_rounds {
+			bail!(Error::IncorrectNumberOfChallenges);
 		}
 
-		let coeffs = transcript.read_scalar_slice(max_degree)?;
-		let round_proof = RoundProof(RoundCoeffs(coeffs));
-
-		let challenge = transcript.sample();
-		challenges.push(challenge);
-
-		sum = interpolate_round_proof(round_proof, sum, challenge);
+		let round_coeffs = self
+			.round_coeffs
+			.take()
+			.ok_or(Error::ExpectedRoundMessage)?;
+		self.sum = evaluate_univariate(&round_coeffs.0, challenge);
+		self.challenges.push(challenge);
+		self.round += 1;
+		Ok(())
 	}
 
-	// Batch in any claims for 0-variate (ie. constant) polynomials.
-	while let Some(claim) = claims.get(active_index) {
-		debug_assert_eq!(claim.n_vars(), 0);
-
-		let next_batch_coeff = transcript.sample();
-		batch_coeffs.push(next_batch_coeff);
-
-		// Batch the next claimed sum into the batched sum.
-		sum += batch_weighted_value(
-			next_batch_coeff,
-			claim
-				.composite_sums()
-				.iter()
-				.map(|inner_claim| inner_claim.sum),
-		);
-		active_index += 1;
-	}
+	/// Performs the final check against the evaluations of the multilinears of every claim at the
+	/// challenge point, consuming the verifier.
+	pub fn finish(self, multilinear_evals: Vec<Vec<F>>) -> Result<BatchSumcheckOutput<F>, Error> {
+		if !self.is_finished() {
+			bail!(Error::IncorrectNumberOfChallenges);
+		}
+		if multilinear_evals.len() != self.claims.len()
+			|| iter::zip(self.claims, &multilinear_evals)
+				.any(|(claim, evals)| evals.len() != claim.n_multilinears())
+		{
+			return Err(VerificationError::NumberOfFinalEvaluations.into());
+		}
 
-	let mut multilinear_evals = Vec::with_capacity(claims.len());
-	for claim in claims.iter() {
-		let evals = transcript.read_scalar_slice::<F>(claim.n_multilinears())?;
-		multilinear_evals.push(evals);
-	}
+		let expected_sum = compute_expected_batch_composite_evaluation_multi_claim(
+			self.batch_coeffs,
+			self.claims,
+			&multilinear_evals,
+		)?;
 
-	let expected_sum = compute_expected_batch_composite_evaluation_multi_claim(
-		batch_coeffs,
-		claims,
-		&multilinear_evals,
-	)?;
+		if self.sum != expected_sum {
+			#[cfg(feature = "diagnostics")]
+			crate::diagnostics::record_mismatch(
+				"sumcheck batch evaluation",
+				self.sum,
+				expected_sum,
+			);
+			return Err(VerificationError::IncorrectBatchEvaluation.into());
+		}
 
-	if sum != expected_sum {
-		return Err(VerificationError::IncorrectBatchEvaluation.into());
+		Ok(BatchSumcheckOutput {
+			challenges: self.challenges,
+			multilinear_evals,
+		})
 	}
-
-	Ok(BatchSumcheckOutput {
-		challenges,
-		multilinear_evals,
-	})
 }
 
 pub fn compute_expected_batch_composite_evaluation_single_claim<F: Field, Composition>(
@@ -196,13 +196,17 @@ where
 mod tests {
 	use std::{iter, sync::Arc};
 
+	use assert_matches::assert_matches;
 	use binius_field::{
-		BinaryField128b, BinaryField8b, ExtensionField, PackedBinaryField1x128b,
+		BinaryField128b, BinaryField32b, BinaryField8b, ExtensionField, PackedBinaryField1x128b,
 		PackedBinaryField4x32b, PackedExtension, PackedFieldIndexable, RepackedExtension,
 	};
-	use binius_hal::{make_portable_backend, ComputationBackend, ComputationBackendExt};
+	use binius_hal::{
+		make_portable_backend, ComputationBackend, ComputationBackendExt, RoundEvalStrategy,
+	};
 	use binius_math::{
-		EvaluationDomainFactory, IsomorphicEvaluationDomainFactory, MultilinearPoly,
+		EvaluationDomainFactory, IsomorphicEvaluationDomainFactory, MultilinearExtension,
+		MultilinearPoly,
 	};
 	use groestl_crypto::Groestl256;
 	use rand::{prelude::StdRng, SeedableRng};
@@ -210,6 +214,7 @@ mod tests {
 	use super::*;
 	use crate::{
 		fiat_shamir::{CanSample, HasherChallenger},
+		polynomial::ArithCircuitPoly,
 		protocols::{
 			sumcheck::{
 				batch_verify,
@@ -333,6 +338,63 @@ mod tests {
 		assert_eq!(sumcheck_challenges_1, sumcheck_challenges_2);
 	}
 
+	type TestP = PackedBinaryField1x128b;
+	type TestPBase = PackedBinaryField4x32b;
+
+	fn test_round_eval_strategies_agree_helper<Composition>(
+		n_vars: usize,
+		n_multilinears: usize,
+		switchover_rd: usize,
+		composition: Composition,
+	) where
+		Composition: CompositionPolyOS<TestPBase> + CompositionPolyOS<TestP> + Clone,
+	{
+		type P = TestP;
+		type PBase = TestPBase;
+		type FDomain = BinaryField8b;
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let multilins =
+			generate_zero_product_multilinears::<PBase, P>(&mut rng, n_vars, n_multilinears);
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+		let outputs = [
+			RoundEvalStrategy::Naive,
+			RoundEvalStrategy::SlopeReuse,
+			RoundEvalStrategy::Karatsuba,
+			RoundEvalStrategy::Gruen,
+		]
+		.map(|strategy| {
+			let mut prove_transcript = TranscriptWriter::<HasherChallenger<Groestl256>>::default();
+			let challenges: Vec<BinaryField128b> = prove_transcript.sample_vec(n_vars);
+
+			let prover = UnivariateZerocheck::<FDomain, PBase, P, _, _, _, _>::new(
+				multilins.clone(),
+				[("test_composition".into(), composition.clone(), composition.clone())],
+				&challenges,
+				domain_factory.clone(),
+				|_| switchover_rd,
+				&backend,
+			)
+			.unwrap()
+			.with_round_eval_strategy(strategy)
+			.into_regular_zerocheck()
+			.unwrap();
+
+			let output = batch_prove(vec![prover], &mut prove_transcript).unwrap();
+			(output, prove_transcript.finalize())
+		});
+
+		let (reference, rest) = outputs.split_last().unwrap();
+		for (output, transcript) in rest {
+			assert_eq!(output.challenges, reference.0.challenges);
+			assert_eq!(output.multilinear_evals, reference.0.multilinear_evals);
+			assert_eq!(transcript, &reference.1);
+		}
+	}
+
 	fn test_prove_verify_product_constraint_helper(
 		n_vars: usize,
 		n_multilinears: usize,
@@ -419,6 +481,40 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_validate_witness_reports_first_failure() {
+		type P = PackedBinaryField1x128b;
+		let n_vars = 8;
+
+		// The product of the two multilinears is non-zero at vertices 37 and 200 only.
+		let ones = vec![P::one(); 1 << n_vars];
+		let sparse = (0..1 << n_vars)
+			.map(|i| {
+				if i == 37 || i == 200 {
+					P::one()
+				} else {
+					P::zero()
+				}
+			})
+			.collect();
+		let multilins = [ones, sparse].map(|values| {
+			MultilinearExtension::from_values(values)
+				.unwrap()
+				.specialize::<P>()
+		});
+
+		let binding = [
+			("first".into(), TestProductComposition::new(2)),
+			("second".into(), TestProductComposition::new(2)),
+		];
+		let err = zerocheck::validate_witness(&multilins, &binding).unwrap_err();
+		assert_matches!(
+			err,
+			Error::ZerocheckNaiveValidationFailure { composition_name, vertex_index: 37 }
+				if composition_name == "first"
+		);
+	}
+
 	#[test]
 	fn test_compare_zerocheck_prover_to_regular_sumcheck() {
 		for n_vars in 2..8 {
@@ -430,6 +526,48 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_round_eval_strategies_agree() {
+		for n_vars in 2..8 {
+			for n_multilinears in 1..5 {
+				for switchover_rd in 1..=n_vars / 2 {
+					test_round_eval_strategies_agree_helper(
+						n_vars,
+						n_multilinears,
+						switchover_rd,
+						TestProductComposition::new(n_multilinears),
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_round_eval_strategies_agree_on_mixed_expression() {
+		// Sums, constants and powers take different paths through the line expansion than a plain
+		// product does. The expression vanishes wherever the product of the variables does.
+		for n_vars in 2..8 {
+			for n_multilinears in 1..5 {
+				let product = (0..n_multilinears)
+					.map(ArithExpr::Var)
+					.reduce(|acc, var| acc * var)
+					.unwrap();
+				let expr = product.clone()
+					* (ArithExpr::Var(0).pow(3) + ArithExpr::Const(BinaryField32b::new(7)))
+					+ product.pow(2);
+				let composition = ArithCircuitPoly::with_n_vars(n_multilinears, expr).unwrap();
+				for switchover_rd in 1..=n_vars / 2 {
+					test_round_eval_strategies_agree_helper(
+						n_vars,
+						n_multilinears,
+						switchover_rd,
+						composition.clone(),
+					);
+				}
+			}
+		}
+	}
+
 	#[test]
 	fn test_prove_verify_product_basic() {
 		for n_vars in 2..8 {
@@ -14,9 +14,8 @@ use std::marker::PhantomData;
 
 use binius_field::{BinaryField, PackedField};
 use binius_ntt::{AdditiveNTT, DynamicDispatchNTT, Error, NTTOptions, ThreadingSettings};
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use getset::CopyGetters;
-use rayon::prelude::*;
 
 use crate::linear_code::LinearCode;
 
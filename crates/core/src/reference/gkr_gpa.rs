@@ -0,0 +1,133 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::cmp::Reverse;
+
+use binius_field::{Field, TowerField};
+use binius_math::ArithExpr;
+use binius_utils::bail;
+
+use super::{eq_ind, verify_sumcheck, Error, SumcheckClaim, SumcheckOutput};
+use crate::{fiat_shamir::CanSample, transcript::CanRead};
+
+/// A claim that the `2^n_vars` hypercube evaluations of a multilinear multiply to `product`.
+#[derive(Debug, Clone, Copy)]
+pub struct ProductClaim<F: Field> {
+	pub n_vars: usize,
+	pub product: F,
+}
+
+/// A claim that a multilinear evaluates to `eval` at `point`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalClaim<F: Field> {
+	pub point: Vec<F>,
+	pub eval: F,
+}
+
+/// Verifies a batch of GKR grand product arguments, reducing every product claim to an evaluation
+/// claim on its multilinear.
+///
+/// The product of a multilinear on `n` variables is computed by a binary tree of multiplications
+/// with `n + 1` layers, where layer `k` has `2^k` values and layer `n` holds the hypercube
+/// evaluations of the multilinear. Value `x` of layer `k` is the product of the values `(x, 0)` and
+/// `(x, 1)` of layer `k + 1`, whose last variable selects between the two halves of the layer. A
+/// claim that the multilinear extension `V_k` of layer `k` evaluates to `v` at `r` is thus the
+/// sumcheck claim
+///
+/// `v = sum_x eq(r, x) * V_{k+1}(x, 0) * V_{k+1}(x, 1)`.
+///
+/// The claim on layer 0 is the claimed product. The claims on layer `k` of all products with more
+/// than `k` variables share their point and are reduced with one batched sumcheck, whose output
+/// are evaluations of `V_{k+1}(., 0)`, `V_{k+1}(., 1)` and the equality indicator at the sumcheck
+/// challenges `r'`. The verifier checks the latter itself, samples `mu` and continues with the
+/// claims `V_{k+1}(r', mu)`, extrapolated on the line through the two evaluations. The claim on
+/// layer `n` is the returned evaluation claim.
+pub fn verify_grand_products<F, Transcript>(
+	claims: &[ProductClaim<F>],
+	transcript: &mut Transcript,
+) -> Result<Vec<EvalClaim<F>>, Error>
+where
+	F: TowerField,
+	Transcript: CanSample<F> + CanRead,
+{
+	// Claims are processed in descending order by number of variables, the stable order for ties.
+	let mut order = (0..claims.len()).collect::<Vec<_>>();
+	order.sort_by_key(|&index| Reverse(claims[index].n_vars));
+	let max_n_vars = order.first().map_or(0, |&index| claims[index].n_vars);
+
+	let mut layer_claims = order
+		.iter()
+		.map(|&index| EvalClaim {
+			point: Vec::new(),
+			eval: claims[index].product,
+		})
+		.collect::<Vec<_>>();
+	let mut eval_claims = vec![None; claims.len()];
+	for layer in 0..=max_n_vars {
+		// Products on `layer` variables have reached their input layer.
+		while let Some(&index) = layer_claims.len().checked_sub(1).map(|last| &order[last]) {
+			if claims[index].n_vars != layer {
+				break;
+			}
+			eval_claims[index] = layer_claims.pop();
+		}
+		if layer_claims.is_empty() {
+			break;
+		}
+		layer_claims = reduce_layer(layer, layer_claims, transcript)?;
+	}
+
+	Ok(eval_claims
+		.into_iter()
+		.map(|claim| claim.expect("every claim reaches its input layer"))
+		.collect())
+}
+
+/// Reduces claims on layer `layer` sharing one point to claims on the next layer.
+fn reduce_layer<F, Transcript>(
+	layer: usize,
+	claims: Vec<EvalClaim<F>>,
+	transcript: &mut Transcript,
+) -> Result<Vec<EvalClaim<F>>, Error>
+where
+	F: TowerField,
+	Transcript: CanSample<F> + CanRead,
+{
+	// The multilinears of the sumcheck are the two halves of every next layer, followed by the
+	// equality indicator.
+	let n_multilinears = 2 * claims.len() + 1;
+	let eq_ind_index = n_multilinears - 1;
+	let sumcheck_claim = SumcheckClaim {
+		n_vars: layer,
+		n_multilinears,
+		composite_sums: claims
+			.iter()
+			.enumerate()
+			.map(|(i, claim)| {
+				let composition = ArithExpr::Var(2 * i)
+					* ArithExpr::Var(2 * i + 1)
+					* ArithExpr::Var(eq_ind_index);
+				(composition, claim.eval)
+			})
+			.collect(),
+	};
+
+	let SumcheckOutput {
+		challenges,
+		multilinear_evals,
+	} = verify_sumcheck(&[sumcheck_claim], transcript)?;
+	let evals = &multilinear_evals[0];
+	let point = &claims.first().expect("claims is not empty").point;
+	if evals[eq_ind_index] != eq_ind(point, &challenges) {
+		bail!(Error::IncorrectEqIndEvaluation);
+	}
+
+	let mu: F = transcript.sample();
+	let next_point = challenges.into_iter().chain([mu]).collect::<Vec<_>>();
+	Ok(evals[..eq_ind_index]
+		.chunks_exact(2)
+		.map(|halves| EvalClaim {
+			point: next_point.clone(),
+			eval: halves[0] + mu * (halves[1] - halves[0]),
+		})
+		.collect())
+}
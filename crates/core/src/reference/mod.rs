@@ -0,0 +1,83 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A reference verifier written directly from the protocol math.
+//!
+//! The verifiers in [`crate::protocols`] share claim types, composition traits and batching helpers
+//! with the provers and are optimized alongside them. This module verifies the same proofs over
+//! plain field scalars, without packed fields, a computation backend or any prover code, trading
+//! speed for being a literal transcription of the protocols. Proofs are read through the same
+//! Fiat-Shamir transcript, so the reference verifier accepts exactly the proofs the optimized
+//! verifiers accept.
+//!
+//! Tests cross-check both verifiers on the same proofs, and third-party implementations can use
+//! this module as an executable specification. It covers the batched sumcheck protocol and the GKR
+//! grand product argument.
+
+mod gkr_gpa;
+mod sumcheck;
+#[cfg(test)]
+mod tests;
+
+use binius_field::Field;
+use binius_math::ArithExpr;
+pub use gkr_gpa::{verify_grand_products, EvalClaim, ProductClaim};
+pub use sumcheck::{verify_sumcheck, SumcheckClaim, SumcheckOutput};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("claims must be sorted in descending order by number of variables")]
+	ClaimsOutOfOrder,
+	#[error("composition reads variable {index}, but the claim has {n_vars} multilinears")]
+	VariableOutOfRange { index: usize, n_vars: usize },
+	#[error("the multilinear evaluations do not match the final sumcheck claim")]
+	IncorrectBatchEvaluation,
+	#[error("the evaluation of the equality indicator is incorrect")]
+	IncorrectEqIndEvaluation,
+	#[error("transcript error: {0}")]
+	Transcript(#[from] crate::transcript::Error),
+}
+
+/// Evaluates an arithmetic expression at a point.
+fn evaluate_expr<F: Field>(expr: &ArithExpr<F>, point: &[F]) -> Result<F, Error> {
+	let value = match expr {
+		ArithExpr::Const(value) => *value,
+		ArithExpr::Var(index) => *point.get(*index).ok_or(Error::VariableOutOfRange {
+			index: *index,
+			n_vars: point.len(),
+		})?,
+		ArithExpr::Add(x, y) => evaluate_expr(x, point)? + evaluate_expr(y, point)?,
+		ArithExpr::Mul(x, y) => evaluate_expr(x, point)? * evaluate_expr(y, point)?,
+		ArithExpr::Pow(base, exp) => pow(evaluate_expr(base, point)?, *exp),
+	};
+	Ok(value)
+}
+
+/// Computes `base^exp` by square-and-multiply.
+fn pow<F: Field>(base: F, exp: u64) -> F {
+	(0..u64::BITS - exp.leading_zeros())
+		.rev()
+		.fold(F::ONE, |acc, bit| {
+			let acc = acc.square();
+			if exp >> bit & 1 == 1 {
+				acc * base
+			} else {
+				acc
+			}
+		})
+}
+
+/// Evaluates the univariate polynomial with coefficients `coeffs`, lowest degree first, at `x`.
+fn evaluate_univariate<F: Field>(coeffs: &[F], x: F) -> F {
+	coeffs
+		.iter()
+		.rev()
+		.fold(F::ZERO, |acc, &coeff| acc * x + coeff)
+}
+
+/// Evaluates the multilinear extension of the equality indicator, `prod_i x_i y_i + (1 - x_i)(1 - y_i)`.
+fn eq_ind<F: Field>(x: &[F], y: &[F]) -> F {
+	x.iter()
+		.zip(y)
+		.map(|(&x_i, &y_i)| x_i * y_i + (F::ONE - x_i) * (F::ONE - y_i))
+		.product()
+}
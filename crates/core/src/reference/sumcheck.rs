@@ -0,0 +1,129 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{Field, TowerField};
+use binius_math::ArithExpr;
+use binius_utils::bail;
+
+use super::{evaluate_expr, evaluate_univariate, Error};
+use crate::{fiat_shamir::CanSample, transcript::CanRead};
+
+/// A claim on the sums of compositions of multilinears over the boolean hypercube.
+#[derive(Debug, Clone)]
+pub struct SumcheckClaim<F: Field> {
+	pub n_vars: usize,
+	pub n_multilinears: usize,
+	/// Compositions of the multilinears, each with its claimed sum.
+	pub composite_sums: Vec<(ArithExpr<F>, F)>,
+}
+
+impl<F: Field> SumcheckClaim<F> {
+	fn degree(&self) -> usize {
+		self.composite_sums
+			.iter()
+			.map(|(composition, _)| composition.degree())
+			.max()
+			.unwrap_or(0)
+	}
+}
+
+/// The evaluation claims a sumcheck reduces to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumcheckOutput<F: Field> {
+	/// The challenges of all rounds, in round order.
+	pub challenges: Vec<F>,
+	/// The evaluations of the multilinears of each claim. A claim on `n` variables is evaluated at
+	/// the last `n` challenges.
+	pub multilinear_evals: Vec<Vec<F>>,
+}
+
+/// Verifies a batched sumcheck over claims sorted in descending order by number of variables.
+///
+/// In the round with `n` remaining variables, the verifier first samples a batching coefficient
+/// `c` for every claim on `n` variables, adding `c * sum_j c^j s_j` to the running sum, where the
+/// `s_j` are the claimed sums of the claim. The prover then sends the round polynomial `r`, whose
+/// degree is the maximum degree of the batched compositions. Its leading coefficient is omitted,
+/// as it is determined by `r(0) + r(1)` equalling the running sum. The verifier samples the round
+/// challenge `x` and continues with the running sum `r(x)`. Claims on zero variables are batched
+/// after the last round.
+///
+/// Finally, the prover sends the evaluations of the multilinears of every claim at the challenges
+/// and the verifier checks that the batched compositions of these evaluations equal the running
+/// sum.
+pub fn verify_sumcheck<F, Transcript>(
+	claims: &[SumcheckClaim<F>],
+	transcript: &mut Transcript,
+) -> Result<SumcheckOutput<F>, Error>
+where
+	F: TowerField,
+	Transcript: CanSample<F> + CanRead,
+{
+	if claims
+		.windows(2)
+		.any(|pair| pair[0].n_vars < pair[1].n_vars)
+	{
+		bail!(Error::ClaimsOutOfOrder);
+	}
+
+	let n_rounds = claims.first().map_or(0, |claim| claim.n_vars);
+	let mut batch_coeffs = Vec::with_capacity(claims.len());
+	let mut sum = F::ZERO;
+	let mut degree = 0;
+	let mut batch_claims = |n_vars: usize, transcript: &mut Transcript, sum: &mut F| {
+		while let Some(claim) = claims.get(batch_coeffs.len()) {
+			if claim.n_vars != n_vars {
+				break;
+			}
+			let coeff: F = transcript.sample();
+			*sum += coeff * weighted_sum(coeff, claim.composite_sums.iter().map(|&(_, s)| s));
+			degree = degree.max(claim.degree());
+			batch_coeffs.push(coeff);
+		}
+		degree
+	};
+
+	let mut challenges = Vec::with_capacity(n_rounds);
+	for round in 0..n_rounds {
+		let degree = batch_claims(n_rounds - round, transcript, &mut sum);
+
+		let mut coeffs = transcript.read_scalar_slice::<F>(degree)?;
+		let leading_coeff =
+			sum - coeffs.first().copied().unwrap_or(F::ZERO) - coeffs.iter().copied().sum::<F>();
+		coeffs.push(leading_coeff);
+
+		let challenge = transcript.sample();
+		sum = evaluate_univariate(&coeffs, challenge);
+		challenges.push(challenge);
+	}
+	batch_claims(0, transcript, &mut sum);
+
+	let multilinear_evals = claims
+		.iter()
+		.map(|claim| transcript.read_scalar_slice::<F>(claim.n_multilinears))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let mut expected_sum = F::ZERO;
+	for ((claim, &coeff), evals) in claims.iter().zip(&batch_coeffs).zip(&multilinear_evals) {
+		let composite_evals = claim
+			.composite_sums
+			.iter()
+			.map(|(composition, _)| evaluate_expr(composition, evals))
+			.collect::<Result<Vec<_>, _>>()?;
+		expected_sum += coeff * weighted_sum(coeff, composite_evals);
+	}
+	if sum != expected_sum {
+		bail!(Error::IncorrectBatchEvaluation);
+	}
+
+	Ok(SumcheckOutput {
+		challenges,
+		multilinear_evals,
+	})
+}
+
+/// Computes `sum_j c^j v_j`.
+fn weighted_sum<F: Field>(coeff: F, values: impl IntoIterator<Item = F>) -> F {
+	values
+		.into_iter()
+		.fold((F::ZERO, F::ONE), |(sum, power), value| (sum + power * value, power * coeff))
+		.0
+}
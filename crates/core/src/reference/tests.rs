@@ -0,0 +1,185 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	arch::OptimalUnderlier128b, as_packed_field::PackedType, BinaryField128b, BinaryField8b, Field,
+	PackedField,
+};
+use binius_hal::make_portable_backend;
+use binius_math::{
+	ArithExpr, IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension,
+};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, SeedableRng};
+
+use super::{verify_grand_products, verify_sumcheck, Error, ProductClaim, SumcheckClaim};
+use crate::{
+	fiat_shamir::HasherChallenger,
+	protocols::{
+		gkr_gpa::{self, GrandProductClaim, GrandProductWitness},
+		sumcheck::{self, prove::RegularSumcheckProver, CompositeSumClaim},
+		test_utils::TestProductComposition,
+	},
+	transcript::{TranscriptReader, TranscriptWriter},
+};
+
+type F = BinaryField128b;
+type P = PackedType<OptimalUnderlier128b, F>;
+type Challenger = HasherChallenger<Groestl256>;
+
+fn random_multilinear(rng: &mut StdRng, n_vars: usize) -> (MultilinearExtension<P>, Vec<F>) {
+	let values = repeat_with(|| <F as Field>::random(&mut *rng))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+	let multilinear =
+		MultilinearExtension::from_values(values.iter().copied().map(P::broadcast).collect())
+			.unwrap();
+	(multilinear, values)
+}
+
+/// Flips a bit of every transcript byte in turn, checking that the reference verifier rejects all
+/// proofs the optimized verifier rejects.
+fn check_tampered_transcripts(
+	transcript: &[u8],
+	optimized: impl Fn(&mut TranscriptReader<Challenger>) -> bool,
+	reference: impl Fn(&mut TranscriptReader<Challenger>) -> bool,
+) {
+	for i in 0..transcript.len() {
+		let mut tampered = transcript.to_vec();
+		tampered[i] ^= 1;
+		let accepts = |verify: &dyn Fn(&mut TranscriptReader<Challenger>) -> bool| {
+			let mut reader = TranscriptReader::<Challenger>::new(tampered.clone());
+			verify(&mut reader) && reader.finalize().is_ok()
+		};
+		assert_eq!(accepts(&optimized), accepts(&reference), "tampered byte {i}");
+	}
+}
+
+#[test]
+fn test_reference_sumcheck_matches_optimized_verifier() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (n_vars, n_multilinears) = (4, 3);
+	let (multilinears, values): (Vec<_>, Vec<_>) =
+		repeat_with(|| random_multilinear(&mut rng, n_vars))
+			.take(n_multilinears)
+			.unzip();
+	let sum = (0..1 << n_vars)
+		.map(|x| values.iter().map(|values| values[x]).product::<F>())
+		.sum::<F>();
+
+	let composition = TestProductComposition::new(n_multilinears);
+	let backend = make_portable_backend();
+	let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+		multilinears
+			.into_iter()
+			.map(MLEDirectAdapter::from)
+			.collect(),
+		[CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+		IsomorphicEvaluationDomainFactory::<BinaryField8b>::default(),
+		|_| 1,
+		&backend,
+	)
+	.unwrap();
+	let mut prover_transcript = TranscriptWriter::<Challenger>::default();
+	sumcheck::batch_prove(vec![prover], &mut prover_transcript).unwrap();
+	let transcript = prover_transcript.finalize();
+
+	let optimized_claim = sumcheck::SumcheckClaim::new(
+		n_vars,
+		n_multilinears,
+		vec![CompositeSumClaim {
+			composition: &composition,
+			sum,
+		}],
+	)
+	.unwrap();
+	let reference_claim = SumcheckClaim {
+		n_vars,
+		n_multilinears,
+		composite_sums: vec![(ArithExpr::Var(0) * ArithExpr::Var(1) * ArithExpr::Var(2), sum)],
+	};
+
+	let mut reader = TranscriptReader::<Challenger>::new(transcript.clone());
+	let optimized = sumcheck::batch_verify(&[optimized_claim.clone()], &mut reader).unwrap();
+	reader.finalize().unwrap();
+	let mut reader = TranscriptReader::<Challenger>::new(transcript.clone());
+	let reference = verify_sumcheck(&[reference_claim.clone()], &mut reader).unwrap();
+	reader.finalize().unwrap();
+	assert_eq!(reference.challenges, optimized.challenges);
+	assert_eq!(reference.multilinear_evals, optimized.multilinear_evals);
+
+	check_tampered_transcripts(
+		&transcript,
+		|reader| sumcheck::batch_verify(&[optimized_claim.clone()], reader).is_ok(),
+		|reader| verify_sumcheck(&[reference_claim.clone()], reader).is_ok(),
+	);
+
+	// A wrong claimed sum is rejected.
+	let wrong_claim = SumcheckClaim {
+		composite_sums: vec![(reference_claim.composite_sums[0].0.clone(), sum + F::ONE)],
+		..reference_claim
+	};
+	let mut reader = TranscriptReader::<Challenger>::new(transcript);
+	assert!(matches!(
+		verify_sumcheck(&[wrong_claim], &mut reader),
+		Err(Error::IncorrectBatchEvaluation)
+	));
+}
+
+#[test]
+fn test_reference_grand_products_match_optimized_verifier() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (witnesses, claims): (Vec<_>, Vec<_>) = [3, 5, 3, 4]
+		.into_iter()
+		.map(|n_vars| {
+			let (multilinear, values) = random_multilinear(&mut rng, n_vars);
+			let witness = GrandProductWitness::new(multilinear.specialize_arc_dyn::<P>()).unwrap();
+			let claim = GrandProductClaim {
+				n_vars,
+				product: values.into_iter().product(),
+			};
+			(witness, claim)
+		})
+		.unzip();
+
+	let mut prover_transcript = TranscriptWriter::<Challenger>::default();
+	gkr_gpa::batch_prove::<_, _, BinaryField8b, _, _>(
+		witnesses,
+		&claims,
+		IsomorphicEvaluationDomainFactory::<BinaryField8b>::default(),
+		&mut prover_transcript,
+		&make_portable_backend(),
+	)
+	.unwrap();
+	let transcript = prover_transcript.finalize();
+
+	let reference_claims = claims
+		.iter()
+		.map(|claim| ProductClaim {
+			n_vars: claim.n_vars,
+			product: claim.product,
+		})
+		.collect::<Vec<_>>();
+
+	let mut reader = TranscriptReader::<Challenger>::new(transcript.clone());
+	let optimized = gkr_gpa::batch_verify(claims.clone(), &mut reader).unwrap();
+	reader.finalize().unwrap();
+	let mut reader = TranscriptReader::<Challenger>::new(transcript.clone());
+	let reference = verify_grand_products(&reference_claims, &mut reader).unwrap();
+	reader.finalize().unwrap();
+	assert_eq!(reference.len(), optimized.len());
+	for (reference, optimized) in reference.iter().zip(&optimized) {
+		assert_eq!(reference.point, optimized.eval_point);
+		assert_eq!(reference.eval, optimized.eval);
+	}
+
+	check_tampered_transcripts(
+		&transcript,
+		|reader| gkr_gpa::batch_verify(claims.clone(), reader).is_ok(),
+		|reader| verify_grand_products(&reference_claims, reader).is_ok(),
+	);
+}
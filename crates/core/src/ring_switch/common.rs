@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::sync::Arc;
+use std::{iter, sync::Arc};
 
 use binius_field::{Field, TowerField};
 use binius_utils::sparse_index::SparseIndex;
@@ -46,6 +46,20 @@ pub struct PIOPSumcheckClaimDesc<'a, F: Field> {
 	pub eval_claim: &'a EvalcheckMultilinearClaim<F>,
 }
 
+/// An opening of a committed multilinear at its evaluation claims that share a point prefix.
+///
+/// The reduction only checks the sums of the row-batched evaluations by prefix, so these claims are
+/// reduced to a single PIOP sumcheck claim against the sum of their transparents.
+#[derive(Debug)]
+pub struct OpeningDesc {
+	/// Index of the committed multilinear, referencing the commit metadata.
+	pub committed_idx: usize,
+	/// Index of the prefix descriptor, referencing the slice in an [`EvalClaimSystem`].
+	pub prefix_desc_idx: usize,
+	/// Number of variables of the packed multilinear.
+	pub n_vars: usize,
+}
+
 /// A system of relations required to verify multilinear evaluation claims using the batched
 /// FRI-Binius protocol.
 ///
@@ -57,6 +71,8 @@ pub struct EvalClaimSystem<'a, F: Field> {
 	pub suffix_descs: Vec<EvalClaimSuffixDesc<F>>,
 	pub sumcheck_claim_descs: Vec<PIOPSumcheckClaimDesc<'a, F>>,
 	pub eval_claim_to_prefix_desc_index: Vec<usize>,
+	pub opening_descs: Vec<OpeningDesc>,
+	pub eval_claim_to_opening_desc_index: Vec<usize>,
 }
 
 impl<'a, F: TowerField> EvalClaimSystem<'a, F> {
@@ -122,12 +138,20 @@ impl<'a, F: TowerField> EvalClaimSystem<'a, F> {
 			})
 			.collect::<Result<Vec<_>, _>>()?;
 
+		let (opening_descs, eval_claim_to_opening_desc_index) = group_claims_by_opening(
+			&sumcheck_claim_descs,
+			&suffix_descs,
+			&eval_claim_to_prefix_desc_index,
+		);
+
 		Ok(Self {
 			commit_meta,
 			prefix_descs,
 			suffix_descs,
 			sumcheck_claim_descs,
 			eval_claim_to_prefix_desc_index,
+			opening_descs,
+			eval_claim_to_opening_desc_index,
 		})
 	}
 
@@ -196,3 +220,47 @@ fn group_claims_by_eval_point<F: TowerField>(
 	}
 	Ok((prefix_descs, claim_to_prefix_index, suffix_descs, claim_to_suffix_index))
 }
+
+fn group_claims_by_opening<F: Field>(
+	sumcheck_claim_descs: &[PIOPSumcheckClaimDesc<F>],
+	suffix_descs: &[EvalClaimSuffixDesc<F>],
+	eval_claim_to_prefix_desc_index: &[usize],
+) -> (Vec<OpeningDesc>, Vec<usize>) {
+	let mut opening_descs = Vec::<OpeningDesc>::new();
+	let claim_to_opening_index = iter::zip(sumcheck_claim_descs, eval_claim_to_prefix_desc_index)
+		.map(|(claim_desc, &prefix_desc_idx)| {
+			opening_descs
+				.iter()
+				.position(|desc| {
+					desc.committed_idx == claim_desc.committed_idx
+						&& desc.prefix_desc_idx == prefix_desc_idx
+				})
+				.unwrap_or_else(|| {
+					let index = opening_descs.len();
+					opening_descs.push(OpeningDesc {
+						committed_idx: claim_desc.committed_idx,
+						prefix_desc_idx,
+						n_vars: suffix_descs[claim_desc.suffix_desc_idx].suffix.len(),
+					});
+					index
+				})
+		})
+		.collect();
+	(opening_descs, claim_to_opening_index)
+}
+
+/// Sums evaluations into the buckets given by `indices`.
+pub(super) fn accumulate_evaluations<F: Field>(
+	evals: impl ExactSizeIterator<Item = F>,
+	n_buckets: usize,
+	indices: impl ExactSizeIterator<Item = usize>,
+) -> Vec<F> {
+	// Pre-condition
+	debug_assert_eq!(evals.len(), indices.len());
+
+	let mut batched_evals = vec![F::ZERO; n_buckets];
+	for (eval, index) in iter::zip(evals, indices) {
+		batched_evals[index] += eval;
+	}
+	batched_evals
+}
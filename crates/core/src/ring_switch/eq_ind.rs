@@ -7,9 +7,8 @@ use binius_field::{
 	PackedFieldIndexable, TowerField,
 };
 use binius_math::{tensor_prod_eq_ind, MultilinearExtension};
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use bytemuck::zeroed_vec;
-use rayon::prelude::*;
 
 use super::error::Error;
 use crate::{
@@ -117,6 +116,55 @@ where
 	}
 }
 
+/// The sum of the [`RingSwitchEqInd`] functions of several evaluation claims.
+///
+/// Claims on the same committed multilinear that share an evaluation point prefix are opened
+/// together, against the sum of their transparents.
+#[derive(Debug)]
+pub struct RingSwitchEqIndSum<F: Field> {
+	terms: Vec<Box<dyn MultivariatePoly<F>>>,
+}
+
+impl<F: Field> RingSwitchEqIndSum<F> {
+	pub fn new(terms: Vec<Box<dyn MultivariatePoly<F>>>) -> Result<Self, Error> {
+		let Some(first) = terms.first() else {
+			bail!(Error::InvalidArgs("RingSwitchEqIndSum::new expects at least one term".into()));
+		};
+		if terms.iter().any(|term| term.n_vars() != first.n_vars()) {
+			bail!(Error::InvalidArgs(
+				"RingSwitchEqIndSum::new expects terms with the same number of variables".into()
+			));
+		}
+		Ok(Self { terms })
+	}
+}
+
+impl<F: Field> MultivariatePoly<F> for RingSwitchEqIndSum<F> {
+	fn n_vars(&self) -> usize {
+		self.terms[0].n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.terms
+			.iter()
+			.map(|term| term.degree())
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, PolynomialError> {
+		self.terms.iter().map(|term| term.evaluate(query)).sum()
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.terms
+			.iter()
+			.map(|term| term.binary_tower_level())
+			.max()
+			.unwrap_or(0)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use binius_field::{BinaryField128b, BinaryField8b};
@@ -20,6 +20,13 @@
 //! \sum_{v \in B_{\ell'}} f'(v) t_z(v) = s'.
 //! $$
 //!
+//! Claims that share the prefix $(z_0, ..., z_{\kappa-1})$ of their evaluation points are mixed
+//! with random coefficients into a single partial evaluation. The transparent $t_z$ depends on
+//! the mixing coefficient of its claim, so every claim has its own. Only the sum of the
+//! row-batched evaluations by prefix is checked, though, and hence all claims on one committed
+//! multilinear that share a prefix are opened jointly, with one sumcheck claim against the sum of
+//! their transparents.
+//!
 //! TODO: Improve documentation and link to binius.xyz docs.
 //!
 //! [DP24]: <https://eprint.iacr.org/2024/504>
@@ -1,16 +1,18 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{iter, sync::Arc};
+use std::{
+	iter::{self, repeat_with},
+	sync::Arc,
+};
 
 use binius_field::{PackedField, PackedFieldIndexable, TowerField};
 use binius_hal::{ComputationBackend, ComputationBackendExt};
-use binius_math::{MLEDirectAdapter, MultilinearPoly, MultilinearQuery};
-use binius_utils::checked_arithmetics::log2_ceil_usize;
-use rayon::prelude::*;
+use binius_math::{MLEDirectAdapter, MultilinearExtension, MultilinearPoly, MultilinearQuery};
+use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::prelude::*};
 use tracing::instrument;
 
 use super::{
-	common::{EvalClaimPrefixDesc, EvalClaimSystem, PIOPSumcheckClaimDesc},
+	common::{accumulate_evaluations, EvalClaimPrefixDesc, EvalClaimSystem, PIOPSumcheckClaimDesc},
 	error::Error,
 	tower_tensor_algebra::TowerTensorAlgebra,
 };
@@ -79,27 +81,24 @@ where
 	let row_batch_coeffs =
 		Arc::from(MultilinearQuery::<F, _>::expand(&row_batch_challenges).into_expansion());
 
-	let row_batched_evals =
-		compute_row_batched_sumcheck_evals(scaled_tensor_elems, &row_batch_coeffs);
+	// For each opening, send the sum of the row-batched evaluations of its claims.
+	let row_batched_evals = accumulate_evaluations(
+		compute_row_batched_sumcheck_evals(scaled_tensor_elems, &row_batch_coeffs).into_iter(),
+		system.opening_descs.len(),
+		system.eval_claim_to_opening_desc_index.iter().copied(),
+	);
 	proof.transcript.write_scalar_slice(&row_batched_evals);
 
-	// Create the reduced PIOP sumcheck witnesses.
-	let ring_switch_eq_inds = make_ring_switch_eq_inds::<_, P, Tower>(
-		&system.sumcheck_claim_descs,
-		&system.suffix_descs,
-		row_batch_coeffs,
-		&mixing_coeffs,
-	)?;
-	let sumcheck_claims = iter::zip(&system.sumcheck_claim_descs, row_batched_evals)
+	// Create the reduced PIOP sumcheck witnesses, one per opening.
+	let ring_switch_eq_inds =
+		make_ring_switch_eq_inds::<_, P, Tower>(system, row_batch_coeffs, &mixing_coeffs)?;
+	let sumcheck_claims = iter::zip(&system.opening_descs, row_batched_evals)
 		.enumerate()
-		.map(|(idx, (claim_desc, eval))| {
-			let suffix_desc = &system.suffix_descs[claim_desc.suffix_desc_idx];
-			PIOPSumcheckClaim {
-				n_vars: suffix_desc.suffix.len(),
-				committed: claim_desc.committed_idx,
-				transparent: idx,
-				sum: eval,
-			}
+		.map(|(idx, (opening_desc, eval))| PIOPSumcheckClaim {
+			n_vars: opening_desc.n_vars,
+			committed: opening_desc.committed_idx,
+			transparent: idx,
+			sum: eval,
 		})
 		.collect::<Vec<_>>();
 
@@ -216,8 +215,7 @@ where
 
 #[instrument(skip_all)]
 fn make_ring_switch_eq_inds<F, P, Tower>(
-	sumcheck_claim_descs: &[PIOPSumcheckClaimDesc<F>],
-	suffix_descs: &[EvalClaimSuffixDesc<F>],
+	system: &EvalClaimSystem<F>,
 	row_batch_coeffs: Arc<[F]>,
 	mixing_coeffs: &[F],
 ) -> Result<Vec<MultilinearWitness<'static, P>>, Error>
@@ -227,21 +225,48 @@ where
 	Tower: TowerFamily<B128 = F>,
 	F: PackedTop<Tower>,
 {
-	sumcheck_claim_descs
+	let eq_inds = system
+		.sumcheck_claim_descs
 		.par_iter()
 		.zip(mixing_coeffs)
 		.map(|(claim_desc, &mixing_coeff)| {
-			let suffix_desc = &suffix_descs[claim_desc.suffix_desc_idx];
+			let suffix_desc = &system.suffix_descs[claim_desc.suffix_desc_idx];
 			make_ring_switch_eq_ind::<P, Tower>(suffix_desc, row_batch_coeffs.clone(), mixing_coeff)
 		})
-		.collect()
+		.collect::<Result<Vec<_>, _>>()?;
+
+	// Sum the transparents of the claims in each opening.
+	let mut opening_eq_inds = repeat_with(|| None::<MultilinearExtension<P>>)
+		.take(system.opening_descs.len())
+		.collect::<Vec<_>>();
+	for (eq_ind, &opening_desc_idx) in iter::zip(eq_inds, &system.eval_claim_to_opening_desc_index)
+	{
+		let opening_eq_ind = &mut opening_eq_inds[opening_desc_idx];
+		*opening_eq_ind = Some(match opening_eq_ind.take() {
+			None => eq_ind,
+			Some(sum) => {
+				let evals = iter::zip(sum.evals(), eq_ind.evals())
+					.map(|(&lhs, &rhs)| lhs + rhs)
+					.collect();
+				MultilinearExtension::from_values(evals)?
+			}
+		});
+	}
+
+	Ok(opening_eq_inds
+		.into_iter()
+		.map(|eq_ind| {
+			let eq_ind = eq_ind.expect("every opening has at least one claim");
+			MLEDirectAdapter::from(eq_ind).upcast_arc_dyn()
+		})
+		.collect())
 }
 
 fn make_ring_switch_eq_ind<P, Tower>(
 	suffix_desc: &EvalClaimSuffixDesc<FExt<Tower>>,
 	row_batch_coeffs: Arc<[FExt<Tower>]>,
 	mixing_coeff: FExt<Tower>,
-) -> Result<MultilinearWitness<'static, P>, Error>
+) -> Result<MultilinearExtension<P>, Error>
 where
 	P: PackedFieldIndexable<Scalar = FExt<Tower>>,
 	Tower: TowerFamily,
@@ -287,5 +312,5 @@ where
 			kappa: suffix_desc.kappa,
 		}),
 	}?;
-	Ok(MLEDirectAdapter::from(eq_ind).upcast_arc_dyn())
+	Ok(eq_ind)
 }
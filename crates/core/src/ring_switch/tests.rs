@@ -1,6 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cmp::Ordering, iter::repeat_with};
+use std::{
+	cmp::Ordering,
+	iter::{self, repeat_with},
+};
 
 use binius_field::{
 	arch::OptimalUnderlier128b,
@@ -118,6 +121,10 @@ fn check_eval_point_consistency<F: Field>(system: &EvalClaimSystem<F>) {
 		let prefix_desc = &system.prefix_descs[prefix_desc_idx];
 		let suffix_desc = &system.suffix_descs[claim_desc.suffix_desc_idx];
 		assert_eq!(prefix_desc.kappa(), suffix_desc.kappa);
+		let opening_desc = &system.opening_descs[system.eval_claim_to_opening_desc_index[i]];
+		assert_eq!(opening_desc.committed_idx, claim_desc.committed_idx);
+		assert_eq!(opening_desc.prefix_desc_idx, prefix_desc_idx);
+		assert_eq!(opening_desc.n_vars, suffix_desc.suffix.len());
 		assert_eq!(
 			[prefix_desc.prefix.clone(), suffix_desc.suffix.to_vec()].concat(),
 			system.sumcheck_claim_descs[i]
@@ -129,7 +136,7 @@ fn check_eval_point_consistency<F: Field>(system: &EvalClaimSystem<F>) {
 }
 
 fn setup_test_eval_claims<U, F>(
-	mut rng: impl Rng,
+	mut rng: &mut StdRng,
 	oracles: &MultilinearOracleSet<F>,
 	witness_index: &MultilinearExtensionIndex<U, F>,
 ) -> Vec<EvalcheckMultilinearClaim<F>>
@@ -179,18 +186,24 @@ where
 	eval_claims
 }
 
-fn with_test_instance_from_oracles<U, Tower, R>(
-	mut rng: R,
+type SetupEvalClaims<U, F> = fn(
+	&mut StdRng,
+	&MultilinearOracleSet<F>,
+	&MultilinearExtensionIndex<U, F>,
+) -> Vec<EvalcheckMultilinearClaim<F>>;
+
+fn with_test_instance_from_oracles<U, Tower>(
+	mut rng: StdRng,
 	oracles: &MultilinearOracleSet<Tower::B128>,
+	setup_eval_claims: SetupEvalClaims<U, Tower::B128>,
 	func: impl FnOnce(
-		R,
+		StdRng,
 		EvalClaimSystem<Tower::B128>,
 		Vec<MultilinearWitness<PackedType<U, Tower::B128>>>,
 	),
 ) where
 	U: TowerUnderlier<Tower>,
 	Tower: TowerFamily,
-	R: Rng,
 {
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(oracles).unwrap();
 
@@ -203,7 +216,7 @@ fn with_test_instance_from_oracles<U, Tower, R>(
 	)
 	.unwrap();
 
-	let eval_claims = setup_test_eval_claims(&mut rng, oracles, &witness_index);
+	let eval_claims = setup_eval_claims(&mut rng, oracles, &witness_index);
 
 	// Finish setting up the test case
 	let system = EvalClaimSystem::new(&commit_meta, oracle_to_commit_index, &eval_claims).unwrap();
@@ -232,38 +245,44 @@ fn test_prove_verify_claim_reduction_with_naive_validation() {
 	let rng = StdRng::seed_from_u64(0);
 	let oracles = make_test_oracle_set();
 
-	with_test_instance_from_oracles::<U, Tower, _>(rng, &oracles, |_rng, system, witnesses| {
-		let mut proof = Proof {
-			transcript: TranscriptWriter::<HasherChallenger<Groestl256>>::default(),
-			advice: AdviceWriter::default(),
-		};
-
-		let backend = make_portable_backend();
-		let ReducedWitness {
-			transparents: transparent_witnesses,
-			sumcheck_claims: prover_sumcheck_claims,
-		} = prove::<_, _, _, Tower, _, _, _>(&system, &witnesses, &mut proof, &backend).unwrap();
-
-		let mut proof = proof.into_verifier();
-		let ReducedClaim {
-			transparents: _,
-			sumcheck_claims: verifier_sumcheck_claims,
-		} = verify::<_, Tower, _, _>(&system, &mut proof).unwrap();
-
-		assert_eq!(prover_sumcheck_claims, verifier_sumcheck_claims);
-
-		piop::validate_sumcheck_witness(
-			&witnesses,
-			&transparent_witnesses,
-			&prover_sumcheck_claims,
-		)
-		.unwrap();
-	});
+	with_test_instance_from_oracles::<U, Tower>(
+		rng,
+		&oracles,
+		setup_test_eval_claims,
+		|_rng, system, witnesses| {
+			let mut proof = Proof {
+				transcript: TranscriptWriter::<HasherChallenger<Groestl256>>::default(),
+				advice: AdviceWriter::default(),
+			};
+
+			let backend = make_portable_backend();
+			let ReducedWitness {
+				transparents: transparent_witnesses,
+				sumcheck_claims: prover_sumcheck_claims,
+			} = prove::<_, _, _, Tower, _, _, _>(&system, &witnesses, &mut proof, &backend).unwrap();
+
+			let mut proof = proof.into_verifier();
+			let ReducedClaim {
+				transparents: _,
+				sumcheck_claims: verifier_sumcheck_claims,
+			} = verify::<_, Tower, _, _>(&system, &mut proof).unwrap();
+
+			assert_eq!(prover_sumcheck_claims, verifier_sumcheck_claims);
+
+			piop::validate_sumcheck_witness(
+				&witnesses,
+				&transparent_witnesses,
+				&prover_sumcheck_claims,
+			)
+			.unwrap();
+		},
+	);
 }
 
 fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 	merkle_prover: &MTProver,
 	oracles: &MultilinearOracleSet<FExt<Tower>>,
+	setup_eval_claims: SetupEvalClaims<U, FExt<Tower>>,
 	log_inv_rate: usize,
 ) where
 	U: TowerUnderlier<Tower>,
@@ -301,7 +320,7 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 		codeword,
 	} = piop::commit(&fri_params, merkle_prover, &committed_multilins).unwrap();
 
-	let eval_claims = setup_test_eval_claims(&mut rng, oracles, &witness_index);
+	let eval_claims = setup_eval_claims(&mut rng, oracles, &witness_index);
 
 	// Finish setting up the test case
 	let system = EvalClaimSystem::new(&commit_meta, oracle_to_commit_index, &eval_claims).unwrap();
@@ -311,7 +330,7 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 		transcript: TranscriptWriter::<HasherChallenger<Groestl256>>::default(),
 		advice: AdviceWriter::default(),
 	};
-	proof.transcript.write(&commitment);
+	proof.transcript.write_slice(&commitment);
 
 	let backend = make_portable_backend();
 	let ReducedWitness {
@@ -325,7 +344,7 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 		merkle_prover,
 		domain_factory,
 		&commit_meta,
-		committed,
+		&committed,
 		&codeword,
 		&committed_multilins,
 		&transparent_multilins,
@@ -336,7 +355,7 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 	.unwrap();
 
 	let mut proof = proof.into_verifier();
-	let commitment = proof.transcript.read().unwrap();
+	let commitment = proof.transcript.read_vec(commitment.len()).unwrap();
 
 	let ReducedClaim {
 		transparents,
@@ -364,5 +383,109 @@ fn test_prove_verify_piop_integration() {
 	let log_inv_rate = 2;
 	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
 
-	commit_prove_verify_piop::<U, Tower, _, _>(&merkle_prover, &oracles, log_inv_rate);
+	commit_prove_verify_piop::<U, Tower, _, _>(
+		&merkle_prover,
+		&oracles,
+		setup_test_eval_claims,
+		log_inv_rate,
+	);
+}
+
+fn setup_shared_prefix_eval_claims<U, F>(
+	rng: &mut StdRng,
+	oracles: &MultilinearOracleSet<F>,
+	witness_index: &MultilinearExtensionIndex<U, F>,
+) -> Vec<EvalcheckMultilinearClaim<F>>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	// Every committed oracle is claimed at two points that differ only in their suffixes, like
+	// the projections of one oracle onto different values of its high variables.
+	let n_vars = oracles.iter().map(|oracle| oracle.n_vars()).max().unwrap();
+	let prefix = random_eval_point::<F>(&mut *rng, 2);
+	let suffixes = repeat_with(|| random_eval_point::<F>(&mut *rng, n_vars - 2))
+		.take(2)
+		.collect::<Vec<_>>();
+
+	oracles
+		.iter()
+		.flat_map(|oracle| {
+			suffixes
+				.iter()
+				.map(|suffix| [prefix.as_slice(), suffix].concat())
+				.map(move |eval_point| make_eval_claim(&oracle, eval_point, witness_index))
+				.collect::<Vec<_>>()
+		})
+		.collect()
+}
+
+#[test]
+fn test_claims_sharing_eval_point_prefix_open_jointly() {
+	type U = OptimalUnderlier128b;
+	type Tower = CanonicalTowerFamily;
+
+	let rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::new();
+	oracles.add_committed(10, 5);
+	oracles.add_committed(10, 5);
+
+	with_test_instance_from_oracles::<U, Tower>(
+		rng,
+		&oracles,
+		setup_shared_prefix_eval_claims,
+		|_rng, system, witnesses| {
+			// Four claims, but one opening per oracle.
+			assert_eq!(system.sumcheck_claim_descs.len(), 4);
+			assert_eq!(system.prefix_descs.len(), 1);
+			assert_eq!(system.opening_descs.len(), 2);
+
+			let mut proof = Proof {
+				transcript: TranscriptWriter::<HasherChallenger<Groestl256>>::default(),
+				advice: AdviceWriter::default(),
+			};
+
+			let backend = make_portable_backend();
+			let ReducedWitness {
+				transparents: transparent_witnesses,
+				sumcheck_claims: prover_sumcheck_claims,
+			} = prove::<_, _, _, Tower, _, _, _>(&system, &witnesses, &mut proof, &backend).unwrap();
+			assert_eq!(prover_sumcheck_claims.len(), 2);
+			assert_eq!(transparent_witnesses.len(), 2);
+
+			let mut proof = proof.into_verifier();
+			let ReducedClaim {
+				transparents,
+				sumcheck_claims: verifier_sumcheck_claims,
+			} = verify::<_, Tower, _, _>(&system, &mut proof).unwrap();
+			assert_eq!(transparents.len(), 2);
+			assert_eq!(prover_sumcheck_claims, verifier_sumcheck_claims);
+
+			// The verifier's summed transparents agree with the prover's.
+			let mut rng = StdRng::seed_from_u64(1);
+			for (transparent, witness) in iter::zip(&transparents, &transparent_witnesses) {
+				let point = random_eval_point(&mut rng, transparent.n_vars());
+				let query = MultilinearQuery::expand(&point);
+				assert_eq!(
+					transparent.evaluate(&point).unwrap(),
+					witness.evaluate(query.to_ref()).unwrap()
+				);
+			}
+
+			piop::validate_sumcheck_witness(
+				&witnesses,
+				&transparent_witnesses,
+				&prover_sumcheck_claims,
+			)
+			.unwrap();
+		},
+	);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	commit_prove_verify_piop::<U, Tower, _, _>(
+		&merkle_prover,
+		&oracles,
+		setup_shared_prefix_eval_claims,
+		2,
+	);
 }
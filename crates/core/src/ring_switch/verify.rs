@@ -1,6 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{iter, sync::Arc};
+use std::{
+	iter::{self, repeat_with},
+	sync::Arc,
+};
 
 use binius_field::{Field, TowerField};
 use binius_math::{MultilinearExtension, MultilinearQuery};
@@ -12,8 +15,10 @@ use crate::{
 	piop::PIOPSumcheckClaim,
 	polynomial::MultivariatePoly,
 	ring_switch::{
-		eq_ind::RingSwitchEqInd, tower_tensor_algebra::TowerTensorAlgebra, Error,
-		EvalClaimSuffixDesc, EvalClaimSystem, PIOPSumcheckClaimDesc, VerificationError,
+		common::accumulate_evaluations,
+		eq_ind::{RingSwitchEqInd, RingSwitchEqIndSum},
+		tower_tensor_algebra::TowerTensorAlgebra,
+		Error, EvalClaimSuffixDesc, EvalClaimSystem, VerificationError,
 	},
 	tower::{PackedTop, TowerFamily},
 	transcript::{CanRead, Proof},
@@ -52,17 +57,17 @@ where
 	let row_batch_coeffs =
 		Arc::from(MultilinearQuery::<F, _>::expand(&row_batch_challenges).into_expansion());
 
-	// For each original evaluation claim, receive the row-batched evaluation claim.
+	// For each opening, receive the row-batched evaluation claim.
 	let row_batched_evals = proof
 		.transcript
-		.read_scalar_slice(system.sumcheck_claim_descs.len())?;
+		.read_scalar_slice(system.opening_descs.len())?;
 
 	// Check that the row-batched evaluation claims sent by the prover are consistent with the
 	// tensor algebra sum elements previously sent.
-	let mixed_row_batched_evals = accumulate_evaluations_by_prefixes(
+	let mixed_row_batched_evals = accumulate_evaluations(
 		row_batched_evals.iter().copied(),
 		system.prefix_descs.len(),
-		&system.eval_claim_to_prefix_desc_index,
+		system.opening_descs.iter().map(|desc| desc.prefix_desc_idx),
 	);
 	for (expected, tensor_elem) in iter::zip(mixed_row_batched_evals, tensor_elems) {
 		if tensor_elem.fold_vertical(&row_batch_coeffs) != expected {
@@ -70,23 +75,16 @@ where
 		}
 	}
 
-	// Create the reduced PIOP sumcheck claims.
-	let ring_switch_eq_inds = make_ring_switch_eq_inds::<_, Tower>(
-		&system.sumcheck_claim_descs,
-		&system.suffix_descs,
-		row_batch_coeffs,
-		&mixing_coeffs,
-	)?;
-	let sumcheck_claims = iter::zip(&system.sumcheck_claim_descs, row_batched_evals)
+	// Create the reduced PIOP sumcheck claims, one per opening.
+	let ring_switch_eq_inds =
+		make_ring_switch_eq_inds::<_, Tower>(system, row_batch_coeffs, &mixing_coeffs)?;
+	let sumcheck_claims = iter::zip(&system.opening_descs, row_batched_evals)
 		.enumerate()
-		.map(|(idx, (claim_desc, eval))| {
-			let suffix_desc = &system.suffix_descs[claim_desc.suffix_desc_idx];
-			PIOPSumcheckClaim {
-				n_vars: suffix_desc.suffix.len(),
-				committed: claim_desc.committed_idx,
-				transparent: idx,
-				sum: eval,
-			}
+		.map(|(idx, (opening_desc, eval))| PIOPSumcheckClaim {
+			n_vars: opening_desc.n_vars,
+			committed: opening_desc.committed_idx,
+			transparent: idx,
+			sum: eval,
 		})
 		.collect::<Vec<_>>();
 
@@ -148,31 +146,15 @@ fn compute_mixed_evaluations<F: TowerField>(
 	// Pre-condition
 	debug_assert!(evals.len() <= mixing_coeffs.len());
 
-	accumulate_evaluations_by_prefixes(
+	accumulate_evaluations(
 		iter::zip(evals, mixing_coeffs).map(|(eval, &mixing_coeff)| eval * mixing_coeff),
 		n_prefixes,
-		eval_claim_to_prefix_desc_index,
+		eval_claim_to_prefix_desc_index.iter().copied(),
 	)
 }
 
-fn accumulate_evaluations_by_prefixes<F: TowerField>(
-	evals: impl ExactSizeIterator<Item = F>,
-	n_prefixes: usize,
-	eval_claim_to_prefix_desc_index: &[usize],
-) -> Vec<F> {
-	// Pre-condition
-	debug_assert_eq!(evals.len(), eval_claim_to_prefix_desc_index.len());
-
-	let mut batched_evals = vec![F::ZERO; n_prefixes];
-	for (eval, &desc_index) in izip!(evals, eval_claim_to_prefix_desc_index) {
-		batched_evals[desc_index] += eval;
-	}
-	batched_evals
-}
-
 fn make_ring_switch_eq_inds<F, Tower>(
-	sumcheck_claim_descs: &[PIOPSumcheckClaimDesc<F>],
-	suffix_descs: &[EvalClaimSuffixDesc<F>],
+	system: &EvalClaimSystem<F>,
 	row_batch_coeffs: Arc<[F]>,
 	mixing_coeffs: &[F],
 ) -> Result<Vec<Box<dyn MultivariatePoly<F>>>, Error>
@@ -181,10 +163,28 @@ where
 	Tower: TowerFamily<B128 = F>,
 	F: PackedTop<Tower>,
 {
-	iter::zip(sumcheck_claim_descs, mixing_coeffs)
-		.map(|(claim_desc, &mixing_coeff)| {
-			let suffix_desc = &suffix_descs[claim_desc.suffix_desc_idx];
-			make_ring_switch_eq_ind::<Tower>(suffix_desc, row_batch_coeffs.clone(), mixing_coeff)
+	let mut opening_eq_inds = repeat_with(Vec::new)
+		.take(system.opening_descs.len())
+		.collect::<Vec<_>>();
+	for (claim_desc, &opening_desc_idx, &mixing_coeff) in
+		izip!(&system.sumcheck_claim_descs, &system.eval_claim_to_opening_desc_index, mixing_coeffs)
+	{
+		let suffix_desc = &system.suffix_descs[claim_desc.suffix_desc_idx];
+		opening_eq_inds[opening_desc_idx].push(make_ring_switch_eq_ind::<Tower>(
+			suffix_desc,
+			row_batch_coeffs.clone(),
+			mixing_coeff,
+		)?);
+	}
+
+	opening_eq_inds
+		.into_iter()
+		.map(|mut eq_inds| {
+			if eq_inds.len() == 1 {
+				Ok(eq_inds.pop().expect("eq_inds has length 1"))
+			} else {
+				Ok(Box::new(RingSwitchEqIndSum::new(eq_inds)?) as Box<dyn MultivariatePoly<_>>)
+			}
 		})
 		.collect()
 }
@@ -0,0 +1,194 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::ops::Range;
+
+use bytes::{Buf, Bytes};
+
+use super::{AdviceReader, AdviceWriter, CanRead, Error, ScalarEncoding};
+
+/// A typed section of the advice tape.
+///
+/// Verifiers mark the start of every section they read with [`super::CanRead::begin_section`], so
+/// that [`AdviceReader::layout`] can describe the advice tape of a proof after verification. A
+/// section spans all bytes read until the start of the next section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AdviceSection {
+	/// The number of evalcheck proofs for the initial evaluation claims of greedy evalcheck.
+	EvalcheckProofCount,
+	/// The number of batches of virtual opening sumchecks of greedy evalcheck.
+	VirtualOpeningRoundCount,
+	/// The codeword of the final FRI oracle.
+	FriTerminateCodeword,
+	/// The Merkle tree layers that the FRI query openings are verified against.
+	FriLayers,
+	/// The coset openings and Merkle branches of the FRI test query with the given position.
+	FriQuery(usize),
+}
+
+/// The sections of an advice tape, as read by a verifier.
+///
+/// Section contents are slices of the advice bytes, so they are accessed without copying.
+#[derive(Debug, Clone)]
+pub struct AdviceLayout {
+	data: Bytes,
+	encoding: ScalarEncoding,
+	sections: Vec<(AdviceSection, Range<usize>)>,
+}
+
+impl AdviceLayout {
+	/// The advice bytes that the sections refer to.
+	pub fn bytes(&self) -> &[u8] {
+		&self.data
+	}
+
+	/// The sections with their byte ranges, in the order they were read.
+	pub fn sections(&self) -> &[(AdviceSection, Range<usize>)] {
+		&self.sections
+	}
+
+	/// The bytes of the first occurrence of a section.
+	pub fn get(&self, section: AdviceSection) -> Option<&[u8]> {
+		self.range(section).map(|range| &self.data[range])
+	}
+
+	/// A reader over the bytes of the first occurrence of a section, which reads field elements
+	/// with the encoding of the advice tape.
+	pub fn reader(&self, section: AdviceSection) -> Option<AdviceReader> {
+		self.range(section).map(|range| {
+			let data = self.data.slice(range);
+			AdviceReader {
+				buffer: data.clone(),
+				data,
+				encoding: self.encoding,
+				sections: Vec::new(),
+			}
+		})
+	}
+
+	/// The value of a count section, such as [`AdviceSection::EvalcheckProofCount`].
+	pub fn count(&self, section: AdviceSection) -> Result<Option<usize>, Error> {
+		self.reader(section)
+			.map(|mut reader| {
+				let count = reader.read_count(section)?;
+				reader.finalize()?;
+				Ok(count)
+			})
+			.transpose()
+	}
+
+	fn range(&self, section: AdviceSection) -> Option<Range<usize>> {
+		self.sections
+			.iter()
+			.find(|(other, _)| *other == section)
+			.map(|(_, range)| range.clone())
+	}
+}
+
+impl AdviceReader {
+	/// The number of bytes read so far.
+	pub fn position(&self) -> usize {
+		self.data.len() - self.buffer.remaining()
+	}
+
+	/// Reads a count written with [`AdviceWriter::write_count`] as its own section.
+	pub fn read_count(&mut self, section: AdviceSection) -> Result<usize, Error> {
+		self.begin_section(section);
+		let mut as_bytes = [0; size_of::<u64>()];
+		if self.buffer.remaining() < as_bytes.len() {
+			return Err(Error::NotEnoughBytes);
+		}
+		self.buffer.copy_to_slice(&mut as_bytes);
+		let count = u64::from_le_bytes(as_bytes);
+		usize::try_from(count).map_err(|_| Error::CountOverflow { count })
+	}
+
+	/// The sections read so far, where the last section extends to the current position.
+	pub fn layout(&self) -> AdviceLayout {
+		let ends = self
+			.sections
+			.iter()
+			.skip(1)
+			.map(|&(_, start)| start)
+			.chain([self.position()]);
+		let sections = self
+			.sections
+			.iter()
+			.zip(ends)
+			.map(|(&(section, start), end)| (section, start..end))
+			.collect();
+		AdviceLayout {
+			data: self.data.clone(),
+			encoding: self.encoding,
+			sections,
+		}
+	}
+}
+
+impl AdviceWriter {
+	/// Writes a count as a little-endian `u64`.
+	pub fn write_count(&mut self, count: usize) {
+		super::write_u64(self, count as u64);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField64b;
+
+	use super::*;
+	use crate::transcript::CanWrite;
+
+	#[test]
+	fn test_advice_layout() {
+		let scalars = [BinaryField64b::new(3), BinaryField64b::new(5)];
+		let mut writer = AdviceWriter::new().with_scalar_encoding(ScalarEncoding::Compact);
+		writer.write_count(2);
+		writer.write_scalar_slice(&scalars);
+		writer.write_count(7);
+
+		let mut reader = writer.into_reader();
+		assert_eq!(
+			reader
+				.read_count(AdviceSection::EvalcheckProofCount)
+				.unwrap(),
+			2
+		);
+		reader.begin_section(AdviceSection::FriTerminateCodeword);
+		assert_eq!(reader.read_scalar_slice::<BinaryField64b>(2).unwrap(), scalars);
+		assert_eq!(
+			reader
+				.read_count(AdviceSection::VirtualOpeningRoundCount)
+				.unwrap(),
+			7
+		);
+
+		let layout = reader.layout();
+		reader.finalize().unwrap();
+
+		let sections = layout
+			.sections()
+			.iter()
+			.map(|(section, _)| *section)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			sections,
+			[
+				AdviceSection::EvalcheckProofCount,
+				AdviceSection::FriTerminateCodeword,
+				AdviceSection::VirtualOpeningRoundCount,
+			]
+		);
+		assert_eq!(layout.count(AdviceSection::EvalcheckProofCount).unwrap(), Some(2));
+		assert_eq!(
+			layout
+				.count(AdviceSection::VirtualOpeningRoundCount)
+				.unwrap(),
+			Some(7)
+		);
+		assert_eq!(layout.count(AdviceSection::FriLayers).unwrap(), None);
+
+		let mut codeword = layout.reader(AdviceSection::FriTerminateCodeword).unwrap();
+		assert_eq!(codeword.read_scalar_slice::<BinaryField64b>(2).unwrap(), scalars);
+		codeword.finalize().unwrap();
+	}
+}
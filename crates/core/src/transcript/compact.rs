@@ -0,0 +1,178 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_field::{deserialize_canonical, serialize_canonical, TowerField};
+use bytes::{Buf, BufMut};
+
+use super::Error;
+
+/// The byte encoding of field elements written to a transcript or advice tape.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarEncoding {
+	/// Every element is written in its canonical encoding, at the full width of its type.
+	#[default]
+	Canonical,
+	/// Every slice of elements is written at the width of the smallest tower field containing all
+	/// of its elements.
+	///
+	/// In the canonical tower basis, an element of the subfield $T_{\iota}$ of a tower field is
+	/// encoded as the canonical encoding of the subfield element padded with zeros. The compact
+	/// encoding drops the zero padding bytes common to all elements of a slice and writes a single
+	/// header byte with the binary logarithm of the remaining byte width in front of the slice.
+	/// Empty slices are written without a header.
+	///
+	/// Every slice has exactly one compact encoding, readers reject a header with a larger width
+	/// than the smallest one holding all elements.
+	Compact,
+}
+
+fn canonical_len<F: TowerField>() -> usize {
+	F::N_BITS.div_ceil(8)
+}
+
+pub(super) fn write_compact_scalar_slice<F: TowerField>(mut buffer: impl BufMut, elems: &[F]) {
+	if elems.is_empty() {
+		return;
+	}
+
+	let n_bytes = canonical_len::<F>();
+	let mut bytes = vec![0u8; elems.len() * n_bytes];
+	for (elem, mut chunk) in elems.iter().zip(bytes.chunks_exact_mut(n_bytes)) {
+		serialize_canonical(*elem, &mut chunk)
+			.expect("chunk has the size of the canonical encoding");
+	}
+
+	let width = bytes
+		.chunks_exact(n_bytes)
+		.map(|chunk| {
+			chunk
+				.iter()
+				.rposition(|&byte| byte != 0)
+				.map_or(0, |pos| pos + 1)
+		})
+		.max()
+		.unwrap_or_default();
+	let log_width = width.next_power_of_two().ilog2();
+
+	buffer.put_u8(log_width as u8);
+	for chunk in bytes.chunks_exact(n_bytes) {
+		buffer.put_slice(&chunk[..1 << log_width]);
+	}
+}
+
+pub(super) fn read_compact_scalar_slice_into<F: TowerField>(
+	mut buffer: impl Buf,
+	elems: &mut [F],
+) -> Result<(), Error> {
+	if elems.is_empty() {
+		return Ok(());
+	}
+
+	let n_bytes = canonical_len::<F>();
+	if !buffer.has_remaining() {
+		return Err(Error::NotEnoughBytes);
+	}
+	let log_width = buffer.get_u8();
+	let width = 1usize
+		.checked_shl(log_width as u32)
+		.filter(|&width| width <= n_bytes)
+		.ok_or(Error::InvalidScalarWidth { log_width })?;
+	if buffer.remaining() < elems.len() * width {
+		return Err(Error::NotEnoughBytes);
+	}
+
+	// The width is the smallest one exactly when some element has a nonzero byte in its upper half
+	let mut is_minimal = width == 1;
+	let mut bytes = vec![0u8; n_bytes];
+	for elem in elems {
+		buffer.copy_to_slice(&mut bytes[..width]);
+		is_minimal |= bytes[width / 2..width].iter().any(|&byte| byte != 0);
+		*elem = deserialize_canonical(bytes.as_slice())?;
+	}
+	if !is_minimal {
+		return Err(Error::InvalidScalarWidth { log_width });
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		BinaryField128b, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, Field,
+	};
+	use bytes::BytesMut;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	fn roundtrip<F: TowerField>(elems: &[F]) -> usize {
+		let mut buffer = BytesMut::new();
+		write_compact_scalar_slice(&mut buffer, elems);
+		let len = buffer.len();
+
+		let mut decoded = vec![F::ZERO; elems.len()];
+		let mut reader = buffer.freeze();
+		read_compact_scalar_slice_into(&mut reader, &mut decoded).unwrap();
+		assert!(!reader.has_remaining());
+		assert_eq!(decoded, elems);
+		len
+	}
+
+	#[test]
+	fn test_compact_roundtrip() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let small = (0..10)
+			.map(|_| BinaryField128b::from(BinaryField8b::random(&mut rng)))
+			.collect::<Vec<_>>();
+		assert_eq!(roundtrip(&small), 1 + 10);
+
+		let mixed = [
+			BinaryField128b::from(BinaryField16b::new(0x1234)),
+			BinaryField128b::from(BinaryField32b::new(0x12345678)),
+		];
+		assert_eq!(roundtrip(&mixed), 1 + 2 * 4);
+
+		let full = (0..10)
+			.map(|_| BinaryField128b::random(&mut rng))
+			.collect::<Vec<_>>();
+		assert!(roundtrip(&full) <= 1 + 10 * 16);
+
+		assert_eq!(roundtrip(&[BinaryField128b::ZERO; 4]), 1 + 4);
+		assert_eq!(roundtrip(&[BinaryField1b::ONE, BinaryField1b::ZERO]), 1 + 2);
+		assert_eq!(roundtrip::<BinaryField32b>(&[]), 0);
+	}
+
+	#[test]
+	fn test_compact_rejects_invalid_width() {
+		let mut reader = &[5u8, 0, 0, 0, 0][..];
+		let mut elems = [BinaryField32b::ZERO];
+		assert!(matches!(
+			read_compact_scalar_slice_into(&mut reader, &mut elems),
+			Err(Error::InvalidScalarWidth { log_width: 5 })
+		));
+	}
+
+	#[test]
+	fn test_compact_rejects_non_minimal_width() {
+		let elems = [BinaryField32b::new(0x12), BinaryField32b::new(0x3456)];
+		let mut buffer = BytesMut::new();
+		write_compact_scalar_slice(&mut buffer, &elems);
+		assert_eq!(&buffer[..], &[1, 0x12, 0, 0x56, 0x34]);
+
+		// The same elements padded to a width of 4 bytes
+		let mut reader = &[2u8, 0x12, 0, 0, 0, 0x56, 0x34, 0, 0][..];
+		let mut decoded = [BinaryField32b::ZERO; 2];
+		assert!(matches!(
+			read_compact_scalar_slice_into(&mut reader, &mut decoded),
+			Err(Error::InvalidScalarWidth { log_width: 2 })
+		));
+
+		// All-zero slices have width 1
+		let mut reader = &[1u8, 0, 0][..];
+		let mut decoded = [BinaryField32b::ZERO];
+		assert!(matches!(
+			read_compact_scalar_slice_into(&mut reader, &mut decoded),
+			Err(Error::InvalidScalarWidth { log_width: 1 })
+		));
+	}
+}
@@ -0,0 +1,24 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::TowerField;
+
+/// A prover message part of which the reader can derive from values it already knows.
+///
+/// Only the values that cannot be derived are written, and the reader reconstructs the full
+/// message from them and the context it shares with the writer. For example, a sumcheck round
+/// polynomial omits its highest-degree coefficient, which the verifier recovers from the claimed
+/// sum. Messages implementing this trait are written with [`super::CanWrite::write_derived`] and
+/// read with [`super::CanRead::read_derived`].
+pub trait DerivedMessage: Sized {
+	type Scalar: TowerField;
+
+	/// The values known to both the writer and the reader that the omitted values are derived
+	/// from.
+	type Context;
+
+	/// The values of the message that must be written.
+	fn sent_values(&self) -> &[Self::Scalar];
+
+	/// Reconstructs the full message from the written values and the shared context.
+	fn derive(sent_values: Vec<Self::Scalar>, context: Self::Context) -> Self;
+}
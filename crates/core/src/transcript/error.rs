@@ -8,6 +8,21 @@ pub enum Error {
 	TranscriptNotEmpty { remaining: usize },
 	#[error("Not enough bytes in the buffer")]
 	NotEnoughBytes,
+	#[error("Count {count} does not fit into a usize")]
+	CountOverflow { count: u64 },
+	#[error("The transcript phases {found:?} do not match the phases {expected:?} of the proof")]
+	PhaseMismatch {
+		expected: Vec<(String, usize)>,
+		found: Vec<(String, usize)>,
+	},
+	#[error("Invalid proof stream: {0}")]
+	InvalidStream(&'static str),
+	#[error("Invalid compact scalar width 2^{log_width} bytes")]
+	InvalidScalarWidth { log_width: u8 },
+	#[error("The prover was cancelled")]
+	Cancelled,
 	#[error("Serialization error: {0}")]
 	Serialization(#[from] SerializationError),
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
 }
@@ -11,18 +11,37 @@
 //! used for values that were previously committed to in the transcript tape.** For example, it is
 //! secure to write a Merkle tree root to the transcript tape, sample a random index, then provide
 //! the Merkle leaf opening at that index in the advice tape.
-
+//!
+//! The prover and verifier mark the start of every phase of a protocol with a label, see
+//! [`CanWrite::begin_phase`]. The transcript tapes absorb the label into the Fiat-Shamir state, so
+//! that challenges sampled in different phases are domain-separated, and record where each phase
+//! starts, so that the layout of a transcript can be described to external verifiers.
+
+mod advice;
+mod compact;
+mod derived;
 mod error;
+mod stream;
+mod streaming;
 
 use std::{iter::repeat_with, slice};
 
+pub use advice::{AdviceLayout, AdviceSection};
 use binius_field::{deserialize_canonical, serialize_canonical, PackedField, TowerField};
 use binius_utils::serialization::{DeserializeBytes, SerializeBytes};
 use bytes::{buf::UninitSlice, Buf, BufMut, Bytes, BytesMut};
+pub use compact::ScalarEncoding;
+use compact::{read_compact_scalar_slice_into, write_compact_scalar_slice};
+pub use derived::DerivedMessage;
 pub use error::Error;
+pub use stream::{ProofStream, StreamSection, Tape, PREAMBLE_LABEL, STREAM_MAGIC, STREAM_VERSION};
+pub use streaming::{StreamingTranscriptWriter, DEFAULT_SPILL_THRESHOLD};
 use tracing::warn;
 
-use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
+use crate::{
+	constraint_system::CancellationToken,
+	fiat_shamir::{CanSample, CanSampleBits, Challenger},
+};
 
 /// Writable(Prover) transcript over some Challenger that `CanWrite` and `CanSample<F: TowerField>`
 ///
@@ -31,6 +50,11 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 #[derive(Debug, Default)]
 pub struct TranscriptWriter<Challenger> {
 	combined: FiatShamirBuf<BytesMut, Challenger>,
+	encoding: ScalarEncoding,
+	/// The phases begun so far, with their start positions.
+	phases: Vec<(String, usize)>,
+	/// The token cancelling the prover writing to the transcript, if any.
+	cancellation: Option<CancellationToken>,
 }
 
 /// Writable(Prover) advice that `CanWrite`
@@ -39,6 +63,7 @@ pub struct TranscriptWriter<Challenger> {
 #[derive(Debug, Default)]
 pub struct AdviceWriter {
 	buffer: BytesMut,
+	encoding: ScalarEncoding,
 }
 
 /// Readable(Verifier) transcript over some Challenger that `CanRead` and `CanSample<F: TowerField>`
@@ -48,6 +73,13 @@ pub struct AdviceWriter {
 #[derive(Debug)]
 pub struct TranscriptReader<Challenger> {
 	combined: FiatShamirBuf<Bytes, Challenger>,
+	encoding: ScalarEncoding,
+	/// The total number of transcript bytes, including those already read.
+	len: usize,
+	/// The phases begun so far, with their start positions.
+	phases: Vec<(String, usize)>,
+	/// The phases the writer began, if known, which must match the phases begun by the reader.
+	expected_phases: Option<Vec<(String, usize)>>,
 }
 
 /// Readable(Verifier) advice that `CanRead`
@@ -57,6 +89,11 @@ pub struct TranscriptReader<Challenger> {
 #[derive(Debug)]
 pub struct AdviceReader {
 	buffer: Bytes,
+	/// All advice bytes, including those already read.
+	data: Bytes,
+	encoding: ScalarEncoding,
+	/// The sections read so far, with their start positions.
+	sections: Vec<(AdviceSection, usize)>,
 }
 
 /// Helper struct combining Transcript and Advice data to create a Proof object
@@ -65,6 +102,22 @@ pub struct Proof<Transcript, Advice> {
 	pub advice: Advice,
 }
 
+/// The tag that starts the frame of a phase label, see [`phase_frame`].
+pub const PHASE_TAG: &[u8] = b"binius-phase";
+
+/// The bytes a transcript absorbs into its Fiat-Shamir state at the start of a phase.
+///
+/// The frame consists of [`PHASE_TAG`], the length of the label as a little-endian `u64` and the
+/// UTF-8 bytes of the label. Frames are only absorbed, never written to the transcript tape.
+pub fn phase_frame(label: &str) -> Vec<u8> {
+	[
+		PHASE_TAG,
+		&(label.len() as u64).to_le_bytes(),
+		label.as_bytes(),
+	]
+	.concat()
+}
+
 #[derive(Debug, Default)]
 struct FiatShamirBuf<Inner, Challenger> {
 	buffer: Inner,
@@ -120,12 +173,47 @@ impl<Challenger: Default> TranscriptWriter<Challenger> {
 		Self::default()
 	}
 
+	pub fn into_reader(self) -> TranscriptReader<Challenger> {
+		let encoding = self.encoding;
+		TranscriptReader::new(self.finalize()).with_scalar_encoding(encoding)
+	}
+}
+
+impl<Challenger> TranscriptWriter<Challenger> {
+	/// Creates a transcript sampling from the given challenger, which need not start in its
+	/// default state, see [`crate::fiat_shamir::ExternalChallenger`].
+	pub fn with_challenger(challenger: Challenger) -> Self {
+		Self {
+			combined: FiatShamirBuf {
+				buffer: BytesMut::new(),
+				challenger,
+			},
+			encoding: ScalarEncoding::default(),
+			phases: Vec::new(),
+			cancellation: None,
+		}
+	}
+
+	/// Sets the encoding of field elements written to the transcript.
+	pub fn with_scalar_encoding(mut self, encoding: ScalarEncoding) -> Self {
+		self.encoding = encoding;
+		self
+	}
+
+	/// Sets a token that the protocols writing to the transcript check between rounds, see
+	/// [`CanWrite::check_cancelled`].
+	pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+		self.cancellation = Some(cancellation);
+		self
+	}
+
 	pub fn finalize(self) -> Vec<u8> {
 		self.combined.buffer.to_vec()
 	}
 
-	pub fn into_reader(self) -> TranscriptReader<Challenger> {
-		TranscriptReader::new(self.finalize())
+	/// The phases begun so far, with the positions in the transcript where they start.
+	pub fn phases(&self) -> &[(String, usize)] {
+		&self.phases
 	}
 }
 
@@ -134,12 +222,19 @@ impl AdviceWriter {
 		Self::default()
 	}
 
+	/// Sets the encoding of field elements written to the advice.
+	pub fn with_scalar_encoding(mut self, encoding: ScalarEncoding) -> Self {
+		self.encoding = encoding;
+		self
+	}
+
 	pub fn finalize(self) -> Vec<u8> {
 		self.buffer.to_vec()
 	}
 
 	pub fn into_reader(self) -> AdviceReader {
-		AdviceReader::new(self.finalize())
+		let encoding = self.encoding;
+		AdviceReader::new(self.finalize()).with_scalar_encoding(encoding)
 	}
 }
 
@@ -161,20 +256,61 @@ impl<Challenger: Default> Proof<TranscriptReader<Challenger>, AdviceReader> {
 
 impl<Challenger: Default> TranscriptReader<Challenger> {
 	pub fn new(vec: Vec<u8>) -> Self {
+		Self::with_challenger(vec, Challenger::default())
+	}
+}
+
+impl<Challenger> TranscriptReader<Challenger> {
+	/// Creates a transcript reader sampling from the given challenger, which must be in the same
+	/// state as the challenger the transcript was written with.
+	pub fn with_challenger(vec: Vec<u8>, challenger: Challenger) -> Self {
 		Self {
+			len: vec.len(),
 			combined: FiatShamirBuf {
-				challenger: Challenger::default(),
+				challenger,
 				buffer: Bytes::from(vec),
 			},
+			encoding: ScalarEncoding::default(),
+			phases: Vec::new(),
+			expected_phases: None,
 		}
 	}
 
+	/// The phases begun so far, with the positions in the transcript where they start.
+	pub fn phases(&self) -> &[(String, usize)] {
+		&self.phases
+	}
+
+	/// Sets the encoding of field elements read from the transcript.
+	///
+	/// This must match the encoding the transcript was written with.
+	pub fn with_scalar_encoding(mut self, encoding: ScalarEncoding) -> Self {
+		self.encoding = encoding;
+		self
+	}
+
+	/// Sets the phases the writer began, with their start positions, such as those recorded in a
+	/// [`ProofStream`]. [`Self::finalize`] fails unless the reader begins the same phases at the
+	/// same positions.
+	pub fn with_expected_phases(mut self, phases: Vec<(String, usize)>) -> Self {
+		self.expected_phases = Some(phases);
+		self
+	}
+
 	pub fn finalize(self) -> Result<(), Error> {
 		if self.combined.buffer.has_remaining() {
 			return Err(Error::TranscriptNotEmpty {
 				remaining: self.combined.buffer.remaining(),
 			});
 		}
+		if let Some(expected) = &self.expected_phases {
+			if *expected != self.phases {
+				return Err(Error::PhaseMismatch {
+					expected: expected.clone(),
+					found: self.phases.clone(),
+				});
+			}
+		}
 		Ok(())
 	}
 }
@@ -201,11 +337,23 @@ impl Drop for AdviceReader {
 
 impl AdviceReader {
 	pub fn new(vec: Vec<u8>) -> Self {
+		let data = Bytes::from(vec);
 		Self {
-			buffer: Bytes::from(vec),
+			buffer: data.clone(),
+			data,
+			encoding: ScalarEncoding::default(),
+			sections: Vec::new(),
 		}
 	}
 
+	/// Sets the encoding of field elements read from the advice.
+	///
+	/// This must match the encoding the advice was written with.
+	pub fn with_scalar_encoding(mut self, encoding: ScalarEncoding) -> Self {
+		self.encoding = encoding;
+		self
+	}
+
 	pub fn finalize(self) -> Result<(), Error> {
 		if self.buffer.has_remaining() {
 			return Err(Error::TranscriptNotEmpty {
@@ -221,6 +369,22 @@ impl AdviceReader {
 pub trait CanRead {
 	fn buffer(&mut self) -> impl Buf + '_;
 
+	/// The encoding of field elements read by the `read_scalar*` and `read_packed*` methods.
+	fn scalar_encoding(&self) -> ScalarEncoding {
+		ScalarEncoding::Canonical
+	}
+
+	/// Marks the start of a section of the advice tape.
+	///
+	/// Only [`AdviceReader`] records sections, other readers ignore them.
+	fn begin_section(&mut self, _section: AdviceSection) {}
+
+	/// Marks the start of a phase of the protocol, see [`CanWrite::begin_phase`].
+	fn begin_phase(&mut self, _label: &str) {}
+
+	/// Absorbs data into the Fiat-Shamir state, see [`CanWrite::absorb`].
+	fn absorb(&mut self, _data: &[u8]) {}
+
 	fn read<T: DeserializeBytes>(&mut self) -> Result<T, Error> {
 		T::deserialize(self.buffer()).map_err(Into::into)
 	}
@@ -248,6 +412,10 @@ pub trait CanRead {
 	}
 
 	fn read_scalar_slice_into<F: TowerField>(&mut self, buf: &mut [F]) -> Result<(), Error> {
+		if self.scalar_encoding() == ScalarEncoding::Compact {
+			return read_compact_scalar_slice_into(self.buffer(), buf);
+		}
+
 		let mut buffer = self.buffer();
 		for elem in buf {
 			*elem = deserialize_canonical(&mut buffer)?;
@@ -261,7 +429,23 @@ pub trait CanRead {
 		Ok(elems)
 	}
 
+	/// Reads a message written with [`CanWrite::write_derived`], given the number of values that
+	/// were written and the context to derive the rest from.
+	fn read_derived<M: DerivedMessage>(
+		&mut self,
+		n_sent_values: usize,
+		context: M::Context,
+	) -> Result<M, Error> {
+		let sent_values = self.read_scalar_slice(n_sent_values)?;
+		Ok(M::derive(sent_values, context))
+	}
+
 	fn read_packed<P: PackedField<Scalar: TowerField>>(&mut self) -> Result<P, Error> {
+		if self.scalar_encoding() == ScalarEncoding::Compact {
+			let scalars = self.read_scalar_slice(P::WIDTH)?;
+			return Ok(P::from_scalars(scalars));
+		}
+
 		let mut pack = P::default();
 
 		for i in 0..P::WIDTH {
@@ -278,6 +462,14 @@ pub trait CanRead {
 		&mut self,
 		len: usize,
 	) -> Result<Vec<P>, Error> {
+		if self.scalar_encoding() == ScalarEncoding::Compact {
+			let scalars = self.read_scalar_slice(len * P::WIDTH)?;
+			return Ok(scalars
+				.chunks_exact(P::WIDTH)
+				.map(|chunk| P::from_scalars(chunk.iter().copied()))
+				.collect());
+		}
+
 		let mut packed = Vec::with_capacity(len);
 		for _ in 0..len {
 			packed.push(self.read_packed()?);
@@ -290,12 +482,39 @@ impl<Challenger_: Challenger> CanRead for TranscriptReader<Challenger_> {
 	fn buffer(&mut self) -> impl Buf + '_ {
 		&mut self.combined
 	}
+
+	fn begin_phase(&mut self, label: &str) {
+		#[cfg(feature = "diagnostics")]
+		crate::diagnostics::record_phase(label);
+		let position = self.len - self.combined.buffer.remaining();
+		self.phases.push((label.to_string(), position));
+		self.combined
+			.challenger
+			.observer()
+			.put_slice(&phase_frame(label));
+	}
+
+	fn absorb(&mut self, data: &[u8]) {
+		self.combined.challenger.observer().put_slice(data);
+	}
+
+	fn scalar_encoding(&self) -> ScalarEncoding {
+		self.encoding
+	}
 }
 
 impl CanRead for AdviceReader {
 	fn buffer(&mut self) -> impl Buf + '_ {
 		&mut self.buffer
 	}
+
+	fn begin_section(&mut self, section: AdviceSection) {
+		self.sections.push((section, self.position()));
+	}
+
+	fn scalar_encoding(&self) -> ScalarEncoding {
+		self.encoding
+	}
 }
 
 /// Trait that is used to write bytes and field elements to transcript/advice
@@ -303,6 +522,33 @@ impl CanRead for AdviceReader {
 pub trait CanWrite {
 	fn buffer(&mut self) -> impl BufMut + '_;
 
+	/// The encoding of field elements written by the `write_scalar*` and `write_packed*` methods.
+	fn scalar_encoding(&self) -> ScalarEncoding {
+		ScalarEncoding::Canonical
+	}
+
+	/// Marks the start of a phase of the protocol.
+	///
+	/// Transcript tapes absorb the [`phase_frame`] of the label into the Fiat-Shamir state and
+	/// record the position of the phase. Advice tapes ignore phases. The reader of a proof must
+	/// begin the same phases at the same positions as the writer.
+	fn begin_phase(&mut self, _label: &str) {}
+
+	/// Absorbs data into the Fiat-Shamir state without writing it to the tape.
+	///
+	/// This binds the challenges to data the reader obtains out of band, such as
+	/// [`crate::constraint_system::ProofMetadata`]. Advice tapes ignore absorbed data. The reader
+	/// of a proof must absorb the same data at the same positions as the writer.
+	fn absorb(&mut self, _data: &[u8]) {}
+
+	/// Returns [`Error::Cancelled`] if the prover writing to this tape has been cancelled.
+	///
+	/// Protocols call this once per round, so that a cancelled prover stops within a phase rather
+	/// than after it. Only transcript tapes with a [`CancellationToken`] are ever cancelled.
+	fn check_cancelled(&self) -> Result<(), Error> {
+		Ok(())
+	}
+
 	fn write<T: SerializeBytes>(&mut self, value: &T) {
 		value
 			.serialize(self.buffer())
@@ -325,19 +571,43 @@ pub trait CanWrite {
 	}
 
 	fn write_scalar_slice<F: TowerField>(&mut self, elems: &[F]) {
+		if self.scalar_encoding() == ScalarEncoding::Compact {
+			write_compact_scalar_slice(self.buffer(), elems);
+			return;
+		}
+
 		let mut buffer = self.buffer();
 		for elem in elems {
 			serialize_canonical(*elem, &mut buffer).expect("TODO: propagate error");
 		}
 	}
 
+	/// Writes only the values of the message that the reader cannot derive.
+	fn write_derived<M: DerivedMessage>(&mut self, message: &M) {
+		self.write_scalar_slice(message.sent_values());
+	}
+
 	fn write_packed<P: PackedField<Scalar: TowerField>>(&mut self, packed: P) {
+		if self.scalar_encoding() == ScalarEncoding::Compact {
+			self.write_scalar_slice(&packed.iter().collect::<Vec<_>>());
+			return;
+		}
+
 		for scalar in packed.iter() {
 			self.write_scalar(scalar);
 		}
 	}
 
 	fn write_packed_slice<P: PackedField<Scalar: TowerField>>(&mut self, packed_slice: &[P]) {
+		if self.scalar_encoding() == ScalarEncoding::Compact {
+			let scalars = packed_slice
+				.iter()
+				.flat_map(|packed| packed.iter())
+				.collect::<Vec<_>>();
+			self.write_scalar_slice(&scalars);
+			return;
+		}
+
 		for &packed in packed_slice {
 			self.write_packed(packed)
 		}
@@ -348,12 +618,40 @@ impl<Challenger_: Challenger> CanWrite for TranscriptWriter<Challenger_> {
 	fn buffer(&mut self) -> impl BufMut + '_ {
 		&mut self.combined
 	}
+
+	fn scalar_encoding(&self) -> ScalarEncoding {
+		self.encoding
+	}
+
+	fn begin_phase(&mut self, label: &str) {
+		self.phases
+			.push((label.to_string(), self.combined.buffer.len()));
+		self.combined
+			.challenger
+			.observer()
+			.put_slice(&phase_frame(label));
+	}
+
+	fn absorb(&mut self, data: &[u8]) {
+		self.combined.challenger.observer().put_slice(data);
+	}
+
+	fn check_cancelled(&self) -> Result<(), Error> {
+		match &self.cancellation {
+			Some(cancellation) if cancellation.is_cancelled() => Err(Error::Cancelled),
+			_ => Ok(()),
+		}
+	}
 }
 
 impl CanWrite for AdviceWriter {
 	fn buffer(&mut self) -> impl BufMut + '_ {
 		&mut self.buffer
 	}
+
+	fn scalar_encoding(&self) -> ScalarEncoding {
+		self.encoding
+	}
 }
 
 impl<F, Challenger_> CanSample<F> for TranscriptReader<Challenger_>
@@ -529,6 +827,42 @@ mod tests {
 		advice_reader.finalize().unwrap();
 	}
 
+	#[test]
+	fn test_phases() {
+		let mut prover_transcript = TranscriptWriter::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.begin_phase("first");
+		prover_transcript.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let sampled_first: BinaryField128b = prover_transcript.sample();
+		prover_transcript.begin_phase("second");
+		prover_transcript.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let sampled_second: BinaryField128b = prover_transcript.sample();
+
+		let mut other_transcript = TranscriptWriter::<HasherChallenger<Groestl256>>::new();
+		other_transcript.begin_phase("first");
+		other_transcript.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let _: BinaryField128b = other_transcript.sample();
+		other_transcript.begin_phase("other");
+		other_transcript.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let sampled_other: BinaryField128b = other_transcript.sample();
+		assert_ne!(sampled_second, sampled_other);
+
+		// Phases are absorbed into the challenger but do not add bytes to the transcript.
+		let phases = prover_transcript.phases().to_vec();
+		assert_eq!(phases, vec![("first".to_string(), 0), ("second".to_string(), 4)]);
+
+		let mut verifier_transcript = prover_transcript.into_reader();
+		verifier_transcript.begin_phase("first");
+		let _: BinaryField32b = verifier_transcript.read_scalar().unwrap();
+		let sampled_first_res: BinaryField128b = verifier_transcript.sample();
+		assert_eq!(sampled_first_res, sampled_first);
+		verifier_transcript.begin_phase("second");
+		let _: BinaryField32b = verifier_transcript.read_scalar().unwrap();
+		let sampled_second_res: BinaryField128b = verifier_transcript.sample();
+		assert_eq!(sampled_second_res, sampled_second);
+		assert_eq!(verifier_transcript.phases(), phases);
+		verifier_transcript.finalize().unwrap();
+	}
+
 	#[test]
 	fn test_challenger() {
 		let mut transcript = TranscriptWriter::<HasherChallenger<Groestl256>>::new();
@@ -0,0 +1,382 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A single byte stream holding all tapes of a proof.
+//!
+//! A proof used to be stored as two separate byte strings, the transcript and the advice, with the
+//! phase positions recorded on neither. A [`ProofStream`] holds the tapes in one buffer, split
+//! into tagged sections: the header of the proof, one transcript section per phase, and the
+//! advice. An index at the start of the stream lists the sections, so a verifier can read any
+//! section without parsing the ones before it, and the transcript reader created from a stream
+//! checks that the verifier begins the same phases at the same positions as the prover did.
+
+use std::ops::Range;
+
+use bytes::{Buf, BufMut, Bytes};
+
+use super::{AdviceReader, Error, ScalarEncoding, TranscriptReader};
+
+/// The bytes a [`ProofStream`] starts with.
+pub const STREAM_MAGIC: &[u8] = b"binius-proof-stream";
+
+/// The version of the [`ProofStream`] format.
+pub const STREAM_VERSION: u8 = 1;
+
+/// The label of the transcript bytes written before the first phase.
+pub const PREAMBLE_LABEL: &str = "";
+
+/// The tape a section of a [`ProofStream`] belongs to.
+///
+/// The sections of a stream are ordered by tape, so that every tape is contiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tape {
+	/// Data describing how to read the other tapes, which is not part of the interaction.
+	Header,
+	Transcript,
+	Advice,
+}
+
+impl Tape {
+	fn from_byte(byte: u8) -> Result<Self, Error> {
+		match byte {
+			0 => Ok(Self::Header),
+			1 => Ok(Self::Transcript),
+			2 => Ok(Self::Advice),
+			_ => Err(Error::InvalidStream("unknown tape")),
+		}
+	}
+
+	fn to_byte(self) -> u8 {
+		match self {
+			Self::Header => 0,
+			Self::Transcript => 1,
+			Self::Advice => 2,
+		}
+	}
+}
+
+/// A tagged section of a [`ProofStream`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamSection {
+	pub tape: Tape,
+	/// The phase of a transcript section, see [`super::CanWrite::begin_phase`].
+	pub label: String,
+	/// The byte range of the section within its tape.
+	pub range: Range<usize>,
+}
+
+/// The tapes of a proof in a single buffer, split into tagged sections.
+///
+/// The serialized stream starts with [`STREAM_MAGIC`] and a version byte, followed by the number
+/// of sections and, for every section, its tape as a byte, its label prefixed with its length and
+/// its length in bytes. Lengths are little-endian `u64`s. The section bodies follow the index in
+/// order.
+#[derive(Debug, Clone)]
+pub struct ProofStream {
+	/// The header, transcript and advice tapes, one after another.
+	data: Bytes,
+	sections: Vec<StreamSection>,
+	tape_offsets: [usize; 3],
+}
+
+impl ProofStream {
+	/// Creates a stream from the tapes of a proof.
+	///
+	/// The transcript is split at the given phases, which are the phase labels with their start
+	/// positions as recorded by [`super::TranscriptWriter::phases`]. Bytes before the first phase
+	/// form a section labelled [`PREAMBLE_LABEL`]. The header and advice are a single section each.
+	pub fn new(
+		header: &[u8],
+		transcript: &[u8],
+		phases: &[(String, usize)],
+		advice: &[u8],
+	) -> Result<Self, Error> {
+		if phases.windows(2).any(|pair| pair[0].1 > pair[1].1)
+			|| phases
+				.last()
+				.is_some_and(|(_, start)| *start > transcript.len())
+		{
+			return Err(Error::InvalidStream("phases are not ordered within the transcript"));
+		}
+
+		let mut sections = vec![StreamSection {
+			tape: Tape::Header,
+			label: String::new(),
+			range: 0..header.len(),
+		}];
+		let first_phase = phases.first().map_or(transcript.len(), |(_, start)| *start);
+		if first_phase > 0 || phases.is_empty() {
+			sections.push(StreamSection {
+				tape: Tape::Transcript,
+				label: PREAMBLE_LABEL.to_string(),
+				range: 0..first_phase,
+			});
+		}
+		let ends = phases
+			.iter()
+			.skip(1)
+			.map(|(_, start)| *start)
+			.chain([transcript.len()]);
+		for ((label, start), end) in phases.iter().zip(ends) {
+			sections.push(StreamSection {
+				tape: Tape::Transcript,
+				label: label.clone(),
+				range: *start..end,
+			});
+		}
+		sections.push(StreamSection {
+			tape: Tape::Advice,
+			label: String::new(),
+			range: 0..advice.len(),
+		});
+
+		Ok(Self {
+			data: Bytes::from([header, transcript, advice].concat()),
+			sections,
+			tape_offsets: [0, header.len(), header.len() + transcript.len()],
+		})
+	}
+
+	/// Parses a stream written with [`Self::to_bytes`].
+	pub fn from_bytes(bytes: impl Into<Bytes>) -> Result<Self, Error> {
+		let mut bytes: Bytes = bytes.into();
+		if !bytes.starts_with(STREAM_MAGIC) {
+			return Err(Error::InvalidStream("missing magic bytes"));
+		}
+		bytes.advance(STREAM_MAGIC.len());
+		if read_byte(&mut bytes)? != STREAM_VERSION {
+			return Err(Error::InvalidStream("unsupported version"));
+		}
+
+		let n_sections = read_len(&mut bytes)?;
+		// Every section takes at least 17 bytes of the index.
+		if n_sections > bytes.remaining() / 17 {
+			return Err(Error::InvalidStream("too many sections"));
+		}
+		let mut sections = Vec::<StreamSection>::with_capacity(n_sections);
+		let mut tape_ends = [0usize; 3];
+		for _ in 0..n_sections {
+			let tape = Tape::from_byte(read_byte(&mut bytes)?)?;
+			if sections.last().is_some_and(|last| last.tape > tape) {
+				return Err(Error::InvalidStream("sections are not ordered by tape"));
+			}
+			let label_len = read_len(&mut bytes)?;
+			if bytes.remaining() < label_len {
+				return Err(Error::NotEnoughBytes);
+			}
+			let label = String::from_utf8(bytes.split_to(label_len).to_vec())
+				.map_err(|_| Error::InvalidStream("label is not UTF-8"))?;
+			let len = read_len(&mut bytes)?;
+
+			let tape_end = &mut tape_ends[tape.to_byte() as usize];
+			let start = *tape_end;
+			*tape_end = start
+				.checked_add(len)
+				.ok_or(Error::InvalidStream("section is too long"))?;
+			sections.push(StreamSection {
+				tape,
+				label,
+				range: start..*tape_end,
+			});
+		}
+
+		let [header_len, transcript_len, advice_len] = tape_ends;
+		if header_len
+			.checked_add(transcript_len)
+			.and_then(|len| len.checked_add(advice_len))
+			!= Some(bytes.remaining())
+		{
+			return Err(Error::InvalidStream("section lengths do not match the stream length"));
+		}
+
+		Ok(Self {
+			data: bytes,
+			sections,
+			tape_offsets: [0, header_len, header_len + transcript_len],
+		})
+	}
+
+	/// Serializes the stream, see [`ProofStream`] for the format.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(self.data.len() + 32 * self.sections.len() + 32);
+		bytes.put_slice(STREAM_MAGIC);
+		bytes.put_u8(STREAM_VERSION);
+		bytes.put_u64_le(self.sections.len() as u64);
+		for section in &self.sections {
+			bytes.put_u8(section.tape.to_byte());
+			bytes.put_u64_le(section.label.len() as u64);
+			bytes.put_slice(section.label.as_bytes());
+			bytes.put_u64_le(section.range.len() as u64);
+		}
+		bytes.put_slice(&self.data);
+		bytes
+	}
+
+	/// The sections of the stream, ordered by tape and then by position.
+	pub fn sections(&self) -> &[StreamSection] {
+		&self.sections
+	}
+
+	/// The bytes of a tape.
+	pub fn tape(&self, tape: Tape) -> Bytes {
+		let index = tape.to_byte() as usize;
+		let end = self
+			.tape_offsets
+			.get(index + 1)
+			.copied()
+			.unwrap_or(self.data.len());
+		self.data.slice(self.tape_offsets[index]..end)
+	}
+
+	/// The bytes of the first section of a tape with the given label.
+	pub fn section(&self, tape: Tape, label: &str) -> Option<Bytes> {
+		let offset = self.tape_offsets[tape.to_byte() as usize];
+		self.sections
+			.iter()
+			.find(|section| section.tape == tape && section.label == label)
+			.map(|section| {
+				self.data
+					.slice(offset + section.range.start..offset + section.range.end)
+			})
+	}
+
+	/// The phases of the transcript with their start positions, as recorded by the prover.
+	pub fn phases(&self) -> Vec<(String, usize)> {
+		self.sections
+			.iter()
+			.filter(|section| section.tape == Tape::Transcript && section.label != PREAMBLE_LABEL)
+			.map(|section| (section.label.clone(), section.range.start))
+			.collect()
+	}
+
+	/// A reader of the transcript tape that checks on [`TranscriptReader::finalize`] that the
+	/// verifier began the phases recorded in the stream.
+	pub fn transcript_reader<Challenger: Default>(
+		&self,
+		encoding: ScalarEncoding,
+	) -> TranscriptReader<Challenger> {
+		TranscriptReader::new(self.tape(Tape::Transcript).to_vec())
+			.with_scalar_encoding(encoding)
+			.with_expected_phases(self.phases())
+	}
+
+	/// A reader of the advice tape.
+	pub fn advice_reader(&self, encoding: ScalarEncoding) -> AdviceReader {
+		AdviceReader::new(self.tape(Tape::Advice).to_vec()).with_scalar_encoding(encoding)
+	}
+}
+
+fn read_byte(bytes: &mut Bytes) -> Result<u8, Error> {
+	if !bytes.has_remaining() {
+		return Err(Error::NotEnoughBytes);
+	}
+	Ok(bytes.get_u8())
+}
+
+fn read_len(bytes: &mut Bytes) -> Result<usize, Error> {
+	if bytes.remaining() < size_of::<u64>() {
+		return Err(Error::NotEnoughBytes);
+	}
+	let len = bytes.get_u64_le();
+	usize::try_from(len).map_err(|_| Error::CountOverflow { count: len })
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField32b};
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::{
+		fiat_shamir::{CanSample, HasherChallenger},
+		transcript::{AdviceSection, AdviceWriter, CanRead, CanWrite, TranscriptWriter},
+	};
+
+	type Challenger = HasherChallenger<Groestl256>;
+
+	fn write_stream() -> (ProofStream, BinaryField128b) {
+		let mut transcript = TranscriptWriter::<Challenger>::new();
+		let mut advice = AdviceWriter::new();
+		transcript.write_scalar(BinaryField32b::new(1));
+		transcript.begin_phase("first");
+		transcript.write_scalar(BinaryField32b::new(2));
+		let challenge = transcript.sample();
+		advice.write_count(3);
+		transcript.begin_phase("second");
+		transcript.write_scalar(BinaryField32b::new(4));
+
+		let phases = transcript.phases().to_vec();
+		let stream =
+			ProofStream::new(b"header", &transcript.finalize(), &phases, &advice.finalize())
+				.unwrap();
+		(stream, challenge)
+	}
+
+	#[test]
+	fn test_stream_roundtrip() {
+		let (stream, challenge) = write_stream();
+		let stream = ProofStream::from_bytes(stream.to_bytes()).unwrap();
+
+		let labels = stream
+			.sections()
+			.iter()
+			.map(|section| (section.tape, section.label.as_str(), section.range.clone()))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			labels,
+			[
+				(Tape::Header, "", 0..6),
+				(Tape::Transcript, PREAMBLE_LABEL, 0..4),
+				(Tape::Transcript, "first", 4..8),
+				(Tape::Transcript, "second", 8..12),
+				(Tape::Advice, "", 0..8),
+			]
+		);
+		assert_eq!(stream.tape(Tape::Header), &b"header"[..]);
+		assert_eq!(stream.section(Tape::Transcript, "second").unwrap(), &4u32.to_le_bytes()[..]);
+		assert!(stream.section(Tape::Transcript, "third").is_none());
+
+		let mut transcript = stream.transcript_reader::<Challenger>(ScalarEncoding::Canonical);
+		let mut advice = stream.advice_reader(ScalarEncoding::Canonical);
+		assert_eq!(transcript.read_scalar::<BinaryField32b>().unwrap(), BinaryField32b::new(1));
+		transcript.begin_phase("first");
+		assert_eq!(transcript.read_scalar::<BinaryField32b>().unwrap(), BinaryField32b::new(2));
+		assert_eq!(CanSample::<BinaryField128b>::sample(&mut transcript), challenge);
+		assert_eq!(advice.read_count(AdviceSection::FriLayers).unwrap(), 3);
+		transcript.begin_phase("second");
+		assert_eq!(transcript.read_scalar::<BinaryField32b>().unwrap(), BinaryField32b::new(4));
+		transcript.finalize().unwrap();
+		advice.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_stream_detects_phase_mismatch() {
+		let (stream, _) = write_stream();
+		let mut transcript = stream.transcript_reader::<Challenger>(ScalarEncoding::Canonical);
+		// The verifier begins the first phase one message late.
+		let _: BinaryField32b = transcript.read_scalar().unwrap();
+		let _: BinaryField32b = transcript.read_scalar().unwrap();
+		transcript.begin_phase("first");
+		transcript.begin_phase("second");
+		let _: BinaryField32b = transcript.read_scalar().unwrap();
+		assert!(matches!(transcript.finalize(), Err(Error::PhaseMismatch { .. })));
+	}
+
+	#[test]
+	fn test_stream_rejects_malformed_bytes() {
+		let (stream, _) = write_stream();
+		let bytes = stream.to_bytes();
+
+		assert!(ProofStream::from_bytes(bytes[1..].to_vec()).is_err());
+		assert!(ProofStream::from_bytes(bytes[..bytes.len() - 1].to_vec()).is_err());
+		let mut extended = bytes.clone();
+		extended.push(0);
+		assert!(ProofStream::from_bytes(extended).is_err());
+		let mut version = bytes.clone();
+		version[STREAM_MAGIC.len()] = STREAM_VERSION + 1;
+		assert!(ProofStream::from_bytes(version).is_err());
+
+		assert!(ProofStream::new(&[], &[0; 4], &[("a".to_string(), 2), ("b".to_string(), 1)], &[])
+			.is_err());
+		assert!(ProofStream::new(&[], &[0; 4], &[("a".to_string(), 5)], &[]).is_err());
+	}
+}
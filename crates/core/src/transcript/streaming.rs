@@ -0,0 +1,230 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::io;
+
+use binius_field::{deserialize_canonical, TowerField};
+use bytes::{buf::UninitSlice, BufMut, BytesMut};
+
+use super::{phase_frame, sample_bits_reader, CanWrite, Error, FiatShamirBuf, ScalarEncoding};
+use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
+
+/// The default number of transcript bytes buffered in memory before spilling to the sink.
+pub const DEFAULT_SPILL_THRESHOLD: usize = 1 << 20;
+
+/// Writable(Prover) transcript that streams the written bytes to an [`io::Write`] sink.
+///
+/// This behaves exactly like [`super::TranscriptWriter`] with respect to the Fiat-Shamir state,
+/// and the bytes written to the sink are identical to the bytes returned by
+/// [`super::TranscriptWriter::finalize`]. However, only a bounded amount of the transcript is held
+/// in memory: once the internal buffer reaches the spill threshold, its contents are written to
+/// the sink.
+///
+/// Errors writing to the sink are deferred and returned by [`Self::finalize`], since the
+/// [`CanWrite`] interface is infallible. Once an error occurs, the remaining transcript is
+/// discarded.
+#[derive(Debug)]
+pub struct StreamingTranscriptWriter<Challenger, W> {
+	combined: FiatShamirBuf<SpillBuf<W>, Challenger>,
+	encoding: ScalarEncoding,
+	/// The phases begun so far, with their start positions.
+	phases: Vec<(String, usize)>,
+}
+
+impl<Challenger: Default, W: io::Write> StreamingTranscriptWriter<Challenger, W> {
+	pub fn new(sink: W) -> Self {
+		Self::with_spill_threshold(sink, DEFAULT_SPILL_THRESHOLD)
+	}
+
+	pub fn with_spill_threshold(sink: W, spill_threshold: usize) -> Self {
+		Self {
+			combined: FiatShamirBuf {
+				buffer: SpillBuf {
+					buffer: BytesMut::with_capacity(spill_threshold),
+					sink,
+					spill_threshold,
+					n_spilled: 0,
+					error: None,
+				},
+				challenger: Challenger::default(),
+			},
+			encoding: ScalarEncoding::default(),
+			phases: Vec::new(),
+		}
+	}
+
+	/// Sets the encoding of field elements written to the transcript.
+	pub fn with_scalar_encoding(mut self, encoding: ScalarEncoding) -> Self {
+		self.encoding = encoding;
+		self
+	}
+
+	/// The total number of bytes written to the transcript so far.
+	pub fn len(&self) -> usize {
+		self.combined.buffer.n_spilled + self.combined.buffer.buffer.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// The phases begun so far, with the positions in the transcript where they start.
+	pub fn phases(&self) -> &[(String, usize)] {
+		&self.phases
+	}
+
+	/// Writes the remaining buffered bytes to the sink, flushes it and returns it.
+	pub fn finalize(self) -> Result<W, Error> {
+		let mut spill_buf = self.combined.buffer;
+		spill_buf.spill();
+		if let Some(err) = spill_buf.error {
+			return Err(err.into());
+		}
+		spill_buf.sink.flush()?;
+		Ok(spill_buf.sink)
+	}
+}
+
+impl<Challenger_: Challenger, W: io::Write> CanWrite for StreamingTranscriptWriter<Challenger_, W> {
+	fn buffer(&mut self) -> impl BufMut + '_ {
+		&mut self.combined
+	}
+
+	fn scalar_encoding(&self) -> ScalarEncoding {
+		self.encoding
+	}
+
+	fn begin_phase(&mut self, label: &str) {
+		let position = self.combined.buffer.n_spilled + self.combined.buffer.buffer.len();
+		self.phases.push((label.to_string(), position));
+		self.combined
+			.challenger
+			.observer()
+			.put_slice(&phase_frame(label));
+	}
+
+	fn absorb(&mut self, data: &[u8]) {
+		self.combined.challenger.observer().put_slice(data);
+	}
+}
+
+impl<F, Challenger_, W> CanSample<F> for StreamingTranscriptWriter<Challenger_, W>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	fn sample(&mut self) -> F {
+		deserialize_canonical(self.combined.challenger.sampler())
+			.expect("challenger has infinite buffer")
+	}
+}
+
+impl<Challenger_, W> CanSampleBits<usize> for StreamingTranscriptWriter<Challenger_, W>
+where
+	Challenger_: Challenger,
+{
+	fn sample_bits(&mut self, bits: usize) -> usize {
+		sample_bits_reader(self.combined.challenger.sampler(), bits)
+	}
+}
+
+/// A write buffer that moves its contents to a sink whenever it grows past a threshold.
+///
+/// Spilling happens in `chunk_mut`, before handing out more space, so that the bytes observed by
+/// the enclosing [`FiatShamirBuf`] in `advance_mut` are always still in the buffer.
+#[derive(Debug)]
+struct SpillBuf<W> {
+	buffer: BytesMut,
+	sink: W,
+	spill_threshold: usize,
+	n_spilled: usize,
+	error: Option<io::Error>,
+}
+
+impl<W: io::Write> SpillBuf<W> {
+	fn spill(&mut self) {
+		if self.error.is_none() {
+			if let Err(err) = self.sink.write_all(&self.buffer) {
+				self.error = Some(err);
+			}
+		}
+		self.n_spilled += self.buffer.len();
+		self.buffer.clear();
+	}
+}
+
+unsafe impl<W: io::Write> BufMut for SpillBuf<W> {
+	fn remaining_mut(&self) -> usize {
+		self.buffer.remaining_mut()
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		self.buffer.advance_mut(cnt);
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		if self.buffer.len() >= self.spill_threshold {
+			self.spill();
+		}
+		self.buffer.chunk_mut()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField32b, BinaryField8b};
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::{fiat_shamir::HasherChallenger, transcript::TranscriptWriter};
+
+	#[test]
+	fn test_streaming_matches_in_memory() {
+		let mut in_memory = TranscriptWriter::<HasherChallenger<Groestl256>>::new();
+		let mut streaming =
+			StreamingTranscriptWriter::<HasherChallenger<Groestl256>, _>::with_spill_threshold(
+				Vec::new(),
+				7,
+			);
+
+		for i in 0..100u32 {
+			in_memory.write_scalar(BinaryField8b::new(i as u8));
+			streaming.write_scalar(BinaryField8b::new(i as u8));
+			in_memory.write_scalar(BinaryField32b::new(i * 0x01010101));
+			streaming.write_scalar(BinaryField32b::new(i * 0x01010101));
+			in_memory.write_bytes(&[i as u8; 13]);
+			streaming.write_bytes(&[i as u8; 13]);
+
+			let expected: BinaryField128b = in_memory.sample();
+			let sampled: BinaryField128b = streaming.sample();
+			assert_eq!(sampled, expected);
+			assert_eq!(in_memory.sample_bits(20), streaming.sample_bits(20));
+		}
+
+		assert_eq!(streaming.len(), 100 * 18);
+		let expected = in_memory.finalize();
+		assert_eq!(streaming.finalize().unwrap(), expected);
+	}
+
+	struct FailingWriter;
+
+	impl io::Write for FailingWriter {
+		fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+			Err(io::Error::other("sink closed"))
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn test_streaming_reports_sink_errors() {
+		let mut streaming =
+			StreamingTranscriptWriter::<HasherChallenger<Groestl256>, _>::with_spill_threshold(
+				FailingWriter,
+				4,
+			);
+		streaming.write_bytes(&[0; 32]);
+		assert!(matches!(streaming.finalize(), Err(Error::Io(_))));
+	}
+}
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::any::Any;
+
 use binius_field::{ExtensionField, TowerField};
 use binius_utils::bail;
 
@@ -24,6 +26,35 @@ impl<F: TowerField> Constant<F> {
 			n_vars,
 		}
 	}
+
+	/// Creates a constant polynomial from a value of `F` that lies in the subfield at
+	/// `tower_level`, as returned by [`Self::value`] and [`MultivariatePoly::binary_tower_level`].
+	pub(crate) fn with_tower_level(
+		n_vars: usize,
+		value: F,
+		tower_level: usize,
+	) -> Result<Self, Error> {
+		super::check_n_vars(n_vars)?;
+		if tower_level > F::TOWER_LEVEL {
+			bail!(Error::ArgumentRangeError {
+				arg: "tower_level".into(),
+				range: 0..F::TOWER_LEVEL + 1,
+			});
+		}
+		Ok(Self {
+			n_vars,
+			value,
+			tower_level,
+		})
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn value(&self) -> F {
+		self.value
+	}
 }
 
 impl<F: TowerField> MultivariatePoly<F> for Constant<F> {
@@ -47,4 +78,8 @@ impl<F: TowerField> MultivariatePoly<F> for Constant<F> {
 	fn binary_tower_level(&self) -> usize {
 		self.tower_level
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
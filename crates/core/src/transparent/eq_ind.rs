@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::any::Any;
+
 use binius_field::{Field, PackedField, TowerField};
 use binius_hal::ComputationBackend;
 use binius_math::MultilinearExtension;
@@ -31,6 +33,10 @@ impl<F: Field> EqIndPartialEval<F> {
 		self.n_vars
 	}
 
+	pub fn r(&self) -> &[F] {
+		&self.r
+	}
+
 	pub fn multilinear_extension<P: PackedField<Scalar = F>, Backend: ComputationBackend>(
 		&self,
 		backend: &Backend,
@@ -68,6 +74,10 @@ impl<F: TowerField, P: PackedField<Scalar = F>> MultivariatePoly<P> for EqIndPar
 	fn binary_tower_level(&self) -> usize {
 		F::TOWER_LEVEL
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
 
 #[cfg(test)]
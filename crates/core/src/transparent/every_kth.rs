@@ -0,0 +1,189 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::any::Any;
+
+use binius_field::{Field, PackedField};
+use binius_math::MultilinearExtension;
+use binius_utils::bail;
+
+use crate::polynomial::{Error, MultivariatePoly};
+
+/// Represents a multilinear F2-polynomial whose evaluations over the hypercube are 1 at every
+/// `(1 << log_k)`-th index, starting at `offset`, and 0 everywhere else.
+///
+/// ```txt
+///     (1 << n_vars)
+/// <-------------------->
+/// 0,1,0,0,0,1,0,0, .. 0,1,0,0
+///   ^       ^
+///   offset  offset + (1 << log_k)
+/// ```
+///
+/// The polynomial only depends on the lowest `log_k` variables, where it is the equality
+/// indicator of `offset`, so the verifier evaluates it in time linear in `log_k`. With `log_k = 1`
+/// it selects the even or the odd rows. This is useful for making constraints that are enforced
+/// once per block of rows.
+#[derive(Debug, Clone)]
+pub struct EveryKth {
+	n_vars: usize,
+	log_k: usize,
+	offset: usize,
+}
+
+impl EveryKth {
+	pub fn new(n_vars: usize, log_k: usize, offset: usize) -> Result<Self, Error> {
+		super::check_n_vars(n_vars)?;
+		if log_k > n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "log_k".into(),
+				range: 0..n_vars + 1,
+			})
+		}
+		if offset >= 1 << log_k {
+			bail!(Error::ArgumentRangeError {
+				arg: "offset".into(),
+				range: 0..(1 << log_k),
+			})
+		}
+		Ok(Self {
+			n_vars,
+			log_k,
+			offset,
+		})
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn log_k(&self) -> usize {
+		self.log_k
+	}
+
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	pub fn multilinear_extension<P: PackedField>(&self) -> Result<MultilinearExtension<P>, Error> {
+		if self.n_vars < P::LOG_WIDTH {
+			bail!(Error::PackedFieldNotFilled {
+				length: 1 << self.n_vars,
+				packed_width: 1 << P::LOG_WIDTH,
+			});
+		}
+		let log_packed_length = self.n_vars - P::LOG_WIDTH;
+		let mut data = vec![P::zero(); 1 << log_packed_length];
+		self.populate(&mut data);
+		Ok(MultilinearExtension::from_values(data)?)
+	}
+
+	pub fn populate<P: PackedField>(&self, data: &mut [P]) {
+		data.fill(P::zero());
+		for i in (self.offset..data.len() * P::WIDTH).step_by(1 << self.log_k) {
+			data[i / P::WIDTH].set(i % P::WIDTH, P::Scalar::ONE);
+		}
+	}
+}
+
+impl<F: Field> MultivariatePoly<F> for EveryKth {
+	fn degree(&self) -> usize {
+		self.log_k
+	}
+
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		let n_vars = MultivariatePoly::<F>::n_vars(self);
+		if query.len() != n_vars {
+			bail!(Error::IncorrectQuerySize { expected: n_vars });
+		}
+		let mut k = self.offset;
+		let mut result = F::ONE;
+		for q in &query[..self.log_k] {
+			if k & 1 == 1 {
+				result *= q;
+			} else {
+				result *= F::ONE - q;
+			}
+			k >>= 1;
+		}
+		Ok(result)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, PackedBinaryField128x1b, PackedField};
+	use binius_utils::felts;
+
+	use super::EveryKth;
+	use crate::polynomial::test_utils::{hypercube_evals_from_oracle, packed_slice};
+
+	#[test]
+	fn test_every_kth_evals_simple_cases() {
+		assert_eq!(every_kth_evals::<BinaryField1b>(2, 0, 0), felts!(BinaryField1b[1, 1, 1, 1]));
+		assert_eq!(every_kth_evals::<BinaryField1b>(2, 1, 0), felts!(BinaryField1b[1, 0, 1, 0]));
+		assert_eq!(every_kth_evals::<BinaryField1b>(2, 1, 1), felts!(BinaryField1b[0, 1, 0, 1]));
+		assert_eq!(
+			every_kth_evals::<BinaryField1b>(3, 2, 3),
+			felts!(BinaryField1b[0, 0, 0, 1, 0, 0, 0, 1])
+		);
+	}
+
+	#[test]
+	fn test_every_kth_evals_with_packing_128() {
+		let expected = (0..1024)
+			.map(|i| (i..i + 1, (i % 32 == 5) as u8))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			every_kth_evals::<PackedBinaryField128x1b>(10, 5, 5),
+			packed_slice::<PackedBinaryField128x1b>(&expected)
+		);
+	}
+
+	#[test]
+	fn test_every_kth_rejects_invalid_arguments() {
+		assert!(EveryKth::new(3, 4, 0).is_err());
+		assert!(EveryKth::new(3, 2, 4).is_err());
+	}
+
+	#[test]
+	fn test_consistency_between_multilinear_extension_and_multilinear_poly_oracle() {
+		for n_vars in 1..6 {
+			for log_k in 0..=n_vars {
+				for offset in 0..1 << log_k {
+					let every_kth = EveryKth::new(n_vars, log_k, offset).unwrap();
+					assert_eq!(
+						hypercube_evals_from_oracle::<BinaryField1b>(&every_kth),
+						every_kth
+							.multilinear_extension::<BinaryField1b>()
+							.unwrap()
+							.evals()
+					);
+				}
+			}
+		}
+	}
+
+	fn every_kth_evals<P>(n_vars: usize, log_k: usize, offset: usize) -> Vec<P>
+	where
+		P: PackedField<Scalar = BinaryField1b>,
+	{
+		EveryKth::new(n_vars, log_k, offset)
+			.unwrap()
+			.multilinear_extension::<P>()
+			.unwrap()
+			.evals()
+			.to_vec()
+	}
+}
@@ -0,0 +1,245 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::collections::BTreeMap;
+
+use binius_field::{ExtensionField, Field, PackedField, TowerField};
+use binius_math::{ArithExpr, MultilinearExtension};
+use binius_utils::bail;
+
+use crate::polynomial::{Error, MultivariatePoly};
+
+/// A multilinear polynomial defined by an arithmetic expression over the bits of the hypercube
+/// index.
+///
+/// The value at hypercube vertex $i$ is the expression evaluated at the bit decomposition of $i$,
+/// where variable $j$ of the expression is bit $j$ of the index. This describes structured
+/// columns, such as periodic selectors or counters, without committing them.
+///
+/// Since every bit squares to itself, the expression is reduced on construction to its multilinear
+/// form, a sum of products of distinct variables, which is the multilinear extension of the column.
+/// The verifier then evaluates it in time linear in the number of monomials. Note that the number
+/// of monomials may grow exponentially with the number of factors in the expression.
+#[derive(Debug, Clone)]
+pub struct IndexExpr<F: Field> {
+	n_vars: usize,
+	/// Monomials of the multilinear form, each a bitmask of its variables and a coefficient.
+	monomials: Vec<(u64, F)>,
+	tower_level: usize,
+}
+
+impl<F: TowerField> IndexExpr<F> {
+	pub fn new<FS: TowerField>(n_vars: usize, expr: &ArithExpr<FS>) -> Result<Self, Error>
+	where
+		F: ExtensionField<FS>,
+	{
+		if n_vars > u64::BITS as usize {
+			bail!(Error::ArgumentRangeError {
+				arg: "n_vars".into(),
+				range: 0..u64::BITS as usize + 1,
+			});
+		}
+		if expr.n_vars() > n_vars {
+			bail!(Error::IncorrectNumberOfVariables {
+				expected: n_vars,
+				actual: expr.n_vars(),
+			});
+		}
+
+		let monomials = multilinear_form(&expr.convert_field::<F>())
+			.into_iter()
+			.collect();
+
+		Ok(Self {
+			n_vars,
+			monomials,
+			tower_level: FS::TOWER_LEVEL,
+		})
+	}
+
+	/// The value at the hypercube vertex with the given index.
+	pub fn evaluate_on_hypercube(&self, index: usize) -> F {
+		self.monomials
+			.iter()
+			.filter(|(mask, _)| mask & !(index as u64) == 0)
+			.map(|(_, coeff)| *coeff)
+			.sum()
+	}
+
+	pub fn multilinear_extension<P: PackedField<Scalar = F>>(
+		&self,
+	) -> Result<MultilinearExtension<P>, Error> {
+		let values = (0..1 << self.n_vars.saturating_sub(P::LOG_WIDTH))
+			.map(|i| P::from_fn(|j| self.evaluate_on_hypercube((i << P::LOG_WIDTH) | j)))
+			.collect();
+		Ok(MultilinearExtension::new(self.n_vars, values)?)
+	}
+}
+
+impl<F: TowerField> MultivariatePoly<F> for IndexExpr<F> {
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn degree(&self) -> usize {
+		self.monomials
+			.iter()
+			.map(|(mask, _)| mask.count_ones() as usize)
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		if query.len() != self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		Ok(self
+			.monomials
+			.iter()
+			.map(|&(mask, coeff)| {
+				query
+					.iter()
+					.enumerate()
+					.filter(|(i, _)| (mask >> i) & 1 == 1)
+					.fold(coeff, |acc, (_, &q)| acc * q)
+			})
+			.sum())
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.tower_level
+	}
+}
+
+type Monomials<F> = BTreeMap<u64, F>;
+
+/// Reduces an expression over Boolean variables to a sum of products of distinct variables.
+fn multilinear_form<F: Field>(expr: &ArithExpr<F>) -> Monomials<F> {
+	match expr {
+		ArithExpr::Const(value) => add_monomials(Monomials::new(), [(0, *value)]),
+		ArithExpr::Var(index) => Monomials::from([(1 << index, F::ONE)]),
+		ArithExpr::Add(left, right) => {
+			add_monomials(multilinear_form(left), multilinear_form(right))
+		}
+		ArithExpr::Mul(left, right) => {
+			mul_monomials(&multilinear_form(left), &multilinear_form(right))
+		}
+		ArithExpr::Pow(base, exp) => {
+			let mut base = multilinear_form(base);
+			let mut result = Monomials::from([(0, F::ONE)]);
+			let mut exp = *exp;
+			while exp > 0 {
+				if exp & 1 == 1 {
+					result = mul_monomials(&result, &base);
+				}
+				exp >>= 1;
+				if exp > 0 {
+					base = mul_monomials(&base, &base);
+				}
+			}
+			result
+		}
+	}
+}
+
+fn add_monomials<F: Field>(
+	mut lhs: Monomials<F>,
+	rhs: impl IntoIterator<Item = (u64, F)>,
+) -> Monomials<F> {
+	for (mask, coeff) in rhs {
+		let sum = *lhs.entry(mask).or_insert(F::ZERO) + coeff;
+		if sum == F::ZERO {
+			lhs.remove(&mask);
+		} else {
+			lhs.insert(mask, sum);
+		}
+	}
+	lhs
+}
+
+fn mul_monomials<F: Field>(lhs: &Monomials<F>, rhs: &Monomials<F>) -> Monomials<F> {
+	let products = lhs.iter().flat_map(|(&lhs_mask, &lhs_coeff)| {
+		rhs.iter()
+			.map(move |(&rhs_mask, &rhs_coeff)| (lhs_mask | rhs_mask, lhs_coeff * rhs_coeff))
+	});
+	add_monomials(Monomials::new(), products)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField128b, BinaryField8b, Field, PackedBinaryField1x128b};
+	use binius_hal::{make_portable_backend, ComputationBackendExt};
+	use binius_math::{ArithExpr, CompositionPoly};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::IndexExpr;
+	use crate::polynomial::{
+		test_utils::decompose_index_to_hypercube_point, ArithCircuitPoly, MultivariatePoly,
+	};
+
+	type F = BinaryField128b;
+
+	fn test_expr() -> ArithExpr<BinaryField8b> {
+		let x = |i| ArithExpr::<BinaryField8b>::Var(i);
+		let c = ArithExpr::Const(BinaryField8b::new(0x2a));
+		(x(0) + x(1) * c.clone()).pow(3) * x(2) + (ArithExpr::one() + x(3)) * x(0) * x(0) + c
+	}
+
+	#[test]
+	fn test_hypercube_values_match_expression() {
+		let n_vars = 5;
+		let expr = test_expr();
+		let index_expr = IndexExpr::<F>::new(n_vars, &expr).unwrap();
+		let circuit = ArithCircuitPoly::with_n_vars(n_vars, expr.convert_field::<F>()).unwrap();
+
+		for i in 0..1 << n_vars {
+			let point = decompose_index_to_hypercube_point::<F>(n_vars, i);
+			assert_eq!(
+				index_expr.evaluate_on_hypercube(i),
+				circuit.evaluate(point.as_slice()).unwrap()
+			);
+			assert_eq!(
+				index_expr.evaluate(&point).unwrap(),
+				circuit.evaluate(point.as_slice()).unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_evaluation_matches_multilinear_extension() {
+		let n_vars = 5;
+		let index_expr = IndexExpr::<F>::new(n_vars, &test_expr()).unwrap();
+		let mle = index_expr
+			.multilinear_extension::<PackedBinaryField1x128b>()
+			.unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let query = make_portable_backend()
+			.multilinear_query::<F>(&point)
+			.unwrap();
+
+		assert_eq!(index_expr.evaluate(&point).unwrap(), mle.evaluate(query.to_ref()).unwrap());
+		assert_eq!(index_expr.binary_tower_level(), 3);
+	}
+
+	#[test]
+	fn test_periodic_selector() {
+		// Selects every fourth row: (1 - x0) * (1 - x1).
+		let x = |i| ArithExpr::<BinaryField8b>::Var(i);
+		let expr = (ArithExpr::one() + x(0)) * (ArithExpr::one() + x(1));
+		let index_expr = IndexExpr::<F>::new(6, &expr).unwrap();
+
+		assert_eq!(MultivariatePoly::<F>::degree(&index_expr), 2);
+		for i in 0..1 << 6 {
+			let expected = if i % 4 == 0 { F::ONE } else { F::ZERO };
+			assert_eq!(index_expr.evaluate_on_hypercube(i), expected);
+		}
+	}
+}
@@ -0,0 +1,209 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::any::Any;
+
+use binius_field::{Field, PackedField};
+use binius_math::MultilinearExtension;
+use binius_utils::bail;
+
+use super::step_down::StepDown;
+use crate::polynomial::{Error, MultivariatePoly};
+
+/// Represents a multilinear F2-polynomial whose evaluations over the hypercube are 1 on the
+/// indices in the range `lo..hi`, and 0 everywhere else.
+///
+/// ```txt
+///     (1 << n_vars)
+/// <-------------------->
+/// 0,0 .. 0,1,1 .. 1,0 .. 0
+///          ^        ^
+///          lo       hi
+/// ```
+///
+/// The polynomial is the difference of the step downs at `hi` and at `lo`, which is how the
+/// verifier evaluates it. This is useful for making constraints that are only enforced in a window
+/// of the trace.
+#[derive(Debug, Clone)]
+pub struct Interval {
+	n_vars: usize,
+	lo: usize,
+	hi: usize,
+}
+
+impl Interval {
+	pub fn new(n_vars: usize, lo: usize, hi: usize) -> Result<Self, Error> {
+		super::check_n_vars(n_vars)?;
+		if hi > 1 << n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "hi".into(),
+				range: 0..(1 << n_vars) + 1,
+			})
+		}
+		if lo > hi {
+			bail!(Error::ArgumentRangeError {
+				arg: "lo".into(),
+				range: 0..hi + 1,
+			})
+		}
+		Ok(Self { n_vars, lo, hi })
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn lo(&self) -> usize {
+		self.lo
+	}
+
+	pub fn hi(&self) -> usize {
+		self.hi
+	}
+
+	pub fn multilinear_extension<P: PackedField>(&self) -> Result<MultilinearExtension<P>, Error> {
+		if self.n_vars < P::LOG_WIDTH {
+			bail!(Error::PackedFieldNotFilled {
+				length: 1 << self.n_vars,
+				packed_width: 1 << P::LOG_WIDTH,
+			});
+		}
+		let log_packed_length = self.n_vars - P::LOG_WIDTH;
+		let mut data = vec![P::zero(); 1 << log_packed_length];
+		self.populate(&mut data);
+		Ok(MultilinearExtension::from_values(data)?)
+	}
+
+	pub fn populate<P: PackedField>(&self, data: &mut [P]) {
+		data.fill(P::zero());
+		// the packed elements that lie entirely inside the interval are filled at once
+		let start = self.lo.div_ceil(P::WIDTH);
+		let end = (self.hi / P::WIDTH).max(start);
+		data[start..end].fill(P::one());
+		for i in (self.lo..self.hi).filter(|i| !(start..end).contains(&(i / P::WIDTH))) {
+			data[i / P::WIDTH].set(i % P::WIDTH, P::Scalar::ONE);
+		}
+	}
+}
+
+impl<F: Field> MultivariatePoly<F> for Interval {
+	fn degree(&self) -> usize {
+		self.n_vars
+	}
+
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		let hi = StepDown::new(self.n_vars, self.hi)?.evaluate(query)?;
+		let lo = StepDown::new(self.n_vars, self.lo)?.evaluate(query)?;
+		Ok(hi - lo)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, Field, PackedBinaryField128x1b, PackedField};
+	use binius_utils::felts;
+
+	use super::Interval;
+	use crate::{
+		oracle::MAX_N_VARS,
+		polynomial::{
+			test_utils::{
+				decompose_index_to_hypercube_point, hypercube_evals_from_oracle, packed_slice,
+			},
+			MultivariatePoly,
+		},
+	};
+
+	#[test]
+	fn test_interval_evals_simple_cases() {
+		assert_eq!(interval_evals::<BinaryField1b>(2, 0, 0), felts!(BinaryField1b[0, 0, 0, 0]));
+		assert_eq!(interval_evals::<BinaryField1b>(2, 1, 3), felts!(BinaryField1b[0, 1, 1, 0]));
+		assert_eq!(interval_evals::<BinaryField1b>(2, 0, 4), felts!(BinaryField1b[1, 1, 1, 1]));
+		assert_eq!(
+			interval_evals::<BinaryField1b>(3, 2, 7),
+			felts!(BinaryField1b[0, 0, 1, 1, 1, 1, 1, 0])
+		);
+	}
+
+	#[test]
+	fn test_interval_evals_with_packing_128() {
+		assert_eq!(
+			interval_evals::<PackedBinaryField128x1b>(10, 100, 555),
+			packed_slice::<PackedBinaryField128x1b>(&[(0..100, 0), (100..555, 1), (555..1024, 0)])
+		);
+		assert_eq!(
+			interval_evals::<PackedBinaryField128x1b>(10, 130, 140),
+			packed_slice::<PackedBinaryField128x1b>(&[(0..130, 0), (130..140, 1), (140..1024, 0)])
+		);
+		assert_eq!(
+			interval_evals::<PackedBinaryField128x1b>(10, 128, 1024),
+			packed_slice::<PackedBinaryField128x1b>(&[(0..128, 0), (128..1024, 1)])
+		);
+	}
+
+	#[test]
+	fn test_interval_rejects_invalid_bounds() {
+		assert!(Interval::new(3, 0, 9).is_err());
+		assert!(Interval::new(3, 5, 4).is_err());
+	}
+
+	#[test]
+	fn test_consistency_between_multilinear_extension_and_multilinear_poly_oracle() {
+		for n_vars in 1..5 {
+			for hi in 0..=(1 << n_vars) {
+				for lo in 0..=hi {
+					let interval = Interval::new(n_vars, lo, hi).unwrap();
+					assert_eq!(
+						hypercube_evals_from_oracle::<BinaryField1b>(&interval),
+						interval
+							.multilinear_extension::<BinaryField1b>()
+							.unwrap()
+							.evals()
+					);
+				}
+			}
+		}
+	}
+
+	fn interval_evals<P>(n_vars: usize, lo: usize, hi: usize) -> Vec<P>
+	where
+		P: PackedField<Scalar = BinaryField1b>,
+	{
+		Interval::new(n_vars, lo, hi)
+			.unwrap()
+			.multilinear_extension::<P>()
+			.unwrap()
+			.evals()
+			.to_vec()
+	}
+
+	#[test]
+	fn test_interval_large_n_vars() {
+		let n_vars = 36;
+		let (lo, hi) = ((1 << 33) + 1, (1 << 35) + 2);
+		let interval = Interval::new(n_vars, lo, hi).unwrap();
+		for (row, expected) in [
+			(1, BinaryField1b::ZERO),
+			(lo - 1, BinaryField1b::ZERO),
+			(lo, BinaryField1b::ONE),
+			(hi - 1, BinaryField1b::ONE),
+			(hi, BinaryField1b::ZERO),
+			((1 << 32) + 1, BinaryField1b::ZERO),
+		] {
+			let query = decompose_index_to_hypercube_point::<BinaryField1b>(n_vars, row);
+			assert_eq!(interval.evaluate(&query).unwrap(), expected);
+		}
+		assert!(Interval::new(MAX_N_VARS + 1, 0, 0).is_err());
+	}
+}
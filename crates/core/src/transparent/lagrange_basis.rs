@@ -0,0 +1,128 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_field::{Field, PackedField, TowerField};
+use binius_math::{
+	eq_ind_partial_eval, EvaluationDomain, EvaluationDomainFactory,
+	IsomorphicEvaluationDomainFactory, MultilinearExtension,
+};
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+use itertools::izip;
+
+use crate::polynomial::{Error, MultivariatePoly};
+
+/// The multilinear extension of the Lagrange basis of a univariate domain, evaluated at a point.
+///
+/// The value at hypercube vertex $i$ is $L_i(z)$, where $L_i$ is the $i$-th Lagrange basis
+/// polynomial of the univariate skip domain of size $2^k$ and $z$ is the given point.
+#[derive(Debug, Clone)]
+pub struct LagrangeBasis<F: Field> {
+	log_domain_size: usize,
+	lagrange_evals: Vec<F>,
+}
+
+impl<F: TowerField> LagrangeBasis<F> {
+	pub fn new(log_domain_size: usize, point: F) -> Result<Self, Error> {
+		let domain: EvaluationDomain<F> =
+			IsomorphicEvaluationDomainFactory::<F::Canonical>::default()
+				.create(1 << log_domain_size)?;
+		Ok(Self {
+			log_domain_size,
+			lagrange_evals: domain.lagrange_evals(point),
+		})
+	}
+
+	pub fn multilinear_extension<P: PackedField<Scalar = F>>(
+		&self,
+	) -> Result<MultilinearExtension<P>, Error> {
+		let mut values = zeroed_vec(1 << self.log_domain_size.saturating_sub(P::LOG_WIDTH));
+		for (dest, chunk) in izip!(&mut values, self.lagrange_evals.chunks(P::WIDTH)) {
+			*dest = P::from_scalars(chunk.iter().copied());
+		}
+		Ok(MultilinearExtension::new(self.log_domain_size, values)?)
+	}
+}
+
+impl<F: TowerField> MultivariatePoly<F> for LagrangeBasis<F> {
+	fn n_vars(&self) -> usize {
+		self.log_domain_size
+	}
+
+	fn degree(&self) -> usize {
+		self.log_domain_size
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		if query.len() != self.log_domain_size {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.log_domain_size
+			});
+		}
+
+		let eq_ind = eq_ind_partial_eval::<F>(query);
+		Ok(izip!(&self.lagrange_evals, eq_ind)
+			.map(|(&lagrange_eval, eq_ind)| lagrange_eval * eq_ind)
+			.sum())
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		F::TOWER_LEVEL
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, Field, PackedBinaryField2x128b};
+	use binius_math::{EvaluationDomainFactory, IsomorphicEvaluationDomainFactory};
+	use rand::{prelude::StdRng, SeedableRng};
+
+	use super::LagrangeBasis;
+	use crate::polynomial::MultivariatePoly;
+
+	#[test]
+	fn test_interpolates_univariate_polynomial() {
+		type F = BinaryField128b;
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_domain_size = 3;
+
+		let domain = IsomorphicEvaluationDomainFactory::<F>::default()
+			.create(1 << log_domain_size)
+			.unwrap();
+		let coeffs = (0..1 << log_domain_size)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let univariate = |x: F| {
+			coeffs
+				.iter()
+				.rev()
+				.fold(F::ZERO, |acc, &coeff| acc * x + coeff)
+		};
+
+		let point = <F as Field>::random(&mut rng);
+		let lagrange_basis = LagrangeBasis::new(log_domain_size, point).unwrap();
+		let mle = lagrange_basis
+			.multilinear_extension::<PackedBinaryField2x128b>()
+			.unwrap();
+
+		// Summing the domain evaluations against the basis recovers the polynomial at the point.
+		let interpolated = domain
+			.points()
+			.iter()
+			.enumerate()
+			.map(|(i, &domain_point)| {
+				univariate(domain_point) * mle.evaluate_on_hypercube(i).unwrap()
+			})
+			.sum::<F>();
+		assert_eq!(interpolated, univariate(point));
+
+		for i in 0..1 << log_domain_size {
+			let query = (0..log_domain_size)
+				.map(|j| if (i >> j) & 1 == 0 { F::ZERO } else { F::ONE })
+				.collect::<Vec<_>>();
+			assert_eq!(
+				lagrange_basis.evaluate(&query).unwrap(),
+				mle.evaluate_on_hypercube(i).unwrap()
+			);
+		}
+	}
+}
@@ -3,6 +3,10 @@
 pub mod constant;
 pub mod disjoint_product;
 pub mod eq_ind;
+pub mod every_kth;
+pub mod index_expr;
+pub mod interval;
+pub mod lagrange_basis;
 pub mod multilinear_extension;
 pub mod powers;
 pub mod select_row;
@@ -11,4 +15,19 @@ pub mod step_down;
 pub mod step_up;
 pub mod tower_basis;
 
+use binius_utils::bail;
 pub use multilinear_extension::*;
+
+use crate::{oracle::MAX_N_VARS, polynomial::Error};
+
+/// Checks that every row of a transparent polynomial with `n_vars` variables can be indexed, see
+/// [`MAX_N_VARS`].
+fn check_n_vars(n_vars: usize) -> Result<(), Error> {
+	if n_vars > MAX_N_VARS {
+		bail!(Error::ArgumentRangeError {
+			arg: "n_vars".into(),
+			range: 0..MAX_N_VARS + 1,
+		});
+	}
+	Ok(())
+}
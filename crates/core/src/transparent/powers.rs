@@ -1,13 +1,12 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter::successors;
+use std::{any::Any, iter::successors};
 
 use binius_field::{Field, PackedField, TowerField};
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use bytemuck::zeroed_vec;
 use itertools::{izip, Itertools};
-use rayon::prelude::*;
 
 use crate::polynomial::{Error, MultivariatePoly};
 
@@ -24,6 +23,14 @@ impl<F: Field> Powers<F> {
 		Powers { n_vars, base }
 	}
 
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn base(&self) -> F {
+		self.base
+	}
+
 	pub fn multilinear_extension<P: PackedField<Scalar = F>>(
 		&self,
 	) -> Result<MultilinearExtension<P>, Error> {
@@ -77,6 +84,10 @@ impl<F: TowerField, P: PackedField<Scalar = F>> MultivariatePoly<P> for Powers<F
 	fn binary_tower_level(&self) -> usize {
 		F::TOWER_LEVEL
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::any::Any;
+
 use binius_field::{packed::set_packed_slice, BinaryField1b, Field, PackedField};
 use binius_math::MultilinearExtension;
 use binius_utils::bail;
@@ -26,6 +28,7 @@ pub struct SelectRow {
 
 impl SelectRow {
 	pub fn new(n_vars: usize, index: usize) -> Result<Self, Error> {
+		super::check_n_vars(n_vars)?;
 		if index >= (1 << n_vars) {
 			bail!(Error::ArgumentRangeError {
 				arg: "index".into(),
@@ -36,6 +39,14 @@ impl SelectRow {
 		}
 	}
 
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
 	pub fn multilinear_extension<P: PackedField<Scalar = BinaryField1b>>(
 		&self,
 	) -> Result<MultilinearExtension<P>, Error> {
@@ -83,17 +94,29 @@ impl<F: Field> MultivariatePoly<F> for SelectRow {
 	fn binary_tower_level(&self) -> usize {
 		0
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use binius_field::{
-		BinaryField1b, PackedBinaryField128x1b, PackedBinaryField256x1b, PackedField,
+		BinaryField1b, Field, PackedBinaryField128x1b, PackedBinaryField256x1b, PackedField,
 	};
 	use binius_utils::felts;
 
 	use super::SelectRow;
-	use crate::polynomial::test_utils::{hypercube_evals_from_oracle, packed_slice};
+	use crate::{
+		oracle::MAX_N_VARS,
+		polynomial::{
+			test_utils::{
+				decompose_index_to_hypercube_point, hypercube_evals_from_oracle, packed_slice,
+			},
+			MultivariatePoly,
+		},
+	};
 
 	#[test]
 	fn test_select_row_evals_without_packing_simple_cases() {
@@ -210,4 +233,21 @@ mod tests {
 			.evals()
 			.to_vec()
 	}
+
+	#[test]
+	fn test_select_row_large_n_vars() {
+		let n_vars = 34;
+		let index = (1 << 33) + 7;
+		let select_row = SelectRow::new(n_vars, index).unwrap();
+		for (row, expected) in [
+			(7, BinaryField1b::ZERO),
+			((1 << 32) + 7, BinaryField1b::ZERO),
+			(index, BinaryField1b::ONE),
+		] {
+			let query = decompose_index_to_hypercube_point::<BinaryField1b>(n_vars, row);
+			assert_eq!(select_row.evaluate(&query).unwrap(), expected);
+		}
+		assert!(SelectRow::new(n_vars, 1 << n_vars).is_err());
+		assert!(SelectRow::new(MAX_N_VARS + 1, 0).is_err());
+	}
 }
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::any::Any;
+
 use binius_field::{util::eq, Field, PackedFieldIndexable, TowerField};
 use binius_math::MultilinearExtension;
 use binius_utils::bail;
@@ -18,6 +20,10 @@ use crate::{
 ///     * $f(x, y) = 1$ if $\{y\} - \{o\} \equiv \{x\} (\text{mod } 2^b)$
 ///     * $f(x, y) = 0$ otw
 ///
+/// Else if ShiftVariant is CircularRight:
+///     * $f(x, y) = 1$ if $\{y\} + \{o\} \equiv \{x\} (\text{mod } 2^b)$
+///     * $f(x, y) = 0$ otw
+///
 /// Else if ShiftVariant is LogicalLeft:
 ///    * $f(x, y) = 1$ if $\{y\} - \{o\} \equiv \{x\}$
 ///    * $f(x, y) = 0$ otw
@@ -43,6 +49,7 @@ use crate::{
 ///
 /// # Note
 /// CircularLeft corresponds to the shift indicator in Section 4.3.
+/// CircularRight is the CircularLeft indicator with offset $2^b - o$.
 /// LogicalLeft corresponds to the shift prime indicator in Section 4.3.
 /// LogicalRight corresponds to the shift double prime indicator in Section 4.3.
 ///
@@ -110,12 +117,31 @@ impl<F: Field> ShiftIndPartialEval<F> {
 		})
 	}
 
-	fn multilinear_extension_circular<P>(&self) -> Result<MultilinearExtension<P>, Error>
+	pub fn block_size(&self) -> usize {
+		self.block_size
+	}
+
+	pub fn shift_offset(&self) -> usize {
+		self.shift_offset
+	}
+
+	pub fn shift_variant(&self) -> ShiftVariant {
+		self.shift_variant
+	}
+
+	pub fn r(&self) -> &[F] {
+		&self.r
+	}
+
+	fn multilinear_extension_circular<P>(
+		&self,
+		left_shift_offset: usize,
+	) -> Result<MultilinearExtension<P>, Error>
 	where
 		P: PackedFieldIndexable<Scalar = F>,
 	{
 		let (ps, pps) =
-			partial_evaluate_hypercube_impl::<P>(self.block_size, self.shift_offset, &self.r)?;
+			partial_evaluate_hypercube_impl::<P>(self.block_size, left_shift_offset, &self.r)?;
 		let values = ps
 			.iter()
 			.zip(pps)
@@ -150,7 +176,10 @@ impl<F: Field> ShiftIndPartialEval<F> {
 		P: PackedFieldIndexable<Scalar = F>,
 	{
 		match self.shift_variant {
-			ShiftVariant::CircularLeft => self.multilinear_extension_circular(),
+			ShiftVariant::CircularLeft => self.multilinear_extension_circular(self.shift_offset),
+			ShiftVariant::CircularRight => self.multilinear_extension_circular(
+				get_left_shift_offset(self.block_size, self.shift_offset),
+			),
 			ShiftVariant::LogicalLeft => self.multilinear_extension_logical_left(),
 			ShiftVariant::LogicalRight => self.multilinear_extension_logical_right(),
 		}
@@ -167,14 +196,16 @@ impl<F: Field> ShiftIndPartialEval<F> {
 		let left_shift_offset = match self.shift_variant {
 			ShiftVariant::CircularLeft => self.shift_offset,
 			ShiftVariant::LogicalLeft => self.shift_offset,
-			ShiftVariant::LogicalRight => get_left_shift_offset(self.block_size, self.shift_offset),
+			ShiftVariant::CircularRight | ShiftVariant::LogicalRight => {
+				get_left_shift_offset(self.block_size, self.shift_offset)
+			}
 		};
 
 		let (p_res, pp_res) =
 			evaluate_shift_ind_help(self.block_size, left_shift_offset, x, &self.r)?;
 
 		match self.shift_variant {
-			ShiftVariant::CircularLeft => Ok(p_res + pp_res),
+			ShiftVariant::CircularLeft | ShiftVariant::CircularRight => Ok(p_res + pp_res),
 			ShiftVariant::LogicalLeft => Ok(p_res),
 			ShiftVariant::LogicalRight => Ok(pp_res),
 		}
@@ -197,6 +228,10 @@ impl<F: TowerField> MultivariatePoly<F> for ShiftIndPartialEval<F> {
 	fn binary_tower_level(&self) -> usize {
 		F::TOWER_LEVEL
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
 
 /// Gets right shift offset from left shift offset
@@ -210,6 +245,7 @@ fn assert_valid_shift_ind_args<F: Field>(
 	shift_offset: usize,
 	partial_query_point: &[F],
 ) -> Result<(), Error> {
+	super::check_n_vars(block_size)?;
 	if partial_query_point.len() != block_size {
 		bail!(Error::IncorrectQuerySize {
 			expected: block_size,
@@ -385,6 +421,37 @@ mod tests {
 		assert_eq!(eval_mle, eval_mvp);
 	}
 
+	fn test_circular_right_shift_consistency_help<
+		F: TowerField,
+		P: PackedFieldIndexable<Scalar = F>,
+	>(
+		block_size: usize,
+		left_shift_offset: usize,
+	) {
+		let mut rng = StdRng::seed_from_u64(0);
+		let backend = make_portable_backend();
+		let r = repeat_with(|| F::random(&mut rng))
+			.take(block_size)
+			.collect::<Vec<_>>();
+		let eval_point = &repeat_with(|| F::random(&mut rng))
+			.take(block_size)
+			.collect::<Vec<_>>();
+
+		// Get Multivariate Poly version
+		let shift_variant = ShiftVariant::CircularRight;
+		let shift_r_mvp =
+			ShiftIndPartialEval::new(block_size, left_shift_offset, shift_variant, r).unwrap();
+		let eval_mvp = shift_r_mvp.evaluate(eval_point).unwrap();
+
+		// Get MultilinearExtension version
+		let shift_r_mle = shift_r_mvp.multilinear_extension::<P>().unwrap();
+		let multilin_query = backend.multilinear_query::<P>(eval_point).unwrap();
+		let eval_mle = shift_r_mle.evaluate(&multilin_query).unwrap();
+
+		// Assert equality
+		assert_eq!(eval_mle, eval_mvp);
+	}
+
 	fn test_logical_left_shift_consistency_help<
 		F: TowerField,
 		P: PackedFieldIndexable<Scalar = F>,
@@ -459,6 +526,18 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_circular_right_shift_consistency_schwartz_zippel() {
+		for block_size in 2..=10 {
+			for left_shift_offset in [1, 2, 3, (1 << block_size) - 1, (1 << block_size) / 2] {
+				test_circular_right_shift_consistency_help::<_, PackedBinaryField4x32b>(
+					block_size,
+					left_shift_offset,
+				);
+			}
+		}
+	}
+
 	#[test]
 	fn test_logical_left_shift_consistency_schwartz_zippel() {
 		for block_size in 2..=10 {
@@ -504,6 +583,26 @@ mod tests {
 			});
 		});
 	}
+	fn test_circular_right_shift_functionality_help<F: TowerField>(
+		block_size: usize,
+		left_shift_offset: usize,
+	) {
+		let shift_variant = ShiftVariant::CircularRight;
+		(0..(1 << block_size)).for_each(|i| {
+			let r = decompose_index_to_hypercube_point::<F>(block_size, i);
+			let shift_r_mvp =
+				ShiftIndPartialEval::new(block_size, left_shift_offset, shift_variant, r).unwrap();
+			(0..(1 << block_size)).for_each(|j| {
+				let x = decompose_index_to_hypercube_point::<F>(block_size, j);
+				let eval_mvp = shift_r_mvp.evaluate(&x).unwrap();
+				if (i + left_shift_offset) % (1 << block_size) == j {
+					assert_eq!(eval_mvp, F::ONE);
+				} else {
+					assert_eq!(eval_mvp, F::ZERO);
+				}
+			});
+		});
+	}
 	fn test_logical_left_shift_functionality_help<F: TowerField>(
 		block_size: usize,
 		right_shift_offset: usize,
@@ -564,6 +663,23 @@ mod tests {
 		}
 	}
 	#[test]
+	fn test_circular_right_shift_functionality() {
+		for block_size in 3..5 {
+			for left_shift_offset in [
+				1,
+				3,
+				(1 << block_size) - 1,
+				(1 << block_size) - 2,
+				(1 << (block_size - 1)),
+			] {
+				test_circular_right_shift_functionality_help::<BinaryField32b>(
+					block_size,
+					left_shift_offset,
+				);
+			}
+		}
+	}
+	#[test]
 	fn test_logical_left_shift_functionality() {
 		for block_size in 3..5 {
 			for right_shift_offset in [
@@ -597,4 +713,29 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn test_shift_ind_large_block_size() {
+		let block_size = 40;
+		let offset = (1 << 33) + 3;
+		let point = |index| decompose_index_to_hypercube_point::<BinaryField32b>(block_size, index);
+		// f(x, y) = 1 iff {y} - {o} = {x}, modulo 2^b for the circular variant.
+		for (variant, y, x) in [
+			(ShiftVariant::LogicalLeft, (1 << 35) + 10, (1 << 35) + 7 - (1 << 33)),
+			(ShiftVariant::CircularLeft, 1, (1 << 40) - (1 << 33) - 2),
+		] {
+			let shift_ind =
+				ShiftIndPartialEval::new(block_size, offset, variant, point(y)).unwrap();
+			assert_eq!(shift_ind.evaluate(&point(x)).unwrap(), BinaryField32b::ONE);
+			// Dropping the bits above 32 of the shifted index must not give another match.
+			assert_eq!(shift_ind.evaluate(&point(x ^ (1 << 32))).unwrap(), BinaryField32b::ZERO);
+		}
+		assert!(ShiftIndPartialEval::new(
+			block_size,
+			1 << block_size,
+			ShiftVariant::LogicalLeft,
+			point(0)
+		)
+		.is_err());
+	}
 }
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::any::Any;
+
 use binius_field::{Field, PackedField};
 use binius_math::MultilinearExtension;
 use binius_utils::bail;
@@ -28,6 +30,7 @@ pub struct StepDown {
 
 impl StepDown {
 	pub fn new(n_vars: usize, index: usize) -> Result<Self, Error> {
+		super::check_n_vars(n_vars)?;
 		if index > 1 << n_vars {
 			bail!(Error::ArgumentRangeError {
 				arg: "index".into(),
@@ -42,6 +45,10 @@ impl StepDown {
 		self.n_vars
 	}
 
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
 	pub fn multilinear_extension<P: PackedField>(&self) -> Result<MultilinearExtension<P>, Error> {
 		if self.n_vars < P::LOG_WIDTH {
 			bail!(Error::PackedFieldNotFilled {
@@ -102,17 +109,29 @@ impl<F: Field> MultivariatePoly<F> for StepDown {
 	fn binary_tower_level(&self) -> usize {
 		0
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use binius_field::{
-		BinaryField1b, PackedBinaryField128x1b, PackedBinaryField256x1b, PackedField,
+		BinaryField1b, Field, PackedBinaryField128x1b, PackedBinaryField256x1b, PackedField,
 	};
 	use binius_utils::felts;
 
 	use super::StepDown;
-	use crate::polynomial::test_utils::{hypercube_evals_from_oracle, packed_slice};
+	use crate::{
+		oracle::MAX_N_VARS,
+		polynomial::{
+			test_utils::{
+				decompose_index_to_hypercube_point, hypercube_evals_from_oracle, packed_slice,
+			},
+			MultivariatePoly,
+		},
+	};
 
 	#[test]
 	fn test_step_down_trace_without_packing_simple_cases() {
@@ -258,4 +277,24 @@ mod tests {
 			.evals()
 			.to_vec()
 	}
+
+	#[test]
+	fn test_step_down_large_n_vars() {
+		let n_vars = 40;
+		let index = (1 << 33) + 5;
+		let step_down = StepDown::new(n_vars, index).unwrap();
+		// The rows are indexed beyond 32 bits, so that index 5 or row 5 must not alias the index.
+		for (row, expected) in [
+			(5, BinaryField1b::ONE),
+			((1 << 32) + 5, BinaryField1b::ONE),
+			(index - 1, BinaryField1b::ONE),
+			(index, BinaryField1b::ZERO),
+			((1 << 40) - 1, BinaryField1b::ZERO),
+		] {
+			let query = decompose_index_to_hypercube_point::<BinaryField1b>(n_vars, row);
+			assert_eq!(step_down.evaluate(&query).unwrap(), expected);
+		}
+		assert!(StepDown::new(MAX_N_VARS, 1 << MAX_N_VARS).is_ok());
+		assert!(StepDown::new(MAX_N_VARS + 1, 0).is_err());
+	}
 }
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::any::Any;
+
 use binius_field::{Field, PackedField};
 use binius_math::MultilinearExtension;
 use binius_utils::bail;
@@ -28,6 +30,7 @@ pub struct StepUp {
 
 impl StepUp {
 	pub fn new(n_vars: usize, index: usize) -> Result<Self, Error> {
+		super::check_n_vars(n_vars)?;
 		if index > 1 << n_vars {
 			bail!(Error::ArgumentRangeError {
 				arg: "index".into(),
@@ -42,6 +45,10 @@ impl StepUp {
 		self.n_vars
 	}
 
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
 	pub fn multilinear_extension<P: PackedField>(&self) -> Result<MultilinearExtension<P>, Error> {
 		if self.n_vars < P::LOG_WIDTH {
 			bail!(Error::PackedFieldNotFilled {
@@ -103,6 +110,10 @@ impl<F: Field> MultivariatePoly<F> for StepUp {
 	fn binary_tower_level(&self) -> usize {
 		0
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::marker::PhantomData;
+use std::{any::Any, marker::PhantomData};
 
 use binius_field::{Field, PackedField, TowerField};
 use binius_math::MultilinearExtension;
@@ -42,6 +42,14 @@ impl<F: TowerField> TowerBasis<F> {
 		})
 	}
 
+	pub fn k(&self) -> usize {
+		self.k
+	}
+
+	pub fn iota(&self) -> usize {
+		self.iota
+	}
+
 	pub fn multilinear_extension<P: PackedField<Scalar = F>>(
 		&self,
 	) -> Result<MultilinearExtension<P>, Error> {
@@ -91,6 +99,10 @@ where
 	fn binary_tower_level(&self) -> usize {
 		self.iota + self.k
 	}
+
+	fn as_any(&self) -> Option<&dyn Any> {
+		Some(self)
+	}
 }
 
 #[cfg(test)]
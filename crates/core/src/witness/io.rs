@@ -0,0 +1,379 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Import and export of witness indices in Apache Arrow IPC and Parquet formats.
+//!
+//! This allows the trace to be generated in a separate process, or in another language, and
+//! handed to the prover as a file.
+//!
+//! Every oracle with witness data becomes one column of a single-row record batch. The column is
+//! named `oracle_{id}` and holds one binary value with the packed underlier data of the witness,
+//! exactly as it is laid out in memory, so 1-bit columns are stored as bits. Since columns of
+//! different heights can't otherwise share a record batch, the number of variables and the tower
+//! level of the column's field are stored in the field metadata.
+
+use std::{
+	collections::HashMap,
+	io::{Read, Write},
+	sync::Arc,
+};
+
+use arrow::{
+	array::{Array, ArrayRef, LargeBinaryArray},
+	datatypes::{DataType, Field as ArrowField, Schema},
+	error::ArrowError,
+	ipc::{reader::StreamReader, writer::StreamWriter},
+	record_batch::RecordBatch,
+};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::WithUnderlier,
+	ExtensionField, RepackedExtension, TowerField,
+};
+use binius_math::MultilinearExtension;
+use bytemuck::Pod;
+use parquet::{
+	arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter},
+	errors::ParquetError,
+	file::reader::ChunkReader,
+};
+
+use super::{MultilinearExtensionIndex, MultilinearWitness};
+use crate::{
+	oracle::OracleId,
+	tower::{TowerFamily, TowerUnderlier},
+};
+
+const N_VARS_KEY: &str = "binius.n_vars";
+const TOWER_LEVEL_KEY: &str = "binius.tower_level";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("Arrow error: {0}")]
+	Arrow(#[from] ArrowError),
+	#[error("Parquet error: {0}")]
+	Parquet(#[from] ParquetError),
+	#[error("witness error: {0}")]
+	Witness(#[from] super::Error),
+	#[error("math error: {0}")]
+	Math(#[from] binius_math::Error),
+	#[error("witness for oracle {id} is not backed by packed evaluations")]
+	NotExported { id: OracleId },
+	#[error("expected a single record batch with a single row")]
+	UnexpectedLayout,
+	#[error("invalid witness column {column}: {reason}")]
+	InvalidColumn { column: String, reason: String },
+}
+
+/// Converts a witness index to a single-row record batch with one column per oracle.
+pub fn to_record_batch<U, FW>(
+	witness: &MultilinearExtensionIndex<U, FW>,
+) -> Result<RecordBatch, Error>
+where
+	U: PackScalar<FW> + Pod,
+	FW: TowerField,
+{
+	let mut fields = Vec::new();
+	let mut columns = Vec::<ArrayRef>::new();
+	for id in 0..witness.entries.len() {
+		if !witness.has(id) {
+			continue;
+		}
+		// Lazy columns are materialized one at a time and freed once exported
+		let entry = witness.get_multilin_poly(id)?;
+		let evals = entry.packed_evals().ok_or(Error::NotExported { id })?;
+		let data =
+			bytemuck::must_cast_slice::<U, u8>(PackedType::<U, FW>::to_underliers_ref(evals));
+		let tower_level = FW::TOWER_LEVEL - entry.log_extension_degree();
+
+		let metadata = HashMap::from([
+			(N_VARS_KEY.to_string(), entry.n_vars().to_string()),
+			(TOWER_LEVEL_KEY.to_string(), tower_level.to_string()),
+		]);
+		fields.push(
+			ArrowField::new(format!("oracle_{id}"), DataType::LargeBinary, false)
+				.with_metadata(metadata),
+		);
+		columns.push(Arc::new(LargeBinaryArray::from_vec(vec![data])));
+	}
+
+	let schema = Arc::new(Schema::new(fields));
+	let batch = if columns.is_empty() {
+		RecordBatch::new_empty(schema)
+	} else {
+		RecordBatch::try_new(schema, columns)?
+	};
+	Ok(batch)
+}
+
+/// Converts a record batch created by [`to_record_batch`] back to a witness index.
+///
+/// The witness data is copied into owned buffers, so the index does not borrow from the batch.
+pub fn from_record_batch<Tower, U>(
+	batch: &RecordBatch,
+) -> Result<MultilinearExtensionIndex<'static, U, Tower::B128>, Error>
+where
+	Tower: TowerFamily,
+	U: TowerUnderlier<Tower> + Pod,
+	PackedType<U, Tower::B128>: RepackedExtension<PackedType<U, Tower::B1>>
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+{
+	if batch.num_columns() > 0 && batch.num_rows() != 1 {
+		return Err(Error::UnexpectedLayout);
+	}
+
+	let schema = batch.schema();
+	let mut witnesses = Vec::with_capacity(batch.num_columns());
+	for (field, column) in schema.fields().iter().zip(batch.columns()) {
+		let invalid = |reason: &str| Error::InvalidColumn {
+			column: field.name().clone(),
+			reason: reason.to_string(),
+		};
+
+		let id = field
+			.name()
+			.strip_prefix("oracle_")
+			.and_then(|id| id.parse::<OracleId>().ok())
+			.ok_or_else(|| invalid("name must be oracle_{id}"))?;
+		let metadata_value = |key: &str| {
+			field
+				.metadata()
+				.get(key)
+				.and_then(|value| value.parse::<usize>().ok())
+				.ok_or_else(|| invalid(&format!("missing or invalid {key} metadata")))
+		};
+		let n_vars = metadata_value(N_VARS_KEY)?;
+		let tower_level = metadata_value(TOWER_LEVEL_KEY)?;
+
+		let data = column
+			.as_any()
+			.downcast_ref::<LargeBinaryArray>()
+			.filter(|array| array.null_count() == 0)
+			.ok_or_else(|| invalid("expected a non-null large binary column"))?
+			.value(0);
+		if data.len() % size_of::<U>() != 0 {
+			return Err(invalid("data length is not a multiple of the underlier size"));
+		}
+		let underliers = bytemuck::pod_collect_to_vec::<u8, U>(data);
+
+		let witness = match tower_level {
+			0 => column_witness::<U, Tower::B1, Tower::B128>(n_vars, underliers)?,
+			3 => column_witness::<U, Tower::B8, Tower::B128>(n_vars, underliers)?,
+			4 => column_witness::<U, Tower::B16, Tower::B128>(n_vars, underliers)?,
+			5 => column_witness::<U, Tower::B32, Tower::B128>(n_vars, underliers)?,
+			6 => column_witness::<U, Tower::B64, Tower::B128>(n_vars, underliers)?,
+			7 => column_witness::<U, Tower::B128, Tower::B128>(n_vars, underliers)?,
+			_ => return Err(invalid("unsupported tower level")),
+		};
+		witnesses.push((id, witness));
+	}
+
+	let mut index = MultilinearExtensionIndex::new();
+	index.update_multilin_poly(witnesses)?;
+	Ok(index)
+}
+
+fn column_witness<U, FS, FW>(
+	n_vars: usize,
+	underliers: Vec<U>,
+) -> Result<MultilinearWitness<'static, PackedType<U, FW>>, Error>
+where
+	U: PackScalar<FS> + PackScalar<FW>,
+	FS: TowerField,
+	FW: TowerField + ExtensionField<FS>,
+	PackedType<U, FW>: RepackedExtension<PackedType<U, FS>>,
+{
+	let packed = underliers
+		.into_iter()
+		.map(PackedType::<U, FS>::from_underlier)
+		.collect::<Vec<_>>();
+	Ok(MultilinearExtension::new(n_vars, packed)?.specialize_arc_dyn())
+}
+
+/// Writes a witness index as an Arrow IPC stream.
+pub fn write_ipc<U, FW, W>(
+	witness: &MultilinearExtensionIndex<U, FW>,
+	writer: W,
+) -> Result<W, Error>
+where
+	U: PackScalar<FW> + Pod,
+	FW: TowerField,
+	W: Write,
+{
+	let batch = to_record_batch(witness)?;
+	let mut writer = StreamWriter::try_new(writer, &batch.schema())?;
+	writer.write(&batch)?;
+	writer.finish()?;
+	Ok(writer.into_inner()?)
+}
+
+/// Reads a witness index from an Arrow IPC stream written by [`write_ipc`].
+pub fn read_ipc<Tower, U, R>(
+	reader: R,
+) -> Result<MultilinearExtensionIndex<'static, U, Tower::B128>, Error>
+where
+	Tower: TowerFamily,
+	U: TowerUnderlier<Tower> + Pod,
+	PackedType<U, Tower::B128>: RepackedExtension<PackedType<U, Tower::B1>>
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	R: Read,
+{
+	let mut batches = StreamReader::try_new(reader, None)?;
+	let batch = batches.next().ok_or(Error::UnexpectedLayout)??;
+	if batches.next().is_some() {
+		return Err(Error::UnexpectedLayout);
+	}
+	from_record_batch::<Tower, U>(&batch)
+}
+
+/// Writes a witness index as a Parquet file.
+pub fn write_parquet<U, FW, W>(
+	witness: &MultilinearExtensionIndex<U, FW>,
+	writer: W,
+) -> Result<W, Error>
+where
+	U: PackScalar<FW> + Pod,
+	FW: TowerField,
+	W: Write + Send,
+{
+	let batch = to_record_batch(witness)?;
+	let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)?;
+	writer.write(&batch)?;
+	Ok(writer.into_inner()?)
+}
+
+/// Reads a witness index from a Parquet file written by [`write_parquet`].
+pub fn read_parquet<Tower, U, R>(
+	reader: R,
+) -> Result<MultilinearExtensionIndex<'static, U, Tower::B128>, Error>
+where
+	Tower: TowerFamily,
+	U: TowerUnderlier<Tower> + Pod,
+	PackedType<U, Tower::B128>: RepackedExtension<PackedType<U, Tower::B1>>
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>,
+	R: ChunkReader + 'static,
+{
+	let mut batches = ParquetRecordBatchReaderBuilder::try_new(reader)?.build()?;
+	let batch = batches.next().ok_or(Error::UnexpectedLayout)??;
+	if batches.next().is_some() {
+		return Err(Error::UnexpectedLayout);
+	}
+	from_record_batch::<Tower, U>(&batch)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		arch::OptimalUnderlier, BinaryField128b, BinaryField1b, BinaryField32b, PackedField,
+	};
+	use binius_math::MultilinearPoly;
+	use bytes::Bytes;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::tower::CanonicalTowerFamily;
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+
+	fn random_column<FS>(
+		n_vars: usize,
+		rng: &mut StdRng,
+	) -> MultilinearWitness<'static, PackedType<U, F>>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+		PackedType<U, F>: RepackedExtension<PackedType<U, FS>>,
+	{
+		let len = 1 << n_vars.saturating_sub(PackedType::<U, FS>::LOG_WIDTH);
+		let values = (0..len)
+			.map(|_| PackedType::<U, FS>::random(&mut *rng))
+			.collect::<Vec<_>>();
+		MultilinearExtension::new(n_vars, values)
+			.unwrap()
+			.specialize_arc_dyn()
+	}
+
+	fn test_witness() -> MultilinearExtensionIndex<'static, U, F> {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut witness = MultilinearExtensionIndex::new();
+		witness
+			.update_multilin_poly([
+				(0, random_column::<BinaryField1b>(10, &mut rng)),
+				(2, random_column::<BinaryField32b>(5, &mut rng)),
+				(3, random_column::<BinaryField128b>(3, &mut rng)),
+			])
+			.unwrap();
+		witness
+	}
+
+	fn assert_witness_eq(
+		lhs: &MultilinearExtensionIndex<U, F>,
+		rhs: &MultilinearExtensionIndex<U, F>,
+	) {
+		for id in 0..4 {
+			assert_eq!(lhs.has(id), rhs.has(id));
+			if !lhs.has(id) {
+				continue;
+			}
+			let lhs = lhs.get_multilin_poly(id).unwrap();
+			let rhs = rhs.get_multilin_poly(id).unwrap();
+			assert_eq!(lhs.n_vars(), rhs.n_vars());
+			assert_eq!(lhs.log_extension_degree(), rhs.log_extension_degree());
+			for i in 0..1 << lhs.n_vars() {
+				let value: F = lhs.evaluate_on_hypercube(i).unwrap();
+				assert_eq!(value, rhs.evaluate_on_hypercube(i).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn test_ipc_roundtrip() {
+		let witness = test_witness();
+		let bytes = write_ipc(&witness, Vec::new()).unwrap();
+		let restored = read_ipc::<CanonicalTowerFamily, U, _>(bytes.as_slice()).unwrap();
+		assert_witness_eq(&witness, &restored);
+	}
+
+	#[test]
+	fn test_parquet_roundtrip() {
+		let witness = test_witness();
+		let bytes = write_parquet(&witness, Vec::new()).unwrap();
+		let restored = read_parquet::<CanonicalTowerFamily, U, _>(Bytes::from(bytes)).unwrap();
+		assert_witness_eq(&witness, &restored);
+	}
+
+	#[test]
+	fn test_rejects_unknown_tower_level() {
+		let witness = test_witness();
+		let batch = to_record_batch(&witness).unwrap();
+		let schema = batch.schema();
+		let mut fields = schema
+			.fields()
+			.iter()
+			.map(|f| f.as_ref().clone())
+			.collect::<Vec<_>>();
+		fields[0] = fields[0].clone().with_metadata(HashMap::from([
+			(N_VARS_KEY.to_string(), "10".to_string()),
+			(TOWER_LEVEL_KEY.to_string(), "2".to_string()),
+		]));
+		let batch =
+			RecordBatch::try_new(Arc::new(Schema::new(fields)), batch.columns().to_vec()).unwrap();
+		assert!(matches!(
+			from_record_batch::<CanonicalTowerFamily, U>(&batch),
+			Err(Error::InvalidColumn { .. })
+		));
+	}
+}
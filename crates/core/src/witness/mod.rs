@@ -0,0 +1,322 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+#[cfg(feature = "arrow")]
+pub mod io;
+mod row_major;
+
+use std::{
+	fmt::{self, Debug},
+	sync::{Arc, Mutex, Weak},
+};
+
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::{UnderlierType, WithUnderlier},
+	ExtensionField, Field, PackedExtension, PackedField, TowerField,
+};
+use binius_math::{MultilinearExtension, MultilinearExtensionBorrowed, MultilinearPoly};
+use binius_utils::bail;
+use digest::{Digest, Output};
+
+use crate::{oracle::OracleId, polynomial::Error as PolynomialError, tower::TowerFamily};
+
+pub type MultilinearWitness<'a, P> = Arc<dyn MultilinearPoly<P> + Send + Sync + 'a>;
+
+/// Computes the witness of a lazy column, see [`MultilinearExtensionIndex::update_lazy_multilin_poly`].
+///
+/// The computation may borrow for `'a`, but the witness it returns owns its evaluations, which
+/// keeps the index covariant in `'a`.
+pub type LazyMultilinearWitness<'a, P> =
+	Arc<dyn Fn() -> Result<MultilinearWitness<'static, P>, Error> + Send + Sync + 'a>;
+
+enum IndexEntry<'a, P: PackedField> {
+	Materialized(MultilinearWitness<'a, P>),
+	Lazy {
+		compute: LazyMultilinearWitness<'a, P>,
+		/// The last materialization, shared by all callers while any of them holds on to it.
+		live: Mutex<Option<Weak<dyn MultilinearPoly<P> + Send + Sync>>>,
+	},
+}
+
+impl<P: PackedField> Debug for IndexEntry<'_, P> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Materialized(witness) => f.debug_tuple("Materialized").field(witness).finish(),
+			Self::Lazy { .. } => f.write_str("Lazy"),
+		}
+	}
+}
+
+impl<'a, P: PackedField> IndexEntry<'a, P> {
+	fn get(&self) -> Result<MultilinearWitness<'a, P>, Error> {
+		match self {
+			Self::Materialized(witness) => Ok(witness.clone()),
+			Self::Lazy { compute, live } => {
+				let mut live = live.lock().expect("lazy witness computation panicked");
+				if let Some(witness) = live.as_ref().and_then(Weak::upgrade) {
+					return Ok(witness);
+				}
+				let witness = compute()?;
+				*live = Some(Arc::downgrade(&witness));
+				Ok(witness)
+			}
+		}
+	}
+}
+
+/// The memory layout of the tables of a witness, as read by the provers of table constraints.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WitnessLayout {
+	/// Every column is stored contiguously.
+	#[default]
+	ColumnMajor,
+	/// The evaluations of the columns of a table at one row are stored contiguously.
+	///
+	/// Zerocheck reads the same rows of all columns of a table in every step, which for very wide
+	/// tables in column-major layout touches as many distant cache lines as there are columns.
+	/// With this layout, the provers copy the columns of each table into row-major tables, one for
+	/// every field of the tower, and read hypercube evaluations from them, including the subfield
+	/// embeddings read by the univariate round. Every value is stored in the field of its column.
+	/// The columns are kept for all other accesses, so the table occupies memory a second time
+	/// while it is proven.
+	RowMajor,
+}
+
+impl WitnessLayout {
+	/// Arranges the witnesses of the columns of a table on `n_vars` variables in this layout.
+	pub fn arrange_table<Tower, P>(
+		self,
+		n_vars: usize,
+		columns: Vec<MultilinearWitness<'_, P>>,
+	) -> Result<Vec<MultilinearWitness<'_, P>>, Error>
+	where
+		Tower: TowerFamily,
+		P: PackedField<Scalar = Tower::B128>,
+	{
+		match self {
+			Self::ColumnMajor => Ok(columns),
+			Self::RowMajor => Ok(row_major::row_major_columns::<Tower, _>(n_vars, columns)?),
+		}
+	}
+}
+
+/// Data structure that indexes multilinear extensions by oracle ID.
+///
+/// A [`crate::oracle::MultilinearOracleSet`] indexes multilinear polynomial oracles by assigning
+/// unique, sequential oracle IDs. The caller can get the [`MultilinearExtension`] defined natively
+/// over a subfield. This is possible because the [`MultilinearExtensionIndex::get`] method is
+/// generic over the subfield type and the struct itself only stores the underlying data.
+#[derive(Default, Debug)]
+pub struct MultilinearExtensionIndex<'a, U: UnderlierType, FW>
+where
+	U: UnderlierType + PackScalar<FW>,
+	FW: Field,
+{
+	entries: Vec<Option<IndexEntry<'a, PackedType<U, FW>>>>,
+	/// Oracle IDs whose witnesses are removed once the prover has committed the witness.
+	drop_after_commit: Vec<OracleId>,
+	layout: WitnessLayout,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("witness not found for oracle {id}")]
+	MissingWitness { id: OracleId },
+	#[error("witness for oracle id {id} does not have an explicit backing multilinear")]
+	NoExplicitBackingMultilinearExtension { id: OracleId },
+	#[error("log degree mismatch for oracle id {oracle_id}. field_log_extension_degree = {field_log_extension_degree} entry_log_extension_degree = {entry_log_extension_degree}")]
+	OracleExtensionDegreeMismatch {
+		oracle_id: OracleId,
+		field_log_extension_degree: usize,
+		entry_log_extension_degree: usize,
+	},
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("HAL error: {0}")]
+	HalError(#[from] binius_hal::Error),
+	#[error("Math error: {0}")]
+	MathError(#[from] binius_math::Error),
+}
+
+impl<'a, U, FW> MultilinearExtensionIndex<'a, U, FW>
+where
+	U: UnderlierType + PackScalar<FW>,
+	FW: Field,
+{
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The layout in which the provers of table constraints read the columns of a table.
+	pub fn layout(&self) -> WitnessLayout {
+		self.layout
+	}
+
+	/// Sets the layout in which the provers of table constraints read the columns of a table,
+	/// [`WitnessLayout::ColumnMajor`] by default.
+	pub fn set_layout(&mut self, layout: WitnessLayout) {
+		self.layout = layout;
+	}
+
+	/// Returns the witness for the given oracle.
+	///
+	/// Lazy entries are materialized by the call, unless a previous materialization is still
+	/// referenced, and freed once the caller and all other holders drop the returned witness.
+	pub fn get_multilin_poly(
+		&self,
+		id: OracleId,
+	) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, Error> {
+		self.entries
+			.get(id)
+			.ok_or(Error::MissingWitness { id })?
+			.as_ref()
+			.ok_or(Error::MissingWitness { id })?
+			.get()
+	}
+
+	/// Whether has data for the given oracle id.
+	pub fn has(&self, id: OracleId) -> bool {
+		self.entries.get(id).is_some_and(Option::is_some)
+	}
+
+	/// Whether the witness for the given oracle is computed on demand.
+	pub fn is_lazy(&self, id: OracleId) -> bool {
+		matches!(self.entries.get(id), Some(Some(IndexEntry::Lazy { .. })))
+	}
+
+	pub fn update_multilin_poly(
+		&mut self,
+		witnesses: impl IntoIterator<Item = (OracleId, MultilinearWitness<'a, PackedType<U, FW>>)>,
+	) -> Result<(), Error> {
+		for (id, witness) in witnesses {
+			self.set_entry(id, IndexEntry::Materialized(witness));
+		}
+		Ok(())
+	}
+
+	/// Registers a witness that is computed on demand instead of being stored in the index.
+	///
+	/// `compute` is called whenever [`Self::get_multilin_poly`] needs the witness and no earlier
+	/// result of it is alive. The prover materializes lazy committed columns for the commitment,
+	/// releases them, and materializes them again for the opening proof, so the column only
+	/// occupies memory while it is in use. This trades recomputation for peak memory and suits
+	/// helper columns that are cheap to compute.
+	pub fn update_lazy_multilin_poly(
+		&mut self,
+		id: OracleId,
+		compute: LazyMultilinearWitness<'a, PackedType<U, FW>>,
+	) {
+		self.set_entry(
+			id,
+			IndexEntry::Lazy {
+				compute,
+				live: Mutex::new(None),
+			},
+		);
+	}
+
+	fn set_entry(&mut self, id: OracleId, entry: IndexEntry<'a, PackedType<U, FW>>) {
+		if id >= self.entries.len() {
+			self.entries.resize_with(id + 1, || None);
+		}
+		self.entries[id] = Some(entry);
+	}
+
+	/// Marks the witness for the given oracle as not needed after the commit phase.
+	///
+	/// The prover drops every column after its last use: committed columns after the opening
+	/// proof, and the other columns after the evaluation claims are reduced to the committed ones,
+	/// which is the last phase that reads them. Marked columns that are not committed are removed
+	/// from the index right after the commitment instead, so that their memory is freed once no
+	/// other references to them remain. This is meant for helper columns that are only needed to
+	/// fill in other columns. Marked columns must not be read by the later phases of the proof, i.e.
+	/// appear in table constraints, flushes or non-zero checks, or have evaluation claims that
+	/// reduce to their witness, otherwise the prover fails with a missing witness error. Marks on
+	/// committed columns have no effect, as the opening proof reads them.
+	pub fn drop_after_commit(&mut self, id: OracleId) {
+		if !self.drop_after_commit.contains(&id) {
+			self.drop_after_commit.push(id);
+		}
+	}
+
+	/// Removes the entries marked with [`Self::drop_after_commit`] from the index, except for the
+	/// ones for which `keep` returns true.
+	pub fn drop_marked(&mut self, keep: impl Fn(OracleId) -> bool) {
+		for id in self.drop_after_commit.drain(..) {
+			if keep(id) {
+				continue;
+			}
+			if let Some(entry) = self.entries.get_mut(id) {
+				*entry = None;
+			}
+		}
+	}
+
+	/// The materialized witnesses of the index, without the lazy ones that are computed on demand.
+	pub fn materialized_multilins(
+		&self,
+	) -> impl Iterator<Item = &MultilinearWitness<'a, PackedType<U, FW>>> {
+		self.entries.iter().filter_map(|entry| match entry {
+			Some(IndexEntry::Materialized(witness)) => Some(witness),
+			_ => None,
+		})
+	}
+
+	/// Hashes the contents of the index, to check that the trace generation reproduces a
+	/// witness.
+	///
+	/// The hash covers the ID, number of variables, extension degree and packed evaluations of
+	/// every entry, in ID order. Lazy entries are materialized one at a time. The layout and the
+	/// entries marked with [`Self::drop_after_commit`] don't affect the proof, so they are not
+	/// hashed.
+	pub fn content_hash<H: Digest>(&self) -> Result<Output<H>, Error> {
+		let mut hasher = H::new();
+		for id in (0..self.entries.len()).filter(|&id| self.has(id)) {
+			let entry = self.get_multilin_poly(id)?;
+			let evals = entry
+				.packed_evals()
+				.ok_or(Error::NoExplicitBackingMultilinearExtension { id })?;
+			for value in [id, entry.n_vars(), entry.log_extension_degree()] {
+				hasher.update((value as u64).to_le_bytes());
+			}
+			hasher.update(bytemuck::must_cast_slice::<U, u8>(
+				PackedType::<U, FW>::to_underliers_ref(evals),
+			));
+		}
+		Ok(hasher.finalize())
+	}
+
+	/// TODO: Remove once PCS no longer needs this
+	pub fn get<FS>(
+		&self,
+		id: OracleId,
+	) -> Result<MultilinearExtensionBorrowed<PackedType<U, FS>>, Error>
+	where
+		FS: TowerField,
+		FW: ExtensionField<FS>,
+		U: PackScalar<FS>,
+	{
+		let entry = match self.entries.get(id) {
+			Some(Some(IndexEntry::Materialized(entry))) => entry,
+			Some(Some(IndexEntry::Lazy { .. })) => {
+				bail!(Error::NoExplicitBackingMultilinearExtension { id })
+			}
+			_ => bail!(Error::MissingWitness { id }),
+		};
+
+		if entry.log_extension_degree() != FW::LOG_DEGREE {
+			bail!(Error::OracleExtensionDegreeMismatch {
+				oracle_id: id,
+				field_log_extension_degree: FW::LOG_DEGREE,
+				entry_log_extension_degree: entry.log_extension_degree()
+			})
+		}
+
+		let evals = entry
+			.packed_evals()
+			.map(<PackedType<U, FW>>::cast_bases)
+			.ok_or(Error::NoExplicitBackingMultilinearExtension { id })?;
+
+		Ok(MultilinearExtension::from_values_slice(evals)?)
+	}
+}
@@ -0,0 +1,408 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::sync::Arc;
+
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	ExtensionField, Field, PackedField,
+};
+use binius_math::{Error, MultilinearExtension, MultilinearPoly, MultilinearQueryRef};
+use binius_utils::{bail, rayon::prelude::*};
+
+use super::MultilinearWitness;
+use crate::tower::TowerFamily;
+
+/// The largest binary logarithm of an embedding degree, which bounds the subfield coordinates of
+/// an embedded evaluation.
+const MAX_TOWER_HEIGHT: usize = 7;
+
+/// The hypercube evaluations of the columns of a table with values in `F`, stored row by row.
+#[derive(Debug)]
+struct RowMajorTable<F> {
+	n_columns: usize,
+	evals: Vec<F>,
+}
+
+/// Copies the columns of a table, which all have `n_vars` variables, into row-major tables and
+/// returns witnesses reading from them.
+///
+/// Columns are grouped by the field of their values, and every group is stored in a table of
+/// that field, so each value takes the width of its own field. Columns over a field that is not
+/// in `Tower` are returned unchanged.
+pub(super) fn row_major_columns<Tower, P>(
+	n_vars: usize,
+	columns: Vec<MultilinearWitness<'_, P>>,
+) -> Result<Vec<MultilinearWitness<'_, P>>, Error>
+where
+	Tower: TowerFamily,
+	P: PackedField<Scalar = Tower::B128>,
+{
+	if let Some(column) = columns.iter().find(|column| column.n_vars() != n_vars) {
+		bail!(Error::IncorrectNumberOfVariables {
+			expected: n_vars,
+			actual: column.n_vars(),
+		});
+	}
+
+	let mut arranged = columns;
+	arrange_columns::<Tower::B1, P>(n_vars, &mut arranged)?;
+	arrange_columns::<Tower::B8, P>(n_vars, &mut arranged)?;
+	arrange_columns::<Tower::B16, P>(n_vars, &mut arranged)?;
+	arrange_columns::<Tower::B32, P>(n_vars, &mut arranged)?;
+	arrange_columns::<Tower::B64, P>(n_vars, &mut arranged)?;
+	arrange_columns::<Tower::B128, P>(n_vars, &mut arranged)?;
+	Ok(arranged)
+}
+
+/// Replaces the columns with values in `FSub` by witnesses reading from one row-major table.
+fn arrange_columns<FSub, P>(
+	n_vars: usize,
+	columns: &mut [MultilinearWitness<'_, P>],
+) -> Result<(), Error>
+where
+	FSub: Field + TryFrom<P::Scalar>,
+	P: PackedField<Scalar: ExtensionField<FSub>>,
+{
+	let log_extension_degree = <P::Scalar as ExtensionField<FSub>>::LOG_DEGREE;
+	let indices = (0..columns.len())
+		.filter(|&i| columns[i].log_extension_degree() == log_extension_degree)
+		.collect::<Vec<_>>();
+	let n_columns = indices.len();
+	if n_columns == 0 {
+		return Ok(());
+	}
+
+	let mut evals = vec![FSub::ZERO; n_columns << n_vars];
+	evals
+		.par_chunks_exact_mut(n_columns)
+		.enumerate()
+		.try_for_each(|(row, row_evals)| {
+			for (eval, &i) in row_evals.iter_mut().zip(&indices) {
+				let Ok(value) = FSub::try_from(columns[i].evaluate_on_hypercube(row)?) else {
+					bail!(binius_field::Error::NotInField);
+				};
+				*eval = value;
+			}
+			Ok::<_, Error>(())
+		})?;
+
+	let table = Arc::new(RowMajorTable { n_columns, evals });
+	for (index, &i) in indices.iter().enumerate() {
+		let column = RowMajorColumn {
+			column: columns[i].clone(),
+			table: table.clone(),
+			index,
+		};
+		columns[i] = Arc::new(column);
+	}
+	Ok(())
+}
+
+/// A column of a [`RowMajorTable`].
+///
+/// Hypercube evaluations, including subfield embeddings of them, and the inner products over them
+/// are read from the table. All other accesses, including [`MultilinearPoly::packed_evals`], go
+/// to the original column witness.
+#[derive(Debug)]
+struct RowMajorColumn<'a, P: PackedField, FSub> {
+	column: MultilinearWitness<'a, P>,
+	table: Arc<RowMajorTable<FSub>>,
+	index: usize,
+}
+
+impl<P: PackedField, FSub: Field> RowMajorColumn<'_, P, FSub> {
+	fn eval(&self, row: usize) -> FSub {
+		self.table.evals[row * self.table.n_columns + self.index]
+	}
+}
+
+impl<P, FSub> MultilinearPoly<P> for RowMajorColumn<'_, P, FSub>
+where
+	P: PackedField<Scalar: ExtensionField<FSub>>,
+	FSub: Field,
+{
+	fn n_vars(&self) -> usize {
+		self.column.n_vars()
+	}
+
+	fn log_extension_degree(&self) -> usize {
+		<P::Scalar as ExtensionField<FSub>>::LOG_DEGREE
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<P::Scalar, Error> {
+		if index >= 1 << self.n_vars() {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		Ok(self.eval(index).into())
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: P::Scalar,
+	) -> Result<P::Scalar, Error> {
+		if index >= 1 << self.n_vars() {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		Ok(scalar * self.eval(index))
+	}
+
+	fn evaluate(&self, query: MultilinearQueryRef<P>) -> Result<P::Scalar, Error> {
+		self.column.evaluate(query)
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.column.evaluate_partial_low(query)
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.column.evaluate_partial_high(query)
+	}
+
+	fn subcube_inner_products(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		inner_products: &mut [P],
+	) -> Result<(), Error> {
+		let query_n_vars = query.n_vars();
+		if query_n_vars + subcube_vars > self.n_vars() {
+			bail!(Error::ArgumentRangeError {
+				arg: "query.n_vars() + subcube_vars".into(),
+				range: 0..self.n_vars(),
+			});
+		}
+
+		let max_index = 1 << (self.n_vars() - query_n_vars - subcube_vars);
+		if subcube_index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_index".into(),
+				range: 0..max_index,
+			});
+		}
+
+		let correct_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+		if inner_products.len() != correct_len {
+			bail!(Error::ArgumentRangeError {
+				arg: "evals.len()".to_string(),
+				range: correct_len..correct_len + 1,
+			});
+		}
+
+		let subcube_start = subcube_index << (query_n_vars + subcube_vars);
+		for scalar_index in 0..1 << subcube_vars {
+			let rows_start = subcube_start + (scalar_index << query_n_vars);
+			let inner_product = (0..1 << query_n_vars)
+				.map(|i| get_packed_slice(query.expansion(), i) * self.eval(rows_start + i))
+				.sum();
+			set_packed_slice(inner_products, scalar_index, inner_product);
+		}
+
+		Ok(())
+	}
+
+	fn subcube_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		log_embedding_degree: usize,
+		evals: &mut [P],
+	) -> Result<(), Error> {
+		let n_vars = self.n_vars();
+		if subcube_vars > n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_vars".to_string(),
+				range: 0..n_vars + 1,
+			});
+		}
+
+		let log_extension_degree = self.log_extension_degree();
+		if log_embedding_degree > log_extension_degree.min(MAX_TOWER_HEIGHT) {
+			bail!(Error::LogEmbeddingDegreeTooLarge {
+				log_embedding_degree
+			});
+		}
+
+		let correct_len = 1 << subcube_vars.saturating_sub(log_embedding_degree + P::LOG_WIDTH);
+		if evals.len() != correct_len {
+			bail!(Error::ArgumentRangeError {
+				arg: "evals.len()".to_string(),
+				range: correct_len..correct_len + 1,
+			});
+		}
+
+		let max_index = 1 << (n_vars - subcube_vars);
+		if subcube_index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_index".to_string(),
+				range: 0..max_index,
+			});
+		}
+
+		let subcube_start = subcube_index << subcube_vars;
+		if log_embedding_degree == 0 {
+			for i in 0..1 << subcube_vars {
+				set_packed_slice(evals, i, self.eval(subcube_start + i).into());
+			}
+			return Ok(());
+		}
+
+		// Consecutive rows are embedded into one scalar as its coordinates over the subfield, the
+		// same way as MLEEmbeddingAdapter embeds them.
+		let mut bases = [FSub::ZERO; 1 << MAX_TOWER_HEIGHT];
+		let bases = &mut bases[..1 << log_embedding_degree];
+		let bases_count = 1 << log_embedding_degree.min(subcube_vars);
+		for i in 0..1 << subcube_vars.saturating_sub(log_embedding_degree) {
+			let rows_start = subcube_start + (i << log_embedding_degree);
+			for (j, base) in bases[..bases_count].iter_mut().enumerate() {
+				*base = self.eval(rows_start + j);
+			}
+			let eval =
+				P::Scalar::from_bases_sparse(bases, log_extension_degree - log_embedding_degree)?;
+			set_packed_slice(evals, i, eval);
+		}
+
+		Ok(())
+	}
+
+	fn packed_evals(&self) -> Option<&[P]> {
+		self.column.packed_evals()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		arch::OptimalUnderlier,
+		as_packed_field::{PackScalar, PackedType},
+		BinaryField128b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+		RepackedExtension, TowerField,
+	};
+	use binius_hal::{make_portable_backend, ComputationBackendExt};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{tower::CanonicalTowerFamily, witness::WitnessLayout};
+
+	type U = OptimalUnderlier;
+	type P = PackedType<U, BinaryField128b>;
+
+	fn random_column<FS>(n_vars: usize, rng: &mut StdRng) -> MultilinearWitness<'static, P>
+	where
+		FS: TowerField,
+		BinaryField128b: ExtensionField<FS>,
+		U: PackScalar<FS>,
+		P: RepackedExtension<PackedType<U, FS>>,
+	{
+		let values: Vec<_> = (0..1 << n_vars.saturating_sub(PackedType::<U, FS>::LOG_WIDTH))
+			.map(|_| PackedType::<U, FS>::random(&mut *rng))
+			.collect();
+		MultilinearExtension::new(n_vars, values)
+			.unwrap()
+			.specialize_arc_dyn()
+	}
+
+	#[test]
+	fn test_row_major_columns_match_columns() {
+		let n_vars = 8;
+		let mut rng = StdRng::seed_from_u64(0);
+		let columns = vec![
+			random_column::<BinaryField1b>(n_vars, &mut rng),
+			random_column::<BinaryField32b>(n_vars, &mut rng),
+			random_column::<BinaryField8b>(n_vars, &mut rng),
+			random_column::<BinaryField128b>(n_vars, &mut rng),
+			random_column::<BinaryField1b>(n_vars, &mut rng),
+		];
+		let row_major = WitnessLayout::RowMajor
+			.arrange_table::<CanonicalTowerFamily, _>(n_vars, columns.clone())
+			.unwrap();
+
+		let backend = make_portable_backend();
+		let query = backend
+			.multilinear_query::<P>(&[BinaryField128b::new(3), BinaryField128b::new(5)])
+			.unwrap();
+		for (column, row_major) in columns.iter().zip(&row_major) {
+			assert_eq!(row_major.log_extension_degree(), column.log_extension_degree());
+			assert_eq!(row_major.packed_evals(), column.packed_evals());
+			for index in [0, 1, 77, (1 << n_vars) - 1] {
+				let scalar = BinaryField128b::new(11);
+				assert_eq!(
+					row_major
+						.evaluate_on_hypercube_and_scale(index, scalar)
+						.unwrap(),
+					column
+						.evaluate_on_hypercube_and_scale(index, scalar)
+						.unwrap()
+				);
+			}
+
+			for subcube_vars in 0..=4usize {
+				let len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+				for subcube_index in 0..1 << (n_vars - subcube_vars - 2) {
+					let mut expected = vec![P::zero(); len];
+					let mut evals = vec![P::zero(); len];
+					column
+						.subcube_inner_products(
+							query.to_ref(),
+							subcube_vars,
+							subcube_index,
+							&mut expected,
+						)
+						.unwrap();
+					row_major
+						.subcube_inner_products(
+							query.to_ref(),
+							subcube_vars,
+							subcube_index,
+							&mut evals,
+						)
+						.unwrap();
+					assert_eq!(evals, expected);
+				}
+			}
+
+			// The univariate round of zerocheck reads columns embedded into its base field.
+			for log_embedding_degree in 0..=column.log_extension_degree() {
+				for subcube_vars in [0, 3, 6, n_vars] {
+					let len = 1 << subcube_vars.saturating_sub(log_embedding_degree + P::LOG_WIDTH);
+					for subcube_index in 0..1 << (n_vars - subcube_vars) {
+						let mut expected = vec![P::zero(); len];
+						let mut evals = vec![P::zero(); len];
+						column
+							.subcube_evals(
+								subcube_vars,
+								subcube_index,
+								log_embedding_degree,
+								&mut expected,
+							)
+							.unwrap();
+						row_major
+							.subcube_evals(
+								subcube_vars,
+								subcube_index,
+								log_embedding_degree,
+								&mut evals,
+							)
+							.unwrap();
+						assert_eq!(evals, expected);
+					}
+				}
+			}
+		}
+
+		let short_column = random_column::<BinaryField32b>(n_vars - 1, &mut rng);
+		assert!(WitnessLayout::RowMajor
+			.arrange_table::<CanonicalTowerFamily, _>(
+				n_vars,
+				vec![columns[0].clone(), short_column]
+			)
+			.is_err());
+	}
+}
@@ -9,7 +9,7 @@ use binius_field::{
 		packed_aes_32::*, packed_aes_512::*, packed_aes_64::*, packed_aes_8::*,
 		packed_polyval_128::*, packed_polyval_256::*, packed_polyval_512::*,
 		HybridRecursiveStrategy, PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
-		PairwiseTableStrategy, SimdStrategy,
+		PairwiseTableStrategy, ScalarArrayStrategy, SimdStrategy,
 	},
 	arithmetic_traits::TaggedInvertOrZero,
 	PackedField,
@@ -33,6 +33,10 @@ fn invert_pairwise_table<T: TaggedInvertOrZero<PairwiseTableStrategy>>(val: T) -
 	val.invert_or_zero()
 }
 
+fn invert_scalar_array<T: TaggedInvertOrZero<ScalarArrayStrategy>>(val: T) -> T {
+	val.invert_or_zero()
+}
+
 fn invert_packed<T: TaggedInvertOrZero<PackedStrategy>>(val: T) -> T {
 	val.invert_or_zero()
 }
@@ -53,6 +57,7 @@ benchmark_packed_operation!(
 		(pairwise, TaggedInvertOrZero::<PairwiseStrategy>, invert_pairwise),
 		(pairwise_recursive, TaggedInvertOrZero::<PairwiseRecursiveStrategy>, invert_pairwise_recursive),
 		(pairwise_table, TaggedInvertOrZero::<PairwiseTableStrategy>, invert_pairwise_table),
+		(scalar_array, TaggedInvertOrZero::<ScalarArrayStrategy>, invert_scalar_array),
 		(hybrid_recursive, TaggedInvertOrZero::<HybridRecursiveStrategy>, invert_hybrid_recursive),
 		(packed, TaggedInvertOrZero::<PackedStrategy>, invert_packed),
 		(simd, TaggedInvertOrZero::<SimdStrategy>, invert_simd),
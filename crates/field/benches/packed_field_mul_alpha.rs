@@ -9,7 +9,7 @@ use binius_field::{
 		packed_aes_32::*, packed_aes_512::*, packed_aes_64::*, packed_aes_8::*,
 		packed_polyval_128::*, packed_polyval_256::*, packed_polyval_512::*,
 		HybridRecursiveStrategy, PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
-		PairwiseTableStrategy, SimdStrategy,
+		PairwiseTableStrategy, ScalarArrayStrategy, SimdStrategy,
 	},
 	arithmetic_traits::{MulAlpha, TaggedMulAlpha},
 };
@@ -32,6 +32,10 @@ fn mul_alpha_pairwise_table<T: TaggedMulAlpha<PairwiseTableStrategy>>(val: T) ->
 	val.mul_alpha()
 }
 
+fn mul_alpha_scalar_array<T: TaggedMulAlpha<ScalarArrayStrategy>>(val: T) -> T {
+	val.mul_alpha()
+}
+
 fn mul_alpha_packed<T: TaggedMulAlpha<PackedStrategy>>(val: T) -> T {
 	val.mul_alpha()
 }
@@ -52,6 +56,7 @@ benchmark_packed_operation!(
 		(pairwise, TaggedMulAlpha::<PairwiseStrategy>, mul_alpha_pairwise),
 		(pairwise_recursive, TaggedMulAlpha::<PairwiseRecursiveStrategy>, mul_alpha_pairwise_recursive),
 		(pairwise_table, TaggedMulAlpha::<PairwiseTableStrategy>, mul_alpha_pairwise_table),
+		(scalar_array, TaggedMulAlpha::<ScalarArrayStrategy>, mul_alpha_scalar_array),
 		(hybrid_recursive, TaggedMulAlpha::<HybridRecursiveStrategy>, mul_alpha_hybrid_recursive),
 		(packed, TaggedMulAlpha::<PackedStrategy>, mul_alpha_packed),
 		(simd, TaggedMulAlpha::<SimdStrategy>, mul_alpha_simd),
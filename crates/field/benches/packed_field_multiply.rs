@@ -11,7 +11,7 @@ use binius_field::{
 		packed_aes_32::*, packed_aes_512::*, packed_aes_64::*, packed_aes_8::*,
 		packed_polyval_128::*, packed_polyval_256::*, packed_polyval_512::*,
 		HybridRecursiveStrategy, PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
-		PairwiseTableStrategy, SimdStrategy,
+		PairwiseTableStrategy, PolyvalIsomorphicStrategy, ScalarArrayStrategy, SimdStrategy,
 	},
 	arithmetic_traits::TaggedMul,
 };
@@ -35,10 +35,18 @@ fn mul_pairwise_table<T: TaggedMul<PairwiseTableStrategy>>(lhs: T, rhs: T) -> T
 	TaggedMul::<PairwiseTableStrategy>::mul(lhs, rhs)
 }
 
+fn mul_scalar_array<T: TaggedMul<ScalarArrayStrategy>>(lhs: T, rhs: T) -> T {
+	TaggedMul::<ScalarArrayStrategy>::mul(lhs, rhs)
+}
+
 fn mul_pairwise_recursive<T: TaggedMul<PairwiseRecursiveStrategy>>(lhs: T, rhs: T) -> T {
 	TaggedMul::<PairwiseRecursiveStrategy>::mul(lhs, rhs)
 }
 
+fn mul_polyval_isomorphic<T: TaggedMul<PolyvalIsomorphicStrategy>>(lhs: T, rhs: T) -> T {
+	TaggedMul::<PolyvalIsomorphicStrategy>::mul(lhs, rhs)
+}
+
 fn mul_packed<T: TaggedMul<PackedStrategy>>(lhs: T, rhs: T) -> T {
 	TaggedMul::<PackedStrategy>::mul(lhs, rhs)
 }
@@ -58,7 +66,9 @@ benchmark_packed_operation!(
 		(main, SelfMul, mul_main),
 		(pairwise, TaggedMul::<PairwiseStrategy>, mul_pairwise),
 		(pairwise_recursive, TaggedMul::<PairwiseRecursiveStrategy>, mul_pairwise_recursive),
+		(polyval_isomorphic, TaggedMul::<PolyvalIsomorphicStrategy>, mul_polyval_isomorphic),
 		(pairwise_table, TaggedMul::<PairwiseTableStrategy>, mul_pairwise_table),
+		(scalar_array, TaggedMul::<ScalarArrayStrategy>, mul_scalar_array),
 		(hybrid_recursive, TaggedMul::<HybridRecursiveStrategy>, mul_hybrid_recursive),
 		(packed, TaggedMul::<PackedStrategy>, mul_packed),
 		(simd, TaggedMul::<SimdStrategy>, mul_simd),
@@ -9,7 +9,7 @@ use binius_field::{
 		packed_aes_32::*, packed_aes_512::*, packed_aes_64::*, packed_aes_8::*,
 		packed_polyval_128::*, packed_polyval_256::*, packed_polyval_512::*,
 		HybridRecursiveStrategy, PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
-		PairwiseTableStrategy, SimdStrategy,
+		PairwiseTableStrategy, PolyvalIsomorphicStrategy, ScalarArrayStrategy, SimdStrategy,
 	},
 	arithmetic_traits::TaggedSquare,
 	PackedField,
@@ -33,6 +33,14 @@ fn square_pairwise_table<T: TaggedSquare<PairwiseTableStrategy>>(val: T) -> T {
 	val.square()
 }
 
+fn square_scalar_array<T: TaggedSquare<ScalarArrayStrategy>>(val: T) -> T {
+	val.square()
+}
+
+fn square_polyval_isomorphic<T: TaggedSquare<PolyvalIsomorphicStrategy>>(val: T) -> T {
+	val.square()
+}
+
 fn square_packed<T: TaggedSquare<PackedStrategy>>(val: T) -> T {
 	val.square()
 }
@@ -52,7 +60,9 @@ benchmark_packed_operation!(
 		(main, PackedField, square_main),
 		(pairwise, TaggedSquare::<PairwiseStrategy>, square_pairwise),
 		(pairwise_recursive, TaggedSquare::<PairwiseRecursiveStrategy>, square_pairwise_recursive),
+		(polyval_isomorphic, TaggedSquare::<PolyvalIsomorphicStrategy>, square_polyval_isomorphic),
 		(pairwise_table, TaggedSquare::<PairwiseTableStrategy>, square_pairwise_table),
+		(scalar_array, TaggedSquare::<ScalarArrayStrategy>, square_scalar_array),
 		(hybrid_recursive, TaggedSquare::<HybridRecursiveStrategy>, square_hybrid_recursive),
 		(packed, TaggedSquare::<PackedStrategy>, square_packed),
 		(simd, TaggedSquare::<SimdStrategy>, square_simd),
@@ -0,0 +1,30 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::transpose_bits;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Transposes traces of `2^log_rows` rows of bit flags into columns.
+fn bench_transpose_bits(c: &mut Criterion) {
+	let mut group = c.benchmark_group("transpose_bits");
+	let mut rng = StdRng::seed_from_u64(0);
+
+	for row_bytes in [1, 8, 64] {
+		for log_rows in [12, 16, 20] {
+			let src = (0..row_bytes << log_rows)
+				.map(|_| rng.gen())
+				.collect::<Vec<u8>>();
+			let mut dst = vec![0u8; src.len()];
+
+			group.throughput(Throughput::Bytes(src.len() as u64));
+			group.bench_function(
+				BenchmarkId::new(format!("{row_bytes}B_rows"), log_rows),
+				|bench| bench.iter(|| transpose_bits(&src, row_bytes, &mut dst).unwrap()),
+			);
+		}
+	}
+	group.finish()
+}
+
+criterion_main!(transpose);
+criterion_group!(transpose, bench_transpose_bits);
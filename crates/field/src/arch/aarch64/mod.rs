@@ -5,6 +5,7 @@ use cfg_if::cfg_if;
 cfg_if! {
 	if #[cfg(all(target_feature = "neon", target_feature = "aes"))] {
 		pub(super) mod m128;
+		mod polyval_isomorphic;
 		pub(super) mod simd_arithmetic;
 
 		pub mod packed_128;
@@ -2,6 +2,8 @@
 
 use std::ops::Mul;
 
+use cfg_if::cfg_if;
+
 use super::{
 	super::portable::{
 		packed::{impl_ops_for_zero_height, PackedPrimitiveType},
@@ -46,13 +48,21 @@ impl_tower_constants!(BinaryField16b, M128, { M128(alphas!(u128, 4)) });
 impl_tower_constants!(BinaryField32b, M128, { M128(alphas!(u128, 5)) });
 impl_tower_constants!(BinaryField64b, M128, { M128(alphas!(u128, 6)) });
 
+cfg_if! {
+	if #[cfg(feature = "aarch64_polyval_mul")] {
+		type Packed1x128bStrategy = crate::arch::PolyvalIsomorphicStrategy;
+	} else {
+		type Packed1x128bStrategy = PairwiseRecursiveStrategy;
+	}
+}
+
 // Define multiplication
 impl_mul_with!(PackedBinaryField64x2b @ SimdStrategy);
 impl_mul_with!(PackedBinaryField32x4b @ SimdStrategy);
 impl_mul_with!(PackedBinaryField8x16b @ SimdStrategy);
 impl_mul_with!(PackedBinaryField4x32b @ PackedStrategy);
 impl_mul_with!(PackedBinaryField2x64b @ PairwiseStrategy);
-impl_mul_with!(PackedBinaryField1x128b @ PairwiseRecursiveStrategy);
+impl_mul_with!(PackedBinaryField1x128b @ Packed1x128bStrategy);
 
 impl Mul for PackedBinaryField16x8b {
 	type Output = Self;
@@ -72,7 +82,7 @@ impl_square_with!(PackedBinaryField32x4b @ SimdStrategy);
 impl_square_with!(PackedBinaryField8x16b @ SimdStrategy);
 impl_square_with!(PackedBinaryField4x32b @ PairwiseStrategy);
 impl_square_with!(PackedBinaryField2x64b @ PairwiseStrategy);
-impl_square_with!(PackedBinaryField1x128b @ PairwiseRecursiveStrategy);
+impl_square_with!(PackedBinaryField1x128b @ Packed1x128bStrategy);
 
 impl Square for PackedBinaryField16x8b {
 	fn square(self) -> Self {
@@ -56,7 +56,7 @@ impl InvertOrZero for PackedBinaryPolyval1x128b {
 impl_transformation_with_strategy!(PackedBinaryPolyval1x128b, PairwiseStrategy);
 
 #[inline]
-fn montgomery_multiply(a: u128, b: u128) -> u128 {
+pub(super) fn montgomery_multiply(a: u128, b: u128) -> u128 {
 	unsafe {
 		let h = vreinterpretq_u8_p128(a);
 		let y = vreinterpretq_u8_p128(b);
@@ -152,8 +152,6 @@ unsafe fn pmull(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
 /// Multiplies the high bits in `a` and `b`.
 #[inline]
 unsafe fn pmull2(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
-	mem::transmute(vmull_p64(
-		vgetq_lane_u64(vreinterpretq_u64_u8(a), 1),
-		vgetq_lane_u64(vreinterpretq_u64_u8(b), 1),
-	))
+	// Compiles to a single `PMULL2`, without moving the high lanes to general purpose registers.
+	mem::transmute(vmull_high_p64(vreinterpretq_p64_u8(a), vreinterpretq_p64_u8(b)))
 }
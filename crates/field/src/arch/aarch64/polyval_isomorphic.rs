@@ -0,0 +1,131 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Multiplication in the canonical 128-bit tower field with `PMULL`.
+//!
+//! The tower representation has no carry-less multiplication structure, but the field is
+//! isomorphic to POLYVAL, which multiplies with three 64-bit `PMULL`/`PMULL2` products and a
+//! Montgomery reduction. Converting both operands and the product costs 32 table lookups each,
+//! so whether this beats the recursive tower multiplication depends on the core. The packed
+//! field only uses it with the `aarch64_polyval_mul` feature.
+
+use super::{
+	super::portable::packed::PackedPrimitiveType, m128::M128,
+	packed_polyval_128::montgomery_multiply,
+};
+use crate::{
+	arch::PolyvalIsomorphicStrategy,
+	arithmetic_traits::{TaggedMul, TaggedSquare},
+	polyval::{BINARY_TO_POLYVAL_TRANSFORMATION, POLYVAL_TO_BINARY_TRANSFORMATION},
+	BinaryField128b,
+};
+
+/// The images of every nibble value at every nibble position of a 128-bit linear transformation.
+type NibbleTable = [[u128; 16]; 32];
+
+static BINARY_TO_POLYVAL_NIBBLES: NibbleTable = nibble_table(binary_to_polyval_bases());
+static POLYVAL_TO_BINARY_NIBBLES: NibbleTable = nibble_table(polyval_to_binary_bases());
+
+const fn binary_to_polyval_bases() -> [u128; 128] {
+	let bases = BINARY_TO_POLYVAL_TRANSFORMATION.const_bases();
+	let mut result = [0; 128];
+	let mut i = 0;
+	while i < 128 {
+		result[i] = bases[i].0;
+		i += 1;
+	}
+	result
+}
+
+const fn polyval_to_binary_bases() -> [u128; 128] {
+	let bases = POLYVAL_TO_BINARY_TRANSFORMATION.const_bases();
+	let mut result = [0; 128];
+	let mut i = 0;
+	while i < 128 {
+		result[i] = bases[i].0;
+		i += 1;
+	}
+	result
+}
+
+const fn nibble_table(bases: [u128; 128]) -> NibbleTable {
+	let mut table = [[0; 16]; 32];
+	let mut position = 0;
+	while position < 32 {
+		let mut nibble = 0;
+		while nibble < 16 {
+			let mut bit = 0;
+			while bit < 4 {
+				if (nibble >> bit) & 1 == 1 {
+					table[position][nibble] ^= bases[4 * position + bit];
+				}
+				bit += 1;
+			}
+			nibble += 1;
+		}
+		position += 1;
+	}
+	table
+}
+
+#[inline]
+fn transform(table: &NibbleTable, value: u128) -> u128 {
+	table
+		.iter()
+		.enumerate()
+		.fold(0, |acc, (position, images)| acc ^ images[(value >> (4 * position)) as usize & 0xf])
+}
+
+impl TaggedMul<PolyvalIsomorphicStrategy> for PackedPrimitiveType<M128, BinaryField128b> {
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		let lhs = transform(&BINARY_TO_POLYVAL_NIBBLES, self.0.into());
+		let rhs = transform(&BINARY_TO_POLYVAL_NIBBLES, rhs.0.into());
+		let product = montgomery_multiply(lhs, rhs);
+
+		Self::from(M128::from(transform(&POLYVAL_TO_BINARY_NIBBLES, product)))
+	}
+}
+
+impl TaggedSquare<PolyvalIsomorphicStrategy> for PackedPrimitiveType<M128, BinaryField128b> {
+	#[inline]
+	fn square(self) -> Self {
+		let value = transform(&BINARY_TO_POLYVAL_NIBBLES, self.0.into());
+		let square = montgomery_multiply(value, value);
+
+		Self::from(M128::from(transform(&POLYVAL_TO_BINARY_NIBBLES, square)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::*;
+	use crate::{
+		arch::portable::packed_128::PackedBinaryField1x128b as PortablePackedBinaryField1x128b,
+		underlier::WithUnderlier, PackedField,
+	};
+
+	type Packed = PackedPrimitiveType<M128, BinaryField128b>;
+
+	proptest! {
+		#[test]
+		fn test_mul_matches_portable(a in any::<u128>(), b in any::<u128>()) {
+			let expected = PortablePackedBinaryField1x128b::from_underlier(a)
+				* PortablePackedBinaryField1x128b::from_underlier(b);
+			let result = TaggedMul::<PolyvalIsomorphicStrategy>::mul(
+				Packed::from_underlier(a.into()),
+				Packed::from_underlier(b.into()),
+			);
+			prop_assert_eq!(u128::from(result.to_underlier()), expected.to_underlier());
+		}
+
+		#[test]
+		fn test_square_matches_portable(a in any::<u128>()) {
+			let expected = PackedField::square(PortablePackedBinaryField1x128b::from_underlier(a));
+			let result =
+				TaggedSquare::<PolyvalIsomorphicStrategy>::square(Packed::from_underlier(a.into()));
+			prop_assert_eq!(u128::from(result.to_underlier()), expected.to_underlier());
+		}
+	}
+}
@@ -12,16 +12,19 @@ cfg_if! {
 		mod portable;
 
 		mod x86_64;
+		pub(crate) use x86_64::bit_transpose;
 		pub use x86_64::{packed_128, packed_256, packed_512, packed_aes_128, packed_aes_256, packed_aes_512, packed_polyval_128, packed_polyval_256, packed_polyval_512};
 	} else if #[cfg(target_arch = "aarch64")] {
 		#[allow(dead_code)]
 		mod portable;
 
 		mod aarch64;
+		pub(crate) use portable::bit_transpose;
 		pub use aarch64::{packed_128, packed_polyval_128, packed_aes_128};
 		pub use portable::{packed_256, packed_512, packed_aes_256, packed_aes_512, packed_polyval_256, packed_polyval_512};
 	} else {
 		mod portable;
+		pub(crate) use portable::bit_transpose;
 		pub use portable::{packed_128, packed_256, packed_512, packed_aes_128, packed_aes_256, packed_aes_512, packed_polyval_128, packed_polyval_256, packed_polyval_512};
 	}
 }
@@ -29,6 +32,6 @@ cfg_if! {
 pub use arch_optimal::*;
 pub use portable::{
 	byte_sliced, packed_1, packed_16, packed_2, packed_32, packed_4, packed_64, packed_8,
-	packed_aes_16, packed_aes_32, packed_aes_64, packed_aes_8,
+	packed_aes_16, packed_aes_32, packed_aes_64, packed_aes_8, packed_scalar_array,
 };
 pub use strategies::*;
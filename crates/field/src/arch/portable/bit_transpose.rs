@@ -0,0 +1,26 @@
+// Copyright 2025 Irreducible Inc.
+
+/// Transposes a 16x8 bit matrix.
+///
+/// Byte `i` of `rows` is row `i` of the matrix. Returns the columns: bit `i` of element `j` is bit
+/// `j` of row `i`.
+#[inline]
+pub fn transpose_16x8(rows: [u8; 16]) -> [u16; 8] {
+	let lo = transpose_8x8(u64::from_le_bytes(rows[..8].try_into().expect("8 bytes")));
+	let hi = transpose_8x8(u64::from_le_bytes(rows[8..].try_into().expect("8 bytes")));
+	let (lo, hi) = (lo.to_le_bytes(), hi.to_le_bytes());
+	std::array::from_fn(|j| u16::from_le_bytes([lo[j], hi[j]]))
+}
+
+/// Transposes an 8x8 bit matrix stored with row `i` in byte `i`.
+///
+/// See Hacker's Delight, Section 7-3.
+#[inline]
+fn transpose_8x8(mut x: u64) -> u64 {
+	let t = (x ^ (x >> 7)) & 0x00AA00AA00AA00AA;
+	x ^= t ^ (t << 7);
+	let t = (x ^ (x >> 14)) & 0x0000CCCC0000CCCC;
+	x ^= t ^ (t << 14);
+	let t = (x ^ (x >> 28)) & 0x00000000F0F0F0F0;
+	x ^ t ^ (t << 28)
+}
@@ -25,10 +25,14 @@ pub mod packed_polyval_128;
 pub mod packed_polyval_256;
 pub mod packed_polyval_512;
 
+pub mod packed_scalar_array;
+
 pub mod byte_sliced;
 
 pub(super) mod packed_scaled;
 
+pub(crate) mod bit_transpose;
+
 pub(super) mod hybrid_recursive_arithmetics;
 pub(super) mod packed_arithmetic;
 pub(super) mod pairwise_arithmetic;
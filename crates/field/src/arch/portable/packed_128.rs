@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use cfg_if::cfg_if;
+
 use super::{
 	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
 	packed_arithmetic::{alphas, impl_tower_constants},
@@ -24,6 +26,18 @@ pub type PackedBinaryField4x32b = PackedPrimitiveType<u128, BinaryField32b>;
 pub type PackedBinaryField2x64b = PackedPrimitiveType<u128, BinaryField64b>;
 pub type PackedBinaryField1x128b = PackedPrimitiveType<u128, BinaryField128b>;
 
+cfg_if! {
+	if #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))] {
+		// These targets export their own 128-bit packed fields, so the types of this module have
+		// no scalar arrays to compute on
+		type ElementwiseStrategy = PairwiseStrategy;
+	} else {
+		// Without SIMD strategies, compute the element-wise operations in loops over scalar
+		// arrays, which the compiler can vectorize
+		type ElementwiseStrategy = crate::arch::ScalarArrayStrategy;
+	}
+}
+
 // Define broadcast
 impl_broadcast!(u128, BinaryField1b);
 impl_broadcast!(u128, BinaryField2b);
@@ -50,8 +64,8 @@ impl_tower_constants!(BinaryField64b, u128, { alphas!(u128, 6) });
 impl_mul_with!(PackedBinaryField64x2b @ PackedStrategy);
 impl_mul_with!(PackedBinaryField32x4b @ PackedStrategy);
 impl_mul_with!(PackedBinaryField16x8b @ PackedStrategy);
-impl_mul_with!(PackedBinaryField8x16b @ PairwiseStrategy);
-impl_mul_with!(PackedBinaryField4x32b @ PairwiseStrategy);
+impl_mul_with!(PackedBinaryField8x16b @ ElementwiseStrategy);
+impl_mul_with!(PackedBinaryField4x32b @ ElementwiseStrategy);
 impl_mul_with!(PackedBinaryField2x64b @ PairwiseRecursiveStrategy);
 impl_mul_with!(PackedBinaryField1x128b @ PairwiseRecursiveStrategy);
 
@@ -60,8 +74,8 @@ impl_square_with!(PackedBinaryField64x2b @ PackedStrategy);
 impl_square_with!(PackedBinaryField32x4b @ PackedStrategy);
 impl_square_with!(PackedBinaryField16x8b @ PackedStrategy);
 impl_square_with!(PackedBinaryField8x16b @ PairwiseRecursiveStrategy);
-impl_square_with!(PackedBinaryField4x32b @ PairwiseStrategy);
-impl_square_with!(PackedBinaryField2x64b @ PairwiseStrategy);
+impl_square_with!(PackedBinaryField4x32b @ ElementwiseStrategy);
+impl_square_with!(PackedBinaryField2x64b @ ElementwiseStrategy);
 impl_square_with!(PackedBinaryField1x128b @ PairwiseRecursiveStrategy);
 
 // Define invert
@@ -69,7 +83,7 @@ impl_invert_with!(PackedBinaryField64x2b @ PackedStrategy);
 impl_invert_with!(PackedBinaryField32x4b @ PackedStrategy);
 impl_invert_with!(PackedBinaryField16x8b @ PairwiseTableStrategy);
 impl_invert_with!(PackedBinaryField8x16b @ PairwiseRecursiveStrategy);
-impl_invert_with!(PackedBinaryField4x32b @ PairwiseStrategy);
+impl_invert_with!(PackedBinaryField4x32b @ ElementwiseStrategy);
 impl_invert_with!(PackedBinaryField2x64b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedBinaryField1x128b @ PairwiseRecursiveStrategy);
 
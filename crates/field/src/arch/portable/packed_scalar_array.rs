@@ -0,0 +1,638 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	array,
+	iter::{Product, Sum},
+	ops::{Add, AddAssign, Deref, Mul, MulAssign, Sub, SubAssign},
+};
+
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use rand::RngCore;
+
+use super::{
+	packed_128::*, packed_16::*, packed_32::*, packed_64::*, packed_8::*, packed_aes_128::*,
+	packed_aes_16::*, packed_aes_32::*, packed_aes_64::*, packed_aes_8::*,
+	packed_scaled::packed_scaled_field,
+};
+use crate::{
+	arch::{PairwiseStrategy, ScalarArrayStrategy},
+	arithmetic_traits::{
+		Broadcast, InvertOrZero, MulAlpha, Square, TaggedInvertOrZero, TaggedMul, TaggedMulAlpha,
+		TaggedPackedTransformationFactory, TaggedSquare,
+	},
+	linear_transformation::{FieldLinearTransformation, PackedTransformationFactory},
+	packed::PackedBinaryField,
+	underlier::{ScaledUnderlier, WithUnderlier},
+	BinaryField, Field, PackedField,
+};
+
+/// Packed field that stores `WIDTH` scalars in an array and performs all operations scalar by
+/// scalar.
+///
+/// Unlike the other packed fields, this type does not depend on an underlier with bit operations
+/// or on hand-written SIMD code for the target. Every operation is a loop over the array with a
+/// length known at compile time, which the compiler unrolls and, for addition and the bit
+/// operations inside the scalar arithmetic, vectorizes for the target. This gives a reasonable
+/// fallback on platforms that have no dedicated strategy, like RISC-V, where the portable packed
+/// fields use it through [`ScalarArrayStrategy`].
+///
+/// The underlier is the array of the underliers of the scalars, so the packed extension traits
+/// view it as a [`ScaledUnderlier`] of the scalar underliers, packed with the subfield types of
+/// those.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct PackedScalarArray<const WIDTH: usize, Scalar: BinaryField>(pub [Scalar; WIDTH]);
+
+impl<const WIDTH: usize, Scalar: BinaryField> PackedScalarArray<WIDTH, Scalar> {
+	pub const LOG_WIDTH: usize = {
+		assert!(WIDTH.is_power_of_two());
+
+		WIDTH.ilog2() as usize
+	};
+
+	#[inline]
+	fn map(self, f: impl Fn(Scalar) -> Scalar) -> Self {
+		Self(array::from_fn(|i| f(self.0[i])))
+	}
+
+	#[inline]
+	fn zip_with(self, rhs: Self, f: impl Fn(Scalar, Scalar) -> Scalar) -> Self {
+		Self(array::from_fn(|i| f(self.0[i], rhs.0[i])))
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Default for PackedScalarArray<WIDTH, Scalar> {
+	fn default() -> Self {
+		Self([Scalar::ZERO; WIDTH])
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> From<[Scalar; WIDTH]>
+	for PackedScalarArray<WIDTH, Scalar>
+{
+	fn from(value: [Scalar; WIDTH]) -> Self {
+		Self(value)
+	}
+}
+
+unsafe impl<const WIDTH: usize, Scalar: BinaryField + Zeroable> Zeroable
+	for PackedScalarArray<WIDTH, Scalar>
+{
+}
+
+unsafe impl<const WIDTH: usize, Scalar: BinaryField + Pod> Pod
+	for PackedScalarArray<WIDTH, Scalar>
+{
+}
+
+// Safety: the array of scalars and the array of their underliers are both transparent over
+// `WIDTH` values of the underlier.
+unsafe impl<const WIDTH: usize, Scalar, U> TransparentWrapper<ScaledUnderlier<U, WIDTH>>
+	for PackedScalarArray<WIDTH, Scalar>
+where
+	Scalar: BinaryField + WithUnderlier<Underlier = U>,
+{
+}
+
+unsafe impl<const WIDTH: usize, Scalar> WithUnderlier for PackedScalarArray<WIDTH, Scalar>
+where
+	Scalar: BinaryField + Zeroable + WithUnderlier<Underlier: Pod>,
+{
+	type Underlier = ScaledUnderlier<Scalar::Underlier, WIDTH>;
+
+	#[inline(always)]
+	fn to_underlier(self) -> Self::Underlier {
+		TransparentWrapper::peel(self)
+	}
+
+	#[inline(always)]
+	fn to_underlier_ref(&self) -> &Self::Underlier {
+		TransparentWrapper::peel_ref(self)
+	}
+
+	#[inline(always)]
+	fn to_underlier_ref_mut(&mut self) -> &mut Self::Underlier {
+		TransparentWrapper::peel_mut(self)
+	}
+
+	#[inline(always)]
+	fn to_underliers_ref(val: &[Self]) -> &[Self::Underlier] {
+		TransparentWrapper::peel_slice(val)
+	}
+
+	#[inline(always)]
+	fn to_underliers_ref_mut(val: &mut [Self]) -> &mut [Self::Underlier] {
+		TransparentWrapper::peel_slice_mut(val)
+	}
+
+	#[inline(always)]
+	fn from_underlier(val: Self::Underlier) -> Self {
+		TransparentWrapper::wrap(val)
+	}
+
+	#[inline(always)]
+	fn from_underlier_ref(val: &Self::Underlier) -> &Self {
+		TransparentWrapper::wrap_ref(val)
+	}
+
+	#[inline(always)]
+	fn from_underlier_ref_mut(val: &mut Self::Underlier) -> &mut Self {
+		TransparentWrapper::wrap_mut(val)
+	}
+
+	#[inline(always)]
+	fn from_underliers_ref(val: &[Self::Underlier]) -> &[Self] {
+		TransparentWrapper::wrap_slice(val)
+	}
+
+	#[inline(always)]
+	fn from_underliers_ref_mut(val: &mut [Self::Underlier]) -> &mut [Self] {
+		TransparentWrapper::wrap_slice_mut(val)
+	}
+}
+
+// The packed subfields of a scalar array are scaled packed fields over the underliers of the
+// scalars. Define the scalar operations of those for the widths of the `WithScalarArray` types.
+macro_rules! packed_scaled_subfields {
+	($size:literal: $($inner:ident),*) => {
+		$(packed_scaled_field!([$inner; $size]);)*
+	};
+}
+
+packed_scaled_subfields!(16:
+	PackedBinaryField8x1b, PackedBinaryField4x2b, PackedBinaryField2x4b, PackedBinaryField1x8b,
+	PackedAESBinaryField1x8b
+);
+packed_scaled_subfields!(8:
+	PackedBinaryField16x1b, PackedBinaryField8x2b, PackedBinaryField4x4b, PackedBinaryField2x8b,
+	PackedBinaryField1x16b, PackedAESBinaryField2x8b, PackedAESBinaryField1x16b
+);
+packed_scaled_subfields!(4:
+	PackedBinaryField32x1b, PackedBinaryField16x2b, PackedBinaryField8x4b, PackedBinaryField4x8b,
+	PackedBinaryField2x16b, PackedBinaryField1x32b, PackedAESBinaryField4x8b,
+	PackedAESBinaryField2x16b, PackedAESBinaryField1x32b
+);
+packed_scaled_subfields!(2:
+	PackedBinaryField64x1b, PackedBinaryField32x2b, PackedBinaryField16x4b, PackedBinaryField8x8b,
+	PackedBinaryField4x16b, PackedBinaryField2x32b, PackedBinaryField1x64b,
+	PackedAESBinaryField8x8b, PackedAESBinaryField4x16b, PackedAESBinaryField2x32b,
+	PackedAESBinaryField1x64b
+);
+packed_scaled_subfields!(1:
+	PackedBinaryField128x1b, PackedBinaryField64x2b, PackedBinaryField32x4b,
+	PackedBinaryField16x8b, PackedBinaryField8x16b, PackedBinaryField4x32b, PackedBinaryField2x64b,
+	PackedBinaryField1x128b, PackedAESBinaryField16x8b, PackedAESBinaryField8x16b,
+	PackedAESBinaryField4x32b, PackedAESBinaryField2x64b, PackedAESBinaryField1x128b
+);
+
+impl<const WIDTH: usize, Scalar: BinaryField> Add for PackedScalarArray<WIDTH, Scalar> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, rhs: Self) -> Self {
+		self.zip_with(rhs, Add::add)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Sub for PackedScalarArray<WIDTH, Scalar> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, rhs: Self) -> Self {
+		self.zip_with(rhs, Sub::sub)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Mul for PackedScalarArray<WIDTH, Scalar> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		crate::tracing::trace_multiplication!(PackedScalarArray<WIDTH, Scalar>);
+
+		self.zip_with(rhs, Mul::mul)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> AddAssign for PackedScalarArray<WIDTH, Scalar> {
+	#[inline]
+	fn add_assign(&mut self, rhs: Self) {
+		*self = *self + rhs;
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> SubAssign for PackedScalarArray<WIDTH, Scalar> {
+	#[inline]
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = *self - rhs;
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> MulAssign for PackedScalarArray<WIDTH, Scalar> {
+	#[inline]
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Add<Scalar> for PackedScalarArray<WIDTH, Scalar> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, rhs: Scalar) -> Self {
+		self.map(|x| x + rhs)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Sub<Scalar> for PackedScalarArray<WIDTH, Scalar> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, rhs: Scalar) -> Self {
+		self.map(|x| x - rhs)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Mul<Scalar> for PackedScalarArray<WIDTH, Scalar> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: Scalar) -> Self {
+		self.map(|x| x * rhs)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> AddAssign<Scalar>
+	for PackedScalarArray<WIDTH, Scalar>
+{
+	#[inline]
+	fn add_assign(&mut self, rhs: Scalar) {
+		*self = *self + rhs;
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> SubAssign<Scalar>
+	for PackedScalarArray<WIDTH, Scalar>
+{
+	#[inline]
+	fn sub_assign(&mut self, rhs: Scalar) {
+		*self = *self - rhs;
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> MulAssign<Scalar>
+	for PackedScalarArray<WIDTH, Scalar>
+{
+	#[inline]
+	fn mul_assign(&mut self, rhs: Scalar) {
+		*self = *self * rhs;
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Sum for PackedScalarArray<WIDTH, Scalar> {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::default(), |result, next| result + next)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Product for PackedScalarArray<WIDTH, Scalar> {
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self([Scalar::ONE; WIDTH]), |result, next| result * next)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Broadcast<Scalar>
+	for PackedScalarArray<WIDTH, Scalar>
+{
+	#[inline]
+	fn broadcast(scalar: Scalar) -> Self {
+		Self([scalar; WIDTH])
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> Square for PackedScalarArray<WIDTH, Scalar> {
+	#[inline]
+	fn square(self) -> Self {
+		self.map(Square::square)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField> InvertOrZero for PackedScalarArray<WIDTH, Scalar> {
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		self.map(InvertOrZero::invert_or_zero)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField + MulAlpha> MulAlpha
+	for PackedScalarArray<WIDTH, Scalar>
+{
+	#[inline]
+	fn mul_alpha(self) -> Self {
+		self.map(MulAlpha::mul_alpha)
+	}
+}
+
+impl<const WIDTH: usize, Scalar: BinaryField + Zeroable> PackedField
+	for PackedScalarArray<WIDTH, Scalar>
+{
+	type Scalar = Scalar;
+
+	const LOG_WIDTH: usize = Self::LOG_WIDTH;
+
+	#[inline]
+	unsafe fn get_unchecked(&self, i: usize) -> Scalar {
+		*self.0.get_unchecked(i)
+	}
+
+	#[inline]
+	unsafe fn set_unchecked(&mut self, i: usize, scalar: Scalar) {
+		*self.0.get_unchecked_mut(i) = scalar;
+	}
+
+	fn random(mut rng: impl RngCore) -> Self {
+		Self(array::from_fn(|_| <Scalar as Field>::random(&mut rng)))
+	}
+
+	#[inline]
+	fn broadcast(scalar: Scalar) -> Self {
+		<Self as Broadcast<Scalar>>::broadcast(scalar)
+	}
+
+	#[inline]
+	fn from_fn(f: impl FnMut(usize) -> Scalar) -> Self {
+		Self(array::from_fn(f))
+	}
+
+	#[inline]
+	fn square(self) -> Self {
+		<Self as Square>::square(self)
+	}
+
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		<Self as InvertOrZero>::invert_or_zero(self)
+	}
+
+	#[inline]
+	fn interleave(self, other: Self, log_block_len: usize) -> (Self, Self) {
+		assert!(log_block_len < Self::LOG_WIDTH);
+		let block_len = 1 << log_block_len;
+		// Element `i` trades places with element `i ^ block_len` of the other vector, if it is in
+		// an odd block of `self` or an even block of `other`.
+		let c = array::from_fn(|i| {
+			if i & block_len == 0 {
+				self.0[i]
+			} else {
+				other.0[i ^ block_len]
+			}
+		});
+		let d = array::from_fn(|i| {
+			if i & block_len == 0 {
+				self.0[i ^ block_len]
+			} else {
+				other.0[i]
+			}
+		});
+		(Self(c), Self(d))
+	}
+
+	#[inline]
+	unsafe fn spread_unchecked(self, log_block_len: usize, block_idx: usize) -> Self {
+		let offset = block_idx << log_block_len;
+		let log_repeat = Self::LOG_WIDTH - log_block_len;
+		Self(array::from_fn(|i| self.0[offset + (i >> log_repeat)]))
+	}
+}
+
+impl<const WIDTH: usize, Scalar, OP> PackedTransformationFactory<OP>
+	for PackedScalarArray<WIDTH, Scalar>
+where
+	Scalar: BinaryField + Zeroable,
+	OP: PackedBinaryField,
+{
+	type PackedTransformation<Data: Deref<Target = [OP::Scalar]>> =
+		<Self as TaggedPackedTransformationFactory<PairwiseStrategy, OP>>::PackedTransformation<
+			Data,
+		>;
+
+	fn make_packed_transformation<Data: Deref<Target = [OP::Scalar]>>(
+		transformation: FieldLinearTransformation<OP::Scalar, Data>,
+	) -> Self::PackedTransformation<Data> {
+		<Self as TaggedPackedTransformationFactory<PairwiseStrategy, OP>>::make_packed_transformation(
+			transformation,
+		)
+	}
+}
+
+/// A packed field that [`ScalarArrayStrategy`] computes on as the [`PackedScalarArray`] of its
+/// scalars.
+pub trait WithScalarArray: PackedField {
+	type ScalarArray: PackedField<Scalar = Self::Scalar>;
+
+	#[inline]
+	fn to_scalar_array(self) -> Self::ScalarArray {
+		Self::ScalarArray::from_fn(|i| self.get(i))
+	}
+
+	#[inline]
+	fn from_scalar_array(array: Self::ScalarArray) -> Self {
+		Self::from_fn(|i| array.get(i))
+	}
+}
+
+/// Implements [`WithScalarArray`] for packed fields, given the width of each.
+macro_rules! impl_with_scalar_array {
+	($($name:ty => $width:literal),* $(,)?) => {
+		$(
+			impl $crate::arch::packed_scalar_array::WithScalarArray for $name {
+				type ScalarArray = $crate::arch::packed_scalar_array::PackedScalarArray<
+					$width,
+					<$name as $crate::PackedField>::Scalar,
+				>;
+			}
+		)*
+	};
+}
+
+pub(crate) use impl_with_scalar_array;
+
+impl_with_scalar_array!(
+	crate::arch::packed_128::PackedBinaryField16x8b => 16,
+	crate::arch::packed_128::PackedBinaryField8x16b => 8,
+	crate::arch::packed_128::PackedBinaryField4x32b => 4,
+	crate::arch::packed_128::PackedBinaryField2x64b => 2,
+	crate::arch::packed_128::PackedBinaryField1x128b => 1,
+	crate::arch::packed_aes_128::PackedAESBinaryField16x8b => 16,
+	crate::arch::packed_aes_128::PackedAESBinaryField8x16b => 8,
+	crate::arch::packed_aes_128::PackedAESBinaryField4x32b => 4,
+	crate::arch::packed_aes_128::PackedAESBinaryField2x64b => 2,
+	crate::arch::packed_aes_128::PackedAESBinaryField1x128b => 1,
+);
+
+impl<PT: WithScalarArray> TaggedMul<ScalarArrayStrategy> for PT {
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		Self::from_scalar_array(self.to_scalar_array() * rhs.to_scalar_array())
+	}
+}
+
+impl<PT: WithScalarArray> TaggedSquare<ScalarArrayStrategy> for PT {
+	#[inline]
+	fn square(self) -> Self {
+		Self::from_scalar_array(PackedField::square(self.to_scalar_array()))
+	}
+}
+
+impl<PT: WithScalarArray> TaggedInvertOrZero<ScalarArrayStrategy> for PT {
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		Self::from_scalar_array(PackedField::invert_or_zero(self.to_scalar_array()))
+	}
+}
+
+impl<PT> TaggedMulAlpha<ScalarArrayStrategy> for PT
+where
+	PT: WithScalarArray<ScalarArray: MulAlpha>,
+{
+	#[inline]
+	fn mul_alpha(self) -> Self {
+		Self::from_scalar_array(MulAlpha::mul_alpha(self.to_scalar_array()))
+	}
+}
+
+// Transforming an element multiplies it by a matrix, which does not vectorize across the
+// elements, so the transformation is the one of `PairwiseStrategy`.
+impl<IP, OP> TaggedPackedTransformationFactory<ScalarArrayStrategy, OP> for IP
+where
+	IP: PackedBinaryField + WithScalarArray,
+	OP: PackedBinaryField,
+{
+	type PackedTransformation<Data: Deref<Target = [OP::Scalar]>> =
+		<IP as TaggedPackedTransformationFactory<PairwiseStrategy, OP>>::PackedTransformation<Data>;
+
+	fn make_packed_transformation<Data: Deref<Target = [OP::Scalar]>>(
+		transformation: FieldLinearTransformation<OP::Scalar, Data>,
+	) -> Self::PackedTransformation<Data> {
+		<IP as TaggedPackedTransformationFactory<PairwiseStrategy, OP>>::make_packed_transformation(
+			transformation,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		test_utils::{
+			define_invert_tests, define_mul_alpha_tests, define_multiply_tests,
+			define_square_tests, define_transformation_tests,
+		},
+		BinaryField16b, BinaryField1b, BinaryField4b, ExtensionField, PackedAESBinaryField16x8b,
+		PackedBinaryField128x1b, PackedBinaryField16x8b, PackedBinaryField1x128b,
+		PackedBinaryField4x32b, PackedBinaryField8x16b, PackedExtension, RepackedExtension,
+	};
+
+	/// Checks that every operation matches the packed field `P` of the same width and scalar.
+	fn check_matches<P, const WIDTH: usize>()
+	where
+		P: PackedField<Scalar: BinaryField + Zeroable> + MulAlpha,
+		P::Scalar: MulAlpha,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		let to_array =
+			|packed: P| PackedScalarArray::<WIDTH, P::Scalar>::from_fn(|i| packed.get(i));
+		for _ in 0..16 {
+			let (a, b) = (P::random(&mut rng), P::random(&mut rng));
+			let scalar = <P::Scalar as Field>::random(&mut rng);
+			let (x, y) = (to_array(a), to_array(b));
+
+			assert_eq!(x + y, to_array(a + b));
+			assert_eq!(x - y, to_array(a - b));
+			assert_eq!(x * y, to_array(a * b));
+			assert_eq!(x * scalar, to_array(a * scalar));
+			assert_eq!(PackedField::square(x), to_array(PackedField::square(a)));
+			assert_eq!(PackedField::invert_or_zero(x), to_array(PackedField::invert_or_zero(a)));
+			assert_eq!(MulAlpha::mul_alpha(x), to_array(MulAlpha::mul_alpha(a)));
+			for log_block_len in 0..P::LOG_WIDTH {
+				let (c, d) = a.interleave(b, log_block_len);
+				assert_eq!(x.interleave(y, log_block_len), (to_array(c), to_array(d)));
+			}
+			for log_block_len in 0..=P::LOG_WIDTH {
+				for block_idx in 0..1 << (P::LOG_WIDTH - log_block_len) {
+					assert_eq!(
+						x.spread(log_block_len, block_idx),
+						to_array(a.spread(log_block_len, block_idx))
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_matches_packed_fields() {
+		check_matches::<PackedBinaryField128x1b, 128>();
+		check_matches::<PackedBinaryField16x8b, 16>();
+		check_matches::<PackedBinaryField8x16b, 8>();
+		check_matches::<PackedBinaryField4x32b, 4>();
+		check_matches::<PackedBinaryField1x128b, 1>();
+		check_matches::<PackedAESBinaryField16x8b, 16>();
+	}
+
+	#[test]
+	fn test_packed_extension() {
+		type P = PackedScalarArray<8, BinaryField16b>;
+		fn assert_repacked<PT, PS>()
+		where
+			PT: RepackedExtension<PS, Scalar: ExtensionField<PS::Scalar>>,
+			PS: PackedField,
+		{
+		}
+		assert_repacked::<P, <P as PackedExtension<BinaryField4b>>::PackedSubfield>();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let packed = [P::random(&mut rng), P::random(&mut rng)];
+		let canonical = packed.map(|x| PackedBinaryField8x16b::from_fn(|i| x.get(i)));
+
+		// The bases are the bits of the scalars, as for the packed field of the same scalars
+		let bases = <P as PackedExtension<BinaryField1b>>::cast_bases(&packed);
+		let canonical_bases =
+			<PackedBinaryField8x16b as PackedExtension<BinaryField1b>>::cast_bases(&canonical);
+		assert!(PackedField::iter_slice(bases).eq(PackedField::iter_slice(canonical_bases)));
+		assert_eq!(<P as PackedExtension<BinaryField1b>>::cast_exts(bases), packed);
+
+		assert_eq!(P::from_underlier(packed[0].to_underlier()), packed[0]);
+	}
+
+	define_multiply_tests!(TaggedMul<ScalarArrayStrategy>::mul, TaggedMul<ScalarArrayStrategy>);
+
+	define_square_tests!(
+		TaggedSquare<ScalarArrayStrategy>::square,
+		TaggedSquare<ScalarArrayStrategy>
+	);
+
+	define_invert_tests!(
+		TaggedInvertOrZero<ScalarArrayStrategy>::invert_or_zero,
+		TaggedInvertOrZero<ScalarArrayStrategy>
+	);
+
+	define_mul_alpha_tests!(
+		TaggedMulAlpha<ScalarArrayStrategy>::mul_alpha,
+		TaggedMulAlpha<ScalarArrayStrategy>
+	);
+
+	#[allow(unused)]
+	trait SelfPackedTransformationFactory:
+		TaggedPackedTransformationFactory<ScalarArrayStrategy, Self>
+	{
+	}
+
+	impl<T: TaggedPackedTransformationFactory<ScalarArrayStrategy, Self>>
+		SelfPackedTransformationFactory for T
+	{
+	}
+
+	define_transformation_tests!(SelfPackedTransformationFactory);
+}
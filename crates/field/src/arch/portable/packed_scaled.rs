@@ -352,12 +352,18 @@ where
 /// The only thing that prevents us from having pure generic implementation of `ScaledPackedField`
 /// is that we can't have generic operations both with `Self` and `PT::Scalar`
 /// (it leads to `conflicting implementations of trait` error).
-/// That's why we implement one of those in a macro.
+/// That's why we implement one of those in a macro. The second form of the macro implements the
+/// operations without naming the type.
 macro_rules! packed_scaled_field {
 	($name:ident = [$inner:ty;$size:literal]) => {
 		pub type $name = $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>;
 
-		impl std::ops::Add<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		$crate::arch::portable::packed_scaled::packed_scaled_field!([$inner; $size]);
+	};
+	([$inner:ty;$size:literal]) => {
+		impl std::ops::Add<<$inner as $crate::packed::PackedField>::Scalar>
+			for $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>
+		{
 			type Output = Self;
 
 			fn add(self, rhs: <$inner as $crate::packed::PackedField>::Scalar) -> Self {
@@ -370,7 +376,9 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::AddAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl std::ops::AddAssign<<$inner as $crate::packed::PackedField>::Scalar>
+			for $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>
+		{
 			fn add_assign(&mut self, rhs: <$inner as $crate::packed::PackedField>::Scalar) {
 				for i in 0..Self::WIDTH_IN_PT {
 					self.0[i] += rhs;
@@ -378,7 +386,9 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::Sub<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl std::ops::Sub<<$inner as $crate::packed::PackedField>::Scalar>
+			for $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>
+		{
 			type Output = Self;
 
 			fn sub(self, rhs: <$inner as $crate::packed::PackedField>::Scalar) -> Self {
@@ -391,7 +401,9 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::SubAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl std::ops::SubAssign<<$inner as $crate::packed::PackedField>::Scalar>
+			for $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>
+		{
 			fn sub_assign(&mut self, rhs: <$inner as $crate::packed::PackedField>::Scalar) {
 				for i in 0..Self::WIDTH_IN_PT {
 					self.0[i] -= rhs;
@@ -399,7 +411,9 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::Mul<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl std::ops::Mul<<$inner as $crate::packed::PackedField>::Scalar>
+			for $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>
+		{
 			type Output = Self;
 
 			fn mul(self, rhs: <$inner as $crate::packed::PackedField>::Scalar) -> Self {
@@ -412,7 +426,9 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::MulAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl std::ops::MulAssign<<$inner as $crate::packed::PackedField>::Scalar>
+			for $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>
+		{
 			fn mul_assign(&mut self, rhs: <$inner as $crate::packed::PackedField>::Scalar) {
 				for i in 0..Self::WIDTH_IN_PT {
 					self.0[i] *= rhs;
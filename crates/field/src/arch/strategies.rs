@@ -11,12 +11,20 @@ pub struct PairwiseRecursiveStrategy;
 pub struct PairwiseStrategy;
 /// Get result of operation from the table for each sub-element
 pub struct PairwiseTableStrategy;
+/// Pairwise strategy that copies the elements into a
+/// [`PackedScalarArray`](crate::arch::packed_scalar_array::PackedScalarArray) and applies the
+/// operation in a loop of known length, which the compiler can vectorize.
+pub struct ScalarArrayStrategy;
 /// Similar to `PackedStrategy`, but uses SIMD operations supported by the platform.
 pub struct SimdStrategy;
 /// Applicable only for multiply by alpha and square operations.
 /// Reuse multiplication operation for that.
 pub struct ReuseMultiplyStrategy;
 
+/// Scalar arithmetic whose running time does not depend on the values, for provers handling
+/// secret data. Only implemented for the scalar canonical tower fields.
+pub struct ConstantTimeStrategy;
+
 /// Use operations with GFNI instructions
 pub struct GfniStrategy;
 /// Specialized versions of the above to resolve conflicting implementations
@@ -27,3 +35,8 @@ pub struct GfniSpecializedStrategy512b;
 /// Performs conversion to the packed isomorphic AES field, applies the operation and
 /// converts the result back to the canonical tower field.
 pub struct AESIsomorphicStrategy;
+
+/// Strategy for the canonical 128-bit tower field.
+/// Performs conversion to the isomorphic POLYVAL field, multiplies with the carry-less
+/// multiplication instructions of the platform and converts the result back.
+pub struct PolyvalIsomorphicStrategy;
@@ -0,0 +1,21 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::arch::x86_64::*;
+
+/// Transposes a 16x8 bit matrix.
+///
+/// Byte `i` of `rows` is row `i` of the matrix. Returns the columns: bit `i` of element `j` is bit
+/// `j` of row `i`. `PMOVMSKB` gathers the top bit of all 16 rows at once, so the columns are
+/// extracted from the highest one down, doubling the bytes in between.
+#[inline]
+pub fn transpose_16x8(rows: [u8; 16]) -> [u16; 8] {
+	let mut columns = [0u16; 8];
+	unsafe {
+		let mut x = _mm_loadu_si128(rows.as_ptr() as *const __m128i);
+		for column in columns.iter_mut().rev() {
+			*column = _mm_movemask_epi8(x) as u16;
+			x = _mm_add_epi8(x, x);
+		}
+	}
+	columns
+}
@@ -12,10 +12,12 @@ mod simd;
 cfg_if! {
 	if #[cfg(target_feature = "sse2")] {
 		pub(super) mod m128;
+		pub(crate) mod bit_transpose;
 		pub mod packed_128;
 		pub mod packed_polyval_128;
 		pub mod packed_aes_128;
 	} else {
+		pub(crate) use super::portable::bit_transpose;
 		pub use super::portable::packed_128;
 		pub use super::portable::packed_aes_128;
 		pub use super::portable::packed_polyval_128;
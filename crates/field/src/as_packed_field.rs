@@ -10,7 +10,7 @@ use crate::{
 	binary_field::*,
 	polyval::BinaryField128bPolyval,
 	underlier::{UnderlierType, WithUnderlier},
-	ExtensionField, Field, PackedField,
+	ExtensionField, Field, PackedField, PackedFieldIndexable,
 };
 
 /// Trait that establishes correspondence between the scalar field and a packed field of the same
@@ -71,6 +71,31 @@ pub trait PackScalar<F: Field>: UnderlierType {
 /// Returns the packed field type for the scalar field `F` and underlier `U`.
 pub type PackedType<U, F> = <U as PackScalar<F>>::Packed;
 
+/// Views a slice of underliers as the scalars of `F` they pack, without copying.
+///
+/// The view requires the packed `F` elements to be indexable, i.e. the scalars to be byte-aligned
+/// and stored contiguously, which the trait bound checks at compile time. Underliers holding
+/// fields narrower than a byte, like `BinaryField1b`, can be viewed as scalars of any byte-aligned
+/// extension instead, because packings of a tower share their bit layout.
+pub fn underliers_as_scalars<U, F>(underliers: &[U]) -> &[F]
+where
+	U: PackScalar<F, Packed: PackedFieldIndexable>,
+	F: Field,
+{
+	PackedType::<U, F>::unpack_scalars(PackedType::<U, F>::from_underliers_ref(underliers))
+}
+
+/// Views a mutable slice of underliers as the scalars of `F` they pack, without copying.
+///
+/// See [`underliers_as_scalars`] for the layout requirements.
+pub fn underliers_as_scalars_mut<U, F>(underliers: &mut [U]) -> &mut [F]
+where
+	U: PackScalar<F, Packed: PackedFieldIndexable>,
+	F: Field,
+{
+	PackedType::<U, F>::unpack_scalars_mut(PackedType::<U, F>::from_underliers_ref_mut(underliers))
+}
+
 /// A trait to convert field to a same bit size packed field with some smaller scalar.
 pub(crate) trait AsPackedField<Scalar: Field>: Field
 where
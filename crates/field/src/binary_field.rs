@@ -85,10 +85,20 @@ pub(super) trait TowerExtensionField:
 /// Macro to generate an implementation of a BinaryField.
 macro_rules! binary_field {
 	($vis:vis $name:ident($typ:ty), $gen:expr) => {
-		#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroable, bytemuck::TransparentWrapper)]
+		#[derive(Default, Clone, Copy, Eq, PartialOrd, Ord, Hash, Zeroable, bytemuck::TransparentWrapper)]
+		#[cfg_attr(not(feature = "const_time"), derive(PartialEq))]
+		#[cfg_attr(feature = "const_time", allow(clippy::derived_hash_with_manual_eq))]
 		#[repr(transparent)]
 		$vis struct $name(pub(crate) $typ);
 
+		#[cfg(feature = "const_time")]
+		impl PartialEq for $name {
+			#[inline]
+			fn eq(&self, other: &Self) -> bool {
+				self.ct_eq(other).into()
+			}
+		}
+
 		impl $name {
 			pub const fn new(value: $typ) -> Self {
 				Self(value)
@@ -874,6 +884,7 @@ pub(crate) mod tests {
 		BinaryField16b as BF16, BinaryField1b as BF1, BinaryField2b as BF2, BinaryField4b as BF4,
 		BinaryField64b as BF64, BinaryField8b as BF8, *,
 	};
+	use crate::arch::ConstantTimeStrategy;
 
 	#[test]
 	fn test_gf2_add() {
@@ -1174,6 +1185,47 @@ pub(crate) mod tests {
 		}
 	}
 
+	fn check_constant_time_arithmetic<F>(a: F, b: F)
+	where
+		F: TowerField
+			+ crate::arithmetic_traits::TaggedMul<ConstantTimeStrategy>
+			+ crate::arithmetic_traits::TaggedSquare<ConstantTimeStrategy>
+			+ crate::arithmetic_traits::TaggedInvertOrZero<ConstantTimeStrategy>,
+	{
+		use crate::arithmetic_traits::{
+			InvertOrZero, Square, TaggedInvertOrZero, TaggedMul, TaggedSquare,
+		};
+
+		assert_eq!(TaggedMul::<ConstantTimeStrategy>::mul(a, b), a * b);
+		assert_eq!(TaggedSquare::<ConstantTimeStrategy>::square(a), Square::square(a));
+		assert_eq!(
+			TaggedInvertOrZero::<ConstantTimeStrategy>::invert_or_zero(a),
+			InvertOrZero::invert_or_zero(a)
+		);
+	}
+
+	proptest! {
+		#[test]
+		fn test_constant_time_arithmetic_4b(a in 0u8..16, b in 0u8..16) {
+			check_constant_time_arithmetic(BF4::from(a), BF4::from(b));
+		}
+
+		#[test]
+		fn test_constant_time_arithmetic_8b(a in any::<u8>(), b in any::<u8>()) {
+			check_constant_time_arithmetic(BF8(a), BF8(b));
+		}
+
+		#[test]
+		fn test_constant_time_arithmetic_32b(a in any::<u32>(), b in any::<u32>()) {
+			check_constant_time_arithmetic(BinaryField32b(a), BinaryField32b(b));
+		}
+
+		#[test]
+		fn test_constant_time_arithmetic_128b(a in any::<u128>(), b in any::<u128>()) {
+			check_constant_time_arithmetic(BinaryField128b(a), BinaryField128b(b));
+		}
+	}
+
 	fn test_mul_primitive<F: TowerField>(val: F, iota: usize) {
 		let result = val.mul_primitive(iota);
 		let expected = <F as ExtensionField<BinaryField1b>>::basis(1 << iota).map(|b| val * b);
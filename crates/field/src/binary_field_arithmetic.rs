@@ -1,7 +1,15 @@
 // Copyright 2023-2025 Irreducible Inc.
 
+use cfg_if::cfg_if;
+
 use super::{arithmetic_traits::InvertOrZero, binary_field::*};
-use crate::{arithmetic_traits::MulAlpha, PackedField};
+#[cfg(feature = "const_time")]
+use crate::arithmetic_traits::impl_invert_with;
+use crate::{
+	arch::ConstantTimeStrategy,
+	arithmetic_traits::{MulAlpha, TaggedInvertOrZero, TaggedMul, TaggedSquare},
+	Field, PackedField,
+};
 
 pub(crate) trait TowerFieldArithmetic: TowerField {
 	fn multiply(self, rhs: Self) -> Self;
@@ -24,6 +32,9 @@ macro_rules! impl_arithmetic_using_packed {
 			}
 		}
 
+		$crate::binary_field_arithmetic::impl_arithmetic_using_packed!(@no_invert $name);
+	};
+	(@no_invert $name:ident) => {
 		impl TowerFieldArithmetic for $name {
 			#[inline]
 			fn multiply(self, rhs: Self) -> Self {
@@ -91,13 +102,152 @@ impl TowerFieldArithmetic for BinaryField1b {
 	}
 }
 
-impl_arithmetic_using_packed!(BinaryField2b);
-impl_arithmetic_using_packed!(BinaryField4b);
-impl_arithmetic_using_packed!(BinaryField8b);
-impl_arithmetic_using_packed!(BinaryField16b);
-impl_arithmetic_using_packed!(BinaryField32b);
-impl_arithmetic_using_packed!(BinaryField64b);
-impl_arithmetic_using_packed!(BinaryField128b);
+cfg_if! {
+	if #[cfg(feature = "const_time")] {
+		impl_arithmetic_using_packed!(@no_invert BinaryField2b);
+		impl_arithmetic_using_packed!(@no_invert BinaryField4b);
+		impl_arithmetic_using_packed!(@no_invert BinaryField8b);
+		impl_arithmetic_using_packed!(@no_invert BinaryField16b);
+		impl_arithmetic_using_packed!(@no_invert BinaryField32b);
+		impl_arithmetic_using_packed!(@no_invert BinaryField64b);
+		impl_arithmetic_using_packed!(@no_invert BinaryField128b);
+
+		impl_invert_with!(BinaryField2b @ ConstantTimeStrategy);
+		impl_invert_with!(BinaryField4b @ ConstantTimeStrategy);
+		impl_invert_with!(BinaryField8b @ ConstantTimeStrategy);
+		impl_invert_with!(BinaryField16b @ ConstantTimeStrategy);
+		impl_invert_with!(BinaryField32b @ ConstantTimeStrategy);
+		impl_invert_with!(BinaryField64b @ ConstantTimeStrategy);
+		impl_invert_with!(BinaryField128b @ ConstantTimeStrategy);
+	} else {
+		impl_arithmetic_using_packed!(BinaryField2b);
+		impl_arithmetic_using_packed!(BinaryField4b);
+		impl_arithmetic_using_packed!(BinaryField8b);
+		impl_arithmetic_using_packed!(BinaryField16b);
+		impl_arithmetic_using_packed!(BinaryField32b);
+		impl_arithmetic_using_packed!(BinaryField64b);
+		impl_arithmetic_using_packed!(BinaryField128b);
+	}
+}
+
+/// Arithmetic of the canonical tower fields using only bit operations on the underliers, so that
+/// its running time does not depend on the values.
+///
+/// An element of $T_{\iota + 1}$ is a pair $(a_0, a_1)$ of elements of $T_{\iota}$ standing for
+/// $a_0 + a_1 X_{\iota}$, where $X_{\iota}^2 = X_{\iota - 1} X_{\iota} + 1$. Products are computed
+/// recursively with Karatsuba multiplication down to $T_0$, where they are bitwise ANDs. This is
+/// much slower than the table and SIMD based arithmetic used otherwise.
+trait ConstantTimeArithmetic: Sized {
+	fn mul_ct(self, rhs: Self) -> Self;
+
+	fn square_ct(self) -> Self;
+
+	/// Multiplies by the generator $X_{\iota - 1}$ of the field over its direct subfield.
+	fn mul_alpha_ct(self) -> Self;
+}
+
+impl ConstantTimeArithmetic for BinaryField1b {
+	#[inline]
+	fn mul_ct(self, rhs: Self) -> Self {
+		Self(self.0 & rhs.0)
+	}
+
+	#[inline]
+	fn square_ct(self) -> Self {
+		self
+	}
+
+	#[inline]
+	fn mul_alpha_ct(self) -> Self {
+		self
+	}
+}
+
+macro_rules! impl_constant_time_arithmetic {
+	($name:ident, $subfield:ident) => {
+		impl ConstantTimeArithmetic for $name {
+			#[inline]
+			fn mul_ct(self, rhs: Self) -> Self {
+				let (a0, a1): ($subfield, $subfield) = self.into();
+				let (b0, b1): ($subfield, $subfield) = rhs.into();
+				let z0 = a0.mul_ct(b0);
+				let z2 = a1.mul_ct(b1);
+				let z1 = (a0 + a1).mul_ct(b0 + b1) - z0 - z2;
+				(z0 + z2, z1 + z2.mul_alpha_ct()).into()
+			}
+
+			#[inline]
+			fn square_ct(self) -> Self {
+				let (a0, a1): ($subfield, $subfield) = self.into();
+				let (z0, z2) = (a0.square_ct(), a1.square_ct());
+				(z0 + z2, z2.mul_alpha_ct()).into()
+			}
+
+			#[inline]
+			fn mul_alpha_ct(self) -> Self {
+				let (a0, a1): ($subfield, $subfield) = self.into();
+				(a1, a0 + a1.mul_alpha_ct()).into()
+			}
+		}
+
+		impl TaggedMul<ConstantTimeStrategy> for $name {
+			#[inline]
+			fn mul(self, rhs: Self) -> Self {
+				self.mul_ct(rhs)
+			}
+		}
+
+		impl TaggedSquare<ConstantTimeStrategy> for $name {
+			#[inline]
+			fn square(self) -> Self {
+				self.square_ct()
+			}
+		}
+
+		impl TaggedInvertOrZero<ConstantTimeStrategy> for $name {
+			/// Computes $x^{2^n - 2} = \prod_{i=1}^{n-1} x^{2^i}$, which is zero for $x = 0$.
+			#[inline]
+			fn invert_or_zero(self) -> Self {
+				let mut power = self;
+				let mut result = Self::ONE;
+				for _ in 1..Self::N_BITS {
+					power = power.square_ct();
+					result = result.mul_ct(power);
+				}
+				result
+			}
+		}
+	};
+}
+
+impl TaggedMul<ConstantTimeStrategy> for BinaryField1b {
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		self.mul_ct(rhs)
+	}
+}
+
+impl TaggedSquare<ConstantTimeStrategy> for BinaryField1b {
+	#[inline]
+	fn square(self) -> Self {
+		self
+	}
+}
+
+impl TaggedInvertOrZero<ConstantTimeStrategy> for BinaryField1b {
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		self
+	}
+}
+
+impl_constant_time_arithmetic!(BinaryField2b, BinaryField1b);
+impl_constant_time_arithmetic!(BinaryField4b, BinaryField2b);
+impl_constant_time_arithmetic!(BinaryField8b, BinaryField4b);
+impl_constant_time_arithmetic!(BinaryField16b, BinaryField8b);
+impl_constant_time_arithmetic!(BinaryField32b, BinaryField16b);
+impl_constant_time_arithmetic!(BinaryField64b, BinaryField32b);
+impl_constant_time_arithmetic!(BinaryField128b, BinaryField64b);
 
 /// For some architectures it may be faster to used SIM versions for packed fields than to use portable
 /// single-element arithmetics. That's why we need these functions
@@ -53,4 +53,4 @@ pub use packed_extension::*;
 pub use packed_extension_ops::*;
 pub use packed_polyval::*;
 pub use polyval::*;
-pub use transpose::{square_transpose, transpose_scalars, Error as TransposeError};
+pub use transpose::{square_transpose, transpose_bits, transpose_scalars, Error as TransposeError};
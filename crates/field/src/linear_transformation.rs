@@ -27,6 +27,12 @@ impl<OF: BinaryField> FieldLinearTransformation<OF, &'static [OF]> {
 
 		Self { bases }
 	}
+
+	/// The bases of a transformation defined in a constant, usable in constant evaluation.
+	#[cfg_attr(not(target_arch = "aarch64"), allow(dead_code))]
+	pub(crate) const fn const_bases(&self) -> &'static [OF] {
+		self.bases
+	}
 }
 
 impl<OF: BinaryField, Data: Deref<Target = [OF]>> FieldLinearTransformation<OF, Data> {
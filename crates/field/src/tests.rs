@@ -156,3 +156,24 @@ generate_spread_tests_small! {
 	spread_equals_basic_spread_128x1, PackedBinaryField128x1b, BinaryField1b, SmallU<1>, 128;
 	spread_equals_basic_spread_64x1, PackedBinaryField64x1b, BinaryField1b, SmallU<1>, 64;
 }
+
+#[test]
+fn test_underliers_as_scalars() {
+	use crate::as_packed_field::{underliers_as_scalars, underliers_as_scalars_mut};
+
+	let mut underliers = [0u128; 2];
+	let words = underliers_as_scalars_mut::<_, BinaryField32b>(&mut underliers);
+	assert_eq!(words.len(), 8);
+	for (i, word) in words.iter_mut().enumerate() {
+		*word = BinaryField32b::new(0x01020304 * i as u32);
+	}
+
+	// The bits of the words, as a 1-bit field packing would store them.
+	assert_eq!(underliers[0] as u32, 0);
+	assert_eq!((underliers[1] >> 32) as u32, 0x01020304 * 5);
+
+	let bytes = underliers_as_scalars::<_, BinaryField8b>(&underliers);
+	assert_eq!(bytes.len(), 32);
+	assert_eq!(bytes[4], BinaryField8b::new(0x04));
+	assert_eq!(bytes[7], BinaryField8b::new(0x01));
+}
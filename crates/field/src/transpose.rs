@@ -1,8 +1,11 @@
 // Copyright 2023-2025 Irreducible Inc.
 
+use std::ops::Range;
+
 use binius_utils::checked_arithmetics::log2_strict_usize;
 
 use super::{packed::PackedField, ExtensionField, PackedFieldIndexable, RepackedExtension};
+use crate::arch::bit_transpose::transpose_16x8;
 
 /// Error thrown when a transpose operation fails.
 #[derive(Clone, thiserror::Error, Debug)]
@@ -117,8 +120,87 @@ where
 	square_transpose(log_d, dst)
 }
 
+/// Transpose a row-major bit matrix into packed columns.
+///
+/// The `src` buffer holds the rows of the matrix, `row_bytes` bytes each: bit `j` of row `i` is
+/// bit `j % 8` of `src[i * row_bytes + j / 8]`. The columns are written to `dst` one after
+/// another, packed the same way: bit `i` of column `j` is bit `i % 8` of
+/// `dst[j * n_rows / 8 + i / 8]`. This is the layout of [`crate::BinaryField1b`] columns with one
+/// element per row, so traces whose rows are bit flags can be copied into witness columns.
+///
+/// The matrix is split recursively until the blocks fit in the cache, whatever its size, and the
+/// blocks are transposed 16 rows by 8 columns at a time with SIMD instructions where available.
+///
+/// # Arguments
+///
+/// * `src`: The rows of the matrix, the number of rows must be a multiple of 8.
+/// * `row_bytes`: The number of bytes of a row, the matrix has `8 * row_bytes` columns.
+/// * `dst`: The columns of the matrix, of the same length as `src`.
+pub fn transpose_bits(src: &[u8], row_bytes: usize, dst: &mut [u8]) -> Result<(), Error> {
+	if row_bytes == 0 || src.len() % (8 * row_bytes) != 0 {
+		return Err(Error::InvalidBufferSize {
+			param: "src",
+			msg: "must hold a multiple of 8 rows of row_bytes bytes".to_string(),
+		});
+	}
+	if dst.len() != src.len() {
+		return Err(Error::InvalidBufferSize {
+			param: "dst",
+			msg: "must have equal length to src buffer".to_string(),
+		});
+	}
+
+	let n_rows = src.len() / row_bytes;
+	let column_bytes = n_rows / 8;
+	for_each_block(0..n_rows, 0..row_bytes, &mut |rows, bytes| {
+		for row in rows.step_by(16) {
+			// A block of 8 rows at the end of the matrix is padded with zeros.
+			let block_rows = (n_rows - row).min(16);
+			for byte in bytes.clone() {
+				let mut block = [0u8; 16];
+				for (i, value) in block.iter_mut().take(block_rows).enumerate() {
+					*value = src[(row + i) * row_bytes + byte];
+				}
+				for (bit, column) in transpose_16x8(block).into_iter().enumerate() {
+					let offset = (8 * byte + bit) * column_bytes + row / 8;
+					let column = column.to_le_bytes();
+					dst[offset..offset + block_rows / 8].copy_from_slice(&column[..block_rows / 8]);
+				}
+			}
+		}
+	});
+
+	Ok(())
+}
+
+/// The number of rows and bytes per row of the largest blocks of a bit matrix transposed directly.
+const BIT_TRANSPOSE_BLOCK_ROWS: usize = 256;
+const BIT_TRANSPOSE_BLOCK_BYTES: usize = 32;
+
+/// Calls `f` on blocks covering a matrix, splitting the longer side in half until the blocks are
+/// small enough. Row ranges are split at multiples of 16 rows.
+fn for_each_block(
+	rows: Range<usize>,
+	bytes: Range<usize>,
+	f: &mut impl FnMut(Range<usize>, Range<usize>),
+) {
+	if rows.len() <= BIT_TRANSPOSE_BLOCK_ROWS && bytes.len() <= BIT_TRANSPOSE_BLOCK_BYTES {
+		f(rows, bytes);
+	} else if rows.len() / BIT_TRANSPOSE_BLOCK_ROWS >= bytes.len() / BIT_TRANSPOSE_BLOCK_BYTES {
+		let mid = rows.start + (rows.len() / 2).next_multiple_of(16);
+		for_each_block(rows.start..mid, bytes.clone(), f);
+		for_each_block(mid..rows.end, bytes, f);
+	} else {
+		let mid = bytes.start + bytes.len() / 2;
+		for_each_block(rows.clone(), bytes.start..mid, f);
+		for_each_block(rows, mid..bytes.end, f);
+	}
+}
+
 #[cfg(test)]
 mod tests {
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
 	use super::*;
 	use crate::{
 		BinaryField32b, PackedBinaryField128x1b, PackedBinaryField16x8b, PackedBinaryField4x32b,
@@ -279,4 +361,38 @@ mod tests {
 		transpose_scalars::<PackedBinaryField16x8b,_,_>(&elems, PackedBinaryField4x32b::cast_bases_mut(&mut dst)).unwrap();
 		assert_eq!(dst, expected);
 	}
+
+	fn transpose_bits_naive(src: &[u8], row_bytes: usize) -> Vec<u8> {
+		let n_rows = src.len() / row_bytes;
+		let mut dst = vec![0u8; src.len()];
+		for i in 0..n_rows {
+			for j in 0..8 * row_bytes {
+				let bit = (src[i * row_bytes + j / 8] >> (j % 8)) & 1;
+				dst[j * n_rows / 8 + i / 8] |= bit << (i % 8);
+			}
+		}
+		dst
+	}
+
+	#[test]
+	fn test_transpose_bits() {
+		let mut rng = StdRng::seed_from_u64(0);
+		// Includes a partial block of 8 rows and matrices split in both directions.
+		for (n_rows, row_bytes) in [(8, 1), (16, 3), (24, 5), (1000, 2), (64, 100), (520, 40)] {
+			let src = (0..n_rows * row_bytes)
+				.map(|_| rng.gen())
+				.collect::<Vec<u8>>();
+			let mut dst = vec![0u8; src.len()];
+			transpose_bits(&src, row_bytes, &mut dst).unwrap();
+			assert_eq!(dst, transpose_bits_naive(&src, row_bytes));
+		}
+	}
+
+	#[test]
+	fn test_transpose_bits_invalid_sizes() {
+		let mut dst = [0u8; 16];
+		assert!(transpose_bits(&[0u8; 12], 3, &mut dst[..12]).is_err());
+		assert!(transpose_bits(&[0u8; 16], 0, &mut dst).is_err());
+		assert!(transpose_bits(&[0u8; 16], 2, &mut dst[..8]).is_err());
+	}
 }
@@ -9,10 +9,11 @@ use binius_field::{ExtensionField, Field, PackedExtension, PackedField, Repacked
 use binius_math::{
 	CompositionPolyOS, MultilinearExtension, MultilinearPoly, MultilinearQuery, MultilinearQueryRef,
 };
-use rayon::iter::FromParallelIterator;
+use binius_ntt::AdditiveNTT;
+use binius_utils::rayon::iter::FromParallelIterator;
 use tracing::instrument;
 
-use crate::{Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+use crate::{Error, RoundEvalStrategy, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
 
 /// HAL-managed memory containing the result of its operations.
 pub trait HalSlice<P: Debug + Send + Sync>:
@@ -49,6 +50,7 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		multilinears: &[SumcheckMultilinear<P, M>],
 		evaluators: &[Evaluator],
 		evaluation_points: &[FDomain],
+		strategy: RoundEvalStrategy,
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
 	where
 		FDomain: Field,
@@ -68,6 +70,7 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		multilinears: &[SumcheckMultilinear<P, M>],
 		evaluators: &[Evaluator],
 		evaluation_points: &[FDomain],
+		strategy: RoundEvalStrategy,
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
 	where
 		FDomain: Field,
@@ -83,6 +86,26 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		multilinear: &impl MultilinearPoly<P>,
 		query_expansion: MultilinearQueryRef<P>,
 	) -> Result<MultilinearExtension<P>, Error>;
+
+	/// Forward additive NTT of a batch of interleaved inputs, see
+	/// [`AdditiveNTT::forward_transform`].
+	fn ntt_forward_transform<P: PackedField>(
+		&self,
+		ntt: &(impl AdditiveNTT<P> + Sync),
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error>;
+
+	/// Inverse additive NTT of a batch of interleaved inputs, see
+	/// [`AdditiveNTT::inverse_transform`].
+	fn ntt_inverse_transform<P: PackedField>(
+		&self,
+		ntt: &(impl AdditiveNTT<P> + Sync),
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error>;
 }
 
 /// Makes it unnecessary to clone backends.
@@ -110,6 +133,7 @@ where
 		multilinears: &[SumcheckMultilinear<P, M>],
 		evaluators: &[Evaluator],
 		evaluation_points: &[FDomain],
+		strategy: RoundEvalStrategy,
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
 	where
 		FDomain: Field,
@@ -127,6 +151,7 @@ where
 			multilinears,
 			evaluators,
 			evaluation_points,
+			strategy,
 		)
 	}
 
@@ -137,6 +162,7 @@ where
 		multilinears: &[SumcheckMultilinear<P, M>],
 		evaluators: &[Evaluator],
 		evaluation_points: &[FDomain],
+		strategy: RoundEvalStrategy,
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
 	where
 		FDomain: Field,
@@ -153,6 +179,7 @@ where
 			multilinears,
 			evaluators,
 			evaluation_points,
+			strategy,
 		)
 	}
 
@@ -163,6 +190,26 @@ where
 	) -> Result<MultilinearExtension<P>, Error> {
 		T::evaluate_partial_high(self, multilinear, query_expansion)
 	}
+
+	fn ntt_forward_transform<P: PackedField>(
+		&self,
+		ntt: &(impl AdditiveNTT<P> + Sync),
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		T::ntt_forward_transform(self, ntt, data, coset, log_batch_size)
+	}
+
+	fn ntt_inverse_transform<P: PackedField>(
+		&self,
+		ntt: &(impl AdditiveNTT<P> + Sync),
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		T::ntt_inverse_transform(self, ntt, data, coset, log_batch_size)
+	}
 }
 
 pub trait ComputationBackendExt: ComputationBackend {
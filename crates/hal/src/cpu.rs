@@ -7,11 +7,13 @@ use binius_math::{
 	eq_ind_partial_eval, CompositionPolyOS, MultilinearExtension, MultilinearPoly,
 	MultilinearQueryRef,
 };
+use binius_ntt::AdditiveNTT;
 use tracing::instrument;
 
 use crate::{
 	sumcheck_round_calculator::{calculate_first_round_evals, calculate_later_round_evals},
-	ComputationBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
+	ComputationBackend, Error, RoundEvalStrategy, RoundEvals, SumcheckEvaluator,
+	SumcheckMultilinear,
 };
 
 /// Implementation of ComputationBackend for the default Backend that uses the CPU for all computations.
@@ -43,6 +45,7 @@ impl ComputationBackend for CpuBackend {
 		multilinears: &[SumcheckMultilinear<P, M>],
 		evaluators: &[Evaluator],
 		evaluation_points: &[FDomain],
+		strategy: RoundEvalStrategy,
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
 	where
 		FDomain: Field,
@@ -54,7 +57,7 @@ impl ComputationBackend for CpuBackend {
 		Evaluator: SumcheckEvaluator<PBase, P, Composition> + Sync,
 		Composition: CompositionPolyOS<P>,
 	{
-		calculate_first_round_evals(n_vars, multilinears, evaluators, evaluation_points)
+		calculate_first_round_evals(n_vars, multilinears, evaluators, evaluation_points, strategy)
 	}
 
 	fn sumcheck_compute_later_round_evals<FDomain, F, P, M, Evaluator, Composition>(
@@ -64,6 +67,7 @@ impl ComputationBackend for CpuBackend {
 		multilinears: &[SumcheckMultilinear<P, M>],
 		evaluators: &[Evaluator],
 		evaluation_points: &[FDomain],
+		strategy: RoundEvalStrategy,
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
 	where
 		FDomain: Field,
@@ -79,6 +83,7 @@ impl ComputationBackend for CpuBackend {
 			multilinears,
 			evaluators,
 			evaluation_points,
+			strategy,
 		)
 	}
 
@@ -90,4 +95,26 @@ impl ComputationBackend for CpuBackend {
 	) -> Result<MultilinearExtension<P>, Error> {
 		Ok(multilinear.evaluate_partial_high(query_expansion)?)
 	}
+
+	#[instrument(skip_all, name = "CpuBackend::ntt_forward_transform", level = "debug")]
+	fn ntt_forward_transform<P: PackedField>(
+		&self,
+		ntt: &(impl AdditiveNTT<P> + Sync),
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		Ok(ntt.forward_transform(data, coset, log_batch_size)?)
+	}
+
+	#[instrument(skip_all, name = "CpuBackend::ntt_inverse_transform", level = "debug")]
+	fn ntt_inverse_transform<P: PackedField>(
+		&self,
+		ntt: &(impl AdditiveNTT<P> + Sync),
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		Ok(ntt.inverse_transform(data, coset, log_batch_size)?)
+	}
 }
@@ -12,5 +12,7 @@ pub enum Error {
 	#[error("the query must have size {expected}")]
 	IncorrectQuerySize { expected: usize },
 	#[error("{0}")]
+	NttError(#[from] binius_ntt::Error),
+	#[error("{0}")]
 	FieldError(#[from] binius_field::Error),
 }
@@ -11,6 +11,7 @@
 mod backend;
 mod cpu;
 mod error;
+mod line_expansion;
 mod sumcheck_evaluator;
 mod sumcheck_multilinear;
 mod sumcheck_round_calculator;
@@ -0,0 +1,276 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Expansion of compositions along lines into univariate polynomials.
+//!
+//! Along the round variable of a sumcheck, the $j$-th multilinear of a subcube row is the line
+//! $a_j + X b_j$, where $a_j$ is its evaluation at zero and $b_j$ its slope. A composition $C$ of
+//! degree $d$ restricted to these lines is a univariate polynomial $C(X)$ of degree at most $d$.
+//! [`LineExpansion`] computes the coefficients of $C(X)$ once per row, which shares the products
+//! of the composition between all evaluation points. Evaluating the coefficients at a point of the
+//! evaluation domain then only takes multiplications by subfield elements.
+//!
+//! Products of several factors are multiplied pairwise, lowest degrees first, so that they form a
+//! balanced tree, and polynomials of equal length are multiplied with Karatsuba's method.
+
+use std::ops::Range;
+
+use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
+use binius_math::ArithExpr;
+use stackalloc::stackalloc_with_default;
+
+/// A step of a [`LineExpansion`], writing polynomial coefficients to a range of the scratch space.
+#[derive(Debug, Clone)]
+enum Step<F> {
+	/// Writes the evaluation at zero and the slope of a multilinear.
+	Line {
+		var: usize,
+		out: usize,
+	},
+	Const {
+		value: F,
+		out: usize,
+	},
+	Add {
+		lhs: Range<usize>,
+		rhs: Range<usize>,
+		out: Range<usize>,
+	},
+	Mul {
+		lhs: Range<usize>,
+		rhs: Range<usize>,
+		out: Range<usize>,
+	},
+}
+
+/// An arithmetic expression compiled into steps that expand it along lines.
+#[derive(Debug, Clone)]
+pub(crate) struct LineExpansion<F> {
+	steps: Vec<Step<F>>,
+	scratch_len: usize,
+	/// The coefficients of the expanded expression in the scratch space, lowest degree first.
+	coeffs: Range<usize>,
+}
+
+impl<F: Field> LineExpansion<F> {
+	pub fn new(expr: &ArithExpr<F>) -> Self {
+		let mut expansion = Self {
+			steps: Vec::new(),
+			scratch_len: 0,
+			coeffs: 0..0,
+		};
+		expansion.coeffs = expansion.compile(expr);
+		expansion
+	}
+
+	/// The number of packed elements of scratch space [`Self::expand`] takes.
+	pub fn scratch_len(&self) -> usize {
+		self.scratch_len
+	}
+
+	/// Expands the expression along the lines returned by `line`, which maps a variable to the
+	/// evaluation at zero and the slope of its multilinear, and returns the coefficients.
+	pub fn expand<'a, P: PackedField<Scalar = F>>(
+		&self,
+		line: impl Fn(usize) -> (P, P),
+		scratch: &'a mut [P],
+	) -> &'a [P] {
+		for step in &self.steps {
+			match step {
+				&Step::Line { var, out } => {
+					(scratch[out], scratch[out + 1]) = line(var);
+				}
+				&Step::Const { value, out } => {
+					scratch[out] = P::broadcast(value);
+				}
+				Step::Add { lhs, rhs, out } => {
+					let (inputs, outputs) = scratch.split_at_mut(out.start);
+					let out = &mut outputs[..out.len()];
+					out.fill(P::zero());
+					for (out, &coeff) in out.iter_mut().zip(&inputs[lhs.clone()]) {
+						*out += coeff;
+					}
+					for (out, &coeff) in out.iter_mut().zip(&inputs[rhs.clone()]) {
+						*out += coeff;
+					}
+				}
+				Step::Mul { lhs, rhs, out } => {
+					let (inputs, outputs) = scratch.split_at_mut(out.start);
+					mul_polys(
+						&inputs[lhs.clone()],
+						&inputs[rhs.clone()],
+						&mut outputs[..out.len()],
+					);
+				}
+			}
+		}
+		&scratch[self.coeffs.clone()]
+	}
+
+	fn alloc(&mut self, len: usize) -> Range<usize> {
+		let start = self.scratch_len;
+		self.scratch_len += len;
+		start..self.scratch_len
+	}
+
+	// Every step writes past the ranges it reads, which `expand` relies on.
+	fn compile(&mut self, expr: &ArithExpr<F>) -> Range<usize> {
+		match expr {
+			ArithExpr::Const(value) => {
+				let out = self.alloc(1);
+				self.steps.push(Step::Const {
+					value: *value,
+					out: out.start,
+				});
+				out
+			}
+			ArithExpr::Var(var) => {
+				let out = self.alloc(2);
+				self.steps.push(Step::Line {
+					var: *var,
+					out: out.start,
+				});
+				out
+			}
+			ArithExpr::Add(lhs, rhs) => {
+				let lhs = self.compile(lhs);
+				let rhs = self.compile(rhs);
+				let out = self.alloc(lhs.len().max(rhs.len()));
+				self.steps.push(Step::Add {
+					lhs,
+					rhs,
+					out: out.clone(),
+				});
+				out
+			}
+			ArithExpr::Mul(..) | ArithExpr::Pow(..) => {
+				let mut factors = Vec::new();
+				self.compile_factors(expr, &mut factors);
+				self.compile_product(factors)
+			}
+		}
+	}
+
+	fn compile_factors(&mut self, expr: &ArithExpr<F>, factors: &mut Vec<Range<usize>>) {
+		match expr {
+			ArithExpr::Mul(lhs, rhs) => {
+				self.compile_factors(lhs, factors);
+				self.compile_factors(rhs, factors);
+			}
+			ArithExpr::Pow(base, exp) => {
+				let mut base = self.compile(base);
+				let mut exp = *exp;
+				while exp > 0 {
+					if exp & 1 == 1 {
+						factors.push(base.clone());
+					}
+					exp >>= 1;
+					if exp > 0 {
+						base = self.compile_mul(base.clone(), base);
+					}
+				}
+			}
+			_ => factors.push(self.compile(expr)),
+		}
+	}
+
+	fn compile_product(&mut self, mut factors: Vec<Range<usize>>) -> Range<usize> {
+		if factors.is_empty() {
+			return self.compile(&ArithExpr::Const(F::ONE));
+		}
+		while factors.len() > 1 {
+			factors.sort_by_key(|factor| factor.len());
+			let lhs = factors.remove(0);
+			let rhs = factors.remove(0);
+			let product = self.compile_mul(lhs, rhs);
+			factors.push(product);
+		}
+		factors.pop().expect("factors is not empty")
+	}
+
+	fn compile_mul(&mut self, lhs: Range<usize>, rhs: Range<usize>) -> Range<usize> {
+		let out = self.alloc(lhs.len() + rhs.len() - 1);
+		self.steps.push(Step::Mul {
+			lhs,
+			rhs,
+			out: out.clone(),
+		});
+		out
+	}
+}
+
+/// Multiplies the polynomials with coefficients `lhs` and `rhs` into `out`.
+///
+/// Polynomials of equal length are split into halves and multiplied with three half-length
+/// products instead of four.
+fn mul_polys<P: PackedField>(lhs: &[P], rhs: &[P], out: &mut [P]) {
+	debug_assert_eq!(out.len(), lhs.len() + rhs.len() - 1);
+
+	let n = lhs.len();
+	if n != rhs.len() || n < 2 {
+		out.fill(P::zero());
+		for (i, &lhs_coeff) in lhs.iter().enumerate() {
+			for (out, &rhs_coeff) in out[i..].iter_mut().zip(rhs) {
+				*out += lhs_coeff * rhs_coeff;
+			}
+		}
+		return;
+	}
+
+	// With lhs = lo + X^h hi and likewise for rhs, the product is
+	// lo lo' + X^h ((lo + hi)(lo' + hi') - lo lo' - hi hi') + X^2h hi hi'.
+	let (h, m) = (n / 2, n - n / 2);
+	let (lhs_lo, lhs_hi) = lhs.split_at(h);
+	let (rhs_lo, rhs_hi) = rhs.split_at(h);
+	{
+		let (out_lo, out_hi) = out.split_at_mut(2 * h);
+		mul_polys(lhs_lo, rhs_lo, &mut out_lo[..2 * h - 1]);
+		out_lo[2 * h - 1] = P::zero();
+		mul_polys(lhs_hi, rhs_hi, out_hi);
+	}
+
+	stackalloc_with_default(4 * m - 1, |buffer: &mut [P]| {
+		let (sums, mid) = buffer.split_at_mut(2 * m);
+		let (lhs_sum, rhs_sum) = sums.split_at_mut(m);
+		lhs_sum.copy_from_slice(lhs_hi);
+		for (sum, &coeff) in lhs_sum.iter_mut().zip(lhs_lo) {
+			*sum += coeff;
+		}
+		rhs_sum.copy_from_slice(rhs_hi);
+		for (sum, &coeff) in rhs_sum.iter_mut().zip(rhs_lo) {
+			*sum += coeff;
+		}
+		mul_polys(lhs_sum, rhs_sum, mid);
+
+		for (mid, &coeff) in mid.iter_mut().zip(&out[..2 * h - 1]) {
+			*mid -= coeff;
+		}
+		for (mid, &coeff) in mid.iter_mut().zip(&out[2 * h..]) {
+			*mid -= coeff;
+		}
+		for (out, &coeff) in out[h..].iter_mut().zip(mid.iter()) {
+			*out += coeff;
+		}
+	});
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `point`, broadcast to `point_broadcast`.
+pub(crate) fn evaluate_at_point<FDomain, P>(
+	coeffs: &[P],
+	point: FDomain,
+	point_broadcast: P::PackedSubfield,
+) -> P
+where
+	FDomain: Field,
+	P: PackedExtension<FDomain, Scalar: ExtensionField<FDomain>>,
+{
+	if point == FDomain::ZERO {
+		coeffs.first().copied().unwrap_or_else(P::zero)
+	} else if point == FDomain::ONE {
+		coeffs.iter().copied().sum()
+	} else {
+		coeffs
+			.iter()
+			.rev()
+			.fold(P::zero(), |acc, &coeff| P::cast_ext(P::cast_base(acc) * point_broadcast) + coeff)
+	}
+}
@@ -3,12 +3,65 @@
 use std::ops::Range;
 
 use binius_field::{Field, PackedField};
-use binius_math::CompositionPolyOS;
+use binius_math::{ArithExpr, CompositionPolyOS};
 
 /// Evaluations of a polynomial at a set of evaluation points.
 #[derive(Debug, Clone)]
 pub struct RoundEvals<F: Field>(pub Vec<F>);
 
+/// The method a sumcheck prover uses to compute the evaluations of its round polynomials.
+///
+/// All strategies compute the same round polynomials and hence produce identical proofs; they
+/// only trade off prover work differently. Factoring out the equality indicator saves an
+/// evaluation point, which matters most for low-degree compositions over many rows, whereas
+/// high-degree compositions over few rows spend most of their time in the composition itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RoundEvalStrategy {
+	/// Extrapolates every multilinear to every evaluation point independently, and evaluates
+	/// zerocheck compositions multiplied with the equality indicator as an additional multilinear.
+	Naive,
+	/// Computes the slope of every multilinear along the round variable once per subcube and
+	/// reuses it for all evaluation points, otherwise proceeding like [`Self::Naive`].
+	///
+	/// Each extrapolation then costs one multiplication by the evaluation point and one addition.
+	/// The compositions are still evaluated at every point.
+	SlopeReuse,
+	/// Reuses slopes like [`Self::SlopeReuse`], and shares the products of compositions of degree
+	/// two and above between evaluation points.
+	///
+	/// Along the round variable every row of a subcube is a line, so the composition restricted to
+	/// it is a univariate polynomial. Its coefficients are computed once per row, multiplying the
+	/// factors of every product in a balanced tree with Karatsuba's method, and then evaluated at
+	/// each point with multiplications by subfield elements only.
+	Karatsuba,
+	/// Reuses slopes like [`Self::SlopeReuse`], and additionally factors the equality indicator of
+	/// the round variable out of zerocheck compositions, lowering the degree of the round
+	/// polynomial computed in the hot loop by one. See [Gruen24] Section 3.2.
+	///
+	/// [Gruen24]: <https://eprint.iacr.org/2024/108>
+	#[default]
+	Gruen,
+}
+
+impl RoundEvalStrategy {
+	/// Whether multilinear slopes along the round variable are shared between evaluation points.
+	pub fn reuses_slopes(self) -> bool {
+		!matches!(self, Self::Naive)
+	}
+
+	/// Whether compositions are expanded along the round variable to share their products between
+	/// evaluation points.
+	pub fn shares_products(self) -> bool {
+		matches!(self, Self::Karatsuba)
+	}
+
+	/// Whether the equality indicator of the round variable is factored out of zerocheck
+	/// compositions.
+	pub fn factors_eq_ind(self) -> bool {
+		matches!(self, Self::Gruen)
+	}
+}
+
 pub trait SumcheckEvaluator<PBase: PackedField, P: PackedField, Composition: CompositionPolyOS<P>> {
 	/// The range of eval point indices over which composition evaluation and summation should happen.
 	/// Returned range must equal the result of `n_round_evals()` in length.
@@ -27,6 +80,23 @@ pub trait SumcheckEvaluator<PBase: PackedField, P: PackedField, Composition: Com
 		batch_query: &[&[PBase]],
 	) -> P;
 
+	/// Accumulates composition evals over a subcube that were computed by the caller.
+	///
+	/// `composition_evals` holds the evaluations of [`Self::base_expression`] at the rows of
+	/// `batch_query`, and the result is the same as that of
+	/// [`Self::process_subcube_at_eval_point`] on the same arguments.
+	fn process_subcube_composition_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		batch_query: &[&[PBase]],
+		composition_evals: &[PBase],
+	) -> P;
+
+	/// Returns the expression of the composition that is evaluated over the rows of a subcube,
+	/// which may be over a subfield of the composition returned by [`Self::composition`].
+	fn base_expression(&self) -> ArithExpr<PBase::Scalar>;
+
 	/// Returns the composition evaluated by this object.
 	fn composition(&self) -> &Composition;
 
@@ -19,4 +19,6 @@ where
 	Folded {
 		large_field_folded_multilinear: MLEDirectAdapter<P>,
 	},
+	/// Large field polynomial that became constant after folding - no longer folded
+	Constant { value: P::Scalar },
 }
@@ -11,12 +11,15 @@ use binius_math::{
 	deinterleave, extrapolate_lines, CompositionPolyOS, MultilinearPoly, MultilinearQuery,
 	MultilinearQueryRef,
 };
+use binius_utils::rayon::prelude::*;
 use bytemuck::zeroed_vec;
 use itertools::izip;
-use rayon::prelude::*;
 use stackalloc::stackalloc_with_iter;
 
-use crate::{Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+use crate::{
+	line_expansion::{evaluate_at_point, LineExpansion},
+	Error, RoundEvalStrategy, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
+};
 
 trait SumcheckMultilinearAccess<P: PackedField> {
 	/// Calculate the evaluations of a sumcheck multilinear over a subcube.
@@ -47,6 +50,7 @@ pub(crate) fn calculate_first_round_evals<FDomain, FBase, F, PBase, P, M, Evalua
 	multilinears: &[SumcheckMultilinear<P, M>],
 	evaluators: &[Evaluator],
 	evaluation_points: &[FDomain],
+	strategy: RoundEvalStrategy,
 ) -> Result<Vec<RoundEvals<F>>, Error>
 where
 	FDomain: Field,
@@ -62,7 +66,7 @@ where
 		.iter()
 		.map(FirstRoundAccess::new)
 		.collect::<Vec<_>>();
-	calculate_round_evals(n_vars, &accesses, evaluators, evaluation_points)
+	calculate_round_evals(n_vars, &accesses, evaluators, evaluation_points, strategy)
 }
 
 /// Calculate the accumulated evaluations for an arbitrary sumcheck round.
@@ -75,6 +79,7 @@ pub(crate) fn calculate_later_round_evals<FDomain, F, P, M, Evaluator, Compositi
 	multilinears: &[SumcheckMultilinear<P, M>],
 	evaluators: &[Evaluator],
 	evaluation_points: &[FDomain],
+	strategy: RoundEvalStrategy,
 ) -> Result<Vec<RoundEvals<F>>, Error>
 where
 	FDomain: Field,
@@ -94,7 +99,7 @@ where
 			tensor_query: query,
 		})
 		.collect::<Vec<_>>();
-	calculate_round_evals(n_vars, &accesses, evaluators, evaluation_points)
+	calculate_round_evals(n_vars, &accesses, evaluators, evaluation_points, strategy)
 }
 
 fn calculate_round_evals<FDomain, FBase, F, PBase, P, Evaluator, Access, Composition>(
@@ -102,6 +107,7 @@ fn calculate_round_evals<FDomain, FBase, F, PBase, P, Evaluator, Access, Composi
 	multilinears: &[Access],
 	evaluators: &[Evaluator],
 	evaluation_points: &[FDomain],
+	strategy: RoundEvalStrategy,
 ) -> Result<Vec<RoundEvals<F>>, Error>
 where
 	FDomain: Field,
@@ -128,15 +134,35 @@ where
 		.map(|evaluator| evaluator.eval_point_indices())
 		.reduce(|range1, range2| range1.start.min(range2.start)..range1.end.max(range2.end))
 		.unwrap_or(0..0);
+	let reuse_slopes =
+		(strategy.reuses_slopes() || strategy.shares_products()) && eval_point_indices.end > 2;
+
+	// Compositions of degree two and above are expanded along the round variable once per row,
+	// instead of being evaluated at every point.
+	let expansions = evaluators
+		.iter()
+		.map(|evaluator| {
+			(reuse_slopes && strategy.shares_products() && evaluator.composition().degree() >= 2)
+				.then(|| LineExpansion::new(&evaluator.base_expression()))
+		})
+		.collect::<Vec<_>>();
+	let eval_point_broadcasts = evaluation_points
+		.iter()
+		.map(|&eval_point| {
+			<PBase as PackedExtension<FDomain>>::PackedSubfield::broadcast(eval_point)
+		})
+		.collect::<Vec<_>>();
 
 	let packed_accumulators = (0..(1 << (n_vars - 1 - subcube_vars)))
 		.into_par_iter()
 		.fold(
-			|| ParFoldStates::new(n_multilinears, n_round_evals.clone(), subcube_vars),
+			|| ParFoldStates::new(n_multilinears, n_round_evals.clone(), &expansions, subcube_vars),
 			|mut par_fold_states, subcube_index| {
 				let ParFoldStates {
 					multilinear_evals,
 					interleaved_evals,
+					expansion_scratch,
+					composition_evals,
 					round_evals,
 				} = &mut par_fold_states;
 
@@ -157,16 +183,64 @@ where
 							evals.evals_1[i] = odd;
 						},
 					);
+
+					if reuse_slopes {
+						for (&eval_0, &eval_1, slope) in izip!(
+							evals.evals_0.as_slice(),
+							evals.evals_1.as_slice(),
+							evals.slopes.as_mut_slice(),
+						) {
+							*slope = eval_1 - eval_0;
+						}
+					}
+				}
+
+				for (expansion, evaluator, composition_evals) in
+					izip!(&expansions, evaluators, composition_evals.iter_mut())
+				{
+					let Some(expansion) = expansion else {
+						continue;
+					};
+					let eval_point_indices = evaluator.eval_point_indices();
+					for row in 0..1 << subcube_vars.saturating_sub(PBase::LOG_WIDTH) {
+						let coeffs = expansion.expand(
+							|var| {
+								let evals = &multilinear_evals[var];
+								(evals.evals_0[row], evals.slopes[row])
+							},
+							expansion_scratch,
+						);
+						for (eval_point_index, evals) in
+							iter::zip(eval_point_indices.clone(), composition_evals.iter_mut())
+						{
+							evals[row] = evaluate_at_point(
+								coeffs,
+								evaluation_points[eval_point_index],
+								eval_point_broadcasts[eval_point_index],
+							);
+						}
+					}
 				}
 
 				// Proceed by evaluation point first to share interpolation work between evaluators.
 				for eval_point_index in eval_point_indices.clone() {
-					let eval_point = evaluation_points[eval_point_index];
-					let eval_point_broadcast =
-						<PBase as PackedExtension<FDomain>>::PackedSubfield::broadcast(eval_point);
+					let eval_point_broadcast = eval_point_broadcasts[eval_point_index];
 
 					// Only points with indices two and above need to be interpolated.
-					if eval_point_index >= 2 {
+					if eval_point_index >= 2 && reuse_slopes {
+						for evals in multilinear_evals.iter_mut() {
+							for (&eval_0, &slope, eval_z) in izip!(
+								evals.evals_0.as_slice(),
+								evals.slopes.as_slice(),
+								evals.evals_z.as_mut_slice(),
+							) {
+								*eval_z = PBase::cast_ext(
+									PBase::cast_base(eval_0)
+										+ PBase::cast_base(slope) * eval_point_broadcast,
+								);
+							}
+						}
+					} else if eval_point_index >= 2 {
 						for evals in multilinear_evals.iter_mut() {
 							for (&eval_0, &eval_1, eval_z) in izip!(
 								evals.evals_0.as_slice(),
@@ -196,20 +270,32 @@ where
 							});
 
 					stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
-						for (evaluator, round_evals) in
-							iter::zip(evaluators, round_evals.iter_mut())
-						{
+						for (evaluator, expansion, composition_evals, round_evals) in izip!(
+							evaluators,
+							&expansions,
+							composition_evals.iter(),
+							round_evals.iter_mut()
+						) {
 							let eval_point_indices = evaluator.eval_point_indices();
 							if !eval_point_indices.contains(&eval_point_index) {
 								continue;
 							}
 
-							round_evals[eval_point_index - eval_point_indices.start] += evaluator
-								.process_subcube_at_eval_point(
+							let point_offset = eval_point_index - eval_point_indices.start;
+							round_evals[point_offset] += if expansion.is_some() {
+								evaluator.process_subcube_composition_evals(
 									subcube_vars,
 									subcube_index,
 									evals_z,
-								);
+									&composition_evals[point_offset],
+								)
+							} else {
+								evaluator.process_subcube_at_eval_point(
+									subcube_vars,
+									subcube_index,
+									evals_z,
+								)
+							};
 						}
 					});
 				}
@@ -258,6 +344,8 @@ struct MultilinearEvals<P: PackedField> {
 	evals_0: Vec<P>,
 	evals_1: Vec<P>,
 	evals_z: Vec<P>,
+	// Differences between the evals at 1 and 0, when reused between evaluation points.
+	slopes: Vec<P>,
 }
 
 impl<P: PackedField> MultilinearEvals<P> {
@@ -267,6 +355,7 @@ impl<P: PackedField> MultilinearEvals<P> {
 			evals_0: zeroed_vec(len),
 			evals_1: zeroed_vec(len),
 			evals_z: zeroed_vec(len),
+			slopes: zeroed_vec(len),
 		}
 	}
 }
@@ -282,6 +371,13 @@ struct ParFoldStates<PBase: PackedField, P: PackedField> {
 	// Scratch space.
 	interleaved_evals: Vec<PBase>,
 
+	// Coefficients of the expanded compositions. Scratch space.
+	expansion_scratch: Vec<PBase>,
+
+	// Evaluations of the expanded compositions over a subcube, per evaluator and evaluation point.
+	// Empty for evaluators whose composition is not expanded. Scratch space.
+	composition_evals: Vec<Vec<Vec<PBase>>>,
+
 	// Accumulated sums of evaluations over univariate domain.
 	//
 	// Each element of the outer vector corresponds to one composite polynomial. Each element of
@@ -292,9 +388,11 @@ struct ParFoldStates<PBase: PackedField, P: PackedField> {
 impl<PBase: PackedField, P: PackedField> ParFoldStates<PBase, P> {
 	fn new(
 		n_multilinears: usize,
-		n_round_evals: impl Iterator<Item = usize>,
+		n_round_evals: impl Iterator<Item = usize> + Clone,
+		expansions: &[Option<LineExpansion<PBase::Scalar>>],
 		subcube_vars: usize,
 	) -> Self {
+		let row_len = 1 << subcube_vars.saturating_sub(PBase::LOG_WIDTH);
 		Self {
 			multilinear_evals: (0..n_multilinears)
 				.map(|_| MultilinearEvals::new(subcube_vars))
@@ -303,6 +401,20 @@ impl<PBase: PackedField, P: PackedField> ParFoldStates<PBase, P> {
 				PBase::default();
 				1 << (subcube_vars + 1).saturating_sub(PBase::LOG_WIDTH)
 			],
+			expansion_scratch: zeroed_vec(
+				expansions
+					.iter()
+					.flatten()
+					.map(LineExpansion::scratch_len)
+					.max()
+					.unwrap_or(0),
+			),
+			composition_evals: iter::zip(n_round_evals.clone(), expansions)
+				.map(|(n_round_evals, expansion)| match expansion {
+					Some(_) => vec![zeroed_vec(row_len); n_round_evals],
+					None => Vec::new(),
+				})
+				.collect(),
 			round_evals: n_round_evals
 				.map(|n_round_evals| zeroed_vec(n_round_evals))
 				.collect(),
@@ -405,6 +517,11 @@ where
 				0,
 				evals,
 			)?),
+
+			SumcheckMultilinear::Constant { value } => {
+				evals.fill(P::broadcast(*value));
+				Ok(())
+			}
 		}
 	}
 }
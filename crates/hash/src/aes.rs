@@ -0,0 +1,316 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! The Haraka-512 v2 compression function, built from AES rounds.
+//!
+//! [Haraka v2] splits the 64-byte input into four 128-bit lanes. Each of its five rounds applies
+//! two keyed AES rounds to every lane and then mixes 32-bit words across lanes, and the input is
+//! fed forward into the final state, which is truncated to 32 bytes. On x86-64 CPUs with AES-NI,
+//! the AES rounds use the hardware instructions, which is detected once when the compression
+//! function is created. Other targets use a portable implementation computing the same function.
+//!
+//! [Haraka v2]: <https://eprint.iacr.org/2016/098>
+
+use std::array;
+
+use sha2::{digest::Output, Sha256};
+
+use crate::{CompressionFunction, PseudoCompressionFunction};
+
+const N_LANES: usize = 4;
+const N_ROUNDS: usize = 5;
+const N_AES_ROUNDS_PER_ROUND: usize = 2;
+const N_ROUND_CONSTANTS: usize = N_ROUNDS * N_AES_ROUNDS_PER_ROUND * N_LANES;
+
+type Block = [u8; 16];
+type State = [Block; N_LANES];
+
+/// The round constants of Haraka v2, as little-endian 128-bit integers.
+const ROUND_CONSTANTS: [u128; N_ROUND_CONSTANTS] = [
+	0x0684704c_e620c00a_b2c5fef0_75817b9d,
+	0x8b66b4e1_88f3a06b_640f6ba4_2f08f717,
+	0x3402de2d_53f28498_cf029d60_9f029114,
+	0x0ed6eae6_2e7b4f08_bbf3bcaf_fd5b4f79,
+	0xcbcfb0cb_4872448b_79eecd1c_be397044,
+	0x7eeacdee_6e9032b7_8d5335ed_2b8a057b,
+	0x67c28f43_5e2e7cd0_e2412761_da4fef1b,
+	0x2924d9b0_afcacc07_675ffde2_1fc70b3b,
+	0xab4d63f1_e6867fe9_ecdb8fca_b9d465ee,
+	0x1c30bf84_d4b7cd64_5b2a404f_ad037e33,
+	0xb2cc0bb9_941723bf_69028b2e_8df69800,
+	0xfa0478a6_de6f5572_4aaa9ec8_5c9d2d8a,
+	0xdfb49f2b_6b772a12_0efa4f2e_29129fd4,
+	0x1ea10344_f449a236_32d611ae_bb6a12ee,
+	0xaf044988_4b050084_5f9600c9_9ca8eca6,
+	0x21025ed8_9d199c4f_78a2c7e3_27e593ec,
+	0xbf3aaaf8_a759c9b7_b9282ecd_82d40173,
+	0x6260700d_6186b017_37f2efd9_10307d6b,
+	0x5aca45c2_21300443_81c29153_f6fc9ac6,
+	0x9223973c_226b68bb_2caf92e8_36d1943a,
+	0xd3bf9238_225886eb_6cbab958_e51071b4,
+	0xdb863ce5_aef0c677_933dfddd_24e1128d,
+	0xbb606268_ffeba09c_83e48de3_cb2212b1,
+	0x734bd3dc_e2e4d19c_2db91a4e_c72bf77d,
+	0x43bb47c3_61301b43_4b1415c4_2cb3924e,
+	0xdba775a8_e707eff6_03b231dd_16eb6899,
+	0x6df3614b_3c755977_8e5e2302_7eca472c,
+	0xcda75a17_d6de7d77_6d1be5b9_b88617f9,
+	0xec6b43f0_6ba8e9aa_9d6c069d_a946ee5d,
+	0xcb1e6950_f957332b_a2531159_3bf327c1,
+	0x2cee0c75_00da619c_e4ed0353_600ed0d9,
+	0xf0b1a5a1_96e90cab_80bbbabc_63a4a350,
+	0xae3db102_5e962988_ab0dde30_938dca39,
+	0x17bb8f38_d554a40b_8814f3a8_2e75b442,
+	0x34bb8a5b_5f427fd7_aeb6b779_360a16f6,
+	0x26f65241_cbe55438_43ce5918_ffbaafde,
+	0x4ce99a54_b9f3026a_a2ca9cf7_839ec978,
+	0xae51a51a_1bdff7be_40c06e28_22901235,
+	0xa0c1613c_ba7ed22b_c173bc0f_48a659cf,
+	0x756acc03_02288288_4ad6bdfd_e9c59da1,
+];
+
+/// The lane and the 32-bit word of the input of the mixing step that every word of its output is
+/// taken from.
+const MIX: [[(usize, usize); 4]; N_LANES] = [
+	[(0, 3), (2, 3), (1, 3), (3, 3)],
+	[(2, 0), (0, 0), (3, 0), (1, 0)],
+	[(2, 1), (0, 1), (3, 1), (1, 1)],
+	[(0, 2), (2, 2), (1, 2), (3, 2)],
+];
+
+/// The Haraka-512 v2 compression function for 32-byte digests, accelerated with AES-NI.
+///
+/// This is a [`PseudoCompressionFunction`] over the same digest type as SHA-256 and Grøstl-256,
+/// so it can replace the compression function of the Merkle tree for either hash.
+#[derive(Debug, Clone)]
+pub struct AesCompression {
+	/// Whether the CPU supports the AES-NI instructions.
+	aes_ni: bool,
+}
+
+impl Default for AesCompression {
+	fn default() -> Self {
+		#[cfg(target_arch = "x86_64")]
+		let aes_ni = std::arch::is_x86_feature_detected!("aes");
+		#[cfg(not(target_arch = "x86_64"))]
+		let aes_ni = false;
+		Self { aes_ni }
+	}
+}
+
+impl AesCompression {
+	fn permute_with(state: &mut State, aes_round: impl Fn(&Block, &Block) -> Block) {
+		let mut round_constants = ROUND_CONSTANTS
+			.iter()
+			.map(|constant| constant.to_le_bytes());
+		for _ in 0..N_ROUNDS {
+			for _ in 0..N_AES_ROUNDS_PER_ROUND {
+				for lane in state.iter_mut() {
+					let round_constant = round_constants.next().expect("enough round constants");
+					*lane = aes_round(lane, &round_constant);
+				}
+			}
+			mix(state);
+		}
+	}
+
+	fn permute(&self, state: &mut State) {
+		#[cfg(target_arch = "x86_64")]
+		if self.aes_ni {
+			// Safety: the AES-NI target feature was detected at runtime.
+			return Self::permute_with(state, |block, key| unsafe {
+				aes_ni::aes_round(block, key)
+			});
+		}
+
+		Self::permute_with(state, portable::aes_round);
+	}
+}
+
+impl PseudoCompressionFunction<Output<Sha256>, 2> for AesCompression {
+	fn compress(&self, input: [Output<Sha256>; 2]) -> Output<Sha256> {
+		let input: State = array::from_fn(|lane| {
+			let digest = &input[lane / 2];
+			let offset = (lane % 2) * 16;
+			array::from_fn(|j| digest[offset + j])
+		});
+
+		let mut state = input;
+		self.permute(&mut state);
+		for (lane, input_lane) in state.iter_mut().zip(&input) {
+			for (byte, input_byte) in lane.iter_mut().zip(input_lane) {
+				*byte ^= input_byte;
+			}
+		}
+
+		// Keep the upper half of the first two lanes and the lower half of the last two.
+		let mut output = Output::<Sha256>::default();
+		output[0..8].copy_from_slice(&state[0][8..16]);
+		output[8..16].copy_from_slice(&state[1][8..16]);
+		output[16..24].copy_from_slice(&state[2][0..8]);
+		output[24..32].copy_from_slice(&state[3][0..8]);
+		output
+	}
+}
+
+impl CompressionFunction<Output<Sha256>, 2> for AesCompression {}
+
+/// The MIX step of Haraka-512 v2, which interleaves the 32-bit words of the lanes.
+fn mix(state: &mut State) {
+	let input = *state;
+	for (output_lane, sources) in state.iter_mut().zip(&MIX) {
+		for (word, &(lane, source_word)) in sources.iter().enumerate() {
+			let source = &input[lane][4 * source_word..4 * source_word + 4];
+			output_lane[4 * word..4 * word + 4].copy_from_slice(source);
+		}
+	}
+}
+
+mod portable {
+	use lazy_static::lazy_static;
+
+	use super::Block;
+
+	lazy_static! {
+		static ref SBOX: [u8; 256] = std::array::from_fn(|x| {
+			// The inverse in GF(2^8) is x^254, followed by the AES affine transformation.
+			let inv = (0..7).fold((x as u8, 1u8), |(square, acc), _| {
+				let square = gf_mul(square, square);
+				(square, gf_mul(acc, square))
+			});
+			let b = inv.1;
+			b ^ b.rotate_left(1) ^ b.rotate_left(2) ^ b.rotate_left(3) ^ b.rotate_left(4) ^ 0x63
+		});
+	}
+
+	fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+		let mut product = 0;
+		while b != 0 {
+			if b & 1 == 1 {
+				product ^= a;
+			}
+			a = xtime(a);
+			b >>= 1;
+		}
+		product
+	}
+
+	fn xtime(a: u8) -> u8 {
+		(a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 }
+	}
+
+	/// One AES encryption round, with the same semantics as the `AESENC` instruction.
+	pub fn aes_round(block: &Block, key: &Block) -> Block {
+		// SubBytes and ShiftRows. The block is in column-major order.
+		let shifted: Block = std::array::from_fn(|i| {
+			let (col, row) = (i / 4, i % 4);
+			SBOX[block[4 * ((col + row) % 4) + row] as usize]
+		});
+
+		// MixColumns and AddRoundKey.
+		std::array::from_fn(|i| {
+			let (col, row) = (i / 4, i % 4);
+			let a = |r: usize| shifted[4 * col + (row + r) % 4];
+			xtime(a(0)) ^ xtime(a(1)) ^ a(1) ^ a(2) ^ a(3) ^ key[i]
+		})
+	}
+
+	#[cfg(test)]
+	pub fn sbox(x: u8) -> u8 {
+		SBOX[x as usize]
+	}
+}
+
+#[cfg(target_arch = "x86_64")]
+mod aes_ni {
+	use std::arch::x86_64::{_mm_aesenc_si128, _mm_loadu_si128, _mm_storeu_si128};
+
+	use super::Block;
+
+	/// One AES encryption round using the `AESENC` instruction.
+	///
+	/// ## Safety
+	///
+	/// The CPU must support the AES-NI target feature.
+	#[target_feature(enable = "aes")]
+	pub unsafe fn aes_round(block: &Block, key: &Block) -> Block {
+		let state = _mm_loadu_si128(block.as_ptr().cast());
+		let key = _mm_loadu_si128(key.as_ptr().cast());
+		let mut output = Block::default();
+		_mm_storeu_si128(output.as_mut_ptr().cast(), _mm_aesenc_si128(state, key));
+		output
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_sbox() {
+		assert_eq!(portable::sbox(0x00), 0x63);
+		assert_eq!(portable::sbox(0x01), 0x7c);
+		assert_eq!(portable::sbox(0x53), 0xed);
+		assert_eq!(portable::sbox(0xff), 0x16);
+	}
+
+	#[test]
+	fn test_portable_aes_round() {
+		// The first round of the FIPS-197 appendix B example.
+		let block = hex_literal::hex!("193de3bea0f4e22b9ac68d2ae9f84808");
+		let key = hex_literal::hex!("a0fafe1788542cb123a339392a6c7605");
+		assert_eq!(
+			portable::aes_round(&block, &key),
+			hex_literal::hex!("a49c7ff2689f352b6b5bea43026a5049")
+		);
+	}
+
+	#[cfg(target_arch = "x86_64")]
+	#[test]
+	fn test_aes_ni_matches_portable() {
+		if !std::arch::is_x86_feature_detected!("aes") {
+			return;
+		}
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let block = rng.gen::<Block>();
+			let key = rng.gen::<Block>();
+			assert_eq!(
+				unsafe { aes_ni::aes_round(&block, &key) },
+				portable::aes_round(&block, &key)
+			);
+		}
+	}
+
+	#[test]
+	fn test_haraka512_known_answer() {
+		// The test vector of the Haraka v2 reference implementation.
+		let input: [u8; 64] = array::from_fn(|i| i as u8);
+		let left = Output::<Sha256>::clone_from_slice(&input[..32]);
+		let right = Output::<Sha256>::clone_from_slice(&input[32..]);
+		let expected =
+			hex_literal::hex!("be7f723b4e80a99813b292287f306f625a6d57331cae5f34dd9277b0945be2aa");
+
+		let portable = AesCompression { aes_ni: false };
+		assert_eq!(portable.compress([left, right]).as_slice(), expected);
+		assert_eq!(AesCompression::default().compress([left, right]).as_slice(), expected);
+	}
+
+	#[test]
+	fn test_compression_depends_on_both_inputs() {
+		let compression = AesCompression::default();
+		let mut rng = StdRng::seed_from_u64(0);
+		let left = Output::<Sha256>::from(rng.gen::<[u8; 32]>());
+		let right = Output::<Sha256>::from(rng.gen::<[u8; 32]>());
+
+		let output = compression.compress([left, right]);
+		assert_eq!(output, compression.compress([left, right]));
+		assert_ne!(output, compression.compress([right, left]));
+
+		let mut flipped = right;
+		flipped[31] ^= 1;
+		assert_ne!(output, compression.compress([left, flipped]));
+	}
+}
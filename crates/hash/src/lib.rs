@@ -10,6 +10,10 @@
 //! This crate also provides an implementation of [Vision Mark-32], a cryptographic sponge function
 //! designed for efficient Binius arithmetization.
 //!
+//! When the hash function does not need to be arithmetized, SHA-256 and the Merkle tree
+//! compressions [`sha2::Sha256Compression`] and [`aes::AesCompression`] use the SHA-NI and AES-NI
+//! instructions where available, which are detected at runtime.
+//!
 //! [Grøstl-256]: <https://www.groestl.info/>
 //! [Vision Mark-32]: <https://eprint.iacr.org/2024/633>
 
@@ -18,6 +22,7 @@
 	feature(stdarch_x86_avx512)
 )]
 
+pub mod aes;
 pub mod compression;
 mod groestl;
 pub mod hasher;
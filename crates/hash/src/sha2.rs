@@ -7,6 +7,9 @@ use sha2::{compress256, digest::Output, Sha256};
 use crate::{CompressionFunction, PseudoCompressionFunction};
 
 /// A two-to-one compression function for SHA-256 digests.
+///
+/// The SHA-256 compression uses the SHA-NI instructions when the CPU supports them, which is
+/// detected at runtime.
 #[derive(Debug, Clone)]
 pub struct Sha256Compression {
 	initial_state: [u32; 8],
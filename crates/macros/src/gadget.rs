@@ -0,0 +1,221 @@
+// Copyright 2025 Irreducible Inc.
+
+use quote::{quote, ToTokens};
+use syn::{
+	parse::{Parse, ParseStream},
+	parse_quote, Token,
+};
+
+use crate::arith_expr::ArithExprItem;
+
+/// The arguments of the `#[gadget(...)]` attribute.
+#[derive(Default)]
+pub(crate) struct GadgetArgs {
+	/// The function filling the witness columns, called with the gadget and the witness builder.
+	fill: Option<syn::Path>,
+}
+
+impl Parse for GadgetArgs {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut args = Self::default();
+		while !input.is_empty() {
+			let key = input.parse::<syn::Ident>()?;
+			input.parse::<Token![=]>()?;
+			match key.to_string().as_str() {
+				"fill" => args.fill = Some(input.parse()?),
+				_ => return Err(syn::Error::new(key.span(), "expected `fill = <path>`")),
+			}
+			if !input.is_empty() {
+				input.parse::<Token![,]>()?;
+			}
+		}
+		Ok(args)
+	}
+}
+
+/// The arguments of a `#[column(Field, log_width = N)]` field attribute.
+struct ColumnArgs {
+	field: syn::Path,
+	log_width: Option<syn::Expr>,
+}
+
+impl Parse for ColumnArgs {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let field = input.parse()?;
+		let mut log_width = None;
+		if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+			let key = input.parse::<syn::Ident>()?;
+			if key != "log_width" {
+				return Err(syn::Error::new(key.span(), "expected `log_width = <expr>`"));
+			}
+			input.parse::<Token![=]>()?;
+			log_width = Some(input.parse()?);
+		}
+		Ok(Self { field, log_width })
+	}
+}
+
+/// The arguments of a `#[constraint("name", Field[vars] = expr)]` struct attribute.
+struct ConstraintArgs {
+	name: syn::LitStr,
+	field: syn::Path,
+	vars: Vec<syn::Ident>,
+	expr: ArithExprItem,
+}
+
+impl Parse for ConstraintArgs {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let name = input.parse()?;
+		input.parse::<Token![,]>()?;
+
+		// Peek at the field and variables, which the expression parser consumes.
+		let fork = input.fork();
+		let field = fork
+			.parse::<syn::Path>()
+			.unwrap_or_else(|_| parse_quote!(binius_field::BinaryField1b));
+		let content;
+		syn::bracketed!(content in fork);
+		let vars = content
+			.parse_terminated(syn::Ident::parse, Token![,])?
+			.into_iter()
+			.collect();
+
+		let expr = input.parse()?;
+		Ok(Self {
+			name,
+			field,
+			vars,
+			expr,
+		})
+	}
+}
+
+pub(crate) fn expand(
+	args: GadgetArgs,
+	mut item: syn::ItemStruct,
+) -> syn::Result<proc_macro2::TokenStream> {
+	let syn::Fields::Named(fields) = &mut item.fields else {
+		return Err(syn::Error::new_spanned(
+			&item,
+			"#[gadget] is only defined for structs with named fields",
+		));
+	};
+	if !item.generics.params.is_empty() {
+		return Err(syn::Error::new_spanned(
+			&item.generics,
+			"#[gadget] is not defined for generic structs",
+		));
+	}
+
+	let mut columns = Vec::new();
+	for field in &mut fields.named {
+		let ident = field.ident.clone().expect("fields are named");
+		let position = field
+			.attrs
+			.iter()
+			.position(|attr| attr.path().is_ident("column"))
+			.ok_or_else(|| {
+				syn::Error::new_spanned(
+					&ident,
+					"every field of a gadget needs a #[column(...)] attribute",
+				)
+			})?;
+		let column = field.attrs.remove(position).parse_args::<ColumnArgs>()?;
+		columns.push((ident, column));
+	}
+
+	let mut constraints = Vec::new();
+	let mut attrs = Vec::new();
+	for attr in item.attrs.drain(..) {
+		if attr.path().is_ident("constraint") {
+			constraints.push(attr.parse_args::<ConstraintArgs>()?);
+		} else {
+			attrs.push(attr);
+		}
+	}
+	item.attrs = attrs;
+
+	for constraint in &constraints {
+		for var in &constraint.vars {
+			if !columns.iter().any(|(ident, _)| ident == var) {
+				return Err(syn::Error::new_spanned(
+					var,
+					"constraint variables must be columns of the gadget",
+				));
+			}
+		}
+	}
+
+	// Every field that columns and constraints are defined over needs a bound.
+	let mut fields_used = Vec::<syn::Path>::new();
+	for field in columns
+		.iter()
+		.map(|(_, column)| &column.field)
+		.chain(constraints.iter().map(|constraint| &constraint.field))
+	{
+		let tokens = field.to_token_stream().to_string();
+		if !fields_used
+			.iter()
+			.any(|used| used.to_token_stream().to_string() == tokens)
+		{
+			fields_used.push(field.clone());
+		}
+	}
+
+	let name = &item.ident;
+	let column_idents = columns.iter().map(|(ident, _)| ident);
+	let column_names = columns.iter().map(|(ident, _)| ident.to_string());
+	let column_fields = columns.iter().map(|(_, column)| &column.field);
+	let column_log_widths = columns.iter().map(|(_, column)| match &column.log_width {
+		Some(log_width) => quote!(#log_width),
+		None => quote!(0),
+	});
+	let fill = args.fill.map(|fill| {
+		quote! {
+			if let Some(witness) = builder.witness() {
+				#fill(&gadget, witness)?;
+			}
+		}
+	});
+	let assertions = constraints.iter().map(
+		|ConstraintArgs {
+		     name, vars, expr, ..
+		 }| {
+			quote! {
+				builder.assert_zero(#name, [#(gadget.#vars),*], (#expr).convert_field());
+			}
+		},
+	);
+
+	Ok(quote! {
+		#item
+
+		impl #name {
+			/// Adds the committed columns of the gadget with `2^log_rows` rows in a new namespace,
+			/// fills their witness and asserts the constraints of the gadget.
+			pub fn build<U, F>(
+				builder: &mut binius_circuits::builder::ConstraintSystemBuilder<U, F>,
+				name: impl ToString,
+				log_rows: usize,
+			) -> Result<Self, anyhow::Error>
+			where
+				U: binius_circuits::trace::TraceUnderlier<F>
+					#(+ binius_field::as_packed_field::PackScalar<#fields_used>)*,
+				F: binius_field::TowerField #(+ binius_field::ExtensionField<#fields_used>)*,
+			{
+				builder.push_namespace(name);
+				let gadget = Self {
+					#(#column_idents: builder.add_committed(
+						#column_names,
+						log_rows + #column_log_widths,
+						<#column_fields as binius_field::TowerField>::TOWER_LEVEL,
+					),)*
+				};
+				#fill
+				#(#assertions)*
+				builder.pop_namespace();
+				Ok(gadget)
+			}
+		}
+	})
+}
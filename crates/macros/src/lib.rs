@@ -4,11 +4,12 @@ extern crate proc_macro;
 mod arith_circuit_poly;
 mod arith_expr;
 mod composition_poly;
+mod gadget;
 
 use std::collections::BTreeSet;
 
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 use crate::{
@@ -198,6 +199,137 @@ pub fn iter_witness_derive(input: TokenStream) -> TokenStream {
 	.into()
 }
 
+/// Defines a gadget from a struct listing its columns and constraints.
+///
+/// Every field of the struct is an `OracleId` of a committed column, annotated with
+/// `#[column(Field)]` or `#[column(Field, log_width = N)]` for columns with `2^N` elements of
+/// `Field` per row. Constraints are listed as `#[constraint("name", [vars] = expr)]` attributes on
+/// the struct, in the syntax of [`arith_expr!`], with the columns as variables. An optional
+/// `fill = path` argument names a function that is called with the gadget and the witness builder
+/// to fill the witness columns.
+///
+/// The macro generates `pub fn build(builder, name, log_rows) -> Result<Self, anyhow::Error>`,
+/// which adds the columns in a namespace of the given name, fills the witness if the builder has
+/// one, and asserts the constraints.
+///
+/// ```ignore
+/// use binius_macros::gadget;
+///
+/// #[gadget(fill = Self::fill)]
+/// #[constraint("and", [x, y, z] = x * y - z)]
+/// struct And {
+///     #[column(BinaryField1b)]
+///     x: OracleId,
+///     #[column(BinaryField1b)]
+///     y: OracleId,
+///     #[column(BinaryField1b)]
+///     z: OracleId,
+/// }
+///
+/// let and = And::build(&mut builder, "and", log_rows)?;
+/// ```
+#[proc_macro_attribute]
+pub fn gadget(attr: TokenStream, item: TokenStream) -> TokenStream {
+	let args = parse_macro_input!(attr as gadget::GadgetArgs);
+	let item = parse_macro_input!(item as syn::ItemStruct);
+	gadget::expand(args, item)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Implements `binius_circuits::trace::TraceAdapter` for a struct of unsigned integers, and
+/// defines a `{Name}Columns` struct with an `OracleId` field for every field of the struct.
+///
+/// Every field must have a type implementing `binius_circuits::trace::TraceValue`.
+///
+/// ```ignore
+/// use binius_macros::TraceColumns;
+///
+/// #[derive(TraceColumns)]
+/// struct AddRow {
+///     x: u32,
+///     y: u32,
+///     z: u32,
+/// }
+///
+/// // Defines `struct AddRowColumns { x: OracleId, y: OracleId, z: OracleId }`.
+/// let columns = AddRow::add_columns(&mut builder, log_rows);
+/// ```
+#[proc_macro_derive(TraceColumns)]
+pub fn trace_columns_derive(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	let Data::Struct(data) = &input.data else {
+		panic!("#[derive(TraceColumns)] is only defined for structs with named fields");
+	};
+	let Fields::Named(fields) = &data.fields else {
+		panic!("#[derive(TraceColumns)] is only defined for structs with named fields");
+	};
+	assert!(
+		input.generics.params.is_empty(),
+		"#[derive(TraceColumns)] is not defined for generic structs"
+	);
+
+	let name = &input.ident;
+	let vis = &input.vis;
+	let columns_name = format_ident!("{}Columns", name);
+	let columns_doc = format!("The oracle IDs of the columns of a trace of [`{name}`] rows.");
+	let field_names = fields
+		.named
+		.iter()
+		.map(|f| f.ident.clone().expect("fields are named"))
+		.collect::<Vec<_>>();
+	let field_types = fields.named.iter().map(|f| &f.ty).collect::<Vec<_>>();
+	let column_names = field_names.iter().map(|f| f.to_string());
+
+	quote! {
+		#[doc = #columns_doc]
+		#[derive(Debug, Clone, Copy)]
+		#vis struct #columns_name {
+			#(pub #field_names: binius_core::oracle::OracleId,)*
+		}
+
+		impl binius_circuits::trace::TraceAdapter for #name {
+			type Columns = #columns_name;
+
+			fn add_columns<U, F>(
+				builder: &mut binius_circuits::builder::ConstraintSystemBuilder<U, F>,
+				log_rows: usize,
+			) -> Self::Columns
+			where
+				U: binius_circuits::trace::TraceUnderlier<F>,
+				F: binius_field::TowerField,
+			{
+				#columns_name {
+					#(#field_names: binius_circuits::trace::add_column::<U, F, #field_types>(
+						builder,
+						#column_names,
+						log_rows,
+					),)*
+				}
+			}
+
+			fn fill_witness<U, F>(
+				rows: &[Self],
+				columns: &Self::Columns,
+				witness: &mut binius_circuits::builder::witness::Builder<U, F>,
+			) -> Result<(), anyhow::Error>
+			where
+				U: binius_circuits::trace::TraceUnderlier<F>,
+				F: binius_field::TowerField,
+			{
+				#(binius_circuits::trace::fill_column(
+					witness,
+					columns.#field_names,
+					rows,
+					|row| row.#field_names,
+				)?;)*
+				Ok(())
+			}
+		}
+	}
+	.into()
+}
+
 /// This will accept the generics definition of a struct (relevant for derive macros),
 /// and return all the generic vars that are constrained by a specific trait identifier.
 /// ```
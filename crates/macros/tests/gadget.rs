@@ -0,0 +1,97 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_circuits::{
+	builder::{witness, ConstraintSystemBuilder},
+	trace::TraceUnderlier,
+};
+use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+use binius_field::{
+	arch::OptimalUnderlier, as_packed_field::PackScalar, BinaryField128b, BinaryField1b,
+	BinaryField8b, ExtensionField, TowerField,
+};
+use binius_macros::gadget;
+
+#[gadget(fill = Self::fill)]
+#[constraint("and", [x, y, z] = x * y - z)]
+struct And {
+	#[column(BinaryField1b, log_width = 5)]
+	x: OracleId,
+	#[column(BinaryField1b, log_width = 5)]
+	y: OracleId,
+	#[column(BinaryField1b, log_width = 5)]
+	z: OracleId,
+}
+
+impl And {
+	fn fill<U, F>(&self, witness: &mut witness::Builder<U, F>) -> Result<(), anyhow::Error>
+	where
+		U: TraceUnderlier<F>,
+		F: TowerField,
+	{
+		let mut x = witness.new_column::<BinaryField1b>(self.x);
+		let mut y = witness.new_column::<BinaryField1b>(self.y);
+		let mut z = witness.new_column::<BinaryField1b>(self.z);
+		let (x, y, z) = (x.as_mut_slice::<u32>(), y.as_mut_slice::<u32>(), z.as_mut_slice::<u32>());
+		for i in 0..x.len() {
+			x[i] = (i as u32).wrapping_mul(0x9e3779b9);
+			y[i] = !(i as u32);
+			z[i] = x[i] & y[i];
+		}
+		Ok(())
+	}
+}
+
+#[gadget(fill = Self::fill)]
+#[constraint("three", BinaryField8b[a] = a - 3)]
+struct Three {
+	#[column(BinaryField8b)]
+	a: OracleId,
+}
+
+impl Three {
+	fn fill<U, F>(&self, witness: &mut witness::Builder<U, F>) -> Result<(), anyhow::Error>
+	where
+		U: TraceUnderlier<F> + PackScalar<BinaryField8b>,
+		F: TowerField + ExtensionField<BinaryField8b>,
+	{
+		let mut a = witness.new_column::<BinaryField8b>(self.a);
+		a.as_mut_slice::<u8>().fill(3);
+		Ok(())
+	}
+}
+
+/// A gadget without a witness, whose columns are filled by the caller.
+#[gadget]
+#[constraint("bit", [b] = b * b - b)]
+struct Bit {
+	#[column(BinaryField1b)]
+	b: OracleId,
+}
+
+#[test]
+fn test_gadget_columns_and_constraints() {
+	let allocator = bumpalo::Bump::new();
+	let mut builder =
+		ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new_with_witness(&allocator);
+
+	let and = And::build(&mut builder, "and", 4).unwrap();
+	assert_eq!(builder.log_rows([and.x, and.y, and.z]).unwrap(), 9);
+	let three = Three::build(&mut builder, "three", 6).unwrap();
+	assert_eq!(builder.log_rows([three.a]).unwrap(), 6);
+
+	let witness = builder.take_witness().unwrap();
+	let constraint_system = builder.build().unwrap();
+	assert_eq!(constraint_system.table_constraints.len(), 2);
+	validate_witness(&constraint_system, &[], &witness).unwrap();
+}
+
+#[test]
+fn test_gadget_without_witness() {
+	let mut builder = ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new();
+	let bit = Bit::build(&mut builder, "bit", 8).unwrap();
+	let constraint_system = builder.build().unwrap();
+	assert_eq!(constraint_system.oracles.n_vars(bit.b), 8);
+	assert_eq!(constraint_system.oracles.label(bit.b), "Committed: bit::b");
+	let constraint = &constraint_system.table_constraints[0].constraints[0];
+	assert_eq!(&*constraint.name, "bit");
+}
@@ -156,6 +156,130 @@ impl<F: Field> ArithExpr<F> {
 			}
 		})
 	}
+
+	/// The number of nodes in the expression tree.
+	pub fn size(&self) -> usize {
+		match self {
+			ArithExpr::Const(_) | ArithExpr::Var(_) => 1,
+			ArithExpr::Add(left, right) | ArithExpr::Mul(left, right) => {
+				1 + left.size() + right.size()
+			}
+			ArithExpr::Pow(base, _) => 1 + base.size(),
+		}
+	}
+
+	/// Returns an equivalent expression with constant sub-expressions folded, nested sums and
+	/// products flattened, and like terms combined.
+	///
+	/// Expressions generated by DSLs often contain redundant structure, such as terms that cancel
+	/// or multiplications by one, which inflates the degree and evaluation cost of a constraint.
+	/// The pass rewrites the expression as a sum of monomials in its non-constant sub-expressions:
+	/// equal factors of a product become powers and terms that differ only by a constant
+	/// coefficient are merged, dropping terms whose coefficients sum to zero, like `x - x`. Sums
+	/// are not distributed over products, so the pass does not expand the expression.
+	pub fn simplify(self) -> Self {
+		match self {
+			ArithExpr::Const(_) | ArithExpr::Var(_) => self,
+			ArithExpr::Add(left, right) => {
+				let mut terms = Vec::new();
+				add_term(&mut terms, left.simplify());
+				add_term(&mut terms, right.simplify());
+				terms
+					.into_iter()
+					.filter(|term| term.coeff != F::ZERO)
+					.map(Monomial::into_expr)
+					.reduce(|acc, term| acc + term)
+					.unwrap_or_else(Self::zero)
+			}
+			ArithExpr::Mul(left, right) => {
+				let mut term = Monomial::from(left.simplify());
+				term.mul(right.simplify(), 1);
+				term.into_expr()
+			}
+			ArithExpr::Pow(base, exp) => {
+				let mut term = Monomial::from(Self::one());
+				term.mul(base.simplify(), exp);
+				term.into_expr()
+			}
+		}
+	}
+}
+
+/// A constant coefficient times a product of powers of distinct non-constant factors.
+struct Monomial<F: Field> {
+	coeff: F,
+	factors: Vec<(ArithExpr<F>, u64)>,
+}
+
+impl<F: Field> From<ArithExpr<F>> for Monomial<F> {
+	fn from(expr: ArithExpr<F>) -> Self {
+		let mut term = Self {
+			coeff: F::ONE,
+			factors: Vec::new(),
+		};
+		term.mul(expr, 1);
+		term
+	}
+}
+
+impl<F: Field> Monomial<F> {
+	/// Multiplies the monomial by `factor^exp`, where `factor` is already simplified.
+	fn mul(&mut self, factor: ArithExpr<F>, exp: u64) {
+		if exp == 0 {
+			return;
+		}
+		match factor {
+			ArithExpr::Const(value) => self.coeff *= value.pow([exp]),
+			ArithExpr::Mul(left, right) => {
+				self.mul(*left, exp);
+				self.mul(*right, exp);
+			}
+			ArithExpr::Pow(base, base_exp) => self.mul(*base, base_exp * exp),
+			factor => match self.factors.iter_mut().find(|(other, _)| *other == factor) {
+				Some((_, other_exp)) => *other_exp += exp,
+				None => self.factors.push((factor, exp)),
+			},
+		}
+	}
+
+	/// Whether the two monomials have the same factors, up to their order.
+	fn same_factors(&self, other: &Self) -> bool {
+		self.factors.len() == other.factors.len()
+			&& self
+				.factors
+				.iter()
+				.all(|factor| other.factors.contains(factor))
+	}
+
+	fn into_expr(self) -> ArithExpr<F> {
+		if self.coeff == F::ZERO {
+			return ArithExpr::zero();
+		}
+		let product = self
+			.factors
+			.into_iter()
+			.map(|(factor, exp)| if exp == 1 { factor } else { factor.pow(exp) })
+			.reduce(|acc, factor| acc * factor);
+		match product {
+			None => ArithExpr::Const(self.coeff),
+			Some(product) if self.coeff == F::ONE => product,
+			Some(product) => ArithExpr::Const(self.coeff) * product,
+		}
+	}
+}
+
+/// Adds a simplified expression to a sum of monomials, merging like terms.
+fn add_term<F: Field>(terms: &mut Vec<Monomial<F>>, expr: ArithExpr<F>) {
+	if let ArithExpr::Add(left, right) = expr {
+		add_term(terms, *left);
+		add_term(terms, *right);
+		return;
+	}
+	let term = Monomial::from(expr);
+	match terms.iter_mut().find(|other| other.same_factors(&term)) {
+		Some(other) => other.coeff += term.coeff,
+		None => terms.push(term),
+	}
 }
 
 impl<F> Default for ArithExpr<F>
@@ -304,4 +428,105 @@ mod tests {
 		.pow(3);
 		assert_eq!(expr.try_convert_field::<BinaryField8b>().unwrap(), expected);
 	}
+
+	#[test]
+	fn test_simplify_folds_constants() {
+		type F = BinaryField8b;
+		let expr = (ArithExpr::Const(F::new(3)) * ArithExpr::Const(F::new(5))
+			+ ArithExpr::Const(F::new(7)))
+		.pow(2);
+		let expected = (F::new(3) * F::new(5) + F::new(7)).pow([2]);
+		assert_eq!(expr.simplify(), ArithExpr::Const(expected));
+
+		let expr = ArithExpr::<F>::Var(0) * ArithExpr::one() + ArithExpr::zero();
+		assert_eq!(expr.simplify(), ArithExpr::Var(0));
+
+		let expr = ArithExpr::Var(0) * ArithExpr::zero() + ArithExpr::Var(1).pow(0);
+		assert_eq!(expr.simplify(), ArithExpr::<F>::one());
+	}
+
+	#[test]
+	fn test_simplify_cancels_terms() {
+		type F = BinaryField8b;
+		let x = || ArithExpr::<F>::Var(0);
+		let y = || ArithExpr::<F>::Var(1);
+
+		assert_eq!((x() - x()).simplify(), ArithExpr::zero());
+
+		// Terms cancel up to the order of the factors of a product.
+		let expr = (x() * y() + y()) - y() * x();
+		assert_eq!(expr.degree(), 2);
+		let simplified = expr.simplify();
+		assert_eq!(simplified, y());
+		assert_eq!(simplified.degree(), 1);
+	}
+
+	#[test]
+	fn test_simplify_flattens_products() {
+		type F = BinaryField8b;
+		let expr = (ArithExpr::Var(0) * ArithExpr::Const(F::new(5)))
+			* (ArithExpr::Var(0).pow(2) * ArithExpr::Const(F::new(7)))
+			+ ArithExpr::Var(1);
+		let coeff = F::new(5) * F::new(7);
+		assert_ne!(coeff, F::ONE);
+		let expected = ArithExpr::Const(coeff) * ArithExpr::Var(0).pow(3) + ArithExpr::Var(1);
+		assert_eq!(expr.simplify(), expected);
+	}
+
+	#[test]
+	fn test_simplify_machine_generated() {
+		type F = BinaryField128b;
+
+		// A bit decomposition check as a DSL would emit it, summing `2^i * b_i - 2^i * b_i` terms
+		// left over from a shifted copy of the decomposition, and multiplying by selector
+		// constants that are fixed to one.
+		let n_bits = 8;
+		let mut expr = ArithExpr::Var(n_bits);
+		let mut coeff = F::ONE;
+		for i in 0..n_bits {
+			let term = ArithExpr::Const(coeff) * ArithExpr::Var(i) * ArithExpr::one();
+			expr = expr + term.clone() + ArithExpr::Var(i) * ArithExpr::Var(i) - term;
+			coeff *= F::new(2);
+		}
+		let expr = expr * (ArithExpr::Const(F::ONE) + ArithExpr::zero());
+
+		let simplified = expr.clone().simplify();
+		assert_eq!(expr.degree(), 2);
+		assert_eq!(simplified.degree(), 2);
+		assert!(simplified.size() < expr.size() / 2);
+
+		let expected = (0..n_bits)
+			.map(|i| ArithExpr::Var(i).pow(2))
+			.fold(ArithExpr::Var(n_bits), |acc, term| acc + term);
+		assert_eq!(simplified, expected);
+
+		// The highest-degree terms cancel entirely.
+		let expr = (0..n_bits).fold(expr, |acc, i| acc - ArithExpr::Var(i).pow(2));
+		assert_eq!(expr.simplify().degree(), 1);
+	}
+
+	#[test]
+	fn test_simplify_preserves_evaluation() {
+		type F = BinaryField8b;
+		let expr = (ArithExpr::Var(0) + ArithExpr::Var(1) * ArithExpr::Const(F::new(9)))
+			* (ArithExpr::Var(1) * ArithExpr::Var(0) - ArithExpr::Var(0) * ArithExpr::Var(1)
+				+ ArithExpr::Var(2))
+			+ (ArithExpr::Var(2) * ArithExpr::Var(2)).pow(3);
+		let simplified = expr.clone().simplify();
+		assert!(simplified.size() < expr.size());
+
+		fn eval(expr: &ArithExpr<F>, vars: &[F]) -> F {
+			match expr {
+				ArithExpr::Const(value) => *value,
+				ArithExpr::Var(index) => vars[*index],
+				ArithExpr::Add(left, right) => eval(left, vars) + eval(right, vars),
+				ArithExpr::Mul(left, right) => eval(left, vars) * eval(right, vars),
+				ArithExpr::Pow(base, exp) => eval(base, vars).pow([*exp]),
+			}
+		}
+		for i in 0..=255u8 {
+			let vars = [F::new(i), F::new(i.wrapping_mul(31)), F::new(i ^ 0x5a)];
+			assert_eq!(eval(&simplified, &vars), eval(&expr, &vars));
+		}
+	}
 }
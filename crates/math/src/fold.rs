@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use core::slice;
-use std::{any::TypeId, cmp::min};
+use std::{any::TypeId, cell::Cell, cmp::min};
 
 use binius_field::{
 	arch::byte_sliced::ByteSlicedAES32x128b,
@@ -11,16 +11,51 @@ use binius_field::{
 	PackedBinaryField256x1b, PackedBinaryField32x1b, PackedBinaryField512x1b,
 	PackedBinaryField64x1b, PackedBinaryField8x1b, PackedField,
 };
-use binius_utils::bail;
+use binius_utils::{
+	bail,
+	rayon::{
+		iter::{IndexedParallelIterator, ParallelIterator},
+		slice::ParallelSliceMut,
+	},
+};
 use bytemuck::Pod;
 use itertools::max;
-use rayon::{
-	iter::{IndexedParallelIterator, ParallelIterator},
-	slice::ParallelSliceMut,
-};
 
 use crate::Error;
 
+/// The default binary logarithm of the number of packed output elements a task folds.
+pub const DEFAULT_FOLD_LOG_CHUNK_LEN: usize = 10;
+
+/// The maximum binary logarithm of the number of packed output elements a task folds.
+pub const MAX_FOLD_LOG_CHUNK_LEN: usize = 30;
+
+thread_local! {
+	static FOLD_LOG_CHUNK_LEN: Cell<usize> = const { Cell::new(DEFAULT_FOLD_LOG_CHUNK_LEN) };
+}
+
+/// Sets the binary logarithm of the number of packed output elements a task folds, for folds
+/// started on the current thread.
+///
+/// Larger chunks keep the memory each thread touches contiguous, which helps on NUMA machines.
+/// The setting is meant to be applied to every thread of a dedicated thread pool when it starts.
+/// Fails if `log_chunk_len` exceeds [`MAX_FOLD_LOG_CHUNK_LEN`].
+pub fn set_fold_log_chunk_len(log_chunk_len: usize) -> Result<(), Error> {
+	if log_chunk_len > MAX_FOLD_LOG_CHUNK_LEN {
+		bail!(Error::ArgumentRangeError {
+			arg: "log_chunk_len".into(),
+			range: 0..MAX_FOLD_LOG_CHUNK_LEN + 1,
+		});
+	}
+	FOLD_LOG_CHUNK_LEN.set(log_chunk_len);
+	Ok(())
+}
+
+/// The binary logarithm of the number of packed output elements a task folds, see
+/// [`set_fold_log_chunk_len`].
+pub fn fold_log_chunk_len() -> usize {
+	FOLD_LOG_CHUNK_LEN.get()
+}
+
 /// Execute the fold operation.
 ///
 /// Every consequent `1 << log_query_size` scalar values are dot-producted with the corresponding
@@ -182,7 +217,7 @@ where
 	}
 	let chunk_size = 1
 		<< max(&[
-			10,
+			fold_log_chunk_len(),
 			(P::LOG_WIDTH + LOG_QUERY_SIZE).saturating_sub(PE::LOG_WIDTH),
 			PE::LOG_WIDTH,
 		])
@@ -254,7 +289,7 @@ where
 	}
 	let chunk_size = 1
 		<< max(&[
-			10,
+			fold_log_chunk_len(),
 			(P::LOG_WIDTH + LOG_QUERY_SIZE).saturating_sub(PE::LOG_WIDTH),
 			PE::LOG_WIDTH,
 		])
@@ -359,14 +394,14 @@ fn fold_fallback<P, PE>(
 	P: PackedField,
 	PE: PackedField<Scalar: ExtensionField<P::Scalar>>,
 {
-	const CHUNK_SIZE: usize = 1 << 10;
+	let chunk_size = 1 << fold_log_chunk_len();
 	let packed_result_evals = out;
 	packed_result_evals
-		.par_chunks_mut(CHUNK_SIZE)
+		.par_chunks_mut(chunk_size)
 		.enumerate()
 		.for_each(|(i, packed_result_evals)| {
 			for (k, packed_result_eval) in packed_result_evals.iter_mut().enumerate() {
-				let offset = i * CHUNK_SIZE;
+				let offset = i * chunk_size;
 				for j in 0..min(PE::WIDTH, 1 << (log_evals_size - log_query_size)) {
 					let index = ((offset + k) << PE::LOG_WIDTH) | j;
 
@@ -393,6 +428,7 @@ fn fold_fallback<P, PE>(
 mod tests {
 	use std::iter::repeat_with;
 
+	use assert_matches::assert_matches;
 	use binius_field::{
 		packed::set_packed_slice, PackedBinaryField16x32b, PackedBinaryField16x8b,
 		PackedBinaryField4x1b, PackedBinaryField512x1b,
@@ -540,4 +576,30 @@ mod tests {
 			);
 		}
 	}
+
+	#[test]
+	fn test_fold_with_small_chunks() {
+		set_fold_log_chunk_len(2).unwrap();
+		let mut rng = StdRng::seed_from_u64(2);
+		let evals = repeat_with(|| PackedBinaryField128x1b::random(&mut rng))
+			.take(1 << 8)
+			.collect::<Vec<_>>();
+		let query = repeat_with(|| PackedBinaryField16x32b::random(&mut rng))
+			.take(1 << 4)
+			.collect::<Vec<_>>();
+
+		for log_query_size in 0..8 {
+			check_fold(&evals, 8 + PackedBinaryField128x1b::LOG_WIDTH, &query, log_query_size);
+		}
+		set_fold_log_chunk_len(DEFAULT_FOLD_LOG_CHUNK_LEN).unwrap();
+	}
+
+	#[test]
+	fn test_fold_log_chunk_len_out_of_range() {
+		assert_matches!(
+			set_fold_log_chunk_len(MAX_FOLD_LOG_CHUNK_LEN + 1),
+			Err(Error::ArgumentRangeError { .. })
+		);
+		assert_eq!(fold_log_chunk_len(), DEFAULT_FOLD_LOG_CHUNK_LEN);
+	}
 }
@@ -34,7 +34,10 @@ pub use binary_subspace::*;
 pub use composition_poly::*;
 pub use deinterleave::*;
 pub use error::*;
-pub use fold::fold;
+pub use fold::{
+	fold, fold_log_chunk_len, set_fold_log_chunk_len, DEFAULT_FOLD_LOG_CHUNK_LEN,
+	MAX_FOLD_LOG_CHUNK_LEN,
+};
 pub use matrix::*;
 pub use mle_adapters::*;
 pub use multilinear::*;
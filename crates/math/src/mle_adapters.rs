@@ -8,8 +8,7 @@ use binius_field::{
 	},
 	ExtensionField, Field, PackedField, RepackedExtension,
 };
-use binius_utils::bail;
-use rayon::prelude::*;
+use binius_utils::{bail, rayon::prelude::*};
 
 use super::{Error, MultilinearExtension, MultilinearPoly, MultilinearQueryRef};
 
@@ -9,9 +9,8 @@ use binius_field::{
 	util::inner_product_par,
 	ExtensionField, Field, PackedField,
 };
-use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
+use binius_utils::{bail, checked_arithmetics::log2_strict_usize, rayon::prelude::*};
 use bytemuck::zeroed_vec;
-use rayon::prelude::*;
 use tracing::instrument;
 
 use crate::{fold, Error, MultilinearQueryRef, PackingDeref};
@@ -33,10 +32,10 @@ pub struct MultilinearExtension<P: PackedField, Data: Deref<Target = [P]> = Vec<
 impl<P: PackedField> MultilinearExtension<P> {
 	pub fn zeros(n_vars: usize) -> Result<Self, Error> {
 		assert!(P::WIDTH.is_power_of_two());
-		if n_vars < log2_strict_usize(P::WIDTH) {
+		if n_vars < log2_strict_usize(P::WIDTH) || n_vars >= usize::BITS as usize {
 			bail!(Error::ArgumentRangeError {
 				arg: "n_vars".to_string(),
-				range: log2_strict_usize(P::WIDTH)..32,
+				range: log2_strict_usize(P::WIDTH)..usize::BITS as usize,
 			});
 		}
 
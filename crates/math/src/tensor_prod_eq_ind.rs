@@ -3,9 +3,8 @@
 use std::cmp::max;
 
 use binius_field::{Field, PackedField};
-use binius_utils::bail;
+use binius_utils::{bail, rayon::prelude::*};
 use bytemuck::zeroed_vec;
-use rayon::prelude::*;
 
 use crate::Error;
 
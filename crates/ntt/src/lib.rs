@@ -4,6 +4,17 @@
 //!
 //! See [LCH14] and [DP24] Section 2.3 for mathematical background.
 //!
+//! The transforms are available to users that build their own encodings. An NTT is constructed
+//! over a domain of `2^log_domain_size` points, for example with [`SingleThreadedNTT::new`] or
+//! [`DynamicDispatchNTT::new`], and transforms inputs of `2^log_n` elements on any of the
+//! `2^(log_domain_size - log_n)` cosets of the domain that have the same size as the input. The
+//! cosets are indexed by the `coset` argument of the [`AdditiveNTT`] methods.
+//!
+//! Batches of inputs are transformed either interleaved element by element, with
+//! [`AdditiveNTT::forward_transform`], or stored row by row, with [`forward_transform_rows`]. The
+//! `binius_hal` backends expose the transforms as well, so that hardware backends can accelerate
+//! them.
+//!
 //! [LCH14]: <https://arxiv.org/abs/1404.3458>
 //! [DP24]: <https://eprint.iacr.org/2024/504>
 
@@ -12,6 +23,7 @@ mod dynamic_dispatch;
 mod error;
 mod multithreaded;
 mod odd_interpolate;
+mod rows;
 mod single_threaded;
 mod strided_array;
 #[cfg(test)]
@@ -23,4 +35,5 @@ pub use dynamic_dispatch::{DynamicDispatchNTT, NTTOptions, ThreadingSettings};
 pub use error::Error;
 pub use multithreaded::MultithreadedNTT;
 pub use odd_interpolate::OddInterpolate;
+pub use rows::{forward_transform_rows, inverse_transform_rows};
 pub use single_threaded::SingleThreadedNTT;
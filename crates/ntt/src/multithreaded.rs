@@ -1,8 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::{BinaryField, PackedField};
-use binius_utils::rayon::get_log_max_threads;
-use rayon::prelude::*;
+use binius_utils::rayon::{get_log_max_threads, prelude::*};
 
 use super::{
 	error::Error,
@@ -0,0 +1,111 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Transforms of many independent rows stored one after the other.
+//!
+//! [`AdditiveNTT::forward_transform`] batches inputs that are interleaved element by element,
+//! which is the layout of Reed–Solomon codewords of extension field elements. Custom encodings
+//! often hold their messages row by row instead, for example the rows of a matrix. The functions
+//! here transform every row of such a buffer in parallel.
+
+use binius_field::PackedField;
+use binius_utils::rayon::prelude::*;
+
+use crate::{AdditiveNTT, Error};
+
+/// Forward transforms every row of `data`, where each row has `2^log_row_len` scalars.
+///
+/// The rows are transformed on the same `coset` of the NTT domain. A row must hold a whole number
+/// of packed elements.
+pub fn forward_transform_rows<P, NTT>(
+	ntt: &NTT,
+	data: &mut [P],
+	log_row_len: usize,
+	coset: u32,
+) -> Result<(), Error>
+where
+	P: PackedField,
+	NTT: AdditiveNTT<P> + Sync,
+{
+	let packed_row_len = check_rows::<P>(data, log_row_len)?;
+	data.par_chunks_exact_mut(packed_row_len)
+		.try_for_each(|row| ntt.forward_transform(row, coset, 0))
+}
+
+/// Inverse transforms every row of `data`, where each row has `2^log_row_len` scalars.
+///
+/// This is the inverse of [`forward_transform_rows`] with the same arguments.
+pub fn inverse_transform_rows<P, NTT>(
+	ntt: &NTT,
+	data: &mut [P],
+	log_row_len: usize,
+	coset: u32,
+) -> Result<(), Error>
+where
+	P: PackedField,
+	NTT: AdditiveNTT<P> + Sync,
+{
+	let packed_row_len = check_rows::<P>(data, log_row_len)?;
+	data.par_chunks_exact_mut(packed_row_len)
+		.try_for_each(|row| ntt.inverse_transform(row, coset, 0))
+}
+
+/// Returns the number of packed elements in a row.
+fn check_rows<P: PackedField>(data: &[P], log_row_len: usize) -> Result<usize, Error> {
+	if log_row_len < P::LOG_WIDTH {
+		return Err(Error::PackingWidthMustDivideDimension);
+	}
+	let packed_row_len = 1 << (log_row_len - P::LOG_WIDTH);
+	if data.len() % packed_row_len != 0 {
+		return Err(Error::IncompleteRow { log_row_len });
+	}
+	Ok(packed_row_len)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b, PackedField};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::SingleThreadedNTT;
+
+	type P = PackedBinaryField4x32b;
+
+	#[test]
+	fn test_rows_match_single_transforms() {
+		let ntt = SingleThreadedNTT::<BinaryField32b>::new(10).unwrap();
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_row_len = 5;
+		let data = (0..8 << (log_row_len - P::LOG_WIDTH))
+			.map(|_| P::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		let mut rows = data.clone();
+		forward_transform_rows(&ntt, &mut rows, log_row_len, 3).unwrap();
+		for (row, transformed) in data
+			.chunks_exact(1 << (log_row_len - P::LOG_WIDTH))
+			.zip(rows.chunks_exact(1 << (log_row_len - P::LOG_WIDTH)))
+		{
+			let mut expected = row.to_vec();
+			ntt.forward_transform(&mut expected, 3, 0).unwrap();
+			assert_eq!(transformed, expected);
+		}
+
+		inverse_transform_rows(&ntt, &mut rows, log_row_len, 3).unwrap();
+		assert_eq!(rows, data);
+	}
+
+	#[test]
+	fn test_rows_reject_partial_rows() {
+		let ntt = SingleThreadedNTT::<BinaryField32b>::new(10).unwrap();
+		let mut data = vec![P::default(); 3];
+		assert!(matches!(
+			forward_transform_rows(&ntt, &mut data, 3, 0),
+			Err(Error::IncompleteRow { log_row_len: 3 })
+		));
+		assert!(matches!(
+			forward_transform_rows(&ntt, &mut data, 1, 0),
+			Err(Error::PackingWidthMustDivideDimension)
+		));
+	}
+}
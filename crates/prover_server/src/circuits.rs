@@ -0,0 +1,175 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, collections::BTreeMap};
+
+use anyhow::{anyhow, Result};
+use binius_circuits::{
+	arithmetic::Flags, builder::ConstraintSystemBuilder, unconstrained::unconstrained,
+};
+use binius_core::{
+	constraint_system::{self, ConstraintSystem, PCSParams, Proof, ProveOptions},
+	fiat_shamir::HasherChallenger,
+	oracle::OracleId,
+	tower::CanonicalTowerFamily,
+	witness::io,
+};
+use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_math::DefaultEvaluationDomainFactory;
+use groestl_crypto::Groestl256;
+
+pub type U = OptimalUnderlier;
+pub type F = BinaryField128b;
+pub type Builder<'a> = ConstraintSystemBuilder<'a, U, F>;
+
+/// Adds a circuit with `2^log_size` rows to a builder, filling the witness if it has one.
+pub type CircuitFn = fn(&mut Builder, usize) -> Result<()>;
+
+/// A registered circuit.
+#[derive(Debug)]
+pub struct Circuit {
+	pub constraint_system: ConstraintSystem<F>,
+	pub pcs_params: PCSParams,
+}
+
+impl Circuit {
+	/// Proves a witness given as an Arrow IPC stream and serializes the proof.
+	pub fn prove(&self, witness: &[u8], options: &ProveOptions) -> Result<Vec<u8>> {
+		let witness = io::read_ipc::<CanonicalTowerFamily, U, _>(witness)?;
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let proof = constraint_system::prove_with_options::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&self.constraint_system,
+			&self.pcs_params,
+			witness,
+			&domain_factory,
+			&backend,
+			options,
+		)?;
+		Ok(proof.to_bytes()?)
+	}
+
+	/// Verifies a proof serialized by [`Circuit::prove`].
+	pub fn verify(&self, proof: Vec<u8>) -> Result<()> {
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&self.constraint_system, &self.pcs_params, vec![], Proof::from_bytes(proof)?)?;
+		Ok(())
+	}
+}
+
+/// The circuits a server proves, by id.
+#[derive(Debug, Default)]
+pub struct CircuitRegistry {
+	circuits: BTreeMap<String, Circuit>,
+}
+
+impl CircuitRegistry {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Registers the constraint system of a circuit under `id`.
+	pub fn register(
+		&mut self,
+		id: impl ToString,
+		circuit: CircuitFn,
+		log_size: usize,
+		pcs_params: PCSParams,
+	) -> Result<()> {
+		let id = id.to_string();
+		if self.circuits.contains_key(&id) {
+			return Err(anyhow!("circuit {id} is already registered"));
+		}
+		let mut builder = Builder::new();
+		circuit(&mut builder, log_size)?;
+		let constraint_system = builder.build()?;
+		self.circuits.insert(
+			id,
+			Circuit {
+				constraint_system,
+				pcs_params,
+			},
+		);
+		Ok(())
+	}
+
+	pub fn get(&self, id: &str) -> Option<&Circuit> {
+		self.circuits.get(id)
+	}
+
+	pub fn ids(&self) -> impl Iterator<Item = &str> {
+		self.circuits.keys().map(String::as_str)
+	}
+}
+
+/// Generates a witness of a circuit and exports it as an Arrow IPC stream, as a client would
+/// before submitting it.
+pub fn witness(circuit: CircuitFn, log_size: usize) -> Result<Vec<u8>> {
+	let allocator = bumpalo::Bump::new();
+	let mut builder = Builder::new_with_witness(&allocator);
+	circuit(&mut builder, log_size)?;
+	let witness = builder.take_witness()?;
+	Ok(io::write_ipc(&witness, Vec::new())?)
+}
+
+/// A 32-bit addition of two unconstrained columns.
+pub fn u32_add(builder: &mut Builder, log_size: usize) -> Result<()> {
+	let in_a = unconstrained::<_, _, BinaryField1b>(builder, "in_a", log_size + 5)?;
+	let in_b = unconstrained::<_, _, BinaryField1b>(builder, "in_b", log_size + 5)?;
+	binius_circuits::arithmetic::u32::add(builder, "sum", in_a, in_b, Flags::Unchecked)?;
+	Ok(())
+}
+
+/// The SHA-256 compression of unconstrained message blocks.
+pub fn sha256(builder: &mut Builder, log_size: usize) -> Result<()> {
+	let input: [OracleId; 16] = array::from_fn(|i| {
+		unconstrained::<_, _, BinaryField1b>(builder, i, log_size + 5)
+			.expect("adding a committed column succeeds")
+	});
+	binius_circuits::sha256::sha256(builder, input, log_size + 5)?;
+	Ok(())
+}
+
+/// The circuits registered by the server binary.
+pub const STANDARD_CIRCUITS: [(&str, CircuitFn); 2] = [("u32_add", u32_add), ("sha256", sha256)];
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_prove_submitted_witness() {
+		let mut registry = CircuitRegistry::new();
+		registry
+			.register("u32_add", u32_add, 8, PCSParams::new(1, 100))
+			.unwrap();
+		assert!(registry
+			.register("u32_add", u32_add, 8, PCSParams::new(1, 100))
+			.is_err());
+		assert_eq!(registry.ids().collect::<Vec<_>>(), ["u32_add"]);
+
+		let circuit = registry.get("u32_add").unwrap();
+		let proof = circuit
+			.prove(&witness(u32_add, 8).unwrap(), &ProveOptions::default())
+			.unwrap();
+		circuit.verify(proof).unwrap();
+
+		assert!(circuit
+			.prove(b"not a witness", &ProveOptions::default())
+			.is_err());
+	}
+}
@@ -0,0 +1,242 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A minimal HTTP/1.1 implementation, sufficient for the API of the server.
+//!
+//! Every connection carries a single request, and the response closes it. Request bodies must
+//! have a `Content-Length`; chunked transfer encoding is not supported.
+
+use std::io::{self, BufRead, Read, Write};
+
+/// The maximum size of the request line and of each header line.
+const MAX_LINE_LEN: usize = 8 * 1024;
+const MAX_HEADERS: usize = 64;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("I/O error: {0}")]
+	Io(#[from] io::Error),
+	#[error("malformed request: {0}")]
+	Malformed(&'static str),
+	#[error("request body of {size} bytes exceeds the limit of {limit} bytes")]
+	BodyTooLarge { size: usize, limit: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+	pub method: String,
+	pub path: String,
+	pub headers: Vec<(String, String)>,
+	pub body: Vec<u8>,
+}
+
+impl Request {
+	/// The value of the first header with the given name, compared case-insensitively.
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(key, _)| key.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// The non-empty segments of the path, without the query string.
+	pub fn segments(&self) -> Vec<&str> {
+		let path = self
+			.path
+			.split_once('?')
+			.map_or(&*self.path, |(path, _)| path);
+		path.split('/')
+			.filter(|segment| !segment.is_empty())
+			.collect()
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+	pub status: u16,
+	pub content_type: &'static str,
+	pub body: Vec<u8>,
+}
+
+impl Response {
+	pub fn json(status: u16, body: serde_json::Value) -> Self {
+		Self {
+			status,
+			content_type: "application/json",
+			body: body.to_string().into_bytes(),
+		}
+	}
+
+	pub fn error(status: u16, message: impl ToString) -> Self {
+		Self::json(status, serde_json::json!({ "error": message.to_string() }))
+	}
+
+	pub fn text(status: u16, body: impl Into<String>) -> Self {
+		Self {
+			status,
+			content_type: "text/plain; charset=utf-8",
+			body: body.into().into_bytes(),
+		}
+	}
+
+	pub fn bytes(body: Vec<u8>) -> Self {
+		Self {
+			status: 200,
+			content_type: "application/octet-stream",
+			body,
+		}
+	}
+}
+
+fn read_line(reader: &mut impl BufRead) -> Result<String, Error> {
+	let mut line = Vec::new();
+	reader
+		.by_ref()
+		.take(MAX_LINE_LEN as u64)
+		.read_until(b'\n', &mut line)?;
+	if line.last() != Some(&b'\n') {
+		return Err(Error::Malformed("line is too long or truncated"));
+	}
+	line.pop();
+	if line.last() == Some(&b'\r') {
+		line.pop();
+	}
+	String::from_utf8(line).map_err(|_| Error::Malformed("line is not UTF-8"))
+}
+
+/// Reads a request, rejecting bodies larger than `max_body` bytes before reading them.
+pub fn read_request(reader: &mut impl BufRead, max_body: usize) -> Result<Request, Error> {
+	let request_line = read_line(reader)?;
+	let mut parts = request_line.split(' ');
+	let (Some(method), Some(path), Some(version), None) =
+		(parts.next(), parts.next(), parts.next(), parts.next())
+	else {
+		return Err(Error::Malformed("invalid request line"));
+	};
+	if !version.starts_with("HTTP/1.") {
+		return Err(Error::Malformed("unsupported HTTP version"));
+	}
+
+	let mut headers = Vec::new();
+	loop {
+		let line = read_line(reader)?;
+		if line.is_empty() {
+			break;
+		}
+		if headers.len() == MAX_HEADERS {
+			return Err(Error::Malformed("too many headers"));
+		}
+		let (name, value) = line
+			.split_once(':')
+			.ok_or(Error::Malformed("invalid header"))?;
+		headers.push((name.trim().to_string(), value.trim().to_string()));
+	}
+
+	let mut request = Request {
+		method: method.to_string(),
+		path: path.to_string(),
+		headers,
+		body: Vec::new(),
+	};
+	if request.header("Transfer-Encoding").is_some() {
+		return Err(Error::Malformed("transfer encodings are not supported"));
+	}
+	let size = match request.header("Content-Length") {
+		Some(value) => value
+			.parse::<usize>()
+			.map_err(|_| Error::Malformed("invalid content length"))?,
+		None => 0,
+	};
+	if size > max_body {
+		return Err(Error::BodyTooLarge {
+			size,
+			limit: max_body,
+		});
+	}
+	// The buffer grows as the body arrives, so a large `Content-Length` does not allocate up front
+	reader
+		.by_ref()
+		.take(size as u64)
+		.read_to_end(&mut request.body)?;
+	if request.body.len() < size {
+		return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+	}
+	Ok(request)
+}
+
+fn reason(status: u16) -> &'static str {
+	match status {
+		200 => "OK",
+		202 => "Accepted",
+		400 => "Bad Request",
+		404 => "Not Found",
+		405 => "Method Not Allowed",
+		409 => "Conflict",
+		413 => "Payload Too Large",
+		429 => "Too Many Requests",
+		503 => "Service Unavailable",
+		_ => "Internal Server Error",
+	}
+}
+
+pub fn write_response(writer: &mut impl Write, response: &Response) -> io::Result<()> {
+	write!(
+		writer,
+		"HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+		response.status,
+		reason(response.status),
+		response.content_type,
+		response.body.len()
+	)?;
+	writer.write_all(&response.body)?;
+	writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+
+	use super::*;
+
+	#[test]
+	fn test_read_request() {
+		let raw = b"POST /v1/circuits/sha256/jobs?wait=0 HTTP/1.1\r\nHost: localhost\r\n\
+			x-tenant: alice\r\nContent-Length: 3\r\n\r\nabcdef";
+		let request = read_request(&mut &raw[..], 1024).unwrap();
+		assert_eq!(request.method, "POST");
+		assert_eq!(request.segments(), ["v1", "circuits", "sha256", "jobs"]);
+		assert_eq!(request.header("X-Tenant"), Some("alice"));
+		assert_eq!(request.header("Accept"), None);
+		assert_eq!(request.body, b"abc");
+	}
+
+	#[test]
+	fn test_read_invalid_request() {
+		assert_matches!(
+			read_request(&mut &b"GET /\r\n\r\n"[..], 0),
+			Err(Error::Malformed("invalid request line"))
+		);
+		assert_matches!(
+			read_request(&mut &b"GET / HTTP/1.1\r\nHost"[..], 0),
+			Err(Error::Malformed(_))
+		);
+		assert_matches!(
+			read_request(&mut &b"POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\nabcde"[..], 4),
+			Err(Error::BodyTooLarge { size: 5, limit: 4 })
+		);
+		assert_matches!(
+			read_request(&mut &b"POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\nabc"[..], 8),
+			Err(Error::Io(_))
+		);
+	}
+
+	#[test]
+	fn test_write_response() {
+		let mut out = Vec::new();
+		write_response(&mut out, &Response::text(404, "no such job")).unwrap();
+		assert_eq!(
+			out,
+			b"HTTP/1.1 404 Not Found\r\nContent-Type: text/plain; charset=utf-8\r\n\
+			Content-Length: 11\r\nConnection: close\r\n\r\nno such job"
+		);
+	}
+}
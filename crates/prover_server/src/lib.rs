@@ -0,0 +1,31 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A reference prover service for Binius circuits.
+//!
+//! The server holds a registry of constraint systems, each under a circuit id, and proves
+//! witnesses submitted over HTTP. Clients build the witness of a registered circuit with the same
+//! builder code, export it as an Arrow IPC stream with [`binius_core::witness::io::write_ipc`] and
+//! submit it as a job. Jobs wait in a bounded queue and are proven by a fixed number of workers, so
+//! the number of concurrent proofs is limited independently of the number of clients. Each tenant,
+//! identified by the `X-Tenant` header, may have a limited number of jobs in flight.
+//!
+//! The API is:
+//!
+//! - `GET /v1/circuits` lists the registered circuit ids.
+//! - `POST /v1/circuits/{id}/jobs` submits the witness in the request body and responds with the
+//!   job id.
+//! - `GET /v1/jobs/{id}` responds with the status of a job.
+//! - `GET /v1/jobs/{id}/proof` responds with the proof of a finished job, serialized with
+//!   [`binius_core::constraint_system::Proof::to_bytes`], and forgets the job.
+//! - `GET /metrics` responds with the queue and prover metrics in the Prometheus text format.
+//! - `GET /healthz` responds with `ok`.
+
+pub mod circuits;
+pub mod http;
+pub mod metrics;
+pub mod queue;
+pub mod server;
+
+pub use circuits::{Circuit, CircuitRegistry};
+pub use queue::{JobId, JobQueue, JobStatus, QueueLimits, SubmitError};
+pub use server::{Server, ServerConfig};
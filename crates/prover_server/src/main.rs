@@ -0,0 +1,91 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Runs a prover server for the standard circuits, see the [`binius_prover_server`] crate
+//! documentation for the API.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use binius_core::constraint_system::{PCSParams, ProofEncoding, ProveOptions};
+use binius_prover_server::{
+	circuits::STANDARD_CIRCUITS, CircuitRegistry, QueueLimits, Server, ServerConfig,
+};
+use binius_utils::rayon::adjust_thread_pool;
+use clap::{value_parser, Parser};
+use tracing_profile::init_tracing;
+
+#[derive(Debug, Parser)]
+struct Args {
+	/// The address to listen on.
+	#[arg(long, default_value = "127.0.0.1:8080")]
+	listen: String,
+	/// The binary logarithm of the number of rows of the registered circuits.
+	#[arg(long, default_value_t = 10, value_parser = value_parser!(u32).range(3..))]
+	log_size: u32,
+	/// The negative binary logarithm of the Reed–Solomon code rate.
+	#[arg(long, default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+	log_inv_rate: u32,
+	/// The target security level in bits.
+	#[arg(long, default_value_t = 100)]
+	security_bits: usize,
+	/// The number of proofs computed at once.
+	#[arg(long, default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+	workers: u32,
+	/// The maximum number of jobs waiting for a worker.
+	#[arg(long, default_value_t = 64)]
+	queue_capacity: usize,
+	/// The maximum number of queued or running jobs of a single tenant.
+	#[arg(long, default_value_t = 8)]
+	tenant_limit: usize,
+	/// How long finished jobs are kept for their proof to be fetched, in seconds.
+	#[arg(long, default_value_t = 600)]
+	retention_secs: u64,
+	/// The maximum size of a submitted witness in MiB.
+	#[arg(long, default_value_t = 64)]
+	max_witness_mib: usize,
+	/// The maximum number of connections served at once.
+	#[arg(long, default_value_t = 64, value_parser = value_parser!(u32).range(1..))]
+	max_connections: u32,
+	/// The timeout of every read from and write to a connection, in seconds.
+	#[arg(long, default_value_t = 30, value_parser = value_parser!(u64).range(1..))]
+	io_timeout_secs: u64,
+	/// Produce compact proofs, see `ProofEncoding::compact`.
+	#[arg(long)]
+	compact: bool,
+}
+
+fn main() -> Result<()> {
+	adjust_thread_pool()
+		.as_ref()
+		.expect("failed to init thread pool");
+
+	let args = Args::parse();
+	let _guard = init_tracing().expect("failed to initialize tracing");
+
+	let pcs_params = PCSParams::new(args.log_inv_rate as usize, args.security_bits);
+	let mut circuits = CircuitRegistry::new();
+	for (id, circuit) in STANDARD_CIRCUITS {
+		circuits.register(id, circuit, args.log_size as usize, pcs_params)?;
+	}
+
+	let mut prove_options = ProveOptions::default();
+	if args.compact {
+		prove_options = prove_options.with_encoding(ProofEncoding::compact());
+	}
+	let config = ServerConfig {
+		workers: args.workers as usize,
+		limits: QueueLimits {
+			capacity: args.queue_capacity,
+			per_tenant: args.tenant_limit,
+			retention: Duration::from_secs(args.retention_secs),
+		},
+		max_witness_size: args.max_witness_mib << 20,
+		max_connections: args.max_connections as usize,
+		io_timeout: Duration::from_secs(args.io_timeout_secs),
+		prove_options,
+	};
+
+	let server = Server::bind(&args.listen, circuits, config)?;
+	tracing::info!("listening on {}", server.local_addr()?);
+	server.run()
+}
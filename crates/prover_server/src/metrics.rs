@@ -0,0 +1,130 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	fmt::Write,
+	sync::atomic::{AtomicU64, Ordering},
+	time::Duration,
+};
+
+/// Counters of the jobs handled by a server, shared by the queue, the workers and the API.
+#[derive(Debug, Default)]
+pub struct Metrics {
+	submitted: AtomicU64,
+	rejected: AtomicU64,
+	completed: AtomicU64,
+	failed: AtomicU64,
+	queued: AtomicU64,
+	running: AtomicU64,
+	prove_nanos: AtomicU64,
+}
+
+impl Metrics {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub(crate) fn job_submitted(&self) {
+		self.submitted.fetch_add(1, Ordering::Relaxed);
+		self.queued.fetch_add(1, Ordering::Relaxed);
+	}
+
+	pub(crate) fn job_rejected(&self) {
+		self.rejected.fetch_add(1, Ordering::Relaxed);
+	}
+
+	pub(crate) fn job_started(&self) {
+		self.queued.fetch_sub(1, Ordering::Relaxed);
+		self.running.fetch_add(1, Ordering::Relaxed);
+	}
+
+	pub(crate) fn job_finished(&self, succeeded: bool, elapsed: Duration) {
+		self.running.fetch_sub(1, Ordering::Relaxed);
+		if succeeded {
+			self.completed.fetch_add(1, Ordering::Relaxed);
+		} else {
+			self.failed.fetch_add(1, Ordering::Relaxed);
+		}
+		self.prove_nanos
+			.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+	}
+
+	/// The number of jobs waiting for a worker.
+	pub fn queued(&self) -> u64 {
+		self.queued.load(Ordering::Relaxed)
+	}
+
+	/// The number of jobs being proven.
+	pub fn running(&self) -> u64 {
+		self.running.load(Ordering::Relaxed)
+	}
+
+	/// Renders the metrics in the Prometheus text exposition format.
+	pub fn render(&self) -> String {
+		let metrics = [
+			(
+				"binius_jobs_submitted_total",
+				"counter",
+				"Jobs accepted into the queue.",
+				self.submitted.load(Ordering::Relaxed) as f64,
+			),
+			(
+				"binius_jobs_rejected_total",
+				"counter",
+				"Jobs rejected because of the queue or tenant limits.",
+				self.rejected.load(Ordering::Relaxed) as f64,
+			),
+			(
+				"binius_jobs_completed_total",
+				"counter",
+				"Jobs that produced a proof.",
+				self.completed.load(Ordering::Relaxed) as f64,
+			),
+			(
+				"binius_jobs_failed_total",
+				"counter",
+				"Jobs that failed to produce a proof.",
+				self.failed.load(Ordering::Relaxed) as f64,
+			),
+			("binius_jobs_queued", "gauge", "Jobs waiting for a worker.", self.queued() as f64),
+			("binius_jobs_running", "gauge", "Jobs being proven.", self.running() as f64),
+			(
+				"binius_prove_seconds_total",
+				"counter",
+				"Time spent proving, summed over the workers.",
+				Duration::from_nanos(self.prove_nanos.load(Ordering::Relaxed)).as_secs_f64(),
+			),
+		];
+
+		let mut out = String::new();
+		for (name, kind, help, value) in metrics {
+			writeln!(out, "# HELP {name} {help}").expect("writing to a string succeeds");
+			writeln!(out, "# TYPE {name} {kind}").expect("writing to a string succeeds");
+			writeln!(out, "{name} {value}").expect("writing to a string succeeds");
+		}
+		out
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_render() {
+		let metrics = Metrics::new();
+		metrics.job_submitted();
+		metrics.job_submitted();
+		metrics.job_rejected();
+		metrics.job_started();
+		metrics.job_finished(true, Duration::from_millis(1500));
+
+		let rendered = metrics.render();
+		assert!(rendered.contains("# TYPE binius_jobs_submitted_total counter\n"));
+		assert!(rendered.contains("binius_jobs_submitted_total 2\n"));
+		assert!(rendered.contains("binius_jobs_rejected_total 1\n"));
+		assert!(rendered.contains("binius_jobs_completed_total 1\n"));
+		assert!(rendered.contains("binius_jobs_queued 1\n"));
+		assert!(rendered.contains("binius_jobs_running 0\n"));
+		assert!(rendered.contains("binius_prove_seconds_total 1.5\n"));
+	}
+}
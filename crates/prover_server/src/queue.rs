@@ -0,0 +1,359 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::{Arc, Condvar, Mutex, MutexGuard},
+	time::{Duration, Instant},
+};
+
+use crate::metrics::Metrics;
+
+pub type JobId = u64;
+
+/// A witness submitted for proving.
+#[derive(Debug)]
+pub struct Job {
+	pub id: JobId,
+	pub tenant: String,
+	pub circuit: String,
+	/// The witness as an Arrow IPC stream.
+	pub witness: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobStatus {
+	Queued,
+	Running,
+	Done,
+	Failed(String),
+}
+
+impl JobStatus {
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::Queued => "queued",
+			Self::Running => "running",
+			Self::Done => "done",
+			Self::Failed(_) => "failed",
+		}
+	}
+
+	/// Whether the job has finished, successfully or not.
+	pub fn is_finished(&self) -> bool {
+		matches!(self, Self::Done | Self::Failed(_))
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueLimits {
+	/// The maximum number of jobs waiting for a worker.
+	pub capacity: usize,
+	/// The maximum number of queued or running jobs of a single tenant.
+	pub per_tenant: usize,
+	/// How long finished jobs are kept for their proof to be fetched.
+	pub retention: Duration,
+}
+
+impl Default for QueueLimits {
+	fn default() -> Self {
+		Self {
+			capacity: 64,
+			per_tenant: 8,
+			retention: Duration::from_secs(600),
+		}
+	}
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum SubmitError {
+	#[error("the job queue is full")]
+	QueueFull,
+	#[error("tenant {0} has too many jobs in flight")]
+	TenantLimit(String),
+	#[error("the job queue is shut down")]
+	ShutDown,
+}
+
+#[derive(Debug)]
+struct Entry {
+	tenant: String,
+	status: JobStatus,
+	proof: Option<Vec<u8>>,
+	finished_at: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+	next_id: JobId,
+	pending: VecDeque<Job>,
+	jobs: HashMap<JobId, Entry>,
+	in_flight: HashMap<String, usize>,
+	shut_down: bool,
+}
+
+/// A bounded first-in, first-out queue of proving jobs, with the status of every job.
+///
+/// The queue only holds the jobs; the workers that prove them call [`JobQueue::next`] and report
+/// the outcome with [`JobQueue::finish`].
+#[derive(Debug)]
+pub struct JobQueue {
+	limits: QueueLimits,
+	metrics: Arc<Metrics>,
+	state: Mutex<State>,
+	ready: Condvar,
+}
+
+impl JobQueue {
+	pub fn new(limits: QueueLimits, metrics: Arc<Metrics>) -> Self {
+		Self {
+			limits,
+			metrics,
+			state: Mutex::new(State::default()),
+			ready: Condvar::new(),
+		}
+	}
+
+	fn state(&self) -> MutexGuard<'_, State> {
+		self.state.lock().expect("job queue lock is not poisoned")
+	}
+
+	/// Adds a job to the queue, unless the queue or the tenant is at its limit.
+	pub fn submit(
+		&self,
+		tenant: &str,
+		circuit: &str,
+		witness: Vec<u8>,
+	) -> Result<JobId, SubmitError> {
+		let mut state = self.state();
+		self.evict_expired(&mut state);
+
+		let in_flight = state.in_flight.get(tenant).copied().unwrap_or(0);
+		let result = if state.shut_down {
+			Err(SubmitError::ShutDown)
+		} else if state.pending.len() >= self.limits.capacity {
+			Err(SubmitError::QueueFull)
+		} else if in_flight >= self.limits.per_tenant {
+			Err(SubmitError::TenantLimit(tenant.to_string()))
+		} else {
+			Ok(())
+		};
+		if let Err(err) = result {
+			self.metrics.job_rejected();
+			return Err(err);
+		}
+
+		let id = state.next_id;
+		state.next_id += 1;
+		*state.in_flight.entry(tenant.to_string()).or_default() += 1;
+		state.jobs.insert(
+			id,
+			Entry {
+				tenant: tenant.to_string(),
+				status: JobStatus::Queued,
+				proof: None,
+				finished_at: None,
+			},
+		);
+		state.pending.push_back(Job {
+			id,
+			tenant: tenant.to_string(),
+			circuit: circuit.to_string(),
+			witness,
+		});
+		self.metrics.job_submitted();
+		self.ready.notify_one();
+		Ok(id)
+	}
+
+	/// The status of a job, or `None` if the job is unknown or was forgotten.
+	pub fn status(&self, id: JobId) -> Option<JobStatus> {
+		self.state().jobs.get(&id).map(|entry| entry.status.clone())
+	}
+
+	/// Takes the proof of a finished job and forgets the job.
+	///
+	/// Returns `None` if the job is unknown, and the status of the job if it has no proof.
+	pub fn take_proof(&self, id: JobId) -> Option<Result<Vec<u8>, JobStatus>> {
+		let mut state = self.state();
+		let entry = state.jobs.get(&id)?;
+		if entry.status != JobStatus::Done {
+			return Some(Err(entry.status.clone()));
+		}
+		let entry = state.jobs.remove(&id).expect("entry exists");
+		Some(Ok(entry.proof.expect("finished jobs have a proof")))
+	}
+
+	/// Waits for the next job and marks it as running, or returns `None` once the queue is shut
+	/// down.
+	pub fn next(&self) -> Option<Job> {
+		let mut state = self.state();
+		loop {
+			if state.shut_down {
+				return None;
+			}
+			if let Some(job) = state.pending.pop_front() {
+				if let Some(entry) = state.jobs.get_mut(&job.id) {
+					entry.status = JobStatus::Running;
+				}
+				self.metrics.job_started();
+				return Some(job);
+			}
+			state = self
+				.ready
+				.wait(state)
+				.expect("job queue lock is not poisoned");
+		}
+	}
+
+	/// Records the outcome of a job returned by [`JobQueue::next`].
+	pub fn finish(&self, id: JobId, result: Result<Vec<u8>, String>, elapsed: Duration) {
+		let mut state = self.state();
+		self.metrics.job_finished(result.is_ok(), elapsed);
+		let Some(entry) = state.jobs.get_mut(&id) else {
+			return;
+		};
+		let tenant = entry.tenant.clone();
+		match result {
+			Ok(proof) => {
+				entry.status = JobStatus::Done;
+				entry.proof = Some(proof);
+			}
+			Err(err) => entry.status = JobStatus::Failed(err),
+		}
+		entry.finished_at = Some(Instant::now());
+		release(&mut state, &tenant);
+	}
+
+	/// Stops handing out jobs and wakes up all waiting workers.
+	///
+	/// Jobs still in the queue are never run.
+	pub fn shut_down(&self) {
+		self.state().shut_down = true;
+		self.ready.notify_all();
+	}
+
+	fn evict_expired(&self, state: &mut State) {
+		let retention = self.limits.retention;
+		state.jobs.retain(|_, entry| {
+			entry
+				.finished_at
+				.is_none_or(|finished_at| finished_at.elapsed() < retention)
+		});
+	}
+}
+
+fn release(state: &mut State, tenant: &str) {
+	if let Some(count) = state.in_flight.get_mut(tenant) {
+		*count -= 1;
+		if *count == 0 {
+			state.in_flight.remove(tenant);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::thread;
+
+	use super::*;
+
+	fn queue(capacity: usize, per_tenant: usize) -> JobQueue {
+		JobQueue::new(
+			QueueLimits {
+				capacity,
+				per_tenant,
+				retention: Duration::from_secs(60),
+			},
+			Arc::new(Metrics::new()),
+		)
+	}
+
+	#[test]
+	fn test_job_lifecycle() {
+		let queue = queue(4, 4);
+		let id = queue.submit("alice", "sha256", vec![1, 2, 3]).unwrap();
+		assert_eq!(queue.status(id), Some(JobStatus::Queued));
+		assert_eq!(queue.take_proof(id), Some(Err(JobStatus::Queued)));
+
+		let job = queue.next().unwrap();
+		assert_eq!(job.id, id);
+		assert_eq!(job.circuit, "sha256");
+		assert_eq!(job.witness, vec![1, 2, 3]);
+		assert_eq!(queue.status(id), Some(JobStatus::Running));
+
+		queue.finish(id, Ok(vec![4, 5]), Duration::ZERO);
+		assert_eq!(queue.status(id), Some(JobStatus::Done));
+		assert_eq!(queue.take_proof(id), Some(Ok(vec![4, 5])));
+		assert_eq!(queue.status(id), None);
+		assert_eq!(queue.take_proof(id), None);
+	}
+
+	#[test]
+	fn test_failed_job() {
+		let queue = queue(4, 4);
+		let id = queue.submit("alice", "sha256", vec![]).unwrap();
+		let job = queue.next().unwrap();
+		queue.finish(job.id, Err("invalid witness".to_string()), Duration::ZERO);
+		let status = JobStatus::Failed("invalid witness".to_string());
+		assert_eq!(queue.status(id), Some(status.clone()));
+		assert_eq!(queue.take_proof(id), Some(Err(status)));
+	}
+
+	#[test]
+	fn test_limits() {
+		let queue = queue(3, 2);
+		let first = queue.submit("alice", "sha256", vec![]).unwrap();
+		queue.submit("alice", "sha256", vec![]).unwrap();
+		assert_eq!(
+			queue.submit("alice", "sha256", vec![]),
+			Err(SubmitError::TenantLimit("alice".to_string()))
+		);
+		queue.submit("bob", "sha256", vec![]).unwrap();
+		assert_eq!(queue.submit("carol", "sha256", vec![]), Err(SubmitError::QueueFull));
+
+		// A running job no longer takes space in the queue, but counts towards the tenant
+		let job = queue.next().unwrap();
+		assert_eq!(job.id, first);
+		assert_eq!(
+			queue.submit("alice", "sha256", vec![]),
+			Err(SubmitError::TenantLimit("alice".to_string()))
+		);
+		queue.submit("carol", "sha256", vec![]).unwrap();
+
+		queue.finish(job.id, Ok(vec![]), Duration::ZERO);
+		assert_eq!(queue.submit("alice", "sha256", vec![]), Err(SubmitError::QueueFull));
+		queue.next().unwrap();
+		queue.submit("alice", "sha256", vec![]).unwrap();
+	}
+
+	#[test]
+	fn test_retention() {
+		let queue = JobQueue::new(
+			QueueLimits {
+				capacity: 4,
+				per_tenant: 4,
+				retention: Duration::ZERO,
+			},
+			Arc::new(Metrics::new()),
+		);
+		let id = queue.submit("alice", "sha256", vec![]).unwrap();
+		queue.next().unwrap();
+		queue.finish(id, Ok(vec![]), Duration::ZERO);
+		assert_eq!(queue.status(id), Some(JobStatus::Done));
+
+		queue.submit("alice", "sha256", vec![]).unwrap();
+		assert_eq!(queue.status(id), None);
+	}
+
+	#[test]
+	fn test_shut_down_wakes_workers() {
+		let queue = Arc::new(queue(4, 4));
+		let worker = thread::spawn({
+			let queue = queue.clone();
+			move || queue.next().map(|job| job.id)
+		});
+		queue.shut_down();
+		assert_eq!(worker.join().unwrap(), None);
+		assert_eq!(queue.submit("alice", "sha256", vec![]), Err(SubmitError::ShutDown));
+	}
+}
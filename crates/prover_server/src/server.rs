@@ -0,0 +1,363 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	io::{BufReader, BufWriter},
+	net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	thread::{self, JoinHandle},
+	time::{Duration, Instant},
+};
+
+use anyhow::{ensure, Result};
+use binius_core::constraint_system::ProveOptions;
+
+use crate::{
+	circuits::CircuitRegistry,
+	http::{self, Request, Response},
+	metrics::Metrics,
+	queue::{JobId, JobQueue, JobStatus, QueueLimits, SubmitError},
+};
+
+/// The tenant of requests without an `X-Tenant` header.
+const DEFAULT_TENANT: &str = "default";
+
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+	/// The number of workers, and so the maximum number of proofs computed at once.
+	pub workers: usize,
+	pub limits: QueueLimits,
+	/// The maximum size of a submitted witness in bytes.
+	pub max_witness_size: usize,
+	/// The maximum number of connections served at once. Further connections are answered with
+	/// 503 and closed.
+	pub max_connections: usize,
+	/// The timeout of every read from and write to a connection.
+	pub io_timeout: Duration,
+	pub prove_options: ProveOptions,
+}
+
+impl Default for ServerConfig {
+	fn default() -> Self {
+		Self {
+			workers: 1,
+			limits: QueueLimits::default(),
+			max_witness_size: 64 << 20,
+			max_connections: 64,
+			io_timeout: Duration::from_secs(30),
+			prove_options: ProveOptions::default(),
+		}
+	}
+}
+
+struct Context {
+	circuits: CircuitRegistry,
+	queue: JobQueue,
+	metrics: Arc<Metrics>,
+	config: ServerConfig,
+	connections: AtomicUsize,
+}
+
+/// One of the [`ServerConfig::max_connections`] connections served at once, released on drop.
+struct Connection(Arc<Context>);
+
+impl Connection {
+	fn acquire(context: &Arc<Context>) -> Option<Self> {
+		context
+			.connections
+			.fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+				(count < context.config.max_connections).then_some(count + 1)
+			})
+			.ok()
+			.map(|_| Self(context.clone()))
+	}
+}
+
+impl Drop for Connection {
+	fn drop(&mut self) {
+		self.0.connections.fetch_sub(1, Ordering::AcqRel);
+	}
+}
+
+/// A prover server listening for requests, with its workers running.
+pub struct Server {
+	listener: TcpListener,
+	context: Arc<Context>,
+	workers: Vec<JoinHandle<()>>,
+}
+
+impl Server {
+	/// Binds to `addr` and starts the workers.
+	pub fn bind(
+		addr: impl ToSocketAddrs,
+		circuits: CircuitRegistry,
+		config: ServerConfig,
+	) -> Result<Self> {
+		ensure!(!config.io_timeout.is_zero(), "the I/O timeout must be positive");
+		let listener = TcpListener::bind(addr)?;
+		let metrics = Arc::new(Metrics::new());
+		let context = Arc::new(Context {
+			circuits,
+			queue: JobQueue::new(config.limits, metrics.clone()),
+			metrics,
+			config,
+			connections: AtomicUsize::new(0),
+		});
+		let workers = (0..context.config.workers.max(1))
+			.map(|index| {
+				let context = context.clone();
+				thread::Builder::new()
+					.name(format!("prover-{index}"))
+					.spawn(move || work(&context))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self {
+			listener,
+			context,
+			workers,
+		})
+	}
+
+	pub fn local_addr(&self) -> Result<SocketAddr> {
+		Ok(self.listener.local_addr()?)
+	}
+
+	/// Accepts connections until the listener fails, handling each on its own thread, up to
+	/// [`ServerConfig::max_connections`] at once.
+	pub fn run(self) -> Result<()> {
+		let result = self.listener.incoming().try_for_each(|stream| {
+			if let Err(err) = accept(&self.context, stream?) {
+				tracing::debug!("connection failed: {err}");
+			}
+			Ok(())
+		});
+
+		self.context.queue.shut_down();
+		for worker in self.workers {
+			let _ = worker.join();
+		}
+		result
+	}
+}
+
+fn work(context: &Context) {
+	while let Some(job) = context.queue.next() {
+		let start = Instant::now();
+		let result = match context.circuits.get(&job.circuit) {
+			Some(circuit) => circuit
+				.prove(&job.witness, &context.config.prove_options)
+				.map_err(|err| err.to_string()),
+			None => Err(format!("unknown circuit {}", job.circuit)),
+		};
+		if let Err(err) = &result {
+			tracing::warn!(job = job.id, tenant = %job.tenant, "proving failed: {err}");
+		}
+		context.queue.finish(job.id, result, start.elapsed());
+	}
+}
+
+fn accept(context: &Arc<Context>, stream: TcpStream) -> Result<()> {
+	stream.set_read_timeout(Some(context.config.io_timeout))?;
+	stream.set_write_timeout(Some(context.config.io_timeout))?;
+	let Some(connection) = Connection::acquire(context) else {
+		let response = Response::error(503, "too many connections");
+		http::write_response(&mut BufWriter::new(stream), &response)?;
+		return Ok(());
+	};
+	thread::spawn(move || {
+		if let Err(err) = serve(&connection.0, stream) {
+			tracing::debug!("connection failed: {err}");
+		}
+	});
+	Ok(())
+}
+
+fn serve(context: &Context, stream: TcpStream) -> Result<()> {
+	let mut reader = BufReader::new(stream.try_clone()?);
+	let response = match http::read_request(&mut reader, context.config.max_witness_size) {
+		Ok(request) => handle(context, &request),
+		Err(err @ http::Error::BodyTooLarge { .. }) => Response::error(413, err),
+		Err(http::Error::Io(err)) => return Err(err.into()),
+		Err(err) => Response::error(400, err),
+	};
+	http::write_response(&mut BufWriter::new(stream), &response)?;
+	Ok(())
+}
+
+fn handle(context: &Context, request: &Request) -> Response {
+	match (request.method.as_str(), request.segments().as_slice()) {
+		("GET", ["healthz"]) => Response::text(200, "ok"),
+		("GET", ["metrics"]) => Response::text(200, context.metrics.render()),
+		("GET", ["v1", "circuits"]) => Response::json(
+			200,
+			serde_json::json!({ "circuits": context.circuits.ids().collect::<Vec<_>>() }),
+		),
+		("POST", ["v1", "circuits", circuit, "jobs"]) => submit(context, request, circuit),
+		("GET", ["v1", "jobs", id]) => with_job_id(id, |id| match context.queue.status(id) {
+			Some(status) => Response::json(200, status_json(id, &status)),
+			None => Response::error(404, format!("unknown job {id}")),
+		}),
+		("GET", ["v1", "jobs", id, "proof"]) => {
+			with_job_id(id, |id| match context.queue.take_proof(id) {
+				Some(Ok(proof)) => Response::bytes(proof),
+				Some(Err(status)) => Response::json(409, status_json(id, &status)),
+				None => Response::error(404, format!("unknown job {id}")),
+			})
+		}
+		(_, ["healthz" | "metrics"] | ["v1", "circuits", ..] | ["v1", "jobs", ..]) => {
+			Response::error(405, "method not allowed")
+		}
+		_ => Response::error(404, "not found"),
+	}
+}
+
+fn submit(context: &Context, request: &Request, circuit: &str) -> Response {
+	if context.circuits.get(circuit).is_none() {
+		return Response::error(404, format!("unknown circuit {circuit}"));
+	}
+	let tenant = request.header("X-Tenant").unwrap_or(DEFAULT_TENANT);
+	match context.queue.submit(tenant, circuit, request.body.clone()) {
+		Ok(id) => Response::json(202, serde_json::json!({ "job_id": id })),
+		Err(err @ (SubmitError::QueueFull | SubmitError::TenantLimit(_))) => {
+			Response::error(429, err)
+		}
+		Err(err @ SubmitError::ShutDown) => Response::error(503, err),
+	}
+}
+
+fn with_job_id(id: &str, f: impl FnOnce(JobId) -> Response) -> Response {
+	match id.parse() {
+		Ok(id) => f(id),
+		Err(_) => Response::error(400, format!("invalid job id {id}")),
+	}
+}
+
+fn status_json(id: JobId, status: &JobStatus) -> serde_json::Value {
+	let mut json = serde_json::json!({ "job_id": id, "status": status.name() });
+	if let JobStatus::Failed(err) = status {
+		json["error"] = err.clone().into();
+	}
+	json
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		io::{Read, Write},
+		thread::sleep,
+		time::Duration,
+	};
+
+	use binius_core::constraint_system::PCSParams;
+
+	use super::*;
+	use crate::circuits::{self, u32_add};
+
+	fn request(addr: SocketAddr, method: &str, path: &str, body: &[u8]) -> (u16, Vec<u8>) {
+		let mut stream = TcpStream::connect(addr).unwrap();
+		write!(
+			stream,
+			"{method} {path} HTTP/1.1\r\nX-Tenant: test\r\nContent-Length: {}\r\n\r\n",
+			body.len()
+		)
+		.unwrap();
+		stream.write_all(body).unwrap();
+
+		let mut response = Vec::new();
+		stream.read_to_end(&mut response).unwrap();
+		let header_end = response
+			.windows(4)
+			.position(|window| window == b"\r\n\r\n")
+			.unwrap();
+		let status = std::str::from_utf8(&response[9..12])
+			.unwrap()
+			.parse()
+			.unwrap();
+		(status, response[header_end + 4..].to_vec())
+	}
+
+	fn json(body: &[u8]) -> serde_json::Value {
+		serde_json::from_slice(body).unwrap()
+	}
+
+	#[test]
+	fn test_connection_limit() {
+		let config = ServerConfig {
+			max_connections: 1,
+			io_timeout: Duration::from_millis(500),
+			..ServerConfig::default()
+		};
+		let server = Server::bind("127.0.0.1:0", CircuitRegistry::new(), config).unwrap();
+		let addr = server.local_addr().unwrap();
+		thread::spawn(move || server.run());
+
+		// A client that never completes its request holds the only connection until it times out
+		let mut idle = TcpStream::connect(addr).unwrap();
+		idle.write_all(b"POST /v1/circuits/u32_add/jobs HTTP/1.1\r\n")
+			.unwrap();
+		// Closing a rejected connection with an unread request resets it, so this client reads the
+		// response without sending a request
+		let mut response = Vec::new();
+		TcpStream::connect(addr)
+			.unwrap()
+			.read_to_end(&mut response)
+			.unwrap();
+		assert!(response.starts_with(b"HTTP/1.1 503 Service Unavailable\r\n"));
+
+		// The connection is closed without a response once the read times out
+		let mut response = Vec::new();
+		idle.read_to_end(&mut response).unwrap();
+		assert!(response.is_empty());
+		sleep(Duration::from_millis(10));
+		assert_eq!(request(addr, "GET", "/healthz", b""), (200, b"ok".to_vec()));
+	}
+
+	#[test]
+	fn test_prove_over_http() {
+		let mut registry = CircuitRegistry::new();
+		registry
+			.register("u32_add", u32_add, 8, PCSParams::new(1, 100))
+			.unwrap();
+		let server = Server::bind("127.0.0.1:0", registry, ServerConfig::default()).unwrap();
+		let addr = server.local_addr().unwrap();
+		thread::spawn(move || server.run());
+
+		assert_eq!(request(addr, "GET", "/healthz", b""), (200, b"ok".to_vec()));
+		let (status, body) = request(addr, "GET", "/v1/circuits", b"");
+		assert_eq!(status, 200);
+		assert_eq!(json(&body)["circuits"], serde_json::json!(["u32_add"]));
+		assert_eq!(request(addr, "POST", "/v1/circuits/sha256/jobs", b"").0, 404);
+		assert_eq!(request(addr, "GET", "/v1/jobs/abc", b"").0, 400);
+		assert_eq!(request(addr, "DELETE", "/v1/jobs/0", b"").0, 405);
+
+		let witness = circuits::witness(u32_add, 8).unwrap();
+		let (status, body) = request(addr, "POST", "/v1/circuits/u32_add/jobs", &witness);
+		assert_eq!(status, 202);
+		let id = json(&body)["job_id"].as_u64().unwrap();
+
+		let mut status = json(&request(addr, "GET", &format!("/v1/jobs/{id}"), b"").1);
+		while status["status"] == "queued" || status["status"] == "running" {
+			sleep(Duration::from_millis(10));
+			status = json(&request(addr, "GET", &format!("/v1/jobs/{id}"), b"").1);
+		}
+		assert_eq!(status["status"], "done");
+
+		let (status, proof) = request(addr, "GET", &format!("/v1/jobs/{id}/proof"), b"");
+		assert_eq!(status, 200);
+		let mut registry = CircuitRegistry::new();
+		registry
+			.register("u32_add", u32_add, 8, PCSParams::new(1, 100))
+			.unwrap();
+		registry.get("u32_add").unwrap().verify(proof).unwrap();
+		assert_eq!(request(addr, "GET", &format!("/v1/jobs/{id}/proof"), b"").0, 404);
+
+		let (status, metrics) = request(addr, "GET", "/metrics", b"");
+		assert_eq!(status, 200);
+		assert!(String::from_utf8(metrics)
+			.unwrap()
+			.contains("binius_jobs_completed_total 1\n"));
+	}
+}
@@ -1,6 +1,27 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{env, sync::OnceLock};
+//! Data parallelism for the workspace.
+//!
+//! With the `rayon` feature, which is enabled by default, this module re-exports the rayon APIs
+//! the workspace uses. Without it, the same APIs are implemented sequentially, so that the prover
+//! builds for platforms without threads and gives clean single-threaded profiles. Crates of the
+//! workspace use rayon only through this module.
+
+use std::{env, io, sync::OnceLock};
+
+#[cfg(feature = "rayon")]
+pub use rayon::{
+	current_num_threads, iter, join, prelude, slice, ThreadPool, ThreadPoolBuildError,
+	ThreadPoolBuilder,
+};
+#[cfg(not(feature = "rayon"))]
+pub use sequential::*;
+
+#[cfg(not(feature = "rayon"))]
+mod sequential;
+
+/// Whether the work is run on a thread pool, i.e. whether the `rayon` feature is enabled.
+pub const PARALLEL: bool = cfg!(feature = "rayon");
 
 /// In case when number of threads is set to 1, use rayon thread pool with
 /// `use_current_thread` set to true. This is solves two problems:
@@ -12,14 +33,14 @@ use std::{env, sync::OnceLock};
 /// The typical usage of the function is to place it's call in the beginning of the `main`.
 /// The function returns reference to the result because `ThreadPoolBuildError`
 /// doesn't implement `Clone`.
-pub fn adjust_thread_pool() -> &'static Result<(), rayon::ThreadPoolBuildError> {
-	static ONCE_GUARD: OnceLock<Result<(), rayon::ThreadPoolBuildError>> = OnceLock::new();
+pub fn adjust_thread_pool() -> &'static Result<(), ThreadPoolBuildError> {
+	static ONCE_GUARD: OnceLock<Result<(), ThreadPoolBuildError>> = OnceLock::new();
 
 	ONCE_GUARD.get_or_init(|| {
 		// We cannot use `rayon::get_current_threads` because it would force the global thread pool
 		// to initialize, so we won't be able to override it.
 		match env::var("RAYON_NUM_THREADS") {
-			Ok(v) if v == "1" => rayon::ThreadPoolBuilder::new()
+			Ok(v) if v == "1" => ThreadPoolBuilder::new()
 				.num_threads(1)
 				.use_current_thread()
 				.build_global(),
@@ -30,5 +51,51 @@ pub fn adjust_thread_pool() -> &'static Result<(), rayon::ThreadPoolBuildError>
 
 /// Returns the base-2 logarithm of the number of threads that should be used for the task
 pub fn get_log_max_threads() -> usize {
-	(2 * rayon::current_num_threads() - 1).ilog2() as _
+	(2 * current_num_threads() - 1).ilog2() as _
+}
+
+/// Pins the calling thread to the CPU core with index `core`.
+///
+/// Pinning is only supported on Linux, on other platforms it fails with
+/// [`io::ErrorKind::Unsupported`]. Core indices beyond the capacity of a CPU set fail with
+/// [`io::ErrorKind::InvalidInput`].
+pub fn pin_current_thread(core: usize) -> io::Result<()> {
+	#[cfg(target_os = "linux")]
+	{
+		if core >= libc::CPU_SETSIZE as usize {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				format!("core {core} exceeds the CPU set size {}", libc::CPU_SETSIZE),
+			));
+		}
+		// Safety: `cpu_set_t` is a plain bitmask for which all zeros is the empty set, and the
+		// pointer passed to `sched_setaffinity` is valid for the size passed with it.
+		unsafe {
+			let mut set = std::mem::zeroed::<libc::cpu_set_t>();
+			libc::CPU_SET(core, &mut set);
+			if libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+				return Err(io::Error::last_os_error());
+			}
+		}
+		Ok(())
+	}
+	#[cfg(not(target_os = "linux"))]
+	{
+		let _ = core;
+		Err(io::Error::new(io::ErrorKind::Unsupported, "thread pinning requires Linux"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[cfg(target_os = "linux")]
+	fn test_pin_current_thread_rejects_out_of_range_core() {
+		for core in [libc::CPU_SETSIZE as usize, usize::MAX] {
+			let err = pin_current_thread(core).unwrap_err();
+			assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+		}
+	}
 }
@@ -0,0 +1,905 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Sequential implementations of the rayon interfaces used in the workspace.
+//!
+//! The traits mirror the rayon traits of the same names, so code written against rayon compiles
+//! unchanged, but every "parallel" iterator runs on the calling thread. The iterators do not
+//! implement [`Iterator`] themselves, since methods like `reduce` and `fold` have different
+//! signatures in rayon and in the standard library.
+
+use std::{error::Error, fmt};
+
+pub mod prelude {
+	pub use super::{
+		iter::{
+			FromParallelIterator, IndexedParallelIterator, IntoParallelIterator,
+			IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelBridge, ParallelIterator,
+		},
+		slice::{ParallelSlice, ParallelSliceMut},
+	};
+}
+
+pub mod iter {
+	use std::{
+		cmp::Ordering,
+		iter::{self, Sum},
+		ops::{Range, RangeInclusive},
+	};
+
+	/// A sequential iterator behind the parallel iterator interface.
+	#[derive(Debug, Clone)]
+	pub struct Sequential<I>(pub(crate) I);
+
+	impl<I: Iterator> ParallelIterator for Sequential<I> {
+		type Item = I::Item;
+		type Iter = I;
+
+		fn into_seq(self) -> I {
+			self.0
+		}
+	}
+
+	impl<I: Iterator> IndexedParallelIterator for Sequential<I> {
+		fn len(&self) -> usize {
+			self.0.size_hint().0
+		}
+	}
+
+	pub trait ParallelIterator: Sized {
+		type Item;
+		#[doc(hidden)]
+		type Iter: Iterator<Item = Self::Item>;
+
+		/// Converts into the underlying sequential iterator.
+		#[doc(hidden)]
+		fn into_seq(self) -> Self::Iter;
+
+		fn for_each<F: Fn(Self::Item)>(self, f: F) {
+			self.into_seq().for_each(f)
+		}
+
+		fn for_each_init<T, INIT, F>(self, init: INIT, f: F)
+		where
+			INIT: Fn() -> T,
+			F: Fn(&mut T, Self::Item),
+		{
+			let mut state = init();
+			self.into_seq().for_each(|item| f(&mut state, item))
+		}
+
+		fn for_each_with<T, F: Fn(&mut T, Self::Item)>(self, mut init: T, f: F) {
+			self.into_seq().for_each(|item| f(&mut init, item))
+		}
+
+		fn try_for_each<E, F>(self, f: F) -> Result<(), E>
+		where
+			F: Fn(Self::Item) -> Result<(), E>,
+		{
+			self.into_seq().try_for_each(f)
+		}
+
+		fn try_for_each_init<T, E, INIT, F>(self, init: INIT, f: F) -> Result<(), E>
+		where
+			INIT: Fn() -> T,
+			F: Fn(&mut T, Self::Item) -> Result<(), E>,
+		{
+			let mut state = init();
+			self.into_seq().try_for_each(|item| f(&mut state, item))
+		}
+
+		fn try_for_each_with<T, E, F>(self, mut init: T, f: F) -> Result<(), E>
+		where
+			F: Fn(&mut T, Self::Item) -> Result<(), E>,
+		{
+			self.into_seq().try_for_each(|item| f(&mut init, item))
+		}
+
+		fn map<R, F: Fn(Self::Item) -> R>(self, f: F) -> Sequential<iter::Map<Self::Iter, F>> {
+			Sequential(self.into_seq().map(f))
+		}
+
+		fn map_init<T, R, INIT, F>(self, init: INIT, f: F) -> Sequential<impl Iterator<Item = R>>
+		where
+			INIT: Fn() -> T,
+			F: Fn(&mut T, Self::Item) -> R,
+		{
+			let mut state = init();
+			Sequential(self.into_seq().map(move |item| f(&mut state, item)))
+		}
+
+		fn map_with<T, R, F>(self, mut init: T, f: F) -> Sequential<impl Iterator<Item = R>>
+		where
+			F: Fn(&mut T, Self::Item) -> R,
+		{
+			Sequential(self.into_seq().map(move |item| f(&mut init, item)))
+		}
+
+		fn inspect<F: Fn(&Self::Item)>(self, f: F) -> Sequential<iter::Inspect<Self::Iter, F>> {
+			Sequential(self.into_seq().inspect(f))
+		}
+
+		fn cloned<'a, T>(self) -> Sequential<iter::Cloned<Self::Iter>>
+		where
+			T: 'a + Clone,
+			Self: ParallelIterator<Item = &'a T>,
+		{
+			Sequential(self.into_seq().cloned())
+		}
+
+		fn copied<'a, T>(self) -> Sequential<iter::Copied<Self::Iter>>
+		where
+			T: 'a + Copy,
+			Self: ParallelIterator<Item = &'a T>,
+		{
+			Sequential(self.into_seq().copied())
+		}
+
+		fn filter<P>(self, predicate: P) -> Sequential<iter::Filter<Self::Iter, P>>
+		where
+			P: Fn(&Self::Item) -> bool,
+		{
+			Sequential(self.into_seq().filter(predicate))
+		}
+
+		fn filter_map<R, P>(self, predicate: P) -> Sequential<iter::FilterMap<Self::Iter, P>>
+		where
+			P: Fn(Self::Item) -> Option<R>,
+		{
+			Sequential(self.into_seq().filter_map(predicate))
+		}
+
+		fn flat_map<PI, F>(self, f: F) -> Sequential<impl Iterator<Item = PI::Item>>
+		where
+			PI: IntoParallelIterator,
+			F: Fn(Self::Item) -> PI,
+		{
+			Sequential(
+				self.into_seq()
+					.flat_map(move |item| f(item).into_par_iter().into_seq()),
+			)
+		}
+
+		fn flat_map_iter<SI, F>(self, f: F) -> Sequential<iter::FlatMap<Self::Iter, SI, F>>
+		where
+			SI: IntoIterator,
+			F: Fn(Self::Item) -> SI,
+		{
+			Sequential(self.into_seq().flat_map(f))
+		}
+
+		fn flatten(
+			self,
+		) -> Sequential<impl Iterator<Item = <Self::Item as IntoParallelIterator>::Item>>
+		where
+			Self::Item: IntoParallelIterator,
+		{
+			Sequential(
+				self.into_seq()
+					.flat_map(|item| item.into_par_iter().into_seq()),
+			)
+		}
+
+		fn flatten_iter(self) -> Sequential<iter::Flatten<Self::Iter>>
+		where
+			Self::Item: IntoIterator,
+		{
+			Sequential(self.into_seq().flatten())
+		}
+
+		fn chain<C>(self, chain: C) -> Sequential<impl Iterator<Item = Self::Item>>
+		where
+			C: IntoParallelIterator<Item = Self::Item>,
+		{
+			Sequential(self.into_seq().chain(chain.into_par_iter().into_seq()))
+		}
+
+		fn fold<T, ID, F>(self, identity: ID, fold_op: F) -> Sequential<iter::Once<T>>
+		where
+			ID: Fn() -> T,
+			F: Fn(T, Self::Item) -> T,
+		{
+			Sequential(iter::once(self.into_seq().fold(identity(), fold_op)))
+		}
+
+		fn fold_with<T, F>(self, init: T, fold_op: F) -> Sequential<iter::Once<T>>
+		where
+			F: Fn(T, Self::Item) -> T,
+		{
+			Sequential(iter::once(self.into_seq().fold(init, fold_op)))
+		}
+
+		fn try_fold<T, E, ID, F>(
+			self,
+			identity: ID,
+			fold_op: F,
+		) -> Sequential<iter::Once<Result<T, E>>>
+		where
+			ID: Fn() -> T,
+			F: Fn(T, Self::Item) -> Result<T, E>,
+		{
+			Sequential(iter::once(self.into_seq().try_fold(identity(), fold_op)))
+		}
+
+		fn try_fold_with<T, E, F>(self, init: T, fold_op: F) -> Sequential<iter::Once<Result<T, E>>>
+		where
+			F: Fn(T, Self::Item) -> Result<T, E>,
+		{
+			Sequential(iter::once(self.into_seq().try_fold(init, fold_op)))
+		}
+
+		fn reduce<ID, OP>(self, identity: ID, op: OP) -> Self::Item
+		where
+			ID: Fn() -> Self::Item,
+			OP: Fn(Self::Item, Self::Item) -> Self::Item,
+		{
+			self.into_seq().fold(identity(), op)
+		}
+
+		fn reduce_with<OP>(self, op: OP) -> Option<Self::Item>
+		where
+			OP: Fn(Self::Item, Self::Item) -> Self::Item,
+		{
+			self.into_seq().reduce(op)
+		}
+
+		fn try_reduce<T, E, ID, OP>(self, identity: ID, op: OP) -> Result<T, E>
+		where
+			Self: ParallelIterator<Item = Result<T, E>>,
+			ID: Fn() -> T,
+			OP: Fn(T, T) -> Result<T, E>,
+		{
+			self.into_seq()
+				.try_fold(identity(), |acc, item| op(acc, item?))
+		}
+
+		fn sum<S: Sum<Self::Item>>(self) -> S {
+			self.into_seq().sum()
+		}
+
+		fn product<P: iter::Product<Self::Item>>(self) -> P {
+			self.into_seq().product()
+		}
+
+		fn count(self) -> usize {
+			self.into_seq().count()
+		}
+
+		fn min(self) -> Option<Self::Item>
+		where
+			Self::Item: Ord,
+		{
+			self.into_seq().min()
+		}
+
+		fn max(self) -> Option<Self::Item>
+		where
+			Self::Item: Ord,
+		{
+			self.into_seq().max()
+		}
+
+		fn min_by<F>(self, f: F) -> Option<Self::Item>
+		where
+			F: Fn(&Self::Item, &Self::Item) -> Ordering,
+		{
+			self.into_seq().min_by(f)
+		}
+
+		fn max_by<F>(self, f: F) -> Option<Self::Item>
+		where
+			F: Fn(&Self::Item, &Self::Item) -> Ordering,
+		{
+			self.into_seq().max_by(f)
+		}
+
+		fn min_by_key<K: Ord, F: Fn(&Self::Item) -> K>(self, f: F) -> Option<Self::Item> {
+			self.into_seq().min_by_key(f)
+		}
+
+		fn max_by_key<K: Ord, F: Fn(&Self::Item) -> K>(self, f: F) -> Option<Self::Item> {
+			self.into_seq().max_by_key(f)
+		}
+
+		fn any<P: Fn(Self::Item) -> bool>(self, predicate: P) -> bool {
+			self.into_seq().any(predicate)
+		}
+
+		fn all<P: Fn(Self::Item) -> bool>(self, predicate: P) -> bool {
+			self.into_seq().all(predicate)
+		}
+
+		fn find_any<P: Fn(&Self::Item) -> bool>(self, predicate: P) -> Option<Self::Item> {
+			self.into_seq().find(predicate)
+		}
+
+		fn find_first<P: Fn(&Self::Item) -> bool>(self, predicate: P) -> Option<Self::Item> {
+			self.into_seq().find(predicate)
+		}
+
+		fn find_map_any<R, P>(self, predicate: P) -> Option<R>
+		where
+			P: Fn(Self::Item) -> Option<R>,
+		{
+			self.into_seq().find_map(predicate)
+		}
+
+		fn find_map_first<R, P>(self, predicate: P) -> Option<R>
+		where
+			P: Fn(Self::Item) -> Option<R>,
+		{
+			self.into_seq().find_map(predicate)
+		}
+
+		fn collect<C: FromParallelIterator<Self::Item>>(self) -> C {
+			C::from_par_iter(self)
+		}
+
+		fn unzip<A, B, FromA, FromB>(self) -> (FromA, FromB)
+		where
+			Self: ParallelIterator<Item = (A, B)>,
+			FromA: Default + Extend<A>,
+			FromB: Default + Extend<B>,
+		{
+			self.into_seq().unzip()
+		}
+
+		fn partition<A, B, P>(self, predicate: P) -> (A, B)
+		where
+			A: Default + Extend<Self::Item>,
+			B: Default + Extend<Self::Item>,
+			P: Fn(&Self::Item) -> bool,
+		{
+			let mut left = A::default();
+			let mut right = B::default();
+			for item in self.into_seq() {
+				if predicate(&item) {
+					left.extend(Some(item));
+				} else {
+					right.extend(Some(item));
+				}
+			}
+			(left, right)
+		}
+
+		fn while_some<T>(self) -> Sequential<impl Iterator<Item = T>>
+		where
+			Self: ParallelIterator<Item = Option<T>>,
+		{
+			Sequential(self.into_seq().map_while(|item| item))
+		}
+
+		fn panic_fuse(self) -> Self {
+			self
+		}
+	}
+
+	// The rayon trait has no `is_empty` either.
+	#[allow(clippy::len_without_is_empty)]
+	pub trait IndexedParallelIterator: ParallelIterator {
+		fn len(&self) -> usize;
+
+		fn enumerate(self) -> Sequential<iter::Enumerate<Self::Iter>> {
+			Sequential(self.into_seq().enumerate())
+		}
+
+		fn zip<Z: IntoParallelIterator>(
+			self,
+			zip_op: Z,
+		) -> Sequential<iter::Zip<Self::Iter, <Z::Iter as ParallelIterator>::Iter>> {
+			Sequential(self.into_seq().zip(zip_op.into_par_iter().into_seq()))
+		}
+
+		fn zip_eq<Z: IntoParallelIterator>(
+			self,
+			zip_op: Z,
+		) -> Sequential<iter::Zip<Self::Iter, <Z::Iter as ParallelIterator>::Iter>>
+		where
+			Z::Iter: IndexedParallelIterator,
+		{
+			let zip_op = zip_op.into_par_iter();
+			assert_eq!(self.len(), zip_op.len(), "zipped iterators must have the same length");
+			self.zip(zip_op)
+		}
+
+		fn chunks(self, chunk_size: usize) -> Sequential<Chunks<Self::Iter>> {
+			assert!(chunk_size != 0, "chunk_size must not be zero");
+			Sequential(Chunks {
+				iter: self.into_seq(),
+				chunk_size,
+			})
+		}
+
+		fn fold_chunks<T, ID, F>(
+			self,
+			chunk_size: usize,
+			identity: ID,
+			fold_op: F,
+		) -> Sequential<impl Iterator<Item = T>>
+		where
+			ID: Fn() -> T,
+			F: Fn(T, Self::Item) -> T,
+		{
+			self.chunks(chunk_size)
+				.map(move |chunk| chunk.into_iter().fold(identity(), &fold_op))
+		}
+
+		fn step_by(self, step: usize) -> Sequential<iter::StepBy<Self::Iter>> {
+			Sequential(self.into_seq().step_by(step))
+		}
+
+		fn skip(self, n: usize) -> Sequential<iter::Skip<Self::Iter>> {
+			Sequential(self.into_seq().skip(n))
+		}
+
+		fn take(self, n: usize) -> Sequential<iter::Take<Self::Iter>> {
+			Sequential(self.into_seq().take(n))
+		}
+
+		fn rev(self) -> Sequential<iter::Rev<Self::Iter>>
+		where
+			Self::Iter: DoubleEndedIterator,
+		{
+			Sequential(self.into_seq().rev())
+		}
+
+		fn position_any<P: Fn(Self::Item) -> bool>(self, predicate: P) -> Option<usize> {
+			self.into_seq().position(predicate)
+		}
+
+		fn position_first<P: Fn(Self::Item) -> bool>(self, predicate: P) -> Option<usize> {
+			self.into_seq().position(predicate)
+		}
+
+		fn collect_into_vec(self, target: &mut Vec<Self::Item>) {
+			target.clear();
+			target.extend(self.into_seq());
+		}
+
+		fn with_min_len(self, _min: usize) -> Self {
+			self
+		}
+
+		fn with_max_len(self, _max: usize) -> Self {
+			self
+		}
+	}
+
+	/// The iterator of [`IndexedParallelIterator::chunks`].
+	#[derive(Debug, Clone)]
+	pub struct Chunks<I> {
+		iter: I,
+		chunk_size: usize,
+	}
+
+	impl<I: Iterator> Iterator for Chunks<I> {
+		type Item = Vec<I::Item>;
+
+		fn next(&mut self) -> Option<Self::Item> {
+			let chunk = self.iter.by_ref().take(self.chunk_size).collect::<Vec<_>>();
+			(!chunk.is_empty()).then_some(chunk)
+		}
+
+		fn size_hint(&self) -> (usize, Option<usize>) {
+			let (lower, upper) = self.iter.size_hint();
+			(lower.div_ceil(self.chunk_size), upper.map(|upper| upper.div_ceil(self.chunk_size)))
+		}
+	}
+
+	pub trait IntoParallelIterator {
+		type Iter: ParallelIterator<Item = Self::Item>;
+		type Item;
+
+		fn into_par_iter(self) -> Self::Iter;
+	}
+
+	impl<T: ParallelIterator> IntoParallelIterator for T {
+		type Iter = T;
+		type Item = T::Item;
+
+		fn into_par_iter(self) -> T {
+			self
+		}
+	}
+
+	impl<T> IntoParallelIterator for Vec<T> {
+		type Iter = Sequential<std::vec::IntoIter<T>>;
+		type Item = T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.into_iter())
+		}
+	}
+
+	impl<'a, T> IntoParallelIterator for &'a Vec<T> {
+		type Iter = Sequential<std::slice::Iter<'a, T>>;
+		type Item = &'a T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.iter())
+		}
+	}
+
+	impl<'a, T> IntoParallelIterator for &'a mut Vec<T> {
+		type Iter = Sequential<std::slice::IterMut<'a, T>>;
+		type Item = &'a mut T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.iter_mut())
+		}
+	}
+
+	impl<'a, T> IntoParallelIterator for &'a [T] {
+		type Iter = Sequential<std::slice::Iter<'a, T>>;
+		type Item = &'a T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.iter())
+		}
+	}
+
+	impl<'a, T> IntoParallelIterator for &'a mut [T] {
+		type Iter = Sequential<std::slice::IterMut<'a, T>>;
+		type Item = &'a mut T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.iter_mut())
+		}
+	}
+
+	impl<T, const N: usize> IntoParallelIterator for [T; N] {
+		type Iter = Sequential<std::array::IntoIter<T, N>>;
+		type Item = T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.into_iter())
+		}
+	}
+
+	impl<'a, T, const N: usize> IntoParallelIterator for &'a [T; N] {
+		type Iter = Sequential<std::slice::Iter<'a, T>>;
+		type Item = &'a T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.iter())
+		}
+	}
+
+	impl<'a, T, const N: usize> IntoParallelIterator for &'a mut [T; N] {
+		type Iter = Sequential<std::slice::IterMut<'a, T>>;
+		type Item = &'a mut T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.iter_mut())
+		}
+	}
+
+	impl<T> IntoParallelIterator for Option<T> {
+		type Iter = Sequential<std::option::IntoIter<T>>;
+		type Item = T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self.into_iter())
+		}
+	}
+
+	impl<T> IntoParallelIterator for Range<T>
+	where
+		Range<T>: Iterator<Item = T>,
+	{
+		type Iter = Sequential<Range<T>>;
+		type Item = T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self)
+		}
+	}
+
+	impl<T> IntoParallelIterator for RangeInclusive<T>
+	where
+		RangeInclusive<T>: Iterator<Item = T>,
+	{
+		type Iter = Sequential<RangeInclusive<T>>;
+		type Item = T;
+
+		fn into_par_iter(self) -> Self::Iter {
+			Sequential(self)
+		}
+	}
+
+	/// The iterator over tuples of items from several iterators, see the tuple implementations of
+	/// [`IntoParallelIterator`].
+	#[derive(Debug, Clone)]
+	pub struct MultiZip<T>(T);
+
+	macro_rules! impl_multi_zip {
+		($($name:ident),+) => {
+			impl<$($name: Iterator),+> Iterator for MultiZip<($($name,)+)> {
+				type Item = ($($name::Item,)+);
+
+				#[allow(non_snake_case)]
+				fn next(&mut self) -> Option<Self::Item> {
+					let ($($name,)+) = &mut self.0;
+					Some(($($name.next()?,)+))
+				}
+
+				#[allow(non_snake_case)]
+				fn size_hint(&self) -> (usize, Option<usize>) {
+					let ($($name,)+) = &self.0;
+					let lower = [$($name.size_hint().0),+].into_iter().min().unwrap_or(0);
+					let upper = [$($name.size_hint().1),+].into_iter().flatten().min();
+					(lower, upper)
+				}
+			}
+
+			impl<$($name: IntoParallelIterator),+> IntoParallelIterator for ($($name,)+) {
+				type Iter = Sequential<MultiZip<($(<$name::Iter as ParallelIterator>::Iter,)+)>>;
+				type Item = ($($name::Item,)+);
+
+				#[allow(non_snake_case)]
+				fn into_par_iter(self) -> Self::Iter {
+					let ($($name,)+) = self;
+					Sequential(MultiZip(($($name.into_par_iter().into_seq(),)+)))
+				}
+			}
+		};
+	}
+
+	impl_multi_zip!(A, B);
+	impl_multi_zip!(A, B, C);
+	impl_multi_zip!(A, B, C, D);
+	impl_multi_zip!(A, B, C, D, E);
+	impl_multi_zip!(A, B, C, D, E, F);
+
+	pub trait IntoParallelRefIterator<'data> {
+		type Iter: ParallelIterator<Item = Self::Item>;
+		type Item: 'data;
+
+		fn par_iter(&'data self) -> Self::Iter;
+	}
+
+	impl<'data, I: 'data + ?Sized> IntoParallelRefIterator<'data> for I
+	where
+		&'data I: IntoParallelIterator,
+	{
+		type Iter = <&'data I as IntoParallelIterator>::Iter;
+		type Item = <&'data I as IntoParallelIterator>::Item;
+
+		fn par_iter(&'data self) -> Self::Iter {
+			self.into_par_iter()
+		}
+	}
+
+	pub trait IntoParallelRefMutIterator<'data> {
+		type Iter: ParallelIterator<Item = Self::Item>;
+		type Item: 'data;
+
+		fn par_iter_mut(&'data mut self) -> Self::Iter;
+	}
+
+	impl<'data, I: 'data + ?Sized> IntoParallelRefMutIterator<'data> for I
+	where
+		&'data mut I: IntoParallelIterator,
+	{
+		type Iter = <&'data mut I as IntoParallelIterator>::Iter;
+		type Item = <&'data mut I as IntoParallelIterator>::Item;
+
+		fn par_iter_mut(&'data mut self) -> Self::Iter {
+			self.into_par_iter()
+		}
+	}
+
+	pub trait FromParallelIterator<T> {
+		fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self;
+	}
+
+	impl<T, C: FromIterator<T>> FromParallelIterator<T> for C {
+		fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+			par_iter.into_par_iter().into_seq().collect()
+		}
+	}
+
+	pub trait ParallelBridge: Iterator + Sized {
+		fn par_bridge(self) -> Sequential<Self> {
+			Sequential(self)
+		}
+	}
+
+	impl<I: Iterator> ParallelBridge for I {}
+}
+
+pub mod slice {
+	use std::{cmp::Ordering, slice};
+
+	use super::iter::Sequential;
+
+	pub trait ParallelSlice<T> {
+		fn as_parallel_slice(&self) -> &[T];
+
+		fn par_chunks(&self, chunk_size: usize) -> Sequential<slice::Chunks<'_, T>> {
+			Sequential(self.as_parallel_slice().chunks(chunk_size))
+		}
+
+		fn par_chunks_exact(&self, chunk_size: usize) -> Sequential<slice::ChunksExact<'_, T>> {
+			Sequential(self.as_parallel_slice().chunks_exact(chunk_size))
+		}
+
+		fn par_windows(&self, window_size: usize) -> Sequential<slice::Windows<'_, T>> {
+			Sequential(self.as_parallel_slice().windows(window_size))
+		}
+	}
+
+	impl<T> ParallelSlice<T> for [T] {
+		fn as_parallel_slice(&self) -> &[T] {
+			self
+		}
+	}
+
+	pub trait ParallelSliceMut<T> {
+		fn as_parallel_slice_mut(&mut self) -> &mut [T];
+
+		fn par_chunks_mut(&mut self, chunk_size: usize) -> Sequential<slice::ChunksMut<'_, T>> {
+			Sequential(self.as_parallel_slice_mut().chunks_mut(chunk_size))
+		}
+
+		fn par_chunks_exact_mut(
+			&mut self,
+			chunk_size: usize,
+		) -> Sequential<slice::ChunksExactMut<'_, T>> {
+			Sequential(self.as_parallel_slice_mut().chunks_exact_mut(chunk_size))
+		}
+
+		fn par_sort(&mut self)
+		where
+			T: Ord,
+		{
+			self.as_parallel_slice_mut().sort()
+		}
+
+		fn par_sort_unstable(&mut self)
+		where
+			T: Ord,
+		{
+			self.as_parallel_slice_mut().sort_unstable()
+		}
+
+		fn par_sort_by<F: Fn(&T, &T) -> Ordering>(&mut self, compare: F) {
+			self.as_parallel_slice_mut().sort_by(compare)
+		}
+
+		fn par_sort_unstable_by<F: Fn(&T, &T) -> Ordering>(&mut self, compare: F) {
+			self.as_parallel_slice_mut().sort_unstable_by(compare)
+		}
+
+		fn par_sort_by_key<K: Ord, F: Fn(&T) -> K>(&mut self, f: F) {
+			self.as_parallel_slice_mut().sort_by_key(f)
+		}
+
+		fn par_sort_unstable_by_key<K: Ord, F: Fn(&T) -> K>(&mut self, f: F) {
+			self.as_parallel_slice_mut().sort_unstable_by_key(f)
+		}
+	}
+
+	impl<T> ParallelSliceMut<T> for [T] {
+		fn as_parallel_slice_mut(&mut self) -> &mut [T] {
+			self
+		}
+	}
+}
+
+/// The number of threads of the current pool, which is always one.
+pub fn current_num_threads() -> usize {
+	1
+}
+
+/// Runs both closures one after the other on the calling thread.
+pub fn join<A, B, RA, RB>(oper_a: A, oper_b: B) -> (RA, RB)
+where
+	A: FnOnce() -> RA,
+	B: FnOnce() -> RB,
+{
+	(oper_a(), oper_b())
+}
+
+/// Builds a [`ThreadPool`], which runs everything on the calling thread.
+#[derive(Debug, Default)]
+pub struct ThreadPoolBuilder;
+
+impl ThreadPoolBuilder {
+	pub fn new() -> Self {
+		Self
+	}
+
+	pub fn num_threads(self, _num_threads: usize) -> Self {
+		self
+	}
+
+	pub fn use_current_thread(self) -> Self {
+		self
+	}
+
+	/// Accepts a handler for the worker threads, of which there are none.
+	pub fn start_handler<H: Fn(usize) + Send + Sync + 'static>(self, _start_handler: H) -> Self {
+		self
+	}
+
+	pub fn thread_name<F: FnMut(usize) -> String + 'static>(self, _thread_name: F) -> Self {
+		self
+	}
+
+	pub fn build(self) -> Result<ThreadPool, ThreadPoolBuildError> {
+		Ok(ThreadPool)
+	}
+
+	pub fn build_global(self) -> Result<(), ThreadPoolBuildError> {
+		Ok(())
+	}
+}
+
+/// A thread pool that runs every operation on the calling thread.
+#[derive(Debug)]
+pub struct ThreadPool;
+
+impl ThreadPool {
+	pub fn install<OP, R>(&self, op: OP) -> R
+	where
+		OP: FnOnce() -> R,
+	{
+		op()
+	}
+
+	pub fn current_num_threads(&self) -> usize {
+		1
+	}
+}
+
+/// The error of building a thread pool, which the sequential pool never returns.
+#[derive(Debug)]
+pub struct ThreadPoolBuildError;
+
+impl fmt::Display for ThreadPoolBuildError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("failed to build the thread pool")
+	}
+}
+
+impl Error for ThreadPoolBuildError {}
+
+#[cfg(test)]
+mod tests {
+	use super::prelude::*;
+
+	#[test]
+	fn test_sequential_iterators_match_rayon_semantics() {
+		let values = (0..10u64).collect::<Vec<_>>();
+		let mut doubled = vec![0u64; 10];
+		(values.par_iter(), doubled.par_iter_mut())
+			.into_par_iter()
+			.for_each(|(value, doubled)| *doubled = 2 * value);
+		assert_eq!(doubled, values.iter().map(|value| 2 * value).collect::<Vec<_>>());
+
+		let sum = values
+			.par_iter()
+			.fold(|| 0, |acc, value| acc + value)
+			.reduce(|| 0, |a, b| a + b);
+		assert_eq!(sum, 45);
+
+		let chunk_sums = values
+			.par_iter()
+			.chunks(4)
+			.map(|chunk| chunk.into_iter().sum::<u64>())
+			.collect::<Vec<_>>();
+		assert_eq!(chunk_sums, vec![6, 22, 17]);
+
+		let checked = values
+			.par_chunks(3)
+			.map(|chunk| {
+				if chunk.len() == 3 {
+					Ok(chunk[0])
+				} else {
+					Err(chunk.len())
+				}
+			})
+			.collect::<Result<Vec<_>, _>>();
+		assert_eq!(checked, Err(1));
+	}
+}
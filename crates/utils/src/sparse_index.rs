@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 /// An index mapping positive integer IDs to optional values.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SparseIndex<T> {
 	entries: Vec<Option<T>>,
 }
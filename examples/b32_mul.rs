@@ -103,8 +103,7 @@ fn main() -> Result<()> {
 		_,
 	>(
 		&constraint_system,
-		args.log_inv_rate as usize,
-		SECURITY_BITS,
+		&constraint_system::PCSParams::new(args.log_inv_rate as usize, SECURITY_BITS),
 		witness,
 		&domain_factory,
 		&backend,
@@ -118,7 +117,12 @@ fn main() -> Result<()> {
 		Groestl256,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
-	>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, vec![], proof)?;
+	>(
+		&constraint_system,
+		&constraint_system::PCSParams::new(args.log_inv_rate as usize, SECURITY_BITS),
+		vec![],
+		proof,
+	)?;
 
 	Ok(())
 }
@@ -81,8 +81,7 @@ fn prove(x0: u32, log_inv_rate: usize) -> Result<(Advice, Proof), anyhow::Error>
 		_,
 	>(
 		&constraint_system,
-		log_inv_rate,
-		SECURITY_BITS,
+		&constraint_system::PCSParams::new(log_inv_rate, SECURITY_BITS),
 		witness,
 		&domain_factory,
 		&make_portable_backend(),
@@ -106,7 +105,12 @@ fn verify(x0: u32, advice: Advice, proof: Proof, log_inv_rate: usize) -> Result<
 		Groestl256,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
-	>(&constraint_system, log_inv_rate, SECURITY_BITS, boundaries, proof)?;
+	>(
+		&constraint_system,
+		&constraint_system::PCSParams::new(log_inv_rate, SECURITY_BITS),
+		boundaries,
+		proof,
+	)?;
 
 	Ok(())
 }
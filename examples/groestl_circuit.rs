@@ -69,8 +69,7 @@ fn main() -> Result<()> {
 		_,
 	>(
 		&constraint_system,
-		args.log_inv_rate as usize,
-		SECURITY_BITS,
+		&constraint_system::PCSParams::new(args.log_inv_rate as usize, SECURITY_BITS),
 		witness,
 		&domain_factory,
 		&backend,
@@ -86,8 +85,7 @@ fn main() -> Result<()> {
 		HasherChallenger<Groestl256>,
 	>(
 		&constraint_system.no_base_constraints(),
-		args.log_inv_rate as usize,
-		SECURITY_BITS,
+		&constraint_system::PCSParams::new(args.log_inv_rate as usize, SECURITY_BITS),
 		vec![],
 		proof,
 	)?;
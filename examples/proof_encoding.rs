@@ -0,0 +1,159 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+#![feature(array_try_from_fn)]
+
+//! Compares the proof sizes of the standard examples under the available proof encodings.
+
+use std::array;
+
+use anyhow::Result;
+use binius_circuits::{
+	arithmetic::Flags, builder::ConstraintSystemBuilder, unconstrained::unconstrained,
+};
+use binius_core::{
+	constraint_system::{self, AdviceCompression, ProofEncoding, ProveOptions},
+	fiat_shamir::HasherChallenger,
+	oracle::OracleId,
+	tower::CanonicalTowerFamily,
+	transcript::ScalarEncoding,
+};
+use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_math::DefaultEvaluationDomainFactory;
+use binius_utils::rayon::adjust_thread_pool;
+use bytesize::ByteSize;
+use clap::{value_parser, Parser};
+use groestl_crypto::Groestl256;
+
+type U = OptimalUnderlier;
+type Builder<'a> = ConstraintSystemBuilder<'a, U, BinaryField128b>;
+type Circuit = fn(&mut Builder, usize) -> Result<()>;
+
+const SECURITY_BITS: usize = 100;
+
+#[derive(Debug, Parser)]
+struct Args {
+	/// The binary logarithm of the number of rows of each example circuit.
+	#[arg(long, default_value_t = 10, value_parser = value_parser!(u32).range(3..))]
+	log_size: u32,
+	/// The negative binary logarithm of the Reed–Solomon code rate.
+	#[arg(long, default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+	log_inv_rate: u32,
+}
+
+fn u32_add(builder: &mut Builder, log_size: usize) -> Result<()> {
+	let in_a = unconstrained::<_, _, BinaryField1b>(builder, "in_a", log_size + 5)?;
+	let in_b = unconstrained::<_, _, BinaryField1b>(builder, "in_b", log_size + 5)?;
+	binius_circuits::arithmetic::u32::add(builder, "sum", in_a, in_b, Flags::Unchecked)?;
+	Ok(())
+}
+
+fn sha256(builder: &mut Builder, log_size: usize) -> Result<()> {
+	let input: [OracleId; 16] =
+		array::try_from_fn(|i| unconstrained::<_, _, BinaryField1b>(builder, i, log_size + 5))?;
+	binius_circuits::sha256::sha256(builder, input, log_size + 5)?;
+	Ok(())
+}
+
+fn keccakf(builder: &mut Builder, log_size: usize) -> Result<()> {
+	binius_circuits::keccakf::keccakf(builder, Some(vec![]), log_size)?;
+	Ok(())
+}
+
+fn proof_size(
+	circuit: Circuit,
+	log_size: usize,
+	log_inv_rate: usize,
+	encoding: ProofEncoding,
+) -> Result<usize> {
+	let allocator = bumpalo::Bump::new();
+	let mut builder = Builder::new_with_witness(&allocator);
+	circuit(&mut builder, log_size)?;
+
+	let witness = builder
+		.take_witness()
+		.expect("builder created with witness");
+	let constraint_system = builder.build()?;
+
+	let domain_factory = DefaultEvaluationDomainFactory::default();
+	let backend = make_portable_backend();
+	let options = ProveOptions::default().with_encoding(encoding);
+
+	let proof = constraint_system::prove_with_options::<
+		U,
+		CanonicalTowerFamily,
+		_,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		&constraint_system::PCSParams::new(log_inv_rate, SECURITY_BITS),
+		witness,
+		&domain_factory,
+		&backend,
+		&options,
+	)?;
+	let size = proof.get_proof_size();
+
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(
+		&constraint_system,
+		&constraint_system::PCSParams::new(log_inv_rate, SECURITY_BITS),
+		vec![],
+		proof,
+	)?;
+
+	Ok(size)
+}
+
+fn main() -> Result<()> {
+	adjust_thread_pool()
+		.as_ref()
+		.expect("failed to init thread pool");
+
+	let args = Args::parse();
+
+	let circuits: [(&str, Circuit); 3] = [
+		("u32_add", u32_add),
+		("sha256", sha256),
+		("keccakf", keccakf),
+	];
+	let encodings = [
+		("canonical", ProofEncoding::default()),
+		(
+			"compact scalars",
+			ProofEncoding {
+				scalars: ScalarEncoding::Compact,
+				advice_compression: AdviceCompression::None,
+			},
+		),
+		("compact + deflate", ProofEncoding::compact()),
+	];
+
+	println!("{:<10} {:<18} {:>12} {:>8}", "circuit", "encoding", "proof size", "ratio");
+	for (circuit_name, circuit) in circuits {
+		let mut baseline = None;
+		for (encoding_name, encoding) in encodings {
+			let size =
+				proof_size(circuit, args.log_size as usize, args.log_inv_rate as usize, encoding)?;
+			let baseline = *baseline.get_or_insert(size);
+			println!(
+				"{:<10} {:<18} {:>12} {:>7.1}%",
+				circuit_name,
+				encoding_name,
+				ByteSize::b(size as u64).to_string(),
+				100.0 * size as f64 / baseline as f64
+			);
+		}
+	}
+
+	Ok(())
+}